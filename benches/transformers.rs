@@ -0,0 +1,120 @@
+//! Timing benchmarks for transformers across input sizes.
+//!
+//! Run with `cargo bench --bench transformers [FILTER]`. Each case reports the
+//! mean time per call. `deflatecompress` runs at levels 1, 6 and 9 to compare
+//! its greedy short-chain matching with the lazy, longer hash chains of the
+//! higher levels.
+//! Uses a plain `main` instead of a bench framework to keep buup dependency-free.
+
+use buup::{transformer_from_id, TransformOptions};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SIZES: [usize; 5] = [64, 256, 1024, 16 * 1024, 1024 * 1024];
+const TARGET_TIME: Duration = Duration::from_millis(200);
+
+// Text with realistic repetition: words drawn from a small vocabulary
+fn sample_text(len: usize) -> String {
+    const WORDS: [&str; 12] = [
+        "buup",
+        "transform",
+        "the",
+        "input",
+        "quick",
+        "brown",
+        "fox",
+        "jumps",
+        "over",
+        "lazy",
+        "dog",
+        "\n",
+    ];
+    let mut seed = 0x9e37_79b9_u32;
+    let mut text = String::with_capacity(len + 16);
+    while text.len() < len {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        text.push_str(WORDS[seed as usize % WORDS.len()]);
+        text.push(' ');
+    }
+    text.truncate(len);
+    text
+}
+
+// Calls `run` repeatedly for about TARGET_TIME and returns the mean duration
+fn measure(mut run: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    let mut iterations = 0u32;
+    while start.elapsed() < TARGET_TIME || iterations == 0 {
+        run();
+        iterations += 1;
+    }
+    start.elapsed() / iterations
+}
+
+fn bench_bytes(filter: Option<&str>, id: &str, prepare: impl Fn(&[u8]) -> Vec<u8>) {
+    bench_bytes_with(filter, id, &TransformOptions::new(), id, prepare);
+}
+
+fn bench_bytes_with(
+    filter: Option<&str>,
+    id: &str,
+    options: &TransformOptions,
+    label: &str,
+    prepare: impl Fn(&[u8]) -> Vec<u8>,
+) {
+    if filter.is_some_and(|f| !label.contains(f)) {
+        return;
+    }
+    let transformer = transformer_from_id(id).unwrap();
+    for size in SIZES {
+        let input = prepare(sample_text(size).as_bytes());
+        let mean = measure(|| {
+            black_box(
+                transformer
+                    .transform_bytes_with_options(black_box(&input), options)
+                    .unwrap(),
+            );
+        });
+        println!("{:<20} {:>9} B {:>12.2?}", label, size, mean);
+    }
+}
+
+fn bench_text(filter: Option<&str>, id: &str) {
+    if filter.is_some_and(|f| !id.contains(f)) {
+        return;
+    }
+    let transformer = transformer_from_id(id).unwrap();
+    for size in SIZES {
+        let input = sample_text(size);
+        let mean = measure(|| {
+            black_box(transformer.transform(black_box(&input)).unwrap());
+        });
+        println!("{:<20} {:>9} B {:>12.2?}", id, size, mean);
+    }
+}
+
+fn main() {
+    // `cargo bench` passes `--bench`; anything else is a substring filter
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with("--"));
+    let filter = filter.as_deref();
+    let compress = |input: &[u8]| {
+        transformer_from_id("deflatecompress")
+            .unwrap()
+            .transform_bytes(input)
+            .unwrap()
+    };
+
+    for level in ["1", "6", "9"] {
+        let mut options = TransformOptions::new();
+        options.set("level", level);
+        let label = format!("deflatecompress -{}", level);
+        bench_bytes_with(filter, "deflatecompress", &options, &label, <[u8]>::to_vec);
+    }
+    bench_bytes(filter, "deflatedecompress", compress);
+    bench_bytes(filter, "gzipcompress", <[u8]>::to_vec);
+    bench_text(filter, "linesorter");
+    bench_text(filter, "sha256hash");
+    bench_text(filter, "base64encode");
+}
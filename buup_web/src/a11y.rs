@@ -0,0 +1,70 @@
+// Screen reader support: a polite live region announcing output updates and
+// transform errors, and keyboard focus handling for the transformer menu,
+// which is built from divs rather than a native <select>.
+
+use crate::i18n;
+use buup::Locale;
+use dioxus::prelude::*;
+
+pub const MENU_ID: &str = "transformer-menu";
+
+/// What the live region should say about the latest run
+pub fn announcement(locale: Locale, output: &str, error: Option<&str>) -> String {
+    match error {
+        Some(error) => format!("{}: {}", i18n::t(locale, "Error"), error),
+        None if output.is_empty() => String::new(),
+        None => format!(
+            "{} ({} {})",
+            i18n::t(locale, "Output updated"),
+            output.chars().count(),
+            i18n::t(locale, "characters")
+        ),
+    }
+}
+
+/// Focuses the first element matching `selector` once it has rendered
+pub fn focus(selector: &str) {
+    #[cfg(feature = "web")]
+    let _ = js_sys::eval(&format!(
+        "setTimeout(() => document.querySelector({})?.focus(), 10);",
+        serde_json::to_string(selector).unwrap()
+    ));
+    #[cfg(not(feature = "web"))]
+    let _ = selector;
+}
+
+/// Moves focus `step` options up or down the transformer menu, from the
+/// search box into the list and back out again past the first option
+pub fn move_menu_focus(step: i32) {
+    #[cfg(feature = "web")]
+    let _ = js_sys::eval(&format!(
+        r#"
+        (() => {{
+            const options = [...document.querySelectorAll('#{MENU_ID} .transformer-option')];
+            if (!options.length) return;
+            const index = options.indexOf(document.activeElement) + {step};
+            if (index < 0) {{
+                document.querySelector('#{MENU_ID} .search-input')?.focus();
+            }} else {{
+                options[Math.min(index, options.length - 1)].focus();
+            }}
+        }})();
+        "#
+    ));
+    #[cfg(not(feature = "web"))]
+    let _ = step;
+}
+
+// Visually hidden region read out by screen readers whenever `message` changes
+#[component]
+pub fn Announcer(message: String) -> Element {
+    rsx! {
+        div {
+            class: "sr-only",
+            role: "status",
+            aria_live: "polite",
+            aria_atomic: "true",
+            "{message}"
+        }
+    }
+}
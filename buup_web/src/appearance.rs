@@ -0,0 +1,230 @@
+// Appearance preferences on top of light/dark mode: accent color, the
+// monospace font and text size of the textareas, and line wrapping. Saved in
+// localStorage next to `buup_dark_mode`.
+
+use crate::storage;
+use dioxus::prelude::*;
+
+// Textarea font size bounds in pixels
+pub const MIN_FONT_SIZE: u32 = 10;
+const DEFAULT_FONT_SIZE: u32 = 14;
+pub const MAX_FONT_SIZE: u32 = 24;
+
+#[derive(Debug, PartialEq)]
+pub struct Accent {
+    pub name: &'static str,
+    pub dark: &'static str,
+    pub light: &'static str,
+}
+
+/// Selectable accent colors, each tuned for dark and light backgrounds
+pub const ACCENTS: [Accent; 6] = [
+    Accent {
+        name: "Blue",
+        dark: "#0A84FF",
+        light: "#0066CC",
+    },
+    Accent {
+        name: "Purple",
+        dark: "#BF5AF2",
+        light: "#8944AB",
+    },
+    Accent {
+        name: "Pink",
+        dark: "#FF375F",
+        light: "#D30F45",
+    },
+    Accent {
+        name: "Orange",
+        dark: "#FF9F0A",
+        light: "#C93400",
+    },
+    Accent {
+        name: "Green",
+        dark: "#30D158",
+        light: "#248A3D",
+    },
+    Accent {
+        name: "Teal",
+        dark: "#40C8E0",
+        light: "#0071A4",
+    },
+];
+
+impl Accent {
+    /// The color for the current light/dark mode
+    pub fn color(&self, dark_mode: bool) -> &'static str {
+        if dark_mode {
+            self.dark
+        } else {
+            self.light
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MonoFont {
+    pub name: &'static str,
+    pub family: &'static str,
+}
+
+/// Selectable textarea fonts; each falls back to the system monospace font
+pub const MONO_FONTS: [MonoFont; 5] = [
+    MonoFont {
+        name: "System",
+        family:
+            "ui-monospace, SFMono-Regular, SF Mono, Menlo, Consolas, Liberation Mono, monospace",
+    },
+    MonoFont {
+        name: "Menlo",
+        family: "Menlo, ui-monospace, monospace",
+    },
+    MonoFont {
+        name: "Consolas",
+        family: "Consolas, ui-monospace, monospace",
+    },
+    MonoFont {
+        name: "Courier",
+        family: "\"Courier New\", Courier, monospace",
+    },
+    MonoFont {
+        name: "Fira Code",
+        family: "\"Fira Code\", ui-monospace, monospace",
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    pub accent: &'static Accent,
+    pub mono_font: &'static MonoFont,
+    /// Textarea font size in pixels
+    pub font_size: u32,
+    pub input_wrap: bool,
+    pub output_wrap: bool,
+}
+
+impl Appearance {
+    pub fn load() -> Self {
+        let accent = storage::load("buup_accent")
+            .and_then(|name| ACCENTS.iter().find(|accent| accent.name == name))
+            .unwrap_or(&ACCENTS[0]);
+        let mono_font = storage::load("buup_mono_font")
+            .and_then(|name| MONO_FONTS.iter().find(|font| font.name == name))
+            .unwrap_or(&MONO_FONTS[0]);
+        // The size used to apply to the output panel only
+        let font_size = storage::load("buup_font_size")
+            .or_else(|| storage::load("buup_output_font_size"))
+            .and_then(|size| size.parse::<u32>().ok())
+            .filter(|size| (MIN_FONT_SIZE..=MAX_FONT_SIZE).contains(size))
+            .unwrap_or(DEFAULT_FONT_SIZE);
+
+        Appearance {
+            accent,
+            mono_font,
+            font_size,
+            input_wrap: storage::load_bool("buup_input_wrap", true),
+            output_wrap: storage::load_bool("buup_output_wrap", true),
+        }
+    }
+
+    pub fn save(&self) {
+        storage::save("buup_accent", self.accent.name);
+        storage::save("buup_mono_font", self.mono_font.name);
+        storage::save("buup_font_size", &self.font_size.to_string());
+        storage::save("buup_input_wrap", &self.input_wrap.to_string());
+        storage::save("buup_output_wrap", &self.output_wrap.to_string());
+    }
+
+    /// Grows or shrinks the text by `step` pixels within the allowed range
+    pub fn resize_font(&mut self, step: i32) {
+        self.font_size = self
+            .font_size
+            .saturating_add_signed(step)
+            .clamp(MIN_FONT_SIZE, MAX_FONT_SIZE);
+    }
+}
+
+// Strip of appearance controls shown under the header
+#[component]
+pub fn AppearanceStrip(appearance: Signal<Appearance>, dark_mode: bool) -> Element {
+    let current = appearance();
+    let mut update = move |change: &dyn Fn(&mut Appearance)| {
+        change(&mut appearance.write());
+        appearance().save();
+    };
+
+    rsx! {
+        div { class: "options-strip appearance-strip",
+            div { class: "option-control",
+                span { class: "option-label", "Accent" }
+                for (accent, color) in ACCENTS.iter().map(|accent| (accent, accent.color(dark_mode))) {
+                    button {
+                        key: "{accent.name}",
+                        class: if std::ptr::eq(accent, current.accent) { "accent-swatch selected" } else { "accent-swatch" },
+                        style: "background: {color};",
+                        title: "{accent.name}",
+                        aria_label: "{accent.name} accent",
+                        onclick: move |_| update(&|appearance| appearance.accent = accent),
+                    }
+                }
+            }
+            label { class: "option-control",
+                span { class: "option-label", "Font" }
+                select {
+                    class: "option-input",
+                    onchange: move |evt| {
+                        if let Some(font) = MONO_FONTS.iter().find(|font| font.name == evt.value()) {
+                            update(&|appearance| appearance.mono_font = font);
+                        }
+                    },
+                    for font in MONO_FONTS.iter() {
+                        option {
+                            value: "{font.name}",
+                            selected: std::ptr::eq(font, current.mono_font),
+                            "{font.name}"
+                        }
+                    }
+                }
+            }
+            label { class: "option-control",
+                span { class: "option-label", "Text size" }
+                input {
+                    class: "option-input option-number",
+                    r#type: "number",
+                    min: "{MIN_FONT_SIZE}",
+                    max: "{MAX_FONT_SIZE}",
+                    value: "{current.font_size}",
+                    oninput: move |evt| {
+                        if let Ok(size) = evt.value().parse::<u32>() {
+                            update(&|appearance| {
+                                appearance.font_size = size.clamp(MIN_FONT_SIZE, MAX_FONT_SIZE)
+                            });
+                        }
+                    },
+                }
+            }
+            label { class: "option-control",
+                span { class: "option-label", "Wrap input" }
+                input {
+                    r#type: "checkbox",
+                    checked: current.input_wrap,
+                    onchange: move |evt| {
+                        let wrap = evt.checked();
+                        update(&|appearance| appearance.input_wrap = wrap);
+                    },
+                }
+            }
+            label { class: "option-control",
+                span { class: "option-label", "Wrap output" }
+                input {
+                    r#type: "checkbox",
+                    checked: current.output_wrap,
+                    onchange: move |evt| {
+                        let wrap = evt.checked();
+                        update(&|appearance| appearance.output_wrap = wrap);
+                    },
+                }
+            }
+        }
+    }
+}
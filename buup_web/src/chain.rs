@@ -0,0 +1,116 @@
+// Chain mode: the input runs through a stack of transformers (a core
+// `Pipeline`), with every step's intermediate output shown in its own card.
+
+use crate::errors::TransformFailure;
+use crate::storage;
+use buup::{Locale, Pipeline, TransformError};
+use dioxus::prelude::*;
+
+/// Characters of each intermediate output shown in a step card
+const PREVIEW_CHARS: usize = 500;
+
+/// The chain saved in localStorage, if any
+pub fn load() -> Option<Pipeline> {
+    storage::load("buup_chain")?
+        .parse::<Pipeline>()
+        .ok()
+        .filter(|chain| !chain.is_empty())
+}
+
+pub fn save(chain: &Pipeline) {
+    storage::save("buup_chain", &chain.to_string());
+}
+
+/// Runs the chain and prepares its results with `failures`
+pub fn run(chain: &Pipeline, input: &str) -> Vec<Result<String, TransformFailure>> {
+    failures(chain.run(input), input)
+}
+
+/// Turns step errors into failures, keeping error locations only for the
+/// first step: later steps fail on intermediate text rather than on what the
+/// user typed
+pub fn failures(
+    results: Vec<Result<String, TransformError>>,
+    input: &str,
+) -> Vec<Result<String, TransformFailure>> {
+    results
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| {
+            result.map_err(|err| {
+                let mut failure = TransformFailure::new(&err, input);
+                if index > 0 {
+                    failure.location = None;
+                    failure.message = format!("Step {}: {}", index + 1, failure.message);
+                }
+                failure
+            })
+        })
+        .collect()
+}
+
+fn preview(output: &str) -> String {
+    match output.char_indices().nth(PREVIEW_CHARS) {
+        Some((end, _)) => format!("{}…", &output[..end]),
+        None => output.to_string(),
+    }
+}
+
+// The list of steps with reorder/remove controls and intermediate outputs
+#[component]
+pub fn ChainSteps(
+    chain: Signal<Pipeline>,
+    results: Vec<Result<String, TransformFailure>>,
+    locale: Locale,
+) -> Element {
+    let steps = chain.read().steps().to_vec();
+    let last = steps.len().saturating_sub(1);
+
+    rsx! {
+        div { class: "chain-steps",
+            if steps.is_empty() {
+                div { class: "chain-empty", "Pick transformers from the menu above to add steps" }
+            }
+            for (index, step) in steps.into_iter().enumerate() {
+                div { key: "{index}-{step.id()}", class: "chain-step",
+                    div { class: "chain-step-header",
+                        span { class: "chain-step-name", "{index + 1}. {buup::localized_name(step, locale)}" }
+                        div { class: "panel-actions",
+                            button {
+                                class: "action-button",
+                                title: "Move step up",
+                                disabled: index == 0,
+                                onclick: move |_| chain.write().move_step(index, index.saturating_sub(1)),
+                                "↑"
+                            }
+                            button {
+                                class: "action-button",
+                                title: "Move step down",
+                                disabled: index == last,
+                                onclick: move |_| chain.write().move_step(index, index + 1),
+                                "↓"
+                            }
+                            button {
+                                class: "action-button",
+                                title: "Remove step",
+                                onclick: move |_| {
+                                    chain.write().remove(index);
+                                },
+                                "✕"
+                            }
+                        }
+                    }
+                    match results.get(index) {
+                        Some(Ok(output)) => rsx! {
+                            pre { class: "chain-step-output", "{preview(output)}" }
+                        },
+                        Some(Err(failure)) => rsx! {
+                            div { class: "chain-step-output chain-step-error", "{failure.message}" }
+                        },
+                        None => rsx! {},
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// WebAssembly has no system clock, so the timestamped transformers read the
+// time from JavaScript.
+
+use buup::utils::time::Clock;
+
+/// The browser's `Date.now()`
+pub struct BrowserClock;
+
+impl Clock for BrowserClock {
+    #[cfg(target_arch = "wasm32")]
+    fn now_millis(&self) -> i64 {
+        js_sys::Date::now() as i64
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn now_millis(&self) -> i64 {
+        buup::utils::time::SystemClock.now_millis()
+    }
+
+    // getTimezoneOffset is UTC minus local time, in minutes
+    #[cfg(target_arch = "wasm32")]
+    fn utc_offset_seconds(&self, seconds: i64) -> i32 {
+        let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(seconds as f64 * 1000.0));
+        -(date.get_timezone_offset() * 60.0) as i32
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn utc_offset_seconds(&self, seconds: i64) -> i32 {
+        buup::utils::time::SystemClock.utc_offset_seconds(seconds)
+    }
+}
+
+/// Makes `BrowserClock` the clock for every transformer
+pub fn install() {
+    buup::utils::time::set_clock(&BrowserClock);
+}
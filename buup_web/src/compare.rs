@@ -0,0 +1,110 @@
+// Compare mode: the input runs through several transformers at once and the
+// outputs are laid out in a grid, e.g. md5/sha1/sha256 side by side.
+
+use crate::{i18n, storage};
+use buup::Locale;
+use dioxus::prelude::*;
+
+const STORAGE_KEY: &str = "buup_compare";
+const DEFAULT_IDS: [&str; 3] = ["md5hash", "sha1hash", "sha256hash"];
+
+/// The saved selection, or a few hashes to start with
+pub fn load() -> Vec<&'static str> {
+    match storage::load(STORAGE_KEY) {
+        Some(saved) => saved
+            .split(',')
+            .filter_map(|id| buup::transformer_from_id(id).ok())
+            .map(|transformer| transformer.id())
+            .collect(),
+        None => DEFAULT_IDS.to_vec(),
+    }
+}
+
+pub fn save(ids: &[&'static str]) {
+    storage::save(STORAGE_KEY, &ids.join(","));
+}
+
+/// Adds `id` to the selection unless it's already there
+pub fn add(ids: &mut Vec<&'static str>, id: &'static str) {
+    if !ids.contains(&id) {
+        ids.push(id);
+    }
+}
+
+// Every selected transformer's output for the input, side by side
+#[component]
+pub fn CompareGrid(ids: Signal<Vec<&'static str>>, input: String, locale: Locale) -> Element {
+    let selected = ids();
+    let mut categories: Vec<_> = buup::categorized_transformers().into_iter().collect();
+    categories.sort_by_key(|(category, _)| category.to_string());
+
+    rsx! {
+        div { class: "compare",
+            div { class: "compare-toolbar",
+                span { class: "compare-hint", "Pick transformers from the menu, or add a whole category:" }
+                for (category, transformers) in categories {
+                    button {
+                        key: "{category}",
+                        class: "category-button",
+                        onclick: move |_| {
+                            let mut ids = ids.write();
+                            for transformer in &transformers {
+                                add(&mut ids, transformer.id());
+                            }
+                        },
+                        {i18n::category(locale, category)}
+                    }
+                }
+                if !selected.is_empty() {
+                    button {
+                        class: "category-button",
+                        onclick: move |_| ids.write().clear(),
+                        "Clear"
+                    }
+                }
+            }
+            div { class: "compare-grid",
+                for id in selected {
+                    CompareCard { key: "{id}", id, input: input.clone(), ids, locale }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CompareCard(
+    id: &'static str,
+    input: String,
+    ids: Signal<Vec<&'static str>>,
+    locale: Locale,
+) -> Element {
+    let transformer = buup::transformer_from_id(id).unwrap();
+    let result = if input.is_empty() {
+        Ok(String::new())
+    } else {
+        transformer.transform(&input).map_err(|err| err.to_string())
+    };
+
+    rsx! {
+        div { class: "compare-card",
+            div { class: "chain-step-header",
+                span { class: "chain-step-name", title: "{buup::localized_description(transformer, locale)}", "{buup::localized_name(transformer, locale)}" }
+                button {
+                    class: "action-button",
+                    title: "Remove from comparison",
+                    onclick: move |_| ids.write().retain(|selected| *selected != id),
+                    "✕"
+                }
+            }
+            match result {
+                Ok(output) => rsx! {
+                    pre { class: "chain-step-output compare-output", "{output}" }
+                },
+                Err(message) => rsx! {
+                    div { class: "chain-step-output chain-step-error", "{message}" }
+                },
+            }
+        }
+    }
+}
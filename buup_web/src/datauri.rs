@@ -0,0 +1,91 @@
+// Images as Base64 `data:` URIs: an image pasted or dropped on the page is
+// shown in the output as a data URI, and a data URI given as input gets an
+// image preview. The Base64 work is done by the core encoder and decoder.
+
+use crate::status::format_size;
+use buup::{Base64Decode, Base64Encode, Transform};
+
+/// Largest image accepted from a paste or drop
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+/// A pasted or dropped image, encoded
+#[derive(Debug, Clone, PartialEq)]
+pub struct PastedImage {
+    pub uri: String,
+    pub mime: String,
+    pub size: usize,
+}
+
+impl PastedImage {
+    pub fn new(mime: String, bytes: &[u8]) -> Self {
+        let base64 = Base64Encode.transform_bytes(bytes).unwrap_or_default();
+        PastedImage {
+            uri: format!(
+                "data:{};base64,{}",
+                mime,
+                String::from_utf8(base64).unwrap_or_default()
+            ),
+            mime,
+            size: bytes.len(),
+        }
+    }
+
+    /// Describes the image for the banner above the output
+    pub fn describe(&self) -> String {
+        format!(
+            "Pasted image ({}, {}) as a data URI",
+            self.mime,
+            format_size(self.size)
+        )
+    }
+}
+
+/// If `input` is a Base64 image data URI, describes the image it holds
+pub fn describe_image_uri(input: &str) -> Option<String> {
+    let rest = input.trim().strip_prefix("data:")?;
+    let (mime, base64) = rest.split_once(";base64,")?;
+    if !mime.starts_with("image/") {
+        return None;
+    }
+    let bytes = Base64Decode.transform_bytes(base64.as_bytes()).ok()?;
+    Some(format!("{}, {}", mime, format_size(bytes.len())))
+}
+
+/// Waits for images pasted or dropped anywhere on the page, calling
+/// `on_image` with each. Runs for as long as the page is open.
+pub async fn listen_for_images(mut on_image: impl FnMut(PastedImage)) {
+    let mut eval = dioxus::document::eval(&format!(
+        r#"
+        const maxBytes = {max_bytes};
+        const sendImage = (file) => {{
+            if (!file || !file.type.startsWith('image/')) return false;
+            if (file.size > maxBytes) {{
+                console.warn('Image is too large to paste:', file.size, 'bytes');
+                return true;
+            }}
+            file.arrayBuffer().then((buffer) => {{
+                dioxus.send([file.type, Array.from(new Uint8Array(buffer))]);
+            }});
+            return true;
+        }};
+        document.addEventListener('paste', (e) => {{
+            const files = e.clipboardData && e.clipboardData.files;
+            if (files && files.length && sendImage(files[0])) e.preventDefault();
+        }});
+        document.addEventListener('dragover', (e) => {{
+            if (e.dataTransfer && Array.from(e.dataTransfer.types).includes('Files')) {{
+                e.preventDefault();
+            }}
+        }});
+        document.addEventListener('drop', (e) => {{
+            const files = e.dataTransfer && e.dataTransfer.files;
+            if (files && files.length && sendImage(files[0])) e.preventDefault();
+        }});
+        await new Promise(() => {{}});
+        "#,
+        max_bytes = MAX_IMAGE_BYTES,
+    ));
+    while let Ok((mime, bytes)) = eval.recv::<(String, Vec<u8>)>().await {
+        on_image(PastedImage::new(mime, &bytes));
+    }
+}
@@ -0,0 +1,20 @@
+// Keeps typing responsive with large inputs: once the input is big, the text
+// the transformers see lags the textarea by a short delay, so a burst of
+// keystrokes costs one transform instead of one per key.
+
+/// Inputs up to this many bytes are transformed on every keystroke
+const INSTANT_LIMIT: usize = 64 * 1024;
+/// Inputs above this many bytes wait the longest
+const LARGE_LIMIT: usize = 1024 * 1024;
+
+/// How long to wait after a keystroke before transforming, in milliseconds,
+/// or `None` to transform straight away
+pub fn delay(input_len: usize) -> Option<u32> {
+    if input_len <= INSTANT_LIMIT {
+        None
+    } else if input_len <= LARGE_LIMIT {
+        Some(150)
+    } else {
+        Some(400)
+    }
+}
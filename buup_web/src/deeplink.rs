@@ -0,0 +1,153 @@
+// Shareable links: the URL hash holds the transformer ID and, when there is
+// input, a DEFLATE-compressed Base64url copy of it, e.g. `#urlencode:y0jNyckHAA`.
+// Plain `#urlencode` links from before keep working.
+//
+// Other sites and bookmarks can also link with query parameters, e.g.
+// `?t=sha256hash&i=hello&run=1`, which are read once on load and then replaced
+// by the hash. `?embed=1` opens the minimal widget for iframes, whose URL is
+// left alone so the embedding page's link stays the source of truth.
+
+use buup::{DeflateCompress, DeflateDecompress, Transform, TransformOptions, UrlDecode};
+
+/// Longest encoded input kept in the URL; bigger inputs share only the ID
+const MAX_ENCODED_LEN: usize = 8 * 1024;
+/// Cap on the size of input restored from a link
+const MAX_DECODED_LEN: usize = 1024 * 1024;
+
+/// Builds the hash (without `#`) for a transformer and its input
+pub fn encode(id: &str, input: &str) -> String {
+    // Text rarely compresses better than 16:1, so skip hopeless inputs early
+    if input.is_empty() || input.len() > MAX_ENCODED_LEN * 16 {
+        return id.to_string();
+    }
+    match DeflateCompress.transform(input) {
+        Ok(base64) if base64.len() <= MAX_ENCODED_LEN => {
+            let data: String = base64
+                .chars()
+                .filter(|&c| c != '=')
+                .map(|c| match c {
+                    '+' => '-',
+                    '/' => '_',
+                    c => c,
+                })
+                .collect();
+            format!("{}:{}", id, data)
+        }
+        _ => id.to_string(),
+    }
+}
+
+/// Splits a hash into the transformer ID and the input it carries, if any.
+/// Input that fails to decode is ignored rather than reported.
+pub fn decode(hash: &str) -> (&str, Option<String>) {
+    let hash = hash.trim_start_matches('#');
+    let Some((id, data)) = hash.split_once(':') else {
+        return (hash, None);
+    };
+    let mut base64: String = data
+        .chars()
+        .map(|c| match c {
+            '-' => '+',
+            '_' => '/',
+            c => c,
+        })
+        .collect();
+    while !base64.len().is_multiple_of(4) {
+        base64.push('=');
+    }
+    let mut options = TransformOptions::new();
+    options.set("max_output", MAX_DECODED_LEN.to_string());
+    let input = DeflateDecompress
+        .transform_with_options(&base64, &options)
+        .ok();
+    (id, input)
+}
+
+/// A link made with query parameters: `t` is the transformer ID (or a comma
+/// separated chain), `i` the URL-encoded input and `run=1` applies it right away
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryLink {
+    pub id: Option<String>,
+    pub input: Option<String>,
+    pub run: bool,
+}
+
+/// Parses a query string (with or without `?`), or `None` if it has neither
+/// a transformer nor an input
+pub fn parse_query(query: &str) -> Option<QueryLink> {
+    let mut link = QueryLink::default();
+    for pair in query.trim_start_matches('?').split('&') {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let Ok(value) = UrlDecode.transform(value) else {
+            continue;
+        };
+        match key {
+            "t" if !value.is_empty() => link.id = Some(value),
+            "i" => link.input = Some(value),
+            "run" => link.run = matches!(value.as_str(), "1" | "true"),
+            _ => {}
+        }
+    }
+    (link.id.is_some() || link.input.is_some()).then_some(link)
+}
+
+/// Whether a query string (with or without `?`) asks for the embeddable widget
+pub fn is_embed(query: &str) -> bool {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .any(|pair| matches!(pair, "embed" | "embed=1" | "embed=true"))
+}
+
+/// Whether the page was opened with `?embed=1`
+pub fn embedded() -> bool {
+    #[cfg(feature = "web")]
+    {
+        web_sys::window()
+            .and_then(|window| window.location().search().ok())
+            .is_some_and(|query| is_embed(&query))
+    }
+    #[cfg(not(feature = "web"))]
+    false
+}
+
+/// Reads the query parameter link from the current URL, if there is one
+pub fn query_from_location() -> Option<QueryLink> {
+    #[cfg(feature = "web")]
+    {
+        parse_query(&web_sys::window()?.location().search().ok()?)
+    }
+    #[cfg(not(feature = "web"))]
+    None
+}
+
+/// Reads the transformer ID and input from the current URL, preferring query
+/// parameters over the hash
+pub fn from_location() -> Option<(String, Option<String>)> {
+    if let Some(link) = query_from_location() {
+        return Some((link.id.unwrap_or_default(), link.input));
+    }
+    #[cfg(feature = "web")]
+    {
+        let hash = web_sys::window()?.location().hash().ok()?;
+        let (id, input) = decode(&hash);
+        (!id.is_empty()).then(|| (id.to_string(), input))
+    }
+    #[cfg(not(feature = "web"))]
+    None
+}
+
+/// Points the URL at a transformer and input without adding a history entry.
+/// Query parameters are dropped, as the hash now carries the same state.
+pub fn update_location(id: &str, input: &str) {
+    #[cfg(feature = "web")]
+    {
+        let js_code = format!(
+            "history.replaceState(null, '', location.pathname + '#' + {});",
+            serde_json::to_string(&encode(id, input)).unwrap()
+        );
+        let _ = js_sys::eval(&js_code);
+    }
+    #[cfg(not(feature = "web"))]
+    let _ = (id, input);
+}
@@ -0,0 +1,38 @@
+// Feeds the generator transformers from `crypto.getRandomValues`, which is
+// the only good source of randomness a page has.
+
+use buup::utils::random::EntropySource;
+#[cfg(not(feature = "web"))]
+use buup::utils::random::SystemEntropy;
+use buup::TransformError;
+
+/// Entropy from the browser's Web Crypto API
+pub struct BrowserEntropy;
+
+impl EntropySource for BrowserEntropy {
+    #[cfg(feature = "web")]
+    fn fill(&self, buf: &mut [u8]) -> Result<(), TransformError> {
+        let unavailable =
+            || TransformError::EntropyError("crypto.getRandomValues is unavailable".to_string());
+        let crypto = web_sys::window()
+            .and_then(|window| window.crypto().ok())
+            .ok_or_else(unavailable)?;
+        // getRandomValues refuses more than 65536 bytes per call
+        for chunk in buf.chunks_mut(65536) {
+            crypto
+                .get_random_values_with_u8_array(chunk)
+                .map_err(|_| unavailable())?;
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "web"))]
+    fn fill(&self, buf: &mut [u8]) -> Result<(), TransformError> {
+        SystemEntropy.fill(buf)
+    }
+}
+
+/// Makes `BrowserEntropy` the source for every transformer
+pub fn install() {
+    buup::utils::random::set_entropy_source(&BrowserEntropy);
+}
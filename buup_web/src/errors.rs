@@ -0,0 +1,151 @@
+// Presentation of failed transforms: the error message plus, for parse errors
+// that report a position, the line/column it maps to in the input.
+
+/// Line/column location of an error within the input text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorLocation {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number (in characters)
+    pub column: usize,
+    /// 0-based character offset into the whole input
+    pub offset: usize,
+}
+
+/// A transform error ready to be rendered in the error banner
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformFailure {
+    pub message: String,
+    pub location: Option<ErrorLocation>,
+}
+
+impl TransformFailure {
+    pub fn new(err: &buup::TransformError, input: &str) -> Self {
+        TransformFailure {
+            message: err.to_string(),
+            location: err.position().and_then(|position| locate(input, position)),
+        }
+    }
+}
+
+// Maps a 1-based character position to a line/column, clamping to the end of input
+fn locate(input: &str, position: usize) -> Option<ErrorLocation> {
+    let total = input.chars().count();
+    if total == 0 {
+        return None;
+    }
+    let offset = position.saturating_sub(1).min(total - 1);
+
+    let mut line = 1;
+    let mut column = 1;
+    for c in input.chars().take(offset) {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    Some(ErrorLocation {
+        line,
+        column,
+        offset,
+    })
+}
+
+/// The input split around an error location, for the highlight drawn behind
+/// the input textarea: the text before the error line, the error line up to
+/// the offending character, that character, the rest of the line, and the
+/// text after the line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputHighlight {
+    pub before: String,
+    pub line_start: String,
+    pub mark: String,
+    pub line_end: String,
+    pub after: String,
+}
+
+impl InputHighlight {
+    pub fn new(input: &str, location: ErrorLocation) -> Self {
+        let byte_offset = |chars: usize| {
+            input
+                .char_indices()
+                .nth(chars)
+                .map_or(input.len(), |(index, _)| index)
+        };
+        let line_begin = byte_offset(location.offset + 1 - location.column);
+        let mark_begin = byte_offset(location.offset);
+        let mark_end = byte_offset(location.offset + 1);
+        let line_finish = input[mark_begin..]
+            .find('\n')
+            .map_or(input.len(), |index| mark_begin + index);
+
+        // A newline can't be highlighted, so mark a space at the end of the line
+        let (mark, line_end) = if mark_end <= line_finish {
+            (&input[mark_begin..mark_end], &input[mark_end..line_finish])
+        } else {
+            (" ", "")
+        };
+
+        InputHighlight {
+            before: input[..line_begin].to_string(),
+            line_start: input[line_begin..mark_begin].to_string(),
+            mark: mark.to_string(),
+            line_end: line_end.to_string(),
+            after: input[line_finish..].to_string(),
+        }
+    }
+}
+
+/// Keeps the highlight behind the input textarea scrolled along with it
+pub fn sync_highlight_scroll() {
+    #[cfg(feature = "web")]
+    {
+        let _ = js_sys::eval(
+            r#"
+            (function() {
+                const el = document.querySelector('.input-textarea');
+                const highlight = document.querySelector('.input-highlight');
+                if (!el || !highlight) return;
+                highlight.scrollTop = el.scrollTop;
+                highlight.scrollLeft = el.scrollLeft;
+            })();
+            "#,
+        );
+    }
+}
+
+/// Scrolls the input textarea so the error line is visible. With `select` the
+/// offending character is also selected, which moves focus to the textarea.
+pub fn reveal_in_input(location: ErrorLocation, select: bool) {
+    #[cfg(feature = "web")]
+    {
+        let js_code = format!(
+            r#"
+            (function() {{
+                const el = document.querySelector('.input-textarea');
+                if (!el) return;
+                const lineHeight = parseFloat(getComputedStyle(el).lineHeight) || 20;
+                el.scrollTop = Math.max(0, ({line} - 1) * lineHeight - el.clientHeight / 2);
+                const highlight = document.querySelector('.input-highlight');
+                if (highlight) highlight.scrollTop = el.scrollTop;
+                if ({select}) {{
+                    // Selection ranges count UTF-16 code units, not characters
+                    const start = Array.from(el.value).slice(0, {offset}).join('').length;
+                    const end = start + (Array.from(el.value)[{offset}] || ' ').length;
+                    el.focus();
+                    el.setSelectionRange(start, end);
+                }}
+            }})();
+            "#,
+            line = location.line,
+            offset = location.offset,
+            select = select,
+        );
+        let _ = js_sys::eval(&js_code);
+    }
+    #[cfg(not(feature = "web"))]
+    let _ = (location, select);
+}
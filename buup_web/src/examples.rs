@@ -0,0 +1,47 @@
+// "Load example" menu for the Input panel: the transformer's default test
+// input plus the curated samples from `Transform::examples`.
+
+use crate::i18n;
+use buup::Locale;
+use dioxus::prelude::*;
+
+// Drop-down of sample inputs for `transformer_id`; picking one replaces the input
+#[component]
+pub fn ExamplePicker(
+    transformer_id: &'static str,
+    locale: Locale,
+    onload: EventHandler<&'static str>,
+) -> Element {
+    let transformer = buup::transformer_from_id(transformer_id).unwrap();
+    let samples: Vec<(&'static str, &'static str)> =
+        std::iter::once((i18n::t(locale, "Default"), transformer.default_test_input()))
+            .chain(
+                transformer
+                    .examples()
+                    .iter()
+                    .map(|example| (example.name, example.input)),
+            )
+            .filter(|(_, input)| !input.is_empty())
+            .collect();
+    if samples.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        select {
+            class: "action-button example-select",
+            title: i18n::t(locale, "Load example"),
+            aria_label: i18n::t(locale, "Load example"),
+            onchange: move |evt| {
+                if let Some((_, input)) = evt.value().parse::<usize>().ok().and_then(|i| samples.get(i)) {
+                    onload.call(input);
+                }
+            },
+            // Stays selected so the same example can be loaded again
+            option { value: "", selected: true, disabled: true, {i18n::t(locale, "Load example")} }
+            for (i, (name, _)) in samples.iter().enumerate() {
+                option { key: "{transformer_id}-{i}", value: "{i}", selected: false, "{name}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Transformers starred in the menu, pinned in a "Favorites" section at the top.
+// Stored in localStorage as a JSON array of IDs in the order they were starred.
+
+use crate::storage;
+
+const FAVORITES_KEY: &str = "buup_favorites";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Favorites {
+    ids: Vec<&'static str>,
+}
+
+impl Favorites {
+    /// Loads the favorites from localStorage, dropping IDs that no longer exist
+    pub fn load() -> Self {
+        let saved: Vec<String> = storage::load(FAVORITES_KEY)
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+        let mut favorites = Favorites::default();
+        // Deprecated IDs resolve to their current transformer
+        for transformer in saved
+            .iter()
+            .filter_map(|id| buup::transformer_from_id(id).ok())
+        {
+            if !favorites.contains(transformer.id()) {
+                favorites.ids.push(transformer.id());
+            }
+        }
+        favorites
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.contains(&id)
+    }
+
+    /// Stars the transformer, or unstars it if it already is
+    pub fn toggle(&mut self, id: &'static str) {
+        if self.contains(id) {
+            self.ids.retain(|favorite| *favorite != id);
+        } else {
+            self.ids.push(id);
+        }
+        storage::save(FAVORITES_KEY, &serde_json::to_string(&self.ids).unwrap());
+    }
+
+    /// Starred transformers in the order they were added
+    pub fn transformers(&self) -> Vec<&'static dyn buup::Transform> {
+        self.ids
+            .iter()
+            .filter_map(|id| buup::transformer_from_id(id).ok())
+            .collect()
+    }
+}
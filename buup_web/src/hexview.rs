@@ -0,0 +1,29 @@
+// Hex view for output that isn't text: when a decoder produces bytes that
+// aren't valid UTF-8, the output panel shows them as an xxd-style dump
+// instead of an error.
+
+use buup::{Base64Decode, Transform, TransformerCategory};
+
+/// The raw output bytes when `transformer`'s output isn't valid UTF-8.
+/// Only worth calling after the text transform failed.
+pub fn binary_output(transformer: &dyn Transform, input: &str) -> Option<Vec<u8>> {
+    let bytes = match transformer.category() {
+        // Compressed data is Base64 text in the text interface
+        TransformerCategory::Compression => {
+            let compressed = Base64Decode.transform_bytes(input.as_bytes()).ok()?;
+            transformer.transform_bytes(&compressed).ok()?
+        }
+        _ => transformer.transform_bytes(input.as_bytes()).ok()?,
+    };
+    std::str::from_utf8(&bytes).is_err().then_some(bytes)
+}
+
+/// Describes binary output for the banner above the hex view
+pub fn describe(bytes: &[u8]) -> String {
+    let unit = if bytes.len() == 1 { "byte" } else { "bytes" };
+    format!(
+        "Binary output ({} {}, not valid UTF-8 text) shown as a hex dump",
+        bytes.len(),
+        unit
+    )
+}
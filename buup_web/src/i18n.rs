@@ -0,0 +1,439 @@
+// Translated UI strings. Transformer names and descriptions come from the core
+// locale tables (`buup::localized_name`); this file only covers the web UI's
+// own labels, keyed by their English text. Anything missing stays English.
+
+use crate::storage;
+use buup::{Locale, TransformerCategory};
+
+/// The saved language, else the browser's, else English
+pub fn initial_locale() -> Locale {
+    storage::load("buup_locale")
+        .and_then(|code| Locale::from_tag(&code))
+        .or_else(|| browser_language().and_then(|tag| Locale::from_tag(&tag)))
+        .unwrap_or_default()
+}
+
+pub fn save(locale: Locale) {
+    storage::save("buup_locale", locale.code());
+}
+
+/// Sets the page's `lang` so screen readers and spellcheckers follow the UI
+pub fn set_document_language(locale: Locale) {
+    #[cfg(feature = "web")]
+    let _ = js_sys::eval(&format!(
+        "document.documentElement.lang = '{}';",
+        locale.code()
+    ));
+    #[cfg(not(feature = "web"))]
+    let _ = locale;
+}
+
+fn browser_language() -> Option<String> {
+    #[cfg(feature = "web")]
+    {
+        js_sys::eval("navigator.language").ok()?.as_string()
+    }
+    #[cfg(not(feature = "web"))]
+    None
+}
+
+/// `text` in `locale`, falling back to the English `text` itself
+pub fn t(locale: Locale, text: &'static str) -> &'static str {
+    let table = match locale {
+        Locale::English => return text,
+        Locale::Spanish => SPANISH,
+        Locale::French => FRENCH,
+        Locale::German => GERMAN,
+    };
+    table
+        .iter()
+        .find(|(english, _)| *english == text)
+        .map_or(text, |(_, translated)| translated)
+}
+
+/// Menu label for a transformer category
+pub fn category(locale: Locale, category: TransformerCategory) -> &'static str {
+    let english = match category {
+        TransformerCategory::Encoder => "encoders",
+        TransformerCategory::Decoder => "decoders",
+        TransformerCategory::Crypto => "crypto",
+        TransformerCategory::Formatter => "formatters",
+        TransformerCategory::Compression => "compression",
+        TransformerCategory::Color => "colors",
+        TransformerCategory::Generator => "generators",
+        TransformerCategory::Other => "others",
+    };
+    t(locale, english)
+}
+
+const SPANISH: &[(&str, &str)] = &[
+    ("Input", "Entrada"),
+    ("Output", "Salida"),
+    ("Paste", "Pegar"),
+    (
+        "Paste from clipboard and transform",
+        "Pegar desde el portapapeles y transformar",
+    ),
+    ("Clear input", "Borrar la entrada"),
+    ("Copy to clipboard", "Copiar al portapapeles"),
+    ("Copied!", "¡Copiado!"),
+    ("Search transformations...", "Buscar transformaciones..."),
+    ("All", "Todas"),
+    ("encoders", "codificadores"),
+    ("decoders", "decodificadores"),
+    ("crypto", "criptografía"),
+    ("formatters", "formateadores"),
+    ("compression", "compresión"),
+    ("colors", "colores"),
+    ("generators", "generadores"),
+    ("others", "otros"),
+    ("Favorites", "Favoritos"),
+    ("Recently used", "Usados recientemente"),
+    ("Most used", "Más usados"),
+    (
+        "No transformations found",
+        "No se encontraron transformaciones",
+    ),
+    (
+        "Remember recently and most used tools (stored only in this browser)",
+        "Recordar las herramientas recientes y más usadas (solo en este navegador)",
+    ),
+    ("Add to favorites", "Añadir a favoritos"),
+    ("Remove from favorites", "Quitar de favoritos"),
+    ("Add a step", "Añadir un paso"),
+    (
+        "Each step transforms the output of the one before it",
+        "Cada paso transforma la salida del anterior",
+    ),
+    ("Add to comparison", "Añadir a la comparación"),
+    (
+        "Every selected transformer runs on the same input",
+        "Todos los transformadores elegidos usan la misma entrada",
+    ),
+    (
+        "Back to a single transformer",
+        "Volver a un solo transformador",
+    ),
+    (
+        "Chain several transformers",
+        "Encadenar varios transformadores",
+    ),
+    (
+        "Compare several transformers",
+        "Comparar varios transformadores",
+    ),
+    (
+        "Show panels side by side",
+        "Mostrar los paneles lado a lado",
+    ),
+    (
+        "Stack panels vertically",
+        "Apilar los paneles verticalmente",
+    ),
+    ("Appearance", "Apariencia"),
+    ("Language", "Idioma"),
+    ("Swap transformation", "Invertir la transformación"),
+    (
+        "Swap the chain with its inverse",
+        "Cambiar la cadena por su inversa",
+    ),
+    (
+        "Drag to resize the panels, double-click to reset",
+        "Arrastra para cambiar el tamaño de los paneles, doble clic para restablecer",
+    ),
+    ("Transforming…", "Transformando…"),
+    (
+        "Format JSON output automatically",
+        "Formatear la salida JSON automáticamente",
+    ),
+    (
+        "Show output as a hex dump",
+        "Mostrar la salida como volcado hexadecimal",
+    ),
+    (
+        "Toggle line wrap",
+        "Activar o desactivar el ajuste de línea",
+    ),
+    ("Decrease font size", "Reducir el tamaño de letra"),
+    ("Increase font size", "Aumentar el tamaño de letra"),
+    ("Full screen", "Pantalla completa"),
+    ("Exit full screen (Esc)", "Salir de pantalla completa (Esc)"),
+    ("Show in input", "Mostrar en la entrada"),
+    ("Line", "Línea"),
+    ("column", "columna"),
+    ("Show as QR", "Mostrar como QR"),
+    ("Close", "Cerrar"),
+    ("There is no output to show", "No hay salida que mostrar"),
+    ("Settings", "Ajustes"),
+    ("Export", "Exportar"),
+    ("Import", "Importar"),
+    (
+        "Download favorites, history and preferences as a file",
+        "Descargar favoritos, historial y preferencias como archivo",
+    ),
+    (
+        "Load settings exported from another browser",
+        "Cargar ajustes exportados desde otro navegador",
+    ),
+    ("Error", "Error"),
+    ("Output updated", "Salida actualizada"),
+    ("characters", "caracteres"),
+    ("Choose a transformation", "Elegir una transformación"),
+    ("Transformations", "Transformaciones"),
+    ("Load example", "Cargar ejemplo"),
+    ("Default", "Predeterminado"),
+    (
+        "Share a link to this transformation",
+        "Compartir un enlace a esta transformación",
+    ),
+    ("Link copied!", "¡Enlace copiado!"),
+    ("Sharing failed", "No se pudo compartir"),
+    ("continue with", "continuar con"),
+    ("Open in buup ↗", "Abrir en buup ↗"),
+];
+
+const FRENCH: &[(&str, &str)] = &[
+    ("Input", "Entrée"),
+    ("Output", "Sortie"),
+    ("Paste", "Coller"),
+    (
+        "Paste from clipboard and transform",
+        "Coller depuis le presse-papiers et transformer",
+    ),
+    ("Clear input", "Effacer l'entrée"),
+    ("Copy to clipboard", "Copier dans le presse-papiers"),
+    ("Copied!", "Copié !"),
+    (
+        "Search transformations...",
+        "Rechercher des transformations...",
+    ),
+    ("All", "Toutes"),
+    ("encoders", "encodeurs"),
+    ("decoders", "décodeurs"),
+    ("crypto", "cryptographie"),
+    ("formatters", "formateurs"),
+    ("compression", "compression"),
+    ("colors", "couleurs"),
+    ("generators", "générateurs"),
+    ("others", "autres"),
+    ("Favorites", "Favoris"),
+    ("Recently used", "Utilisés récemment"),
+    ("Most used", "Les plus utilisés"),
+    ("No transformations found", "Aucune transformation trouvée"),
+    (
+        "Remember recently and most used tools (stored only in this browser)",
+        "Mémoriser les outils récents et les plus utilisés (uniquement dans ce navigateur)",
+    ),
+    ("Add to favorites", "Ajouter aux favoris"),
+    ("Remove from favorites", "Retirer des favoris"),
+    ("Add a step", "Ajouter une étape"),
+    (
+        "Each step transforms the output of the one before it",
+        "Chaque étape transforme la sortie de la précédente",
+    ),
+    ("Add to comparison", "Ajouter à la comparaison"),
+    (
+        "Every selected transformer runs on the same input",
+        "Tous les transformateurs choisis traitent la même entrée",
+    ),
+    (
+        "Back to a single transformer",
+        "Revenir à un seul transformateur",
+    ),
+    (
+        "Chain several transformers",
+        "Enchaîner plusieurs transformateurs",
+    ),
+    (
+        "Compare several transformers",
+        "Comparer plusieurs transformateurs",
+    ),
+    (
+        "Show panels side by side",
+        "Afficher les panneaux côte à côte",
+    ),
+    (
+        "Stack panels vertically",
+        "Empiler les panneaux verticalement",
+    ),
+    ("Appearance", "Apparence"),
+    ("Language", "Langue"),
+    ("Swap transformation", "Inverser la transformation"),
+    (
+        "Swap the chain with its inverse",
+        "Remplacer la chaîne par son inverse",
+    ),
+    (
+        "Drag to resize the panels, double-click to reset",
+        "Glisser pour redimensionner les panneaux, double-cliquer pour réinitialiser",
+    ),
+    ("Transforming…", "Transformation…"),
+    (
+        "Format JSON output automatically",
+        "Formater automatiquement la sortie JSON",
+    ),
+    (
+        "Show output as a hex dump",
+        "Afficher la sortie en hexadécimal",
+    ),
+    (
+        "Toggle line wrap",
+        "Activer ou désactiver le retour à la ligne",
+    ),
+    ("Decrease font size", "Réduire la taille du texte"),
+    ("Increase font size", "Augmenter la taille du texte"),
+    ("Full screen", "Plein écran"),
+    ("Exit full screen (Esc)", "Quitter le plein écran (Échap)"),
+    ("Show in input", "Afficher dans l'entrée"),
+    ("Line", "Ligne"),
+    ("column", "colonne"),
+    ("Show as QR", "Afficher en QR"),
+    ("Close", "Fermer"),
+    (
+        "There is no output to show",
+        "Il n'y a aucune sortie à afficher",
+    ),
+    ("Settings", "Réglages"),
+    ("Export", "Exporter"),
+    ("Import", "Importer"),
+    (
+        "Download favorites, history and preferences as a file",
+        "Télécharger les favoris, l'historique et les préférences dans un fichier",
+    ),
+    (
+        "Load settings exported from another browser",
+        "Charger des réglages exportés depuis un autre navigateur",
+    ),
+    ("Error", "Erreur"),
+    ("Output updated", "Sortie mise à jour"),
+    ("characters", "caractères"),
+    ("Choose a transformation", "Choisir une transformation"),
+    ("Transformations", "Transformations"),
+    ("Load example", "Charger un exemple"),
+    ("Default", "Par défaut"),
+    (
+        "Share a link to this transformation",
+        "Partager un lien vers cette transformation",
+    ),
+    ("Link copied!", "Lien copié !"),
+    ("Sharing failed", "Échec du partage"),
+    ("continue with", "continuer avec"),
+    ("Open in buup ↗", "Ouvrir dans buup ↗"),
+];
+
+const GERMAN: &[(&str, &str)] = &[
+    ("Input", "Eingabe"),
+    ("Output", "Ausgabe"),
+    ("Paste", "Einfügen"),
+    (
+        "Paste from clipboard and transform",
+        "Aus der Zwischenablage einfügen und umwandeln",
+    ),
+    ("Clear input", "Eingabe löschen"),
+    ("Copy to clipboard", "In die Zwischenablage kopieren"),
+    ("Copied!", "Kopiert!"),
+    ("Search transformations...", "Umwandlungen suchen..."),
+    ("All", "Alle"),
+    ("encoders", "Kodierer"),
+    ("decoders", "Dekodierer"),
+    ("crypto", "Kryptografie"),
+    ("formatters", "Formatierer"),
+    ("compression", "Komprimierung"),
+    ("colors", "Farben"),
+    ("generators", "Generatoren"),
+    ("others", "Sonstige"),
+    ("Favorites", "Favoriten"),
+    ("Recently used", "Zuletzt verwendet"),
+    ("Most used", "Am häufigsten verwendet"),
+    ("No transformations found", "Keine Umwandlungen gefunden"),
+    (
+        "Remember recently and most used tools (stored only in this browser)",
+        "Zuletzt und häufig verwendete Werkzeuge merken (nur in diesem Browser gespeichert)",
+    ),
+    ("Add to favorites", "Zu Favoriten hinzufügen"),
+    ("Remove from favorites", "Aus Favoriten entfernen"),
+    ("Add a step", "Schritt hinzufügen"),
+    (
+        "Each step transforms the output of the one before it",
+        "Jeder Schritt wandelt die Ausgabe des vorherigen um",
+    ),
+    ("Add to comparison", "Zum Vergleich hinzufügen"),
+    (
+        "Every selected transformer runs on the same input",
+        "Alle ausgewählten Transformer verarbeiten dieselbe Eingabe",
+    ),
+    (
+        "Back to a single transformer",
+        "Zurück zu einem einzelnen Transformer",
+    ),
+    (
+        "Chain several transformers",
+        "Mehrere Transformer verketten",
+    ),
+    (
+        "Compare several transformers",
+        "Mehrere Transformer vergleichen",
+    ),
+    (
+        "Show panels side by side",
+        "Bereiche nebeneinander anzeigen",
+    ),
+    ("Stack panels vertically", "Bereiche untereinander anzeigen"),
+    ("Appearance", "Darstellung"),
+    ("Language", "Sprache"),
+    ("Swap transformation", "Umwandlung umkehren"),
+    (
+        "Swap the chain with its inverse",
+        "Kette durch ihre Umkehrung ersetzen",
+    ),
+    (
+        "Drag to resize the panels, double-click to reset",
+        "Ziehen, um die Bereiche anzupassen, Doppelklick zum Zurücksetzen",
+    ),
+    ("Transforming…", "Wird umgewandelt…"),
+    (
+        "Format JSON output automatically",
+        "JSON-Ausgabe automatisch formatieren",
+    ),
+    ("Show output as a hex dump", "Ausgabe als Hex-Dump anzeigen"),
+    ("Toggle line wrap", "Zeilenumbruch umschalten"),
+    ("Decrease font size", "Schrift verkleinern"),
+    ("Increase font size", "Schrift vergrößern"),
+    ("Full screen", "Vollbild"),
+    ("Exit full screen (Esc)", "Vollbild beenden (Esc)"),
+    ("Show in input", "In der Eingabe zeigen"),
+    ("Line", "Zeile"),
+    ("column", "Spalte"),
+    ("Show as QR", "Als QR-Code anzeigen"),
+    ("Close", "Schließen"),
+    (
+        "There is no output to show",
+        "Es gibt keine Ausgabe zum Anzeigen",
+    ),
+    ("Settings", "Einstellungen"),
+    ("Export", "Exportieren"),
+    ("Import", "Importieren"),
+    (
+        "Download favorites, history and preferences as a file",
+        "Favoriten, Verlauf und Einstellungen als Datei herunterladen",
+    ),
+    (
+        "Load settings exported from another browser",
+        "Aus einem anderen Browser exportierte Einstellungen laden",
+    ),
+    ("Error", "Fehler"),
+    ("Output updated", "Ausgabe aktualisiert"),
+    ("characters", "Zeichen"),
+    ("Choose a transformation", "Umwandlung auswählen"),
+    ("Transformations", "Umwandlungen"),
+    ("Load example", "Beispiel laden"),
+    ("Default", "Standard"),
+    (
+        "Share a link to this transformation",
+        "Link zu dieser Umwandlung teilen",
+    ),
+    ("Link copied!", "Link kopiert!"),
+    ("Sharing failed", "Teilen fehlgeschlagen"),
+    ("continue with", "weiter mit"),
+    ("Open in buup ↗", "In buup öffnen ↗"),
+];
@@ -0,0 +1,65 @@
+// Entry points for text arriving from outside the page: the PWA share target
+// (see `share_target` in site.webmanifest) and the asynchronous Clipboard API.
+
+/// Returns text shared into the app through the Web Share Target, if any.
+///
+/// Android share sheets send `title`, `text` and `url` query parameters; apps
+/// disagree on which one carries the payload, so the first non-empty one in
+/// the order text, url, title wins. The query string is removed afterwards so
+/// a reload doesn't import the same text again.
+pub fn take_shared_text() -> Option<String> {
+    #[cfg(feature = "web")]
+    {
+        let js_code = r#"
+            (function() {
+                const params = new URLSearchParams(window.location.search);
+                const shared = ['text', 'url', 'title']
+                    .map(name => params.get(name))
+                    .find(value => value && value.trim() !== '');
+                if (shared === undefined) return null;
+                ['text', 'url', 'title'].forEach(name => params.delete(name));
+                const query = params.toString();
+                history.replaceState(null, '',
+                    window.location.pathname + (query ? '?' + query : '') + window.location.hash);
+                return shared;
+            })()
+        "#;
+        js_sys::eval(js_code)
+            .ok()
+            .and_then(|value| value.as_string())
+    }
+    #[cfg(not(feature = "web"))]
+    None
+}
+
+/// Whether the browser lets the page read the clipboard on demand
+pub fn clipboard_read_supported() -> bool {
+    #[cfg(feature = "web")]
+    {
+        js_sys::eval("!!(navigator.clipboard && navigator.clipboard.readText)")
+            .map(|value| value.as_bool().unwrap_or(false))
+            .unwrap_or(false)
+    }
+    #[cfg(not(feature = "web"))]
+    false
+}
+
+/// Reads text from the clipboard, prompting for permission where required.
+/// Returns `None` if access is denied or the clipboard holds no text.
+pub async fn read_clipboard() -> Option<String> {
+    let mut eval = dioxus::document::eval(
+        r#"
+        try {
+            dioxus.send(await navigator.clipboard.readText());
+        } catch (e) {
+            console.error('Failed to read clipboard:', e);
+            dioxus.send(null);
+        }
+        "#,
+    );
+    eval.recv::<Option<String>>()
+        .await
+        .ok()
+        .flatten()
+        .filter(|text| !text.is_empty())
+}
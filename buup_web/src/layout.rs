@@ -0,0 +1,77 @@
+// Panel layout: input and output side by side or stacked, split at a
+// draggable divider, with either panel able to fill the window. Orientation
+// and split are kept in localStorage; full screen lasts until it's closed.
+
+use crate::storage;
+use dioxus::html::geometry::{ClientPoint, PixelsRect};
+
+/// Smallest share of the space either panel can be dragged down to, in percent
+const MIN_SPLIT: f64 = 20.0;
+const DEFAULT_SPLIT: f64 = 50.0;
+
+/// One of the two main panels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Input,
+    Output,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Layout {
+    /// Input above output rather than beside it
+    pub vertical: bool,
+    /// Share of the space given to the input panel, in percent
+    pub split: f64,
+}
+
+impl Layout {
+    pub fn load() -> Self {
+        let split = storage::load("buup_layout_split")
+            .and_then(|split| split.parse().ok())
+            .filter(|split: &f64| (MIN_SPLIT..=100.0 - MIN_SPLIT).contains(split))
+            .unwrap_or(DEFAULT_SPLIT);
+        Layout {
+            vertical: storage::load_bool("buup_layout_vertical", false),
+            split,
+        }
+    }
+
+    pub fn save(&self) {
+        storage::save("buup_layout_vertical", &self.vertical.to_string());
+        storage::save("buup_layout_split", &format!("{:.1}", self.split));
+    }
+
+    /// Grid tracks for the panels container: input, divider, output
+    pub fn grid_style(&self) -> String {
+        let tracks = format!(
+            "minmax(0, {:.1}fr) auto minmax(0, {:.1}fr)",
+            self.split,
+            100.0 - self.split
+        );
+        if self.vertical {
+            format!(
+                "grid-template-columns: 1fr; grid-template-rows: {};",
+                tracks
+            )
+        } else {
+            format!("grid-template-columns: {};", tracks)
+        }
+    }
+
+    /// Moves the divider to `point` within the panels container's `rect`
+    pub fn drag_to(&mut self, rect: PixelsRect, point: ClientPoint) {
+        let (start, length, at) = if self.vertical {
+            (rect.origin.y, rect.size.height, point.y)
+        } else {
+            (rect.origin.x, rect.size.width, point.x)
+        };
+        if length > 0.0 {
+            self.split = ((at - start) / length * 100.0).clamp(MIN_SPLIT, 100.0 - MIN_SPLIT);
+        }
+    }
+
+    /// Puts the divider back in the middle
+    pub fn reset_split(&mut self) {
+        self.split = DEFAULT_SPLIT;
+    }
+}
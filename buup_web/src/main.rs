@@ -5,7 +5,32 @@ use std::rc::Rc;
 #[cfg(feature = "web")]
 use wasm_bindgen::JsCast;
 
+mod a11y;
+mod appearance;
+mod chain;
+mod clock;
+mod compare;
+mod datauri;
+mod debounce;
+mod deeplink;
+mod entropy;
+mod errors;
+mod examples;
+mod favorites;
+mod hexview;
+mod i18n;
+mod intents;
+mod layout;
+mod options;
+mod qrview;
+mod runner;
+mod settings;
+mod share;
+mod status;
+mod storage;
 mod styles; // Add module declaration
+mod suggest;
+mod usage;
 
 const FAVICON_ICO: Asset = asset!("assets/favicon.ico");
 const FAVICON_32: Asset = asset!("assets/favicon-32x32.png");
@@ -15,9 +40,50 @@ const BUUP_ICON_SVG: Asset = asset!("assets/buup-icon.svg");
 const SITE_MANIFEST: Asset = asset!("assets/site.webmanifest");
 
 fn main() {
+    clock::install();
+    entropy::install();
     dioxus::launch(App);
 }
 
+// Pretty-prints JSON output for transformers that emit it. Formatters are left
+// alone since controlling the layout is their whole purpose.
+fn format_json_output(transformer: &dyn buup::Transform, output: String) -> String {
+    let trimmed = output.trim_start();
+    if transformer.category() == buup::TransformerCategory::Formatter
+        || !(trimmed.starts_with('{') || trimmed.starts_with('['))
+    {
+        return output;
+    }
+    buup::Transform::transform(&buup::JsonFormatter, &output).unwrap_or(output)
+}
+
+// The output panel's result for a run: the last step's output in chain mode,
+// with the error located in `input` otherwise
+fn run_output(
+    input: &str,
+    chain_mode: bool,
+    last: Option<&dyn buup::Transform>,
+    auto_format: bool,
+    steps: &[Result<String, errors::TransformFailure>],
+    (result, stats): (
+        Result<String, buup::TransformError>,
+        Option<buup::TransformStats>,
+    ),
+) -> (
+    Result<String, errors::TransformFailure>,
+    Option<buup::TransformStats>,
+) {
+    let result = match steps.last() {
+        Some(step) if chain_mode => step.clone(),
+        None if chain_mode => Ok(input.to_string()),
+        _ => result.map_err(|err| errors::TransformFailure::new(&err, input)),
+    };
+    match (result, last) {
+        (Ok(output), Some(last)) if auto_format => (Ok(format_json_output(last, output)), stats),
+        (result, _) => (result, stats),
+    }
+}
+
 // Function to provide custom meta descriptions for popular tools
 fn get_meta_description(
     transformer_id: &str,
@@ -44,7 +110,7 @@ fn get_meta_description(
         "morseencode" => "Encode text to Morse code online. Free Morse code translator works entirely in your browser for privacy. Convert letters and numbers easily.".to_string(),
         "morsedecode" => "Decode Morse code to text online. Free Morse code translator operates offline in your browser. Convert dots and dashes back to readable text.".to_string(),
         "jwtdecode" => "Decode JSON Web Tokens (JWT) online without verification. Free JWT decoder tool inspects header and payload client-side. Works securely offline.".to_string(),
-        "uuid_generate" => "Generate Version 4 UUIDs (Universally Unique Identifiers) online. Free UUID generator creates random v4 UUIDs directly in your browser.".to_string(), // Note: PRNG quality mentioned in README
+        "uuid_generate" => "Generate Version 4 UUIDs (Universally Unique Identifiers) online. Free UUID generator creates cryptographically random v4 UUIDs directly in your browser.".to_string(),
         "textreverse" => "Reverse text strings online. Free tool reverses the order of characters in your text. Works instantly in your browser, offline.".to_string(),
         "linesorter" => "Sort lines of text alphabetically online. Free tool sorts lines in ascending order. Works entirely in your browser for privacy.".to_string(),
         "slugify" => "Convert text to a URL-friendly slug online. Free slug generator creates lowercase, dashed strings safe for URLs. Works offline in your browser.".to_string(),
@@ -108,18 +174,29 @@ fn App() -> Element {
         use js_sys::{global, Function, Object};
         use wasm_bindgen::JsCast;
 
-        // First check if there's a hash in the URL (e.g., #base64encode)
+        // First check if there's a hash in the URL (e.g., #base64encode or #base64encode:<input>)
         let _window = web_sys::window().expect("Window should exist");
         let location = _window.location();
-        let hash = location.hash().unwrap_or_default();
+        // A `?t=` query parameter link takes precedence over the hash
+        let hash = match deeplink::query_from_location().and_then(|link| link.id) {
+            Some(id) => id,
+            None => location.hash().unwrap_or_default(),
+        };
 
         // If hash exists and isn't empty, try to use it as transformer ID
         if !hash.is_empty() {
-            // Remove the '#' prefix
-            let hash = hash.trim_start_matches('#');
+            // Keep only the ID; any input in the link is restored below
+            let (hash, _) = deeplink::decode(&hash);
 
             // Check if this ID exists in our transformers
             if !hash.is_empty() && buup::transformer_from_id(hash).is_ok() {
+                // Old links keep working; the hash is rewritten to the current ID
+                if let Some(current) = buup::deprecated_id(hash) {
+                    let _ = js_sys::eval(&format!(
+                        "console.warn(\"Transformer ID '{}' is deprecated, use '{}' instead\");",
+                        hash, current
+                    ));
+                }
                 hash.to_string()
             } else {
                 // If hash is invalid, fallback to localStorage
@@ -196,11 +273,80 @@ fn App() -> Element {
                 .unwrap_or_else(|_| buup::transformer_from_id("base64encode").unwrap()),
         )
     });
-    let mut input = use_signal(|| "".to_string());
-    let mut show_transformer_menu = use_signal(|| false);
+    // Chain mode runs the input through a stack of transformers instead of one.
+    // A link to a chain (`#base64decode,jsonformatter:...`) opens in chain mode.
+    let linked_chain = use_hook(|| {
+        deeplink::from_location()
+            .filter(|(id, _)| id.contains(','))
+            .and_then(|(id, _)| id.parse::<buup::Pipeline>().ok())
+    });
+    // `?embed=1`: only the panels for the linked transformer, for iframes
+    let embedded = use_hook(deeplink::embedded);
+    let mut chain_mode = use_signal(|| {
+        linked_chain.is_some() || (!embedded && storage::load_bool("buup_chain_mode", false))
+    });
+    let mut chain = use_signal(|| {
+        linked_chain
+            .clone()
+            .or_else(chain::load)
+            .unwrap_or_else(|| {
+                let mut chain = buup::Pipeline::new();
+                chain.push(*current_transformer());
+                chain
+            })
+    });
+    // Compare mode shows several transformers' outputs for the same input
+    let mut compare_mode = use_signal(|| {
+        !embedded && !*chain_mode.peek() && storage::load_bool("buup_compare_mode", false)
+    });
+    let mut compare_ids = use_signal(compare::load);
+    // Text shared into the app (the PWA share target) opens in the last selected transformer
+    let shared_text = use_hook(intents::take_shared_text);
+    let mut input = use_signal(|| {
+        shared_text
+            .clone()
+            .or_else(|| deeplink::from_location().and_then(|(_, input)| input))
+            .unwrap_or_default()
+    });
+    // Input linked with `?i=` but without `run=1` also waits for a transformer to be picked
+    let linked_without_run = use_hook(|| {
+        !embedded
+            && deeplink::query_from_location().is_some_and(|link| link.input.is_some() && !link.run)
+    });
+    let mut show_transformer_menu = use_signal(|| linked_without_run);
     let mut transformer_category = use_signal(|| "all".to_string());
     let mut search_query = use_signal(String::new);
     let mut show_copy_success = use_signal(|| false);
+    // Shown briefly under the share button when the link was copied instead of shared
+    let mut share_notice = use_signal(|| None::<&'static str>);
+    let mut usage = use_signal(usage::UsageStats::load);
+    let mut favorites = use_signal(favorites::Favorites::load);
+
+    // UI language, also used for transformer names and search
+    let mut locale = use_signal(i18n::initial_locale);
+    use_effect(move || i18n::set_document_language(locale()));
+    // Accent color, textarea font and line wrapping
+    let mut appearance = use_signal(appearance::Appearance::load);
+    let mut show_appearance = use_signal(|| false);
+    // Output panel preferences
+    let mut auto_format_json = use_signal(|| storage::load_bool("buup_output_auto_json", false));
+    let mut hex_view = use_signal(|| false);
+    let mut show_qr = use_signal(|| false);
+    // An image pasted or dropped on the page, shown as a data URI until the input changes
+    let mut pasted_image = use_signal(|| None::<datauri::PastedImage>);
+    use_future(move || datauri::listen_for_images(move |image| pasted_image.set(Some(image))));
+    use_effect(move || {
+        input();
+        pasted_image.set(None);
+    });
+    let mut layout = use_signal(layout::Layout::load);
+    let mut maximized = use_signal(|| None::<layout::Pane>);
+    let mut panels_element = use_signal(|| None::<Rc<MountedData>>);
+    // The panels container's bounds while the divider is being dragged
+    let mut drag_rect = use_signal(|| None::<dioxus::html::geometry::PixelsRect>);
+    // Option values of configurable transformers, by transformer ID
+    let mut option_values = use_signal(options::load_all);
+    let can_paste = use_hook(intents::clipboard_read_supported);
 
     // Initialize categories signal with values from the core library
     let categorized = buup::categorized_transformers();
@@ -218,11 +364,6 @@ fn App() -> Element {
             let dark_mode = is_dark_mode();
             let transformer_id = current_transformer().id();
 
-            // Update URL hash when transformer changes
-            if let Some(_window) = web_sys::window() {
-                let _ = js_sys::eval(&format!("window.location.hash = '{}';", transformer_id));
-            }
-
             let js_code = format!(
                 r#"
                 try {{
@@ -239,6 +380,52 @@ fn App() -> Element {
         }
     });
 
+    use_effect(move || storage::save("buup_chain_mode", &chain_mode().to_string()));
+    use_effect(move || chain::save(&chain()));
+    use_effect(move || storage::save("buup_compare_mode", &compare_mode().to_string()));
+    use_effect(move || compare::save(&compare_ids()));
+
+    // What the transformers run on: follows the input, lagging behind it while
+    // a large input is being typed so each keystroke doesn't transform it all
+    let mut transform_input = use_signal(|| input.peek().clone());
+    let mut transform_pending = use_signal(|| false);
+    let mut input_generation = use_signal(|| 0_usize);
+    use_effect(move || {
+        let text = input();
+        let generation = *input_generation.peek() + 1;
+        input_generation.set(generation);
+        let Some(delay) = debounce::delay(text.len()) else {
+            transform_input.set(text);
+            return;
+        };
+        // Shown until the transform has run (the run clears it); the page
+        // repaints during the wait
+        transform_pending.set(true);
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(delay).await;
+            // A newer keystroke has scheduled its own run
+            if *input_generation.peek() == generation {
+                if *transform_input.peek() == text {
+                    transform_pending.set(false);
+                } else {
+                    transform_input.set(text);
+                }
+            }
+        });
+    });
+
+    // Keep the URL hash a shareable link to the current transformer (or chain) and input
+    use_effect(move || {
+        let id = if chain_mode() && !chain().is_empty() {
+            chain().to_string()
+        } else {
+            current_transformer().id().to_string()
+        };
+        if !embedded {
+            deeplink::update_location(&id, &transform_input());
+        }
+    });
+
     // Add JavaScript click handler for closing menu when clicking outside
     #[cfg(feature = "web")]
     use_effect(move || {
@@ -288,15 +475,152 @@ fn App() -> Element {
         }
     });
 
-    // Apply transformation and get output
-    let output = if input().is_empty() {
-        "".to_string()
+    // Intermediate results of each chain step, and the output (failures kept
+    // apart) with its stats. Inputs small enough to transform on every
+    // keystroke run straight away; larger ones run in a task that gives the
+    // page turns while it works and clears the busy indicator once done.
+    // Starting a run cancels the one before it.
+    let mut chain_run = use_signal(Vec::new);
+    let mut transform_run = use_signal(|| (Ok(String::new()), None));
+    let mut run_token = use_signal(buup::CancelToken::new);
+    use_effect(move || {
+        let text = transform_input();
+        let chain_mode = chain_mode();
+        let chain = chain();
+        let transformer: &'static dyn buup::Transform = *current_transformer();
+        let auto_format = auto_format_json();
+        let token = buup::CancelToken::new();
+        run_token.peek().cancel();
+        run_token.set(token.clone());
+        let transform_options =
+            options::to_transform_options(option_values.read().get(transformer.id()))
+                .with_cancel(token.clone());
+        let last = if chain_mode {
+            chain.steps().last().copied()
+        } else {
+            Some(transformer)
+        };
+        let mut finish = {
+            let text = text.clone();
+            move |steps: Vec<_>, run: (_, _)| {
+                let output = run_output(&text, chain_mode, last, auto_format, &steps, run);
+                chain_run.set(steps);
+                transform_run.set(output);
+                transform_pending.set(false);
+            }
+        };
+
+        if text.is_empty() {
+            finish(Vec::new(), (Ok(String::new()), None));
+        } else if debounce::delay(text.len()).is_none() {
+            if chain_mode {
+                finish(chain::run(&chain, &text), (Ok(String::new()), None));
+            } else {
+                finish(
+                    Vec::new(),
+                    status::transform_with_stats(transformer, &text, &transform_options),
+                );
+            }
+        } else {
+            transform_pending.set(true);
+            spawn(async move {
+                let run = runner::Run { token };
+                let (steps, output) = if chain_mode {
+                    (run.chain(&chain, &text).await, (Ok(String::new()), None))
+                } else {
+                    let output = run.transform(transformer, &text, &transform_options).await;
+                    (Vec::new(), output)
+                };
+                // A newer run has taken over and will finish instead
+                if !run.token.is_cancelled() {
+                    finish(steps, output);
+                }
+            });
+        }
+    });
+    // Output that failed only for not being UTF-8 is shown in the hex view
+    let binary_output = use_memo(move || match &transform_run.read().0 {
+        Err(_) if !chain_mode() => {
+            hexview::binary_output(&**current_transformer(), &transform_input())
+        }
+        _ => None,
+    });
+    // Offers to continue past another layer of encoding in the output
+    let suggestions = use_memo(move || {
+        if compare_mode() || embedded || pasted_image().is_some() {
+            return Vec::new();
+        }
+        let last = if chain_mode() {
+            chain().steps().last().copied()
+        } else {
+            Some(*current_transformer())
+        };
+        let run = transform_run.read();
+        last.map(|last| {
+            suggest::suggestions(last, run.0.as_deref().ok(), binary_output().as_deref())
+        })
+        .unwrap_or_default()
+    });
+    let binary_output = binary_output();
+    let showing_hex = hex_view() || binary_output.is_some();
+    let pasted_image = pasted_image();
+    let output = if let Some(image) = &pasted_image {
+        image.uri.clone()
     } else {
-        match current_transformer().transform(&input()) {
-            Ok(result) => result,
-            Err(err) => err.to_string(),
+        match &binary_output {
+            Some(bytes) => buup::utils::hexdump(bytes),
+            None if hex_view() => buup::utils::hexdump(
+                transform_run
+                    .read()
+                    .0
+                    .as_deref()
+                    .unwrap_or_default()
+                    .as_bytes(),
+            ),
+            None => transform_run.read().0.clone().unwrap_or_default(),
         }
     };
+    let failure = match binary_output {
+        Some(_) => None,
+        None if pasted_image.is_some() => None,
+        None => transform_run.read().0.clone().err(),
+    };
+    // A data URI as input gets a preview of the image it holds
+    let image_uri_notice = datauri::describe_image_uri(&transform_input());
+    let binary_notice = binary_output.as_deref().map(hexview::describe);
+    let error_location = failure.as_ref().and_then(|failure| failure.location);
+    let (line_label, column_label) = (i18n::t(locale(), "Line"), i18n::t(locale(), "column"));
+    let input_highlight =
+        error_location.map(|location| errors::InputHighlight::new(&transform_input(), location));
+    let run_stats = transform_run.read().1.clone();
+    let output_placeholder = current_transformer()
+        .transform_with_options(
+            current_transformer().default_test_input(),
+            &buup::TransformOptions::from_iter(
+                current_transformer().default_test_options().iter().copied(),
+            ),
+        )
+        .unwrap_or_else(|err| err.to_string());
+    // The transformer that reads the input, whose examples the Input panel offers
+    let input_transformer_id = if chain_mode() {
+        chain().steps().first().map(|step| step.id())
+    } else if compare_mode() {
+        compare_ids().first().copied()
+    } else {
+        None
+    }
+    .unwrap_or_else(|| current_transformer().id());
+
+    // Keep the offending input line in view while an error is shown
+    use_effect(move || {
+        if let Err(errors::TransformFailure {
+            location: Some(location),
+            ..
+        }) = &transform_run.read().0
+        {
+            errors::reveal_in_input(*location, false);
+        }
+    });
 
     // Clone output for use in the clipboard function
     let output_for_clipboard = output.clone();
@@ -345,6 +669,55 @@ fn App() -> Element {
         }
     };
 
+    // Follow a suggestion chip, turning a single transformer into a chain
+    let pick_suggestion = move |suggestion: suggest::Suggestion| {
+        let next = buup::transformer_from_id(suggestion.id).unwrap();
+        if !chain_mode() {
+            if suggestion.replace {
+                current_transformer.set(Rc::new(next));
+                return;
+            }
+            let mut steps = buup::Pipeline::new();
+            steps.push(*current_transformer());
+            chain.set(steps);
+            chain_mode.set(true);
+            compare_mode.set(false);
+        } else if suggestion.replace {
+            let last = chain().len().saturating_sub(1);
+            chain.write().remove(last);
+        }
+        chain.write().push(next);
+    };
+
+    // Share a link to the current transformer (or chain) and input
+    let share = move |_| async move {
+        let id = if chain_mode() && !chain().is_empty() {
+            chain().to_string()
+        } else {
+            current_transformer().id().to_string()
+        };
+        let notice = match share::share_link(&id, &transform_input()).await {
+            share::ShareOutcome::Shared | share::ShareOutcome::Cancelled => return,
+            share::ShareOutcome::Copied => "Link copied!",
+            share::ShareOutcome::Failed => "Sharing failed",
+        };
+        share_notice.set(Some(notice));
+        gloo_timers::future::TimeoutFuture::new(2000).await;
+        share_notice.set(None);
+    };
+
+    // The full app's link to what the embedded widget shows
+    let embed_link = if embedded {
+        let id = if chain_mode() && !chain().is_empty() {
+            chain().to_string()
+        } else {
+            current_transformer().id().to_string()
+        };
+        deeplink::encode(&id, &transform_input())
+    } else {
+        String::new()
+    };
+
     // Theme colors
     let theme = if is_dark_mode() {
         Theme {
@@ -353,8 +726,10 @@ fn App() -> Element {
             text: "#FFFFFF",
             text_secondary: "rgba(255, 255, 255, 0.7)",
             border: "#38383A",
-            accent: "#0A84FF",
+            accent: appearance().accent.color(true),
             hover: "#2C2C2E",
+            mono_font: appearance().mono_font.family,
+            font_size: appearance().font_size,
         }
     } else {
         Theme {
@@ -363,19 +738,40 @@ fn App() -> Element {
             text: "#000000",
             text_secondary: "rgba(0, 0, 0, 0.7)",
             border: "#D2D2D7",
-            accent: "#0066CC",
+            accent: appearance().accent.color(false),
             hover: "#E8E8ED",
+            mono_font: appearance().mono_font.family,
+            font_size: appearance().font_size,
         }
     };
 
     // Function to swap between encoder/decoder pairs
     let swap_transform = move |_| {
+        if chain_mode() {
+            // The inverse chain undoes the steps in reverse order
+            if let Some(inverse) = chain().inverse() {
+                let current_output = match input().is_empty() {
+                    true => "".to_string(),
+                    false => chain()
+                        .transform(&input())
+                        .unwrap_or_else(|err| err.to_string()),
+                };
+                input.set(current_output);
+                chain.set(inverse);
+            }
+            return;
+        }
         if let Some(inverse) = buup::inverse_transformer(&**current_transformer()) {
             // First, get the current output value
             let current_output = match input().is_empty() {
                 true => "".to_string(),
                 false => current_transformer()
-                    .transform(&input())
+                    .transform_with_options(
+                        &input(),
+                        &options::to_transform_options(
+                            option_values.read().get(current_transformer().id()),
+                        ),
+                    )
                     .unwrap_or_else(|err| err.to_string()),
             };
 
@@ -384,47 +780,63 @@ fn App() -> Element {
 
             // Switch to the inverse transformer
             current_transformer.set(Rc::new(inverse));
+            usage.write().record(inverse.id());
+        }
+    };
 
-            // Update URL hash when swapping transformers
-            if let Some(_window) = web_sys::window() {
-                let new_id = inverse.id();
-                let _ = js_sys::eval(&format!("window.location.hash = '{}';", new_id));
-            }
+    // Select a transformer from the menu, recording the use when tracking is enabled
+    let select_transformer = move |id: &'static str| {
+        let transformer = buup::transformer_from_id(id).unwrap();
+        if chain_mode() {
+            chain.write().push(transformer);
+        } else if compare_mode() {
+            compare::add(&mut compare_ids.write(), id);
+        } else {
+            current_transformer.set(Rc::new(transformer));
+        }
+        show_transformer_menu.set(false);
+        search_query.set(String::new());
+        usage.write().record(id);
+
+        #[cfg(feature = "web")]
+        {
+            // Use JS to ensure focus returns to the transformer selector
+            let js_code = r#"
+                setTimeout(() => {
+                    const button = document.querySelector('.current-transformer');
+                    if (button) {
+                        button.focus();
+                    }
+                }, 10);
+            "#;
+            let _ = js_sys::eval(js_code);
         }
     };
 
+    let toggle_favorite = move |id: &'static str| favorites.write().toggle(id);
+
+    // Favorites and usage sections are only shown on the unfiltered menu
+    let unfiltered_menu = search_query().is_empty() && transformer_category() == "all";
+    let show_usage_sections = usage().enabled && unfiltered_menu;
+    let favorite_transformers = favorites().transformers();
+    let recently_used = usage().recently_used();
+    let most_used = usage().most_used();
+
     // Filter transformers based on selected category and search query
     let filtered_transformers = {
-        let category_filtered = if transformer_category() == "all" {
-            transformers.to_vec()
-        } else {
-            // Parse the category string to TransformerCategory enum
-            if let Ok(category) = transformer_category().parse::<buup::TransformerCategory>() {
-                // Get transformers for the selected category
-                categorized.get(&category).unwrap().clone()
-            } else {
-                // Fallback to all transformers if category parsing fails
-                transformers.to_vec()
-            }
-        };
-
-        // If search query is empty, show all transformers for the selected category
+        // An unparseable category falls back to all transformers
+        let category = transformer_category()
+            .parse::<buup::TransformerCategory>()
+            .ok();
         if search_query().is_empty() {
-            category_filtered
+            // Keep the usual menu order when not searching
+            match category {
+                Some(category) => categorized.get(&category).unwrap().clone(),
+                None => transformers.to_vec(),
+            }
         } else {
-            // Filter transformers based on search query (match name or description)
-            let search_lower = search_query().to_lowercase();
-            category_filtered
-                .into_iter()
-                .filter(|transformer| {
-                    transformer.name().to_lowercase().contains(&search_lower)
-                        || transformer
-                            .description()
-                            .to_lowercase()
-                            .contains(&search_lower)
-                        || transformer.id().to_lowercase().contains(&search_lower)
-                })
-                .collect::<Vec<_>>()
+            // Matches are ranked by relevance by the core search API
+            buup::search_transformers_localized(&search_query(), category, locale())
         }
     };
 
@@ -565,325 +977,774 @@ fn App() -> Element {
         // Use the imported function for CSS generation
         style { { styles::generate_css(&theme) } }
 
-        div { class: if is_dark_mode() { "container dark" } else { "container" },
+        div {
+            class: match (is_dark_mode(), embedded) {
+                (true, true) => "container dark embed",
+                (true, false) => "container dark",
+                (false, true) => "container embed",
+                (false, false) => "container",
+            },
             // Header section
-            div { class: "header",
-                div { class: "app-title",
-                    img {
-                        src: BUUP_ICON_SVG,
-                        alt: "Buup logo",
-                        style: "width: 30px; height: 30px; margin-right: 10px;"
+            if !embedded {
+                div { class: "header",
+                    div { class: "app-title",
+                        img {
+                            src: BUUP_ICON_SVG,
+                            alt: "Buup logo",
+                            style: "width: 30px; height: 30px; margin-right: 10px;"
+                        }
+                        "Buup"
                     }
-                    "Buup"
-                }
-                div { class: "controls",
-                    button {
-                        class: "icon-button",
-                        onclick: move |_| is_dark_mode.set(!is_dark_mode()),
-                        if is_dark_mode() { "☀️" } else { "🌙" }
+                    div { class: "controls",
+                        button {
+                            class: if chain_mode() { "icon-button toggled" } else { "icon-button" },
+                            title: i18n::t(locale(), if chain_mode() { "Back to a single transformer" } else { "Chain several transformers" }),
+                            onclick: move |_| {
+                                chain_mode.set(!chain_mode());
+                                compare_mode.set(false);
+                            },
+                            "⛓"
+                        }
+                        button {
+                            class: if compare_mode() { "icon-button toggled" } else { "icon-button" },
+                            title: i18n::t(locale(), if compare_mode() { "Back to a single transformer" } else { "Compare several transformers" }),
+                            onclick: move |_| {
+                                compare_mode.set(!compare_mode());
+                                chain_mode.set(false);
+                            },
+                            "▦"
+                        }
+                        button {
+                            class: "icon-button",
+                            title: i18n::t(locale(), if layout().vertical { "Show panels side by side" } else { "Stack panels vertically" }),
+                            onclick: move |_| {
+                                layout.write().vertical = !layout().vertical;
+                                layout().save();
+                            },
+                            if layout().vertical { "◫" } else { "⬒" }
+                        }
+                        select {
+                            class: "locale-select",
+                            title: i18n::t(locale(), "Language"),
+                            aria_label: i18n::t(locale(), "Language"),
+                            onchange: move |evt| {
+                                if let Some(selected) = buup::Locale::from_tag(&evt.value()) {
+                                    locale.set(selected);
+                                    i18n::save(selected);
+                                }
+                            },
+                            for option in buup::Locale::ALL {
+                                option {
+                                    value: "{option.code()}",
+                                    selected: option == locale(),
+                                    "{option.native_name()}"
+                                }
+                            }
+                        }
+                        button {
+                            class: if show_appearance() { "icon-button toggled" } else { "icon-button" },
+                            title: i18n::t(locale(), "Appearance"),
+                            onclick: move |_| show_appearance.set(!show_appearance()),
+                            "Aa"
+                        }
+                        button {
+                            class: "icon-button",
+                            onclick: move |_| is_dark_mode.set(!is_dark_mode()),
+                            if is_dark_mode() { "☀️" } else { "🌙" }
+                        }
                     }
                 }
             }
 
+            if show_appearance() && !embedded {
+                appearance::AppearanceStrip { appearance, dark_mode: is_dark_mode() }
+                settings::SettingsTransfer { locale: locale() }
+            }
+
             // Transformer selector
-            div { class: "transformer-selector",
-                div {
-                    class: "current-transformer",
-                    tabindex: "0",
-                    onclick: move |evt| {
-                        // Toggle menu visibility
-                        show_transformer_menu.set(!show_transformer_menu());
-
-                        // Prevent default to ensure click works properly on mobile
-                        evt.stop_propagation();
-
-                        #[cfg(feature = "web")]
-                        {
-                            // If opening the menu, focus the search input
-                            if !show_transformer_menu() {
-                                // Use JS to ensure the button stays focusable
-                                let js_code = r#"
-                                    setTimeout(() => {
-                                        const button = document.querySelector('.current-transformer');
-                                        if (button) {
-                                            button.focus();
-                                        }
-                                    }, 10);
-                                "#;
-                                let _ = js_sys::eval(js_code);
-                            } else {
+            if !embedded {
+                div { class: "transformer-selector",
+                    div {
+                        class: "current-transformer",
+                        tabindex: "0",
+                        role: "button",
+                        aria_haspopup: "dialog",
+                        aria_expanded: "{show_transformer_menu()}",
+                        aria_controls: a11y::MENU_ID,
+                        onkeydown: move |evt| match evt.key() {
+                            Key::Enter | Key::ArrowDown => {
+                                evt.prevent_default();
+                                show_transformer_menu.set(true);
+                            }
+                            Key::Character(c) if c == " " => {
+                                evt.prevent_default();
+                                show_transformer_menu.set(!show_transformer_menu());
+                            }
+                            _ => {}
+                        },
+                        onclick: move |evt| {
+                            // Toggle menu visibility
+                            show_transformer_menu.set(!show_transformer_menu());
+
+                            // Prevent default to ensure click works properly on mobile
+                            evt.stop_propagation();
+
+                            #[cfg(feature = "web")]
+                            {
                                 // If opening the menu, focus the search input
-                                let js_code = r#"
-                                    setTimeout(() => {
-                                        const searchInput = document.querySelector('.search-input');
-                                        if (searchInput) {
-                                            searchInput.focus();
-                                        }
-                                    }, 10);
-                                "#;
-                                let _ = js_sys::eval(js_code);
+                                if !show_transformer_menu() {
+                                    // Use JS to ensure the button stays focusable
+                                    let js_code = r#"
+                                        setTimeout(() => {
+                                            const button = document.querySelector('.current-transformer');
+                                            if (button) {
+                                                button.focus();
+                                            }
+                                        }, 10);
+                                    "#;
+                                    let _ = js_sys::eval(js_code);
+                                } else {
+                                    // If opening the menu, focus the search input
+                                    let js_code = r#"
+                                        setTimeout(() => {
+                                            const searchInput = document.querySelector('.search-input');
+                                            if (searchInput) {
+                                                searchInput.focus();
+                                            }
+                                        }, 10);
+                                    "#;
+                                    let _ = js_sys::eval(js_code);
+                                }
                             }
-                        }
-                    },
+                        },
 
-                    div {
-                        div { class: "transformer-name", "{current_transformer().name()}" }
-                        div { class: "transformer-description", "{current_transformer().description()}" }
-                    }
+                        if chain_mode() {
+                            div {
+                                div { class: "transformer-name", {i18n::t(locale(), "Add a step")} }
+                                div { class: "transformer-description", {i18n::t(locale(), "Each step transforms the output of the one before it")} }
+                            }
+                        } else if compare_mode() {
+                            div {
+                                div { class: "transformer-name", {i18n::t(locale(), "Add to comparison")} }
+                                div { class: "transformer-description", {i18n::t(locale(), "Every selected transformer runs on the same input")} }
+                            }
+                        } else {
+                            div {
+                                div { class: "transformer-name", {buup::localized_name(&**current_transformer(), locale())} }
+                                div { class: "transformer-description", {buup::localized_description(&**current_transformer(), locale())} }
+                            }
+                        }
 
-                    div {
-                        class: if show_transformer_menu() { "arrow-icon open" } else { "arrow-icon" },
-                        "▼"
+                        div {
+                            class: if show_transformer_menu() { "arrow-icon open" } else { "arrow-icon" },
+                            "▼"
+                        }
                     }
-                }
 
-                // Dropdown menu for transformer selection
-                {if show_transformer_menu() {
-                    rsx! {
-                        div {
-                            class: "transformer-menu",
-                            tabindex: "0",
-                            onblur: move |evt| {
-                                // Only close if the related target is not within the menu structure
-                                #[cfg(feature = "web")]
-                                {
-                                    let js_code = r#"
-                                        const menu = document.querySelector('.transformer-menu');
-                                        const button = document.querySelector('.current-transformer');
-                                        const related = document.activeElement;
-                                        
-                                        // Only close if focus is moving outside our components
-                                        if (menu && button && related) {
-                                            if (!menu.contains(related) && !button.contains(related)) {
-                                                return true; // Close the menu
+                    // Dropdown menu for transformer selection
+                    {if show_transformer_menu() {
+                        rsx! {
+                            div {
+                                id: a11y::MENU_ID,
+                                class: "transformer-menu",
+                                tabindex: "0",
+                                role: "dialog",
+                                aria_label: i18n::t(locale(), "Choose a transformation"),
+                                onkeydown: move |evt| match evt.key() {
+                                    Key::Escape => {
+                                        show_transformer_menu.set(false);
+                                        a11y::focus(".current-transformer");
+                                    }
+                                    Key::ArrowDown => {
+                                        evt.prevent_default();
+                                        a11y::move_menu_focus(1);
+                                    }
+                                    Key::ArrowUp => {
+                                        evt.prevent_default();
+                                        a11y::move_menu_focus(-1);
+                                    }
+                                    _ => {}
+                                },
+                                onblur: move |evt| {
+                                    // Only close if the related target is not within the menu structure
+                                    #[cfg(feature = "web")]
+                                    {
+                                        let js_code = r#"
+                                            const menu = document.querySelector('.transformer-menu');
+                                            const button = document.querySelector('.current-transformer');
+                                            const related = document.activeElement;
+                                            
+                                            // Only close if focus is moving outside our components
+                                            if (menu && button && related) {
+                                                if (!menu.contains(related) && !button.contains(related)) {
+                                                    return true; // Close the menu
+                                                }
                                             }
-                                        }
-                                        return false; // Keep menu open
-                                    "#;
+                                            return false; // Keep menu open
+                                        "#;
 
-                                    if let Ok(result) = js_sys::eval(js_code) {
-                                        if let Some(close) = result.as_bool() {
-                                            if close {
-                                                show_transformer_menu.set(false);
+                                        if let Ok(result) = js_sys::eval(js_code) {
+                                            if let Some(close) = result.as_bool() {
+                                                if close {
+                                                    show_transformer_menu.set(false);
+                                                }
                                             }
                                         }
                                     }
-                                }
 
-                                #[cfg(not(feature = "web"))]
-                                {
-                                    show_transformer_menu.set(false);
-                                }
+                                    #[cfg(not(feature = "web"))]
+                                    {
+                                        show_transformer_menu.set(false);
+                                    }
 
-                                evt.stop_propagation();
-                            },
+                                    evt.stop_propagation();
+                                },
+
+                                // Search input
+                                div { class: "search-container",
+                                    input {
+                                        class: "search-input",
+                                        r#type: "text",
+                                        placeholder: i18n::t(locale(), "Search transformations..."),
+                                        aria_label: i18n::t(locale(), "Search transformations..."),
+                                        value: "{search_query}",
+                                        oninput: move |evt| search_query.set(evt.value().clone()),
+                                        autofocus: true,
+                                        onmounted: move |_| {
+                                            #[cfg(feature = "web")]
+                                            {
+                                                // Focus the search input when mounted
+                                                let js_code = r#"
+                                                    setTimeout(() => {
+                                                        const searchInput = document.querySelector('.search-input');
+                                                        if (searchInput) {
+                                                            searchInput.focus();
+                                                        }
+                                                    }, 50);
+                                                "#;
+                                                let _ = js_sys::eval(js_code);
+                                            }
+                                        },
+                                    }
+                                }
 
-                            // Search input
-                            div { class: "search-container",
-                                input {
-                                    class: "search-input",
-                                    r#type: "text",
-                                    placeholder: "Search transformations...",
-                                    value: "{search_query}",
-                                    oninput: move |evt| search_query.set(evt.value().clone()),
-                                    autofocus: true,
-                                    onmounted: move |_| {
-                                        #[cfg(feature = "web")]
-                                        {
-                                            // Focus the search input when mounted
-                                            let js_code = r#"
-                                                setTimeout(() => {
-                                                    const searchInput = document.querySelector('.search-input');
-                                                    if (searchInput) {
-                                                        searchInput.focus();
-                                                    }
-                                                }, 50);
-                                            "#;
-                                            let _ = js_sys::eval(js_code);
+                                // Categories
+                                div { class: "transformer-categories", role: "group",
+                                    button {
+                                        class: if transformer_category() == "all" { "category-button active" } else { "category-button" },
+                                        aria_pressed: "{transformer_category() == \"all\"}",
+                                        onclick: move |_| transformer_category.set("all".to_string()),
+                                        {i18n::t(locale(), "All")}
+                                    }
+                                    {categories.iter().map(|category| {
+                                        let category_str = category.to_string();
+                                        let category_label = i18n::category(locale(), **category);
+                                        rsx! {
+                                            button {
+                                                class: if transformer_category() == category_str { "category-button active" } else { "category-button" },
+                                                aria_pressed: "{transformer_category() == category_str}",
+                                                onclick: move |_| {
+                                                    // Now we can directly use the category string
+                                                    transformer_category.set(category_str.clone());
+                                                },
+                                                {category_label}
+                                            }
                                         }
-                                    },
+                                    })}
                                 }
-                            }
 
-                            // Categories
-                            div { class: "transformer-categories",
-                                button {
-                                    class: if transformer_category() == "all" { "category-button active" } else { "category-button" },
-                                    onclick: move |_| transformer_category.set("all".to_string()),
-                                    "All"
-                                }
-                                {categories.iter().map(|category| {
-                                    let category_str = category.to_string();
+                                // Starred transformers, pinned above everything else
+                                {if unfiltered_menu && !favorite_transformers.is_empty() {
                                     rsx! {
-                                        button {
-                                            class: if transformer_category() == category_str { "category-button active" } else { "category-button" },
-                                            onclick: move |_| {
-                                                // Now we can directly use the category string
-                                                transformer_category.set(category_str.clone());
-                                            },
-                                            "{&category.to_string()}"
+                                        div {
+                                            class: "transformer-list usage-section",
+                                            role: "listbox",
+                                            aria_label: i18n::t(locale(), "Favorites"),
+                                            div { class: "menu-section-title", aria_hidden: "true", {i18n::t(locale(), "Favorites")} }
+                                            {favorite_transformers.iter().map(|transformer| {
+                                                let id = transformer.id();
+                                                rsx! {
+                                                    TransformerOption {
+                                                        key: "favorite-{id}",
+                                                        id,
+                                                        is_current: current_transformer().id() == id,
+                                                        is_favorite: true,
+                                                        locale: locale(),
+                                                        onselect: select_transformer,
+                                                        ontogglefavorite: toggle_favorite,
+                                                    }
+                                                }
+                                            })}
                                         }
                                     }
-                                })}
-                            }
+                                } else {
+                                    rsx! {}
+                                }}
 
-                            // Transformer list
-                            div { class: "transformer-list",
-                                {if filtered_transformers.is_empty() {
+                                // Recently/most used sections (opt-in, stored locally)
+                                {if show_usage_sections && !recently_used.is_empty() {
                                     rsx! {
-                                        div { class: "no-results",
-                                            "No transformations found"
+                                        div {
+                                            class: "transformer-list usage-section",
+                                            role: "listbox",
+                                            aria_label: i18n::t(locale(), "Recently used"),
+                                            div { class: "menu-section-title", aria_hidden: "true", {i18n::t(locale(), "Recently used")} }
+                                            {recently_used.iter().map(|transformer| {
+                                                let id = transformer.id();
+                                                rsx! {
+                                                    TransformerOption {
+                                                        key: "recent-{id}",
+                                                        id,
+                                                        is_current: current_transformer().id() == id,
+                                                        is_favorite: favorites().contains(id),
+                                                        locale: locale(),
+                                                        onselect: select_transformer,
+                                                        ontogglefavorite: toggle_favorite,
+                                                    }
+                                                }
+                                            })}
+                                            div { class: "menu-section-title", role: "presentation", {i18n::t(locale(), "Most used")} }
+                                            {most_used.iter().map(|transformer| {
+                                                let id = transformer.id();
+                                                rsx! {
+                                                    TransformerOption {
+                                                        key: "most-{id}",
+                                                        id,
+                                                        is_current: current_transformer().id() == id,
+                                                        is_favorite: favorites().contains(id),
+                                                        locale: locale(),
+                                                        onselect: select_transformer,
+                                                        ontogglefavorite: toggle_favorite,
+                                                    }
+                                                }
+                                            })}
                                         }
                                     }
                                 } else {
-                                    rsx! {
-                                        {filtered_transformers.iter().map(|transformer| {
-                                            let id = transformer.id();
-                                            let name = transformer.name();
-                                            let description = transformer.description();
-                                            let is_current = current_transformer().id() == id;
-
-                                            rsx! {
-                                                div {
-                                                    key: "{id}",
-                                                    class: if is_current { "transformer-option active" } else { "transformer-option" },
-                                                    onclick: move |evt| {
-                                                        current_transformer.set(Rc::new(buup::transformer_from_id(id).unwrap()));
-                                                        show_transformer_menu.set(false);
-                                                        search_query.set(String::new());
-
-                                                        // Update URL hash when changing transformer
-                                                        if let Some(_window) = web_sys::window() {
-                                                            let _ = js_sys::eval(&format!(
-                                                                "window.location.hash = '{}';",
-                                                                id
-                                                            ));
-                                                        }
-
-                                                        // Stop event propagation to prevent issues
-                                                        evt.stop_propagation();
-
-                                                        #[cfg(feature = "web")]
-                                                        {
-                                                            // Use JS to ensure focus returns to the transformer selector
-                                                            let js_code = r#"
-                                                                setTimeout(() => {
-                                                                    const button = document.querySelector('.current-transformer');
-                                                                    if (button) {
-                                                                        button.focus();
-                                                                    }
-                                                                }, 10);
-                                                            "#;
-                                                            let _ = js_sys::eval(js_code);
-                                                        }
-                                                    },
+                                    rsx! {}
+                                }}
 
-                                                    div { class: "option-name", "{name}" }
-                                                    div { class: "option-description", "{description}" }
-                                                }
+                                // Transformer list
+                                div {
+                                    class: "transformer-list",
+                                    role: "listbox",
+                                    aria_label: i18n::t(locale(), "Transformations"),
+                                    {if filtered_transformers.is_empty() {
+                                        rsx! {
+                                            div { class: "no-results", role: "status",
+                                                {i18n::t(locale(), "No transformations found")}
                                             }
-                                        })}
+                                        }
+                                    } else {
+                                        rsx! {
+                                            {filtered_transformers.iter().map(|transformer| {
+                                                let id = transformer.id();
+                                                rsx! {
+                                                    TransformerOption {
+                                                        key: "{id}",
+                                                        id,
+                                                        is_current: current_transformer().id() == id,
+                                                        is_favorite: favorites().contains(id),
+                                                        locale: locale(),
+                                                        onselect: select_transformer,
+                                                        ontogglefavorite: toggle_favorite,
+                                                    }
+                                                }
+                                            })}
+                                        }
+                                    }}
+                                }
+
+                                // Opt-in usage tracking toggle
+                                div { class: "menu-footer",
+                                    label { class: "usage-toggle",
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: usage().enabled,
+                                            onchange: move |evt| usage.write().set_enabled(evt.checked()),
+                                        }
+                                        {i18n::t(locale(), "Remember recently and most used tools (stored only in this browser)")}
                                     }
-                                }}
+                                }
                             }
                         }
+                    } else {
+                        rsx! {}
+                    }}
+                }
+            }
+
+            if embedded {
+                div { class: "embed-bar",
+                    span { class: "embed-title",
+                        if chain_mode() {
+                            "{chain()}"
+                        } else {
+                            {buup::localized_name(&**current_transformer(), locale())}
+                        }
                     }
-                } else {
-                    rsx! {}
-                }}
+                    a {
+                        href: "./#{embed_link}",
+                        target: "_blank",
+                        rel: "noopener noreferrer",
+                        {i18n::t(locale(), "Open in buup ↗")}
+                    }
+                }
+            }
+
+            if chain_mode() && !embedded {
+                chain::ChainSteps { chain, results: chain_run(), locale: locale() }
+            }
+
+            // Settings for transformers that declare options
+            if !chain_mode() && !compare_mode() && !embedded && !current_transformer().options().is_empty() {
+                options::OptionsStrip {
+                    transformer_id: current_transformer().id(),
+                    values: option_values.read().get(current_transformer().id()).cloned().unwrap_or_default(),
+                    onchange: move |values: options::OptionValues| {
+                        let id = current_transformer().id();
+                        options::save(id, &values);
+                        option_values.write().insert(id, values);
+                    },
+                }
             }
 
             // Input/Output panels
-            div { class: "panels",
+            div {
+                class: match (compare_mode(), layout().vertical) {
+                    (true, _) => "panels single",
+                    (false, true) => "panels vertical",
+                    (false, false) => "panels",
+                },
+                style: if !compare_mode() { layout().grid_style() },
+                onmounted: move |evt| panels_element.set(Some(evt.data())),
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Escape && maximized().is_some() {
+                        maximized.set(None);
+                    }
+                },
                 // Input panel
-                div { class: "panel",
+                div { class: if maximized() == Some(layout::Pane::Input) { "panel maximized" } else { "panel" },
                     div { class: "panel-header",
-                        div { class: "panel-title", "Input" }
+                        div { class: "panel-title", {i18n::t(locale(), "Input")} }
                         div { class: "panel-actions",
+                            examples::ExamplePicker {
+                                key: "{input_transformer_id}",
+                                transformer_id: input_transformer_id,
+                                locale: locale(),
+                                onload: move |sample: &'static str| {
+                                    // Samples run with the transformer's test options (argon2verify's hash)
+                                    let id = input_transformer_id;
+                                    let sample_options = buup::transformer_from_id(id).unwrap().default_test_options();
+                                    if !sample_options.is_empty() {
+                                        let mut values = option_values.read().get(id).cloned().unwrap_or_default();
+                                        for (key, value) in sample_options {
+                                            values.insert(key.to_string(), value.to_string());
+                                        }
+                                        options::save(id, &values);
+                                        option_values.write().insert(id, values);
+                                    }
+                                    input.set(sample.to_string());
+                                },
+                            }
+                            if can_paste {
+                                button {
+                                    class: "action-button paste-button",
+                                    title: i18n::t(locale(), "Paste from clipboard and transform"),
+                                    onclick: move |_| async move {
+                                        if let Some(text) = intents::read_clipboard().await {
+                                            input.set(text);
+                                        }
+                                    },
+                                    {i18n::t(locale(), "Paste")}
+                                }
+                            }
                             button {
                                 class: "action-button",
-                                title: "Clear input",
+                                title: i18n::t(locale(), "Clear input"),
                                 onclick: move |_| input.set("".to_string()),
                                 "✕"
                             }
+                            if !compare_mode() {
+                                FullScreenButton { pane: layout::Pane::Input, maximized, locale: locale() }
+                            }
                         }
                     }
                     div { class: "textarea-container",
+                        // Marks the error line and character behind the transparent textarea
+                        if let Some(highlight) = input_highlight {
+                            div {
+                                class: if appearance().input_wrap { "textarea input-highlight" } else { "textarea input-highlight nowrap" },
+                                aria_hidden: "true",
+                                "{highlight.before}"
+                                span { class: "error-line",
+                                    "{highlight.line_start}"
+                                    mark { class: "error-char", "{highlight.mark}" }
+                                    "{highlight.line_end}"
+                                }
+                                "{highlight.after}"
+                            }
+                        }
                         textarea {
-                            class: "textarea",
+                            class: match (failure.is_some(), appearance().input_wrap) {
+                                (true, true) => "textarea input-textarea has-error",
+                                (true, false) => "textarea input-textarea has-error nowrap",
+                                (false, true) => "textarea input-textarea",
+                                (false, false) => "textarea input-textarea nowrap",
+                            },
+                            wrap: if appearance().input_wrap { "soft" } else { "off" },
+                            aria_label: i18n::t(locale(), "Input"),
+                            aria_invalid: "{failure.is_some()}",
                             value: "{input}",
                             oninput: move |evt| input.set(evt.value().clone()),
+                            onscroll: move |_| errors::sync_highlight_scroll(),
                             placeholder: "{current_transformer().default_test_input()}",
                         }
                     }
                 }
 
-                // Swap button
-                div { class: "swap-button-container",
-                    button {
-                        class: "swap-button",
-                        onclick: swap_transform,
-                        title: "Swap transformation",
-                        "⇄"
+                // Compare mode shows its grid of outputs instead
+                if !compare_mode() {
+                    // Swap button, on the divider that resizes the panels
+                    div {
+                        class: "swap-button-container",
+                        title: i18n::t(locale(), "Drag to resize the panels, double-click to reset"),
+                        onpointerdown: move |_| async move {
+                            let Some(panels) = panels_element() else { return };
+                            if let Ok(rect) = panels.get_client_rect().await {
+                                drag_rect.set(Some(rect));
+                            }
+                        },
+                        ondoubleclick: move |_| {
+                            layout.write().reset_split();
+                            layout().save();
+                        },
+                        button {
+                            class: "swap-button",
+                            onclick: swap_transform,
+                            // Clicking the button shouldn't start a resize
+                            onpointerdown: move |evt| evt.stop_propagation(),
+                            ondoubleclick: move |evt| evt.stop_propagation(),
+                            disabled: chain_mode() && chain().inverse().is_none(),
+                            title: i18n::t(locale(), if chain_mode() { "Swap the chain with its inverse" } else { "Swap transformation" }),
+                            if layout().vertical { "⇅" } else { "⇄" }
+                        }
                     }
-                }
 
-                // Output panel
-                div { class: "panel",
-                    div { class: "panel-header",
-                        div { class: "panel-title", "Output" }
-                        div { class: "panel-actions",
-                            button {
-                                class: "copy-button",
-                                title: "Copy to clipboard",
-                                onclick: copy_to_clipboard,
-                                // Add data attribute for clipboard.js to use
-                                "data-clipboard-text": "{output}",
-                                svg {
-                                    view_box: "0 0 24 24",
-                                    xmlns: "http://www.w3.org/2000/svg",
-                                    path {
-                                        d: "M16 1H4C2.9 1 2 1.9 2 3V17H4V3H16V1ZM19 5H8C6.9 5 6 5.9 6 7V21C6 22.1 6.9 23 8 23H19C20.1 23 21 22.1 21 21V7C21 5.9 20.1 5 19 5ZM19 21H8V7H19V21Z"
+                    // Output panel
+                    div { class: if maximized() == Some(layout::Pane::Output) { "panel maximized" } else { "panel" },
+                        div { class: "panel-header",
+                            div { class: "panel-title",
+                                {i18n::t(locale(), "Output")}
+                                if transform_pending() {
+                                    span { class: "busy-indicator", role: "status", {i18n::t(locale(), "Transforming…")} }
+                                }
+                            }
+                            div { class: "panel-actions",
+                                button {
+                                    class: if auto_format_json() { "action-button toggled" } else { "action-button" },
+                                    title: i18n::t(locale(), "Format JSON output automatically"),
+                                    onclick: move |_| {
+                                        let enabled = !auto_format_json();
+                                        auto_format_json.set(enabled);
+                                        storage::save("buup_output_auto_json", &enabled.to_string());
+                                    },
+                                    "{{}}"
+                                }
+                                button {
+                                    class: if showing_hex { "action-button toggled" } else { "action-button" },
+                                    title: i18n::t(locale(), "Show output as a hex dump"),
+                                    // Binary output can only be shown as hex
+                                    disabled: binary_notice.is_some(),
+                                    onclick: move |_| hex_view.set(!hex_view()),
+                                    "0x"
+                                }
+                                button {
+                                    class: if appearance().output_wrap { "action-button toggled" } else { "action-button" },
+                                    title: i18n::t(locale(), "Toggle line wrap"),
+                                    onclick: move |_| {
+                                        let wrap = !appearance().output_wrap;
+                                        appearance.write().output_wrap = wrap;
+                                        appearance().save();
+                                    },
+                                    "↵"
+                                }
+                                button {
+                                    class: "action-button",
+                                    title: i18n::t(locale(), "Decrease font size"),
+                                    disabled: appearance().font_size <= appearance::MIN_FONT_SIZE,
+                                    onclick: move |_| {
+                                        appearance.write().resize_font(-1);
+                                        appearance().save();
+                                    },
+                                    "A−"
+                                }
+                                button {
+                                    class: "action-button",
+                                    title: i18n::t(locale(), "Increase font size"),
+                                    disabled: appearance().font_size >= appearance::MAX_FONT_SIZE,
+                                    onclick: move |_| {
+                                        appearance.write().resize_font(1);
+                                        appearance().save();
+                                    },
+                                    "A+"
+                                }
+                                FullScreenButton { pane: layout::Pane::Output, maximized, locale: locale() }
+                                button {
+                                    class: "copy-button",
+                                    title: i18n::t(locale(), "Share a link to this transformation"),
+                                    aria_label: i18n::t(locale(), "Share a link to this transformation"),
+                                    onclick: share,
+                                    svg {
+                                        view_box: "0 0 24 24",
+                                        xmlns: "http://www.w3.org/2000/svg",
+                                        path {
+                                            d: "M18 16.08C17.24 16.08 16.56 16.38 16.04 16.85L8.91 12.7C8.96 12.47 9 12.24 9 12S8.96 11.53 8.91 11.3L15.96 7.19C16.5 7.69 17.21 8 18 8C19.66 8 21 6.66 21 5S19.66 2 18 2 15 3.34 15 5C15 5.24 15.04 5.47 15.09 5.7L8.04 9.81C7.5 9.31 6.79 9 6 9C4.34 9 3 10.34 3 12S4.34 15 6 15C6.79 15 7.5 14.69 8.04 14.19L15.16 18.35C15.11 18.56 15.08 18.78 15.08 19C15.08 20.61 16.39 21.92 18 21.92S20.92 20.61 20.92 19 19.61 16.08 18 16.08Z"
+                                        }
+                                    }
+                                    div {
+                                        class: if share_notice().is_some() { "copy-success visible" } else { "copy-success" },
+                                        role: "status",
+                                        {share_notice().map(|notice| i18n::t(locale(), notice))}
                                     }
                                 }
-                                div {
-                                    class: if show_copy_success() { "copy-success visible" } else { "copy-success" },
-                                    "Copied!"
+                                button {
+                                    class: "action-button",
+                                    title: i18n::t(locale(), "Show as QR"),
+                                    // A QR code of a hex dump wouldn't help anyone
+                                    disabled: showing_hex,
+                                    onclick: move |_| show_qr.set(true),
+                                    "QR"
+                                }
+                                button {
+                                    class: "copy-button",
+                                    title: i18n::t(locale(), "Copy to clipboard"),
+                                    onclick: copy_to_clipboard,
+                                    // Add data attribute for clipboard.js to use
+                                    "data-clipboard-text": "{output}",
+                                    svg {
+                                        view_box: "0 0 24 24",
+                                        xmlns: "http://www.w3.org/2000/svg",
+                                        path {
+                                            d: "M16 1H4C2.9 1 2 1.9 2 3V17H4V3H16V1ZM19 5H8C6.9 5 6 5.9 6 7V21C6 22.1 6.9 23 8 23H19C20.1 23 21 22.1 21 21V7C21 5.9 20.1 5 19 5ZM19 21H8V7H19V21Z"
+                                        }
+                                    }
+                                    div {
+                                        class: if show_copy_success() { "copy-success visible" } else { "copy-success" },
+                                        {i18n::t(locale(), "Copied!")}
+                                    }
                                 }
                             }
                         }
-                    }
-                    div { class: "textarea-container",
-                        textarea {
-                            class: "textarea",
-                            value: "{output}" ,
-                            readonly: true,
-                            placeholder: "{current_transformer().transform(current_transformer().default_test_input()).unwrap_or_else(|err| err.to_string())}",
+                        if let Some(image) = &pasted_image {
+                            div { class: "info-banner image-banner", role: "status",
+                                img { class: "image-preview", src: "{image.uri}", alt: "" }
+                                span { "{image.describe()}" }
+                            }
+                        } else if let Some(notice) = &image_uri_notice {
+                            div { class: "info-banner image-banner", role: "status",
+                                img { class: "image-preview", src: "{transform_input().trim()}", alt: "" }
+                                span { "{notice}" }
+                            }
+                        }
+                        if let Some(notice) = &binary_notice {
+                            div { class: "info-banner", role: "status", "{notice}" }
+                        }
+                        if let Some(failure) = &failure {
+                            div { class: "error-banner",
+                                div { class: "error-message", "{failure.message}" }
+                                if let Some(location) = error_location {
+                                    button {
+                                        class: "error-location",
+                                        title: i18n::t(locale(), "Show in input"),
+                                        onclick: move |_| errors::reveal_in_input(location, true),
+                                        "{line_label} {location.line}, {column_label} {location.column}"
+                                    }
+                                }
+                            }
+                        }
+                        suggest::SuggestionChips {
+                            suggestions: suggestions(),
+                            locale: locale(),
+                            onpick: pick_suggestion,
+                        }
+                        div { class: "textarea-container",
+                            textarea {
+                                // Hex dump columns only line up unwrapped
+                                class: if appearance().output_wrap && !showing_hex { "textarea" } else { "textarea nowrap" },
+                                wrap: if appearance().output_wrap && !showing_hex { "soft" } else { "off" },
+                                aria_label: i18n::t(locale(), "Output"),
+                                value: "{output}" ,
+                                readonly: true,
+                                placeholder: "{output_placeholder}",
+                            }
                         }
                     }
                 }
             }
 
+            if compare_mode() {
+                compare::CompareGrid { ids: compare_ids, input: transform_input(), locale: locale() }
+            }
+
+            if show_qr() {
+                qrview::QrOverlay {
+                    output: output.clone(),
+                    locale: locale(),
+                    onclose: move |_| show_qr.set(false),
+                }
+            }
+
+            // Catches the pointer anywhere on the page while the divider is dragged
+            if let Some(rect) = drag_rect() {
+                div {
+                    class: if layout().vertical { "drag-overlay vertical" } else { "drag-overlay" },
+                    onpointermove: move |evt| layout.write().drag_to(rect, evt.client_coordinates()),
+                    onpointerup: move |_| {
+                        drag_rect.set(None);
+                        layout().save();
+                    },
+                    onpointerleave: move |_| {
+                        drag_rect.set(None);
+                        layout().save();
+                    },
+                }
+            }
+
+            a11y::Announcer {
+                message: a11y::announcement(locale(), &output, failure.as_ref().map(|failure| failure.message.as_str())),
+            }
+
+            // Status bar
+            if let Some(stats) = run_stats.filter(|_| !compare_mode() && !embedded) {
+                div { class: "status-bar",
+                    span { class: "status-transformer", "{stats.transformer_id}" }
+                    span { "{status::describe(&stats)}" }
+                }
+            }
+
             // Footer
-            div { class: "footer",
-                span {
-                    {"Made with "}
-                    span { class: "heart", "❤" }
-                    {" by "}
-                    a {
-                        href: "https://benletchford.com",
-                        target: "_blank",
-                        rel: "noopener noreferrer",
-                        "Ben Letchford"
+            if !embedded {
+                div { class: "footer",
+                    span {
+                        {"Made with "}
+                        span { class: "heart", "❤" }
+                        {" by "}
+                        a {
+                            href: "https://benletchford.com",
+                            target: "_blank",
+                            rel: "noopener noreferrer",
+                            "Ben Letchford"
+                        }
+                        {" "}
                     }
-                    {" "}
-                }
-                span {
-                    a {
-                        href: format!("{}/releases/tag/v{}", env!("CARGO_PKG_REPOSITORY"), env!("CARGO_PKG_VERSION")),
-                        target: "_blank",
-                        rel: "noopener noreferrer",
-                        {format!("({}-{})", env!("CARGO_PKG_VERSION"), env!("BUUP_WEB_GIT_HASH"))}
+                    span {
+                        a {
+                            href: format!("{}/releases/tag/v{}", env!("CARGO_PKG_REPOSITORY"), env!("CARGO_PKG_VERSION")),
+                            target: "_blank",
+                            rel: "noopener noreferrer",
+                            {format!("({}-{})", env!("CARGO_PKG_VERSION"), env!("BUUP_WEB_GIT_HASH"))}
+                        }
                     }
                 }
             }
@@ -891,6 +1752,77 @@ fn App() -> Element {
     }
 }
 
+// Toggles whether `pane` fills the window
+#[component]
+fn FullScreenButton(
+    pane: layout::Pane,
+    maximized: Signal<Option<layout::Pane>>,
+    locale: buup::Locale,
+) -> Element {
+    let is_maximized = maximized() == Some(pane);
+
+    rsx! {
+        button {
+            class: if is_maximized { "action-button toggled" } else { "action-button" },
+            title: i18n::t(locale, if is_maximized { "Exit full screen (Esc)" } else { "Full screen" }),
+            onclick: move |_| maximized.set(if is_maximized { None } else { Some(pane) }),
+            if is_maximized { "⤡" } else { "⤢" }
+        }
+    }
+}
+
+// A single selectable entry in the transformer menu
+#[component]
+fn TransformerOption(
+    id: &'static str,
+    is_current: bool,
+    is_favorite: bool,
+    locale: buup::Locale,
+    onselect: EventHandler<&'static str>,
+    ontogglefavorite: EventHandler<&'static str>,
+) -> Element {
+    let transformer = buup::transformer_from_id(id).unwrap();
+
+    rsx! {
+        div {
+            class: if is_current { "transformer-option active" } else { "transformer-option" },
+            role: "option",
+            tabindex: "-1",
+            aria_selected: "{is_current}",
+            onkeydown: move |evt| {
+                if evt.key() == Key::Enter {
+                    evt.prevent_default();
+                    evt.stop_propagation();
+                    onselect.call(id);
+                }
+            },
+            onclick: move |evt| {
+                onselect.call(id);
+                // Stop event propagation to prevent issues
+                evt.stop_propagation();
+            },
+
+            div { class: "option-text",
+                div { class: "option-name", {buup::localized_name(transformer, locale)} }
+                div { class: "option-description", {buup::localized_description(transformer, locale)} }
+            }
+            button {
+                class: if is_favorite { "favorite-toggle starred" } else { "favorite-toggle" },
+                title: i18n::t(locale, if is_favorite { "Remove from favorites" } else { "Add to favorites" }),
+                aria_label: i18n::t(locale, if is_favorite { "Remove from favorites" } else { "Add to favorites" }),
+                aria_pressed: "{is_favorite}",
+                tabindex: "-1",
+                onclick: move |evt| {
+                    ontogglefavorite.call(id);
+                    // Starring shouldn't also select the transformer
+                    evt.stop_propagation();
+                },
+                if is_favorite { "★" } else { "☆" }
+            }
+        }
+    }
+}
+
 // Move Theme struct here as it's used by styles.rs now
 #[derive(Debug, Clone)] // Add Clone and Debug for potential future uses
 pub struct Theme {
@@ -901,4 +1833,7 @@ pub struct Theme {
     pub border: &'static str,
     pub accent: &'static str,
     pub hover: &'static str,
+    pub mono_font: &'static str,
+    /// Textarea font size in pixels
+    pub font_size: u32,
 }
@@ -0,0 +1,151 @@
+// Settings strip for transformers that declare options (indent width,
+// delimiter, ...). Choices are kept per transformer in localStorage under
+// `buup_options_<id>`, and only values that differ from the default are stored.
+
+use crate::storage;
+use buup::{TransformOption, TransformOptionKind, TransformOptions};
+use dioxus::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+/// Option values set for one transformer, by option key
+pub type OptionValues = BTreeMap<String, String>;
+
+fn storage_key(id: &str) -> String {
+    format!("buup_options_{}", id)
+}
+
+/// Loads the saved values of every transformer that has options
+pub fn load_all() -> HashMap<&'static str, OptionValues> {
+    buup::all_transformers()
+        .into_iter()
+        .filter(|transformer| !transformer.options().is_empty())
+        .filter_map(|transformer| {
+            let values: OptionValues = storage::load(&storage_key(transformer.id()))
+                .and_then(|raw| serde_json::from_str(&raw).ok())?;
+            // Drop values for options the transformer no longer has, or
+            // that it no longer accepts (such as a renamed choice)
+            let values = values
+                .into_iter()
+                .filter(|(key, value)| {
+                    TransformOptions::from_iter([(key.as_str(), value.as_str())])
+                        .validate(transformer.options())
+                        .is_ok()
+                })
+                .collect();
+            Some((transformer.id(), values))
+        })
+        .collect()
+}
+
+pub fn save(id: &str, values: &OptionValues) {
+    storage::save(&storage_key(id), &serde_json::to_string(values).unwrap());
+}
+
+/// The values as options for a transform run
+pub fn to_transform_options(values: Option<&OptionValues>) -> TransformOptions {
+    let mut options = TransformOptions::new();
+    for (key, value) in values.into_iter().flatten() {
+        options.set(key.as_str(), value.as_str());
+    }
+    options
+}
+
+/// Sets `key` to `value`, removing it when it's back to the default
+pub fn update(values: &mut OptionValues, option: &TransformOption, value: String) {
+    if value == option.default || value.is_empty() {
+        values.remove(option.key);
+    } else {
+        values.insert(option.key.to_string(), value);
+    }
+}
+
+// One control per option of the current transformer
+#[component]
+pub fn OptionsStrip(
+    transformer_id: &'static str,
+    values: OptionValues,
+    onchange: EventHandler<OptionValues>,
+) -> Element {
+    let transformer = buup::transformer_from_id(transformer_id).unwrap();
+    let options = transformer.options();
+    let customized = !values.is_empty();
+
+    rsx! {
+        div { class: "options-strip",
+            for option in options.iter() {
+                OptionControl {
+                    key: "{transformer_id}-{option.key}",
+                    option: *option,
+                    value: values.get(option.key).cloned().unwrap_or_else(|| option.default.to_string()),
+                    onchange: {
+                        let values = values.clone();
+                        move |value: String| {
+                            let mut values = values.clone();
+                            update(&mut values, option, value);
+                            onchange.call(values);
+                        }
+                    },
+                }
+            }
+            if customized {
+                button {
+                    class: "category-button",
+                    title: "Restore the default settings",
+                    onclick: move |_| onchange.call(OptionValues::new()),
+                    "Reset"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn OptionControl(
+    option: TransformOption,
+    value: String,
+    onchange: EventHandler<String>,
+) -> Element {
+    let label = option.description;
+    let control = match option.kind {
+        TransformOptionKind::Boolean => rsx! {
+            input {
+                r#type: "checkbox",
+                checked: value == "true",
+                onchange: move |evt| onchange.call(evt.checked().to_string()),
+            }
+        },
+        TransformOptionKind::Integer => rsx! {
+            input {
+                class: "option-input option-number",
+                r#type: "number",
+                min: "0",
+                value: "{value}",
+                oninput: move |evt| onchange.call(evt.value()),
+            }
+        },
+        TransformOptionKind::Text => rsx! {
+            input {
+                class: "option-input",
+                r#type: "text",
+                value: "{value}",
+                oninput: move |evt| onchange.call(evt.value()),
+            }
+        },
+        TransformOptionKind::Choice(choices) => rsx! {
+            select {
+                class: "option-input",
+                onchange: move |evt| onchange.call(evt.value()),
+                for choice in choices.iter() {
+                    option { value: "{choice}", selected: *choice == value, "{choice}" }
+                }
+            }
+        },
+    };
+
+    rsx! {
+        label { class: "option-control", title: "{option.key}",
+            span { class: "option-label", "{label}" }
+            {control}
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// "Show as QR": the output drawn as a QR code by the core encoder, for moving
+// short secrets or URLs to a phone without typing them.
+
+use crate::i18n;
+use buup::utils::qr::{EccLevel, QrCode};
+use buup::Locale;
+use dioxus::prelude::*;
+
+/// Modules of light border around the code, as the QR standard asks
+const QUIET_ZONE: usize = 4;
+
+// Overlay with the output's QR code, closed by clicking outside it or Esc
+#[component]
+pub fn QrOverlay(output: String, locale: Locale, onclose: EventHandler<()>) -> Element {
+    let code = if output.is_empty() {
+        Err(i18n::t(locale, "There is no output to show").to_string())
+    } else {
+        QrCode::encode(output.as_bytes(), EccLevel::Medium)
+            .map(|qr| qr.to_svg(QUIET_ZONE))
+            .map_err(|err| err.to_string())
+    };
+
+    rsx! {
+        div {
+            class: "qr-overlay",
+            onclick: move |_| onclose.call(()),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape {
+                    onclose.call(());
+                }
+            },
+            div {
+                class: "qr-card",
+                role: "dialog",
+                aria_label: i18n::t(locale, "Show as QR"),
+                tabindex: "0",
+                onmounted: move |evt| async move {
+                    let _ = evt.data().set_focus(true).await;
+                },
+                // Clicks on the card itself keep it open
+                onclick: move |evt| evt.stop_propagation(),
+                match code {
+                    Ok(svg) => rsx! {
+                        div { class: "qr-code", dangerous_inner_html: "{svg}" }
+                    },
+                    Err(message) => rsx! {
+                        div { class: "qr-error", "{message}" }
+                    },
+                }
+                button {
+                    class: "category-button",
+                    onclick: move |_| onclose.call(()),
+                    {i18n::t(locale, "Close")}
+                }
+            }
+        }
+    }
+}
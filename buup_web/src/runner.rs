@@ -0,0 +1,91 @@
+// Runs transforms on large inputs without freezing the page. The work is done
+// in a task that hands the browser a turn before it starts and between pieces
+// of work (chunks of a streamed input, steps of a chain), so the busy
+// indicator paints and keystrokes are handled in between. Each run carries a
+// `CancelToken`; a newer run cancels it, and it stops at its next turn.
+
+use crate::chain;
+use crate::errors::TransformFailure;
+use crate::status;
+use buup::{CancelToken, Pipeline, Transform, TransformError, TransformOptions, TransformStats};
+
+/// Input fed to a streaming transformer between two turns
+const CHUNK_BYTES: usize = 256 * 1024;
+
+/// One transform run, cancelled through its token
+pub struct Run {
+    pub token: CancelToken,
+}
+
+impl Run {
+    // Lets the browser paint and handle input, then checks whether a newer
+    // run has taken over
+    async fn pause(&self) -> Result<(), TransformError> {
+        gloo_timers::future::TimeoutFuture::new(0).await;
+        self.token.check()
+    }
+
+    /// Like `status::transform_with_stats`. A transformer that can stream,
+    /// with no options set, gets its input a chunk at a time.
+    pub async fn transform(
+        &self,
+        transformer: &dyn Transform,
+        input: &str,
+        options: &TransformOptions,
+    ) -> (Result<String, TransformError>, Option<TransformStats>) {
+        if let Err(err) = self.pause().await {
+            return (Err(err), None);
+        }
+        let Some(mut stream) = transformer.stream().filter(|_| options.is_empty()) else {
+            return status::transform_with_stats(transformer, input, options);
+        };
+
+        let start = status::now();
+        let mut output = Vec::new();
+        let mut result = Ok(());
+        for chunk in input.as_bytes().chunks(CHUNK_BYTES) {
+            result = match stream.update(chunk, &mut output) {
+                Ok(()) => self.pause().await,
+                err => err,
+            };
+            if result.is_err() {
+                break;
+            }
+        }
+        let result = result
+            .and_then(|_| stream.finish(&mut output))
+            .and_then(|_| String::from_utf8(output).map_err(|_| TransformError::Utf8Error));
+        let stats = TransformStats {
+            transformer_id: transformer.id(),
+            input_bytes: input.len(),
+            output_bytes: result.as_ref().map_or(0, String::len),
+            elapsed: start
+                .zip(status::now())
+                .map(|(start, end)| end.saturating_sub(start)),
+            succeeded: result.is_ok(),
+        };
+        (result, Some(stats))
+    }
+
+    /// Like `chain::run`, one step per turn
+    pub async fn chain(
+        &self,
+        chain: &Pipeline,
+        input: &str,
+    ) -> Vec<Result<String, TransformFailure>> {
+        let mut results: Vec<Result<String, TransformError>> = Vec::new();
+        for step in chain.steps() {
+            let current = match results.last() {
+                Some(Ok(previous)) => previous.as_str(),
+                Some(Err(_)) => break,
+                None => input,
+            };
+            let result = match self.pause().await {
+                Ok(()) => step.transform_with_cancel(current, &self.token),
+                Err(err) => Err(err),
+            };
+            results.push(result);
+        }
+        chain::failures(results, input)
+    }
+}
@@ -0,0 +1,126 @@
+// Export/import of everything buup_web keeps in localStorage (favorites,
+// history, theme, appearance, chains, per-transformer options) as one JSON
+// file, for moving a setup to another browser or machine.
+
+use crate::{i18n, storage};
+use buup::Locale;
+use dioxus::prelude::*;
+use serde_json::{Map, Value};
+
+/// Marks a file as a buup_web settings export, and its format version
+const FORMAT_KEY: &str = "buup_settings";
+const FORMAT_VERSION: u64 = 1;
+const FILE_NAME: &str = "buup-settings.json";
+
+/// All saved preferences as a pretty-printed JSON document
+pub fn export() -> String {
+    let mut keys = storage::keys();
+    keys.sort();
+    let values: Map<String, Value> = keys
+        .into_iter()
+        .filter_map(|key| {
+            let value = storage::load(&key)?;
+            Some((key, Value::String(value)))
+        })
+        .collect();
+
+    let mut document = Map::new();
+    document.insert(FORMAT_KEY.to_string(), Value::from(FORMAT_VERSION));
+    document.insert("values".to_string(), Value::Object(values));
+    serde_json::to_string_pretty(&Value::Object(document)).unwrap()
+}
+
+/// Saves the preferences in an exported document, returning how many were set.
+/// Keys without the `buup_` prefix are ignored so a file can't touch other data.
+pub fn import(json: &str) -> Result<usize, String> {
+    let document: Value =
+        serde_json::from_str(json).map_err(|err| format!("Not a settings file: {}", err))?;
+    match document.get(FORMAT_KEY).and_then(Value::as_u64) {
+        Some(version) if version <= FORMAT_VERSION => {}
+        Some(version) => return Err(format!("Unsupported settings version {}", version)),
+        None => return Err("Not a buup settings file".to_string()),
+    }
+    let values = document
+        .get("values")
+        .and_then(Value::as_object)
+        .ok_or("The settings file has no values")?;
+
+    let mut imported = 0;
+    for (key, value) in values {
+        if let (true, Some(value)) = (key.starts_with("buup_"), value.as_str()) {
+            storage::save(key, value);
+            imported += 1;
+        }
+    }
+    Ok(imported)
+}
+
+// Offers `contents` as a file download
+fn download(contents: &str) {
+    #[cfg(feature = "web")]
+    let _ = js_sys::eval(&format!(
+        r#"
+        (() => {{
+            const blob = new Blob([{}], {{ type: 'application/json' }});
+            const link = document.createElement('a');
+            link.href = URL.createObjectURL(blob);
+            link.download = {};
+            link.click();
+            setTimeout(() => URL.revokeObjectURL(link.href), 0);
+        }})();
+        "#,
+        serde_json::to_string(contents).unwrap(),
+        serde_json::to_string(FILE_NAME).unwrap()
+    ));
+    #[cfg(not(feature = "web"))]
+    let _ = contents;
+}
+
+// Reloads the page so every signal starts again from the imported settings
+fn reload() {
+    #[cfg(feature = "web")]
+    let _ = js_sys::eval("location.reload()");
+}
+
+// Export/import buttons shown with the appearance controls
+#[component]
+pub fn SettingsTransfer(locale: Locale) -> Element {
+    let mut error = use_signal(|| None::<String>);
+
+    rsx! {
+        div { class: "options-strip settings-strip",
+            span { class: "option-label", {i18n::t(locale, "Settings")} }
+            button {
+                class: "category-button",
+                title: i18n::t(locale, "Download favorites, history and preferences as a file"),
+                onclick: move |_| download(&export()),
+                {i18n::t(locale, "Export")}
+            }
+            label {
+                class: "category-button",
+                title: i18n::t(locale, "Load settings exported from another browser"),
+                {i18n::t(locale, "Import")}
+                input {
+                    class: "settings-file",
+                    r#type: "file",
+                    accept: ".json,application/json",
+                    onchange: move |evt| async move {
+                        let Some(files) = evt.files() else { return };
+                        let Some(name) = files.files().into_iter().next() else { return };
+                        let result = match files.read_file_to_string(&name).await {
+                            Some(json) => import(&json),
+                            None => Err(format!("Could not read {}", name)),
+                        };
+                        match result {
+                            Ok(_) => reload(),
+                            Err(err) => error.set(Some(err)),
+                        }
+                    },
+                }
+            }
+            if let Some(err) = error() {
+                span { class: "settings-error", "{err}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// "Share" action: hands the deep link to the current transformer and input to
+// the Web Share API (the system share sheet on phones), or copies it where
+// `navigator.share` isn't available.
+
+use crate::deeplink;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareOutcome {
+    /// Handed to the share sheet
+    Shared,
+    /// Copied to the clipboard instead
+    Copied,
+    /// The share sheet was dismissed
+    Cancelled,
+    Failed,
+}
+
+/// Shares a link to `id` (a transformer or chain) with `input`
+pub async fn share_link(id: &str, input: &str) -> ShareOutcome {
+    let js_code = format!(
+        r#"
+        const url = location.origin + location.pathname + '#' + {};
+        let outcome = 'failed';
+        if (navigator.share) {{
+            try {{
+                await navigator.share({{ title: document.title, url }});
+                outcome = 'shared';
+            }} catch (e) {{
+                if (e.name === 'AbortError') outcome = 'cancelled';
+            }}
+        }}
+        if (outcome === 'failed') {{
+            try {{
+                await navigator.clipboard.writeText(url);
+                outcome = 'copied';
+            }} catch (e) {{
+                console.error('Failed to share link:', e);
+            }}
+        }}
+        dioxus.send(outcome);
+        "#,
+        serde_json::to_string(&deeplink::encode(id, input)).unwrap()
+    );
+    let mut eval = dioxus::document::eval(&js_code);
+    match eval.recv::<String>().await.as_deref() {
+        Ok("shared") => ShareOutcome::Shared,
+        Ok("copied") => ShareOutcome::Copied,
+        Ok("cancelled") => ShareOutcome::Cancelled,
+        _ => ShareOutcome::Failed,
+    }
+}
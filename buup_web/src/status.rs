@@ -0,0 +1,65 @@
+// Status bar data: sizes and timing of the latest transform, gathered through
+// the core `Observer` hooks rather than ad-hoc timers in the UI.
+
+use buup::{Observer, Transform, TransformError, TransformOptions, TransformStats};
+use std::cell::RefCell;
+use std::time::Duration;
+
+/// Observer that keeps the stats of the last run, timed with `performance.now()`
+#[derive(Default)]
+struct StatusObserver {
+    stats: RefCell<Option<TransformStats>>,
+}
+
+impl Observer for StatusObserver {
+    fn on_finish(&self, stats: &TransformStats) {
+        *self.stats.borrow_mut() = Some(stats.clone());
+    }
+
+    fn now(&self) -> Option<Duration> {
+        now()
+    }
+}
+
+/// Time since the page loaded, from `performance.now()`
+pub fn now() -> Option<Duration> {
+    #[cfg(feature = "web")]
+    {
+        let millis = web_sys::window()?.performance()?.now();
+        Some(Duration::from_secs_f64(millis / 1000.0))
+    }
+    #[cfg(not(feature = "web"))]
+    None
+}
+
+/// Runs the transformer and returns its result along with the run's stats
+pub fn transform_with_stats(
+    transformer: &dyn Transform,
+    input: &str,
+    options: &TransformOptions,
+) -> (Result<String, TransformError>, Option<TransformStats>) {
+    let observer = StatusObserver::default();
+    let result = buup::transform_observed(transformer, input, options, &observer);
+    (result, observer.stats.into_inner())
+}
+
+/// Formats stats for the status bar, e.g. "12 B → 16 B · 0.10 ms"
+pub fn describe(stats: &TransformStats) -> String {
+    let mut text = format!(
+        "{} → {}",
+        format_size(stats.input_bytes),
+        format_size(stats.output_bytes)
+    );
+    if let Some(elapsed) = stats.elapsed {
+        text.push_str(&format!(" · {:.2} ms", elapsed.as_secs_f64() * 1000.0));
+    }
+    text
+}
+
+pub fn format_size(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
@@ -0,0 +1,70 @@
+// Small helpers around `localStorage` for persisting preferences.
+// All keys are prefixed with `buup_` by convention.
+
+/// Reads a value from localStorage, returning `None` if missing or unavailable
+#[cfg(feature = "web")]
+pub fn load(key: &str) -> Option<String> {
+    use js_sys::{global, Function, Object};
+    use wasm_bindgen::JsCast;
+
+    let storage = js_sys::Reflect::get(&global(), &"localStorage".into())
+        .ok()?
+        .dyn_into::<Object>()
+        .ok()?;
+    let get_fn = js_sys::Reflect::get(&storage, &"getItem".into())
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?;
+    get_fn.call1(&storage, &key.into()).ok()?.as_string()
+}
+
+#[cfg(not(feature = "web"))]
+pub fn load(_key: &str) -> Option<String> {
+    None
+}
+
+/// Writes a value to localStorage, ignoring failures (e.g. private browsing quotas)
+#[cfg(feature = "web")]
+pub fn save(key: &str, value: &str) {
+    let js_code = format!(
+        r#"
+        try {{
+            localStorage.setItem({}, {});
+        }} catch (e) {{
+            console.error('Failed to save preference:', e);
+        }}
+        "#,
+        serde_json::to_string(key).unwrap(),
+        serde_json::to_string(value).unwrap()
+    );
+    let _ = js_sys::eval(&js_code);
+}
+
+#[cfg(not(feature = "web"))]
+pub fn save(_key: &str, _value: &str) {}
+
+/// Reads a boolean preference stored as "true"/"false"
+pub fn load_bool(key: &str, default: bool) -> bool {
+    match load(key).as_deref() {
+        Some("true") => true,
+        Some("false") => false,
+        _ => default,
+    }
+}
+
+/// Every `buup_` key currently in localStorage
+#[cfg(feature = "web")]
+pub fn keys() -> Vec<String> {
+    use wasm_bindgen::JsCast;
+
+    js_sys::eval("Object.keys(localStorage).filter((key) => key.startsWith('buup_'))")
+        .ok()
+        .and_then(|keys| keys.dyn_into::<js_sys::Array>().ok())
+        .map(|keys| keys.iter().filter_map(|key| key.as_string()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(not(feature = "web"))]
+pub fn keys() -> Vec<String> {
+    Vec::new()
+}
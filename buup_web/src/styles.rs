@@ -20,6 +20,10 @@ pub fn generate_css(theme: &Theme) -> String {
                 from {{ transform: translateY(-10px); opacity: 0; }}
                 to {{ transform: translateY(0); opacity: 1; }}
             }}
+
+            @keyframes pulse {{
+                50% {{ opacity: 0.4; }}
+            }}
             
             body {{ 
                 font-family: -apple-system, BlinkMacSystemFont, "SF Pro Text", "SF Pro Icons", "Helvetica Neue", sans-serif;
@@ -88,9 +92,24 @@ pub fn generate_css(theme: &Theme) -> String {
                 font-size: 1.2rem;
             }}
             
+            .locale-select {{
+                background: transparent;
+                color: {text};
+                border: 1px solid {border};
+                border-radius: 0.5rem;
+                padding: 0.25rem 0.5rem;
+                font-size: 0.875rem;
+                cursor: pointer;
+            }}
+
             .icon-button:hover {{ 
                 background: {hover};
             }}
+
+            .icon-button.toggled {{
+                background: {hover};
+                color: {accent};
+            }}
             
             .transformer-selector {{ 
                 position: relative;
@@ -206,6 +225,9 @@ pub fn generate_css(theme: &Theme) -> String {
             }}
             
             .transformer-option {{ 
+                display: flex;
+                align-items: center;
+                gap: 0.5rem;
                 padding: 0.75rem 1rem;
                 cursor: pointer;
                 border-radius: 0.5rem;
@@ -219,7 +241,54 @@ pub fn generate_css(theme: &Theme) -> String {
             .transformer-option.active {{ 
                 background: {hover};
             }}
+
+            .transformer-option:focus-visible {{
+                outline: 2px solid {accent};
+                outline-offset: -2px;
+            }}
+
+            .sr-only {{
+                position: absolute;
+                width: 1px;
+                height: 1px;
+                overflow: hidden;
+                clip: rect(0 0 0 0);
+                white-space: nowrap;
+            }}
             
+            .option-text {{
+                flex: 1;
+                min-width: 0;
+            }}
+
+            .favorite-toggle {{
+                background: transparent;
+                border: none;
+                color: {text_secondary};
+                cursor: pointer;
+                font-size: 1.1rem;
+                padding: 0.25rem;
+                opacity: 0;
+                transition: opacity 0.2s ease, color 0.2s ease;
+            }}
+
+            .transformer-option:hover .favorite-toggle,
+            .favorite-toggle:focus,
+            .favorite-toggle.starred {{
+                opacity: 1;
+            }}
+
+            .favorite-toggle.starred {{
+                color: #FFB800;
+            }}
+
+            /* No hover on touch screens, so always show the star there */
+            @media (hover: none) {{
+                .favorite-toggle {{
+                    opacity: 1;
+                }}
+            }}
+
             .option-name {{ 
                 font-weight: 500;
                 margin-bottom: 0.25rem;
@@ -230,6 +299,33 @@ pub fn generate_css(theme: &Theme) -> String {
                 color: {text_secondary};
             }}
             
+            .menu-section-title {{
+                padding: 0.5rem 1rem 0.25rem;
+                font-size: 0.7rem;
+                font-weight: 600;
+                letter-spacing: 0.05em;
+                text-transform: uppercase;
+                color: {text_secondary};
+            }}
+
+            .usage-section {{
+                border-bottom: 1px solid {border};
+            }}
+
+            .menu-footer {{
+                padding: 0.75rem 1rem;
+                border-top: 1px solid {border};
+            }}
+
+            .usage-toggle {{
+                display: flex;
+                align-items: center;
+                gap: 0.5rem;
+                font-size: 0.75rem;
+                color: {text_secondary};
+                cursor: pointer;
+            }}
+
             .no-results {{
                 padding: 1rem;
                 text-align: center;
@@ -270,6 +366,13 @@ pub fn generate_css(theme: &Theme) -> String {
                 font-size: 0.875rem;
                 font-weight: 500;
             }}
+
+            .busy-indicator {{
+                margin-left: 0.75rem;
+                font-weight: 400;
+                color: {text_secondary};
+                animation: pulse 1.2s ease-in-out infinite;
+            }}
             
             .panel-actions {{ 
                 display: flex;
@@ -278,6 +381,8 @@ pub fn generate_css(theme: &Theme) -> String {
             
             .action-button {{ 
                 border: none;
+                font-size: 0.75rem;
+                white-space: nowrap;
                 background: transparent;
                 color: {text_secondary};
                 cursor: pointer;
@@ -294,6 +399,13 @@ pub fn generate_css(theme: &Theme) -> String {
                 background: {hover};
                 color: {text};
             }}
+
+            .example-select {{
+                width: auto;
+                max-width: 9rem;
+                padding: 0 0.25rem;
+                font-family: inherit;
+            }}
             
             .copy-button {{ 
                 background: transparent;
@@ -382,13 +494,100 @@ pub fn generate_css(theme: &Theme) -> String {
                 border: none; 
                 color: {text}; 
                 resize: none; 
-                font-family: ui-monospace, SFMono-Regular, SF Mono, Menlo, Consolas, Liberation Mono, monospace;
-                font-size: 0.9rem; 
+                font-family: {mono_font};
+                font-size: {font_size}px;
                 line-height: 1.5; 
                 overflow-y: auto;
                 flex: 1;
             }}
             
+            .textarea.nowrap {{
+                white-space: pre;
+                overflow-x: auto;
+            }}
+
+            .action-button.toggled {{
+                background: {hover};
+                color: {accent};
+            }}
+
+            .action-button:disabled {{
+                opacity: 0.4;
+                cursor: default;
+            }}
+
+            .textarea.has-error {{
+                box-shadow: inset 3px 0 0 #FF453A;
+            }}
+
+            .input-textarea {{
+                position: relative;
+                z-index: 1;
+            }}
+
+            .textarea.input-highlight {{
+                position: absolute;
+                inset: 0;
+                color: transparent;
+                white-space: pre-wrap;
+                overflow-wrap: break-word;
+                overflow: hidden;
+                pointer-events: none;
+            }}
+
+            .textarea.input-highlight.nowrap {{
+                white-space: pre;
+            }}
+
+            .input-highlight .error-line {{
+                background: rgba(255, 69, 58, 0.12);
+            }}
+
+            .input-highlight .error-char {{
+                background: rgba(255, 69, 58, 0.45);
+                color: transparent;
+                border-radius: 2px;
+            }}
+
+            .info-banner {{
+                padding: 10px 16px;
+                background: {hover};
+                border-bottom: 1px solid {border};
+                color: {text_secondary};
+                font-size: 0.875rem;
+            }}
+
+            .error-banner {{
+                display: flex;
+                align-items: center;
+                justify-content: space-between;
+                gap: 12px;
+                padding: 10px 16px;
+                background: rgba(255, 69, 58, 0.12);
+                border-bottom: 1px solid rgba(255, 69, 58, 0.4);
+                color: #FF453A;
+                font-size: 0.875rem;
+            }}
+
+            .error-message {{
+                overflow-wrap: anywhere;
+            }}
+
+            .error-location {{
+                flex-shrink: 0;
+                border: 1px solid rgba(255, 69, 58, 0.4);
+                background: none;
+                color: inherit;
+                border-radius: 6px;
+                padding: 4px 8px;
+                font-size: 0.75rem;
+                cursor: pointer;
+            }}
+
+            .error-location:hover {{
+                background: rgba(255, 69, 58, 0.2);
+            }}
+
             .swap-button-container {{ 
                 display: flex; 
                 justify-content: center;
@@ -426,6 +625,317 @@ pub fn generate_css(theme: &Theme) -> String {
             .swap-button:active {{ 
                 transform: scale(0.98);
             }}
+
+            .swap-button:disabled {{
+                opacity: 0.4;
+                cursor: default;
+                pointer-events: none;
+            }}
+
+            .panels.single {{
+                grid-template-columns: 1fr;
+            }}
+
+            /* Panel layout */
+            .panels .swap-button-container {{
+                cursor: col-resize;
+                touch-action: none;
+            }}
+
+            .panels.vertical {{
+                height: max(600px, 85vh);
+            }}
+
+            .panels.vertical .panel {{
+                max-height: none;
+            }}
+
+            .panels.vertical .swap-button-container {{
+                width: 100%;
+                height: 48px;
+                cursor: row-resize;
+            }}
+
+            .drag-overlay {{
+                position: fixed;
+                inset: 0;
+                z-index: 100;
+                cursor: col-resize;
+            }}
+
+            .drag-overlay.vertical {{
+                cursor: row-resize;
+            }}
+
+            .accent-swatch {{
+                width: 1.25rem;
+                height: 1.25rem;
+                border-radius: 50%;
+                border: 2px solid transparent;
+                cursor: pointer;
+            }}
+
+            .accent-swatch.selected {{
+                border-color: {text};
+            }}
+
+            .settings-strip label.category-button {{
+                cursor: pointer;
+            }}
+
+            .settings-file {{
+                display: none;
+            }}
+
+            .settings-error {{
+                color: #FF453A;
+            }}
+
+            /* Embedded widget (?embed=1): fill the iframe instead of the page */
+            .container.embed {{
+                max-width: none;
+                height: 100svh;
+                min-height: 0;
+                padding: 0.5rem;
+            }}
+
+            .container.embed .panel,
+            .container.embed .textarea-container {{
+                max-height: none;
+            }}
+
+            .embed-bar {{
+                display: flex;
+                justify-content: space-between;
+                align-items: center;
+                gap: 1rem;
+                margin-bottom: 0.5rem;
+                font-size: 0.85rem;
+            }}
+
+            .embed-title {{
+                font-weight: 600;
+                overflow: hidden;
+                text-overflow: ellipsis;
+                white-space: nowrap;
+            }}
+
+            .embed-bar a {{
+                color: {accent};
+                text-decoration: none;
+                white-space: nowrap;
+            }}
+
+            .suggestion-chips {{
+                display: flex;
+                flex-wrap: wrap;
+                gap: 0.5rem;
+                margin-bottom: 0.5rem;
+            }}
+
+            .suggestion-chip {{
+                border: 1px solid {accent};
+                border-radius: 999px;
+                background: transparent;
+                color: {accent};
+                font-size: 0.8rem;
+                padding: 0.25rem 0.75rem;
+                cursor: pointer;
+            }}
+
+            .suggestion-chip:hover {{
+                background: {hover};
+            }}
+
+            .image-banner {{
+                display: flex;
+                align-items: center;
+                gap: 12px;
+            }}
+
+            .image-preview {{
+                max-width: 96px;
+                max-height: 96px;
+                border-radius: 4px;
+                background: repeating-conic-gradient({hover} 0% 25%, transparent 0% 50%) 50% / 16px 16px;
+            }}
+
+                        .qr-overlay {{
+                position: fixed;
+                inset: 0;
+                z-index: 200;
+                display: flex;
+                align-items: center;
+                justify-content: center;
+                background: rgba(0, 0, 0, 0.6);
+                animation: fadeIn 0.2s ease;
+            }}
+
+            .qr-card {{
+                display: flex;
+                flex-direction: column;
+                align-items: center;
+                gap: 1rem;
+                padding: 1.5rem;
+                background: {surface};
+                border: 1px solid {border};
+                border-radius: 0.75rem;
+                outline: none;
+            }}
+
+            .qr-code svg {{
+                display: block;
+                width: min(80vw, 360px);
+                height: auto;
+            }}
+
+            .qr-error {{
+                max-width: 320px;
+                color: #FF453A;
+            }}
+
+            .panel.maximized {{
+                position: fixed;
+                inset: 0;
+                z-index: 50;
+                max-height: none;
+                border-radius: 0;
+            }}
+
+            .panel.maximized .textarea-container {{
+                max-height: none;
+            }}
+
+            /* Transformer options */
+            .options-strip {{
+                display: flex;
+                flex-wrap: wrap;
+                align-items: center;
+                gap: 0.75rem 1.5rem;
+                margin-bottom: 1rem;
+                padding: 0.625rem 1rem;
+                background: {surface};
+                border: 1px solid {border};
+                border-radius: 0.75rem;
+                font-size: 0.875rem;
+                animation: slideIn 0.5s ease;
+            }}
+
+            .option-control {{
+                display: flex;
+                align-items: center;
+                gap: 0.5rem;
+                cursor: pointer;
+            }}
+
+            .option-label {{
+                color: {text_secondary};
+            }}
+
+            .option-input {{
+                padding: 0.25rem 0.5rem;
+                background: {bg};
+                color: {text};
+                border: 1px solid {border};
+                border-radius: 0.375rem;
+            }}
+
+            .option-number {{
+                width: 5rem;
+            }}
+
+            /* Compare mode */
+            .compare {{
+                margin-top: 1rem;
+                animation: slideIn 0.5s ease;
+            }}
+
+            .compare-toolbar {{
+                display: flex;
+                flex-wrap: wrap;
+                align-items: center;
+                gap: 0.5rem;
+                margin-bottom: 0.75rem;
+            }}
+
+            .compare-hint {{
+                font-size: 0.875rem;
+                color: {text_secondary};
+            }}
+
+            .compare-grid {{
+                display: grid;
+                grid-template-columns: repeat(auto-fill, minmax(320px, 1fr));
+                gap: 0.75rem;
+            }}
+
+            .compare-card {{
+                background: {surface};
+                border: 1px solid {border};
+                border-radius: 0.75rem;
+                overflow: hidden;
+                min-width: 0;
+            }}
+
+            .compare-output {{
+                max-height: 200px;
+                color: {text};
+            }}
+
+            /* Chain mode */
+            .chain-steps {{
+                display: flex;
+                flex-direction: column;
+                gap: 0.5rem;
+                margin-bottom: 1.5rem;
+                animation: slideIn 0.5s ease;
+            }}
+
+            .chain-empty {{
+                padding: 1rem;
+                border: 1px dashed {border};
+                border-radius: 0.75rem;
+                color: {text_secondary};
+                text-align: center;
+                font-size: 0.875rem;
+            }}
+
+            .chain-step {{
+                background: {surface};
+                border: 1px solid {border};
+                border-radius: 0.75rem;
+                overflow: hidden;
+            }}
+
+            .chain-step-header {{
+                display: flex;
+                align-items: center;
+                justify-content: space-between;
+                padding: 6px 12px 6px 16px;
+            }}
+
+            .chain-step-name {{
+                font-weight: 500;
+                font-size: 0.9375rem;
+            }}
+
+            .chain-step-output {{
+                margin: 0;
+                padding: 8px 16px;
+                max-height: 120px;
+                overflow: auto;
+                border-top: 1px solid {border};
+                font-family: {mono_font};
+                font-size: 0.8125rem;
+                white-space: pre-wrap;
+                word-break: break-all;
+                color: {text_secondary};
+            }}
+
+            .chain-step-error {{
+                color: #FF453A;
+                background: rgba(255, 69, 58, 0.12);
+            }}
             
             .placeholder {{ 
                 position: absolute;
@@ -435,7 +945,7 @@ pub fn generate_css(theme: &Theme) -> String {
                 pointer-events: none;
                 transition: opacity 0.2s ease;
                 opacity: 0;
-                font-family: "SF Mono", "Menlo", monospace;
+                font-family: {mono_font};
                 font-size: 0.9375rem;
             }}
             
@@ -487,6 +997,15 @@ pub fn generate_css(theme: &Theme) -> String {
                     width: 100%; /* Full width on mobile */
                     height: 48px; /* Fixed height on mobile */
                 }}
+
+                /* Panels always stack on mobile, so there's nothing to resize */
+                .panels.vertical {{
+                    height: auto;
+                }}
+
+                .panels .swap-button-container {{
+                    cursor: default;
+                }}
                 
                 .swap-button {{ 
                     width: 100%; /* Full width button on mobile */
@@ -497,6 +1016,20 @@ pub fn generate_css(theme: &Theme) -> String {
             }}
             
             /* Footer styles */
+            .status-bar {{
+                display: flex;
+                justify-content: flex-end;
+                gap: 12px;
+                margin-top: 0.5rem;
+                font-size: 0.75rem;
+                font-variant-numeric: tabular-nums;
+                color: {text_secondary};
+            }}
+
+            .status-transformer {{
+                font-weight: 500;
+            }}
+
             .footer {{
                 margin-top: 1.5rem;
                 padding: 1rem 0;
@@ -528,6 +1061,8 @@ pub fn generate_css(theme: &Theme) -> String {
         text_secondary = theme.text_secondary,
         border = theme.border,
         accent = theme.accent,
-        hover = theme.hover
+        hover = theme.hover,
+        mono_font = theme.mono_font,
+        font_size = theme.font_size
     )
 }
@@ -0,0 +1,73 @@
+// "Continue with …" chips under the output: after a decoder or decompressor
+// succeeds, `buup::detect` looks at what came out for another layer to peel
+// off (Base64 holding gzip data, a decoded payload that's JSON, ...).
+
+use crate::i18n;
+use buup::{Locale, Transform, TransformerCategory};
+use dioxus::prelude::*;
+
+/// Most chips shown at once
+const MAX_SUGGESTIONS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Suggestion {
+    pub id: &'static str,
+    /// Takes the place of the last step instead of following it: binary
+    /// output is only readable by a transformer that decodes the same input
+    pub replace: bool,
+}
+
+/// What to offer after `last` produced `output` (or binary `bytes`)
+pub fn suggestions(
+    last: &dyn Transform,
+    output: Option<&str>,
+    bytes: Option<&[u8]>,
+) -> Vec<Suggestion> {
+    let (found, replace) = match (output, bytes) {
+        // Transformers like gzipdecompress read Base64 text, i.e. base64decode's input
+        (_, Some(bytes)) if last.id() == "base64decode" => (buup::detect_bytes(bytes), true),
+        (Some(output), _)
+            if matches!(
+                last.category(),
+                TransformerCategory::Decoder | TransformerCategory::Compression
+            ) =>
+        {
+            (buup::detect(output), false)
+        }
+        _ => return Vec::new(),
+    };
+    found
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|transformer| Suggestion {
+            id: transformer.id(),
+            replace,
+        })
+        .collect()
+}
+
+#[component]
+pub fn SuggestionChips(
+    suggestions: Vec<Suggestion>,
+    locale: Locale,
+    onpick: EventHandler<Suggestion>,
+) -> Element {
+    if suggestions.is_empty() {
+        return rsx! {};
+    }
+    let label = i18n::t(locale, "continue with");
+
+    rsx! {
+        div { class: "suggestion-chips",
+            for suggestion in suggestions {
+                button {
+                    key: "{suggestion.id}",
+                    class: "suggestion-chip",
+                    title: buup::transformer_from_id(suggestion.id).map(|t| buup::localized_description(t, locale)).unwrap_or_default(),
+                    onclick: move |_| onpick.call(suggestion),
+                    "{label} {suggestion.id} →"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Opt-in, local-only transformer usage counters.
+// Nothing here ever leaves the browser: counts live in localStorage only.
+
+use crate::storage;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+const ENABLED_KEY: &str = "buup_usage_enabled";
+const STATS_KEY: &str = "buup_usage";
+const MAX_RECENT: usize = 5;
+const MAX_MOST_USED: usize = 5;
+
+/// Per-transformer invocation counts plus a most-recent-first history
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageStats {
+    pub enabled: bool,
+    counts: HashMap<String, u32>,
+    recent: Vec<String>,
+}
+
+impl UsageStats {
+    /// Loads the stats from localStorage
+    pub fn load() -> Self {
+        let enabled = storage::load_bool(ENABLED_KEY, false);
+        let mut stats = UsageStats {
+            enabled,
+            ..Default::default()
+        };
+
+        let saved = storage::load(STATS_KEY)
+            .and_then(|raw| serde_json::from_str::<Value>(&raw).ok())
+            .unwrap_or(Value::Null);
+
+        if let Some(counts) = saved.get("counts").and_then(Value::as_object) {
+            for (id, count) in counts {
+                if let Some(count) = count.as_u64() {
+                    stats.counts.insert(id.clone(), count as u32);
+                }
+            }
+        }
+        if let Some(recent) = saved.get("recent").and_then(Value::as_array) {
+            stats.recent = recent
+                .iter()
+                .filter_map(|id| id.as_str().map(str::to_string))
+                .collect();
+        }
+
+        stats
+    }
+
+    /// Turns tracking on or off; disabling also forgets everything recorded so far
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.counts.clear();
+            self.recent.clear();
+        }
+        storage::save(ENABLED_KEY, &enabled.to_string());
+        self.persist();
+    }
+
+    /// Records one use of a transformer (no-op unless tracking is enabled)
+    pub fn record(&mut self, id: &str) {
+        if !self.enabled {
+            return;
+        }
+        *self.counts.entry(id.to_string()).or_insert(0) += 1;
+        self.recent.retain(|recent_id| recent_id != id);
+        self.recent.insert(0, id.to_string());
+        self.recent.truncate(MAX_RECENT);
+        self.persist();
+    }
+
+    /// Most recently used transformers, newest first
+    pub fn recently_used(&self) -> Vec<&'static dyn buup::Transform> {
+        self.recent
+            .iter()
+            .filter_map(|id| buup::transformer_from_id(id).ok())
+            .collect()
+    }
+
+    /// Transformers ordered by invocation count, ties broken by ID
+    pub fn most_used(&self) -> Vec<&'static dyn buup::Transform> {
+        let mut counts: Vec<(&String, &u32)> = self.counts.iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        counts
+            .into_iter()
+            .filter_map(|(id, _)| buup::transformer_from_id(id).ok())
+            .take(MAX_MOST_USED)
+            .collect()
+    }
+
+    fn persist(&self) {
+        let counts: Map<String, Value> = self
+            .counts
+            .iter()
+            .map(|(id, count)| (id.clone(), json!(count)))
+            .collect();
+        let value = json!({ "counts": counts, "recent": self.recent });
+        storage::save(STATS_KEY, &value.to_string());
+    }
+}
@@ -1,7 +1,35 @@
-use buup::{categorized_transformers, transformer_from_id, Transform, TransformerCategory};
+use buup::{
+    deprecated_id, inverse_transformer, transform_bytes_observed, transform_observed,
+    transformer_from_id, utils, CancelToken, Observer, Transform, TransformError, TransformOptions,
+};
 use std::fs::File;
-use std::io::{self, Read, Write};
+use std::io::{self, IsTerminal, Read, Write};
 use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+#[path = "cli/config.rs"]
+mod config;
+#[path = "cli/diff.rs"]
+mod diff;
+#[path = "cli/error.rs"]
+mod error;
+#[path = "cli/json.rs"]
+mod json;
+#[path = "cli/list.rs"]
+mod list;
+#[path = "cli/mcp.rs"]
+mod mcp;
+#[path = "cli/stats.rs"]
+mod stats;
+#[path = "cli/stream.rs"]
+mod stream;
+#[path = "cli/verify.rs"]
+mod verify;
+
+use config::Config;
+use error::CliError;
+use stats::StatsObserver;
 
 fn print_usage() {
     println!("buup - Text transformation utility belt");
@@ -11,14 +39,47 @@ fn print_usage() {
     println!();
     println!("COMMANDS:");
     println!("  list               List all available transformers");
+    println!("    -c, --category C   Only list one category (e.g. crypto, encoders)");
+    println!("    -S, --search TEXT  Only list transformers matching TEXT");
+    println!("    -f, --format FMT   Output as text (default), json or tsv");
+    println!("    -l, --lang CODE    Describe transformers in en, es, fr or de");
+    println!("  config show        Show the active configuration");
+    println!("  config edit        Open the configuration file in $EDITOR");
+    println!("  mcp                Serve transformers as MCP tools over stdio");
     println!("  [transformer_id]   Run the specified transformer");
     println!();
     println!("OPTIONS:");
     println!("  -i, --input FILE   Input file (stdin if not specified)");
     println!("  -o, --output FILE  Output file (stdout if not specified)");
+    println!("  -O, --option K=V   Set a transformer option (repeatable)");
+    println!("  --max-output SIZE  Limit a decompressor's output, e.g. 64M (default 256M)");
+    println!("  --stats            Print input/output sizes and timing to stderr");
+    println!("  --verify           Check the output round-trips through the inverse transformer");
+    println!("  -b, --binary       Read and write raw bytes (compression, hashes)");
+    println!("  -x, --hexdump      Show the output as an offset/hex/ASCII dump (like xxd)");
+    println!("  -d, --diff         Show a unified diff from input to output instead");
+    println!("  -s, --strict       Reject empty input and treat warnings as errors");
+    println!("  -q, --quiet        Suppress error and warning messages");
+    println!("  -t, --timeout SECS Abort the transformation after SECS seconds");
     println!("  -h, --help         Show this help message");
     println!("  -v, --version      Show version information");
     println!();
+    println!("CONFIGURATION:");
+    match config::config_path() {
+        Some(path) => println!("  {}", path.display()),
+        None => println!("  $XDG_CONFIG_HOME/buup/config.toml"),
+    }
+    println!("  Supports [defaults] flags/output_dir and [aliases] (e.g. b64 = \"base64encode\")");
+    println!();
+    println!("EXIT CODES:");
+    println!("  0  Success");
+    println!("  1  Usage or configuration error");
+    println!("  2  Unknown transformer");
+    println!("  3  Invalid input");
+    println!("  4  I/O error");
+    println!("  5  Timed out");
+    println!("  6  Round-trip verification failed");
+    println!();
     println!("Run 'buup list' to see all available transformers");
 }
 
@@ -26,152 +87,277 @@ fn print_version() {
     println!("buup {}", env!("CARGO_PKG_VERSION"));
 }
 
-fn list_transformers() -> Result<(), String> {
-    println!("Available transformers:");
-
-    // Get transformers categorized by the library function
-    let categories = categorized_transformers();
-
-    let encoders = categories.get(&TransformerCategory::Encoder).unwrap();
-    let decoders = categories.get(&TransformerCategory::Decoder).unwrap();
-    let formatters = categories.get(&TransformerCategory::Formatter).unwrap();
-    let cryptography = categories.get(&TransformerCategory::Crypto).unwrap();
-    let compression = categories.get(&TransformerCategory::Compression).unwrap();
-    let colors = categories.get(&TransformerCategory::Color).unwrap();
-    let others = categories.get(&TransformerCategory::Other).unwrap();
-
-    // Print groups with better formatting
-    if !encoders.is_empty() {
-        println!("\nENCODERS:");
-        for t in encoders {
-            println!("  {:<15} - {}", t.id(), t.description());
-        }
-    }
-
-    if !decoders.is_empty() {
-        println!("\nDECODERS:");
-        for t in decoders {
-            println!("  {:<15} - {}", t.id(), t.description());
-        }
-    }
-
-    if !formatters.is_empty() {
-        println!("\nFORMATTERS:");
-        for t in formatters {
-            println!("  {:<15} - {}", t.id(), t.description());
-        }
-    }
-
-    if !cryptography.is_empty() {
-        println!("\nCRYPTOGRAPHY:");
-        for t in cryptography {
-            println!("  {:<15} - {}", t.id(), t.description());
-        }
-    }
+fn config_command(config: &Config, args: &[String]) -> Result<(), CliError> {
+    let path = config::config_path().ok_or_else(|| {
+        CliError::Usage("Unable to determine the config directory (is $HOME set?)".to_string())
+    })?;
 
-    if !compression.is_empty() {
-        println!("\nCOMPRESSION:");
-        for t in compression {
-            println!("  {:<15} - {}", t.id(), t.description());
+    match args.first().map(String::as_str) {
+        Some("show") | None => {
+            let status = if path.exists() { "" } else { " (not found)" };
+            println!("# {}{}", path.display(), status);
+            print!("{}", config.to_toml());
+            Ok(())
         }
-    }
+        Some("edit") => {
+            if !path.exists() {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).map_err(|e| {
+                        CliError::Io(format!("Failed to create config directory: {}", e))
+                    })?;
+                }
+                std::fs::write(&path, config::CONFIG_TEMPLATE)
+                    .map_err(|e| CliError::Io(format!("Failed to create config file: {}", e)))?;
+            }
 
-    if !colors.is_empty() {
-        println!("\nCOLORS:");
-        for t in colors {
-            println!("  {:<15} - {}", t.id(), t.description());
-        }
-    }
+            let editor = std::env::var("VISUAL")
+                .or_else(|_| std::env::var("EDITOR"))
+                .unwrap_or_else(|_| "vi".to_string());
+            let status = Command::new(&editor).arg(&path).status().map_err(|e| {
+                CliError::Io(format!("Failed to launch editor '{}': {}", editor, e))
+            })?;
+            if !status.success() {
+                return Err(CliError::Io(format!(
+                    "Editor '{}' exited with {}",
+                    editor, status
+                )));
+            }
 
-    if !others.is_empty() {
-        println!("\nOTHERS:");
-        for t in others {
-            println!("  {:<15} - {}", t.id(), t.description());
+            // Validate the edited file so mistakes surface immediately
+            Config::load().map(|_| ()).map_err(CliError::Usage)
         }
+        Some(other) => Err(CliError::Usage(format!(
+            "Unknown config command: {}. Expected 'show' or 'edit'.",
+            other
+        ))),
     }
-
-    // Usage examples
-    println!("\nEXAMPLES:");
-    println!("  buup base64encode \"Hello, world!\"     # Encode text directly");
-    println!("  buup urldecode -i encoded.txt         # Decode from file");
-    println!("  echo \"Hello\" | buup hexencode         # Pipe from stdin");
-
-    Ok(())
 }
 
-fn read_input(input_path: Option<PathBuf>) -> Result<String, String> {
+fn read_input(input_path: Option<PathBuf>) -> Result<Vec<u8>, CliError> {
     match input_path {
         Some(path) => {
-            let mut file =
-                File::open(path).map_err(|e| format!("Failed to open input file: {}", e))?;
-            let mut content = String::new();
-            file.read_to_string(&mut content)
-                .map_err(|e| format!("Failed to read input file: {}", e))?;
+            let mut file = File::open(path)
+                .map_err(|e| CliError::Io(format!("Failed to open input file: {}", e)))?;
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)
+                .map_err(|e| CliError::Io(format!("Failed to read input file: {}", e)))?;
             Ok(content)
         }
         None => {
             // Check if stdin has data available
             let stdin = io::stdin();
             let mut stdin_handle = stdin.lock();
-            let mut content = String::new();
+            let mut content = Vec::new();
 
-            // We use read_to_string which will read until EOF
+            // We use read_to_end which will read until EOF
             stdin_handle
-                .read_to_string(&mut content)
-                .map_err(|e| format!("Failed to read from stdin: {}", e))?;
+                .read_to_end(&mut content)
+                .map_err(|e| CliError::Io(format!("Failed to read from stdin: {}", e)))?;
 
             Ok(content)
         }
     }
 }
 
-fn write_output(output_path: Option<PathBuf>, content: String) -> Result<(), String> {
+fn write_output(output_path: Option<PathBuf>, content: &[u8]) -> Result<(), CliError> {
     match output_path {
         Some(path) => {
-            let mut file =
-                File::create(path).map_err(|e| format!("Failed to create output file: {}", e))?;
-            file.write_all(content.as_bytes())
-                .map_err(|e| format!("Failed to write to output file: {}", e))?;
+            let mut file = File::create(path)
+                .map_err(|e| CliError::Io(format!("Failed to create output file: {}", e)))?;
+            file.write_all(content)
+                .map_err(|e| CliError::Io(format!("Failed to write to output file: {}", e)))?;
             Ok(())
         }
         None => {
-            print!("{}", content);
-            io::stdout()
-                .flush()
-                .map_err(|e| format!("Failed to flush stdout: {}", e))?;
+            let mut stdout = io::stdout();
+            stdout
+                .write_all(content)
+                .and_then(|_| stdout.flush())
+                .map_err(|e| CliError::Io(format!("Failed to write to stdout: {}", e)))?;
             Ok(())
         }
     }
 }
 
-fn transform(
-    transformer: &dyn Transform,
+/// Options accepted by a transformer invocation
+#[derive(Debug, Default)]
+struct Options {
     input_path: Option<PathBuf>,
     output_path: Option<PathBuf>,
     text_args: Vec<String>,
-) -> Result<(), String> {
+    strict: bool,
+    quiet: bool,
+    timeout: Option<Duration>,
+    binary: bool,
+    hexdump: bool,
+    diff: bool,
+    stats: bool,
+    verify: bool,
+    max_output: Option<usize>,
+    transform_options: TransformOptions,
+}
+
+impl Options {
+    /// Reports a warning, which fails the invocation in strict mode
+    fn warn(&self, message: &str) -> Result<(), CliError> {
+        if self.strict {
+            return Err(CliError::Usage(message.to_string()));
+        }
+        if !self.quiet {
+            eprintln!("Warning: {}", message);
+        }
+        Ok(())
+    }
+}
+
+// Observer used when --stats is not requested
+struct SilentObserver;
+
+impl Observer for SilentObserver {}
+
+// Maps a failed transform to the CLI error reported for it
+fn transform_error(e: TransformError) -> CliError {
+    match e {
+        TransformError::Cancelled => CliError::Timeout,
+        e => CliError::InvalidInput(format!("Transformation error: {}", e)),
+    }
+}
+
+// Runs a --binary transform on a file or stdin in chunks when the transformer
+// supports it and no flag needs the whole input or output at once. Returns
+// false to fall back to a regular run.
+fn transform_streamed(transformer: &dyn Transform, options: &Options) -> Result<bool, CliError> {
+    let buffered = !options.binary
+        || !options.text_args.is_empty()
+        || !options.transform_options.is_empty()
+        || options.strict
+        || options.hexdump
+        || options.diff
+        || options.stats
+        || options.verify;
+    let Some(mut stream) = transformer.stream().filter(|_| !buffered) else {
+        return Ok(false);
+    };
+
+    let token = options
+        .timeout
+        .map(CancelToken::with_timeout)
+        .unwrap_or_default();
+    let reader: Box<dyn Read> = match &options.input_path {
+        Some(path) => Box::new(
+            File::open(path)
+                .map_err(|e| CliError::Io(format!("Failed to open input file: {}", e)))?,
+        ),
+        None => Box::new(io::stdin().lock()),
+    };
+    match &options.output_path {
+        Some(path) => {
+            let file = File::create(path)
+                .map_err(|e| CliError::Io(format!("Failed to create output file: {}", e)))?;
+            let result = stream::run(stream.as_mut(), reader, io::BufWriter::new(file), &token);
+            // Don't leave a truncated output file behind
+            if result.is_err() {
+                let _ = std::fs::remove_file(path);
+            }
+            result?;
+        }
+        None => stream::run(stream.as_mut(), reader, io::stdout().lock(), &token)?,
+    }
+    Ok(true)
+}
+
+fn transform(transformer: &dyn Transform, options: Options) -> Result<(), CliError> {
+    if transform_streamed(transformer, &options)? {
+        return Ok(());
+    }
+
     // Read input based on priority:
     // 1. Text provided as arguments
     // 2. Input file specified by path
     // 3. Stdin
-    let input = if !text_args.is_empty() {
-        text_args.join(" ")
+    let input = if !options.text_args.is_empty() {
+        if options.input_path.is_some() {
+            options.warn("Ignoring --input because input text was given as arguments")?;
+        }
+        options.text_args.join(" ").into_bytes()
     } else {
-        read_input(input_path)?
+        read_input(options.input_path.clone())?
     };
 
+    if options.strict && input.is_empty() {
+        return Err(CliError::InvalidInput("Input is empty".to_string()));
+    }
+
     // Transform the input
-    let output = transformer
-        .transform(&input)
-        .map_err(|e| format!("Transformation error: {}", e))?;
+    let token = options
+        .timeout
+        .map(CancelToken::with_timeout)
+        .unwrap_or_default();
+    let transform_options = options.transform_options.clone().with_cancel(token);
+    let observer: &dyn Observer = if options.stats {
+        &StatsObserver
+    } else {
+        &SilentObserver
+    };
+    let output = if options.binary {
+        transform_bytes_observed(transformer, &input, &transform_options, observer)
+    } else {
+        let input = std::str::from_utf8(&input).map_err(|_| {
+            CliError::InvalidInput(
+                "Input is not valid UTF-8 (use --binary for raw bytes)".to_string(),
+            )
+        })?;
+        transform_observed(transformer, input, &transform_options, observer).map(String::into_bytes)
+    }
+    .map_err(transform_error)?;
+
+    // Verify before writing so a lossy transform never overwrites anything
+    if options.verify {
+        match inverse_transformer(transformer) {
+            Some(inverse) => verify::check_round_trip(
+                transformer,
+                inverse,
+                &input,
+                &output,
+                options.binary,
+                transform_options.cancel_token(),
+            )?,
+            None => options.warn(&format!(
+                "{} has no inverse transformer; skipping --verify",
+                transformer.id()
+            ))?,
+        }
+    }
 
     // Write output
-    write_output(output_path, output)?;
+    if options.diff {
+        // Colour only when a person is likely reading it
+        let color = options.output_path.is_none()
+            && io::stdout().is_terminal()
+            && std::env::var_os("NO_COLOR").is_none();
+        let input_label = match (&options.input_path, options.text_args.is_empty()) {
+            (Some(path), true) => path.display().to_string(),
+            (None, true) => "<stdin>".to_string(),
+            (_, false) => "<arguments>".to_string(),
+        };
+        let diff = diff::unified_diff(
+            &String::from_utf8_lossy(&input),
+            &String::from_utf8_lossy(&output),
+            &input_label,
+            &format!("{} output", transformer.id()),
+            color,
+        );
+        write_output(options.output_path, diff.as_bytes())?;
+    } else if options.hexdump {
+        write_output(options.output_path, utils::hexdump(&output).as_bytes())?;
+    } else {
+        write_output(options.output_path, &output)?;
+    }
 
     Ok(())
 }
 
-fn parse_args(args: Vec<String>) -> Result<(), String> {
+fn parse_args(args: Vec<String>, quiet: &mut bool) -> Result<(), CliError> {
     if args.len() <= 1 {
         print_usage();
         return Ok(());
@@ -179,9 +365,7 @@ fn parse_args(args: Vec<String>) -> Result<(), String> {
 
     let command = &args[1];
 
-    if command == "list" {
-        return list_transformers();
-    } else if command == "--help" || command == "-h" {
+    if command == "--help" || command == "-h" {
         print_usage();
         return Ok(());
     } else if command == "--version" || command == "-v" {
@@ -189,54 +373,173 @@ fn parse_args(args: Vec<String>) -> Result<(), String> {
         return Ok(());
     }
 
-    // Check if the command name matches a transformer ID
-    match transformer_from_id(command) {
-        Ok(transformer) => {
-            let mut input_path = None;
-            let mut output_path = None;
-            let mut text_args = Vec::new();
-            let mut i = 2;
-
-            while i < args.len() {
-                if args[i] == "-i" || args[i] == "--input" {
-                    if i + 1 >= args.len() {
-                        return Err("Missing input file path".to_string());
-                    }
-                    input_path = Some(PathBuf::from(&args[i + 1]));
-                    i += 2;
-                } else if args[i] == "-o" || args[i] == "--output" {
-                    if i + 1 >= args.len() {
-                        return Err("Missing output file path".to_string());
-                    }
-                    output_path = Some(PathBuf::from(&args[i + 1]));
-                    i += 2;
-                } else if args[i] == "-h" || args[i] == "--help" {
-                    print_usage();
-                    return Ok(());
-                } else {
-                    // Collect all remaining args as text input
-                    text_args.extend(args[i..].iter().cloned());
-                    break;
-                }
+    let config = Config::load().map_err(CliError::Usage)?;
+
+    if command == "list" {
+        return list::run(&args[2..]);
+    } else if command == "config" {
+        return config_command(&config, &args[2..]);
+    } else if command == "mcp" {
+        return mcp::run(&args[2..]);
+    }
+
+    // Check if the command name matches a transformer ID (or a user alias)
+    let transformer_id = config.resolve_alias(command);
+    let transformer = transformer_from_id(transformer_id)
+        .map_err(|_| CliError::UnknownTransformer(command.to_string()))?;
+
+    let mut options = Options::default();
+
+    // Default flags from the config come first so explicit flags override them
+    let default_count = config.default_flags.len();
+    let flags: Vec<String> = config
+        .default_flags
+        .iter()
+        .chain(args[2..].iter())
+        .cloned()
+        .collect();
+    let mut i = 0;
+
+    while i < flags.len() {
+        if flags[i] == "-i" || flags[i] == "--input" {
+            if i + 1 >= flags.len() {
+                return Err(CliError::Usage("Missing input file path".to_string()));
+            }
+            options.input_path = Some(PathBuf::from(&flags[i + 1]));
+            i += 2;
+        } else if flags[i] == "-o" || flags[i] == "--output" {
+            if i + 1 >= flags.len() {
+                return Err(CliError::Usage("Missing output file path".to_string()));
             }
+            options.output_path = Some(config.resolve_output(PathBuf::from(&flags[i + 1])));
+            i += 2;
+        } else if flags[i] == "-t" || flags[i] == "--timeout" {
+            let seconds = flags
+                .get(i + 1)
+                .ok_or_else(|| CliError::Usage("Missing timeout in seconds".to_string()))?;
+            let timeout = seconds
+                .parse::<f64>()
+                .ok()
+                .and_then(|s| Duration::try_from_secs_f64(s).ok())
+                .ok_or_else(|| CliError::Usage(format!("Invalid timeout: {}", seconds)))?;
+            options.timeout = Some(timeout);
+            i += 2;
+        } else if flags[i] == "-O" || flags[i] == "--option" {
+            let option = flags.get(i + 1).ok_or_else(|| {
+                CliError::Usage("Missing option (expected KEY=VALUE)".to_string())
+            })?;
+            let (key, value) = option.split_once('=').ok_or_else(|| {
+                CliError::Usage(format!("Invalid option '{}' (expected KEY=VALUE)", option))
+            })?;
+            options.transform_options.set(key, value);
+            i += 2;
+        } else if flags[i] == "--max-output" {
+            let size = flags
+                .get(i + 1)
+                .ok_or_else(|| CliError::Usage("Missing maximum output size".to_string()))?;
+            let bytes = parse_size(size)
+                .ok_or_else(|| CliError::Usage(format!("Invalid size: {}", size)))?;
+            options.max_output = Some(bytes);
+            i += 2;
+        } else if flags[i] == "--verify" {
+            options.verify = true;
+            i += 1;
+        } else if flags[i] == "--stats" {
+            options.stats = true;
+            i += 1;
+        } else if flags[i] == "-b" || flags[i] == "--binary" {
+            options.binary = true;
+            i += 1;
+        } else if flags[i] == "-x" || flags[i] == "--hexdump" {
+            options.hexdump = true;
+            i += 1;
+        } else if flags[i] == "-d" || flags[i] == "--diff" {
+            options.diff = true;
+            i += 1;
+        } else if flags[i] == "-s" || flags[i] == "--strict" {
+            options.strict = true;
+            i += 1;
+        } else if flags[i] == "-q" || flags[i] == "--quiet" {
+            options.quiet = true;
+            *quiet = true;
+            i += 1;
+        } else if flags[i] == "-h" || flags[i] == "--help" {
+            print_usage();
+            return Ok(());
+        } else if i < default_count {
+            return Err(CliError::Usage(format!(
+                "Unsupported flag in config defaults: {}",
+                flags[i]
+            )));
+        } else {
+            // Collect all remaining args as text input
+            options.text_args.extend(flags[i..].iter().cloned());
+            break;
+        }
+    }
 
-            transform(transformer, input_path, output_path, text_args)
+    if options.diff && options.hexdump {
+        return Err(CliError::Usage(
+            "--diff and --hexdump cannot be combined".to_string(),
+        ));
+    }
+
+    // Only the decompressors have an output limit to set
+    if let Some(bytes) = options.max_output {
+        if !transformer
+            .options()
+            .iter()
+            .any(|option| option.key == "max_output")
+        {
+            return Err(CliError::Usage(format!(
+                "--max-output only applies to decompressors, not '{}'",
+                transformer.id()
+            )));
         }
-        Err(_) => Err(format!(
-            "Unknown transformer: {}. Run 'buup list' to see available transformers.",
-            command
-        )),
+        options
+            .transform_options
+            .set("max_output", bytes.to_string());
     }
+
+    options
+        .transform_options
+        .validate(transformer.options())
+        .map_err(|e| CliError::Usage(e.to_string()))?;
+
+    if let Some(current) = deprecated_id(transformer_id) {
+        options.warn(&format!(
+            "'{}' is deprecated, use '{}' instead",
+            transformer_id, current
+        ))?;
+    }
+
+    transform(transformer, options)
+}
+
+// Parses a byte size with an optional K/M/G (binary) suffix, e.g. "512K" or "1G"
+fn parse_size(size: &str) -> Option<usize> {
+    let size = size.trim();
+    let (digits, multiplier) = match size.char_indices().last()? {
+        (i, 'k' | 'K') => (&size[..i], 1 << 10),
+        (i, 'm' | 'M') => (&size[..i], 1 << 20),
+        (i, 'g' | 'G') => (&size[..i], 1 << 30),
+        _ => (size, 1),
+    };
+    digits.parse::<usize>().ok()?.checked_mul(multiplier)
 }
 
 pub fn main() {
     let args: Vec<String> = std::env::args().collect();
 
-    match parse_args(args) {
+    let mut quiet = false;
+
+    match parse_args(args, &mut quiet) {
         Ok(_) => std::process::exit(0),
         Err(e) => {
-            eprintln!("Error: {}", e);
-            std::process::exit(1)
+            if !quiet {
+                eprintln!("Error: {}", e);
+            }
+            std::process::exit(e.exit_code())
         }
     }
 }
@@ -0,0 +1,339 @@
+//! User configuration for the buup CLI.
+//!
+//! The config file lives at `$XDG_CONFIG_HOME/buup/config.toml` (falling back to
+//! `~/.config/buup/config.toml`) and supports a small subset of TOML:
+//!
+//! ```toml
+//! [defaults]
+//! flags = ["--output", "result.txt"] # Flags prepended to every transformer invocation
+//! output_dir = "~/buup"              # Directory that relative output paths resolve against
+//!
+//! [aliases]
+//! b64 = "base64encode"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Template written by `buup config edit` when no config file exists yet
+pub const CONFIG_TEMPLATE: &str = r#"# buup configuration
+
+[defaults]
+# Flags prepended to every transformer invocation
+# flags = ["--output", "result.txt"]
+
+# Directory that relative --output paths are resolved against
+# output_dir = "~/buup"
+
+[aliases]
+# Shorthand names for transformers
+# b64 = "base64encode"
+"#;
+
+/// Parsed CLI configuration
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Flags prepended to the options of every transformer invocation
+    pub default_flags: Vec<String>,
+    /// Directory that relative output paths are resolved against
+    pub output_dir: Option<PathBuf>,
+    /// User defined aliases mapping a short name to a transformer ID
+    pub aliases: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Loads the config from the default location, returning an empty config if none exists
+    pub fn load() -> Result<Self, String> {
+        match config_path() {
+            Some(path) if path.exists() => {
+                let content = fs::read_to_string(&path)
+                    .map_err(|e| format!("Failed to read config file: {}", e))?;
+                Self::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Parses the config from its TOML source
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+        let mut section = String::new();
+
+        for (index, raw_line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let line = strip_comment(raw_line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name
+                    .strip_suffix(']')
+                    .ok_or_else(|| format!("line {}: unterminated section header", line_number))?
+                    .trim();
+                if name != "defaults" && name != "aliases" {
+                    return Err(format!("line {}: unknown section [{}]", line_number, name));
+                }
+                section = name.to_string();
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected 'key = value'", line_number))?;
+            let key = unquote_key(key.trim());
+            let value = value.trim();
+
+            match (section.as_str(), key.as_str()) {
+                ("defaults", "flags") => {
+                    config.default_flags = parse_string_array(value)
+                        .map_err(|e| format!("line {}: {}", line_number, e))?;
+                }
+                ("defaults", "output_dir") => {
+                    let dir =
+                        parse_string(value).map_err(|e| format!("line {}: {}", line_number, e))?;
+                    config.output_dir = Some(expand_home(&dir));
+                }
+                ("aliases", _) => {
+                    let target =
+                        parse_string(value).map_err(|e| format!("line {}: {}", line_number, e))?;
+                    config.aliases.insert(key, target);
+                }
+                ("", _) => {
+                    return Err(format!(
+                        "line {}: key '{}' must be inside a section",
+                        line_number, key
+                    ))
+                }
+                _ => {
+                    return Err(format!(
+                        "line {}: unknown key '{}' in [{}]",
+                        line_number, key, section
+                    ))
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Resolves a command name through the user aliases
+    pub fn resolve_alias<'a>(&'a self, command: &'a str) -> &'a str {
+        self.aliases
+            .get(command)
+            .map(String::as_str)
+            .unwrap_or(command)
+    }
+
+    /// Resolves an output path against the configured output directory
+    pub fn resolve_output(&self, path: PathBuf) -> PathBuf {
+        match &self.output_dir {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path,
+        }
+    }
+
+    /// Renders the config back to TOML
+    pub fn to_toml(&self) -> String {
+        let mut out = String::from("[defaults]\n");
+        let flags: Vec<String> = self.default_flags.iter().map(|f| quote(f)).collect();
+        out.push_str(&format!("flags = [{}]\n", flags.join(", ")));
+        if let Some(dir) = &self.output_dir {
+            out.push_str(&format!(
+                "output_dir = {}\n",
+                quote(&dir.display().to_string())
+            ));
+        }
+        out.push_str("\n[aliases]\n");
+        for (alias, target) in &self.aliases {
+            out.push_str(&format!("{} = {}\n", alias, quote(target)));
+        }
+        out
+    }
+}
+
+/// Returns the location of the config file
+pub fn config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".config")))?;
+    Some(base.join("buup").join("config.toml"))
+}
+
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ if path == "~" => home_dir().unwrap_or_else(|| PathBuf::from(path)),
+        _ => Path::new(path).to_path_buf(),
+    }
+}
+
+// Removes a trailing `#` comment, ignoring `#` inside quoted strings
+fn strip_comment(line: &str) -> &str {
+    let mut in_quote: Option<char> = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match in_quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if c == '\\' => escaped = true,
+            Some(q) if c == q => in_quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => in_quote = Some(c),
+            None if c == '#' => return &line[..i],
+            None => {}
+        }
+    }
+    line
+}
+
+fn unquote_key(key: &str) -> String {
+    parse_string(key).unwrap_or_else(|_| key.to_string())
+}
+
+// Parses a basic ("...") or literal ('...') TOML string
+fn parse_string(value: &str) -> Result<String, String> {
+    let (parsed, rest) = take_string(value)?;
+    if !rest.trim().is_empty() {
+        return Err(format!("unexpected trailing characters: {}", rest.trim()));
+    }
+    Ok(parsed)
+}
+
+// Parses a TOML array of strings, e.g. ["-o", "result.txt"]
+fn parse_string_array(value: &str) -> Result<Vec<String>, String> {
+    let mut rest = value
+        .strip_prefix('[')
+        .ok_or("expected an array of strings")?
+        .trim_start();
+    let mut items = Vec::new();
+
+    loop {
+        if let Some(after) = rest.strip_prefix(']') {
+            if !after.trim().is_empty() {
+                return Err(format!("unexpected trailing characters: {}", after.trim()));
+            }
+            return Ok(items);
+        }
+        let (item, after) = take_string(rest)?;
+        items.push(item);
+        rest = after.trim_start();
+        if let Some(after) = rest.strip_prefix(',') {
+            rest = after.trim_start();
+        } else if !rest.starts_with(']') {
+            return Err("expected ',' or ']' in array".to_string());
+        }
+    }
+}
+
+// Reads one quoted string from the start of `input`, returning it and the remainder
+fn take_string(input: &str) -> Result<(String, &str), String> {
+    let mut chars = input.char_indices();
+    let quote = match chars.next() {
+        Some((_, c)) if c == '"' || c == '\'' => c,
+        _ => return Err(format!("expected a quoted string, found: {}", input)),
+    };
+
+    let mut result = String::new();
+    while let Some((i, c)) = chars.next() {
+        if c == quote {
+            return Ok((result, &input[i + 1..]));
+        }
+        if c == '\\' && quote == '"' {
+            match chars.next() {
+                Some((_, 'n')) => result.push('\n'),
+                Some((_, 't')) => result.push('\t'),
+                Some((_, '"')) => result.push('"'),
+                Some((_, '\\')) => result.push('\\'),
+                Some((_, other)) => {
+                    return Err(format!("unsupported escape sequence: \\{}", other))
+                }
+                None => break,
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_config() {
+        let config = Config::parse(
+            r#"
+            # My settings
+            [defaults]
+            flags = ["--quiet", "-o", "out # not a comment.txt"] # trailing comment
+            output_dir = "/tmp/buup"
+
+            [aliases]
+            b64 = "base64encode"
+            'unb64' = 'base64decode'
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.default_flags,
+            vec!["--quiet", "-o", "out # not a comment.txt"]
+        );
+        assert_eq!(config.output_dir, Some(PathBuf::from("/tmp/buup")));
+        assert_eq!(config.resolve_alias("b64"), "base64encode");
+        assert_eq!(config.resolve_alias("unb64"), "base64decode");
+        assert_eq!(config.resolve_alias("urlencode"), "urlencode");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Config::parse("[unknown]").is_err());
+        assert!(Config::parse("[defaults]\ncolour = \"red\"").is_err());
+        assert!(Config::parse("b64 = \"base64encode\"").is_err());
+        assert!(Config::parse("[aliases]\nb64 = base64encode").is_err());
+        assert!(Config::parse("[defaults]\nflags = [\"--quiet\"").is_err());
+    }
+
+    #[test]
+    fn test_resolve_output() {
+        let config = Config {
+            output_dir: Some(PathBuf::from("/tmp/buup")),
+            ..Config::default()
+        };
+        assert_eq!(
+            config.resolve_output(PathBuf::from("out.txt")),
+            PathBuf::from("/tmp/buup/out.txt")
+        );
+        assert_eq!(
+            config.resolve_output(PathBuf::from("/abs/out.txt")),
+            PathBuf::from("/abs/out.txt")
+        );
+    }
+
+    #[test]
+    fn test_round_trip_toml() {
+        let config = Config::parse(CONFIG_TEMPLATE).unwrap();
+        assert_eq!(config, Config::default());
+
+        let mut config = Config::default();
+        config.default_flags.push("--quiet".to_string());
+        config
+            .aliases
+            .insert("b64".to_string(), "base64encode".to_string());
+        assert_eq!(Config::parse(&config.to_toml()).unwrap(), config);
+    }
+}
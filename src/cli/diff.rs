@@ -0,0 +1,190 @@
+//! `--diff`: show what a transformer changed as a unified diff.
+
+use std::fmt::Write;
+
+/// Lines of unchanged context shown around each change
+const CONTEXT: usize = 3;
+/// Largest changed region (old lines × new lines) diffed line by line. Bigger
+/// regions are shown as a single replacement to bound time and memory.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Renders a unified diff from `old` to `new`, with ANSI colours if `color`.
+/// Returns an empty string when the texts are identical.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str, color: bool) -> String {
+    let a: Vec<&str> = old.split_inclusive('\n').collect();
+    let b: Vec<&str> = new.split_inclusive('\n').collect();
+    let edits = diff_lines(&a, &b);
+    if edits.iter().all(|e| matches!(e, Edit::Equal(..))) {
+        return String::new();
+    }
+
+    let paint = |code: &'static str| if color { code } else { "" };
+    let mut out = String::new();
+    let _ = writeln!(out, "{}--- {}{}", paint(BOLD), old_label, paint(RESET));
+    let _ = writeln!(out, "{}+++ {}{}", paint(BOLD), new_label, paint(RESET));
+
+    for hunk in hunks(&edits) {
+        let is_old = |e: &&Edit| !matches!(e, Edit::Insert(_));
+        let is_new = |e: &&Edit| !matches!(e, Edit::Delete(_));
+        let (old_start, old_count) = span(&edits, &hunk, is_old);
+        let (new_start, new_count) = span(&edits, &hunk, is_new);
+        let _ = writeln!(
+            out,
+            "{}@@ -{},{} +{},{} @@{}",
+            paint(CYAN),
+            old_start,
+            old_count,
+            new_start,
+            new_count,
+            paint(RESET)
+        );
+        for edit in &edits[hunk] {
+            let (prefix, line, code) = match *edit {
+                Edit::Equal(i, _) => (' ', a[i], ""),
+                Edit::Delete(i) => ('-', a[i], RED),
+                Edit::Insert(j) => ('+', b[j], GREEN),
+            };
+            let text = line.strip_suffix('\n').unwrap_or(line);
+            let _ = writeln!(out, "{}{}{}{}", paint(code), prefix, text, paint(RESET));
+            if !line.ends_with('\n') {
+                out.push_str("\\ No newline at end of file\n");
+            }
+        }
+    }
+    out
+}
+
+// Line-level edit script: a longest common subsequence over the region left
+// after trimming the common prefix and suffix
+fn diff_lines(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    let mut edits: Vec<Edit> = (0..prefix).map(|i| Edit::Equal(i, i)).collect();
+    let (n, m) = (a_mid.len(), b_mid.len());
+    if n.saturating_mul(m) > MAX_DIFF_CELLS {
+        edits.extend((0..n).map(|i| Edit::Delete(prefix + i)));
+        edits.extend((0..m).map(|j| Edit::Insert(prefix + j)));
+    } else {
+        // lcs[i * (m + 1) + j] = LCS length of a_mid[i..] and b_mid[j..]
+        let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i * (m + 1) + j] = if a_mid[i] == b_mid[j] {
+                    lcs[(i + 1) * (m + 1) + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * (m + 1) + j].max(lcs[i * (m + 1) + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && a_mid[i] == b_mid[j] {
+                edits.push(Edit::Equal(prefix + i, prefix + j));
+                i += 1;
+                j += 1;
+            } else if j == m || (i < n && lcs[(i + 1) * (m + 1) + j] >= lcs[i * (m + 1) + j + 1]) {
+                edits.push(Edit::Delete(prefix + i));
+                i += 1;
+            } else {
+                edits.push(Edit::Insert(prefix + j));
+                j += 1;
+            }
+        }
+    }
+    let (a_end, b_end) = (a.len() - suffix, b.len() - suffix);
+    edits.extend((0..suffix).map(|k| Edit::Equal(a_end + k, b_end + k)));
+    edits
+}
+
+// Ranges of `edits` to print: each change plus CONTEXT lines either side,
+// merging changes whose context would overlap
+fn hunks(edits: &[Edit]) -> Vec<std::ops::Range<usize>> {
+    let mut ranges: Vec<std::ops::Range<usize>> = Vec::new();
+    for (index, edit) in edits.iter().enumerate() {
+        if matches!(edit, Edit::Equal(..)) {
+            continue;
+        }
+        let start = index.saturating_sub(CONTEXT);
+        let end = (index + 1 + CONTEXT).min(edits.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    ranges
+}
+
+// Hunk header numbers for one side: the 1-based first line and the line
+// count. An empty side gives the line before the hunk, as GNU diff does.
+fn span(
+    edits: &[Edit],
+    hunk: &std::ops::Range<usize>,
+    on_side: impl Fn(&&Edit) -> bool,
+) -> (usize, usize) {
+    let before = edits[..hunk.start].iter().filter(&on_side).count();
+    let count = edits[hunk.clone()].iter().filter(&on_side).count();
+    (if count == 0 { before } else { before + 1 }, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_texts() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "in", "out", false), "");
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+        assert_eq!(
+            unified_diff(old, new, "input", "output", false),
+            "--- input\n+++ output\n@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n"
+        );
+    }
+
+    #[test]
+    fn test_minified_to_formatted() {
+        let diff = unified_diff("{\"a\":1}", "{\n  \"a\": 1\n}", "in", "out", false);
+        assert_eq!(
+            diff,
+            "--- in\n+++ out\n@@ -1,1 +1,3 @@\n-{\"a\":1}\n\\ No newline at end of file\n\
+             +{\n+  \"a\": 1\n+}\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_insertion_into_empty() {
+        let diff = unified_diff("", "new\n", "in", "out", false);
+        assert_eq!(diff, "--- in\n+++ out\n@@ -0,0 +1,1 @@\n+new\n");
+    }
+
+    #[test]
+    fn test_colors() {
+        let diff = unified_diff("a\n", "b\n", "in", "out", true);
+        assert!(diff.contains("\x1b[31m-a\x1b[0m"));
+        assert!(diff.contains("\x1b[32m+b\x1b[0m"));
+    }
+}
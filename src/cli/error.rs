@@ -0,0 +1,82 @@
+//! Errors reported by the buup CLI and the exit codes they map to.
+//!
+//! | Code | Meaning                                            |
+//! |------|----------------------------------------------------|
+//! | 0    | Success                                            |
+//! | 1    | Usage or configuration error                       |
+//! | 2    | Unknown transformer                                |
+//! | 3    | Invalid input (the transformer rejected the input) |
+//! | 4    | I/O error (reading input or writing output)        |
+//! | 5    | Timed out (see `--timeout`)                        |
+//! | 6    | Round-trip verification failed (see `--verify`)    |
+
+use std::fmt;
+
+/// An error that terminates the CLI with a specific exit code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// Bad command line arguments or configuration
+    Usage(String),
+    /// The requested transformer does not exist
+    UnknownTransformer(String),
+    /// The input could not be transformed
+    InvalidInput(String),
+    /// Reading input or writing output failed
+    Io(String),
+    /// The transform did not finish within the requested timeout
+    Timeout,
+    /// The output did not survive a round trip through the inverse transformer
+    VerifyFailed(String),
+}
+
+impl CliError {
+    /// Process exit code for this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Usage(_) => 1,
+            Self::UnknownTransformer(_) => 2,
+            Self::InvalidInput(_) => 3,
+            Self::Io(_) => 4,
+            Self::Timeout => 5,
+            Self::VerifyFailed(_) => 6,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usage(message)
+            | Self::InvalidInput(message)
+            | Self::Io(message)
+            | Self::VerifyFailed(message) => {
+                write!(f, "{}", message)
+            }
+            Self::UnknownTransformer(id) => write!(
+                f,
+                "Unknown transformer: {}. Run 'buup list' to see available transformers.",
+                id
+            ),
+            Self::Timeout => write!(f, "Transformation timed out"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes_are_distinct() {
+        let errors = [
+            CliError::Usage(String::new()),
+            CliError::UnknownTransformer(String::new()),
+            CliError::InvalidInput(String::new()),
+            CliError::Io(String::new()),
+            CliError::Timeout,
+            CliError::VerifyFailed(String::new()),
+        ];
+        let codes: Vec<i32> = errors.iter().map(CliError::exit_code).collect();
+        assert_eq!(codes, vec![1, 2, 3, 4, 5, 6]);
+    }
+}
@@ -0,0 +1,178 @@
+//! Minimal JSON values for the CLI's machine interfaces (`buup mcp`).
+//!
+//! Parsing is done by the library's [`JsonValue`]; this type adds what
+//! JSON-RPC messages need on top: numeric values and sorted keys.
+
+use buup::utils::json::{push_escaped, JsonValue};
+use buup::TransformError;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+/// A parsed JSON value. Objects keep keys sorted so output is deterministic.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(BTreeMap<String, Json>),
+}
+
+impl Json {
+    /// Builds an object from key/value pairs
+    pub fn object<const N: usize>(entries: [(&str, Json); N]) -> Json {
+        Json::Object(
+            entries
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect(),
+        )
+    }
+
+    /// Looks up `key` if this is an object
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self {
+        Json::String(s.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Self {
+        Json::String(s)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Self {
+        Json::Bool(b)
+    }
+}
+
+impl fmt::Display for Json {
+    // Compact serialization on a single line
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Number(n) if n.is_finite() && n.fract() == 0.0 && n.abs() < 1e15 => {
+                write!(f, "{}", *n as i64)
+            }
+            Json::Number(n) if n.is_finite() => write!(f, "{}", n),
+            Json::Number(_) => f.write_str("null"),
+            Json::String(s) => write_string(f, s),
+            Json::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(map) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in map.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    push_escaped(&mut escaped, s);
+    escaped.push('"');
+    f.write_str(&escaped)
+}
+
+/// Parses a complete JSON document
+pub fn parse(text: &str) -> Result<Json, String> {
+    JsonValue::parse(text).map(Json::from).map_err(|e| match e {
+        TransformError::JsonParseError(details) => details,
+        e => e.to_string(),
+    })
+}
+
+impl From<JsonValue> for Json {
+    fn from(value: JsonValue) -> Self {
+        match value {
+            JsonValue::Null => Json::Null,
+            JsonValue::Bool(b) => Json::Bool(b),
+            // Always parses, as the text is a valid JSON number
+            JsonValue::Number(n) => n.parse().map_or(Json::Null, Json::Number),
+            JsonValue::String(s) => Json::String(s),
+            JsonValue::Array(items) => Json::Array(items.into_iter().map(Json::from).collect()),
+            JsonValue::Object(entries) => Json::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, Json::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_serialize() {
+        let text = r#"{"id": 1, "params": {"name": "x\ny", "list": [true, null, -2.5e1]}}"#;
+        let value = parse(text).unwrap();
+        assert_eq!(value.get("id"), Some(&Json::Number(1.0)));
+        assert_eq!(
+            value
+                .get("params")
+                .and_then(|p| p.get("name"))
+                .and_then(Json::as_str),
+            Some("x\ny")
+        );
+        assert_eq!(
+            value.to_string(),
+            r#"{"id":1,"params":{"list":[true,null,-25],"name":"x\ny"}}"#
+        );
+    }
+
+    #[test]
+    fn test_unicode_escapes() {
+        assert_eq!(
+            parse(r#""\u00e9\ud83d\ude00""#).unwrap(),
+            Json::String("é😀".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("{\"a\" 1}").is_err());
+        assert!(parse("[1, 2").is_err());
+        assert!(parse("\"unterminated").is_err());
+        assert!(parse("1 2").is_err());
+        assert!(parse("tru").is_err());
+    }
+}
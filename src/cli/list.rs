@@ -0,0 +1,253 @@
+//! `buup list`: browse, filter and export the available transformers.
+
+use super::CliError;
+use buup::{
+    localized_description, localized_name, search_transformers_localized, Locale, Transform,
+    TransformerCategory,
+};
+
+/// Order in which categories are printed by the text listing
+const CATEGORY_ORDER: [(TransformerCategory, &str); 8] = [
+    (TransformerCategory::Encoder, "ENCODERS"),
+    (TransformerCategory::Decoder, "DECODERS"),
+    (TransformerCategory::Formatter, "FORMATTERS"),
+    (TransformerCategory::Crypto, "CRYPTOGRAPHY"),
+    (TransformerCategory::Compression, "COMPRESSION"),
+    (TransformerCategory::Color, "COLORS"),
+    (TransformerCategory::Generator, "GENERATORS"),
+    (TransformerCategory::Other, "OTHERS"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+/// Parsed `buup list` arguments
+#[derive(Debug, PartialEq, Eq)]
+struct ListArgs {
+    category: Option<TransformerCategory>,
+    search: Option<String>,
+    format: Format,
+    locale: Locale,
+}
+
+/// Runs `buup list` with the arguments following the command
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let args = parse_list_args(args)?;
+    let matches = search_transformers_localized(
+        args.search.as_deref().unwrap_or(""),
+        args.category,
+        args.locale,
+    );
+
+    match args.format {
+        Format::Json => println!("{}", format_json(&matches, args.locale)),
+        Format::Tsv => print!("{}", format_tsv(&matches, args.locale)),
+        Format::Text if args.category.is_none() && args.search.is_none() => {
+            print_text(&matches, args.locale);
+            print_examples();
+        }
+        Format::Text if matches.is_empty() => println!("No matching transformers"),
+        Format::Text => print_text(&matches, args.locale),
+    }
+    Ok(())
+}
+
+fn parse_list_args(args: &[String]) -> Result<ListArgs, CliError> {
+    let mut parsed = ListArgs {
+        category: None,
+        search: None,
+        format: Format::Text,
+        locale: Locale::English,
+    };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .ok_or_else(|| CliError::Usage(format!("Missing value for {}", arg)))
+        };
+        match arg.as_str() {
+            "--category" | "-c" => {
+                let category = value()?;
+                parsed.category = Some(category.parse().map_err(|_| {
+                    let known: Vec<String> =
+                        CATEGORY_ORDER.iter().map(|(c, _)| c.to_string()).collect();
+                    CliError::Usage(format!(
+                        "Unknown category '{}' (expected one of: {})",
+                        category,
+                        known.join(", ")
+                    ))
+                })?);
+            }
+            "--search" | "-S" => parsed.search = Some(value()?.clone()),
+            "--lang" | "-l" => {
+                parsed.locale = value()?
+                    .parse()
+                    .map_err(|e: buup::TransformError| CliError::Usage(e.to_string()))?;
+            }
+            "--format" | "-f" => {
+                parsed.format = match value()?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "tsv" => Format::Tsv,
+                    other => {
+                        return Err(CliError::Usage(format!(
+                            "Unknown list format '{}' (expected text, json or tsv)",
+                            other
+                        )))
+                    }
+                };
+            }
+            other => {
+                return Err(CliError::Usage(format!(
+                    "Unknown option for list: {}",
+                    other
+                )))
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+fn print_text(transformers: &[&dyn Transform], locale: Locale) {
+    println!("Available transformers:");
+    for (category, heading) in CATEGORY_ORDER {
+        let group: Vec<_> = transformers
+            .iter()
+            .filter(|t| t.category() == category)
+            .collect();
+        if group.is_empty() {
+            continue;
+        }
+        println!("\n{}:", heading);
+        for t in group {
+            println!("  {:<15} - {}", t.id(), localized_description(*t, locale));
+        }
+    }
+}
+
+fn print_examples() {
+    println!("\nEXAMPLES:");
+    println!("  buup base64encode \"Hello, world!\"     # Encode text directly");
+    println!("  buup urldecode -i encoded.txt         # Decode from file");
+    println!("  echo \"Hello\" | buup hexencode         # Pipe from stdin");
+    println!("  buup gzipcompress --binary -i a.raw -o a.raw.gz  # Raw bytes in and out");
+    println!("  buup list --category crypto --format tsv        # Filter and export");
+}
+
+// One tab-separated line per transformer: id, name, category, description
+fn format_tsv(transformers: &[&dyn Transform], locale: Locale) -> String {
+    let clean = |s: &str| s.replace(['\t', '\n'], " ");
+    transformers
+        .iter()
+        .map(|t| {
+            format!(
+                "{}\t{}\t{}\t{}\n",
+                t.id(),
+                clean(localized_name(*t, locale)),
+                t.category(),
+                clean(localized_description(*t, locale))
+            )
+        })
+        .collect()
+}
+
+fn format_json(transformers: &[&dyn Transform], locale: Locale) -> String {
+    let entries: Vec<String> = transformers
+        .iter()
+        .map(|t| {
+            format!(
+                "  {{\"id\": {}, \"name\": {}, \"category\": {}, \"description\": {}}}",
+                json_string(t.id()),
+                json_string(localized_name(*t, locale)),
+                json_string(&t.category().to_string()),
+                json_string(localized_description(*t, locale))
+            )
+        })
+        .collect();
+    if entries.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", entries.join(",\n"))
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use buup::{transformer_from_id, JsonFormatter};
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_list_args() {
+        let parsed = parse_list_args(&args(&[
+            "--category",
+            "crypto",
+            "--search",
+            "sha",
+            "-f",
+            "tsv",
+        ]))
+        .unwrap();
+        assert_eq!(
+            parsed,
+            ListArgs {
+                category: Some(TransformerCategory::Crypto),
+                search: Some("sha".to_string()),
+                format: Format::Tsv,
+                locale: Locale::English,
+            }
+        );
+        assert!(parse_list_args(&args(&["--category", "nope"])).is_err());
+        assert!(parse_list_args(&args(&["--format", "xml"])).is_err());
+        assert!(parse_list_args(&args(&["--search"])).is_err());
+        assert_eq!(
+            parse_list_args(&args(&["--lang", "fr"])).unwrap().locale,
+            Locale::French
+        );
+        assert!(parse_list_args(&args(&["--lang", "xx"])).is_err());
+    }
+
+    #[test]
+    fn test_machine_readable_formats() {
+        let md5 = transformer_from_id("md5hash").unwrap();
+        let tsv = format_tsv(&[md5], Locale::English);
+        assert!(tsv.starts_with("md5hash\tMD5 Hash\tcrypto\t"));
+        let tsv = format_tsv(&[md5], Locale::German);
+        assert!(tsv.starts_with("md5hash\tMD5-Hash\tcrypto\t"));
+        assert!(tsv.ends_with('\n'));
+
+        // The JSON listing must be valid JSON
+        let json = format_json(
+            &[md5, transformer_from_id("base64encode").unwrap()],
+            Locale::Spanish,
+        );
+        assert!(JsonFormatter.transform(&json).is_ok());
+        assert_eq!(format_json(&[], Locale::English), "[]");
+        assert_eq!(json_string("a\"b\\\n"), "\"a\\\"b\\\\\\n\"");
+    }
+}
@@ -0,0 +1,334 @@
+//! `buup mcp`: a Model Context Protocol server over stdio.
+//!
+//! Messages are newline-delimited JSON-RPC 2.0. Every registered transformer
+//! is exposed as a tool named after its ID, taking the text to transform as
+//! `input` plus one argument per transformer option. Nothing leaves the
+//! machine: tools run in-process exactly like the CLI.
+
+use super::json::{self, Json};
+use super::CliError;
+use buup::{all_transformers, transformer_from_id, Transform, TransformOption};
+use buup::{TransformOptionKind, TransformOptions};
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+/// Protocol revisions this server can speak, newest first
+const PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+// JSON-RPC error codes
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+
+type RpcError = (i32, String);
+
+/// Serves requests from stdin until it is closed
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    if let Some(arg) = args.first() {
+        return Err(CliError::Usage(format!(
+            "Unexpected argument for mcp: {}",
+            arg
+        )));
+    }
+    let mut stdout = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|e| CliError::Io(format!("Failed to read from stdin: {}", e)))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_message(&line) {
+            writeln!(stdout, "{}", response)
+                .and_then(|_| stdout.flush())
+                .map_err(|e| CliError::Io(format!("Failed to write to stdout: {}", e)))?;
+        }
+    }
+    Ok(())
+}
+
+/// Handles one JSON-RPC message, returning the response to send (if any).
+/// Notifications and stray responses from the client get no reply.
+fn handle_message(line: &str) -> Option<Json> {
+    let message = match json::parse(line) {
+        Ok(message) => message,
+        Err(e) => return Some(error_response(Json::Null, (PARSE_ERROR, e))),
+    };
+    let id = message.get("id").cloned();
+    let Some(method) = message.get("method").and_then(Json::as_str) else {
+        return id.map(|id| error_response(id, (INVALID_REQUEST, "Missing method".to_string())));
+    };
+    let empty = Json::Object(BTreeMap::new());
+    let params = message.get("params").unwrap_or(&empty);
+
+    let result = match method {
+        "initialize" => Ok(initialize(params)),
+        "ping" => Ok(Json::Object(BTreeMap::new())),
+        "tools/list" => Ok(Json::object([(
+            "tools",
+            Json::Array(sorted_transformers().into_iter().map(tool).collect()),
+        )])),
+        "tools/call" => call_tool(params),
+        _ => Err((METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+    };
+    let id = id?;
+    Some(match result {
+        Ok(result) => Json::object([("jsonrpc", "2.0".into()), ("id", id), ("result", result)]),
+        Err(error) => error_response(id, error),
+    })
+}
+
+fn error_response(id: Json, (code, message): RpcError) -> Json {
+    Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("id", id),
+        (
+            "error",
+            Json::object([
+                ("code", Json::Number(code as f64)),
+                ("message", message.into()),
+            ]),
+        ),
+    ])
+}
+
+fn initialize(params: &Json) -> Json {
+    // Agree to the client's revision when we know it, otherwise offer ours
+    let requested = params.get("protocolVersion").and_then(Json::as_str);
+    let version = PROTOCOL_VERSIONS
+        .into_iter()
+        .find(|v| Some(*v) == requested)
+        .unwrap_or(PROTOCOL_VERSIONS[0]);
+    Json::object([
+        ("protocolVersion", version.into()),
+        (
+            "capabilities",
+            Json::object([("tools", Json::object([("listChanged", false.into())]))]),
+        ),
+        (
+            "serverInfo",
+            Json::object([
+                ("name", "buup".into()),
+                ("version", env!("CARGO_PKG_VERSION").into()),
+            ]),
+        ),
+        (
+            "instructions",
+            "Each tool is a buup transformer (encoders, decoders, formatters, hashes, \
+             compression). Pass the text to transform as `input`; compressed output is Base64."
+                .into(),
+        ),
+    ])
+}
+
+fn sorted_transformers() -> Vec<&'static dyn Transform> {
+    let mut transformers = all_transformers();
+    transformers.sort_by_key(|t| t.id());
+    transformers
+}
+
+// Tool definition with a JSON schema built from the transformer's metadata
+fn tool(transformer: &dyn Transform) -> Json {
+    let mut properties = BTreeMap::new();
+    properties.insert(
+        "input".to_string(),
+        Json::object([
+            ("type", "string".into()),
+            ("description", "The text to transform".into()),
+        ]),
+    );
+    for option in transformer.options() {
+        properties.insert(option.key.to_string(), option_schema(option));
+    }
+    Json::object([
+        ("name", transformer.id().into()),
+        ("title", transformer.name().into()),
+        ("description", transformer.description().into()),
+        (
+            "inputSchema",
+            Json::object([
+                ("type", "object".into()),
+                ("properties", Json::Object(properties)),
+                ("required", Json::Array(vec!["input".into()])),
+                ("additionalProperties", false.into()),
+            ]),
+        ),
+    ])
+}
+
+fn option_schema(option: &TransformOption) -> Json {
+    let description = ("description", option.description.into());
+    match option.kind {
+        TransformOptionKind::Integer => Json::object([
+            ("type", "integer".into()),
+            ("minimum", Json::Number(0.0)),
+            description,
+            (
+                "default",
+                option
+                    .default
+                    .parse()
+                    .map(Json::Number)
+                    .unwrap_or(Json::Null),
+            ),
+        ]),
+        TransformOptionKind::Boolean => Json::object([
+            ("type", "boolean".into()),
+            description,
+            ("default", (option.default == "true").into()),
+        ]),
+        TransformOptionKind::Text => Json::object([
+            ("type", "string".into()),
+            description,
+            ("default", option.default.into()),
+        ]),
+        TransformOptionKind::Choice(choices) => Json::object([
+            ("type", "string".into()),
+            (
+                "enum",
+                Json::Array(choices.iter().map(|c| (*c).into()).collect()),
+            ),
+            description,
+            ("default", option.default.into()),
+        ]),
+    }
+}
+
+fn call_tool(params: &Json) -> Result<Json, RpcError> {
+    let invalid = |message: String| (INVALID_PARAMS, message);
+    let name = params
+        .get("name")
+        .and_then(Json::as_str)
+        .ok_or_else(|| invalid("Missing tool name".to_string()))?;
+    let transformer =
+        transformer_from_id(name).map_err(|_| invalid(format!("Unknown tool: {}", name)))?;
+
+    let mut input = None;
+    let mut options = TransformOptions::new();
+    if let Some(Json::Object(arguments)) = params.get("arguments") {
+        for (key, value) in arguments {
+            match (key.as_str(), value) {
+                ("input", Json::String(text)) => input = Some(text.as_str()),
+                ("input", _) => return Err(invalid("'input' must be a string".to_string())),
+                (_, Json::String(text)) => {
+                    options.set(key.as_str(), text.as_str());
+                }
+                (_, Json::Number(_) | Json::Bool(_)) => {
+                    options.set(key.as_str(), value.to_string());
+                }
+                _ => return Err(invalid(format!("Unsupported value for '{}'", key))),
+            }
+        }
+    }
+    let input = input.ok_or_else(|| invalid("Missing required argument 'input'".to_string()))?;
+
+    // Failures of the transform itself are tool results the model can read
+    let (text, is_error) = match transformer.transform_with_options(input, &options) {
+        Ok(output) => (output, false),
+        Err(e) => (e.to_string(), true),
+    };
+    Ok(Json::object([
+        (
+            "content",
+            Json::Array(vec![Json::object([
+                ("type", "text".into()),
+                ("text", text.into()),
+            ])]),
+        ),
+        ("isError", is_error.into()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(line: &str) -> Json {
+        handle_message(line).expect("expected a response")
+    }
+
+    #[test]
+    fn test_initialize() {
+        let response = request(
+            r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2024-11-05","capabilities":{},"clientInfo":{"name":"test","version":"1"}}}"#,
+        );
+        let result = response.get("result").unwrap();
+        assert_eq!(
+            result.get("protocolVersion").and_then(Json::as_str),
+            Some("2024-11-05")
+        );
+        assert!(result.get("capabilities").unwrap().get("tools").is_some());
+
+        // Notifications are not answered
+        assert!(
+            handle_message(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#).is_none()
+        );
+    }
+
+    #[test]
+    fn test_tools_list() {
+        let response = request(r#"{"jsonrpc":"2.0","id":"a","method":"tools/list"}"#);
+        let Some(Json::Array(tools)) = response.get("result").unwrap().get("tools") else {
+            panic!("tools/list did not return an array");
+        };
+        assert_eq!(tools.len(), all_transformers().len());
+
+        let gunzip = tools
+            .iter()
+            .find(|t| t.get("name").and_then(Json::as_str) == Some("gzipdecompress"))
+            .unwrap();
+        let properties = gunzip
+            .get("inputSchema")
+            .unwrap()
+            .get("properties")
+            .unwrap();
+        assert!(properties.get("input").is_some());
+        assert_eq!(
+            properties
+                .get("max_output")
+                .and_then(|o| o.get("type"))
+                .and_then(Json::as_str),
+            Some("integer")
+        );
+    }
+
+    #[test]
+    fn test_tools_call() {
+        let response = request(
+            r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"base64encode","arguments":{"input":"Hi"}}}"#,
+        );
+        assert_eq!(
+            response.to_string(),
+            r#"{"id":2,"jsonrpc":"2.0","result":{"content":[{"text":"SGk=","type":"text"}],"isError":false}}"#
+        );
+
+        let failed = request(
+            r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"base64decode","arguments":{"input":"***"}}}"#,
+        );
+        let result = failed.get("result").unwrap();
+        assert_eq!(result.get("isError"), Some(&Json::Bool(true)));
+
+        let unknown = request(
+            r#"{"jsonrpc":"2.0","id":4,"method":"tools/call","params":{"name":"nope","arguments":{"input":""}}}"#,
+        );
+        assert_eq!(
+            unknown.get("error").and_then(|e| e.get("code")),
+            Some(&Json::Number(INVALID_PARAMS as f64))
+        );
+    }
+
+    #[test]
+    fn test_protocol_errors() {
+        let parse_error = request("{not json");
+        assert_eq!(parse_error.get("id"), Some(&Json::Null));
+        assert_eq!(
+            parse_error.get("error").and_then(|e| e.get("code")),
+            Some(&Json::Number(PARSE_ERROR as f64))
+        );
+
+        let unknown = request(r#"{"jsonrpc":"2.0","id":5,"method":"resources/list"}"#);
+        assert_eq!(
+            unknown.get("error").and_then(|e| e.get("code")),
+            Some(&Json::Number(METHOD_NOT_FOUND as f64))
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! `--stats` reporting for the buup CLI.
+
+use buup::{Observer, TransformStats};
+
+/// Observer that prints a one-line summary of each run to stderr
+pub struct StatsObserver;
+
+impl Observer for StatsObserver {
+    fn on_finish(&self, stats: &TransformStats) {
+        eprintln!("{}", format_stats(stats));
+    }
+}
+
+fn format_stats(stats: &TransformStats) -> String {
+    let mut line = format!(
+        "{}: {} -> {}",
+        stats.transformer_id,
+        format_size(stats.input_bytes),
+        format_size(stats.output_bytes)
+    );
+    if stats.succeeded && stats.input_bytes > 0 {
+        let ratio = stats.output_bytes as f64 / stats.input_bytes as f64 * 100.0;
+        line.push_str(&format!(" ({:.1}%)", ratio));
+    }
+    if let Some(elapsed) = stats.elapsed {
+        line.push_str(&format!(" in {:.2} ms", elapsed.as_secs_f64() * 1000.0));
+    }
+    if !stats.succeeded {
+        line.push_str(" [failed]");
+    }
+    line
+}
+
+fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_stats() {
+        let stats = TransformStats {
+            transformer_id: "gzipcompress",
+            input_bytes: 2048,
+            output_bytes: 512,
+            elapsed: Some(Duration::from_micros(1500)),
+            succeeded: true,
+        };
+        assert_eq!(
+            format_stats(&stats),
+            "gzipcompress: 2.00 KiB -> 512 B (25.0%) in 1.50 ms"
+        );
+
+        let failed = TransformStats {
+            output_bytes: 0,
+            elapsed: None,
+            succeeded: false,
+            ..stats
+        };
+        assert_eq!(
+            format_stats(&failed),
+            "gzipcompress: 2.00 KiB -> 0 B [failed]"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Chunked `--binary` runs for transformers with a `StreamTransform`, so
+//! files larger than memory can be encoded or decoded.
+
+use super::{transform_error, CliError};
+use buup::{CancelToken, StreamTransform};
+use std::io::{ErrorKind, Read, Write};
+
+/// Bytes read from the input per `update` call
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Pipes everything from `reader` through `stream` into `writer`, checking
+/// `cancel` between chunks
+pub fn run(
+    stream: &mut dyn StreamTransform,
+    mut reader: impl Read,
+    mut writer: impl Write,
+    cancel: &CancelToken,
+) -> Result<(), CliError> {
+    let write_error = |e: std::io::Error| CliError::Io(format!("Failed to write output: {}", e));
+    let mut chunk = vec![0u8; CHUNK_SIZE];
+    let mut output = Vec::with_capacity(CHUNK_SIZE * 2);
+
+    loop {
+        cancel.check().map_err(transform_error)?;
+        let read = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(CliError::Io(format!("Failed to read input: {}", e))),
+        };
+        stream
+            .update(&chunk[..read], &mut output)
+            .map_err(transform_error)?;
+        writer.write_all(&output).map_err(write_error)?;
+        output.clear();
+    }
+
+    stream.finish(&mut output).map_err(transform_error)?;
+    writer.write_all(&output).map_err(write_error)?;
+    writer.flush().map_err(write_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use buup::transformer_from_id;
+
+    #[test]
+    fn test_stream_large_input() {
+        let input: Vec<u8> = (0..=255u8).cycle().take(CHUNK_SIZE * 3 + 5).collect();
+        let encoder = transformer_from_id("base64encode").unwrap();
+        let mut encoded = Vec::new();
+        run(
+            encoder.stream().unwrap().as_mut(),
+            input.as_slice(),
+            &mut encoded,
+            &CancelToken::new(),
+        )
+        .unwrap();
+        assert_eq!(encoded, encoder.transform_bytes(&input).unwrap());
+
+        let decoder = transformer_from_id("base64decode").unwrap();
+        let mut decoded = Vec::new();
+        run(
+            decoder.stream().unwrap().as_mut(),
+            encoded.as_slice(),
+            &mut decoded,
+            &CancelToken::new(),
+        )
+        .unwrap();
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let decoder = transformer_from_id("base64decode").unwrap();
+        let result = run(
+            decoder.stream().unwrap().as_mut(),
+            &b"not base64!"[..],
+            Vec::new(),
+            &CancelToken::new(),
+        );
+        assert!(matches!(result, Err(CliError::InvalidInput(_))));
+
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let result = run(
+            decoder.stream().unwrap().as_mut(),
+            &b"YQ=="[..],
+            Vec::new(),
+            &cancel,
+        );
+        assert_eq!(result, Err(CliError::Timeout));
+    }
+}
@@ -0,0 +1,103 @@
+//! `--verify`: round-trip the output through the inverse transformer.
+
+use super::CliError;
+use buup::{CancelToken, Transform, TransformError, TransformOptions, TransformerCategory};
+
+/// Checks that `inverse` undoes `transformer` for this input/output pair.
+///
+/// Encoders, compressors and the like must reproduce the input exactly.
+/// Formatter pairs (e.g. format/minify) discard layout by design, so for those
+/// the round-tripped text must instead format back to the same output.
+pub fn check_round_trip(
+    transformer: &dyn Transform,
+    inverse: &dyn Transform,
+    input: &[u8],
+    output: &[u8],
+    binary: bool,
+    cancel: &CancelToken,
+) -> Result<(), CliError> {
+    let options = TransformOptions::new().with_cancel(cancel.clone());
+    let run = |t: &dyn Transform, data: &[u8]| -> Result<Vec<u8>, CliError> {
+        let result = if binary {
+            t.transform_bytes_with_options(data, &options)
+        } else {
+            std::str::from_utf8(data)
+                .map_err(|_| TransformError::Utf8Error)
+                .and_then(|text| t.transform_with_options(text, &options))
+                .map(String::into_bytes)
+        };
+        result.map_err(|e| match e {
+            TransformError::Cancelled => CliError::Timeout,
+            e => CliError::VerifyFailed(format!(
+                "Verification failed: {} rejected the round trip: {}",
+                t.id(),
+                e
+            )),
+        })
+    };
+
+    let round_trip = run(inverse, output)?;
+    let layout_only = transformer.category() == TransformerCategory::Formatter
+        && inverse.id() != transformer.id();
+    let (expected, actual, what) = if layout_only {
+        (output.to_vec(), run(transformer, &round_trip)?, "output")
+    } else {
+        (input.to_vec(), round_trip, "input")
+    };
+
+    match first_difference(&expected, &actual) {
+        None => Ok(()),
+        Some(offset) => Err(CliError::VerifyFailed(format!(
+            "Verification failed: {} does not reproduce the {} (first difference at byte {})",
+            inverse.id(),
+            what,
+            offset
+        ))),
+    }
+}
+
+fn first_difference(expected: &[u8], actual: &[u8]) -> Option<usize> {
+    expected
+        .iter()
+        .zip(actual)
+        .position(|(e, a)| e != a)
+        .or_else(|| (expected.len() != actual.len()).then(|| expected.len().min(actual.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use buup::transformer_from_id;
+
+    fn verify(id: &str, input: &str) -> Result<(), CliError> {
+        let transformer = transformer_from_id(id).unwrap();
+        let inverse = buup::inverse_transformer(transformer).unwrap();
+        let output = transformer.transform(input).unwrap();
+        check_round_trip(
+            transformer,
+            inverse,
+            input.as_bytes(),
+            output.as_bytes(),
+            false,
+            &CancelToken::new(),
+        )
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(verify("base64encode", "Hello, world!\n").is_ok());
+        assert!(verify("jsonformatter", "{\"a\":[1,2,{\"b\":\"\\\"q\\\"\"}]}").is_ok());
+
+        // Leading zeros are lost when converting to hex and back
+        let err = verify("dec_to_hex", "007").unwrap_err();
+        assert_eq!(err.exit_code(), 6);
+        assert!(err.to_string().contains("first difference at byte 0"));
+    }
+
+    #[test]
+    fn test_first_difference() {
+        assert_eq!(first_difference(b"abc", b"abc"), None);
+        assert_eq!(first_difference(b"abc", b"abd"), Some(2));
+        assert_eq!(first_difference(b"abc", b"ab"), Some(2));
+    }
+}
@@ -0,0 +1,205 @@
+//! Guessing which transformer some text is meant for.
+//!
+//! [`detect`] looks at the shape of its input (a JWT, Base64 of gzip data,
+//! JSON, percent-encoding, ...) and suggests transformers to run next, most
+//! likely first. Every suggestion has been tried on the input and succeeds
+//! with a different output, so a frontend can offer it without second-guessing.
+
+use crate::{transformer_from_id, Transform, TransformerCategory};
+
+/// Shortest Base64 or hex text worth suggesting a decoder for
+const MIN_ENCODED_LEN: usize = 8;
+
+/// Transformers that `input` looks encoded or formatted for, most likely first
+pub fn detect(input: &str) -> Vec<&'static dyn Transform> {
+    let text = input.trim();
+    let candidates = [
+        ("jwtdecode", looks_like_jwt(text)),
+        (
+            "gzipdecompress",
+            text.starts_with("H4sI") && is_base64(text),
+        ),
+        (
+            "zstddecompress",
+            text.starts_with("KLUv/") && is_base64(text),
+        ),
+        ("ziplist", text.starts_with("UEsDB") && is_base64(text)),
+        (
+            "jsonformatter",
+            text.starts_with('{') || text.starts_with('['),
+        ),
+        ("xmlformatter", text.starts_with('<') && text.ends_with('>')),
+        ("binarydecode", is_binary(text)),
+        ("hexdecode", is_hex(text)),
+        ("base64decode", is_base64(text)),
+        ("urldecode", has_percent_escape(text)),
+        ("htmldecode", has_html_entity(text)),
+        ("morsedecode", is_morse(text)),
+    ];
+
+    let mut found: Vec<&'static dyn Transform> = Vec::new();
+    for (id, _) in candidates.iter().filter(|(_, matches)| *matches) {
+        let Ok(transformer) = transformer_from_id(id) else {
+            continue;
+        };
+        // Base64 of compressed data is also plain Base64, but only one is useful
+        if id == &"base64decode"
+            && found
+                .iter()
+                .any(|t| t.category() == TransformerCategory::Compression)
+        {
+            continue;
+        }
+        if let Ok(output) = transformer.transform(input) {
+            if output != input && is_readable(&output) {
+                found.push(transformer);
+            }
+        }
+    }
+    found
+}
+
+/// Transformers that binary `data` looks like it needs, e.g. gzip data for
+/// `gzipdecompress`. These take the Base64 form of `data` as text input.
+pub fn detect_bytes(data: &[u8]) -> Vec<&'static dyn Transform> {
+    let mut found = Vec::new();
+    if data.starts_with(&[0x1f, 0x8b]) {
+        found.extend(transformer_from_id("gzipdecompress").ok());
+    }
+    if data.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+        found.extend(transformer_from_id("zstddecompress").ok());
+    }
+    if data.starts_with(b"PK\x03\x04") {
+        found.extend(transformer_from_id("ziplist").ok());
+    }
+    found
+}
+
+// Text without control characters other than line breaks and tabs
+fn is_readable(text: &str) -> bool {
+    text.chars()
+        .all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+}
+
+fn looks_like_jwt(text: &str) -> bool {
+    let parts: Vec<&str> = text.split('.').collect();
+    parts.len() == 3
+        && text.starts_with("eyJ")
+        && parts[..2].iter().all(|part| {
+            !part.is_empty()
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        })
+}
+
+fn is_base64(text: &str) -> bool {
+    let data = text.trim_end_matches('=');
+    text.len() >= MIN_ENCODED_LEN
+        && text.len() - data.len() <= 2
+        && data
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'-' | b'_'))
+}
+
+fn is_hex(text: &str) -> bool {
+    let digits: Vec<u8> = text.bytes().filter(|b| *b != b' ').collect();
+    digits.len() >= MIN_ENCODED_LEN
+        && digits.len().is_multiple_of(2)
+        && digits.iter().all(u8::is_ascii_hexdigit)
+}
+
+fn is_binary(text: &str) -> bool {
+    text.split_whitespace().count() > 0
+        && text
+            .split_whitespace()
+            .all(|group| group.len() == 8 && group.bytes().all(|b| b == b'0' || b == b'1'))
+}
+
+fn has_percent_escape(text: &str) -> bool {
+    text.as_bytes()
+        .windows(3)
+        .any(|w| w[0] == b'%' && w[1].is_ascii_hexdigit() && w[2].is_ascii_hexdigit())
+}
+
+fn has_html_entity(text: &str) -> bool {
+    text.match_indices('&').any(|(start, _)| {
+        let rest = &text[start + 1..];
+        rest.find(';').is_some_and(|end| {
+            end > 0
+                && end <= 10
+                && rest[..end]
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'#')
+        })
+    })
+}
+
+fn is_morse(text: &str) -> bool {
+    text.len() >= 3
+        && text.contains(['.', '-'])
+        && text.chars().all(|c| matches!(c, '.' | '-' | '/' | ' '))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(found: Vec<&'static dyn Transform>) -> Vec<&'static str> {
+        found.into_iter().map(|t| t.id()).collect()
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(ids(detect("SGVsbG8sIFdvcmxkIQ==")), ["base64decode"]);
+        assert_eq!(
+            ids(detect(
+                "H4sIAAAAAAAA//NIzcnJ11Fwr8osUAjPL8pJUQQAcbBHdRIAAAA="
+            )),
+            ["gzipdecompress"]
+        );
+        assert_eq!(
+            ids(detect("KLUv/QRYkQAASGVsbG8sIFpzdGQgV29ybGQh4VILvQ==")),
+            ["zstddecompress"]
+        );
+        assert_eq!(
+            ids(detect(crate::transformers::zip_list::DEFAULT_TEST_INPUT)),
+            ["ziplist"]
+        );
+        assert_eq!(ids(detect(r#"{"a":1,"b":[true]}"#)), ["jsonformatter"]);
+        assert_eq!(ids(detect("48656c6c6f2c20576f726c6421")), ["hexdecode"]);
+        assert_eq!(ids(detect("Hello%2C%20World%21")), ["urldecode"]);
+        assert_eq!(ids(detect("Tom &amp; Jerry")), ["htmldecode"]);
+        assert_eq!(ids(detect("... --- ...")), ["morsedecode"]);
+        assert_eq!(ids(detect("01001000 01101001")), ["binarydecode"]);
+        assert_eq!(
+            ids(detect("eyJhbGciOiJub25lIn0.eyJzdWIiOiIxMjM0NTY3ODkwIn0.")),
+            ["jwtdecode"]
+        );
+    }
+
+    #[test]
+    fn test_detect_ignores_plain_text() {
+        assert!(detect("").is_empty());
+        assert!(detect("Hello, world!").is_empty());
+        assert!(detect("Tom & Jerry; friends").is_empty());
+        // Already formatted JSON has nothing left to format
+        assert!(detect("{\n  \"a\": 1\n}").is_empty());
+        // Valid Base64 characters, but decodes to bytes rather than text
+        assert!(detect("abcdefgh").is_empty());
+    }
+
+    #[test]
+    fn test_detect_bytes() {
+        assert_eq!(
+            ids(detect_bytes(&[0x1f, 0x8b, 0x08, 0x00])),
+            ["gzipdecompress"]
+        );
+        assert_eq!(
+            ids(detect_bytes(&[0x28, 0xb5, 0x2f, 0xfd, 0x04])),
+            ["zstddecompress"]
+        );
+        assert_eq!(ids(detect_bytes(b"PK\x03\x04\x14\x00")), ["ziplist"]);
+        assert!(detect_bytes(b"plain text").is_empty());
+    }
+}
@@ -0,0 +1,14 @@
+//! Curated sample inputs that show off what a transformer does.
+//!
+//! Every transformer has a single [`default_test_input`](crate::Transform::default_test_input);
+//! [`Transform::examples`](crate::Transform::examples) adds named, more realistic inputs
+//! for the web UI's "Load example" menu and for documentation.
+
+/// A named sample input for a transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    /// Short label, e.g. `Nested objects`
+    pub name: &'static str,
+    /// The input itself
+    pub input: &'static str,
+}
@@ -3,24 +3,53 @@ use std::collections::HashMap;
 use std::fmt;
 use std::sync::OnceLock;
 
+mod detect;
+mod example;
+mod locale;
+mod observer;
+mod options;
+mod pipeline;
+mod stream;
 pub mod transformers;
 pub mod utils;
 
+pub use detect::{detect, detect_bytes};
+pub use example::Example;
+pub use locale::{localized_description, localized_name, Locale};
+pub use observer::{transform_bytes_observed, transform_observed, Observer, TransformStats};
+pub use options::{TransformOption, TransformOptionKind, TransformOptions};
+pub use pipeline::Pipeline;
+pub use stream::StreamTransform;
+pub use utils::CancelToken;
+
 // Create mutable storage for registry
 static REGISTRY: OnceLock<Registry> = OnceLock::new();
 
 // Export the transformer structs for backward compatibility
 pub use transformers::{
-    AsciiToHex, Base64Decode, Base64Encode, BinToDecTransformer, BinToHexTransformer, BinaryDecode,
-    BinaryEncode, CamelToSnake, ColorCodeConvert, CsvToJson, DecToBinTransformer,
-    DecToHexTransformer, DeflateCompress, DeflateDecompress, GzipCompress, GzipDecompress,
-    HexDecode, HexEncode, HexToAscii, HexToBinTransformer, HexToDecTransformer, HexToHsl, HexToRgb,
-    HslToHex, HslToRgb, HtmlDecode, HtmlEncode, HtmlToMarkdown, JsonFormatter, JsonMinifier,
-    JsonToCsv, JwtDecode, LineNumberAdder, LineNumberRemover, LineSorter, MarkdownToHtml,
-    Md5HashTransformer, MorseDecode, MorseEncode, RgbToHex, RgbToHsl, Rot13, Sha1Hash,
-    Sha256HashTransformer, Slugify, SnakeToCamel, SqlFormatter, SqlMinifier, TextReverse,
-    TextStats, UniqueLines, UrlDecode, UrlEncode, UrlParser, Uuid5Generate, UuidGenerate,
-    WhitespaceRemover, XmlFormatter, XmlMinifier,
+    AnsiStrip, AnsiToHtml, Argon2Hash, Argon2Verify, ArrayToJsonl, AsciiToHex, Base64Decode,
+    Base64Encode, BencodeToJson, BinToDecTransformer, BinToHexTransformer, BinaryDecode,
+    BinaryEncode, CamelToSnake, CaseConvert, ColorCodeConvert, ColorMix, ColorNameToHex,
+    ColorRandom, CommentAdd, CommentRemove, CompressionAnalyze, Crc16, Crc64, CronDescribe,
+    CssFormatter, CssMinifier, CsvDelimiterConvert, CsvSelectColumns, CsvStats, CsvToHtmlTable,
+    CsvToJson, CsvToSqlInsert, DateDiff, DateParse, DecToBinTransformer, DecToHexTransformer,
+    DedentText, DeflateCompress, DeflateDecompress, DurationFormat, EndianSwap, EnvToJson,
+    FloatToHex, GzipCompress, GzipDecompress, HashVerify, HexDecode, HexEncode, HexToAscii,
+    HexToBinTransformer, HexToDecTransformer, HexToFloat, HexToHsl, HexToHsv, HexToLab,
+    HexToNearestColorName, HexToOklch, HexToRgb, HmacMd5, HmacSha1, HmacSha256, HslToHex, HslToRgb,
+    HsvToHex, HsvToRgb, HtmlDecode, HtmlEncode, HtmlFormatter, HtmlMinifier, HtmlTableToCsv,
+    HtmlToMarkdown, IndentText, IsoToTimestamp, JsFormatter, JsonFormatter, JsonMinifier,
+    JsonSortKeys, JsonToBencode, JsonToCsv, JsonToEnv, JsonToProperties, JsonToQueryString,
+    JsonlToArray, JwtDecode, KelvinToRgb, LabToHex, LabToRgb, Leetspeak, LeetspeakDecode,
+    LineEndingConvert, LineFilter, LineNumberAdder, LineNumberRemover, LineSample, LineShuffle,
+    LineSorter, Lz4Compress, Lz4Decompress, MarkdownToHtml, Md5HashTransformer, MorseDecode,
+    MorseEncode, NatoDecode, NatoEncode, OklchToHex, OklchToRgb, PasswordGenerate, PigLatinDecode,
+    PigLatinEncode, PropertiesToJson, QueryStringToJson, RgbToHex, RgbToHsl, RgbToHsv, RgbToLab,
+    RgbToOklch, Rot13, Sha1Hash, Sha256HashTransformer, Slugify, SnakeToCamel, SqlFormatter,
+    SqlMinifier, TextReverse, TextSimilarity, TextStats, TimestampToIso, UlidGenerate, UlidInspect,
+    UniqueLines, UrlDecode, UrlEncode, UrlParser, Uuid5Generate, UuidGenerate, UuidInspect,
+    UuidV7Generate, WhitespaceRemover, WordFrequency, WordWrap, XmlFormatter, XmlMinifier,
+    XorCipher, ZipExtractFile, ZipList, ZstdDecompress,
 };
 
 /// Represents a transformation error
@@ -34,6 +63,9 @@ pub enum TransformError {
     HexDecodeError(String),
     CompressionError(String),
     InvalidArgument(std::borrow::Cow<'static, str>),
+    Cancelled,
+    /// No secure random bytes could be had for a generator
+    EntropyError(String),
 }
 
 impl fmt::Display for TransformError {
@@ -49,12 +81,31 @@ impl fmt::Display for TransformError {
                 write!(f, "Compression/decompression error: {}", details)
             }
             Self::InvalidArgument(details) => write!(f, "Invalid argument: {}", details),
+            Self::Cancelled => write!(f, "Transformation cancelled"),
+            Self::EntropyError(details) => write!(f, "No secure random source: {}", details),
         }
     }
 }
 
 impl std::error::Error for TransformError {}
 
+impl TransformError {
+    /// Returns the 1-based character position in the input that the error refers to,
+    /// for parse errors that report one (e.g. "Invalid token at position 12")
+    pub fn position(&self) -> Option<usize> {
+        let details = match self {
+            Self::JsonParseError(details)
+            | Self::HexDecodeError(details)
+            | Self::CompressionError(details) => details.as_ref(),
+            Self::InvalidArgument(details) => details.as_ref(),
+            _ => return None,
+        };
+        let (_, rest) = details.split_once("position ")?;
+        let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse().ok()
+    }
+}
+
 /// Represents the category of a transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TransformerCategory {
@@ -68,8 +119,10 @@ pub enum TransformerCategory {
     Formatter,
     /// Compression transformers (e.g., lzwcompress, lzwdecompress)
     Compression,
-    /// Color transformers (e.g., hex2rgb, rgb2hsl)
+    /// Color transformers (e.g., hex_to_rgb, rgb_to_hsl)
     Color,
+    /// Generators (e.g., color_random)
+    Generator,
     /// Other transformers that don't fit into above categories
     Other,
 }
@@ -83,6 +136,7 @@ impl std::fmt::Display for TransformerCategory {
             Self::Formatter => write!(f, "formatters"),
             Self::Compression => write!(f, "compression"),
             Self::Color => write!(f, "colors"),
+            Self::Generator => write!(f, "generators"),
             Self::Other => write!(f, "others"),
         }
     }
@@ -99,6 +153,7 @@ impl std::str::FromStr for TransformerCategory {
             "formatters" => Ok(Self::Formatter),
             "compression" => Ok(Self::Compression),
             "colors" => Ok(Self::Color),
+            "generators" => Ok(Self::Generator),
             "others" => Ok(Self::Other),
             _ => Err(TransformError::UnknownTransformer),
         }
@@ -122,8 +177,111 @@ pub trait Transform: Sync + Send {
     /// Transform the input text
     fn transform(&self, input: &str) -> Result<String, TransformError>;
 
+    /// Transform raw bytes rather than text.
+    ///
+    /// The default implementation requires UTF-8 input and forwards to `transform`.
+    /// Transformers that naturally operate on binary data (compression, hashes)
+    /// override it to skip the Base64 wrapping their text interface uses, and
+    /// decoders override it because the bytes they decode needn't be UTF-8.
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let text = std::str::from_utf8(input).map_err(|_| TransformError::Utf8Error)?;
+        self.transform(text).map(String::into_bytes)
+    }
+
+    /// Options understood by `transform_with_options`
+    fn options(&self) -> &'static [TransformOption] {
+        &[]
+    }
+
+    /// Transform the input text using the given option values.
+    ///
+    /// Rejects unknown options, then runs `transform_configured` between two
+    /// checks of the options' cancellation token. Transformers with options
+    /// override that hook rather than this method.
+    fn transform_with_options(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+    ) -> Result<String, TransformError> {
+        options.validate(self.options())?;
+        let token = options.cancel_token();
+        token.check()?;
+        let output = self.transform_configured(input, options, token)?;
+        token.check()?;
+        Ok(output)
+    }
+
+    /// Byte-level counterpart of `transform_with_options`, running `transform_bytes_configured`
+    fn transform_bytes_with_options(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+    ) -> Result<Vec<u8>, TransformError> {
+        options.validate(self.options())?;
+        let token = options.cancel_token();
+        token.check()?;
+        let output = self.transform_bytes_configured(input, options, token)?;
+        token.check()?;
+        Ok(output)
+    }
+
+    /// Transform the input text with options `transform_with_options` has validated.
+    ///
+    /// The default ignores the options and forwards to `transform_with_cancel`.
+    /// Overrides poll `token` with [`CancelToken::check_every`] in their loops
+    /// over the input, since the caller only checks it before and after.
+    fn transform_configured(
+        &self,
+        input: &str,
+        _options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, token)
+    }
+
+    /// Byte-level counterpart of `transform_configured`; the default forwards to `transform_bytes`
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        _options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes(input)
+    }
+
+    /// Transform the input text, aborting with `TransformError::Cancelled` once `token` fires.
+    ///
+    /// The default implementation only checks the token before and after calling
+    /// `transform`; transformers with long running loops override it to check periodically.
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        token.check()?;
+        let output = self.transform(input)?;
+        token.check()?;
+        Ok(output)
+    }
+
     /// Provide a default input string suitable for testing the transformer.
     fn default_test_input(&self) -> &'static str;
+
+    /// `(key, value)` option pairs that `default_test_input` and `examples` run with
+    fn default_test_options(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
+    /// Curated sample inputs beyond `default_test_input`, each valid for this transformer
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+
+    /// Incremental form of `transform_bytes` for inputs too large to hold in
+    /// memory twice, or `None` (the default) if the whole input is needed
+    fn stream(&self) -> Option<Box<dyn StreamTransform>> {
+        None
+    }
 }
 
 // Static registry of transformers
@@ -131,6 +289,25 @@ struct Registry {
     transformers: HashMap<&'static str, &'static dyn Transform>,
 }
 
+impl Registry {
+    // Adds a transformer under its ID. IDs must be lowercase and unique, and
+    // debug builds catch violations as soon as the registry is built.
+    fn register(&mut self, transformer: &'static dyn Transform) {
+        let id = transformer.id();
+        debug_assert!(
+            !id.is_empty() && id == id.to_lowercase(),
+            "Transformer ID '{}' must be non-empty and lowercase",
+            id
+        );
+        let previous = self.transformers.insert(id, transformer);
+        debug_assert!(
+            previous.is_none(),
+            "Transformer ID '{}' is registered twice",
+            id
+        );
+    }
+}
+
 // Register built-in transformers
 fn register_builtin_transformers() -> Registry {
     let mut registry = Registry {
@@ -138,153 +315,181 @@ fn register_builtin_transformers() -> Registry {
     };
 
     // Register built-in transformers
-    registry
-        .transformers
-        .insert(Base64Encode.id(), &Base64Encode);
-    registry
-        .transformers
-        .insert(Base64Decode.id(), &Base64Decode);
-    registry.transformers.insert(UrlEncode.id(), &UrlEncode);
-    registry.transformers.insert(UrlDecode.id(), &UrlDecode);
-    registry.transformers.insert(TextReverse.id(), &TextReverse);
-    registry
-        .transformers
-        .insert(JsonFormatter.id(), &JsonFormatter);
-    registry
-        .transformers
-        .insert(JsonMinifier.id(), &JsonMinifier);
-    registry
-        .transformers
-        .insert(SqlFormatter.id(), &SqlFormatter);
-    registry.transformers.insert(SqlMinifier.id(), &SqlMinifier);
-    registry.transformers.insert(HexEncode.id(), &HexEncode);
-    registry.transformers.insert(HexDecode.id(), &HexDecode);
-    registry.transformers.insert(HtmlEncode.id(), &HtmlEncode);
-    registry.transformers.insert(HtmlDecode.id(), &HtmlDecode);
-    registry
-        .transformers
-        .insert(CamelToSnake.id(), &CamelToSnake);
-    registry
-        .transformers
-        .insert(SnakeToCamel.id(), &SnakeToCamel);
-    registry
-        .transformers
-        .insert(Sha256HashTransformer.id(), &Sha256HashTransformer);
-    registry
-        .transformers
-        .insert(Md5HashTransformer.id(), &Md5HashTransformer);
-    registry.transformers.insert(CsvToJson.id(), &CsvToJson);
-    registry.transformers.insert(JsonToCsv.id(), &JsonToCsv);
-    registry.transformers.insert(Rot13.id(), &Rot13);
+    registry.register(&Base64Encode);
+    registry.register(&Base64Decode);
+    registry.register(&UrlEncode);
+    registry.register(&UrlDecode);
+    registry.register(&TextReverse);
+    registry.register(&JsonFormatter);
+    registry.register(&JsonMinifier);
+    registry.register(&JsonSortKeys);
+    registry.register(&JsFormatter);
+    registry.register(&HtmlFormatter);
+    registry.register(&HtmlMinifier);
+    registry.register(&CssFormatter);
+    registry.register(&CssMinifier);
+    registry.register(&SqlFormatter);
+    registry.register(&SqlMinifier);
+    registry.register(&HexEncode);
+    registry.register(&HexDecode);
+    registry.register(&HtmlEncode);
+    registry.register(&HtmlDecode);
+    registry.register(&CamelToSnake);
+    registry.register(&SnakeToCamel);
+    registry.register(&CaseConvert);
+    registry.register(&Sha256HashTransformer);
+    registry.register(&Crc16);
+    registry.register(&Crc64);
+    registry.register(&HmacMd5);
+    registry.register(&HmacSha1);
+    registry.register(&HmacSha256);
+    registry.register(&Argon2Hash);
+    registry.register(&Argon2Verify);
+    registry.register(&XorCipher);
+    registry.register(&EndianSwap);
+    registry.register(&FloatToHex);
+    registry.register(&HexToFloat);
+    registry.register(&TimestampToIso);
+    registry.register(&IsoToTimestamp);
+    registry.register(&DateParse);
+    registry.register(&DurationFormat);
+    registry.register(&CronDescribe);
+    registry.register(&DateDiff);
+    registry.register(&HashVerify);
+    registry.register(&Md5HashTransformer);
+    registry.register(&CsvToJson);
+    registry.register(&JsonToCsv);
+    registry.register(&CsvDelimiterConvert);
+    registry.register(&CsvToHtmlTable);
+    registry.register(&HtmlTableToCsv);
+    registry.register(&CsvToSqlInsert);
+    registry.register(&CsvSelectColumns);
+    registry.register(&CsvStats);
+    registry.register(&EnvToJson);
+    registry.register(&JsonToEnv);
+    registry.register(&PropertiesToJson);
+    registry.register(&JsonToProperties);
+    registry.register(&JsonlToArray);
+    registry.register(&ArrayToJsonl);
+    registry.register(&QueryStringToJson);
+    registry.register(&JsonToQueryString);
+    registry.register(&BencodeToJson);
+    registry.register(&JsonToBencode);
+    registry.register(&AnsiStrip);
+    registry.register(&AnsiToHtml);
+    registry.register(&Rot13);
 
     // Register new base conversion transformers
-    registry
-        .transformers
-        .insert(DecToHexTransformer.id(), &DecToHexTransformer);
-    registry
-        .transformers
-        .insert(HexToDecTransformer.id(), &HexToDecTransformer);
-    registry
-        .transformers
-        .insert(DecToBinTransformer.id(), &DecToBinTransformer);
-    registry
-        .transformers
-        .insert(BinToDecTransformer.id(), &BinToDecTransformer);
-    registry
-        .transformers
-        .insert(HexToBinTransformer.id(), &HexToBinTransformer);
-    registry
-        .transformers
-        .insert(BinToHexTransformer.id(), &BinToHexTransformer);
+    registry.register(&DecToHexTransformer);
+    registry.register(&HexToDecTransformer);
+    registry.register(&DecToBinTransformer);
+    registry.register(&BinToDecTransformer);
+    registry.register(&HexToBinTransformer);
+    registry.register(&BinToHexTransformer);
 
     // Added binary transformers
-    registry
-        .transformers
-        .insert(BinaryEncode.id(), &BinaryEncode);
-    registry
-        .transformers
-        .insert(BinaryDecode.id(), &BinaryDecode);
+    registry.register(&BinaryEncode);
+    registry.register(&BinaryDecode);
 
-    registry.transformers.insert(AsciiToHex.id(), &AsciiToHex);
-    registry.transformers.insert(HexToAscii.id(), &HexToAscii);
+    registry.register(&AsciiToHex);
+    registry.register(&HexToAscii);
 
     // Register morse code transformers
-    registry.transformers.insert(MorseEncode.id(), &MorseEncode);
-    registry.transformers.insert(MorseDecode.id(), &MorseDecode);
-
-    registry
-        .transformers
-        .insert(UuidGenerate.id(), &UuidGenerate);
-    registry.transformers.insert(TextStats.id(), &TextStats);
-    registry.transformers.insert(UrlParser.id(), &UrlParser);
-    registry.transformers.insert(Slugify.id(), &Slugify);
+    registry.register(&MorseEncode);
+    registry.register(&MorseDecode);
+    registry.register(&NatoEncode);
+    registry.register(&NatoDecode);
+    registry.register(&PigLatinEncode);
+    registry.register(&PigLatinDecode);
+    registry.register(&Leetspeak);
+    registry.register(&LeetspeakDecode);
+
+    registry.register(&UuidGenerate);
+    registry.register(&TextStats);
+    registry.register(&TextSimilarity);
+    registry.register(&WordFrequency);
+    registry.register(&WordWrap);
+    registry.register(&LineEndingConvert);
+    registry.register(&IndentText);
+    registry.register(&DedentText);
+    registry.register(&CommentAdd);
+    registry.register(&CommentRemove);
+    registry.register(&LineShuffle);
+    registry.register(&LineSample);
+    registry.register(&LineFilter);
+    registry.register(&UrlParser);
+    registry.register(&Slugify);
 
     // Register new transformers
-    registry.transformers.insert(LineSorter.id(), &LineSorter);
-    registry.transformers.insert(UniqueLines.id(), &UniqueLines);
+    registry.register(&LineSorter);
+    registry.register(&UniqueLines);
 
     // Register added transformers
-    registry
-        .transformers
-        .insert(WhitespaceRemover.id(), &WhitespaceRemover);
-    registry
-        .transformers
-        .insert(LineNumberAdder.id(), &LineNumberAdder);
-    registry
-        .transformers
-        .insert(LineNumberRemover.id(), &LineNumberRemover);
+    registry.register(&WhitespaceRemover);
+    registry.register(&LineNumberAdder);
+    registry.register(&LineNumberRemover);
 
     // Add uuid5_generate
-    registry
-        .transformers
-        .insert(Uuid5Generate.id(), &Uuid5Generate);
+    registry.register(&Uuid5Generate);
 
-    registry.transformers.insert(JwtDecode.id(), &JwtDecode);
+    registry.register(&JwtDecode);
 
     // Add new Compression transformer
-    registry
-        .transformers
-        .insert(DeflateCompress.id(), &DeflateCompress);
+    registry.register(&DeflateCompress);
     // Register Decompress
-    registry
-        .transformers
-        .insert(DeflateDecompress.id(), &DeflateDecompress);
+    registry.register(&DeflateDecompress);
 
     // Register the color transformers
-    registry.transformers.insert(HexToRgb.id(), &HexToRgb);
-    registry.transformers.insert(RgbToHex.id(), &RgbToHex);
-    registry.transformers.insert(HexToHsl.id(), &HexToHsl);
-    registry.transformers.insert(HslToHex.id(), &HslToHex);
-    registry.transformers.insert(RgbToHsl.id(), &RgbToHsl);
-    registry.transformers.insert(HslToRgb.id(), &HslToRgb);
-    registry
-        .transformers
-        .insert(ColorCodeConvert.id(), &ColorCodeConvert);
+    registry.register(&HexToRgb);
+    registry.register(&RgbToHex);
+    registry.register(&HexToHsl);
+    registry.register(&HslToHex);
+    registry.register(&RgbToHsl);
+    registry.register(&HslToRgb);
+    registry.register(&HexToHsv);
+    registry.register(&HsvToHex);
+    registry.register(&RgbToHsv);
+    registry.register(&HsvToRgb);
+    registry.register(&HexToLab);
+    registry.register(&LabToHex);
+    registry.register(&RgbToLab);
+    registry.register(&LabToRgb);
+    registry.register(&HexToOklch);
+    registry.register(&OklchToHex);
+    registry.register(&RgbToOklch);
+    registry.register(&OklchToRgb);
+    registry.register(&ColorNameToHex);
+    registry.register(&HexToNearestColorName);
+    registry.register(&ColorMix);
+    registry.register(&KelvinToRgb);
+    registry.register(&ColorRandom);
+    registry.register(&PasswordGenerate);
+    registry.register(&UlidGenerate);
+    registry.register(&UlidInspect);
+    registry.register(&UuidV7Generate);
+    registry.register(&UuidInspect);
+    registry.register(&ColorCodeConvert);
 
     // Register Gzip transformers
-    registry
-        .transformers
-        .insert(GzipCompress.id(), &GzipCompress);
-    registry
-        .transformers
-        .insert(GzipDecompress.id(), &GzipDecompress);
+    registry.register(&GzipCompress);
+    registry.register(&GzipDecompress);
+    registry.register(&Lz4Compress);
+    registry.register(&Lz4Decompress);
+    registry.register(&ZstdDecompress);
+    registry.register(&ZipList);
+    registry.register(&ZipExtractFile);
+    registry.register(&CompressionAnalyze);
 
     // Register the new SHA-1 transformer
-    registry.transformers.insert(Sha1Hash.id(), &Sha1Hash);
+    registry.register(&Sha1Hash);
 
     // Register XML transformers
-    registry
-        .transformers
-        .insert(XmlFormatter.id(), &XmlFormatter);
-    registry.transformers.insert(XmlMinifier.id(), &XmlMinifier);
+    registry.register(&XmlFormatter);
+    registry.register(&XmlMinifier);
 
     // Add markdown transformers
-    registry
-        .transformers
-        .insert(MarkdownToHtml.id(), &MarkdownToHtml);
-    registry
-        .transformers
-        .insert(HtmlToMarkdown.id(), &HtmlToMarkdown);
+    registry.register(&MarkdownToHtml);
+    registry.register(&HtmlToMarkdown);
 
     registry
 }
@@ -299,15 +504,39 @@ pub fn all_transformers() -> Vec<&'static dyn Transform> {
     get_registry().transformers.values().copied().collect()
 }
 
-/// Find a transformer by its ID
+/// Former transformer IDs mapped to their current ID.
+///
+/// Renaming a transformer adds an entry here so scripts and saved links keep
+/// working. Frontends warn when an old ID is used (see [`deprecated_id`]).
+const DEPRECATED_IDS: &[(&str, &str)] = &[
+    // Spellings used by early docs before color IDs settled on `x_to_y`
+    ("hex2rgb", "hex_to_rgb"),
+    ("rgb2hex", "rgb_to_hex"),
+    ("hex2hsl", "hex_to_hsl"),
+    ("hsl2hex", "hsl_to_hex"),
+    ("rgb2hsl", "rgb_to_hsl"),
+    ("hsl2rgb", "hsl_to_rgb"),
+];
+
+/// Find a transformer by its ID (deprecated IDs resolve to their replacement)
 pub fn transformer_from_id(id: &str) -> Result<&'static dyn Transform, TransformError> {
-    get_registry()
+    let registry = get_registry();
+    registry
         .transformers
         .get(id)
+        .or_else(|| deprecated_id(id).and_then(|current| registry.transformers.get(current)))
         .copied()
         .ok_or(TransformError::UnknownTransformer)
 }
 
+/// If `id` is a deprecated transformer ID, returns the ID that replaced it
+pub fn deprecated_id(id: &str) -> Option<&'static str> {
+    DEPRECATED_IDS
+        .iter()
+        .find(|(old, _)| *old == id)
+        .map(|(_, current)| *current)
+}
+
 /// Get transformer pairs (transformer and its inverse)
 pub fn transformer_pairs() -> Vec<(&'static dyn Transform, Option<&'static dyn Transform>)> {
     all_transformers()
@@ -328,6 +557,10 @@ pub fn inverse_transformer(t: &dyn Transform) -> Option<&'static dyn Transform>
         "jsonminifier" => transformer_from_id("jsonformatter").ok(),
         "sqlformatter" => transformer_from_id("sqlminifier").ok(),
         "sqlminifier" => transformer_from_id("sqlformatter").ok(),
+        "htmlformatter" => transformer_from_id("htmlminifier").ok(),
+        "htmlminifier" => transformer_from_id("htmlformatter").ok(),
+        "cssformatter" => transformer_from_id("cssminifier").ok(),
+        "cssminifier" => transformer_from_id("cssformatter").ok(),
         "hexencode" => transformer_from_id("hexdecode").ok(),
         "hexdecode" => transformer_from_id("hexencode").ok(),
         "htmlencode" => transformer_from_id("htmldecode").ok(),
@@ -335,6 +568,7 @@ pub fn inverse_transformer(t: &dyn Transform) -> Option<&'static dyn Transform>
         "cameltosnake" => transformer_from_id("snaketocamel").ok(),
         "snaketocamel" => transformer_from_id("cameltosnake").ok(),
         "rot13" => transformer_from_id("rot13").ok(),
+        "endianswap" => transformer_from_id("endianswap").ok(),
         // Add quasi-inverses for base conversions (no direct inverse function, but conceptually paired)
         "dec_to_hex" => transformer_from_id("hex_to_dec").ok(),
         "hex_to_dec" => transformer_from_id("dec_to_hex").ok(),
@@ -342,6 +576,12 @@ pub fn inverse_transformer(t: &dyn Transform) -> Option<&'static dyn Transform>
         "bin_to_dec" => transformer_from_id("dec_to_bin").ok(),
         "hex_to_bin" => transformer_from_id("bin_to_hex").ok(),
         "bin_to_hex" => transformer_from_id("hex_to_bin").ok(),
+        // Add IEEE 754 float inverses
+        "float_to_hex" => transformer_from_id("hex_to_float").ok(),
+        "hex_to_float" => transformer_from_id("float_to_hex").ok(),
+        // Add Unix timestamp inverses
+        "timestamp_to_iso" => transformer_from_id("iso_to_timestamp").ok(),
+        "iso_to_timestamp" => transformer_from_id("timestamp_to_iso").ok(),
         // Added binary transformers
         "binaryencode" => transformer_from_id("binarydecode").ok(),
         "binarydecode" => transformer_from_id("binaryencode").ok(),
@@ -350,6 +590,15 @@ pub fn inverse_transformer(t: &dyn Transform) -> Option<&'static dyn Transform>
         // Add morse code inverses
         "morseencode" => transformer_from_id("morsedecode").ok(),
         "morsedecode" => transformer_from_id("morseencode").ok(),
+        // Add NATO phonetic alphabet inverses
+        "natoencode" => transformer_from_id("natodecode").ok(),
+        "natodecode" => transformer_from_id("natoencode").ok(),
+        // Add Pig Latin inverses
+        "piglatinencode" => transformer_from_id("piglatindecode").ok(),
+        "piglatindecode" => transformer_from_id("piglatinencode").ok(),
+        // Add leetspeak inverses
+        "leetspeak" => transformer_from_id("leetspeakdecode").ok(),
+        "leetspeakdecode" => transformer_from_id("leetspeak").ok(),
         // Add line numbering inverses
         "linenumberadder" => transformer_from_id("linenumberremover").ok(),
         "linenumberremover" => transformer_from_id("linenumberadder").ok(),
@@ -359,6 +608,8 @@ pub fn inverse_transformer(t: &dyn Transform) -> Option<&'static dyn Transform>
         // Add Gzip inverse pair
         "gzipcompress" => transformer_from_id("gzipdecompress").ok(),
         "gzipdecompress" => transformer_from_id("gzipcompress").ok(),
+        "lz4compress" => transformer_from_id("lz4decompress").ok(),
+        "lz4decompress" => transformer_from_id("lz4compress").ok(),
         // Add color transformer pairs
         "hex_to_rgb" => transformer_from_id("rgb_to_hex").ok(),
         "rgb_to_hex" => transformer_from_id("hex_to_rgb").ok(),
@@ -366,9 +617,44 @@ pub fn inverse_transformer(t: &dyn Transform) -> Option<&'static dyn Transform>
         "hsl_to_hex" => transformer_from_id("hex_to_hsl").ok(),
         "rgb_to_hsl" => transformer_from_id("hsl_to_rgb").ok(),
         "hsl_to_rgb" => transformer_from_id("rgb_to_hsl").ok(),
+        "hex_to_hsv" => transformer_from_id("hsv_to_hex").ok(),
+        "hsv_to_hex" => transformer_from_id("hex_to_hsv").ok(),
+        "rgb_to_hsv" => transformer_from_id("hsv_to_rgb").ok(),
+        "hsv_to_rgb" => transformer_from_id("rgb_to_hsv").ok(),
+        "hex_to_lab" => transformer_from_id("lab_to_hex").ok(),
+        "lab_to_hex" => transformer_from_id("hex_to_lab").ok(),
+        "rgb_to_lab" => transformer_from_id("lab_to_rgb").ok(),
+        "lab_to_rgb" => transformer_from_id("rgb_to_lab").ok(),
+        "hex_to_oklch" => transformer_from_id("oklch_to_hex").ok(),
+        "oklch_to_hex" => transformer_from_id("hex_to_oklch").ok(),
+        "rgb_to_oklch" => transformer_from_id("oklch_to_rgb").ok(),
+        "oklch_to_rgb" => transformer_from_id("rgb_to_oklch").ok(),
+        "color_name_to_hex" => transformer_from_id("hex_to_nearest_color_name").ok(),
+        "hex_to_nearest_color_name" => transformer_from_id("color_name_to_hex").ok(),
         // Add XML transformer inverses
         "xmlformatter" => transformer_from_id("xmlminifier").ok(),
         "xmlminifier" => transformer_from_id("xmlformatter").ok(),
+        // Add .env inverses
+        "envtojson" => transformer_from_id("jsontoenv").ok(),
+        "jsontoenv" => transformer_from_id("envtojson").ok(),
+        // Add Java properties inverses
+        "propertiestojson" => transformer_from_id("jsontoproperties").ok(),
+        "jsontoproperties" => transformer_from_id("propertiestojson").ok(),
+        // Add HTML table inverses
+        "csvtohtmltable" => transformer_from_id("htmltabletocsv").ok(),
+        "htmltabletocsv" => transformer_from_id("csvtohtmltable").ok(),
+        // Add JSON Lines inverses
+        "jsonltoarray" => transformer_from_id("arraytojsonl").ok(),
+        "arraytojsonl" => transformer_from_id("jsonltoarray").ok(),
+        // Add query string inverses
+        "querystringtojson" => transformer_from_id("jsontoquerystring").ok(),
+        "jsontoquerystring" => transformer_from_id("querystringtojson").ok(),
+        // Add Bencode inverses
+        "bencodetojson" => transformer_from_id("jsontobencode").ok(),
+        "jsontobencode" => transformer_from_id("bencodetojson").ok(),
+        // Add indentation inverses
+        "indent_text" => transformer_from_id("dedent_text").ok(),
+        "dedent_text" => transformer_from_id("indent_text").ok(),
         // Hashes have no inverse
         "sha1hash" => None,
         "sha256hash" => None,
@@ -391,6 +677,7 @@ pub fn categorized_transformers() -> HashMap<TransformerCategory, Vec<&'static d
     categories.insert(TransformerCategory::Crypto, Vec::new());
     categories.insert(TransformerCategory::Compression, Vec::new());
     categories.insert(TransformerCategory::Color, Vec::new());
+    categories.insert(TransformerCategory::Generator, Vec::new());
     categories.insert(TransformerCategory::Other, Vec::new());
 
     // Categorize each transformer using the category method
@@ -417,6 +704,52 @@ pub fn get_transformers_by_category(category: TransformerCategory) -> Vec<&'stat
         .unwrap_or_default()
 }
 
+/// Finds transformers whose ID, name or description contains `query`
+/// (case-insensitive), optionally restricted to a single category.
+///
+/// Results are ranked by relevance: an exact ID match first, then ID prefix
+/// matches, other ID or name matches, and finally description-only matches.
+/// Ties are ordered by ID. An empty query matches every transformer.
+pub fn search_transformers(
+    query: &str,
+    category: Option<TransformerCategory>,
+) -> Vec<&'static dyn Transform> {
+    search_transformers_localized(query, category, Locale::English)
+}
+
+/// Like [`search_transformers`], but names and descriptions also match in
+/// `locale` (English always matches too)
+pub fn search_transformers_localized(
+    query: &str,
+    category: Option<TransformerCategory>,
+    locale: Locale,
+) -> Vec<&'static dyn Transform> {
+    let query = query.trim().to_lowercase();
+    let contains = |text: &str| text.to_lowercase().contains(&query);
+    let rank = |t: &dyn Transform| -> Option<u8> {
+        let id = t.id().to_lowercase();
+        if query.is_empty() || id == query {
+            Some(0)
+        } else if id.starts_with(&query) {
+            Some(1)
+        } else if id.contains(&query) || contains(t.name()) || contains(localized_name(t, locale)) {
+            Some(2)
+        } else if contains(t.description()) || contains(localized_description(t, locale)) {
+            Some(3)
+        } else {
+            None
+        }
+    };
+
+    let mut matches: Vec<(u8, &'static dyn Transform)> = all_transformers()
+        .into_iter()
+        .filter(|t| category.is_none_or(|c| t.category() == c))
+        .filter_map(|t| rank(t).map(|r| (r, t)))
+        .collect();
+    matches.sort_by(|(ra, a), (rb, b)| ra.cmp(rb).then_with(|| a.id().cmp(b.id())));
+    matches.into_iter().map(|(_, t)| t).collect()
+}
+
 /// Determines the category of a transformer
 pub fn get_transformer_category(transformer: &dyn Transform) -> TransformerCategory {
     transformer.category()
@@ -427,6 +760,182 @@ mod tests {
     use super::*;
     use std::str::FromStr;
 
+    #[test]
+    fn test_error_position() {
+        let err = JsonFormatter.transform("{\"a\": tru}").unwrap_err();
+        assert_eq!(err.position(), Some(7));
+        assert_eq!(
+            TransformError::JsonParseError("Unexpected end of input".to_string()).position(),
+            None
+        );
+        assert_eq!(TransformError::Base64DecodeError.position(), None);
+    }
+
+    #[test]
+    fn test_search_transformers() {
+        let ids = |results: Vec<&dyn Transform>| -> Vec<&str> {
+            results.into_iter().map(|t| t.id()).collect()
+        };
+
+        let jwt = ids(search_transformers("JWT", None));
+        assert_eq!(jwt.first(), Some(&"jwtdecode"));
+
+        // Exact ID match ranks ahead of IDs that merely start with the query
+        let base64 = ids(search_transformers("base64encode", None));
+        assert_eq!(base64, vec!["base64encode"]);
+        let prefix = ids(search_transformers("hex", None));
+        assert!(
+            prefix.iter().position(|id| *id == "hexencode")
+                < prefix.iter().position(|id| *id == "ascii_to_hex")
+        );
+
+        let crypto = search_transformers("", Some(TransformerCategory::Crypto));
+        assert!(!crypto.is_empty());
+        assert!(crypto
+            .iter()
+            .all(|t| t.category() == TransformerCategory::Crypto));
+        assert_eq!(
+            search_transformers("", None).len(),
+            all_transformers().len()
+        );
+        assert!(search_transformers("no such transformer", None).is_empty());
+
+        let german = ids(search_transformers_localized(
+            "komprim",
+            None,
+            Locale::German,
+        ));
+        assert!(german.contains(&"gzipcompress"));
+        assert!(search_transformers("komprim", None).is_empty());
+    }
+
+    #[test]
+    fn test_registry_ids() {
+        let mut seen = std::collections::HashSet::new();
+        for t in all_transformers() {
+            let id = t.id();
+            assert_eq!(id, id.to_lowercase(), "ID '{}' is not lowercase", id);
+            assert!(seen.insert(id), "ID '{}' is registered twice", id);
+            assert_eq!(transformer_from_id(id).unwrap().id(), id);
+            assert!(!t.name().is_empty(), "{} has no name", id);
+            assert!(!t.description().is_empty(), "{} has no description", id);
+        }
+    }
+
+    #[test]
+    fn test_deprecated_ids() {
+        assert_eq!(deprecated_id("hex2rgb"), Some("hex_to_rgb"));
+        assert_eq!(deprecated_id("hex_to_rgb"), None);
+        assert_eq!(transformer_from_id("hex2rgb").unwrap().id(), "hex_to_rgb");
+
+        for (old, current) in DEPRECATED_IDS {
+            assert!(
+                !get_registry().transformers.contains_key(old),
+                "deprecated ID '{}' is still registered",
+                old
+            );
+            assert!(
+                get_registry().transformers.contains_key(current),
+                "deprecated ID '{}' points at unknown ID '{}'",
+                old,
+                current
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_test_inputs_transform() {
+        for t in all_transformers() {
+            let options = TransformOptions::from_iter(t.default_test_options().iter().copied());
+            if let Err(e) = t.transform_with_options(t.default_test_input(), &options) {
+                panic!("{} fails on its default test input: {}", t.id(), e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_examples_transform() {
+        for t in all_transformers() {
+            let options = TransformOptions::from_iter(t.default_test_options().iter().copied());
+            for (i, example) in t.examples().iter().enumerate() {
+                assert!(
+                    !example.name.is_empty(),
+                    "{} has an unnamed example",
+                    t.id()
+                );
+                assert!(
+                    t.examples()[..i].iter().all(|e| e.name != example.name),
+                    "{} has two examples named '{}'",
+                    t.id(),
+                    example.name
+                );
+                if let Err(e) = t.transform_with_options(example.input, &options) {
+                    panic!("{} fails on its '{}' example: {}", t.id(), example.name, e);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_streams_match_transform_bytes() {
+        for t in all_transformers() {
+            let Some(mut stream) = t.stream() else {
+                continue;
+            };
+            let input = t.default_test_input().as_bytes();
+            let expected = t.transform_bytes(input).unwrap();
+            for chunk_size in [1, 3, 7, input.len().max(1)] {
+                let mut output = Vec::new();
+                for chunk in input.chunks(chunk_size) {
+                    stream.update(chunk, &mut output).unwrap();
+                }
+                stream.finish(&mut output).unwrap();
+                assert_eq!(
+                    output,
+                    expected,
+                    "{} streamed in {} byte chunks",
+                    t.id(),
+                    chunk_size
+                );
+                stream = t.stream().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_declarations_are_symmetric() {
+        for t in all_transformers() {
+            let Some(inverse) = inverse_transformer(t) else {
+                continue;
+            };
+            assert_eq!(
+                inverse_transformer(inverse).map(|i| i.id()),
+                Some(t.id()),
+                "{} declares {} as its inverse, but not the other way round",
+                t.id(),
+                inverse.id()
+            );
+
+            // Encoders pair with decoders; every other category (and
+            // self-inverse transformers such as rot13) pairs with itself
+            let expected = match t.category() {
+                _ if inverse.id() == t.id() => t.category(),
+                TransformerCategory::Encoder => TransformerCategory::Decoder,
+                TransformerCategory::Decoder => TransformerCategory::Encoder,
+                other => other,
+            };
+            assert_eq!(
+                inverse.category(),
+                expected,
+                "{} ({}) has inverse {} in category {}",
+                t.id(),
+                t.category(),
+                inverse.id(),
+                inverse.category()
+            );
+        }
+    }
+
     #[test]
     fn test_transformer_from_id() {
         assert_eq!(
@@ -568,6 +1077,7 @@ mod tests {
         assert!(categorized.contains_key(&TransformerCategory::Formatter));
         assert!(categorized.contains_key(&TransformerCategory::Compression));
         assert!(categorized.contains_key(&TransformerCategory::Color));
+        assert!(categorized.contains_key(&TransformerCategory::Generator));
         assert!(categorized.contains_key(&TransformerCategory::Other));
 
         let encoders = categorized.get(&TransformerCategory::Encoder).unwrap();
@@ -612,6 +1122,7 @@ mod tests {
         assert_eq!(TransformerCategory::Formatter.to_string(), "formatters");
         assert_eq!(TransformerCategory::Compression.to_string(), "compression");
         assert_eq!(TransformerCategory::Color.to_string(), "colors");
+        assert_eq!(TransformerCategory::Generator.to_string(), "generators");
         assert_eq!(TransformerCategory::Other.to_string(), "others");
     }
 
@@ -641,6 +1152,10 @@ mod tests {
             TransformerCategory::from_str("colors").unwrap(),
             TransformerCategory::Color
         );
+        assert_eq!(
+            TransformerCategory::from_str("generators").unwrap(),
+            TransformerCategory::Generator
+        );
         assert_eq!(
             TransformerCategory::from_str("others").unwrap(),
             TransformerCategory::Other
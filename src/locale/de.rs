@@ -0,0 +1,153 @@
+//! German transformer names and descriptions.
+
+use super::Translation;
+
+pub(super) const TRANSLATIONS: &[Translation] = &[
+    ("ansistrip", "ANSI entfernen", "Entfernt ANSI-Escape-Sequenzen (Farben, Cursorbewegungen, Terminaltitel und Hyperlinks) aus Terminalausgaben und lässt den reinen Text übrig."),
+    ("ansitohtml", "ANSI zu HTML", "Wandelt ANSI-farbige Terminalausgaben in HTML um, jeder formatierte Abschnitt in einem <span> mit Inline-CSS. Andere Escape-Sequenzen werden entfernt."),
+    ("argon2hash", "Argon2id-Hash", "Hasht ein Passwort mit Argon2id zu einem PHC-String ($argon2id$v=19$...) für die Passwortspeicherung"),
+    ("argon2verify", "Argon2id-Prüfung", "Prüft, ob das eingegebene Passwort zum Argon2id-PHC-String in der Option hash passt"),
+    ("arraytojsonl", "Array zu JSON Lines", "Schreibt jedes Element eines JSON-Arrays als minifiziertes JSON in eine eigene Zeile (JSON Lines, NDJSON)"),
+    ("ascii_to_hex", "ASCII zu Hex", "Wandelt ASCII-Zeichen in ihre hexadezimale Darstellung um."),
+    ("base64decode", "Base64-Dekodierung", "Dekodiert Base64-Text in Klartext"),
+    ("base64encode", "Base64-Kodierung", "Kodiert Text im Base64-Format"),
+    ("bencodetojson", "Bencode zu JSON", "Dekodiert Bencode (Torrent-Dateien, Tracker-Antworten) zu JSON. Byte-Strings, die kein UTF-8 sind, werden zu {\"$hex\": \"...\"}-Objekten."),
+    ("bin_to_dec", "Binär zu Dezimal", "Wandelt Binärzahlen in Dezimalzahlen um."),
+    ("bin_to_hex", "Binär zu Hex", "Wandelt Binärzahlen in Hexadezimalzahlen um."),
+    ("binarydecode", "Binär-Dekodierung", "Dekodiert eine durch Leerzeichen getrennte Binärdarstellung zurück in Text."),
+    ("binaryencode", "Binär-Kodierung", "Kodiert Text in seine Binärdarstellung (durch Leerzeichen getrennte Bytes)."),
+    ("cameltosnake", "camelCase zu snake_case", "Wandelt camelCase oder PascalCase in snake_case um"),
+    ("case_convert", "Schreibweise umwandeln", "Wandelt jede Zeile in camelCase, PascalCase, snake_case, CONSTANT_CASE, kebab-case, Title Case, Sentence case, dot.case oder path/case um"),
+    ("color_code_convert", "Farbcode-Konverter", "Konvertiert zwischen verschiedenen Farbformaten (HEX, RGB, HSL, CMYK)"),
+    ("color_mix", "Farben mischen", "Mischt zwei Farben (eine pro Zeile) in einem Verhältnis, mit normaler, multiplizierender oder Negativ-multiplizierender Überblendung"),
+    ("color_name_to_hex", "Farbname zu Hex", "Wandelt einen CSS-Farbnamen (z. B. rebeccapurple) in seinen Hex-Code um"),
+    ("color_random", "Zufallsfarben", "Erzeugt zufällige Hex-Farben, eine pro Zeile, wahlweise pastellig, dunkel oder in einem Farbtonbereich. Ignoriert die Eingabe."),
+    ("comment_add", "Kommentare hinzufügen", "Kommentiert jede Zeile in der Syntax der gewählten Sprache aus, mit Zeilenkommentaren, wo es sie gibt, und sonst mit einem Blockkommentar um jede Zeile"),
+    ("comment_remove", "Kommentare entfernen", "Entfernt Zeilen- und Blockkommentare aus Code in der gewählten Sprache, lässt Zeichenketten unverändert und löscht Zeilen, die nur einen Kommentar enthielten"),
+    ("compressionanalyze", "Kompressionsanalyse", "Vergleicht die Ausgabegröße jedes Kompressors für die Eingabe samt Entropie, um bei der Wahl zu helfen."),
+    ("crc16", "CRC-16-Prüfsumme", "Berechnet die CRC-16-Prüfsumme der Eingabe (Variante CCITT, Kermit, XMODEM oder Modbus)"),
+    ("crc64", "CRC-64-Prüfsumme", "Berechnet die CRC-64-Prüfsumme der Eingabe (Variante ECMA-182, XZ oder ISO)"),
+    ("cron_describe", "Cron beschreiben", "Erklärt einen Cron-Ausdruck (5 Felder, oder 6 mit Sekunden zuerst) in Klartext und listet die nächsten 5 Ausführungen in UTC auf"),
+    ("cssformatter", "CSS-Formatierer", "Formatiert CSS- und SCSS-artige Stylesheets mit einem Selektor und einer Deklaration pro Zeile."),
+    ("cssminifier", "CSS-Minifizierer", "Minimiert CSS, entfernt Kommentare, unnötigen Leerraum und das letzte Semikolon jedes Blocks."),
+    ("csv_delimiter_convert", "CSV-Trennzeichen umwandeln", "Wandelt zwischen komma-, tabulator-, semikolon- und senkrechtstrichgetrennten Daten um und setzt Felder neu in Anführungszeichen, damit enthaltene Trennzeichen, Anführungszeichen und Zeilenumbrüche erhalten bleiben"),
+    ("csv_select_columns", "CSV-Spalten auswählen", "Behält nur die in der Option columns angegebenen CSV-Spalten, nach Name oder Nummer, in der angegebenen Reihenfolge"),
+    ("csv_stats", "CSV-Statistik", "Fasst jede CSV-Spalte zusammen: erkannter Typ, Anzahl leerer und Null-Werte, verschiedene Werte sowie Minimum, Maximum und Mittelwert von Zahlen"),
+    ("csvtohtmltable", "CSV zu HTML-Tabelle", "Wandelt CSV in eine HTML-<table> mit der Kopfzeile in <thead> um, maskiert Markup und macht aus Zeilenumbrüchen in Feldern <br>"),
+    ("csvtojson", "CSV zu JSON", "Wandelt CSV-Daten in das JSON-Format um"),
+    ("csvtosqlinsert", "CSV zu SQL-INSERT", "Wandelt CSV mit Kopfzeile in SQL-INSERT-Anweisungen um. Spalten, deren Werte alle Zahlen sind, bleiben ohne Anführungszeichen, leere Felder werden zu NULL."),
+    ("date_diff", "Datumsdifferenz", "Berechnet die Zeit zwischen zwei Daten oder Zeitstempeln, einer pro Zeile, in Jahren, Monaten und Tagen sowie in Tagen, Stunden und Sekunden insgesamt"),
+    ("date_parse", "Datumsparser", "Erkennt Datumsangaben in vielen Formaten (ISO 8601, RFC 2822, JJJJ/MM/TT, Twitter, Unix-, Cocoa- und FILETIME-Zeitstempel), eine pro Zeile, und schreibt jede als ISO 8601 UTC mit dem erkannten Format"),
+    ("dec_to_bin", "Dezimal zu Binär", "Wandelt Dezimalzahlen in Binärzahlen um."),
+    ("dec_to_hex", "Dezimal zu Hex", "Wandelt Dezimalzahlen in Hexadezimalzahlen um."),
+    ("dedent_text", "Einrückung entfernen", "Entfernt den führenden Leerraum, den alle nicht leeren Zeilen gemeinsam haben, oder ein angegebenes Präfix wie \"> \" oder \"// \" von jeder Zeile, die damit beginnt"),
+    ("deflatecompress", "DEFLATE-Komprimierung", "Komprimiert die Eingabe mit dem DEFLATE-Algorithmus (RFC 1951) und kodiert die Ausgabe als Base64."),
+    ("deflatedecompress", "DEFLATE-Dekomprimierung", "Dekomprimiert DEFLATE-Eingaben (RFC 1951). Erwartet Base64-Eingabe."),
+    ("duration_format", "Dauer formatieren", "Wandelt Zeitdauern, eine pro Zeile, zwischen Sekunden oder Millisekunden, lesbarer Form (2h 30m 15s), Uhrform (2:30:15) und ISO 8601 (PT2H30M15S) um"),
+    ("endianswap", "Byte-Reihenfolge tauschen", "Kehrt die Byte-Reihenfolge jedes 16-, 32- oder 64-Bit-Hexworts um, entweder pro durch Leerzeichen getrennter Gruppe (78563412) oder über einzelne Bytes (78 56 34 12)"),
+    ("envtojson", ".env zu JSON", "Wandelt eine .env-Datei in ein JSON-Objekt um, mit Anführungszeichen, Escapes, mehrzeiligen Werten, export-Präfixen und Kommentaren"),
+    ("float_to_hex", "Gleitkomma zu Hex", "Zeigt die IEEE-754-Bits einer Dezimalzahl als Hex, mit Vorzeichen, Exponent und Mantisse"),
+    ("gzipcompress", "Gzip-Komprimierung", "Komprimiert die Eingabe mit Gzip (RFC 1952) und kodiert die Ausgabe als Base64."),
+    ("gzipdecompress", "Gzip-Dekomprimierung", "Dekomprimiert Gzip-formatierte Eingaben (RFC 1952). Erwartet Base64-Eingabe."),
+    ("hashverify", "Hash-Prüfung", "Prüft einen erwarteten Hash (erste Zeile, Hex oder Base64) mit jedem unterstützten Algorithmus gegen den folgenden Text und meldet, welcher passt"),
+    ("hex_to_ascii", "Hex zu ASCII", "Dekodiert eine hexadezimale Zeichenkette in ihre ASCII-Darstellung."),
+    ("hex_to_bin", "Hex zu Binär", "Wandelt hexadezimale Eingaben in ihre Binärdarstellung (Base64-kodiert) um."),
+    ("hex_to_dec", "Hex zu Dezimal", "Wandelt Hexadezimalzahlen in ihre Dezimaldarstellung um."),
+    ("hex_to_float", "Hex zu Gleitkomma", "Liest 8 Hexziffern als f32 oder 16 als f64 (IEEE 754) und zeigt den Wert mit Vorzeichen, Exponent und Mantisse"),
+    ("hex_to_hsl", "Hex zu HSL", "Wandelt einen hexadezimalen Farbcode in das HSL-Format um"),
+    ("hex_to_hsv", "Hex zu HSV", "Wandelt einen Hex-Farbcode in das HSV-Format (HSB) um"),
+    ("hex_to_lab", "Hex zu Lab", "Wandelt einen Hex-Farbcode in CIELAB um (CSS lab(), D50)"),
+    ("hex_to_nearest_color_name", "Hex zum nächsten Farbnamen", "Findet den CSS-Farbnamen, der einer Hex-Farbe am nächsten liegt, samt Abstand (CIE76 ΔE, 0 ist exakt)"),
+    ("hex_to_oklch", "Hex zu OKLCH", "Wandelt einen Hex-Farbcode in OKLCH um (CSS oklch())"),
+    ("hex_to_rgb", "Hex zu RGB", "Wandelt einen hexadezimalen Farbcode in das RGB-Format um"),
+    ("hexdecode", "Hex-Dekodierung", "Dekodiert eine hexadezimale Zeichenkette in die ursprünglichen Bytes und interpretiert sie als UTF-8."),
+    ("hexencode", "Hex-Kodierung", "Kodiert Text in hexadezimaler Darstellung"),
+    ("hmac_md5", "HMAC-MD5", "Signiert die Eingabe mit HMAC-MD5 und der Schlüsseloption, für APIs, die es noch verwenden"),
+    ("hmac_sha1", "HMAC-SHA1", "Signiert die Eingabe mit HMAC-SHA1 und der Schlüsseloption, für APIs, die es noch verwenden"),
+    ("hmac_sha256", "HMAC-SHA256", "Signiert die Eingabe mit HMAC-SHA256 und der Schlüsseloption, z. B. um Webhook-Signaturen zu prüfen"),
+    ("hsl_to_hex", "HSL zu Hex", "Wandelt eine HSL-Farbe in das Hex-Format um"),
+    ("hsl_to_rgb", "HSL zu RGB", "Wandelt eine HSL-Farbe in das RGB-Format um"),
+    ("hsv_to_hex", "HSV zu Hex", "Wandelt eine HSV-Farbe (HSB) in das Hex-Format um"),
+    ("hsv_to_rgb", "HSV zu RGB", "Wandelt eine HSV-Farbe (HSB) in das RGB-Format um"),
+    ("htmldecode", "HTML-Dekodierung", "Dekodiert HTML-Entitäten (z. B. &lt;) zurück in Zeichen (<)."),
+    ("htmlencode", "HTML-Kodierung", "Kodiert HTML-Sonderzeichen als Entitäten (z. B. < zu &lt;)."),
+    ("htmlformatter", "HTML-Formatierer", "Formatiert HTML mit Einrückung, lässt Inline-Markup auf seiner Zeile und den Inhalt von pre, script und style unverändert."),
+    ("htmlminifier", "HTML-Minifizierer", "Minimiert HTML, entfernt Kommentare und Leerraum zwischen Blockelementen und lässt den Inhalt von pre, script und style unverändert."),
+    ("htmltabletocsv", "HTML-Tabelle zu CSV", "Extrahiert die erste HTML-<table> als CSV, eine Zeile pro <tr>, behält den Zellentext, macht aus <br> Zeilenumbrüche und füllt colspan mit leeren Zellen auf"),
+    ("htmltomarkdown", "HTML zu Markdown", "Wandelt HTML in das Markdown-Format um"),
+    ("indent_text", "Text einrücken", "Setzt ein Präfix an den Anfang jeder Zeile, standardmäßig vier Leerzeichen; leere Zeilen erhalten nur den sichtbaren Teil des Präfixes, Zitieren mit \"> \" lässt also ein einzelnes \">\" stehen"),
+    ("iso_to_timestamp", "ISO 8601 zu Zeitstempel", "Wandelt ISO-8601-Daten und -Zeiten, eine pro Zeile, in Unix-Zeitstempel um. Zeiten ohne Versatz gelten als UTC."),
+    ("jsformatter", "JavaScript-Formatierer", "Formatiert JavaScript-Code lesbar (Pretty-Print)."),
+    ("json_sort_keys", "JSON-Schlüssel sortieren", "Sortiert die Schlüssel aller JSON-Objekte in jeder Tiefe, damit Dokumente verglichen oder gehasht werden können. Schreibt optional die kanonische Form nach RFC 8785."),
+    ("jsonformatter", "JSON-Formatierer", "Formatiert eine JSON-Zeichenkette lesbar (Pretty-Print)."),
+    ("jsonltoarray", "JSON Lines zu Array", "Fasst zeilengetrenntes JSON (JSON Lines, NDJSON) zu einem einzigen JSON-Array zusammen und überspringt Leerzeilen"),
+    ("jsonminifier", "JSON-Minifizierer", "Minifiziert eine JSON-Zeichenkette und entfernt unnötige Leerzeichen."),
+    ("jsontobencode", "JSON zu Bencode", "Kodiert JSON als Bencode mit sortierten Dictionary-Schlüsseln. Nur Ganzzahlen, Strings, Arrays und Objekte; {\"$hex\": \"...\"}-Objekte werden zu rohen Byte-Strings."),
+    ("jsontocsv", "JSON zu CSV", "Wandelt ein JSON-Array von Objekten in das CSV-Format um."),
+    ("jsontoenv", "JSON zu .env", "Wandelt ein flaches JSON-Objekt in .env-Zeilen um und setzt Anführungszeichen nur bei Bedarf. Verschachtelte Arrays und Objekte werden als kompaktes JSON geschrieben."),
+    ("jsontoproperties", "JSON zu Properties", "Wandelt ein JSON-Objekt in Java-.properties-Zeilen um, wobei verschachtelte Objekte zu Punkt-Schlüsseln und Arrays zu key[0], key[1], ... abgeflacht werden"),
+    ("jsontoquerystring", "JSON zu Query-String", "Wandelt ein JSON-Objekt in einen URL-Query-String um, prozentkodiert Schlüssel und Werte und schreibt verschachtelte Objekte als key[sub]=wert"),
+    ("jwtdecode", "JWT-Dekodierer", "Dekodiert ein JSON Web Token (JWT), ohne die Signatur zu prüfen."),
+    ("kelvin_to_rgb", "Kelvin zu RGB", "Nähert die Lichtfarbe bei einer Temperatur wie 6500K an (1000K bis 40000K)"),
+    ("lab_to_hex", "Lab zu Hex", "Wandelt eine CIELAB-Farbe (CSS lab(), D50) in das Hex-Format um"),
+    ("lab_to_rgb", "Lab zu RGB", "Wandelt eine CIELAB-Farbe (CSS lab(), D50) in das RGB-Format um"),
+    ("leetspeak", "Leetspeak", "Schreibt Text in Leetspeak, entweder mit Ziffern statt Vokalen (h3ll0) oder mit Ersatz für jeden Buchstaben (|-|3|_|_0)"),
+    ("leetspeakdecode", "Leetspeak-Dekodierung", "Wandelt Leetspeak so gut wie möglich in Kleinbuchstaben zurück. Wörter nur aus Ziffern bleiben Zahlen."),
+    ("line_ending_convert", "Zeilenenden umwandeln", "Wandelt Zeilenenden zwischen LF (Unix), CRLF (Windows) und CR (klassischer Mac) um oder zeigt, welche ein Text verwendet und ob er mit einem Zeilenumbruch endet"),
+    ("line_filter", "Zeilen filtern", "Behält die Zeilen, die einen Text enthalten oder auf einen regulären Ausdruck passen, oder entfernt sie bei Umkehrung, wie grep"),
+    ("line_sample", "Zeilenstichprobe", "Behält eine zufällige Auswahl von N Zeilen oder N % davon in ihrer ursprünglichen Reihenfolge"),
+    ("line_shuffle", "Zeilen mischen", "Bringt Zeilen in eine zufällige Reihenfolge, aus der sicheren Zufallsquelle des Systems, sofern kein Seed für eine wiederholbare Reihenfolge angegeben ist"),
+    ("linenumberadder", "Zeilennummern hinzufügen", "Fügt am Anfang jeder Zeile eine Zeilennummer hinzu."),
+    ("linenumberremover", "Zeilennummern entfernen", "Entfernt Zeilennummern (und optionale Trennzeichen) am Anfang jeder Zeile."),
+    ("linesorter", "Zeilen sortieren", "Sortiert Zeilen alphabetisch."),
+    ("lz4compress", "LZ4-Komprimierung", "Komprimiert die Eingabe zu einem LZ4-Block und kodiert die Ausgabe als Base64."),
+    ("lz4decompress", "LZ4-Dekomprimierung", "Dekomprimiert einen LZ4-Block. Erwartet Base64-Eingabe."),
+    ("markdowntohtml", "Markdown zu HTML", "Wandelt Markdown-Text in das HTML-Format um"),
+    ("md5hash", "MD5-Hash", "Berechnet den MD5-Hash der Eingabe."),
+    ("morsedecode", "Morse-Dekodierung", "Dekodiert Morsecode in Text."),
+    ("morseencode", "Morse-Kodierung", "Kodiert Text als Morsecode"),
+    ("natodecode", "NATO-Alphabet dekodieren", "Wandelt Codewörter des NATO-Alphabets zurück in Text, mit / zwischen Wörtern"),
+    ("natoencode", "NATO-Alphabet kodieren", "Buchstabiert Text mit dem NATO-Alphabet (aus abc wird Alfa Bravo Charlie), mit / zwischen Wörtern"),
+    ("oklch_to_hex", "OKLCH zu Hex", "Wandelt eine OKLCH-Farbe (CSS oklch()) in das Hex-Format um"),
+    ("oklch_to_rgb", "OKLCH zu RGB", "Wandelt eine OKLCH-Farbe (CSS oklch()) in das RGB-Format um"),
+    ("password_generate", "Passwortgenerator", "Erzeugt kryptografisch zufällige Passwörter, eines pro Zeile. Die Eingabe kann die Optionen überschreiben, z. B. \"24 no-symbols\"."),
+    ("piglatindecode", "Pig-Latin-Dekodierung", "Übersetzt Pig Latin zurück ins Englische. Ohne Bindestriche ist die Trennung eine Schätzung (orldway wird zu orld, nicht world)."),
+    ("piglatinencode", "Pig-Latin-Kodierung", "Übersetzt Text in Pig Latin (hello wird ellohay, apple wird appleway) und behält Großschreibung und Satzzeichen bei"),
+    ("propertiestojson", "Properties zu JSON", "Wandelt eine Java-.properties-Datei in ein flaches JSON-Objekt um, mit = : und Leerzeichen als Trenner, Zeilenfortsetzungen und \\uXXXX-Escapes"),
+    ("querystringtojson", "Query-String zu JSON", "Wandelt einen URL-Query-String (oder eine ganze URL) in ein JSON-Objekt um. Wiederholte Schlüssel und key[] ergeben Arrays, key[sub] verschachtelte Objekte."),
+    ("rgb_to_hex", "RGB zu Hex", "Wandelt eine RGB-Farbe in das Hex-Format um"),
+    ("rgb_to_hsl", "RGB zu HSL", "Wandelt eine RGB-Farbe in das HSL-Format um"),
+    ("rgb_to_hsv", "RGB zu HSV", "Wandelt eine RGB-Farbe in das HSV-Format (HSB) um"),
+    ("rgb_to_lab", "RGB zu Lab", "Wandelt eine RGB-Farbe in CIELAB um (CSS lab(), D50)"),
+    ("rgb_to_oklch", "RGB zu OKLCH", "Wandelt eine RGB-Farbe in OKLCH um (CSS oklch())"),
+    ("rot13", "Rot13", "Wendet die ROT13-Substitutionschiffre auf den Eingabetext an."),
+    ("sha1hash", "SHA-1-Hash", "Berechnet den SHA-1-Hash des Eingabetexts (Warnung: SHA-1 ist kryptografisch schwach)"),
+    ("sha256hash", "SHA-256-Hash", "Berechnet den SHA-256-Hash des Eingabetexts"),
+    ("slugify", "Slugify", "Wandelt Text in einen URL-freundlichen Slug um (Kleinbuchstaben, Bindestriche, ohne Sonderzeichen)"),
+    ("snaketocamel", "snake_case zu camelCase", "Wandelt snake_case in camelCase um"),
+    ("sqlformatter", "SQL-Formatierer", "Formatiert SQL-Abfragen mit korrekter Einrückung und Abständen"),
+    ("sqlminifier", "SQL-Minifizierer", "Minifiziert SQL-Abfragen durch Entfernen unnötiger Leerzeichen und Formatierung"),
+    ("text_similarity", "Textähnlichkeit", "Vergleicht zwei Texte, als zwei Zeilen oder über die Option other: Levenshtein-Distanz, Ähnlichkeit in Prozent und längste gemeinsame Teilfolge"),
+    ("text_stats", "Textstatistik", "Berechnet einfache Textstatistiken (Zeilen, Wörter, Zeichen, Sätze)"),
+    ("textreverse", "Text umkehren", "Kehrt den Eingabetext um"),
+    ("timestamp_to_iso", "Zeitstempel zu ISO 8601", "Wandelt Unix-Zeitstempel, einen pro Zeile, in ISO 8601 in UTC und Ortszeit um. Sekunden, Millisekunden und Mikrosekunden werden an der Größe erkannt."),
+    ("ulid_generate", "ULID generieren", "Erzeugt ULIDs (sortierbare eindeutige IDs mit Zeitstempel), eine pro Zeile in aufsteigender Reihenfolge. Ignoriert die Eingabe."),
+    ("ulid_inspect", "ULID untersuchen", "Zeigt, wann eine ULID erzeugt wurde, und ihren Zufallsanteil"),
+    ("uniquelines", "Eindeutige Zeilen", "Entfernt doppelte Zeilen und behält die Reihenfolge des ersten Vorkommens bei."),
+    ("urldecode", "URL-Dekodierung", "Dekodiert URL-kodierten Text"),
+    ("urlencode", "URL-Kodierung", "Kodiert Text zur Verwendung in URLs"),
+    ("urlparser", "URL-Parser", "Zerlegt eine URL in ihre Bestandteile (Schema, Authority, Pfad, Query, Fragment)"),
+    ("uuid5_generate", "UUID v5 erzeugen (SHA-1, namensraumbasiert)", "Erzeugt eine UUID der Version 5 aus Namensraum und Name mit SHA-1. Eingabeformat: \"namensraum|name\". Der Namensraum kann eine UUID oder einer von dns, url, oid, x500 sein."),
+    ("uuid7_generate", "UUID erzeugen (v7)", "Erzeugt eine UUID der Version 7, die mit der aktuellen Zeit beginnt, sodass neuere UUIDs nach älteren sortiert werden. Die Eingabe wird ignoriert."),
+    ("uuid_generate", "UUID erzeugen (v4)", "Erzeugt eine zufällige UUID der Version 4. Die Eingabe wird ignoriert."),
+    ("uuid_inspect", "UUID untersuchen", "Zeigt Version und Variante einer UUID sowie Zeitstempel, Taktsequenz und Knoten zeitbasierter UUIDs"),
+    ("whitespaceremover", "Leerraum entfernen", "Entfernt sämtlichen Leerraum (Leerzeichen, Tabs, Zeilenumbrüche) aus dem Eingabetext."),
+    ("word_frequency", "Worthäufigkeit", "Zählt, wie oft jedes Wort vorkommt, und listet die Wörter vom häufigsten zum seltensten mit ihrem Anteil an der Gesamtzahl auf"),
+    ("word_wrap", "Zeilenumbruch", "Bricht Absätze auf eine Spaltenbreite neu um und behält Leerzeilen zwischen Absätzen, die Einrückung jedes Absatzes und einzelne Listeneinträge bei"),
+    ("xmlformatter", "XML-Formatierer", "Formatiert XML-Code mit korrekter Einrückung"),
+    ("xmlminifier", "XML-Minifizierer", "Komprimiert XML durch Entfernen unnötiger Leerzeichen"),
+    ("xorcipher", "XOR-Verschlüsselung", "Verknüpft die Eingabe per XOR mit einem wiederholten Schlüssel. Erneutes Anwenden mit demselben Schlüssel macht es rückgängig."),
+    ("zipextractfile", "ZIP-Datei extrahieren", "Extrahiert eine Datei aus einem ZIP-Archiv, die erste, sofern die Option file keine andere nennt. Erwartet Base64-Eingabe."),
+    ("ziplist", "ZIP-Inhalt auflisten", "Listet die Dateien eines ZIP-Archivs mit Größe und Kompressionsmethode auf. Erwartet Base64-Eingabe."),
+    ("zstddecompress", "Zstandard-Dekomprimierung", "Dekomprimiert Zstandard-Eingaben (RFC 8878) ohne Wörterbuch. Erwartet Base64-Eingabe."),
+];
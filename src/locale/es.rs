@@ -0,0 +1,153 @@
+//! Spanish transformer names and descriptions.
+
+use super::Translation;
+
+pub(super) const TRANSLATIONS: &[Translation] = &[
+    ("ansistrip", "Quitar ANSI", "Elimina las secuencias de escape ANSI (colores, movimientos del cursor, títulos de terminal e hipervínculos) de la salida de terminal, dejando el texto plano."),
+    ("ansitohtml", "ANSI a HTML", "Convierte la salida de terminal con colores ANSI en HTML, con cada tramo con estilo en un <span> con CSS en línea. Las demás secuencias de escape se eliminan."),
+    ("argon2hash", "Hash Argon2id", "Calcula el hash Argon2id de una contraseña como cadena PHC ($argon2id$v=19$...) para almacenar contraseñas"),
+    ("argon2verify", "Verificar Argon2id", "Comprueba si la contraseña de entrada coincide con la cadena PHC Argon2id de la opción hash"),
+    ("arraytojsonl", "Array a JSON Lines", "Escribe cada elemento de un array JSON como JSON minificado en su propia línea (JSON Lines, NDJSON)"),
+    ("ascii_to_hex", "ASCII a hexadecimal", "Convierte caracteres ASCII a su representación hexadecimal."),
+    ("base64decode", "Decodificar Base64", "Decodifica texto Base64 a texto plano"),
+    ("base64encode", "Codificar Base64", "Codifica texto en formato Base64"),
+    ("bencodetojson", "Bencode a JSON", "Decodifica Bencode (archivos torrent, respuestas de tracker) a JSON. Las cadenas de bytes que no son UTF-8 se convierten en objetos {\"$hex\": \"...\"}."),
+    ("bin_to_dec", "Binario a decimal", "Convierte números binarios a decimal."),
+    ("bin_to_hex", "Binario a hexadecimal", "Convierte números binarios a hexadecimal."),
+    ("binarydecode", "Decodificar binario", "Decodifica una representación binaria separada por espacios de vuelta a texto."),
+    ("binaryencode", "Codificar binario", "Codifica texto en su representación binaria (bytes separados por espacios)."),
+    ("cameltosnake", "camelCase a snake_case", "Convierte camelCase o PascalCase a snake_case"),
+    ("case_convert", "Conversor de mayúsculas", "Convierte cada línea a camelCase, PascalCase, snake_case, CONSTANT_CASE, kebab-case, Title Case, Sentence case, dot.case o path/case"),
+    ("color_code_convert", "Conversor de códigos de color", "Convierte entre distintos formatos de color (HEX, RGB, HSL, CMYK)"),
+    ("color_mix", "Mezcla de colores", "Mezcla dos colores (uno por línea) según una proporción, con fusión normal, multiplicar o trama"),
+    ("color_name_to_hex", "Nombre de color a hexadecimal", "Convierte un nombre de color CSS (p. ej. rebeccapurple) en su código hexadecimal"),
+    ("color_random", "Colores aleatorios", "Genera colores hexadecimales aleatorios, uno por línea, opcionalmente pastel, oscuros o dentro de un rango de tono. Ignora la entrada."),
+    ("comment_add", "Añadir comentarios", "Comenta cada línea con la sintaxis del lenguaje elegido, usando comentarios de línea cuando los tiene y envolviendo cada línea en un comentario de bloque cuando no"),
+    ("comment_remove", "Quitar comentarios", "Elimina los comentarios de línea y de bloque del código en el lenguaje elegido, sin tocar las cadenas y quitando las líneas que solo contenían un comentario"),
+    ("compressionanalyze", "Analizador de compresión", "Compara el tamaño de salida de cada compresor sobre la entrada, junto con su entropía, para ayudar a elegir uno."),
+    ("crc16", "Suma de comprobación CRC-16", "Calcula la suma de comprobación CRC-16 de la entrada (variante CCITT, Kermit, XMODEM o Modbus)"),
+    ("crc64", "Suma de comprobación CRC-64", "Calcula la suma de comprobación CRC-64 de la entrada (variante ECMA-182, XZ o ISO)"),
+    ("cron_describe", "Describir cron", "Explica una expresión cron (5 campos, o 6 con los segundos primero) en lenguaje llano y lista sus próximas 5 ejecuciones en UTC"),
+    ("cssformatter", "Formateador CSS", "Formatea (embellece) hojas de estilo CSS y de estilo SCSS, con un selector y una declaración por línea."),
+    ("cssminifier", "Minificador CSS", "Minifica CSS eliminando comentarios, los espacios innecesarios y el último punto y coma de cada bloque."),
+    ("csv_delimiter_convert", "Convertir delimitador CSV", "Convierte entre datos separados por comas, tabulaciones, punto y coma o barras verticales, volviendo a entrecomillar los campos para que los delimitadores, comillas y saltos de línea internos se conserven"),
+    ("csv_select_columns", "Seleccionar columnas CSV", "Conserva solo las columnas CSV indicadas en la opción columns, por nombre o número, en el orden dado"),
+    ("csv_stats", "Estadísticas CSV", "Resume cada columna CSV: tipo inferido, recuentos de vacíos y nulos, valores distintos y mínimo, máximo y media de los números"),
+    ("csvtohtmltable", "CSV a tabla HTML", "Convierte CSV en una <table> HTML con la fila de cabecera en <thead>, escapando el marcado y convirtiendo los saltos de línea de los campos en <br>"),
+    ("csvtojson", "CSV a JSON", "Convierte datos CSV a formato JSON"),
+    ("csvtosqlinsert", "CSV a INSERT de SQL", "Convierte CSV con fila de cabecera en sentencias INSERT de SQL. Las columnas cuyos valores son todos números quedan sin comillas y los campos vacíos pasan a NULL."),
+    ("date_diff", "Diferencia de fechas", "Calcula el tiempo entre dos fechas o marcas de tiempo, una por línea, en años, meses y días y en días, horas y segundos totales"),
+    ("date_parse", "Analizador de fechas", "Reconoce fechas en muchos formatos (ISO 8601, RFC 2822, AAAA/MM/DD, Twitter, marcas de tiempo Unix, Cocoa y FILETIME), una por línea, y reescribe cada una en ISO 8601 UTC indicando el formato detectado"),
+    ("dec_to_bin", "Decimal a binario", "Convierte números decimales a binario."),
+    ("dec_to_hex", "Decimal a hexadecimal", "Convierte números decimales a hexadecimal."),
+    ("dedent_text", "Quitar sangría", "Elimina el espacio inicial que comparten todas las líneas no vacías, o un prefijo dado como \"> \" o \"// \" de cada línea que empiece por él"),
+    ("deflatecompress", "Comprimir DEFLATE", "Comprime la entrada con el algoritmo DEFLATE (RFC 1951) y codifica la salida en Base64."),
+    ("deflatedecompress", "Descomprimir DEFLATE", "Descomprime una entrada DEFLATE (RFC 1951). Espera una entrada en Base64."),
+    ("duration_format", "Formato de duración", "Convierte duraciones, una por línea, entre segundos o milisegundos, forma legible (2h 30m 15s), forma de reloj (2:30:15) e ISO 8601 (PT2H30M15S)"),
+    ("endianswap", "Intercambio de endianness", "Invierte el orden de bytes de cada palabra hexadecimal de 16, 32 o 64 bits, ya sea por grupo separado por espacios (78563412) o entre bytes sueltos (78 56 34 12)"),
+    ("envtojson", ".env a JSON", "Convierte un archivo .env en un objeto JSON, con comillas, escapes, valores de varias líneas, prefijos export y comentarios"),
+    ("float_to_hex", "Flotante a hex", "Muestra los bits IEEE 754 de un número decimal en hexadecimal, con su signo, exponente y mantisa"),
+    ("gzipcompress", "Comprimir Gzip", "Comprime la entrada con Gzip (RFC 1952) y codifica la salida en Base64."),
+    ("gzipdecompress", "Descomprimir Gzip", "Descomprime una entrada en formato Gzip (RFC 1952). Espera una entrada en Base64."),
+    ("hashverify", "Verificar hash", "Comprueba un hash esperado (primera línea, hex o Base64) contra el texto que sigue con cada algoritmo compatible e indica cuál coincide"),
+    ("hex_to_ascii", "Hexadecimal a ASCII", "Decodifica una cadena hexadecimal a su representación ASCII."),
+    ("hex_to_bin", "Hexadecimal a binario", "Convierte una entrada hexadecimal a su representación binaria (codificada en Base64)."),
+    ("hex_to_dec", "Hexadecimal a decimal", "Convierte números hexadecimales a su representación decimal."),
+    ("hex_to_float", "Hex a flotante", "Lee 8 dígitos hexadecimales como f32 o 16 como f64 (IEEE 754) y muestra el valor con su signo, exponente y mantisa"),
+    ("hex_to_hsl", "Hexadecimal a HSL", "Convierte un código de color hexadecimal a formato HSL"),
+    ("hex_to_hsv", "Hexadecimal a HSV", "Convierte un código de color hexadecimal a formato HSV (HSB)"),
+    ("hex_to_lab", "Hexadecimal a Lab", "Convierte un código de color hexadecimal a CIELAB (lab() de CSS, D50)"),
+    ("hex_to_nearest_color_name", "Hexadecimal al nombre de color más cercano", "Busca el nombre de color CSS más cercano a un color hexadecimal, con su distancia (ΔE CIE76, 0 es exacto)"),
+    ("hex_to_oklch", "Hexadecimal a OKLCH", "Convierte un código de color hexadecimal a OKLCH (oklch() de CSS)"),
+    ("hex_to_rgb", "Hexadecimal a RGB", "Convierte un código de color hexadecimal a formato RGB"),
+    ("hexdecode", "Decodificar hexadecimal", "Decodifica una cadena hexadecimal a sus bytes originales y los interpreta como UTF-8."),
+    ("hexencode", "Codificar hexadecimal", "Codifica texto en representación hexadecimal"),
+    ("hmac_md5", "HMAC-MD5", "Firma la entrada con HMAC-MD5 usando la opción de clave, para las API que aún lo usan"),
+    ("hmac_sha1", "HMAC-SHA1", "Firma la entrada con HMAC-SHA1 usando la opción de clave, para las API que aún lo usan"),
+    ("hmac_sha256", "HMAC-SHA256", "Firma la entrada con HMAC-SHA256 usando la opción de clave, p. ej. para comprobar firmas de webhooks"),
+    ("hsl_to_hex", "HSL a hexadecimal", "Convierte un color HSL a formato hexadecimal"),
+    ("hsl_to_rgb", "HSL a RGB", "Convierte un color HSL a formato RGB"),
+    ("hsv_to_hex", "HSV a hexadecimal", "Convierte un color HSV (HSB) a formato hexadecimal"),
+    ("hsv_to_rgb", "HSV a RGB", "Convierte un color HSV (HSB) a formato RGB"),
+    ("htmldecode", "Decodificar HTML", "Decodifica entidades HTML (p. ej., &lt;) de vuelta a caracteres (<)."),
+    ("htmlencode", "Codificar HTML", "Codifica caracteres especiales de HTML en su entidad (p. ej., < a &lt;)."),
+    ("htmlformatter", "Formateador HTML", "Formatea (embellece) HTML, manteniendo el marcado en línea en su línea y sin tocar el contenido de pre, script y style."),
+    ("htmlminifier", "Minificador HTML", "Minifica HTML eliminando comentarios y los espacios entre elementos de bloque, sin tocar el contenido de pre, script y style."),
+    ("htmltabletocsv", "Tabla HTML a CSV", "Extrae la primera <table> HTML como CSV, una fila por <tr>, conservando el texto de las celdas, convirtiendo <br> en saltos de línea y rellenando colspan con celdas vacías"),
+    ("htmltomarkdown", "HTML a Markdown", "Convierte HTML a formato Markdown"),
+    ("indent_text", "Sangrar texto", "Añade un prefijo al inicio de cada línea, cuatro espacios por defecto; las líneas en blanco solo reciben la parte visible del prefijo, así que citar con \"> \" deja un \">\" solo"),
+    ("iso_to_timestamp", "ISO 8601 a marca de tiempo", "Convierte fechas y horas ISO 8601, una por línea, a marcas de tiempo Unix. Las horas sin desfase se toman como UTC."),
+    ("jsformatter", "Formateador JavaScript", "Formatea (embellece) código JavaScript."),
+    ("json_sort_keys", "Ordenar claves JSON", "Ordena las claves de cada objeto JSON, a cualquier profundidad, para poder comparar o calcular el hash de documentos. Opcionalmente escribe la forma canónica RFC 8785."),
+    ("jsonformatter", "Formateador JSON", "Formatea (embellece) una cadena JSON."),
+    ("jsonltoarray", "JSON Lines a array", "Reúne JSON delimitado por saltos de línea (JSON Lines, NDJSON) en un único array JSON, omitiendo las líneas en blanco"),
+    ("jsonminifier", "Minificador JSON", "Minifica una cadena JSON eliminando los espacios innecesarios."),
+    ("jsontobencode", "JSON a Bencode", "Codifica JSON como Bencode con las claves de diccionario ordenadas. Solo enteros, cadenas, arrays y objetos; los objetos {\"$hex\": \"...\"} se convierten en cadenas de bytes."),
+    ("jsontocsv", "JSON a CSV", "Convierte un array JSON de objetos a formato CSV."),
+    ("jsontoenv", "JSON a .env", "Convierte un objeto JSON plano en líneas .env, con comillas solo cuando hacen falta. Los arrays y objetos anidados se escriben como JSON compacto."),
+    ("jsontoproperties", "JSON a Properties", "Convierte un objeto JSON en líneas .properties de Java, aplanando los objetos anidados en claves con puntos y los arrays en clave[0], clave[1], ..."),
+    ("jsontoquerystring", "JSON a query string", "Convierte un objeto JSON en una query string de URL, codificando claves y valores con porcentajes y escribiendo los objetos anidados como clave[sub]=valor"),
+    ("jwtdecode", "Decodificador JWT", "Decodifica un JSON Web Token (JWT) sin verificar la firma."),
+    ("kelvin_to_rgb", "Kelvin a RGB", "Aproxima el color de la luz a una temperatura como 6500K (de 1000K a 40000K)"),
+    ("lab_to_hex", "Lab a hexadecimal", "Convierte un color CIELAB (lab() de CSS, D50) a formato hexadecimal"),
+    ("lab_to_rgb", "Lab a RGB", "Convierte un color CIELAB (lab() de CSS, D50) a formato RGB"),
+    ("leetspeak", "Leetspeak", "Escribe texto en leetspeak, cambiando vocales por dígitos (h3ll0) o reemplazando cada letra (|-|3|_|_0)"),
+    ("leetspeakdecode", "Decodificar Leetspeak", "Convierte leetspeak de vuelta en letras minúsculas lo mejor posible. Las palabras formadas solo por dígitos se mantienen como números."),
+    ("line_ending_convert", "Convertir finales de línea", "Convierte los finales de línea entre LF (Unix), CRLF (Windows) y CR (Mac clásico), o informa de cuáles usa un texto y si termina con un salto de línea"),
+    ("line_filter", "Filtrar líneas", "Conserva las líneas que contienen un texto o coinciden con una expresión regular, o las descarta si se invierte, como grep"),
+    ("line_sample", "Muestra de líneas", "Conserva una selección aleatoria de N líneas o del N% de ellas, en su orden original"),
+    ("line_shuffle", "Mezclar líneas", "Pone las líneas en un orden aleatorio, a partir de la fuente aleatoria segura del sistema salvo que se indique una semilla para un orden repetible"),
+    ("linenumberadder", "Añadir números de línea", "Añade números de línea al principio de cada línea."),
+    ("linenumberremover", "Quitar números de línea", "Elimina los números de línea (y los delimitadores opcionales) del principio de cada línea."),
+    ("linesorter", "Ordenar líneas", "Ordena las líneas alfabéticamente."),
+    ("lz4compress", "Comprimir LZ4", "Comprime la entrada en un bloque LZ4 y codifica la salida en Base64."),
+    ("lz4decompress", "Descomprimir LZ4", "Descomprime un bloque LZ4. Espera una entrada en Base64."),
+    ("markdowntohtml", "Markdown a HTML", "Convierte texto Markdown a formato HTML"),
+    ("md5hash", "Hash MD5", "Calcula el hash MD5 de la cadena de entrada."),
+    ("morsedecode", "Decodificar Morse", "Decodifica código Morse a texto."),
+    ("morseencode", "Codificar Morse", "Codifica texto en código Morse"),
+    ("natodecode", "Decodificar alfabeto OTAN", "Convierte las palabras clave del alfabeto fonético de la OTAN de nuevo en texto, con / entre palabras"),
+    ("natoencode", "Codificar alfabeto OTAN", "Deletrea el texto con el alfabeto fonético de la OTAN (abc pasa a Alfa Bravo Charlie), con / entre palabras"),
+    ("oklch_to_hex", "OKLCH a hexadecimal", "Convierte un color OKLCH (oklch() de CSS) a formato hexadecimal"),
+    ("oklch_to_rgb", "OKLCH a RGB", "Convierte un color OKLCH (oklch() de CSS) a formato RGB"),
+    ("password_generate", "Generador de contraseñas", "Genera contraseñas criptográficamente aleatorias, una por línea. La entrada puede sustituir las opciones, p. ej. \"24 no-symbols\"."),
+    ("piglatindecode", "Decodificar Pig Latin", "Traduce Pig Latin de vuelta al inglés. Sin guiones la división es una estimación (orldway se lee como orld, no world)."),
+    ("piglatinencode", "Codificar Pig Latin", "Traduce texto a Pig Latin (hello pasa a ellohay, apple a appleway), conservando mayúsculas y puntuación"),
+    ("propertiestojson", "Properties a JSON", "Convierte un archivo .properties de Java en un objeto JSON plano, con separadores = : y espacio, continuaciones de línea y escapes \\uXXXX"),
+    ("querystringtojson", "Query string a JSON", "Convierte una query string de URL (o una URL completa) en un objeto JSON. Las claves repetidas y clave[] forman arrays, clave[sub] crea objetos anidados."),
+    ("rgb_to_hex", "RGB a hexadecimal", "Convierte un color RGB a formato hexadecimal"),
+    ("rgb_to_hsl", "RGB a HSL", "Convierte un color RGB a formato HSL"),
+    ("rgb_to_hsv", "RGB a HSV", "Convierte un color RGB a formato HSV (HSB)"),
+    ("rgb_to_lab", "RGB a Lab", "Convierte un color RGB a CIELAB (lab() de CSS, D50)"),
+    ("rgb_to_oklch", "RGB a OKLCH", "Convierte un color RGB a OKLCH (oklch() de CSS)"),
+    ("rot13", "Rot13", "Aplica el cifrado de sustitución ROT13 al texto de entrada."),
+    ("sha1hash", "Hash SHA-1", "Calcula el hash SHA-1 del texto de entrada (Aviso: SHA-1 es criptográficamente débil)"),
+    ("sha256hash", "Hash SHA-256", "Calcula el hash SHA-256 del texto de entrada"),
+    ("slugify", "Slugify", "Convierte texto en un slug apto para URL (minúsculas, guiones, sin caracteres especiales)"),
+    ("snaketocamel", "snake_case a camelCase", "Convierte snake_case a camelCase"),
+    ("sqlformatter", "Formateador SQL", "Formatea consultas SQL con sangría y espaciado adecuados"),
+    ("sqlminifier", "Minificador SQL", "Minifica consultas SQL eliminando espacios y formato innecesarios"),
+    ("text_similarity", "Similitud de textos", "Compara dos textos, dados como dos líneas o con la opción other: distancia de Levenshtein, porcentaje de similitud y subsecuencia común más larga"),
+    ("text_stats", "Estadísticas de texto", "Calcula estadísticas básicas del texto (líneas, palabras, caracteres, oraciones)"),
+    ("textreverse", "Invertir texto", "Invierte el texto de entrada"),
+    ("timestamp_to_iso", "Marca de tiempo a ISO 8601", "Convierte marcas de tiempo Unix, una por línea, a ISO 8601 en UTC y hora local. Segundos, milisegundos y microsegundos se distinguen por su tamaño."),
+    ("ulid_generate", "Generar ULID", "Genera ULID (identificadores únicos ordenables con marca de tiempo), uno por línea y en orden creciente. Ignora la entrada."),
+    ("ulid_inspect", "Inspeccionar ULID", "Muestra cuándo se generó un ULID y su parte aleatoria"),
+    ("uniquelines", "Líneas únicas", "Elimina las líneas duplicadas conservando el orden de la primera aparición."),
+    ("urldecode", "Decodificar URL", "Decodifica texto codificado para URL"),
+    ("urlencode", "Codificar URL", "Codifica texto para usarlo en URLs"),
+    ("urlparser", "Analizador de URL", "Descompone una URL en sus componentes (esquema, autoridad, ruta, consulta, fragmento)"),
+    ("uuid5_generate", "Generar UUID v5 (SHA-1, basado en espacio de nombres)", "Genera un UUID versión 5 a partir de un espacio de nombres y un nombre usando SHA-1. Formato de entrada: \"espacio|nombre\". El espacio de nombres puede ser un UUID o uno de: dns, url, oid, x500."),
+    ("uuid7_generate", "Generar UUID (v7)", "Genera un UUID versión 7, que empieza con la hora actual para que los UUID más nuevos se ordenen después de los anteriores. La entrada se ignora."),
+    ("uuid_generate", "Generar UUID (v4)", "Genera un UUID aleatorio versión 4. La entrada se ignora."),
+    ("uuid_inspect", "Inspeccionar UUID", "Muestra la versión y la variante de un UUID, y la marca de tiempo, la secuencia de reloj y el nodo incluidos en los basados en tiempo"),
+    ("whitespaceremover", "Quitar espacios en blanco", "Elimina todos los espacios en blanco (espacios, tabulaciones, saltos de línea) del texto."),
+    ("word_frequency", "Frecuencia de palabras", "Cuenta cuántas veces aparece cada palabra y las lista de la más a la menos frecuente, con la proporción de cada una sobre el total"),
+    ("word_wrap", "Ajuste de línea", "Reajusta los párrafos a un ancho de columna, manteniendo las líneas en blanco entre párrafos, la sangría de cada párrafo y los elementos de lista separados"),
+    ("xmlformatter", "Formateador XML", "Formatea código XML con la sangría adecuada"),
+    ("xmlminifier", "Minificador XML", "Comprime XML eliminando los espacios innecesarios"),
+    ("xorcipher", "Cifrado XOR", "Aplica XOR a la entrada con una clave repetida. Aplicarlo de nuevo con la misma clave lo deshace."),
+    ("zipextractfile", "Extraer archivo ZIP", "Extrae un archivo de un archivo ZIP, el primero salvo que la opción file indique otro. Espera una entrada en Base64."),
+    ("ziplist", "Listar ZIP", "Lista los archivos de un archivo ZIP con sus tamaños y método de compresión. Espera una entrada en Base64."),
+    ("zstddecompress", "Descomprimir Zstandard", "Descomprime una entrada Zstandard (RFC 8878) sin diccionario. Espera una entrada en Base64."),
+];
@@ -0,0 +1,153 @@
+//! French transformer names and descriptions.
+
+use super::Translation;
+
+pub(super) const TRANSLATIONS: &[Translation] = &[
+    ("ansistrip", "Supprimer ANSI", "Supprime les séquences d'échappement ANSI (couleurs, déplacements du curseur, titres de terminal et liens) d'une sortie de terminal pour ne garder que le texte."),
+    ("ansitohtml", "ANSI vers HTML", "Convertit une sortie de terminal colorée en ANSI en HTML, chaque passage stylé étant placé dans un <span> avec du CSS en ligne. Les autres séquences d'échappement sont supprimées."),
+    ("argon2hash", "Hachage Argon2id", "Hache un mot de passe avec Argon2id en une chaîne PHC ($argon2id$v=19$...) pour le stockage des mots de passe"),
+    ("argon2verify", "Vérification Argon2id", "Vérifie si le mot de passe en entrée correspond à la chaîne PHC Argon2id de l'option hash"),
+    ("arraytojsonl", "Tableau vers JSON Lines", "Écrit chaque élément d'un tableau JSON en JSON minifié sur sa propre ligne (JSON Lines, NDJSON)"),
+    ("ascii_to_hex", "ASCII vers hexadécimal", "Convertit des caractères ASCII en leur représentation hexadécimale."),
+    ("base64decode", "Décodage Base64", "Décode un texte Base64 en texte brut"),
+    ("base64encode", "Encodage Base64", "Encode un texte au format Base64"),
+    ("bencodetojson", "Bencode vers JSON", "Décode du Bencode (fichiers torrent, réponses de tracker) en JSON. Les chaînes d'octets qui ne sont pas en UTF-8 deviennent des objets {\"$hex\": \"...\"}."),
+    ("bin_to_dec", "Binaire vers décimal", "Convertit des nombres binaires en décimal."),
+    ("bin_to_hex", "Binaire vers hexadécimal", "Convertit des nombres binaires en hexadécimal."),
+    ("binarydecode", "Décodage binaire", "Décode une représentation binaire séparée par des espaces en texte."),
+    ("binaryencode", "Encodage binaire", "Encode un texte en sa représentation binaire (octets séparés par des espaces)."),
+    ("cameltosnake", "camelCase vers snake_case", "Convertit du camelCase ou PascalCase en snake_case"),
+    ("case_convert", "Convertisseur de casse", "Convertit chaque ligne en camelCase, PascalCase, snake_case, CONSTANT_CASE, kebab-case, Title Case, Sentence case, dot.case ou path/case"),
+    ("color_code_convert", "Convertisseur de codes couleur", "Convertit entre différents formats de couleur (HEX, RGB, HSL, CMYK)"),
+    ("color_mix", "Mélange de couleurs", "Mélange deux couleurs (une par ligne) selon un ratio, avec une fusion normale, produit ou superposition écran"),
+    ("color_name_to_hex", "Nom de couleur vers hexadécimal", "Convertit un nom de couleur CSS (par ex. rebeccapurple) en son code hexadécimal"),
+    ("color_random", "Couleurs aléatoires", "Génère des couleurs hexadécimales aléatoires, une par ligne, éventuellement pastel, sombres ou dans une plage de teinte. Ignore l'entrée."),
+    ("comment_add", "Ajouter des commentaires", "Met chaque ligne en commentaire avec la syntaxe du langage choisi, en utilisant des commentaires de ligne quand il en a et en entourant chaque ligne d'un commentaire de bloc sinon"),
+    ("comment_remove", "Supprimer les commentaires", "Supprime les commentaires de ligne et de bloc du code dans le langage choisi, sans toucher aux chaînes, et retire les lignes qui ne contenaient qu'un commentaire"),
+    ("compressionanalyze", "Analyseur de compression", "Compare la taille de sortie de chaque compresseur sur l'entrée, avec son entropie, pour aider à en choisir un."),
+    ("crc16", "Somme de contrôle CRC-16", "Calcule la somme de contrôle CRC-16 de l'entrée (variante CCITT, Kermit, XMODEM ou Modbus)"),
+    ("crc64", "Somme de contrôle CRC-64", "Calcule la somme de contrôle CRC-64 de l'entrée (variante ECMA-182, XZ ou ISO)"),
+    ("cron_describe", "Décrire cron", "Explique une expression cron (5 champs, ou 6 avec les secondes en premier) en langage clair et liste ses 5 prochaines exécutions en UTC"),
+    ("cssformatter", "Formateur CSS", "Formate (indente) les feuilles de style CSS et de type SCSS, avec un sélecteur et une déclaration par ligne."),
+    ("cssminifier", "Minificateur CSS", "Minifie le CSS en supprimant les commentaires, les espaces inutiles et le dernier point-virgule de chaque bloc."),
+    ("csv_delimiter_convert", "Convertir le délimiteur CSV", "Convertit entre données séparées par virgules, tabulations, points-virgules ou barres verticales, en remettant les guillemets pour préserver les délimiteurs, guillemets et sauts de ligne internes"),
+    ("csv_select_columns", "Sélectionner des colonnes CSV", "Garde uniquement les colonnes CSV indiquées dans l'option columns, par nom ou numéro, dans l'ordre donné"),
+    ("csv_stats", "Statistiques CSV", "Résume chaque colonne CSV : type déduit, nombre de valeurs vides et nulles, valeurs distinctes, et minimum, maximum et moyenne des nombres"),
+    ("csvtohtmltable", "CSV vers tableau HTML", "Convertit du CSV en <table> HTML avec la ligne d'en-tête dans <thead>, en échappant le balisage et en changeant les sauts de ligne des champs en <br>"),
+    ("csvtojson", "CSV vers JSON", "Convertit des données CSV au format JSON"),
+    ("csvtosqlinsert", "CSV vers INSERT SQL", "Transforme du CSV avec une ligne d'en-tête en instructions INSERT SQL. Les colonnes dont toutes les valeurs sont des nombres restent sans guillemets, les champs vides deviennent NULL."),
+    ("date_diff", "Différence de dates", "Calcule le temps entre deux dates ou horodatages, un par ligne, en années, mois et jours et en jours, heures et secondes au total"),
+    ("date_parse", "Analyseur de dates", "Reconnaît des dates dans de nombreux formats (ISO 8601, RFC 2822, AAAA/MM/JJ, Twitter, horodatages Unix, Cocoa et FILETIME), une par ligne, et réécrit chacune en ISO 8601 UTC avec le format détecté"),
+    ("dec_to_bin", "Décimal vers binaire", "Convertit des nombres décimaux en binaire."),
+    ("dec_to_hex", "Décimal vers hexadécimal", "Convertit des nombres décimaux en hexadécimal."),
+    ("dedent_text", "Désindenter le texte", "Supprime l'espacement initial commun à toutes les lignes non vides, ou un préfixe donné comme « > » ou « // » de chaque ligne qui commence par lui"),
+    ("deflatecompress", "Compression DEFLATE", "Compresse l'entrée avec l'algorithme DEFLATE (RFC 1951) et encode la sortie en Base64."),
+    ("deflatedecompress", "Décompression DEFLATE", "Décompresse une entrée DEFLATE (RFC 1951). Attend une entrée en Base64."),
+    ("duration_format", "Format de durée", "Convertit des durées, une par ligne, entre secondes ou millisecondes, forme lisible (2h 30m 15s), forme horloge (2:30:15) et ISO 8601 (PT2H30M15S)"),
+    ("endianswap", "Inversion d'endianness", "Inverse l'ordre des octets de chaque mot hexadécimal de 16, 32 ou 64 bits, par groupe séparé par des espaces (78563412) ou sur des octets isolés (78 56 34 12)"),
+    ("envtojson", ".env vers JSON", "Convertit un fichier .env en objet JSON, en gérant les guillemets, les échappements, les valeurs sur plusieurs lignes, les préfixes export et les commentaires"),
+    ("float_to_hex", "Flottant vers hex", "Affiche les bits IEEE 754 d'un nombre décimal en hexadécimal, avec son signe, son exposant et sa mantisse"),
+    ("gzipcompress", "Compression Gzip", "Compresse l'entrée avec Gzip (RFC 1952) et encode la sortie en Base64."),
+    ("gzipdecompress", "Décompression Gzip", "Décompresse une entrée au format Gzip (RFC 1952). Attend une entrée en Base64."),
+    ("hashverify", "Vérification de hachage", "Compare un hachage attendu (première ligne, hex ou Base64) au texte qui suit avec chaque algorithme pris en charge et indique lequel correspond"),
+    ("hex_to_ascii", "Hexadécimal vers ASCII", "Décode une chaîne hexadécimale en sa représentation ASCII."),
+    ("hex_to_bin", "Hexadécimal vers binaire", "Convertit une entrée hexadécimale en sa représentation binaire (encodée en Base64)."),
+    ("hex_to_dec", "Hexadécimal vers décimal", "Convertit des nombres hexadécimaux en leur représentation décimale."),
+    ("hex_to_float", "Hex vers flottant", "Lit 8 chiffres hexadécimaux comme un f32 ou 16 comme un f64 (IEEE 754) et affiche la valeur avec son signe, son exposant et sa mantisse"),
+    ("hex_to_hsl", "Hexadécimal vers HSL", "Convertit un code couleur hexadécimal au format HSL"),
+    ("hex_to_hsv", "Hexadécimal vers HSV", "Convertit un code couleur hexadécimal au format HSV (HSB)"),
+    ("hex_to_lab", "Hexadécimal vers Lab", "Convertit un code couleur hexadécimal en CIELAB (lab() de CSS, D50)"),
+    ("hex_to_nearest_color_name", "Hexadécimal vers nom de couleur le plus proche", "Trouve le nom de couleur CSS le plus proche d'une couleur hexadécimale, avec sa distance (ΔE CIE76, 0 signifie exact)"),
+    ("hex_to_oklch", "Hexadécimal vers OKLCH", "Convertit un code couleur hexadécimal en OKLCH (oklch() de CSS)"),
+    ("hex_to_rgb", "Hexadécimal vers RGB", "Convertit un code couleur hexadécimal au format RGB"),
+    ("hexdecode", "Décodage hexadécimal", "Décode une chaîne hexadécimale en ses octets d'origine, puis les interprète en UTF-8."),
+    ("hexencode", "Encodage hexadécimal", "Encode un texte en représentation hexadécimale"),
+    ("hmac_md5", "HMAC-MD5", "Signe l'entrée avec HMAC-MD5 à l'aide de l'option de clé, pour les API qui l'utilisent encore"),
+    ("hmac_sha1", "HMAC-SHA1", "Signe l'entrée avec HMAC-SHA1 à l'aide de l'option de clé, pour les API qui l'utilisent encore"),
+    ("hmac_sha256", "HMAC-SHA256", "Signe l'entrée avec HMAC-SHA256 à l'aide de l'option de clé, p. ex. pour vérifier les signatures de webhooks"),
+    ("hsl_to_hex", "HSL vers hexadécimal", "Convertit une couleur HSL au format hexadécimal"),
+    ("hsl_to_rgb", "HSL vers RGB", "Convertit une couleur HSL au format RGB"),
+    ("hsv_to_hex", "HSV vers hexadécimal", "Convertit une couleur HSV (HSB) au format hexadécimal"),
+    ("hsv_to_rgb", "HSV vers RGB", "Convertit une couleur HSV (HSB) au format RGB"),
+    ("htmldecode", "Décodage HTML", "Décode les entités HTML (p. ex. &lt;) en caractères (<)."),
+    ("htmlencode", "Encodage HTML", "Encode les caractères spéciaux HTML en entités (p. ex. < en &lt;)."),
+    ("htmlformatter", "Formateur HTML", "Formate (indente) le HTML, en gardant le balisage en ligne sur sa ligne et sans toucher au contenu de pre, script et style."),
+    ("htmlminifier", "Minificateur HTML", "Minifie le HTML en supprimant les commentaires et les espaces entre éléments de bloc, sans toucher au contenu de pre, script et style."),
+    ("htmltabletocsv", "Tableau HTML vers CSV", "Extrait la première <table> HTML en CSV, une ligne par <tr>, en gardant le texte des cellules, en changeant <br> en sauts de ligne et en complétant colspan par des cellules vides"),
+    ("htmltomarkdown", "HTML vers Markdown", "Convertit du HTML au format Markdown"),
+    ("indent_text", "Indenter le texte", "Ajoute un préfixe au début de chaque ligne, quatre espaces par défaut ; les lignes vides ne reçoivent que la partie visible du préfixe, donc citer avec « > » laisse un « > » seul"),
+    ("iso_to_timestamp", "ISO 8601 vers horodatage", "Convertit des dates et heures ISO 8601, une par ligne, en horodatages Unix. Les heures sans décalage sont considérées en UTC."),
+    ("jsformatter", "Formateur JavaScript", "Met en forme (indente) du code JavaScript."),
+    ("json_sort_keys", "Trier les clés JSON", "Trie les clés de chaque objet JSON, à toute profondeur, pour comparer ou hacher des documents. Peut aussi écrire la forme canonique RFC 8785."),
+    ("jsonformatter", "Formateur JSON", "Met en forme (indente) une chaîne JSON."),
+    ("jsonltoarray", "JSON Lines vers tableau", "Rassemble du JSON délimité par des sauts de ligne (JSON Lines, NDJSON) en un seul tableau JSON, en ignorant les lignes vides"),
+    ("jsonminifier", "Minificateur JSON", "Minifie une chaîne JSON en supprimant les espaces inutiles."),
+    ("jsontobencode", "JSON vers Bencode", "Encode du JSON en Bencode avec les clés de dictionnaire triées. Entiers, chaînes, tableaux et objets uniquement ; les objets {\"$hex\": \"...\"} deviennent des chaînes d'octets brutes."),
+    ("jsontocsv", "JSON vers CSV", "Convertit un tableau JSON d'objets au format CSV."),
+    ("jsontoenv", "JSON vers .env", "Convertit un objet JSON plat en lignes .env, avec des guillemets seulement si nécessaire. Les tableaux et objets imbriqués sont écrits en JSON compact."),
+    ("jsontoproperties", "JSON vers Properties", "Convertit un objet JSON en lignes .properties Java, en aplatissant les objets imbriqués en clés pointées et les tableaux en clé[0], clé[1], ..."),
+    ("jsontoquerystring", "JSON vers query string", "Convertit un objet JSON en query string d'URL, en encodant clés et valeurs en pourcentage et en écrivant les objets imbriqués sous la forme clé[sous]=valeur"),
+    ("jwtdecode", "Décodeur JWT", "Décode un JSON Web Token (JWT) sans vérifier la signature."),
+    ("kelvin_to_rgb", "Kelvin vers RGB", "Approxime la couleur de la lumière à une température comme 6500K (de 1000K à 40000K)"),
+    ("lab_to_hex", "Lab vers hexadécimal", "Convertit une couleur CIELAB (lab() de CSS, D50) au format hexadécimal"),
+    ("lab_to_rgb", "Lab vers RGB", "Convertit une couleur CIELAB (lab() de CSS, D50) au format RGB"),
+    ("leetspeak", "Leetspeak", "Écrit le texte en leetspeak, en remplaçant les voyelles par des chiffres (h3ll0) ou chaque lettre (|-|3|_|_0)"),
+    ("leetspeakdecode", "Décodage Leetspeak", "Retransforme au mieux le leetspeak en lettres minuscules. Les mots composés uniquement de chiffres restent des nombres."),
+    ("line_ending_convert", "Convertir les fins de ligne", "Convertit les fins de ligne entre LF (Unix), CRLF (Windows) et CR (Mac classique), ou indique celles qu'utilise un texte et s'il se termine par un saut de ligne"),
+    ("line_filter", "Filtrer les lignes", "Conserve les lignes contenant un texte ou correspondant à une expression régulière, ou les retire si inversé, comme grep"),
+    ("line_sample", "Échantillon de lignes", "Conserve une sélection aléatoire de N lignes ou de N % d'entre elles, dans leur ordre d'origine"),
+    ("line_shuffle", "Mélanger les lignes", "Met les lignes dans un ordre aléatoire, à partir de la source aléatoire sûre du système sauf si une graine est donnée pour un ordre reproductible"),
+    ("linenumberadder", "Ajout de numéros de ligne", "Ajoute des numéros de ligne au début de chaque ligne."),
+    ("linenumberremover", "Suppression des numéros de ligne", "Supprime les numéros de ligne (et les délimiteurs éventuels) au début de chaque ligne."),
+    ("linesorter", "Tri des lignes", "Trie les lignes par ordre alphabétique."),
+    ("lz4compress", "Compression LZ4", "Compresse l'entrée en un bloc LZ4 et encode la sortie en Base64."),
+    ("lz4decompress", "Décompression LZ4", "Décompresse un bloc LZ4. Attend une entrée en Base64."),
+    ("markdowntohtml", "Markdown vers HTML", "Convertit un texte Markdown au format HTML"),
+    ("md5hash", "Empreinte MD5", "Calcule l'empreinte MD5 de la chaîne d'entrée."),
+    ("morsedecode", "Décodage Morse", "Décode du code Morse en texte."),
+    ("morseencode", "Encodage Morse", "Encode un texte en code Morse"),
+    ("natodecode", "Décodage alphabet OTAN", "Reconvertit les mots de code de l'alphabet phonétique de l'OTAN en texte, avec / entre les mots"),
+    ("natoencode", "Encodage alphabet OTAN", "Épelle le texte avec l'alphabet phonétique de l'OTAN (abc devient Alfa Bravo Charlie), avec / entre les mots"),
+    ("oklch_to_hex", "OKLCH vers hexadécimal", "Convertit une couleur OKLCH (oklch() de CSS) au format hexadécimal"),
+    ("oklch_to_rgb", "OKLCH vers RGB", "Convertit une couleur OKLCH (oklch() de CSS) au format RGB"),
+    ("password_generate", "Générateur de mots de passe", "Génère des mots de passe cryptographiquement aléatoires, un par ligne. L'entrée peut remplacer les options, p. ex. \"24 no-symbols\"."),
+    ("piglatindecode", "Décodage Pig Latin", "Retraduit le Pig Latin en anglais. Sans traits d'union, le découpage est une estimation (orldway se lit orld, pas world)."),
+    ("piglatinencode", "Encodage Pig Latin", "Traduit le texte en Pig Latin (hello devient ellohay, apple devient appleway), en conservant majuscules et ponctuation"),
+    ("propertiestojson", "Properties vers JSON", "Convertit un fichier .properties Java en objet JSON plat, en gérant les séparateurs = : et espace, les continuations de ligne et les échappements \\uXXXX"),
+    ("querystringtojson", "Query string vers JSON", "Convertit une query string d'URL (ou une URL entière) en objet JSON. Les clés répétées et clé[] forment des tableaux, clé[sous] crée des objets imbriqués."),
+    ("rgb_to_hex", "RGB vers hexadécimal", "Convertit une couleur RGB au format hexadécimal"),
+    ("rgb_to_hsl", "RGB vers HSL", "Convertit une couleur RGB au format HSL"),
+    ("rgb_to_hsv", "RGB vers HSV", "Convertit une couleur RGB au format HSV (HSB)"),
+    ("rgb_to_lab", "RGB vers Lab", "Convertit une couleur RGB en CIELAB (lab() de CSS, D50)"),
+    ("rgb_to_oklch", "RGB vers OKLCH", "Convertit une couleur RGB en OKLCH (oklch() de CSS)"),
+    ("rot13", "Rot13", "Applique le chiffrement par substitution ROT13 au texte d'entrée."),
+    ("sha1hash", "Empreinte SHA-1", "Calcule l'empreinte SHA-1 du texte d'entrée (Attention : SHA-1 est cryptographiquement faible)"),
+    ("sha256hash", "Empreinte SHA-256", "Calcule l'empreinte SHA-256 du texte d'entrée"),
+    ("slugify", "Slugify", "Convertit un texte en slug adapté aux URL (minuscules, tirets, sans caractères spéciaux)"),
+    ("snaketocamel", "snake_case vers camelCase", "Convertit du snake_case en camelCase"),
+    ("sqlformatter", "Formateur SQL", "Met en forme les requêtes SQL avec une indentation et un espacement corrects"),
+    ("sqlminifier", "Minificateur SQL", "Minifie les requêtes SQL en supprimant les espaces et la mise en forme inutiles"),
+    ("text_similarity", "Similarité de textes", "Compare deux textes, donnés sur deux lignes ou via l'option other : distance de Levenshtein, pourcentage de similarité et plus longue sous-séquence commune"),
+    ("text_stats", "Statistiques du texte", "Calcule des statistiques de base sur le texte (lignes, mots, caractères, phrases)"),
+    ("textreverse", "Inversion du texte", "Inverse le texte d'entrée"),
+    ("timestamp_to_iso", "Horodatage vers ISO 8601", "Convertit des horodatages Unix, un par ligne, en ISO 8601 en UTC et en heure locale. Secondes, millisecondes et microsecondes sont distinguées par leur taille."),
+    ("ulid_generate", "Générer des ULID", "Génère des ULID (identifiants uniques triables et horodatés), un par ligne et dans l'ordre croissant. Ignore l'entrée."),
+    ("ulid_inspect", "Inspecter un ULID", "Indique quand un ULID a été généré et sa partie aléatoire"),
+    ("uniquelines", "Lignes uniques", "Supprime les lignes en double en conservant l'ordre de première apparition."),
+    ("urldecode", "Décodage d'URL", "Décode un texte encodé pour les URL"),
+    ("urlencode", "Encodage d'URL", "Encode un texte pour l'utiliser dans des URL"),
+    ("urlparser", "Analyseur d'URL", "Décompose une URL en ses composants (schéma, autorité, chemin, requête, fragment)"),
+    ("uuid5_generate", "Génération d'UUID v5 (SHA-1, par espace de noms)", "Génère un UUID version 5 à partir d'un espace de noms et d'un nom avec SHA-1. Format d'entrée : \"espace|nom\". L'espace de noms peut être un UUID ou l'un de : dns, url, oid, x500."),
+    ("uuid7_generate", "Génération d'UUID (v7)", "Génère un UUID version 7, qui commence par l'heure actuelle afin que les UUID récents soient triés après les plus anciens. L'entrée est ignorée."),
+    ("uuid_generate", "Génération d'UUID (v4)", "Génère un UUID aléatoire version 4. L'entrée est ignorée."),
+    ("uuid_inspect", "Inspecter un UUID", "Affiche la version et la variante d'un UUID, ainsi que l'horodatage, la séquence d'horloge et le nœud des UUID temporels"),
+    ("whitespaceremover", "Suppression des espaces", "Supprime tous les blancs (espaces, tabulations, retours à la ligne) du texte d'entrée."),
+    ("word_frequency", "Fréquence des mots", "Compte le nombre d'occurrences de chaque mot et les classe du plus au moins fréquent, avec la part de chacun dans le total"),
+    ("word_wrap", "Retour à la ligne", "Réorganise les paragraphes à une largeur de colonne en conservant les lignes vides entre paragraphes, l'indentation de chaque paragraphe et les éléments de liste séparés"),
+    ("xmlformatter", "Formateur XML", "Met en forme du code XML avec une indentation correcte"),
+    ("xmlminifier", "Minificateur XML", "Compresse du XML en supprimant les espaces inutiles"),
+    ("xorcipher", "Chiffrement XOR", "Applique un XOR à l'entrée avec une clé répétée. L'appliquer à nouveau avec la même clé l'annule."),
+    ("zipextractfile", "Extraction de fichier ZIP", "Extrait un fichier d'une archive ZIP, le premier sauf si l'option file en désigne un autre. Attend une entrée en Base64."),
+    ("ziplist", "Liste ZIP", "Liste les fichiers d'une archive ZIP avec leur taille et leur méthode de compression. Attend une entrée en Base64."),
+    ("zstddecompress", "Décompression Zstandard", "Décompresse une entrée Zstandard (RFC 8878) sans dictionnaire. Attend une entrée en Base64."),
+];
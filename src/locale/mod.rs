@@ -0,0 +1,174 @@
+//! Translated transformer names and descriptions.
+//!
+//! [`Transform::name`](crate::Transform::name) and
+//! [`Transform::description`](crate::Transform::description) are always
+//! English. Frontends that want another language look the transformer up by
+//! ID in a per-locale table through [`localized_name`] and
+//! [`localized_description`]; anything missing from a table falls back to
+//! English. IDs are never translated.
+
+mod de;
+mod es;
+mod fr;
+
+use crate::{Transform, TransformError};
+use std::fmt;
+
+/// A language buup can show transformer names and descriptions in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English (the built-in names and descriptions)
+    #[default]
+    English,
+    /// Spanish
+    Spanish,
+    /// French
+    French,
+    /// German
+    German,
+}
+
+// (id, name, description) for one transformer
+type Translation = (&'static str, &'static str, &'static str);
+
+impl Locale {
+    /// Every supported locale, English first
+    pub const ALL: [Locale; 4] = [
+        Locale::English,
+        Locale::Spanish,
+        Locale::French,
+        Locale::German,
+    ];
+
+    /// Two-letter language code, e.g. `de`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+            Self::French => "fr",
+            Self::German => "de",
+        }
+    }
+
+    /// Name of the language in that language, e.g. `Deutsch`
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "Español",
+            Self::French => "Français",
+            Self::German => "Deutsch",
+        }
+    }
+
+    /// Parses a language tag such as `fr`, `de-AT` or `es_ES.UTF-8`,
+    /// ignoring the region and encoding
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(['-', '_', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Self::ALL.into_iter().find(|l| l.code() == language)
+    }
+
+    fn translations(&self) -> &'static [Translation] {
+        match self {
+            Self::English => &[],
+            Self::Spanish => es::TRANSLATIONS,
+            Self::French => fr::TRANSLATIONS,
+            Self::German => de::TRANSLATIONS,
+        }
+    }
+
+    fn lookup(&self, id: &str) -> Option<&'static Translation> {
+        self.translations().iter().find(|(key, _, _)| *key == id)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = TransformError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_tag(s).ok_or_else(|| {
+            let codes: Vec<&str> = Self::ALL.iter().map(Locale::code).collect();
+            TransformError::InvalidArgument(
+                format!(
+                    "Unsupported language '{}' (expected one of: {})",
+                    s,
+                    codes.join(", ")
+                )
+                .into(),
+            )
+        })
+    }
+}
+
+/// The transformer's display name in `locale`, falling back to English
+pub fn localized_name(transformer: &dyn Transform, locale: Locale) -> &'static str {
+    locale
+        .lookup(transformer.id())
+        .map_or_else(|| transformer.name(), |(_, name, _)| *name)
+}
+
+/// The transformer's description in `locale`, falling back to English
+pub fn localized_description(transformer: &dyn Transform, locale: Locale) -> &'static str {
+    locale.lookup(transformer.id()).map_or_else(
+        || transformer.description(),
+        |(_, _, description)| *description,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{transformer_from_id, Base64Encode};
+
+    #[test]
+    fn test_locale_from_tag() {
+        assert_eq!(Locale::from_tag("de"), Some(Locale::German));
+        assert_eq!(Locale::from_tag("es-MX"), Some(Locale::Spanish));
+        assert_eq!(Locale::from_tag("fr_CA.UTF-8"), Some(Locale::French));
+        assert_eq!(Locale::from_tag("EN"), Some(Locale::English));
+        assert_eq!(Locale::from_tag("ja"), None);
+        assert!("xx".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn test_localized_text() {
+        assert_eq!(
+            localized_name(&Base64Encode, Locale::English),
+            "Base64 Encode"
+        );
+        assert_eq!(
+            localized_name(&Base64Encode, Locale::German),
+            "Base64-Kodierung"
+        );
+        assert_eq!(
+            localized_description(&Base64Encode, Locale::English),
+            Base64Encode.description()
+        );
+    }
+
+    #[test]
+    fn test_translation_tables() {
+        for locale in Locale::ALL {
+            let mut seen = std::collections::HashSet::new();
+            for (id, name, description) in locale.translations() {
+                assert!(
+                    transformer_from_id(id).is_ok(),
+                    "{} table has an entry for unknown transformer '{}'",
+                    locale,
+                    id
+                );
+                assert!(seen.insert(id), "{} table lists '{}' twice", locale, id);
+                assert!(!name.is_empty() && !description.is_empty());
+            }
+        }
+    }
+}
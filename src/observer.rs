@@ -0,0 +1,152 @@
+//! Local instrumentation hooks for transformer runs.
+//!
+//! An [`Observer`] is notified when a transform starts and finishes, with the
+//! input/output sizes and elapsed time. Frontends (the CLI's `--stats`, the web
+//! status bar, benchmarks) plug in their own observer instead of timing calls
+//! themselves. Nothing is collected or sent anywhere unless an observer does so.
+
+use crate::{Transform, TransformError, TransformOptions};
+use std::time::Duration;
+
+/// Measurements for a single transformer run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransformStats {
+    /// ID of the transformer that ran
+    pub transformer_id: &'static str,
+    /// Size of the input in bytes
+    pub input_bytes: usize,
+    /// Size of the output in bytes (0 if the transform failed)
+    pub output_bytes: usize,
+    /// Wall-clock time spent, if the observer's clock is available
+    pub elapsed: Option<Duration>,
+    /// Whether the transform succeeded
+    pub succeeded: bool,
+}
+
+/// Receives notifications about transformer runs
+pub trait Observer {
+    /// Called before the transform runs
+    fn on_start(&self, _transformer: &dyn Transform, _input_bytes: usize) {}
+
+    /// Called after the transform finished, successfully or not
+    fn on_finish(&self, _stats: &TransformStats) {}
+
+    /// Monotonic clock used for timing, relative to an arbitrary fixed point.
+    ///
+    /// The default uses `std::time::Instant`, which is unavailable on
+    /// `wasm32-unknown-unknown`; browser observers override it (e.g. with
+    /// `performance.now()`), otherwise `elapsed` is `None` there.
+    fn now(&self) -> Option<Duration> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use std::sync::OnceLock;
+            use std::time::Instant;
+
+            static EPOCH: OnceLock<Instant> = OnceLock::new();
+            Some(EPOCH.get_or_init(Instant::now).elapsed())
+        }
+        #[cfg(target_arch = "wasm32")]
+        None
+    }
+}
+
+/// Runs `transform_with_options`, reporting the run to `observer`
+pub fn transform_observed(
+    transformer: &dyn Transform,
+    input: &str,
+    options: &TransformOptions,
+    observer: &dyn Observer,
+) -> Result<String, TransformError> {
+    observe(transformer, input.len(), observer, String::len, || {
+        transformer.transform_with_options(input, options)
+    })
+}
+
+/// Runs `transform_bytes_with_options`, reporting the run to `observer`
+pub fn transform_bytes_observed(
+    transformer: &dyn Transform,
+    input: &[u8],
+    options: &TransformOptions,
+    observer: &dyn Observer,
+) -> Result<Vec<u8>, TransformError> {
+    observe(transformer, input.len(), observer, Vec::len, || {
+        transformer.transform_bytes_with_options(input, options)
+    })
+}
+
+fn observe<T>(
+    transformer: &dyn Transform,
+    input_bytes: usize,
+    observer: &dyn Observer,
+    output_len: impl Fn(&T) -> usize,
+    run: impl FnOnce() -> Result<T, TransformError>,
+) -> Result<T, TransformError> {
+    observer.on_start(transformer, input_bytes);
+    let start = observer.now();
+    let result = run();
+    let elapsed = match (start, observer.now()) {
+        (Some(start), Some(end)) => Some(end.saturating_sub(start)),
+        _ => None,
+    };
+
+    observer.on_finish(&TransformStats {
+        transformer_id: transformer.id(),
+        input_bytes,
+        output_bytes: result.as_ref().map(&output_len).unwrap_or(0),
+        elapsed,
+        succeeded: result.is_ok(),
+    });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Base64Encode;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct Recorder {
+        started: RefCell<Vec<usize>>,
+        finished: RefCell<Vec<TransformStats>>,
+    }
+
+    impl Observer for Recorder {
+        fn on_start(&self, _transformer: &dyn Transform, input_bytes: usize) {
+            self.started.borrow_mut().push(input_bytes);
+        }
+
+        fn on_finish(&self, stats: &TransformStats) {
+            self.finished.borrow_mut().push(stats.clone());
+        }
+    }
+
+    #[test]
+    fn test_observer_receives_sizes() {
+        let recorder = Recorder::default();
+        let output =
+            transform_observed(&Base64Encode, "Hello", &TransformOptions::new(), &recorder)
+                .unwrap();
+        assert_eq!(output, "SGVsbG8=");
+        assert_eq!(*recorder.started.borrow(), vec![5]);
+
+        let stats = &recorder.finished.borrow()[0];
+        assert_eq!(stats.transformer_id, "base64encode");
+        assert_eq!(stats.input_bytes, 5);
+        assert_eq!(stats.output_bytes, 8);
+        assert!(stats.elapsed.is_some());
+        assert!(stats.succeeded);
+    }
+
+    #[test]
+    fn test_observer_reports_failures() {
+        let recorder = Recorder::default();
+        let mut options = TransformOptions::new();
+        options.set("unknown", "1");
+        assert!(transform_observed(&Base64Encode, "Hello", &options, &recorder).is_err());
+
+        let stats = &recorder.finished.borrow()[0];
+        assert_eq!(stats.output_bytes, 0);
+        assert!(!stats.succeeded);
+    }
+}
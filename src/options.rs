@@ -0,0 +1,201 @@
+//! Named options that tune how a transformer behaves.
+//!
+//! Transformers advertise the options they understand through
+//! [`Transform::options`](crate::Transform::options) and read the values passed
+//! to [`Transform::transform_with_options`](crate::Transform::transform_with_options).
+//! Values are kept as strings (as they arrive from the CLI or the web UI) and
+//! parsed on access; missing values fall back to the option's default.
+
+use crate::{CancelToken, TransformError};
+use std::collections::BTreeMap;
+
+/// The type of value an option accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformOptionKind {
+    /// A non-negative whole number
+    Integer,
+    /// `true` or `false`
+    Boolean,
+    /// Free-form text
+    Text,
+    /// One of a fixed set of values
+    Choice(&'static [&'static str]),
+}
+
+/// Describes an option understood by a transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransformOption {
+    /// Key used to set the option, e.g. `max_output`
+    pub key: &'static str,
+    /// Short human readable explanation
+    pub description: &'static str,
+    /// Value used when the option is not set
+    pub default: &'static str,
+    /// The type of value accepted
+    pub kind: TransformOptionKind,
+}
+
+/// A set of option values passed to a transformer
+#[derive(Debug, Clone, Default)]
+pub struct TransformOptions {
+    values: BTreeMap<String, String>,
+    cancel: CancelToken,
+}
+
+impl TransformOptions {
+    /// Creates an empty set of options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets an option value, replacing any previous value
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.values.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the raw value of an option, if set
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Returns true if no option values are set
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Attaches a cancellation token that option-aware transforms honour
+    pub fn with_cancel(mut self, token: CancelToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// The cancellation token for this invocation (never fires unless one was attached)
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel
+    }
+
+    /// Checks that every value set is a known option with a well-formed value
+    pub fn validate(&self, known: &[TransformOption]) -> Result<(), TransformError> {
+        for (key, value) in &self.values {
+            let option = known.iter().find(|o| o.key == key).ok_or_else(|| {
+                let expected: Vec<&str> = known.iter().map(|o| o.key).collect();
+                let expected = if expected.is_empty() {
+                    "this transformer has no options".to_string()
+                } else {
+                    format!("expected one of: {}", expected.join(", "))
+                };
+                TransformError::InvalidArgument(
+                    format!("Unknown option '{}' ({})", key, expected).into(),
+                )
+            })?;
+            check_value(option, value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the value of `option`, or its default when unset
+    pub fn value<'a>(&'a self, option: &'a TransformOption) -> &'a str {
+        self.get(option.key).unwrap_or(option.default)
+    }
+
+    /// Returns the value of an [`TransformOptionKind::Integer`] option
+    pub fn usize_value(&self, option: &TransformOption) -> Result<usize, TransformError> {
+        let value = self.value(option);
+        value
+            .parse()
+            .map_err(|_| invalid_value(option, value, "expected a whole number"))
+    }
+
+    /// Returns the value of a [`TransformOptionKind::Boolean`] option
+    pub fn bool_value(&self, option: &TransformOption) -> Result<bool, TransformError> {
+        match self.value(option) {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            value => Err(invalid_value(option, value, "expected true or false")),
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for TransformOptions {
+    /// Collects `(key, value)` pairs, later pairs replacing earlier ones
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(pairs: I) -> Self {
+        let mut options = Self::new();
+        for (key, value) in pairs {
+            options.set(key, value);
+        }
+        options
+    }
+}
+
+fn check_value(option: &TransformOption, value: &str) -> Result<(), TransformError> {
+    match option.kind {
+        TransformOptionKind::Integer if value.parse::<usize>().is_err() => {
+            Err(invalid_value(option, value, "expected a whole number"))
+        }
+        TransformOptionKind::Boolean if value != "true" && value != "false" => {
+            Err(invalid_value(option, value, "expected true or false"))
+        }
+        TransformOptionKind::Choice(choices) if !choices.contains(&value) => Err(invalid_value(
+            option,
+            value,
+            &format!("expected one of: {}", choices.join(", ")),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn invalid_value(option: &TransformOption, value: &str, expected: &str) -> TransformError {
+    TransformError::InvalidArgument(
+        format!(
+            "Invalid value '{}' for option '{}' ({})",
+            value, option.key, expected
+        )
+        .into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: TransformOption = TransformOption {
+        key: "limit",
+        description: "A limit",
+        default: "10",
+        kind: TransformOptionKind::Integer,
+    };
+    const MODE: TransformOption = TransformOption {
+        key: "mode",
+        description: "A mode",
+        default: "fast",
+        kind: TransformOptionKind::Choice(&["fast", "slow"]),
+    };
+
+    #[test]
+    fn test_defaults_and_values() {
+        let mut options = TransformOptions::new();
+        assert_eq!(options.usize_value(&LIMIT).unwrap(), 10);
+        options.set("limit", "42");
+        assert_eq!(options.usize_value(&LIMIT).unwrap(), 42);
+        assert_eq!(options.value(&MODE), "fast");
+
+        let options = TransformOptions::from_iter([("limit", "1"), ("limit", "2")]);
+        assert_eq!(options.usize_value(&LIMIT).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut options = TransformOptions::new();
+        options.set("mode", "slow");
+        assert!(options.validate(&[LIMIT, MODE]).is_ok());
+
+        options.set("limit", "lots");
+        assert!(options.validate(&[LIMIT, MODE]).is_err());
+
+        let mut unknown = TransformOptions::new();
+        unknown.set("colour", "red");
+        assert!(unknown.validate(&[LIMIT, MODE]).is_err());
+        assert!(unknown.validate(&[]).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Chains of transformers run one after another.
+//!
+//! A [`Pipeline`] feeds each step's output into the next step, e.g.
+//! `gzipdecompress,jsonformatter`. Frontends use [`Pipeline::run`] to show
+//! every intermediate result, and [`Pipeline::inverse`] to undo a whole chain
+//! when each step has an inverse.
+
+use crate::{inverse_transformer, transformer_from_id, Transform, TransformError};
+use std::fmt;
+
+/// An ordered list of transformers
+#[derive(Clone, Default)]
+pub struct Pipeline {
+    steps: Vec<&'static dyn Transform>,
+}
+
+impl Pipeline {
+    /// Creates an empty pipeline, which passes its input through unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The steps in the order they run
+    pub fn steps(&self) -> &[&'static dyn Transform] {
+        &self.steps
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Appends a step
+    pub fn push(&mut self, transformer: &'static dyn Transform) -> &mut Self {
+        self.steps.push(transformer);
+        self
+    }
+
+    /// Inserts a step at `index`, shifting later steps along.
+    /// Panics if `index > len()`.
+    pub fn insert(&mut self, index: usize, transformer: &'static dyn Transform) {
+        self.steps.insert(index, transformer);
+    }
+
+    /// Removes and returns the step at `index`, if there is one
+    pub fn remove(&mut self, index: usize) -> Option<&'static dyn Transform> {
+        (index < self.steps.len()).then(|| self.steps.remove(index))
+    }
+
+    /// Moves the step at `from` so that it ends up at `to`.
+    /// Out-of-range positions leave the pipeline unchanged.
+    pub fn move_step(&mut self, from: usize, to: usize) {
+        if from < self.steps.len() && to < self.steps.len() {
+            let step = self.steps.remove(from);
+            self.steps.insert(to, step);
+        }
+    }
+
+    /// Runs every step, returning each step's result in order. Stops after
+    /// the first failure, so the last entry is the error if one occurred.
+    pub fn run(&self, input: &str) -> Vec<Result<String, TransformError>> {
+        let mut results: Vec<Result<String, TransformError>> = Vec::with_capacity(self.len());
+        for step in &self.steps {
+            let current = match results.last() {
+                Some(Ok(previous)) => previous.as_str(),
+                Some(Err(_)) => break,
+                None => input,
+            };
+            let result = step.transform(current);
+            results.push(result);
+        }
+        results
+    }
+
+    /// Runs every step and returns the final output
+    pub fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.steps
+            .iter()
+            .try_fold(input.to_string(), |current, step| step.transform(&current))
+    }
+
+    /// The pipeline that undoes this one: each step's inverse, in reverse
+    /// order. `None` if any step has no inverse.
+    pub fn inverse(&self) -> Option<Pipeline> {
+        let steps = self
+            .steps
+            .iter()
+            .rev()
+            .map(|step| inverse_transformer(*step))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Pipeline { steps })
+    }
+}
+
+impl fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.steps.iter().map(|step| step.id()))
+            .finish()
+    }
+}
+
+impl PartialEq for Pipeline {
+    fn eq(&self, other: &Self) -> bool {
+        self.steps.len() == other.steps.len()
+            && self
+                .steps
+                .iter()
+                .zip(&other.steps)
+                .all(|(a, b)| a.id() == b.id())
+    }
+}
+
+/// Comma-separated transformer IDs, e.g. `base64decode,jsonformatter`
+impl fmt::Display for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids: Vec<&str> = self.steps.iter().map(|step| step.id()).collect();
+        write!(f, "{}", ids.join(","))
+    }
+}
+
+impl std::str::FromStr for Pipeline {
+    type Err = TransformError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = s
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(transformer_from_id)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Pipeline { steps })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Base64Decode, Base64Encode, JsonFormatter, Sha256HashTransformer, UrlEncode};
+
+    #[test]
+    fn test_pipeline_run() {
+        let mut pipeline = Pipeline::new();
+        pipeline.push(&Base64Decode).push(&JsonFormatter);
+        let results = pipeline.run("eyJhIjoxfQ==");
+        assert_eq!(
+            results,
+            vec![
+                Ok("{\"a\":1}".to_string()),
+                Ok("{\n  \"a\": 1\n}".to_string())
+            ]
+        );
+        assert_eq!(
+            pipeline.transform("eyJhIjoxfQ=="),
+            Ok("{\n  \"a\": 1\n}".to_string())
+        );
+
+        // Steps after a failure don't run
+        let results = pipeline.run("not base64!");
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+        assert!(pipeline.transform("not base64!").is_err());
+
+        assert_eq!(Pipeline::new().transform("same"), Ok("same".to_string()));
+    }
+
+    #[test]
+    fn test_pipeline_editing() {
+        let mut pipeline: Pipeline = "base64encode, urlencode".parse().unwrap();
+        pipeline.insert(0, &JsonFormatter);
+        assert_eq!(pipeline.to_string(), "jsonformatter,base64encode,urlencode");
+        pipeline.move_step(0, 2);
+        assert_eq!(pipeline.to_string(), "base64encode,urlencode,jsonformatter");
+        assert_eq!(pipeline.remove(2).map(|t| t.id()), Some("jsonformatter"));
+        assert!(pipeline.remove(5).is_none());
+        assert_eq!(pipeline.len(), 2);
+        assert!("base64encode,nope".parse::<Pipeline>().is_err());
+    }
+
+    #[test]
+    fn test_pipeline_inverse() {
+        let mut pipeline = Pipeline::new();
+        pipeline.push(&Base64Encode).push(&UrlEncode);
+        let inverse = pipeline.inverse().unwrap();
+        assert_eq!(inverse.to_string(), "urldecode,base64decode");
+
+        let input = "a+b=c/d?";
+        let encoded = pipeline.transform(input).unwrap();
+        assert_eq!(inverse.transform(&encoded).unwrap(), input);
+
+        pipeline.push(&Sha256HashTransformer);
+        assert!(pipeline.inverse().is_none());
+    }
+}
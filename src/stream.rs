@@ -0,0 +1,21 @@
+//! Incremental transforms for inputs too large to hold in memory twice.
+//!
+//! A [`StreamTransform`] is fed its input a chunk at a time and appends output
+//! as it goes, so a frontend can pipe a large file through a transformer with
+//! memory bounded by the chunk size. Transformers that can work this way
+//! return one from [`Transform::stream`](crate::Transform::stream).
+
+use crate::TransformError;
+
+/// Byte-level transform that consumes its input in chunks.
+///
+/// Passing an input through [`update`](Self::update), split anywhere, then
+/// calling [`finish`](Self::finish) produces the same bytes as the
+/// transformer's `transform_bytes` on the whole input.
+pub trait StreamTransform {
+    /// Consumes the next chunk of input, appending any output it completes
+    fn update(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<(), TransformError>;
+
+    /// Ends the input, appending the rest of the output
+    fn finish(&mut self, output: &mut Vec<u8>) -> Result<(), TransformError>;
+}
@@ -0,0 +1,174 @@
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+const ESC: char = '\u{1b}';
+const BEL: char = '\u{7}';
+/// Single character form of `ESC [`
+const C1_CSI: char = '\u{9b}';
+
+/// ANSI escape code stripper
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnsiStrip;
+
+impl Transform for AnsiStrip {
+    fn name(&self) -> &'static str {
+        "ANSI Strip"
+    }
+
+    fn id(&self) -> &'static str {
+        "ansistrip"
+    }
+
+    fn description(&self) -> &'static str {
+        "Removes ANSI escape sequences (colors, cursor movement, terminal titles and hyperlinks) from terminal output, leaving the plain text."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        Ok(segments(input)
+            .into_iter()
+            .filter_map(|segment| match segment {
+                Segment::Text(text) => Some(text),
+                Segment::Sgr(_) => None,
+            })
+            .collect())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "\u{1b}[1;32m   Compiling\u{1b}[0m buup v0.1.0\n\u{1b}[1m\u{1b}[31merror\u{1b}[0m\u{1b}[1m: expected `;`\u{1b}[0m"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "256 colors and hyperlink",
+                input: "\u{1b}[38;5;208mwarning\u{1b}[39m: see \u{1b}]8;;https://buup.io\u{7}the docs\u{1b}]8;;\u{7}",
+            },
+            Example {
+                name: "Progress bar",
+                input: "\u{1b}[2K\u{1b}[1G[=====>    ] 50%\u{1b}[?25h",
+            },
+        ]
+    }
+}
+
+/// A run of plain text or a Select Graphic Rendition sequence between two
+/// escape codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment<'a> {
+    Text(&'a str),
+    /// The parameters of `ESC [ ... m`, e.g. "1;31"
+    Sgr(&'a str),
+}
+
+/// Splits terminal output into text and SGR sequences, dropping every other
+/// escape sequence (cursor movement, OSC titles and hyperlinks, charset
+/// switches). An unfinished sequence at the end is dropped as well.
+pub(crate) fn segments(input: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let csi = match c {
+            ESC => match chars.peek() {
+                Some((_, '[')) => {
+                    chars.next();
+                    true
+                }
+                Some((_, ']' | 'P' | 'X' | '^' | '_')) => {
+                    // OSC and other strings run to BEL or ST (ESC \)
+                    while let Some((_, c)) = chars.next() {
+                        if c == BEL || (c == ESC && chars.next_if(|(_, c)| *c == '\\').is_some()) {
+                            break;
+                        }
+                    }
+                    false
+                }
+                // nF sequences like `ESC ( B` take intermediates then a final byte
+                Some((_, ' '..='/')) => {
+                    while chars.next_if(|(_, c)| matches!(c, ' '..='/')).is_some() {}
+                    chars.next();
+                    false
+                }
+                Some(_) => {
+                    chars.next();
+                    false
+                }
+                None => false,
+            },
+            C1_CSI => true,
+            _ => continue,
+        };
+
+        if start > text_start {
+            segments.push(Segment::Text(&input[text_start..start]));
+        }
+        if csi {
+            // Parameter and intermediate bytes, then one final byte
+            let params_start = chars.peek().map_or(input.len(), |(i, _)| *i);
+            while chars.next_if(|(_, c)| matches!(c, '0'..='?')).is_some() {}
+            let params_end = chars.peek().map_or(input.len(), |(i, _)| *i);
+            while chars.next_if(|(_, c)| matches!(c, ' '..='/')).is_some() {}
+            if let Some((_, 'm')) = chars.next() {
+                segments.push(Segment::Sgr(&input[params_start..params_end]));
+            }
+        }
+        text_start = chars.peek().map_or(input.len(), |(i, _)| *i);
+    }
+    if text_start < input.len() {
+        segments.push(Segment::Text(&input[text_start..]));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_strip() {
+        assert_eq!(
+            AnsiStrip.transform(AnsiStrip.default_test_input()).unwrap(),
+            "   Compiling buup v0.1.0\nerror: expected `;`"
+        );
+        assert_eq!(AnsiStrip.transform("plain text").unwrap(), "plain text");
+        assert_eq!(AnsiStrip.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_ansi_strip_other_sequences() {
+        let strip = |input: &str| AnsiStrip.transform(input).unwrap();
+        assert_eq!(
+            strip(AnsiStrip.examples()[0].input),
+            "warning: see the docs"
+        );
+        assert_eq!(strip(AnsiStrip.examples()[1].input), "[=====>    ] 50%");
+        // Window title ended by ST, charset switch, C1 CSI, keypad mode
+        assert_eq!(
+            strip("\u{1b}]0;title\u{1b}\\a\u{1b}(Bb\u{9b}31mc\u{1b}=d"),
+            "abcd"
+        );
+        // Unfinished sequences at the end are dropped
+        assert_eq!(strip("done\u{1b}[1;3"), "done");
+        assert_eq!(strip("done\u{1b}"), "done");
+        assert_eq!(strip("é\u{1b}[0mü"), "éü");
+    }
+
+    #[test]
+    fn test_segments() {
+        assert_eq!(
+            segments("a\u{1b}[1;31mb\u{1b}[mc\u{1b}[2Jd"),
+            vec![
+                Segment::Text("a"),
+                Segment::Sgr("1;31"),
+                Segment::Text("b"),
+                Segment::Sgr(""),
+                Segment::Text("c"),
+                Segment::Text("d"),
+            ]
+        );
+    }
+}
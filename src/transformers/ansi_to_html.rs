@@ -0,0 +1,303 @@
+use super::ansi_strip::{segments, Segment};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// xterm's default colors 0-15: black, red, green, yellow, blue, magenta,
+/// cyan, white, then their bright versions
+const PALETTE: [&str; 16] = [
+    "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd", "#00cdcd", "#e5e5e5",
+    "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff", "#00ffff", "#ffffff",
+];
+/// Colors reverse video swaps in when the text has no color of its own
+const DEFAULT_FOREGROUND: &str = "#e5e5e5";
+const DEFAULT_BACKGROUND: &str = "#000000";
+
+const PRE_OPTION: TransformOption = TransformOption {
+    key: "pre",
+    description: "Wrap the output in <pre> so line breaks and alignment survive",
+    default: "true",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// ANSI color codes to HTML transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnsiToHtml;
+
+impl Transform for AnsiToHtml {
+    fn name(&self) -> &'static str {
+        "ANSI to HTML"
+    }
+
+    fn id(&self) -> &'static str {
+        "ansitohtml"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts ANSI colored terminal output into HTML, with each styled run in a <span> with inline CSS. Other escape sequences are removed."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[PRE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let pre = options.bool_value(&PRE_OPTION)?;
+
+        let mut html = String::new();
+        let mut style = Style::default();
+        // Style of the <span> currently open, if any
+        let mut open_span: Option<String> = None;
+        for (i, segment) in segments(input).into_iter().enumerate() {
+            token.check_every(i)?;
+            match segment {
+                Segment::Sgr(params) => style.apply(params),
+                Segment::Text(text) => {
+                    let css = style.css();
+                    if open_span.as_ref().is_some_and(|open| *open != css) {
+                        html.push_str("</span>");
+                        open_span = None;
+                    }
+                    if open_span.is_none() && !css.is_empty() {
+                        html.push_str(&format!("<span style=\"{}\">", css));
+                        open_span = Some(css);
+                    }
+                    push_escaped(&mut html, text);
+                }
+            }
+        }
+        if open_span.is_some() {
+            html.push_str("</span>");
+        }
+        Ok(if pre {
+            format!("<pre>{}</pre>", html)
+        } else {
+            html
+        })
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "\u{1b}[1;32m   Compiling\u{1b}[0m buup v0.1.0\n\u{1b}[1m\u{1b}[31merror\u{1b}[0m\u{1b}[1m: expected `;`\u{1b}[0m"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Test results",
+                input: "test parse ... \u{1b}[32mok\u{1b}[0m\ntest render ... \u{1b}[31mFAILED\u{1b}[0m\n\u{1b}[4mfailures:\u{1b}[24m <render>",
+            },
+            Example {
+                name: "256 and true color",
+                input: "\u{1b}[38;5;208morange\u{1b}[0m \u{1b}[48;2;30;30;46;38;2;205;214;244mcatppuccin\u{1b}[0m \u{1b}[7minverse\u{1b}[27m",
+            },
+        ]
+    }
+}
+
+fn push_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// The text attributes SGR sequences set
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Style {
+    foreground: Option<String>,
+    background: Option<String>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    inverse: bool,
+    hidden: bool,
+    strikethrough: bool,
+}
+
+impl Style {
+    // Applies the `;` separated codes of one SGR sequence. Unknown codes are
+    // skipped, as terminals do.
+    fn apply(&mut self, params: &str) {
+        let codes: Vec<u32> = params
+            .split([';', ':'])
+            .map(|code| code.parse().unwrap_or(0))
+            .collect();
+        let mut i = 0;
+        while i < codes.len() {
+            match codes[i] {
+                0 => *self = Style::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                7 => self.inverse = true,
+                8 => self.hidden = true,
+                9 => self.strikethrough = true,
+                22 => (self.bold, self.dim) = (false, false),
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.inverse = false,
+                28 => self.hidden = false,
+                29 => self.strikethrough = false,
+                code @ 30..=37 => self.foreground = Some(PALETTE[code as usize - 30].to_string()),
+                code @ 40..=47 => self.background = Some(PALETTE[code as usize - 40].to_string()),
+                code @ 90..=97 => self.foreground = Some(PALETTE[code as usize - 82].to_string()),
+                code @ 100..=107 => self.background = Some(PALETTE[code as usize - 92].to_string()),
+                39 => self.foreground = None,
+                49 => self.background = None,
+                code @ (38 | 48) => {
+                    let (color, used) = extended_color(&codes[i + 1..]);
+                    i += used;
+                    if let Some(color) = color {
+                        if code == 38 {
+                            self.foreground = Some(color);
+                        } else {
+                            self.background = Some(color);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    fn css(&self) -> String {
+        let (mut foreground, mut background) = (self.foreground.clone(), self.background.clone());
+        if self.inverse {
+            (foreground, background) = (
+                Some(background.unwrap_or_else(|| DEFAULT_BACKGROUND.to_string())),
+                Some(foreground.unwrap_or_else(|| DEFAULT_FOREGROUND.to_string())),
+            );
+        }
+        let mut css = Vec::new();
+        if let Some(color) = foreground {
+            css.push(format!("color:{}", color));
+        }
+        if let Some(color) = background {
+            css.push(format!("background-color:{}", color));
+        }
+        if self.bold {
+            css.push("font-weight:bold".to_string());
+        }
+        if self.dim {
+            css.push("opacity:0.7".to_string());
+        }
+        if self.italic {
+            css.push("font-style:italic".to_string());
+        }
+        let lines: Vec<&str> = [
+            (self.underline, "underline"),
+            (self.strikethrough, "line-through"),
+        ]
+        .iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, line)| *line)
+        .collect();
+        if !lines.is_empty() {
+            css.push(format!("text-decoration:{}", lines.join(" ")));
+        }
+        if self.hidden {
+            css.push("visibility:hidden".to_string());
+        }
+        css.join(";")
+    }
+}
+
+// The color after a 38 or 48 code, either `5;n` from the 256 color palette or
+// `2;r;g;b`, and how many codes it took
+fn extended_color(codes: &[u32]) -> (Option<String>, usize) {
+    match codes {
+        [5, n, ..] => (color_256(*n), 2),
+        [2, r, g, b, ..] => {
+            let channel = |value: u32| value.min(255);
+            (
+                Some(format!(
+                    "#{:02x}{:02x}{:02x}",
+                    channel(*r),
+                    channel(*g),
+                    channel(*b)
+                )),
+                4,
+            )
+        }
+        _ => (None, codes.len()),
+    }
+}
+
+// Colors 16-231 are a 6x6x6 cube, 232-255 a gray ramp
+fn color_256(n: u32) -> Option<String> {
+    const LEVELS: [u32; 6] = [0, 95, 135, 175, 215, 255];
+    match n {
+        0..=15 => Some(PALETTE[n as usize].to_string()),
+        16..=231 => {
+            let n = n - 16;
+            Some(format!(
+                "#{:02x}{:02x}{:02x}",
+                LEVELS[(n / 36) as usize],
+                LEVELS[(n / 6 % 6) as usize],
+                LEVELS[(n % 6) as usize]
+            ))
+        }
+        232..=255 => {
+            let gray = 8 + (n - 232) * 10;
+            Some(format!("#{:02x}{:02x}{:02x}", gray, gray, gray))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_to_html() {
+        assert_eq!(
+            AnsiToHtml.transform(AnsiToHtml.default_test_input()).unwrap(),
+            "<pre><span style=\"color:#00cd00;font-weight:bold\">   Compiling</span> buup v0.1.0\n<span style=\"color:#cd0000;font-weight:bold\">error</span><span style=\"font-weight:bold\">: expected `;`</span></pre>"
+        );
+        assert_eq!(AnsiToHtml.transform("").unwrap(), "<pre></pre>");
+    }
+
+    #[test]
+    fn test_ansi_to_html_colors() {
+        let mut options = TransformOptions::new();
+        options.set("pre", "false");
+        let html = |input: &str| AnsiToHtml.transform_with_options(input, &options).unwrap();
+        assert_eq!(
+            html(AnsiToHtml.examples()[1].input),
+            "<span style=\"color:#ff8700\">orange</span> <span style=\"color:#cdd6f4;background-color:#1e1e2e\">catppuccin</span> <span style=\"color:#000000;background-color:#e5e5e5\">inverse</span>"
+        );
+        assert_eq!(
+            html("\u{1b}[94;101mx\u{1b}[38;5;244my\u{1b}[39;49;3;4;9mz"),
+            "<span style=\"color:#5c5cff;background-color:#ff0000\">x</span><span style=\"color:#808080;background-color:#ff0000\">y</span><span style=\"font-style:italic;text-decoration:underline line-through\">z</span>"
+        );
+        // Same style again keeps the span open, escaping applies inside
+        assert_eq!(
+            html("\u{1b}[31ma<\u{1b}[31m&b\u{1b}[0m\u{1b}[2K>"),
+            "<span style=\"color:#cd0000\">a&lt;&amp;b</span>&gt;"
+        );
+        // Malformed extended colors are ignored
+        assert_eq!(html("\u{1b}[38;5mx\u{1b}[38;2;1;2mx"), "xx");
+    }
+}
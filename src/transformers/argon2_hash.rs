@@ -0,0 +1,195 @@
+use crate::utils::argon2::{argon2id, Params, PhcHash};
+use crate::utils::random::{entropy_source, EntropySource};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// Most memory a hash may use, in KiB, so a browser tab can't be exhausted
+pub(crate) const MAX_MEMORY: u32 = 256 * 1024;
+
+const MEMORY_OPTION: TransformOption = TransformOption {
+    key: "memory",
+    description: "Memory cost in KiB (at most 262144)",
+    default: "19456",
+    kind: TransformOptionKind::Integer,
+};
+
+const ITERATIONS_OPTION: TransformOption = TransformOption {
+    key: "iterations",
+    description: "Number of passes over the memory",
+    default: "2",
+    kind: TransformOptionKind::Integer,
+};
+
+const PARALLELISM_OPTION: TransformOption = TransformOption {
+    key: "parallelism",
+    description: "Number of lanes",
+    default: "1",
+    kind: TransformOptionKind::Integer,
+};
+
+const SALT_OPTION: TransformOption = TransformOption {
+    key: "salt",
+    description: "Salt text of at least 8 bytes (empty for 16 random bytes)",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+const LENGTH_OPTION: TransformOption = TransformOption {
+    key: "length",
+    description: "Hash length in bytes",
+    default: "32",
+    kind: TransformOptionKind::Integer,
+};
+
+/// Argon2id password hashing transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Hash;
+
+impl Transform for Argon2Hash {
+    fn name(&self) -> &'static str {
+        "Argon2id Hash"
+    }
+
+    fn id(&self) -> &'static str {
+        "argon2hash"
+    }
+
+    fn description(&self) -> &'static str {
+        "Hashes a password with Argon2id into a PHC string ($argon2id$v=19$...) for password storage"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new().with_cancel(token.clone()))
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[
+            MEMORY_OPTION,
+            ITERATIONS_OPTION,
+            PARALLELISM_OPTION,
+            SALT_OPTION,
+            LENGTH_OPTION,
+        ]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let number = |option: &TransformOption| {
+            u32::try_from(options.usize_value(option)?).map_err(|_| {
+                TransformError::InvalidArgument(format!("{} is too large", option.key).into())
+            })
+        };
+        let params = Params {
+            memory: number(&MEMORY_OPTION)?,
+            iterations: number(&ITERATIONS_OPTION)?,
+            parallelism: number(&PARALLELISM_OPTION)?,
+            output_len: options.usize_value(&LENGTH_OPTION)?,
+        };
+        check_limits(&params)?;
+
+        let salt = match options.value(&SALT_OPTION) {
+            "" => random_salt(entropy_source())?,
+            salt => salt.as_bytes().to_vec(),
+        };
+        let hash = argon2id(input.as_bytes(), &salt, &params, token)?;
+        Ok(PhcHash { params, salt, hash }.to_string())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "correct horse battery staple"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Password",
+            input: "hunter2",
+        }]
+    }
+}
+
+// 16 bytes of salt straight from `source`. There is deliberately no weaker
+// fallback: without entropy the hash fails rather than reuse a guessable salt.
+fn random_salt(source: &dyn EntropySource) -> Result<Vec<u8>, TransformError> {
+    let mut salt = vec![0; 16];
+    source.fill(&mut salt)?;
+    Ok(salt)
+}
+
+/// Rejects costs beyond what this tool is willing to spend
+pub(crate) fn check_limits(params: &Params) -> Result<(), TransformError> {
+    if params.memory > MAX_MEMORY {
+        return Err(TransformError::InvalidArgument(
+            format!("Argon2 memory is limited to {} KiB here", MAX_MEMORY).into(),
+        ));
+    }
+    if params.output_len > 1024 {
+        return Err(TransformError::InvalidArgument(
+            "Argon2 hash length is limited to 1024 bytes here".into(),
+        ));
+    }
+    params.validate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_with(input: &str, settings: &[(&str, &str)]) -> Result<String, TransformError> {
+        let options: TransformOptions = settings.iter().copied().collect();
+        Argon2Hash.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_argon2_hash_with_salt() {
+        assert_eq!(
+            hash_with("password", &[("memory", "1024"), ("salt", "somesalt")]).unwrap(),
+            "$argon2id$v=19$m=1024,t=2,p=1$c29tZXNhbHQ$7FfsnA6vUe7qLpL/3Kqc3uR48ZJyFbUVt7jWZlf0Htk"
+        );
+    }
+
+    #[test]
+    fn test_argon2_hash_random_salt() {
+        let settings = [("memory", "64"), ("iterations", "1"), ("length", "16")];
+        let first = hash_with("password", &settings).unwrap();
+        let second = hash_with("password", &settings).unwrap();
+        assert!(first.starts_with("$argon2id$v=19$m=64,t=1,p=1$"));
+        assert_ne!(first, second);
+        let parsed = PhcHash::parse(&first).unwrap();
+        assert_eq!((parsed.salt.len(), parsed.hash.len()), (16, 16));
+        assert!(parsed.verify(b"password", &CancelToken::new()).unwrap());
+    }
+
+    #[test]
+    fn test_argon2_hash_without_entropy() {
+        assert_eq!(
+            random_salt(&crate::utils::random::NoEntropy),
+            Err(TransformError::EntropyError("none in tests".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_argon2_hash_limits() {
+        assert!(hash_with("password", &[("memory", "300000")]).is_err());
+        assert!(hash_with("password", &[("memory", "64"), ("salt", "short")]).is_err());
+        assert!(hash_with("password", &[("memory", "64"), ("parallelism", "0")]).is_err());
+        assert!(hash_with("password", &[("memory", "64"), ("length", "2")]).is_err());
+    }
+}
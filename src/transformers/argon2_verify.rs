@@ -0,0 +1,137 @@
+use super::argon2_hash::check_limits;
+use crate::utils::argon2::PhcHash;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// The hash of "password" under the salt "somesalt", for the samples
+const SAMPLE_HASH: &str =
+    "$argon2id$v=19$m=1024,t=2,p=1$c29tZXNhbHQ$7FfsnA6vUe7qLpL/3Kqc3uR48ZJyFbUVt7jWZlf0Htk";
+
+const HASH_OPTION: TransformOption = TransformOption {
+    key: "hash",
+    description: "Argon2id hash in PHC format ($argon2id$v=19$m=...)",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+/// Argon2id password verification transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Verify;
+
+impl Transform for Argon2Verify {
+    fn name(&self) -> &'static str {
+        "Argon2id Verify"
+    }
+
+    fn id(&self) -> &'static str {
+        "argon2verify"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks whether the input password matches the Argon2id PHC string in the hash option"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new().with_cancel(token.clone()))
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[HASH_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let hash = options.value(&HASH_OPTION);
+        if hash.is_empty() {
+            return Err(TransformError::InvalidArgument(
+                "hash option is required".into(),
+            ));
+        }
+        let phc = PhcHash::parse(hash)?;
+        check_limits(&phc.params)?;
+        Ok(if phc.verify(input.as_bytes(), token)? {
+            "Password matches".to_string()
+        } else {
+            "Password does not match".to_string()
+        })
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "password"
+    }
+
+    fn default_test_options(&self) -> &'static [(&'static str, &'static str)] {
+        &[("hash", SAMPLE_HASH)]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Wrong password",
+            input: "Password",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_argon2_verify() {
+        let options = TransformOptions::from_iter([("hash", SAMPLE_HASH)]);
+        assert_eq!(
+            Argon2Verify
+                .transform_with_options("password", &options)
+                .unwrap(),
+            "Password matches"
+        );
+        assert_eq!(
+            Argon2Verify
+                .transform_with_options("Password", &options)
+                .unwrap(),
+            "Password does not match"
+        );
+    }
+
+    #[test]
+    fn test_argon2_verify_requires_hash() {
+        assert!(matches!(
+            Argon2Verify.transform("password"),
+            Err(TransformError::InvalidArgument(message)) if message == "hash option is required"
+        ));
+    }
+
+    #[test]
+    fn test_argon2_verify_invalid_hash() {
+        let mut options = TransformOptions::new();
+        options.set("hash", "$2b$10$notargon");
+        assert!(Argon2Verify
+            .transform_with_options("password", &options)
+            .is_err());
+        options.set(
+            "hash",
+            "$argon2id$v=19$m=999999,t=1,p=1$c29tZXNhbHQ$7FfsnA6vUe7qLpL",
+        );
+        assert!(Argon2Verify
+            .transform_with_options("password", &options)
+            .is_err());
+    }
+}
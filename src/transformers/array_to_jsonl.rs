@@ -0,0 +1,92 @@
+use crate::utils::json::JsonValue;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// JSON array to JSON Lines transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrayToJsonl;
+
+impl Transform for ArrayToJsonl {
+    fn name(&self) -> &'static str {
+        "Array to JSON Lines"
+    }
+
+    fn id(&self) -> &'static str {
+        "arraytojsonl"
+    }
+
+    fn description(&self) -> &'static str {
+        "Writes each element of a JSON array as minified JSON on its own line (JSON Lines, NDJSON)"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        if input.trim().is_empty() {
+            return Ok(String::new());
+        }
+        match JsonValue::parse(input)? {
+            JsonValue::Array(items) => {
+                let lines: Vec<String> = items.iter().map(JsonValue::to_string).collect();
+                Ok(lines.join("\n"))
+            }
+            _ => Err(TransformError::JsonParseError(
+                "Input must be a JSON array".to_string(),
+            )),
+        }
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "[\n  {\"id\": 1, \"event\": \"login\", \"user\": \"ada\"},\n  {\"id\": 2, \"event\": \"upload\", \"user\": \"grace\", \"files\": 3},\n  {\"id\": 3, \"event\": \"logout\", \"user\": \"ada\"}\n]"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Nested records",
+                input: "[{\"user\": {\"name\": \"Ada\", \"langs\": [\"en\", \"fr\"]}}, {\"user\": {\"name\": \"Grace\", \"langs\": []}}]",
+            },
+            Example {
+                name: "Strings with line breaks",
+                input: "[\"first\\nline\", \"second\"]",
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::JsonlToArray;
+
+    #[test]
+    fn test_array_to_jsonl() {
+        assert_eq!(
+            ArrayToJsonl
+                .transform(ArrayToJsonl.default_test_input())
+                .unwrap(),
+            "{\"id\":1,\"event\":\"login\",\"user\":\"ada\"}\n{\"id\":2,\"event\":\"upload\",\"user\":\"grace\",\"files\":3}\n{\"id\":3,\"event\":\"logout\",\"user\":\"ada\"}"
+        );
+        // Line breaks inside strings stay escaped, one value per line
+        assert_eq!(
+            ArrayToJsonl.transform("[\"a\\nb\", 1.50, []]").unwrap(),
+            "\"a\\nb\"\n1.50\n[]"
+        );
+        assert_eq!(ArrayToJsonl.transform("[]").unwrap(), "");
+        assert_eq!(ArrayToJsonl.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_array_to_jsonl_round_trip() {
+        let jsonl = JsonlToArray.default_test_input().replace("\n\n", "\n");
+        let array = JsonlToArray.transform(&jsonl).unwrap();
+        assert_eq!(ArrayToJsonl.transform(&array).unwrap(), jsonl);
+    }
+
+    #[test]
+    fn test_array_to_jsonl_invalid() {
+        assert!(ArrayToJsonl.transform("{\"a\": 1}").is_err());
+        assert!(ArrayToJsonl.transform("[1,").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, StreamTransform, Transform, TransformError, TransformerCategory};
 
 /// Base64 decode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,92 +26,173 @@ impl Transform for Base64Decode {
         String::from_utf8(decoded).map_err(|_| TransformError::Utf8Error)
     }
 
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let text = std::str::from_utf8(input).map_err(|_| TransformError::Base64DecodeError)?;
+        base64_decode(text).map_err(|_| TransformError::Base64DecodeError)
+    }
+
+    fn stream(&self) -> Option<Box<dyn StreamTransform>> {
+        Some(Box::new(Base64Decoder::default()))
+    }
+
     fn default_test_input(&self) -> &'static str {
         "SGVsbG8sIFdvcmxkIQ=="
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Unicode text",
+                input: "SMOpbGxvIHfDtnJsZCDwn5GL",
+            },
+            Example {
+                name: "JSON payload",
+                input: "eyJ1c2VyIjoiYnV1cCIsInJvbGVzIjpbImFkbWluIiwiZGV2Il0sImFjdGl2ZSI6dHJ1ZX0=",
+            },
+        ]
+    }
 }
 
-/// Decodes base64 string to bytes without external dependencies
-pub(crate) fn base64_decode(input: &str) -> Result<Vec<u8>, &'static str> {
-    // Creates a mapping from each base64 character to its 6-bit value
-    fn create_lookup_table() -> [i8; 256] {
-        let mut table = [-1i8; 256];
-        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
-            .iter()
-            .enumerate()
-            .for_each(|(i, &c)| table[c as usize] = i as i8);
-        table
+// 6-bit value of each Base64 character, or INVALID
+const INVALID: u8 = 0xFF;
+const LOOKUP: [u8; 256] = {
+    let alphabet = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < alphabet.len() {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
     }
+    table
+};
 
-    let lookup = create_lookup_table();
+/// Decodes base64 string to bytes without external dependencies
+pub(crate) fn base64_decode(input: &str) -> Result<Vec<u8>, &'static str> {
     let input = input.trim().as_bytes();
+    let mut decoder = Base64Decoder::default();
+    let mut output = Vec::with_capacity(input.len() / 4 * 3 + 2);
+    decoder.decode(input, &mut output)?;
+    decoder.end(&mut output)?;
+    Ok(output)
+}
 
-    // Calculate output length (removing padding)
-    let padding = input.iter().rev().take_while(|&&c| c == b'=').count();
-    let output_len = input.len() * 3 / 4 - padding;
-
-    let mut output = vec![0u8; output_len];
-    let mut output_index = 0;
+/// Base64 decoder fed one chunk at a time, accepting the same input as
+/// `base64_decode`: surrounding whitespace and an unpadded final group are
+/// fine, padded groups may be concatenated (`aGk=aGk=` is `hihi`), but
+/// whitespace may not split the data
+#[derive(Debug, Default)]
+pub(crate) struct Base64Decoder {
+    // 6-bit values of a group split across chunks
+    group: [u8; 4],
+    group_len: usize,
+    // '=' characters in the current group
+    padding: usize,
+    // Data has started, so whitespace from here on must end the input
+    started: bool,
+    // Set once the data has ended with whitespace, to the error for anything
+    // but whitespace after it
+    ended: Option<&'static str>,
+}
 
-    // Process 4 input bytes at a time
-    for chunk in input.chunks(4) {
-        if chunk.len() < 2 {
-            return Err("Invalid base64 length");
+impl Base64Decoder {
+    fn decode(&mut self, mut input: &[u8], output: &mut Vec<u8>) -> Result<(), &'static str> {
+        while !input.is_empty() {
+            if self.group_len == 0 && self.ended.is_none() {
+                // Whole groups of alphabet characters decode straight into `output`
+                let mut decoded = 0;
+                for group in input.chunks_exact(4) {
+                    let [a, b, c, d] = [0, 1, 2, 3].map(|i| LOOKUP[group[i] as usize]);
+                    if (a | b | c | d) == INVALID {
+                        break;
+                    }
+                    output.extend_from_slice(&[a << 2 | b >> 4, b << 4 | c >> 2, c << 6 | d]);
+                    decoded += 4;
+                }
+                self.started |= decoded > 0;
+                input = &input[decoded..];
+                if input.is_empty() {
+                    break;
+                }
+            }
+            self.decode_byte(input[0], output)?;
+            input = &input[1..];
         }
+        Ok(())
+    }
 
-        // Convert each character to its 6-bit value
-        let b0 = lookup[chunk[0] as usize];
-        let b1 = lookup[chunk[1] as usize];
-
-        // Check for invalid characters
-        if b0 < 0 || b1 < 0 {
-            return Err("Invalid base64 character");
+    fn decode_byte(&mut self, byte: u8, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        if byte.is_ascii_whitespace() {
+            if self.started && self.ended.is_none() {
+                self.ended = Some("Invalid base64 character");
+            }
+            return Ok(());
         }
-
-        // Handle the first byte
-        if output_index < output_len {
-            output[output_index] = ((b0 << 2) | (b1 >> 4)) as u8;
-            output_index += 1;
+        if let Some(error) = self.ended {
+            return Err(error);
         }
+        self.started = true;
 
-        if chunk.len() > 2 {
-            // Handle padding or valid character
-            if chunk[2] == b'=' {
-                if chunk.len() < 4 || chunk[3] != b'=' {
-                    return Err("Invalid base64 padding");
-                }
-                continue; // Done with this chunk
-            }
-
-            let b2 = lookup[chunk[2] as usize];
-            if b2 < 0 {
+        if byte == b'=' {
+            // Padding can only fill the last one or two places of a group
+            if self.group_len < 2 {
                 return Err("Invalid base64 character");
             }
-
-            if output_index < output_len {
-                output[output_index] = (((b1 & 0xF) << 4) | (b2 >> 2)) as u8;
-                output_index += 1;
+            self.group[self.group_len] = 0;
+            self.group_len += 1;
+            self.padding += 1;
+            if self.group_len == 4 {
+                self.flush(output);
             }
+            return Ok(());
+        }
+        if self.padding > 0 {
+            return Err("Invalid base64 padding");
+        }
+        let value = LOOKUP[byte as usize];
+        if value == INVALID {
+            return Err("Invalid base64 character");
+        }
+        self.group[self.group_len] = value;
+        self.group_len += 1;
+        if self.group_len == 4 {
+            self.flush(output);
+        }
+        Ok(())
+    }
 
-            if chunk.len() > 3 {
-                if chunk[3] == b'=' {
-                    continue; // Done with this chunk
-                }
-
-                let b3 = lookup[chunk[3] as usize];
-                if b3 < 0 {
-                    return Err("Invalid base64 character");
-                }
+    // Writes the bytes of the (possibly padded or partial) current group
+    fn flush(&mut self, output: &mut Vec<u8>) {
+        let [a, b, c, d] = self.group;
+        let bytes = [a << 2 | b >> 4, b << 4 | c >> 2, c << 6 | d];
+        output.extend_from_slice(&bytes[..self.group_len - 1 - self.padding]);
+        self.group_len = 0;
+        self.padding = 0;
+    }
 
-                if output_index < output_len {
-                    output[output_index] = (((b2 & 0x3) << 6) | b3) as u8;
-                    output_index += 1;
-                }
+    fn end(&mut self, output: &mut Vec<u8>) -> Result<(), &'static str> {
+        match (self.group_len, self.padding) {
+            (0, _) => Ok(()),
+            (1, _) => Err("Invalid base64 length"),
+            (_, 0) => {
+                self.flush(output);
+                Ok(())
             }
+            // A group padded with a single '=' where two were needed
+            _ => Err("Invalid base64 padding"),
         }
     }
+}
 
-    Ok(output)
+impl StreamTransform for Base64Decoder {
+    fn update(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<(), TransformError> {
+        self.decode(input, output)
+            .map_err(|_| TransformError::Base64DecodeError)
+    }
+
+    fn finish(&mut self, output: &mut Vec<u8>) -> Result<(), TransformError> {
+        self.end(output)
+            .map_err(|_| TransformError::Base64DecodeError)
+    }
 }
 
 #[cfg(test)]
@@ -130,4 +211,52 @@ mod tests {
         assert_eq!(transformer.transform("").unwrap(), "");
         assert_eq!(transformer.transform("YQ==").unwrap(), "a");
     }
+
+    #[test]
+    fn test_base64_decode_binary() {
+        let transformer = Base64Decode;
+        assert_eq!(
+            transformer.transform("/w=="),
+            Err(TransformError::Utf8Error)
+        );
+        assert_eq!(transformer.transform_bytes(b"/w==").unwrap(), vec![0xff]);
+        assert!(transformer.transform_bytes(b"***").is_err());
+    }
+
+    #[test]
+    fn test_base64_decode_padding_and_whitespace() {
+        assert_eq!(base64_decode("  YWI=\n").unwrap(), b"ab");
+        assert_eq!(base64_decode("YWI").unwrap(), b"ab");
+        assert_eq!(base64_decode("YQ").unwrap(), b"a");
+        assert_eq!(base64_decode("Y"), Err("Invalid base64 length"));
+        assert_eq!(base64_decode("YQ="), Err("Invalid base64 padding"));
+        assert_eq!(base64_decode("YQ=a"), Err("Invalid base64 padding"));
+        // Concatenated padded groups decode one after the other, as they did
+        // before streaming
+        assert_eq!(base64_decode("aGk=aGk=").unwrap(), b"hihi");
+        assert_eq!(base64_decode("YQ==YQ==").unwrap(), b"aa");
+        assert_eq!(base64_decode("YQ==YQ").unwrap(), b"aa");
+        assert_eq!(base64_decode("YQ==YQ="), Err("Invalid base64 padding"));
+        assert_eq!(base64_decode("="), Err("Invalid base64 character"));
+        assert_eq!(base64_decode("YW Jj"), Err("Invalid base64 character"));
+        assert_eq!(base64_decode("YW*j"), Err("Invalid base64 character"));
+    }
+
+    #[test]
+    fn test_base64_decoder_chunks() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let input = format!(
+            " {}\n",
+            crate::transformers::base64_encode::base64_encode(&bytes)
+        );
+        for chunk_size in [1, 2, 3, 4, 5, 64, input.len()] {
+            let mut decoder = Base64Decoder::default();
+            let mut output = Vec::new();
+            for chunk in input.as_bytes().chunks(chunk_size) {
+                decoder.update(chunk, &mut output).unwrap();
+            }
+            decoder.finish(&mut output).unwrap();
+            assert_eq!(output, bytes, "chunk size {}", chunk_size);
+        }
+    }
 }
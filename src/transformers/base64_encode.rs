@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, StreamTransform, Transform, TransformError, TransformerCategory};
 
 /// Base64 encode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,42 +25,104 @@ impl Transform for Base64Encode {
         Ok(base64_encode(input.as_bytes()))
     }
 
+    // Any bytes can be encoded, not only UTF-8 text (e.g. images)
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        Ok(base64_encode(input).into_bytes())
+    }
+
+    fn stream(&self) -> Option<Box<dyn StreamTransform>> {
+        Some(Box::new(Base64Encoder::default()))
+    }
+
     fn default_test_input(&self) -> &'static str {
         "Hello, World!"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Unicode text",
+                input: "Héllo wörld 👋",
+            },
+            Example {
+                name: "JSON payload",
+                input: "{\"user\":\"buup\",\"roles\":[\"admin\",\"dev\"],\"active\":true}",
+            },
+        ]
+    }
 }
 
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
 /// Encodes bytes to base64 without external dependencies
 pub(crate) fn base64_encode(input: &[u8]) -> String {
-    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
-    const PAD: u8 = b'=';
-
     let mut output = Vec::with_capacity(input.len().div_ceil(3) * 4);
+    encode_into(input, &mut output);
+    // Only alphabet and padding characters were written
+    String::from_utf8(output).unwrap()
+}
 
-    for chunk in input.chunks(3) {
-        let b0 = chunk.first().copied().unwrap_or(0);
-        let b1 = chunk.get(1).copied().unwrap_or(0);
-        let b2 = chunk.get(2).copied().unwrap_or(0);
-
-        let n = ((b0 as u32) << 16) | ((b1 as u32) << 8) | (b2 as u32);
+// Appends the Base64 of `input` to `output`, padding a final partial group
+fn encode_into(input: &[u8], output: &mut Vec<u8>) {
+    let sextet = |n: u32, shift: u32| ALPHABET[((n >> shift) & 0x3F) as usize];
 
-        output.push(ALPHABET[((n >> 18) & 0x3F) as usize]);
-        output.push(ALPHABET[((n >> 12) & 0x3F) as usize]);
+    output.reserve(input.len().div_ceil(3) * 4);
+    let groups = input.chunks_exact(3);
+    let tail = groups.remainder();
+    for group in groups {
+        let n = (u32::from(group[0]) << 16) | (u32::from(group[1]) << 8) | u32::from(group[2]);
+        output.extend_from_slice(&[sextet(n, 18), sextet(n, 12), sextet(n, 6), sextet(n, 0)]);
+    }
+    match *tail {
+        [b0] => {
+            let n = u32::from(b0) << 16;
+            output.extend_from_slice(&[sextet(n, 18), sextet(n, 12), PAD, PAD]);
+        }
+        [b0, b1] => {
+            let n = (u32::from(b0) << 16) | (u32::from(b1) << 8);
+            output.extend_from_slice(&[sextet(n, 18), sextet(n, 12), sextet(n, 6), PAD]);
+        }
+        _ => {}
+    }
+}
 
-        output.push(if chunk.len() >= 2 {
-            ALPHABET[((n >> 6) & 0x3F) as usize]
-        } else {
-            PAD
-        });
+/// Base64 encoder fed one chunk at a time, producing the same output as
+/// `base64_encode` on the whole input
+#[derive(Debug, Default)]
+pub(crate) struct Base64Encoder {
+    // Bytes of a group split across chunks
+    pending: [u8; 3],
+    pending_len: usize,
+}
 
-        output.push(if chunk.len() >= 3 {
-            ALPHABET[(n & 0x3F) as usize]
-        } else {
-            PAD
-        });
+impl StreamTransform for Base64Encoder {
+    fn update(&mut self, mut input: &[u8], output: &mut Vec<u8>) -> Result<(), TransformError> {
+        if self.pending_len > 0 {
+            let taken = input.len().min(3 - self.pending_len);
+            self.pending[self.pending_len..self.pending_len + taken]
+                .copy_from_slice(&input[..taken]);
+            self.pending_len += taken;
+            input = &input[taken..];
+            if self.pending_len < 3 {
+                return Ok(());
+            }
+            encode_into(&self.pending, output);
+            self.pending_len = 0;
+        }
+
+        let whole = input.len() - input.len() % 3;
+        encode_into(&input[..whole], output);
+        self.pending_len = input.len() - whole;
+        self.pending[..self.pending_len].copy_from_slice(&input[whole..]);
+        Ok(())
     }
 
-    String::from_utf8(output).unwrap()
+    fn finish(&mut self, output: &mut Vec<u8>) -> Result<(), TransformError> {
+        encode_into(&self.pending[..self.pending_len], output);
+        self.pending_len = 0;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -79,4 +141,28 @@ mod tests {
         assert_eq!(transformer.transform("").unwrap(), "");
         assert_eq!(transformer.transform("a").unwrap(), "YQ==");
     }
+
+    #[test]
+    fn test_base64_encode_bytes() {
+        let transformer = Base64Encode;
+        assert_eq!(
+            transformer.transform_bytes(&[0xFF, 0x00, 0x89]).unwrap(),
+            b"/wCJ"
+        );
+    }
+
+    #[test]
+    fn test_base64_encoder_chunks() {
+        let input: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let expected = base64_encode(&input);
+        for chunk_size in [1, 2, 3, 4, 5, 64, 1000] {
+            let mut encoder = Base64Encoder::default();
+            let mut output = Vec::new();
+            for chunk in input.chunks(chunk_size) {
+                encoder.update(chunk, &mut output).unwrap();
+            }
+            encoder.finish(&mut output).unwrap();
+            assert_eq!(output, expected.as_bytes(), "chunk size {}", chunk_size);
+        }
+    }
 }
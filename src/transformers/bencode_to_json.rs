@@ -0,0 +1,275 @@
+use crate::utils::digest::to_hex;
+use crate::utils::json::JsonValue;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// Deepest list and dictionary nesting accepted
+const MAX_DEPTH: usize = 256;
+
+/// Key of the object standing in for a byte string that isn't UTF-8, such
+/// as a torrent's `pieces`
+pub(crate) const HEX_KEY: &str = "$hex";
+
+/// Bencode to JSON transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BencodeToJson;
+
+impl Transform for BencodeToJson {
+    fn name(&self) -> &'static str {
+        "Bencode to JSON"
+    }
+
+    fn id(&self) -> &'static str {
+        "bencodetojson"
+    }
+
+    fn description(&self) -> &'static str {
+        "Decodes Bencode (torrent files, tracker responses) into JSON. Byte strings that aren't UTF-8 become {\"$hex\": \"...\"} objects."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let output = self.transform_bytes(input.trim().as_bytes())?;
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    // Torrent files hold binary strings, so read them as raw bytes
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut parser = Parser { input, pos: 0 };
+        let value = parser.value(0)?;
+        if parser.pos < input.len() {
+            return Err(parser.error("Unexpected data after the end of the value"));
+        }
+        Ok(value.to_pretty_string(2).into_bytes())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "d8:announce35:http://tracker.example.com/announce4:infod6:lengthi1048576e4:name9:notes.txt12:piece lengthi262144eee"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Tracker response",
+                input: "d8:completei12e10:incompletei3e8:intervali1800e5:peersld2:ip11:203.0.113.74:porti6881eeee",
+            },
+            Example {
+                name: "List of integers",
+                input: "li-1ei0ei42ee",
+            },
+        ]
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    // Positions are 1-based byte offsets
+    fn error(&self, message: &str) -> TransformError {
+        TransformError::InvalidArgument(format!("{} at position {}", message, self.pos + 1).into())
+    }
+
+    fn value(&mut self, depth: usize) -> Result<JsonValue, TransformError> {
+        match self.input.get(self.pos) {
+            Some(b'i') => {
+                self.pos += 1;
+                let digits = self.take_until(b'e')?;
+                let text = std::str::from_utf8(digits).unwrap_or_default();
+                let magnitude = text.strip_prefix('-').unwrap_or(text);
+                let canonical = !magnitude.is_empty()
+                    && magnitude.bytes().all(|b| b.is_ascii_digit())
+                    && (magnitude == "0" || !magnitude.starts_with('0'))
+                    && text != "-0";
+                if !canonical {
+                    self.pos -= digits.len() + 1;
+                    return Err(self.error("Invalid integer"));
+                }
+                Ok(JsonValue::Number(text.to_string()))
+            }
+            Some(b'0'..=b'9') => {
+                let bytes = self.string()?;
+                Ok(match String::from_utf8(bytes.to_vec()) {
+                    Ok(text) => JsonValue::String(text),
+                    Err(_) => JsonValue::Object(vec![(
+                        HEX_KEY.to_string(),
+                        JsonValue::String(to_hex(bytes)),
+                    )]),
+                })
+            }
+            Some(b'l') | Some(b'd') if depth >= MAX_DEPTH => Err(self.error("Nesting too deep")),
+            Some(b'l') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                while !self.end_of_container()? {
+                    items.push(self.value(depth + 1)?);
+                }
+                Ok(JsonValue::Array(items))
+            }
+            Some(b'd') => {
+                self.pos += 1;
+                let mut entries: Vec<(String, JsonValue)> = Vec::new();
+                while !self.end_of_container()? {
+                    if !self.input[self.pos].is_ascii_digit() {
+                        return Err(self.error("Expected a string dictionary key"));
+                    }
+                    let start = self.pos;
+                    let key = std::str::from_utf8(self.string()?)
+                        .map(str::to_string)
+                        .map_err(|_| {
+                            self.pos = start;
+                            self.error("Dictionary key isn't UTF-8")
+                        })?;
+                    if entries.iter().any(|(existing, _)| *existing == key) {
+                        self.pos = start;
+                        return Err(self.error(&format!("Duplicate key \"{}\"", key)));
+                    }
+                    let value = self.value(depth + 1)?;
+                    entries.push((key, value));
+                }
+                Ok(JsonValue::Object(entries))
+            }
+            Some(_) => Err(self.error("Expected 'i', 'l', 'd' or a string length")),
+            None => Err(self.error("Unexpected end of input")),
+        }
+    }
+
+    // Consumes the 'e' closing a list or dictionary, if it's next
+    fn end_of_container(&mut self) -> Result<bool, TransformError> {
+        match self.input.get(self.pos) {
+            Some(b'e') => {
+                self.pos += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err(self.error("Unterminated list or dictionary")),
+        }
+    }
+
+    // A `<length>:<bytes>` string
+    fn string(&mut self) -> Result<&[u8], TransformError> {
+        let start = self.pos;
+        let digits = self.take_until(b':')?;
+        let length = std::str::from_utf8(digits)
+            .ok()
+            .filter(|d| d.bytes().all(|b| b.is_ascii_digit()))
+            .filter(|d| *d == "0" || !d.starts_with('0'))
+            .and_then(|d| d.parse::<usize>().ok())
+            .ok_or_else(|| {
+                self.pos = start;
+                self.error("Invalid string length")
+            })?;
+        if self.input.len() - self.pos < length {
+            self.pos = start;
+            return Err(self.error(&format!("String of {} bytes runs past the end", length)));
+        }
+        let bytes = &self.input[self.pos..self.pos + length];
+        self.pos += length;
+        Ok(bytes)
+    }
+
+    // The bytes up to `end`, moving past it
+    fn take_until(&mut self, end: u8) -> Result<&[u8], TransformError> {
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .iter()
+            .position(|&b| b == end)
+            .ok_or_else(|| self.error(&format!("Missing '{}'", end as char)))?;
+        self.pos += len + 1;
+        Ok(&rest[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bencode_to_json() {
+        assert_eq!(
+            BencodeToJson
+                .transform(BencodeToJson.default_test_input())
+                .unwrap(),
+            r#"{
+  "announce": "http://tracker.example.com/announce",
+  "info": {
+    "length": 1048576,
+    "name": "notes.txt",
+    "piece length": 262144
+  }
+}"#
+        );
+        assert_eq!(BencodeToJson.transform("").unwrap(), "");
+        assert_eq!(BencodeToJson.transform("0:").unwrap(), "\"\"");
+        assert_eq!(BencodeToJson.transform("le").unwrap(), "[]");
+        assert_eq!(
+            BencodeToJson
+                .transform("i123456789012345678901234567890e")
+                .unwrap(),
+            "123456789012345678901234567890"
+        );
+    }
+
+    #[test]
+    fn test_bencode_binary_strings() {
+        let mut torrent = b"d6:pieces4:".to_vec();
+        torrent.extend([0x00, 0xff, 0x10, 0x80]);
+        torrent.extend(b"4:name4:\xc3\xa9t\xc3e");
+        let output = BencodeToJson.transform_bytes(&torrent);
+        // "name" isn't valid UTF-8 either
+        assert_eq!(
+            String::from_utf8(output.unwrap()).unwrap(),
+            "{\n  \"pieces\": {\n    \"$hex\": \"00ff1080\"\n  },\n  \"name\": {\n    \"$hex\": \"c3a974c3\"\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn test_bencode_errors() {
+        let error = |input: &str| BencodeToJson.transform(input).unwrap_err().to_string();
+        assert_eq!(
+            error("i03e"),
+            "Invalid argument: Invalid integer at position 2"
+        );
+        assert_eq!(
+            error("i-0e"),
+            "Invalid argument: Invalid integer at position 2"
+        );
+        assert_eq!(
+            error("ie"),
+            "Invalid argument: Invalid integer at position 2"
+        );
+        assert_eq!(error("i12"), "Invalid argument: Missing 'e' at position 2");
+        assert_eq!(
+            error("5:abc"),
+            "Invalid argument: String of 5 bytes runs past the end at position 1"
+        );
+        assert_eq!(
+            error("l1:a"),
+            "Invalid argument: Unterminated list or dictionary at position 5"
+        );
+        assert_eq!(
+            error("di1ei2ee"),
+            "Invalid argument: Expected a string dictionary key at position 2"
+        );
+        assert_eq!(
+            error("d1:ai1e1:ai2ee"),
+            "Invalid argument: Duplicate key \"a\" at position 8"
+        );
+        assert_eq!(
+            error("i1ei2e"),
+            "Invalid argument: Unexpected data after the end of the value at position 4"
+        );
+        assert_eq!(
+            error("x"),
+            "Invalid argument: Expected 'i', 'l', 'd' or a string length at position 1"
+        );
+        assert!(BencodeToJson.transform(&"l".repeat(MAX_DEPTH + 1)).is_err());
+    }
+}
@@ -39,8 +39,8 @@ impl Transform for BinToHexTransformer {
     }
 
     fn category(&self) -> TransformerCategory {
-        // Categorizing as Encoder as it primarily changes representation
-        TransformerCategory::Encoder
+        // Decoder, as the inverse of hex_to_bin (mirrors bin_to_dec)
+        TransformerCategory::Decoder
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
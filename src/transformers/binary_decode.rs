@@ -22,40 +22,45 @@ impl Transform for BinaryDecode {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        if input.is_empty() {
-            return Ok(String::new());
-        }
-
-        let bytes: Result<Vec<u8>, _> = input
-            .split_whitespace()
-            .map(|s| {
-                if s.len() != 8 || !s.chars().all(|c| c == '0' || c == '1') {
-                    Err(TransformError::InvalidArgument(
-                        format!("Invalid 8-bit binary chunk: '{}'", s).into(),
-                    ))
-                } else {
-                    u8::from_str_radix(s, 2).map_err(|e| {
-                        TransformError::InvalidArgument(
-                            format!("Failed to parse binary chunk '{}': {}", s, e).into(),
-                        )
-                    })
-                }
-            })
-            .collect();
-
-        let bytes = bytes?;
-
+        let bytes = decode(input)?;
         String::from_utf8(bytes).map_err(|e| {
             TransformError::InvalidArgument(format!("Invalid UTF-8 sequence: {}", e).into())
         })
     }
 
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let text = std::str::from_utf8(input).map_err(|_| {
+            TransformError::InvalidArgument("Input contains non-binary characters".into())
+        })?;
+        decode(text)
+    }
+
     fn default_test_input(&self) -> &'static str {
         "01001000 01100101 01101100 01101100 01101111 00101100 00100000 01010111 01101111 01110010 01101100 01100100 00100001"
         // "Hello, World!"
     }
 }
 
+// Decodes whitespace-separated 8-bit binary chunks to bytes
+fn decode(input: &str) -> Result<Vec<u8>, TransformError> {
+    input
+        .split_whitespace()
+        .map(|s| {
+            if s.len() != 8 || !s.chars().all(|c| c == '0' || c == '1') {
+                Err(TransformError::InvalidArgument(
+                    format!("Invalid 8-bit binary chunk: '{}'", s).into(),
+                ))
+            } else {
+                u8::from_str_radix(s, 2).map_err(|e| {
+                    TransformError::InvalidArgument(
+                        format!("Failed to parse binary chunk '{}': {}", s, e).into(),
+                    )
+                })
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +133,10 @@ mod tests {
             }
             _ => panic!("Expected InvalidArgument error"),
         }
+        assert_eq!(
+            transformer.transform_bytes(b"11110000 10011111").unwrap(),
+            vec![0xf0, 0x9f]
+        );
     }
 
     #[test]
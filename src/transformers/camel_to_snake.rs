@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// CamelToSnake transformer converts camelCase/PascalCase to snake_case
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -50,6 +50,19 @@ impl Transform for CamelToSnake {
     fn default_test_input(&self) -> &'static str {
         "helloWorldExample"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Acronyms",
+                input: "parseHTTPResponseCode",
+            },
+            Example {
+                name: "Pascal case",
+                input: "UserAccountSettings",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
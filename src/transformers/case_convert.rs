@@ -0,0 +1,256 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const TO_OPTION: TransformOption = TransformOption {
+    key: "to",
+    description: "Case to convert to",
+    default: "kebab",
+    kind: TransformOptionKind::Choice(&[
+        "camel", "pascal", "snake", "constant", "kebab", "title", "sentence", "dot", "path",
+    ]),
+};
+
+/// Converts identifiers and phrases between naming conventions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaseConvert;
+
+impl Transform for CaseConvert {
+    fn name(&self) -> &'static str {
+        "Case Convert"
+    }
+
+    fn id(&self) -> &'static str {
+        "case_convert"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts each line to camelCase, PascalCase, snake_case, CONSTANT_CASE, kebab-case, Title Case, Sentence case, dot.case or path/case"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[TO_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let to = options.value(&TO_OPTION);
+        let mut lines = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            token.check_every(i)?;
+            lines.push(convert(&words(line), to));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "parseHTTPResponse"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Mixed conventions",
+                input: "user_id\nMAX-RETRY-COUNT\nXMLHttpRequest\nthe quick brown fox",
+            },
+            Example {
+                name: "File name",
+                input: "my component.v2",
+            },
+        ]
+    }
+}
+
+// Splits at anything but letters and digits, and inside words where the case
+// changes: "parseHTTPResponse" is parse, HTTP, Response
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in text.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (previous, current) = (chars[i - 1], chars[i]);
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            // aB, or the B starting a word after an acronym as in ABc
+            let boundary = current.is_uppercase()
+                && (previous.is_lowercase()
+                    || previous.is_numeric()
+                    || (previous.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(chars[start..i].iter().collect());
+                start = i;
+            }
+        }
+        if start < chars.len() {
+            words.push(chars[start..].iter().collect());
+        }
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn convert(words: &[String], to: &str) -> String {
+    let lower = || words.iter().map(|word| word.to_lowercase());
+    match to {
+        "camel" => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.to_lowercase()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+        "pascal" => words.iter().map(|word| capitalize(word)).collect(),
+        "snake" => lower().collect::<Vec<_>>().join("_"),
+        "constant" => words
+            .iter()
+            .map(|word| word.to_uppercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        "title" => words
+            .iter()
+            .map(|word| capitalize(word))
+            .collect::<Vec<_>>()
+            .join(" "),
+        "sentence" => {
+            let sentence = lower().collect::<Vec<_>>().join(" ");
+            capitalize_first(&sentence)
+        }
+        "dot" => lower().collect::<Vec<_>>().join("."),
+        "path" => lower().collect::<Vec<_>>().join("/"),
+        _ => lower().collect::<Vec<_>>().join("-"),
+    }
+}
+
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert_to(input: &str, to: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("to", to);
+        CaseConvert.transform_with_options(input, &options).unwrap()
+    }
+
+    #[test]
+    fn test_case_convert() {
+        assert_eq!(
+            CaseConvert
+                .transform(CaseConvert.default_test_input())
+                .unwrap(),
+            "parse-http-response"
+        );
+        let input = CaseConvert.examples()[0].input;
+        let expected = [
+            (
+                "camel",
+                "userId\nmaxRetryCount\nxmlHttpRequest\ntheQuickBrownFox",
+            ),
+            (
+                "pascal",
+                "UserId\nMaxRetryCount\nXmlHttpRequest\nTheQuickBrownFox",
+            ),
+            (
+                "snake",
+                "user_id\nmax_retry_count\nxml_http_request\nthe_quick_brown_fox",
+            ),
+            (
+                "constant",
+                "USER_ID\nMAX_RETRY_COUNT\nXML_HTTP_REQUEST\nTHE_QUICK_BROWN_FOX",
+            ),
+            (
+                "kebab",
+                "user-id\nmax-retry-count\nxml-http-request\nthe-quick-brown-fox",
+            ),
+            (
+                "title",
+                "User Id\nMax Retry Count\nXml Http Request\nThe Quick Brown Fox",
+            ),
+            (
+                "sentence",
+                "User id\nMax retry count\nXml http request\nThe quick brown fox",
+            ),
+            (
+                "dot",
+                "user.id\nmax.retry.count\nxml.http.request\nthe.quick.brown.fox",
+            ),
+            (
+                "path",
+                "user/id\nmax/retry/count\nxml/http/request\nthe/quick/brown/fox",
+            ),
+        ];
+        for (to, output) in expected {
+            assert_eq!(convert_to(input, to), output, "to {}", to);
+        }
+    }
+
+    #[test]
+    fn test_case_convert_words() {
+        assert_eq!(words("parseHTTPResponse"), ["parse", "HTTP", "Response"]);
+        assert_eq!(
+            words("  __version2Update--ID "),
+            ["version2", "Update", "ID"]
+        );
+        assert_eq!(words("ÉcoleNormale straße"), ["École", "Normale", "straße"]);
+        assert_eq!(words("sha256sum"), ["sha256sum"]);
+        assert!(words("--").is_empty());
+        assert_eq!(convert_to("", "camel"), "");
+        assert_eq!(convert_to("a\n\nb", "constant"), "A\n\nB");
+    }
+
+    #[test]
+    fn test_case_convert_round_trips() {
+        for to in [
+            "camel", "pascal", "snake", "constant", "kebab", "dot", "path",
+        ] {
+            let converted = convert_to("fetchUserProfileV2", to);
+            assert_eq!(
+                convert_to(&converted, "camel"),
+                "fetchUserProfileV2",
+                "via {}",
+                to
+            );
+        }
+        assert!(CaseConvert
+            .transform_with_options("x", &{
+                let mut options = TransformOptions::new();
+                options.set("to", "shouty");
+                options
+            })
+            .is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Color Code Converter transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -324,6 +324,23 @@ impl Transform for ColorCodeConvert {
     fn default_test_input(&self) -> &'static str {
         "#FF0000"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "RGB",
+                input: "rgb(52, 152, 219)",
+            },
+            Example {
+                name: "HSL",
+                input: "hsl(204, 70%, 53%)",
+            },
+            Example {
+                name: "CMYK",
+                input: "cmyk(76%, 31%, 0%, 14%)",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
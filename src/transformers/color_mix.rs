@@ -0,0 +1,209 @@
+use crate::utils::Color;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const RATIO_OPTION: TransformOption = TransformOption {
+    key: "ratio",
+    description: "Percentage of the second color in the mix",
+    default: "50",
+    kind: TransformOptionKind::Integer,
+};
+
+const MODE_OPTION: TransformOption = TransformOption {
+    key: "mode",
+    description: "How the second color is blended onto the first",
+    default: "normal",
+    kind: TransformOptionKind::Choice(&["normal", "multiply", "screen"]),
+};
+
+const FORMAT_OPTION: TransformOption = TransformOption {
+    key: "format",
+    description: "Notation of the mixed color",
+    default: "hex",
+    kind: TransformOptionKind::Choice(&["hex", "rgb", "hsl"]),
+};
+
+/// Color mixing transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorMix;
+
+impl Transform for ColorMix {
+    fn name(&self) -> &'static str {
+        "Color Mix"
+    }
+
+    fn id(&self) -> &'static str {
+        "color_mix"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Blends two colors (one per line) by a ratio, with normal, multiply or screen blending"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[RATIO_OPTION, MODE_OPTION, FORMAT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let ratio = options.usize_value(&RATIO_OPTION)?;
+        if ratio > 100 {
+            return Err(TransformError::InvalidArgument(
+                "Mix ratio must be between 0 and 100".into(),
+            ));
+        }
+        let (first, second) = split_colors(input)?;
+        let blend: fn(u8, u8) -> u8 = match options.value(&MODE_OPTION) {
+            "multiply" => |a, b| ((u16::from(a) * u16::from(b) + 127) / 255) as u8,
+            "screen" => |a, b| 255 - ((u16::from(255 - a) * u16::from(255 - b) + 127) / 255) as u8,
+            _ => |_, b| b,
+        };
+
+        let mixed = mix(&first, &second, ratio as f64 / 100.0, blend);
+        Ok(match options.value(&FORMAT_OPTION) {
+            "rgb" => mixed.to_rgb(),
+            "hsl" => mixed.to_hsl(),
+            _ => mixed.to_hex(),
+        })
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "#ff0000\n#0000ff"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Named colors",
+                input: "gold\nrebeccapurple",
+            },
+            Example {
+                name: "Mixed notations",
+                input: "rgb(255, 255, 255)\nhsl(200deg, 80%, 40%)",
+            },
+        ]
+    }
+}
+
+// Two colors on separate lines, or two whitespace separated names or hex codes
+fn split_colors(input: &str) -> Result<(Color, Color), TransformError> {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    let parts: Vec<&str> = match lines.as_slice() {
+        [line] => line.split_whitespace().collect(),
+        _ => lines,
+    };
+    match parts.as_slice() {
+        [first, second] => Ok((Color::parse(first)?, Color::parse(second)?)),
+        _ => Err(TransformError::InvalidArgument(
+            "Expected two colors, one per line".into(),
+        )),
+    }
+}
+
+// Lays `second`, blended with `blend`, over `first` at an opacity of `ratio`
+fn mix(first: &Color, second: &Color, ratio: f64, blend: fn(u8, u8) -> u8) -> Color {
+    let lerp = |a: u8, b: u8| (f64::from(a) * (1.0 - ratio) + f64::from(b) * ratio).round() as u8;
+    let channel = |a: u8, b: u8| lerp(a, blend(a, b));
+    Color {
+        r: channel(first.r, second.r),
+        g: channel(first.g, second.g),
+        b: channel(first.b, second.b),
+        a: match (first.a, second.a) {
+            (None, None) => None,
+            (a, b) => Some(lerp(a.unwrap_or(255), b.unwrap_or(255))),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mix_with(input: &str, settings: &[(&str, &str)]) -> Result<String, TransformError> {
+        let options: TransformOptions = settings.iter().copied().collect();
+        ColorMix.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_color_mix_normal() {
+        assert_eq!(ColorMix.transform("#ff0000\n#0000ff").unwrap(), "#800080");
+        assert_eq!(
+            mix_with("#ff0000\n#0000ff", &[("ratio", "25")]).unwrap(),
+            "#bf0040"
+        );
+        assert_eq!(mix_with("red blue", &[("ratio", "0")]).unwrap(), "#ff0000");
+        assert_eq!(
+            mix_with("red blue", &[("ratio", "100")]).unwrap(),
+            "#0000ff"
+        );
+        assert_eq!(
+            mix_with(
+                "rgb(255, 255, 255)\nhsl(0deg, 0%, 0%)",
+                &[("format", "rgb")]
+            )
+            .unwrap(),
+            "rgb(128,128,128)"
+        );
+    }
+
+    #[test]
+    fn test_color_mix_blend_modes() {
+        let full = [("ratio", "100")];
+        assert_eq!(
+            mix_with("#ff8000\n#808080", &[full[0], ("mode", "multiply")]).unwrap(),
+            "#804000"
+        );
+        assert_eq!(
+            mix_with("#ff8000\n#808080", &[full[0], ("mode", "screen")]).unwrap(),
+            "#ffc080"
+        );
+        // Half way between the first color and the blended one
+        assert_eq!(
+            mix_with("#ffffff\n#000000", &[("mode", "multiply")]).unwrap(),
+            "#808080"
+        );
+        assert_eq!(
+            mix_with(
+                "#ff0000\n#00ff00",
+                &[full[0], ("mode", "screen"), ("format", "hsl")]
+            )
+            .unwrap(),
+            "hsl(60deg,100%,50%)"
+        );
+    }
+
+    #[test]
+    fn test_color_mix_alpha() {
+        assert_eq!(
+            ColorMix.transform("#ff000000\n#0000ff").unwrap(),
+            "#80008080"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(ColorMix.transform("#ff0000").is_err());
+        assert!(ColorMix.transform("#ff0000\n#00ff00\n#0000ff").is_err());
+        assert!(ColorMix.transform("#ff0000\nnotacolor").is_err());
+        assert!(mix_with("red\nblue", &[("ratio", "150")]).is_err());
+        assert!(mix_with("red\nblue", &[("mode", "overlay")]).is_err());
+    }
+}
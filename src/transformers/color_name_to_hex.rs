@@ -0,0 +1,79 @@
+use crate::utils::Color;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// CSS color name to Hex color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorNameToHex;
+
+impl Transform for ColorNameToHex {
+    fn name(&self) -> &'static str {
+        "Color Name to Hex"
+    }
+
+    fn id(&self) -> &'static str {
+        "color_name_to_hex"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts a CSS color name (e.g. rebeccapurple) to its hex code"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let color = Color::from_name(input)?;
+        Ok(color.to_hex())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "rebeccapurple"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Tomato",
+                input: "tomato",
+            },
+            Example {
+                name: "Cornflower blue",
+                input: "CornflowerBlue",
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::color::CSS_NAMED_COLORS;
+
+    #[test]
+    fn test_color_name_to_hex() {
+        let transformer = ColorNameToHex;
+        assert_eq!(transformer.transform("rebeccapurple").unwrap(), "#663399");
+        assert_eq!(transformer.transform("  Tomato\n").unwrap(), "#ff6347");
+        assert_eq!(transformer.transform("aliceblue").unwrap(), "#f0f8ff");
+        assert_eq!(transformer.transform("yellowgreen").unwrap(), "#9acd32");
+        assert_eq!(
+            transformer.transform("grey").unwrap(),
+            transformer.transform("gray").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_table_is_sorted() {
+        // Lookups use binary search
+        assert!(CSS_NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = ColorNameToHex;
+        assert!(transformer.transform("").is_err());
+        assert!(transformer.transform("notacolor").is_err());
+        assert!(transformer.transform("#ff0000").is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use crate::utils::random::{rng_from_options, Rng, SEED_OPTION};
+use crate::utils::Color;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// Upper bound on `count`, so a typo can't produce megabytes of colors
+const MAX_COUNT: usize = 1000;
+
+const COUNT_OPTION: TransformOption = TransformOption {
+    key: "count",
+    description: "Number of colors to generate (at most 1000)",
+    default: "5",
+    kind: TransformOptionKind::Integer,
+};
+
+const STYLE_OPTION: TransformOption = TransformOption {
+    key: "style",
+    description: "Limits colors to soft pastels or dark shades",
+    default: "any",
+    kind: TransformOptionKind::Choice(&["any", "pastel", "dark"]),
+};
+
+const HUE_OPTION: TransformOption = TransformOption {
+    key: "hue",
+    description: "Hue range in degrees such as 180-240, or 330-30 across red (empty for any)",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+/// Random color generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorRandom;
+
+impl Transform for ColorRandom {
+    fn name(&self) -> &'static str {
+        "Random Colors"
+    }
+
+    fn id(&self) -> &'static str {
+        "color_random"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Generator
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates random hex colors, one per line, optionally pastel, dark or within a hue range. Ignores its input."
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[COUNT_OPTION, STYLE_OPTION, HUE_OPTION, SEED_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        _input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let count = options.usize_value(&COUNT_OPTION)?;
+        if count == 0 || count > MAX_COUNT {
+            return Err(TransformError::InvalidArgument(
+                format!("Count must be between 1 and {}", MAX_COUNT).into(),
+            ));
+        }
+        let (hue_start, hue_width) = parse_hue_range(options.value(&HUE_OPTION))?;
+        // Saturation and value ranges in HSV for each style
+        let (saturation, value) = match options.value(&STYLE_OPTION) {
+            "pastel" => ((0.15, 0.4), (0.9, 1.0)),
+            "dark" => ((0.4, 1.0), (0.15, 0.45)),
+            _ => ((0.0, 1.0), (0.0, 1.0)),
+        };
+
+        let mut rng = rng_from_options(options)?;
+        let colors: Vec<String> = (0..count)
+            .map(|_| {
+                let h = hue_start + rng.next_f64() * hue_width;
+                let s = between(&mut rng, saturation);
+                let v = between(&mut rng, value);
+                let (r, g, b) = Color::hsv_to_rgb(h, s, v);
+                Color { r, g, b, a: None }.to_hex()
+            })
+            .collect();
+        Ok(colors.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        ""
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "No input needed",
+            input: "",
+        }]
+    }
+}
+
+fn between(rng: &mut Rng, (low, high): (f64, f64)) -> f64 {
+    low + rng.next_f64() * (high - low)
+}
+
+// The start and width of a hue range in degrees; the range wraps past 360
+// when the end is smaller than the start
+fn parse_hue_range(range: &str) -> Result<(f64, f64), TransformError> {
+    let range = range.trim();
+    if range.is_empty() {
+        return Ok((0.0, 360.0));
+    }
+    let degrees = |text: &str| {
+        text.trim()
+            .trim_end_matches("deg")
+            .parse::<f64>()
+            .ok()
+            .filter(|hue| (0.0..=360.0).contains(hue))
+            .ok_or_else(|| {
+                TransformError::InvalidArgument(
+                    format!("Invalid hue '{}', expected 0 to 360", text.trim()).into(),
+                )
+            })
+    };
+    match range.split_once('-') {
+        Some((start, end)) => {
+            let (start, end) = (degrees(start)?, degrees(end)?);
+            let width = if end >= start {
+                end - start
+            } else {
+                end + 360.0 - start
+            };
+            Ok((start, width))
+        }
+        None => Ok((degrees(range)?, 0.0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate(settings: &[(&str, &str)]) -> Result<Vec<Color>, TransformError> {
+        let options: TransformOptions = settings.iter().copied().collect();
+        let output = ColorRandom.transform_with_options("", &options)?;
+        Ok(output
+            .lines()
+            .map(|line| Color::from_hex(line).unwrap())
+            .collect())
+    }
+
+    #[test]
+    fn test_color_random_count_and_seed() {
+        let mut options = TransformOptions::new();
+        options.set("seed", "7");
+        let colors = ColorRandom.transform_with_options("", &options).unwrap();
+        assert_eq!(colors.lines().count(), 5);
+        assert_eq!(
+            colors,
+            ColorRandom.transform_with_options("", &options).unwrap()
+        );
+        options.set("seed", "8");
+        assert_ne!(
+            colors,
+            ColorRandom.transform_with_options("", &options).unwrap()
+        );
+        assert_eq!(generate(&[("count", "40")]).unwrap().len(), 40);
+    }
+
+    #[test]
+    fn test_color_random_styles() {
+        for color in generate(&[("style", "pastel"), ("count", "50")]).unwrap() {
+            let (_, s, v) = Color::rgb_to_hsv(color.r, color.g, color.b);
+            assert!(s <= 0.41 && v >= 0.89, "{} is not pastel", color.to_hex());
+        }
+        for color in generate(&[("style", "dark"), ("count", "50")]).unwrap() {
+            let (_, _, v) = Color::rgb_to_hsv(color.r, color.g, color.b);
+            assert!(v <= 0.46, "{} is not dark", color.to_hex());
+        }
+    }
+
+    #[test]
+    fn test_color_random_hue_range() {
+        let settings = [("style", "dark"), ("count", "50"), ("hue", "330-30")];
+        for color in generate(&settings).unwrap() {
+            let (h, _, _) = Color::rgb_to_hsv(color.r, color.g, color.b);
+            assert!(!(36.0..324.0).contains(&h), "{} is not red", color.to_hex());
+        }
+        assert_eq!(parse_hue_range("0-360").unwrap(), (0.0, 360.0));
+        assert_eq!(
+            generate(&[("hue", "120"), ("style", "pastel"), ("count", "1")])
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_color_random_invalid_options() {
+        assert!(generate(&[("count", "0")]).is_err());
+        assert!(generate(&[("count", "1001")]).is_err());
+        assert!(generate(&[("hue", "400")]).is_err());
+        assert!(generate(&[("hue", "red")]).is_err());
+        assert!(generate(&[("style", "neon")]).is_err());
+    }
+}
@@ -0,0 +1,273 @@
+use super::dedent_text::common_indent;
+use super::indent_text::split_lines;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+pub(crate) const LANGUAGE_OPTION: TransformOption = TransformOption {
+    key: "language",
+    description: "Comment syntax to use; c also covers C++, Java, C#, Go and Kotlin, shell covers Bash, YAML and TOML",
+    default: "rust",
+    kind: TransformOptionKind::Choice(&[
+        "rust",
+        "c",
+        "javascript",
+        "python",
+        "shell",
+        "sql",
+        "html",
+        "css",
+    ]),
+};
+
+/// A string literal delimiter and whether backslash escapes apply inside it
+pub(crate) struct Quote {
+    pub delimiter: &'static str,
+    pub escapes: bool,
+}
+
+/// How comments and strings are written in one language
+pub(crate) struct Syntax {
+    pub line: Option<&'static str>,
+    pub block: Option<(&'static str, &'static str)>,
+    /// Block comments nest, as in Rust
+    pub nested: bool,
+    /// Quotes are tried in order, so longer delimiters come first
+    pub quotes: &'static [Quote],
+    /// Line comments must start a word, as in shell where `a#b` is one word
+    pub line_at_word_start: bool,
+    /// Rust: ' starts a char literal only when one closes it, otherwise it's a lifetime
+    pub char_literals: bool,
+}
+
+const C_LIKE: Syntax = Syntax {
+    line: Some("//"),
+    block: Some(("/*", "*/")),
+    nested: false,
+    quotes: &[
+        Quote {
+            delimiter: "\"",
+            escapes: true,
+        },
+        Quote {
+            delimiter: "'",
+            escapes: true,
+        },
+    ],
+    line_at_word_start: false,
+    char_literals: false,
+};
+
+pub(crate) fn syntax(language: &str) -> Syntax {
+    match language {
+        "rust" => Syntax {
+            nested: true,
+            quotes: &[Quote {
+                delimiter: "\"",
+                escapes: true,
+            }],
+            char_literals: true,
+            ..C_LIKE
+        },
+        "javascript" => Syntax {
+            quotes: &[
+                Quote {
+                    delimiter: "\"",
+                    escapes: true,
+                },
+                Quote {
+                    delimiter: "'",
+                    escapes: true,
+                },
+                Quote {
+                    delimiter: "`",
+                    escapes: true,
+                },
+            ],
+            ..C_LIKE
+        },
+        "python" => Syntax {
+            line: Some("#"),
+            block: None,
+            quotes: &[
+                Quote {
+                    delimiter: "\"\"\"",
+                    escapes: true,
+                },
+                Quote {
+                    delimiter: "'''",
+                    escapes: true,
+                },
+                Quote {
+                    delimiter: "\"",
+                    escapes: true,
+                },
+                Quote {
+                    delimiter: "'",
+                    escapes: true,
+                },
+            ],
+            ..C_LIKE
+        },
+        "shell" => Syntax {
+            line: Some("#"),
+            block: None,
+            quotes: &[
+                Quote {
+                    delimiter: "\"",
+                    escapes: true,
+                },
+                Quote {
+                    delimiter: "'",
+                    escapes: false,
+                },
+            ],
+            line_at_word_start: true,
+            ..C_LIKE
+        },
+        // '' inside a string reads as the string closing and reopening
+        "sql" => Syntax {
+            line: Some("--"),
+            quotes: &[
+                Quote {
+                    delimiter: "'",
+                    escapes: false,
+                },
+                Quote {
+                    delimiter: "\"",
+                    escapes: false,
+                },
+            ],
+            ..C_LIKE
+        },
+        "html" => Syntax {
+            line: None,
+            block: Some(("<!--", "-->")),
+            quotes: &[],
+            ..C_LIKE
+        },
+        "css" => Syntax {
+            line: None,
+            ..C_LIKE
+        },
+        _ => C_LIKE,
+    }
+}
+
+/// Comment out transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentAdd;
+
+impl Transform for CommentAdd {
+    fn name(&self) -> &'static str {
+        "Comment Add"
+    }
+
+    fn id(&self) -> &'static str {
+        "comment_add"
+    }
+
+    fn description(&self) -> &'static str {
+        "Comments out every line in the chosen language's syntax, using line comments where it has them and wrapping each line in a block comment where it doesn't"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[LANGUAGE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let syntax = syntax(options.value(&LANGUAGE_OPTION));
+        // Markers line up at the shallowest indent, like an editor's toggle comment
+        let indent = common_indent(input);
+
+        let mut out = String::with_capacity(input.len() * 2);
+        for (i, (line, ending)) in split_lines(input).enumerate() {
+            token.check_every(i)?;
+            if !line.trim().is_empty() {
+                let code = &line[indent.len()..];
+                out.push_str(indent);
+                match (syntax.line, syntax.block) {
+                    (Some(marker), _) => {
+                        out.push_str(marker);
+                        out.push(' ');
+                        out.push_str(code);
+                    }
+                    (None, Some((open, close))) => {
+                        out.push_str(open);
+                        out.push(' ');
+                        out.push_str(code);
+                        out.push(' ');
+                        out.push_str(close);
+                    }
+                    (None, None) => out.push_str(code),
+                }
+            }
+            out.push_str(ending);
+        }
+        Ok(out)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "fn main() {\n    let answer = 42;\n\n    println!(\"{answer}\");\n}"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Indented block",
+            input: "    if debug:\n        print(state)",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(input: &str, language: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("language", language);
+        CommentAdd.transform_with_options(input, &options).unwrap()
+    }
+
+    #[test]
+    fn test_comment_add() {
+        assert_eq!(
+            CommentAdd
+                .transform(CommentAdd.default_test_input())
+                .unwrap(),
+            "// fn main() {\n//     let answer = 42;\n\n//     println!(\"{answer}\");\n// }"
+        );
+        assert_eq!(
+            comment(CommentAdd.examples()[0].input, "python"),
+            "    # if debug:\n    #     print(state)"
+        );
+        assert_eq!(comment("SELECT 1;\r\n", "sql"), "-- SELECT 1;\r\n");
+        assert_eq!(CommentAdd.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_comment_add_block_only() {
+        assert_eq!(
+            comment("<p>\n  Hi\n</p>", "html"),
+            "<!-- <p> -->\n<!--   Hi -->\n<!-- </p> -->"
+        );
+        assert_eq!(
+            comment("  a { color: red; }", "css"),
+            "  /* a { color: red; } */"
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use super::comment_add::{syntax, LANGUAGE_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// Comment stripper transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentRemove;
+
+impl Transform for CommentRemove {
+    fn name(&self) -> &'static str {
+        "Comment Remove"
+    }
+
+    fn id(&self) -> &'static str {
+        "comment_remove"
+    }
+
+    fn description(&self) -> &'static str {
+        "Strips line and block comments from code in the chosen language, leaving string literals alone and dropping lines that held nothing but a comment"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[LANGUAGE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let syntax = syntax(options.value(&LANGUAGE_OPTION));
+
+        let mut out = Output::default();
+        let mut i = 0;
+        // A shebang looks like a comment but isn't one
+        if syntax.line == Some("#") && input.starts_with("#!") {
+            i = input.find('\n').unwrap_or(input.len());
+            out.text.push_str(&input[..i]);
+        }
+
+        while i < input.len() {
+            token.check_every(i)?;
+            let rest = &input[i..];
+
+            if let Some((open, close)) = syntax.block.filter(|(open, _)| rest.starts_with(open)) {
+                let end = block_comment_end(input, i, open, close, syntax.nested)
+                    .ok_or_else(|| unterminated("comment", input, i))?;
+                // A comment between two words still separates them
+                let word = |c: char| c.is_alphanumeric() || c == '_';
+                let before = out.text.chars().last().is_some_and(word);
+                let after = input[end..].chars().next().is_some_and(word);
+                if before && after {
+                    out.text.push(' ');
+                }
+                out.had_comment = true;
+                i = end;
+                // And one between two spaces shouldn't leave both behind
+                if out.text.ends_with(' ') && input[end..].starts_with(' ') {
+                    i += 1;
+                }
+                continue;
+            }
+
+            if let Some(marker) = syntax.line.filter(|marker| rest.starts_with(marker)) {
+                let word_start = !syntax.line_at_word_start
+                    || input[..i].chars().last().is_none_or(char::is_whitespace);
+                if word_start {
+                    i = input[i..].find('\n').map_or(input.len(), |end| i + end);
+                    out.had_comment = true;
+                    continue;
+                }
+                out.text.push_str(marker);
+                i += marker.len();
+                continue;
+            }
+
+            if let Some(quote) = syntax
+                .quotes
+                .iter()
+                .find(|quote| rest.starts_with(quote.delimiter))
+            {
+                let end = string_end(input, i, quote.delimiter, quote.escapes)
+                    .ok_or_else(|| unterminated("string", input, i))?;
+                out.push_code(&input[i..end]);
+                i = end;
+                continue;
+            }
+
+            let c = rest.chars().next().unwrap_or_default();
+            if c == '\'' && syntax.char_literals {
+                // 'a' and '\n' are chars, while 'a in <'a> is a lifetime
+                let end = char_literal_end(rest).map_or(1, |end| end);
+                out.text.push_str(&rest[..end]);
+                i += end;
+            } else if c == '\n' {
+                out.end_line("\n");
+                i += 1;
+            } else {
+                out.text.push(c);
+                i += c.len_utf8();
+            }
+        }
+        out.end_line("");
+        Ok(out.text)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "/// Adds one\nfn add_one(x: i32) -> i32 {\n    // TODO: overflow\n    x + 1 /* for now */\n}\n\nconst URL: &str = \"https://example.com\"; // not a comment inside the string\n"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Nested block comment",
+            input: "fn f<'a>(s: &'a str) -> char {\n    /* outer /* inner */ still a comment */\n    '/'\n}",
+        }]
+    }
+}
+
+#[derive(Default)]
+struct Output {
+    text: String,
+    // Where the current line starts in text
+    line_start: usize,
+    // Whether a comment was removed from the current line
+    had_comment: bool,
+}
+
+impl Output {
+    // Copies code that may span lines, such as a multi-line string
+    fn push_code(&mut self, code: &str) {
+        self.text.push_str(code);
+        if let Some(newline) = code.rfind('\n') {
+            self.line_start = self.text.len() - (code.len() - newline - 1);
+            self.had_comment = false;
+        }
+    }
+
+    fn end_line(&mut self, newline: &str) {
+        if self.had_comment {
+            let line = self.text[self.line_start..].trim_end();
+            let crlf = self.text.ends_with('\r');
+            if line.is_empty() {
+                // Nothing but the comment was on this line
+                self.text.truncate(self.line_start);
+                return;
+            }
+            self.text.truncate(self.line_start + line.len());
+            if crlf {
+                self.text.push('\r');
+            }
+        }
+        self.text.push_str(newline);
+        self.line_start = self.text.len();
+        self.had_comment = false;
+    }
+}
+
+// Index just past the block comment opened at start
+fn block_comment_end(
+    input: &str,
+    start: usize,
+    open: &str,
+    close: &str,
+    nested: bool,
+) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = start;
+    while i < input.len() {
+        let rest = &input[i..];
+        if rest.starts_with(close) {
+            depth -= 1;
+            i += close.len();
+            if depth == 0 {
+                return Some(i);
+            }
+        } else if rest.starts_with(open) && (nested || depth == 0) {
+            depth += 1;
+            i += open.len();
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    None
+}
+
+// Index just past the string opened at start
+fn string_end(input: &str, start: usize, delimiter: &str, escapes: bool) -> Option<usize> {
+    let mut chars = input[start + delimiter.len()..].char_indices();
+    while let Some((offset, c)) = chars.next() {
+        let i = start + delimiter.len() + offset;
+        if escapes && c == '\\' {
+            chars.next();
+        } else if input[i..].starts_with(delimiter) {
+            return Some(i + delimiter.len());
+        }
+    }
+    None
+}
+
+// Length of a char literal at the start of rest, if it is one
+fn char_literal_end(rest: &str) -> Option<usize> {
+    let mut chars = rest.char_indices().skip(1);
+    let (_, c) = chars.next()?;
+    if c == '\\' {
+        // Escapes run at most to '\u{10FFFF}'
+        return chars.take(10).find(|&(_, c)| c == '\'').map(|(i, _)| i + 1);
+    }
+    chars.next().filter(|&(_, c)| c == '\'').map(|(i, _)| i + 1)
+}
+
+fn unterminated(what: &str, input: &str, start: usize) -> TransformError {
+    TransformError::InvalidArgument(
+        format!(
+            "Unterminated {} starting at position {}",
+            what,
+            input[..start].chars().count() + 1
+        )
+        .into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip(input: &str, language: &str) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("language", language);
+        CommentRemove.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_comment_remove() {
+        assert_eq!(
+            CommentRemove
+                .transform(CommentRemove.default_test_input())
+                .unwrap(),
+            "fn add_one(x: i32) -> i32 {\n    x + 1\n}\n\nconst URL: &str = \"https://example.com\";\n"
+        );
+        assert_eq!(
+            CommentRemove
+                .transform(CommentRemove.examples()[0].input)
+                .unwrap(),
+            "fn f<'a>(s: &'a str) -> char {\n    '/'\n}"
+        );
+        assert_eq!(strip("a/* x */b\r\n// c\r\nd", "c").unwrap(), "a b\r\nd");
+        assert_eq!(
+            strip("let s = 'it\\'s // fine'; // gone", "javascript").unwrap(),
+            "let s = 'it\\'s // fine';"
+        );
+    }
+
+    #[test]
+    fn test_comment_remove_languages() {
+        assert_eq!(
+            strip("#!/bin/sh\n# setup\necho a#b \"#x\" # done\n", "shell").unwrap(),
+            "#!/bin/sh\necho a#b \"#x\"\n"
+        );
+        assert_eq!(
+            strip("x = \"\"\"\n# kept\n\"\"\"  # dropped\n", "python").unwrap(),
+            "x = \"\"\"\n# kept\n\"\"\"\n"
+        );
+        assert_eq!(
+            strip("SELECT 'it''s -- text' -- why\nFROM t /* a\nb */;", "sql").unwrap(),
+            "SELECT 'it''s -- text'\nFROM t ;"
+        );
+        assert_eq!(
+            strip("<p>\n  <!-- note -->\n  Hi<!-- x -->!\n</p>", "html").unwrap(),
+            "<p>\n  Hi!\n</p>"
+        );
+        // CSS has no line comments, so // inside a url is safe
+        assert_eq!(
+            strip("a { background: url(//x.png); /* y */ }", "css").unwrap(),
+            "a { background: url(//x.png); }"
+        );
+    }
+
+    #[test]
+    fn test_comment_remove_errors() {
+        let error = strip("a /* b", "c").unwrap_err();
+        assert_eq!(error.position(), Some(3));
+        assert!(strip("x = \"abc", "python").is_err());
+        assert_eq!(strip("", "rust").unwrap(), "");
+    }
+}
@@ -0,0 +1,234 @@
+use super::deflate_compress::{self, deflate_bytes_with_cancel};
+use super::gzip_compress::gzip_bytes;
+use super::lz4_compress::lz4_compress_block;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// Compresses the input with every available compressor and reports the
+/// sizes side by side, with an order-0 entropy estimate for reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionAnalyze;
+
+impl Transform for CompressionAnalyze {
+    fn name(&self) -> &'static str {
+        "Compression Analyzer"
+    }
+
+    fn id(&self) -> &'static str {
+        "compressionanalyze"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Compression
+    }
+
+    fn description(&self) -> &'static str {
+        "Compares the output size of each compressor on the input, with its entropy, to help pick one."
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Buup transforms text. Buup transforms text. Buup transforms text, again and again and again."
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Repetitive text",
+                input: "Buup transforms text. Buup transforms text. Buup transforms text, again and again and again.",
+            },
+            Example {
+                name: "JSON",
+                input: r#"[{"id":1,"name":"Ada","active":true},{"id":2,"name":"Grace","active":true},{"id":3,"name":"Linus","active":false}]"#,
+            },
+        ]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new().with_cancel(token.clone()))
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[deflate_compress::LEVEL_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let output = self.transform_bytes_configured(input.as_bytes(), options, token)?;
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let level = deflate_compress::level_value(options)?;
+        let results = [
+            (
+                "deflate",
+                deflate_bytes_with_cancel(input, level, token)?.len(),
+            ),
+            ("gzip", gzip_bytes(input, level, token)?.len()),
+            ("lz4", lz4_compress_block(input, token)?.len()),
+        ];
+        Ok(format_report(input, &results).into_bytes())
+    }
+}
+
+fn format_report(input: &[u8], results: &[(&str, usize)]) -> String {
+    let entropy = entropy(input);
+    let mut report = format!(
+        "Original: {} bytes\nEntropy: {:.2} bits/byte (at least {} bytes without modelling repeats)\n",
+        input.len(),
+        entropy,
+        (entropy * input.len() as f64 / 8.0).ceil() as usize
+    );
+
+    let rows: Vec<[String; 4]> = results
+        .iter()
+        .map(|&(codec, size)| {
+            [
+                codec.to_string(),
+                size.to_string(),
+                ratio(size, input.len()),
+                base64_len(size).to_string(),
+            ]
+        })
+        .collect();
+    let header = ["Codec", "Size", "Ratio", "Base64"];
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let line = |cells: [&str; 4]| {
+        format!(
+            "\n{:<w0$}  {:>w1$}  {:>w2$}  {:>w3$}",
+            cells[0],
+            cells[1],
+            cells[2],
+            cells[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        )
+    };
+    report.push_str(&line(header));
+    for row in &rows {
+        report.push_str(&line([&row[0], &row[1], &row[2], &row[3]]));
+    }
+
+    // The first of equally small results wins, keeping the listed order
+    let smallest = results
+        .iter()
+        .fold(None::<(&str, usize)>, |best, &(codec, size)| match best {
+            Some((_, best_size)) if best_size <= size => best,
+            _ => Some((codec, size)),
+        });
+    match smallest {
+        Some((codec, size)) if size < input.len() => {
+            report.push_str(&format!("\n\nSmallest: {}", codec))
+        }
+        _ => report.push_str("\n\nSmallest: none, the input doesn't compress"),
+    }
+    report
+}
+
+// Shannon entropy of the byte distribution, in bits per byte
+fn entropy(input: &[u8]) -> f64 {
+    let mut counts = [0usize; 256];
+    for &byte in input {
+        counts[usize::from(byte)] += 1;
+    }
+    let total = input.len() as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .fold(0.0, |sum, &count| {
+            let p = count as f64 / total;
+            sum - p * p.log2()
+        })
+}
+
+// Compressed size as a percentage of the original
+fn ratio(size: usize, original: usize) -> String {
+    if original == 0 {
+        "-".to_string()
+    } else {
+        format!("{:.1}%", size as f64 * 100.0 / original as f64)
+    }
+}
+
+fn base64_len(size: usize) -> usize {
+    size.div_ceil(3) * 4
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_analyze() {
+        let input = "abc".repeat(100);
+        let report = CompressionAnalyze.transform(&input).unwrap();
+        let deflated = deflate_compress::deflate_bytes(input.as_bytes()).unwrap();
+        assert!(report.starts_with(
+            "Original: 300 bytes\nEntropy: 1.58 bits/byte (at least 60 bytes without modelling repeats)\n\nCodec    Size"
+        ));
+        assert!(report.contains(&format!("\ndeflate  {:>4}", deflated.len())));
+        // gzip adds an 18 byte header and trailer to the same DEFLATE data
+        assert!(report.contains(&format!("\ngzip     {:>4}", deflated.len() + 18)));
+        assert!(report.ends_with("\n\nSmallest: deflate"));
+    }
+
+    #[test]
+    fn test_compression_analyze_empty_and_incompressible() {
+        let report = CompressionAnalyze.transform("").unwrap();
+        assert!(report.starts_with("Original: 0 bytes\nEntropy: 0.00 bits/byte"));
+        assert!(report.contains("\nlz4         1      -       4"));
+        assert!(report.ends_with("Smallest: none, the input doesn't compress"));
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        let report =
+            String::from_utf8(CompressionAnalyze.transform_bytes(&bytes).unwrap()).unwrap();
+        assert!(report.contains("Entropy: 8.00 bits/byte (at least 256 bytes"));
+        assert!(report.ends_with("Smallest: none, the input doesn't compress"));
+    }
+
+    #[test]
+    fn test_compression_analyze_options() {
+        let mut options = TransformOptions::new();
+        options.set("level", "12");
+        assert!(CompressionAnalyze
+            .transform_with_options("text", &options)
+            .is_err());
+
+        let token = CancelToken::new();
+        token.cancel();
+        assert!(matches!(
+            CompressionAnalyze.transform_with_cancel(&"text ".repeat(100), &token),
+            Err(TransformError::Cancelled)
+        ));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::utils::crc::{Crc, CRC16_CCITT_FALSE, CRC16_KERMIT, CRC16_MODBUS, CRC16_XMODEM};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const VARIANT_OPTION: TransformOption = TransformOption {
+    key: "variant",
+    description: "CRC-16 flavour: ccitt (CCITT-FALSE), kermit, xmodem or modbus",
+    default: "ccitt",
+    kind: TransformOptionKind::Choice(&["ccitt", "kermit", "xmodem", "modbus"]),
+};
+
+/// CRC-16 checksum transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc16;
+
+impl Transform for Crc16 {
+    fn name(&self) -> &'static str {
+        "CRC-16 Checksum"
+    }
+
+    fn id(&self) -> &'static str {
+        "crc16"
+    }
+
+    fn description(&self) -> &'static str {
+        "Computes the CRC-16 checksum of the input (CCITT, Kermit, XMODEM or Modbus variant)"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[VARIANT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        Ok(variant(options).hex_checksum(input.as_bytes()))
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        Ok(variant(options).hex_checksum(input).into_bytes())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "123456789"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Pangram",
+            input: "The quick brown fox jumps over the lazy dog",
+        }]
+    }
+}
+
+fn variant(options: &TransformOptions) -> &'static Crc {
+    match options.value(&VARIANT_OPTION) {
+        "kermit" => &CRC16_KERMIT,
+        "xmodem" => &CRC16_XMODEM,
+        "modbus" => &CRC16_MODBUS,
+        _ => &CRC16_CCITT_FALSE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crc16(input: &[u8], variant: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("variant", variant);
+        String::from_utf8(Crc16.transform_bytes_with_options(input, &options).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_crc16_variants() {
+        assert_eq!(Crc16.transform("123456789").unwrap(), "29b1");
+        assert_eq!(crc16(b"123456789", "kermit"), "2189");
+        assert_eq!(crc16(b"123456789", "xmodem"), "31c3");
+        assert_eq!(crc16(b"123456789", "modbus"), "4b37");
+    }
+
+    #[test]
+    fn test_crc16_modbus_frame() {
+        // Read holding register 0 of device 1, sent on the wire as 84 0a
+        assert_eq!(
+            crc16(&[0x01, 0x03, 0x00, 0x00, 0x00, 0x01], "modbus"),
+            "0a84"
+        );
+        assert_eq!(Crc16.transform("").unwrap(), "ffff");
+    }
+
+    #[test]
+    fn test_crc16_unknown_variant() {
+        let mut options = TransformOptions::new();
+        options.set("variant", "arc");
+        assert!(Crc16.transform_with_options("buup", &options).is_err());
+    }
+}
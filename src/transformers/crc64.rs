@@ -0,0 +1,107 @@
+use crate::utils::crc::{Crc, CRC64_ECMA_182, CRC64_ISO, CRC64_XZ};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const VARIANT_OPTION: TransformOption = TransformOption {
+    key: "variant",
+    description: "CRC-64 flavour: ecma (ECMA-182), xz or iso (GO-ISO)",
+    default: "ecma",
+    kind: TransformOptionKind::Choice(&["ecma", "xz", "iso"]),
+};
+
+/// CRC-64 checksum transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc64;
+
+impl Transform for Crc64 {
+    fn name(&self) -> &'static str {
+        "CRC-64 Checksum"
+    }
+
+    fn id(&self) -> &'static str {
+        "crc64"
+    }
+
+    fn description(&self) -> &'static str {
+        "Computes the CRC-64 checksum of the input (ECMA-182, XZ or ISO variant)"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[VARIANT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        Ok(variant(options).hex_checksum(input.as_bytes()))
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        Ok(variant(options).hex_checksum(input).into_bytes())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "123456789"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Pangram",
+            input: "The quick brown fox jumps over the lazy dog",
+        }]
+    }
+}
+
+fn variant(options: &TransformOptions) -> &'static Crc {
+    match options.value(&VARIANT_OPTION) {
+        "xz" => &CRC64_XZ,
+        "iso" => &CRC64_ISO,
+        _ => &CRC64_ECMA_182,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crc64(input: &[u8], variant: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("variant", variant);
+        String::from_utf8(Crc64.transform_bytes_with_options(input, &options).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_crc64_variants() {
+        assert_eq!(Crc64.transform("123456789").unwrap(), "6c40df5f0b497347");
+        assert_eq!(crc64(b"123456789", "xz"), "995dc9bbdf1939fa");
+        assert_eq!(crc64(b"123456789", "iso"), "b90956c775a41001");
+    }
+
+    #[test]
+    fn test_crc64_pads_to_sixteen_digits() {
+        assert_eq!(Crc64.transform("").unwrap(), "0000000000000000");
+        assert_eq!(crc64(b"", "xz"), "0000000000000000");
+    }
+}
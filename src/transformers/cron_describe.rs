@@ -0,0 +1,632 @@
+use crate::utils::time::{days_from_civil, now_millis, weekday, DateTime, Timestamp};
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// How many upcoming runs are listed
+const RUN_COUNT: usize = 5;
+
+/// How far ahead to look for runs; the calendar's weekdays and leap days repeat
+/// every 28 years
+const SEARCH_YEARS: i64 = 28;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Shorthands standing for a whole expression
+const MACROS: [(&str, &str); 7] = [
+    ("@yearly", "0 0 1 1 *"),
+    ("@annually", "0 0 1 1 *"),
+    ("@monthly", "0 0 1 * *"),
+    ("@weekly", "0 0 * * 0"),
+    ("@daily", "0 0 * * *"),
+    ("@midnight", "0 0 * * *"),
+    ("@hourly", "0 * * * *"),
+];
+
+/// Cron expression describer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronDescribe;
+
+impl Transform for CronDescribe {
+    fn name(&self) -> &'static str {
+        "Cron Describe"
+    }
+
+    fn id(&self) -> &'static str {
+        "cron_describe"
+    }
+
+    fn description(&self) -> &'static str {
+        "Explains a cron expression (5 fields, or 6 with seconds first) in plain English and lists its next 5 runs in UTC"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let schedule = Schedule::parse(input.trim())?;
+        let runs = schedule.next_runs(Timestamp::from_millis(now_millis()), RUN_COUNT);
+        let mut output = format!("{}\n\n", schedule.describe());
+        if runs.is_empty() {
+            output.push_str(&format!("No runs in the next {} years", SEARCH_YEARS));
+        } else {
+            output.push_str(&format!("Next {} runs (UTC):", runs.len()));
+            for run in runs {
+                output.push('\n');
+                output.push_str(&run.to_rfc3339());
+            }
+        }
+        Ok(output)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "*/15 9-17 * * MON-FRI"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Nightly backup",
+                input: "30 2 * * *",
+            },
+            Example {
+                name: "With seconds",
+                input: "0 0/5 * * * ?",
+            },
+            Example {
+                name: "Shorthand",
+                input: "@weekly",
+            },
+            Example {
+                name: "First of the quarter",
+                input: "0 0 1 JAN,APR,JUL,OCT *",
+            },
+        ]
+    }
+}
+
+/// One comma separated part of a field: a value, range or stepped range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Term {
+    start: u32,
+    end: u32,
+    step: u32,
+    /// Whether the range was written as `*` (the field's whole range)
+    every: bool,
+}
+
+/// A parsed cron field
+#[derive(Debug, Clone)]
+struct Field {
+    terms: Vec<Term>,
+    /// Bit n is set when value n matches
+    mask: u64,
+    /// Whether the field was `*` or `?`, possibly stepped
+    star: bool,
+}
+
+impl Field {
+    fn parse(
+        text: &str,
+        name: &str,
+        min: u32,
+        max: u32,
+        names: Option<&[&str]>,
+    ) -> Result<Self, TransformError> {
+        let invalid = |reason: &str| {
+            TransformError::InvalidArgument(
+                format!("Invalid {} field '{}': {}", name, text, reason).into(),
+            )
+        };
+        let value = |part: &str| -> Result<u32, TransformError> {
+            if let Some(names) = names {
+                if let Some(index) = names
+                    .iter()
+                    .position(|n| part.len() >= 3 && n[..3].eq_ignore_ascii_case(part))
+                {
+                    // Month names start at 1, day names at 0
+                    return Ok(index as u32 + min.min(1));
+                }
+            }
+            let number: u32 = part
+                .parse()
+                .map_err(|_| invalid(&format!("'{}' is not a number", part)))?;
+            if number < min || number > max {
+                return Err(invalid(&format!(
+                    "{} is outside {} to {}",
+                    number, min, max
+                )));
+            }
+            Ok(number)
+        };
+
+        let mut terms = Vec::new();
+        for part in text.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => {
+                    let step: u32 = step
+                        .parse()
+                        .ok()
+                        .filter(|&step| step > 0)
+                        .ok_or_else(|| invalid("steps must be positive numbers"))?;
+                    (range, Some(step))
+                }
+                None => (part, None),
+            };
+            let term = if range == "*" || range == "?" {
+                Term {
+                    start: min,
+                    end: max,
+                    step: step.unwrap_or(1),
+                    every: true,
+                }
+            } else if let Some((start, end)) = range.split_once('-') {
+                let (start, end) = (value(start)?, value(end)?);
+                if start > end {
+                    return Err(invalid("ranges must go from low to high"));
+                }
+                Term {
+                    start,
+                    end,
+                    step: step.unwrap_or(1),
+                    every: false,
+                }
+            } else {
+                let start = value(range)?;
+                // "5/15" means from 5 to the end in steps of 15
+                Term {
+                    start,
+                    end: if step.is_some() { max } else { start },
+                    step: step.unwrap_or(1),
+                    every: step.is_some() && start == min,
+                }
+            };
+            terms.push(term);
+        }
+
+        let mut mask = 0u64;
+        for term in &terms {
+            for value in (term.start..=term.end).step_by(term.step as usize) {
+                mask |= 1 << value;
+            }
+        }
+        Ok(Self {
+            terms,
+            mask,
+            star: text.starts_with(['*', '?']),
+        })
+    }
+
+    fn matches(&self, value: u32) -> bool {
+        self.mask & (1 << value) != 0
+    }
+
+    /// Whether the field matches everything, i.e. is a plain `*`
+    fn is_any(&self) -> bool {
+        self.terms.len() == 1 && self.terms[0].every && self.terms[0].step == 1
+    }
+
+    /// Whether every term is a step, read as "every n units"
+    fn is_stepped(&self) -> bool {
+        self.terms.iter().all(|term| term.every || term.step > 1)
+    }
+
+    /// The single value the field matches, if it is just one number
+    fn single(&self) -> Option<u32> {
+        match self.terms[..] {
+            [term] if term.start == term.end => Some(term.start),
+            _ => None,
+        }
+    }
+
+    /// All values if the field is a plain list of numbers
+    fn values(&self) -> Option<Vec<u32>> {
+        self.terms
+            .iter()
+            .map(|term| (term.start == term.end).then_some(term.start))
+            .collect()
+    }
+
+    /// An English phrase for the field such as "every 15 minutes" or
+    /// "Monday through Friday"
+    fn describe(&self, unit: &str, name: impl Fn(u32) -> String) -> String {
+        let phrases: Vec<String> = self
+            .terms
+            .iter()
+            .map(|term| {
+                let every = match term.step {
+                    1 => None,
+                    step => Some(format!("every {} {}s", step, unit)),
+                };
+                match (every, term.every) {
+                    (Some(every), true) => every,
+                    (Some(every), false) => format!(
+                        "{} from {} through {}",
+                        every,
+                        name(term.start),
+                        name(term.end)
+                    ),
+                    (None, true) => format!("every {}", unit),
+                    (None, false) if term.start == term.end => name(term.start),
+                    (None, false) => format!("{} through {}", name(term.start), name(term.end)),
+                }
+            })
+            .collect();
+        join_english(&phrases)
+    }
+}
+
+/// A parsed cron expression
+#[derive(Debug, Clone)]
+struct Schedule {
+    seconds: Option<Field>,
+    minutes: Field,
+    hours: Field,
+    days: Field,
+    months: Field,
+    weekdays: Field,
+}
+
+impl Schedule {
+    fn parse(expression: &str) -> Result<Self, TransformError> {
+        let expression = MACROS
+            .iter()
+            .find(|(name, _)| expression.eq_ignore_ascii_case(name))
+            .map_or(expression, |&(_, expansion)| expansion);
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let (seconds, rest) = match fields.len() {
+            5 => (None, &fields[..]),
+            6 => (Some(fields[0]), &fields[1..]),
+            count => {
+                return Err(TransformError::InvalidArgument(
+                    format!(
+                        "Expected 5 cron fields (minute hour day month weekday) or 6 with seconds first, got {}",
+                        count
+                    )
+                    .into(),
+                ))
+            }
+        };
+        let mut weekdays = Field::parse(rest[4], "weekday", 0, 7, Some(&DAY_NAMES))?;
+        // 7 is another name for Sunday
+        if weekdays.matches(7) {
+            weekdays.mask = (weekdays.mask | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            seconds: seconds
+                .map(|field| Field::parse(field, "second", 0, 59, None))
+                .transpose()?,
+            minutes: Field::parse(rest[0], "minute", 0, 59, None)?,
+            hours: Field::parse(rest[1], "hour", 0, 23, None)?,
+            days: Field::parse(rest[2], "day of month", 1, 31, None)?,
+            months: Field::parse(rest[3], "month", 1, 12, Some(&MONTH_NAMES))?,
+            weekdays,
+        })
+    }
+
+    /// Whether the schedule runs on a date. As in Vixie cron, when both
+    /// the day of month and the weekday are restricted either may match.
+    fn matches_date(&self, year: i64, month: u32, day: u32) -> bool {
+        if !self.months.matches(month) {
+            return false;
+        }
+        let day_matches = self.days.matches(day);
+        let weekday_matches = self
+            .weekdays
+            .matches(weekday(days_from_civil(year, month, day)));
+        if self.days.star || self.weekdays.star {
+            day_matches && weekday_matches
+        } else {
+            day_matches || weekday_matches
+        }
+    }
+
+    /// The first `count` run times strictly after `after`
+    fn next_runs(&self, after: Timestamp, count: usize) -> Vec<Timestamp> {
+        let step = if self.seconds.is_some() { 1 } else { 60 };
+        // The next whole second or minute
+        let start = (after.seconds.div_euclid(step) + 1) * step;
+        let mut time = DateTime::from_timestamp(
+            Timestamp {
+                seconds: start,
+                nanos: 0,
+            },
+            0,
+        );
+        let last_year = time.year + SEARCH_YEARS;
+        let mut runs = Vec::new();
+        while runs.len() < count && time.year <= last_year {
+            if !self.matches_date(time.year, time.month, time.day) {
+                // On to the next day
+                let next = DateTime::from_date(time.year, time.month, time.day).timestamp(0);
+                time = DateTime::from_timestamp(
+                    Timestamp {
+                        seconds: next.seconds + 86_400,
+                        nanos: 0,
+                    },
+                    0,
+                );
+            } else if !self.hours.matches(time.hour) {
+                time = advance(time, 3600 - i64::from(time.minute * 60 + time.second));
+            } else if !self.minutes.matches(time.minute) {
+                time = advance(time, 60 - i64::from(time.second));
+            } else if self
+                .seconds
+                .as_ref()
+                .is_some_and(|seconds| !seconds.matches(time.second))
+            {
+                time = advance(time, 1);
+            } else {
+                runs.push(time.timestamp(0));
+                time = advance(time, step);
+            }
+        }
+        runs
+    }
+
+    fn describe(&self) -> String {
+        let mut parts = vec![self.describe_time()];
+        let days = (!self.days.is_any()).then(|| {
+            format!(
+                "{} of the month",
+                match self.days.single() {
+                    Some(day) => format!("on day {}", day),
+                    None if self.days.is_stepped() => {
+                        self.days.describe("day", |d| d.to_string())
+                    }
+                    None => format!("on days {}", self.days.describe("day", |d| d.to_string())),
+                }
+            )
+        });
+        let weekdays = (!self.weekdays.is_any()).then(|| {
+            self.weekdays
+                .describe("day", |d| DAY_NAMES[d as usize % 7].to_string())
+        });
+        match (days, weekdays) {
+            (Some(days), Some(weekdays)) if !self.days.star && !self.weekdays.star => {
+                parts.push(format!("{} or on {}", days, weekdays))
+            }
+            (days, weekdays) => parts.extend(
+                days.into_iter()
+                    .chain(weekdays.map(|w| format!("on {}", w))),
+            ),
+        }
+        if !self.months.is_any() {
+            parts.push(format!(
+                "in {}",
+                self.months
+                    .describe("month", |m| MONTH_NAMES[m as usize - 1].to_string())
+            ));
+        }
+        let sentence = parts.join(", ");
+        let mut chars = sentence.chars();
+        chars.next().map_or_else(String::new, |first| {
+            first.to_uppercase().collect::<String>() + chars.as_str()
+        })
+    }
+
+    fn describe_time(&self) -> String {
+        let second = self.seconds.as_ref().map_or(Some(0), Field::single);
+        // Fixed times of day, such as "at 09:00 and 17:30"
+        if let (Some(minute), Some(hours), Some(second)) =
+            (self.minutes.single(), self.hours.values(), second)
+        {
+            if hours.len() <= 6 {
+                let times: Vec<String> = hours
+                    .iter()
+                    .map(|&hour| {
+                        if second == 0 {
+                            format!("{:02}:{:02}", hour, minute)
+                        } else {
+                            format!("{:02}:{:02}:{:02}", hour, minute, second)
+                        }
+                    })
+                    .collect();
+                return format!("at {}", join_english(&times));
+            }
+        }
+
+        let mut parts = Vec::new();
+        if let Some(seconds) = &self.seconds {
+            if seconds.single() != Some(0) {
+                parts.push(match seconds.single() {
+                    Some(second) => format!("at second {}", second),
+                    None if seconds.is_stepped() => seconds.describe("second", |s| s.to_string()),
+                    None => format!(
+                        "at seconds {}",
+                        seconds.describe("second", |s| s.to_string())
+                    ),
+                });
+            }
+        }
+        let minutes = match self.minutes.single() {
+            Some(minute) => format!("at minute {}", minute),
+            None if self.minutes.is_stepped() => self.minutes.describe("minute", |m| m.to_string()),
+            None => format!(
+                "at minutes {}",
+                self.minutes.describe("minute", |m| m.to_string())
+            ),
+        };
+        // "every second" already covers every minute
+        if !self.minutes.is_any() || parts.is_empty() {
+            parts.push(minutes);
+        }
+        if !self.hours.is_any() {
+            parts.push(match self.hours.terms[..] {
+                [term] if !term.every && term.step == 1 && term.start != term.end => {
+                    format!("between {:02}:00 and {:02}:59", term.start, term.end)
+                }
+                _ if self.hours.single().is_some() || self.hours.values().is_some() => format!(
+                    "past hour {}",
+                    self.hours.describe("hour", |h| h.to_string())
+                ),
+                _ => self.hours.describe("hour", |h| format!("{:02}:00", h)),
+            });
+        }
+        parts.join(", ")
+    }
+}
+
+fn advance(time: DateTime, seconds: i64) -> DateTime {
+    let timestamp = time.timestamp(0);
+    DateTime::from_timestamp(
+        Timestamp {
+            seconds: timestamp.seconds + seconds,
+            nanos: 0,
+        },
+        0,
+    )
+}
+
+// "a", "a and b", "a, b and c"
+fn join_english(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(expression: &str) -> String {
+        Schedule::parse(expression).unwrap().describe()
+    }
+
+    fn runs(expression: &str, after: &str, count: usize) -> Vec<String> {
+        let (date_time, _) = crate::utils::time::parse_iso8601(after).unwrap();
+        Schedule::parse(expression)
+            .unwrap()
+            .next_runs(date_time.timestamp(0), count)
+            .iter()
+            .map(Timestamp::to_rfc3339)
+            .collect()
+    }
+
+    #[test]
+    fn test_cron_descriptions() {
+        assert_eq!(
+            describe("*/15 9-17 * * MON-FRI"),
+            "Every 15 minutes, between 09:00 and 17:59, on Monday through Friday"
+        );
+        assert_eq!(describe("30 2 * * *"), "At 02:30");
+        assert_eq!(describe("0 9,17 * * *"), "At 09:00 and 17:00");
+        assert_eq!(describe("* * * * *"), "Every minute");
+        assert_eq!(describe("5 * * * *"), "At minute 5");
+        assert_eq!(describe("@weekly"), "At 00:00, on Sunday");
+        assert_eq!(
+            describe("0 0 1 JAN,APR,JUL,OCT *"),
+            "At 00:00, on day 1 of the month, in January, April, July and October"
+        );
+        assert_eq!(
+            describe("0 12 1,15 * 5"),
+            "At 12:00, on days 1 and 15 of the month or on Friday"
+        );
+        assert_eq!(describe("0 0/5 * * * ?"), "Every 5 minutes");
+        assert_eq!(
+            describe("5/20 * * * *"),
+            "Every 20 minutes from 5 through 59"
+        );
+        assert_eq!(describe("*/10 * * * * *"), "Every 10 seconds");
+        assert_eq!(
+            describe("0 0 */2 * *"),
+            "At 00:00, every 2 days of the month"
+        );
+    }
+
+    #[test]
+    fn test_cron_next_runs() {
+        assert_eq!(
+            runs("*/15 9-17 * * MON-FRI", "2024-02-29T17:40:00Z", 3),
+            [
+                "2024-02-29T17:45:00Z",
+                "2024-03-01T09:00:00Z",
+                "2024-03-01T09:15:00Z"
+            ]
+        );
+        // Either the 13th or a Friday
+        assert_eq!(
+            runs("0 0 13 * 5", "2024-09-10T00:00:00Z", 3),
+            [
+                "2024-09-13T00:00:00Z",
+                "2024-09-20T00:00:00Z",
+                "2024-09-27T00:00:00Z"
+            ]
+        );
+        assert_eq!(
+            runs("0 0 29 2 *", "2024-03-01T00:00:00Z", 1),
+            ["2028-02-29T00:00:00Z"]
+        );
+        // The 29th or any Monday in February
+        assert_eq!(
+            runs("0 0 29 2 1", "2024-03-01T00:00:00Z", 2),
+            ["2025-02-03T00:00:00Z", "2025-02-10T00:00:00Z"]
+        );
+        assert_eq!(
+            runs("30 * * * * *", "2024-01-01T00:00:30Z", 2),
+            ["2024-01-01T00:01:30Z", "2024-01-01T00:02:30Z"]
+        );
+        assert_eq!(
+            runs("0 0 31 2 *", "2024-01-01T00:00:00Z", 1),
+            Vec::<String>::new()
+        );
+        // 7 is Sunday too
+        assert_eq!(
+            runs("0 0 * * 7", "2024-02-29T00:00:00Z", 1),
+            ["2024-03-03T00:00:00Z"]
+        );
+    }
+
+    #[test]
+    fn test_cron_transform_output() {
+        let output = CronDescribe.transform("@hourly").unwrap();
+        assert!(output.starts_with("At minute 0\n\nNext 5 runs (UTC):\n"));
+        assert_eq!(output.lines().count(), 8);
+        assert!(CronDescribe
+            .transform("0 0 31 2 *")
+            .unwrap()
+            .ends_with("No runs in the next 28 years"));
+    }
+
+    #[test]
+    fn test_cron_invalid() {
+        for invalid in [
+            "* * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "* * * * FOO",
+            "0 0 L * *",
+            "0 0 * * 5#3",
+        ] {
+            assert!(CronDescribe.transform(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,447 @@
+use super::json_formatter::{indent_value, DEFAULT_INDENT, INDENT_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// CSS Formatter transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CssFormatter;
+
+impl Transform for CssFormatter {
+    fn name(&self) -> &'static str {
+        "CSS Formatter"
+    }
+
+    fn id(&self) -> &'static str {
+        "cssformatter"
+    }
+
+    fn description(&self) -> &'static str {
+        "Formats (pretty-prints) CSS and SCSS-like stylesheets, one selector and one declaration per line."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Formatter
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "body{margin:0;font-family:system-ui,sans-serif}h1,h2{color:#333}@media (max-width:600px){body{font-size:14px}.nav a:hover{color:red!important}}"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "SCSS nesting",
+                input: "$accent:#0af;.card{padding:1rem;// compact\n&:hover{border-color:$accent}.title{font:bold 1.2em/1.4 \"Fira Sans\"}}",
+            },
+            Example {
+                name: "Font face",
+                input: "@import url(\"theme.css\");@font-face{font-family:Inter;src:url(/fonts/inter.woff2) format(\"woff2\")}/* Layout */.grid{display:grid;grid-template-columns:repeat(3,1fr)}",
+            },
+        ]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        format(input, DEFAULT_INDENT, token)
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[INDENT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        format(input, indent_value(options)?, token)
+    }
+}
+
+// Prints one selector list, declaration or statement per line, with blank
+// lines around blocks
+fn format(input: &str, indent: usize, cancel: &CancelToken) -> Result<String, TransformError> {
+    let mut out = String::new();
+    let mut level = 0;
+    let mut previous: Option<&Item> = None;
+    let items = parse(input, true, cancel)?;
+
+    for item in &items {
+        let pad = " ".repeat(level * indent);
+        // Empty blocks stay on the opening line
+        if matches!(item, Item::Close) && matches!(previous, Some(Item::Open(_))) {
+            level -= 1;
+            out.push('}');
+            previous = Some(item);
+            continue;
+        }
+        if !out.is_empty() {
+            out.push('\n');
+            // Blocks are set apart from their siblings by a blank line
+            let after_block = matches!(previous, Some(Item::Close)) && !matches!(item, Item::Close);
+            let before_block = matches!(item, Item::Open(_))
+                && matches!(
+                    previous,
+                    Some(Item::Declaration { .. } | Item::Statement(_))
+                );
+            if after_block || before_block {
+                out.push('\n');
+            }
+        }
+        match item {
+            Item::Open(selectors) => {
+                out.push_str(&pad);
+                out.push_str(&selectors.join(&format!(",\n{}", pad)));
+                out.push_str(" {");
+                level += 1;
+            }
+            Item::Close => {
+                level -= 1;
+                out.push_str(&" ".repeat(level * indent));
+                out.push('}');
+            }
+            Item::Declaration { property, value } => {
+                out.push_str(&format!("{}{}: {};", pad, property, space_commas(value)));
+            }
+            Item::Statement(text) => out.push_str(&format!("{}{};", pad, text)),
+            Item::Comment(text) => {
+                out.push_str(&pad);
+                out.push_str(text);
+            }
+        }
+        previous = Some(item);
+    }
+    Ok(out)
+}
+
+// Puts a space after the commas separating a value's parts, as in a font
+// stack, leaving the ones in function arguments like rgba(0,0,0) alone
+fn space_commas(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        out.push(c);
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 && chars.peek().is_some_and(|next| *next != ' ') => {
+                out.push(' ')
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// One piece of a stylesheet, with whitespace outside strings collapsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Item {
+    /// The start of a block: a rule's comma-separated selectors, or an
+    /// at-rule's prelude as a single entry
+    Open(Vec<String>),
+    Close,
+    /// `property: value`, without the semicolon
+    Declaration {
+        property: String,
+        value: String,
+    },
+    /// Any other statement, like `@import url(x.css)` or `@include mixin`
+    Statement(String),
+    /// A comment on its own, either `/* ... */` or an SCSS `// ...` line
+    Comment(String),
+}
+
+/// Splits a stylesheet into blocks, declarations and comments. Comments inside
+/// a selector or declaration stay part of its text when `keep_comments` is
+/// set and are dropped otherwise.
+pub(crate) fn parse(
+    input: &str,
+    keep_comments: bool,
+    cancel: &CancelToken,
+) -> Result<Vec<Item>, TransformError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut items = Vec::new();
+    let mut buffer = String::new();
+    // 1-based positions of the unclosed '{'s
+    let mut open_braces = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        cancel.check_every(i)?;
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '"' | '\'' => {
+                let end = string_end(&chars, i).ok_or_else(|| {
+                    TransformError::InvalidArgument(
+                        format!("Unterminated string starting at position {}", i + 1).into(),
+                    )
+                })?;
+                buffer.extend(&chars[i..=end]);
+                i = end + 1;
+            }
+            '/' if next == Some('*') => {
+                let end = (i + 2..chars.len().saturating_sub(1))
+                    .find(|&j| chars[j] == '*' && chars[j + 1] == '/')
+                    .ok_or_else(|| {
+                        TransformError::InvalidArgument(
+                            format!("Unterminated comment starting at position {}", i + 1).into(),
+                        )
+                    })?;
+                let comment: String = chars[i..end + 2].iter().collect();
+                if !keep_comments {
+                    // A comment still separates what's on either side
+                    if !buffer.is_empty() && !buffer.ends_with(' ') {
+                        buffer.push(' ');
+                    }
+                } else if buffer.is_empty() {
+                    items.push(Item::Comment(comment));
+                } else {
+                    buffer.push_str(&comment);
+                }
+                i = end + 2;
+            }
+            // SCSS line comments, only where a statement could start so that
+            // a `//` inside a value is left alone
+            '/' if next == Some('/') && buffer.is_empty() => {
+                let end = (i..chars.len())
+                    .find(|&j| chars[j] == '\n')
+                    .unwrap_or(chars.len());
+                if keep_comments {
+                    let comment: String = chars[i..end].iter().collect();
+                    items.push(Item::Comment(comment.trim_end().to_string()));
+                }
+                i = end;
+            }
+            // url(...) may hold unquoted ';', '//' and the like
+            '(' if buffer.to_ascii_lowercase().ends_with("url") => {
+                let end = (i..chars.len()).find(|&j| chars[j] == ')').ok_or_else(|| {
+                    TransformError::InvalidArgument(
+                        format!("Unterminated url( at position {}", i + 1).into(),
+                    )
+                })?;
+                buffer.extend(&chars[i..=end]);
+                i = end + 1;
+            }
+            // SCSS interpolation, #{$name}
+            '{' if buffer.ends_with('#') => {
+                let end = (i..chars.len()).find(|&j| chars[j] == '}').ok_or_else(|| {
+                    TransformError::InvalidArgument(
+                        format!("Unterminated interpolation at position {}", i).into(),
+                    )
+                })?;
+                buffer.extend(&chars[i..=end]);
+                i = end + 1;
+            }
+            '{' => {
+                items.push(Item::Open(split_selectors(buffer.trim_end())));
+                buffer.clear();
+                open_braces.push(i + 1);
+                i += 1;
+            }
+            '}' => {
+                if open_braces.pop().is_none() {
+                    return Err(TransformError::InvalidArgument(
+                        format!("Unexpected '}}' at position {}", i + 1).into(),
+                    ));
+                }
+                push_statement(&mut items, &mut buffer);
+                items.push(Item::Close);
+                i += 1;
+            }
+            ';' => {
+                push_statement(&mut items, &mut buffer);
+                i += 1;
+            }
+            c if c.is_whitespace() => {
+                if !buffer.is_empty() && !buffer.ends_with(' ') {
+                    buffer.push(' ');
+                }
+                i += 1;
+            }
+            c => {
+                buffer.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    if let Some(position) = open_braces.pop() {
+        return Err(TransformError::InvalidArgument(
+            format!("Unclosed '{{' at position {}", position).into(),
+        ));
+    }
+    push_statement(&mut items, &mut buffer);
+    Ok(items)
+}
+
+// Index of the quote closing the string that opens at `start`. Strings can't
+// span lines unless the line break is escaped.
+fn string_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut j = start + 1;
+    while j < chars.len() {
+        match chars[j] {
+            '\\' => j += 2,
+            '\n' => return None,
+            c if c == chars[start] => return Some(j),
+            _ => j += 1,
+        }
+    }
+    None
+}
+
+// Turns the buffered text, if any, into a declaration or statement
+fn push_statement(items: &mut Vec<Item>, buffer: &mut String) {
+    let text = buffer.trim_end();
+    if !text.is_empty() {
+        let declaration = text
+            .split_once(':')
+            .filter(|(property, value)| {
+                !text.starts_with('@')
+                    && !property.is_empty()
+                    && !value.trim().is_empty()
+                    && property.trim_end().chars().all(|c| {
+                        c.is_alphanumeric() || matches!(c, '-' | '_' | '$' | '*' | '#' | '{' | '}')
+                    })
+            })
+            .map(|(property, value)| Item::Declaration {
+                property: property.trim_end().to_string(),
+                value: value.trim_start().to_string(),
+            });
+        items.push(declaration.unwrap_or_else(|| Item::Statement(text.to_string())));
+    }
+    buffer.clear();
+}
+
+// A rule's selectors, split at the commas outside parentheses, brackets and
+// strings. At-rule preludes like `@media screen, print` stay whole.
+fn split_selectors(prelude: &str) -> Vec<String> {
+    if prelude.starts_with('@') {
+        return vec![prelude.to_string()];
+    }
+    let mut selectors = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    for c in prelude.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                selectors.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    selectors.push(current.trim().to_string());
+    selectors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_css_formatter() {
+        assert_eq!(
+            CssFormatter
+                .transform(CssFormatter.default_test_input())
+                .unwrap(),
+            "body {
+  margin: 0;
+  font-family: system-ui, sans-serif;
+}
+
+h1,
+h2 {
+  color: #333;
+}
+
+@media (max-width:600px) {
+  body {
+    font-size: 14px;
+  }
+
+  .nav a:hover {
+    color: red!important;
+  }
+}"
+        );
+        assert_eq!(CssFormatter.transform("").unwrap(), "");
+        assert_eq!(CssFormatter.transform("a{}").unwrap(), "a {}");
+    }
+
+    #[test]
+    fn test_css_formatter_scss() {
+        assert_eq!(
+            CssFormatter
+                .transform("$gap : 4px ;.a{ // note\n&:hover , &.b{margin:$gap}#{$side}-x:1px;@include m(1,2)}")
+                .unwrap(),
+            "$gap: 4px;\n\n.a {\n  // note\n  &:hover,\n  &.b {\n    margin: $gap;\n  }\n\n  #{$side}-x: 1px;\n  @include m(1,2);\n}"
+        );
+    }
+
+    #[test]
+    fn test_css_formatter_keeps_strings_and_urls() {
+        assert_eq!(
+            CssFormatter
+                .transform("a::after{content:\"a;  {b}\";background:url(data:image/png;base64,AA//)}/* c */")
+                .unwrap(),
+            "a::after {\n  content: \"a;  {b}\";\n  background: url(data:image/png;base64,AA//);\n}\n\n/* c */"
+        );
+        assert_eq!(
+            CssFormatter
+                .transform("a[href^='http://x,y'],b{x:y}")
+                .unwrap(),
+            "a[href^='http://x,y'],\nb {\n  x: y;\n}"
+        );
+    }
+
+    #[test]
+    fn test_css_formatter_indent_option() {
+        let mut options = TransformOptions::new();
+        options.set("indent", "4");
+        assert_eq!(
+            CssFormatter
+                .transform_with_options("a{b:c}", &options)
+                .unwrap(),
+            "a {\n    b: c;\n}"
+        );
+    }
+
+    #[test]
+    fn test_css_formatter_errors() {
+        let err = CssFormatter.transform("a{b:c}}").unwrap_err();
+        assert!(err.to_string().contains("Unexpected '}'"));
+        assert_eq!(err.position(), Some(7));
+
+        let err = CssFormatter.transform("a{b:c}\nd{e:f").unwrap_err();
+        assert!(err.to_string().contains("Unclosed '{'"));
+        assert_eq!(err.position(), Some(9));
+
+        let err = CssFormatter.transform("a{content:\"x}").unwrap_err();
+        assert_eq!(err.position(), Some(11));
+        assert!(CssFormatter.transform("a{}/* open").is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use super::css_formatter::{parse, Item};
+use crate::{CancelToken, Example, Transform, TransformError, TransformerCategory};
+
+/// CSS Minifier transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CssMinifier;
+
+impl Transform for CssMinifier {
+    fn name(&self) -> &'static str {
+        "CSS Minifier"
+    }
+
+    fn id(&self) -> &'static str {
+        "cssminifier"
+    }
+
+    fn description(&self) -> &'static str {
+        "Minifies CSS by removing comments, unneeded whitespace and the last semicolon in each block."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Formatter
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let mut out = String::new();
+        // Statements are separated, not terminated, by semicolons
+        let mut needs_semicolon = false;
+        for item in parse(input, false, token)? {
+            match item {
+                Item::Open(selectors) => {
+                    if needs_semicolon {
+                        out.push(';');
+                    }
+                    let selectors: Vec<String> =
+                        selectors.iter().map(|s| compact_commas(s)).collect();
+                    out.push_str(&selectors.join(","));
+                    out.push('{');
+                    needs_semicolon = false;
+                }
+                Item::Close => {
+                    out.push('}');
+                    needs_semicolon = false;
+                }
+                Item::Declaration { property, value } => {
+                    if needs_semicolon {
+                        out.push(';');
+                    }
+                    out.push_str(&property);
+                    out.push(':');
+                    out.push_str(&compact_commas(&value));
+                    needs_semicolon = true;
+                }
+                Item::Statement(text) => {
+                    if needs_semicolon {
+                        out.push(';');
+                    }
+                    out.push_str(&text);
+                    needs_semicolon = true;
+                }
+                Item::Comment(_) => {}
+            }
+        }
+        Ok(out)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "/* Base */\nbody {\n  margin: 0;\n  font-family: system-ui, sans-serif;\n}\n\nh1,\nh2 {\n  color: #333;\n}\n\n@media (max-width: 600px) {\n  body {\n    font-size: 14px;\n  }\n}"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Strings and urls",
+            input: ".icon::before {\n  content: \"a ,  b\";\n  background: url(/img/icon.svg) no-repeat;\n}",
+        }]
+    }
+}
+
+// Drops the spaces around commas outside strings, as in `rgba(0, 0, 0, .5)`
+fn compact_commas(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut quote = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), '\\') => {
+                out.push(c);
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+                continue;
+            }
+            (None, '"' | '\'') => quote = Some(c),
+            (None, ' ') if chars.peek() == Some(&',') => continue,
+            (None, ',') => {
+                out.push(c);
+                if chars.peek() == Some(&' ') {
+                    chars.next();
+                }
+                continue;
+            }
+            _ => {}
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::CssFormatter;
+
+    #[test]
+    fn test_css_minifier() {
+        assert_eq!(
+            CssMinifier
+                .transform(CssMinifier.default_test_input())
+                .unwrap(),
+            "body{margin:0;font-family:system-ui,sans-serif}h1,h2{color:#333}@media (max-width: 600px){body{font-size:14px}}"
+        );
+        assert_eq!(CssMinifier.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_css_minifier_keeps_strings() {
+        assert_eq!(
+            CssMinifier
+                .transform(CssMinifier.examples()[0].input)
+                .unwrap(),
+            ".icon::before{content:\"a ,  b\";background:url(/img/icon.svg) no-repeat}"
+        );
+        assert_eq!(
+            CssMinifier
+                .transform("@import url(a.css) ;\n// scss note\na /* x */ b , c{color : rgba(0, 0, 0, .5)}")
+                .unwrap(),
+            "@import url(a.css);a b,c{color:rgba(0,0,0,.5)}"
+        );
+        assert!(CssMinifier.transform("a{").is_err());
+    }
+
+    #[test]
+    fn test_css_minifier_round_trip() {
+        let css = CssFormatter.default_test_input();
+        let formatted = CssFormatter.transform(css).unwrap();
+        let minified = CssMinifier.transform(&formatted).unwrap();
+        assert_eq!(CssFormatter.transform(&minified).unwrap(), formatted);
+    }
+}
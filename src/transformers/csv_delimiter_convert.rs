@@ -0,0 +1,123 @@
+use crate::utils::csv;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+const FROM_OPTION: TransformOption = TransformOption {
+    key: "from",
+    description: "Delimiter the input uses",
+    default: "comma",
+    kind: csv::DELIMITER_OPTION.kind,
+};
+
+const TO_OPTION: TransformOption = TransformOption {
+    key: "to",
+    description: "Delimiter to write",
+    default: "tab",
+    kind: csv::DELIMITER_OPTION.kind,
+};
+
+/// Rewrites delimited data with a different delimiter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvDelimiterConvert;
+
+impl Transform for CsvDelimiterConvert {
+    fn name(&self) -> &'static str {
+        "CSV Delimiter Convert"
+    }
+
+    fn id(&self) -> &'static str {
+        "csv_delimiter_convert"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts between comma, tab, semicolon and pipe separated data, re-quoting fields so embedded delimiters, quotes and line breaks survive"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[FROM_OPTION, TO_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let from = csv::delimiter_value(options, &FROM_OPTION)?;
+        let records = csv::parse_with_cancel(input, from, token)?;
+        Ok(csv::write(
+            &records,
+            csv::delimiter_value(options, &TO_OPTION)?,
+        ))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "name,motto,year\n\"Lovelace, Ada\",\"Tabs\tand \"\"quotes\"\"\",1843\nTuring,Plain,1950"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Quoted commas",
+                input: "city,country\n\"Paris, Texas\",USA\nParis,France",
+            },
+            Example {
+                name: "Multi-line field",
+                input: "id,note\n1,\"first line\nsecond line\"\n2,short",
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, from: &str, to: &str) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("from", from);
+        options.set("to", to);
+        CsvDelimiterConvert.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_csv_to_tsv() {
+        assert_eq!(
+            CsvDelimiterConvert
+                .transform(CsvDelimiterConvert.default_test_input())
+                .unwrap(),
+            "name\tmotto\tyear\nLovelace, Ada\t\"Tabs\tand \"\"quotes\"\"\"\t1843\nTuring\tPlain\t1950"
+        );
+    }
+
+    #[test]
+    fn test_delimiter_round_trips() {
+        let csv = "a,\"b,c\",d;e|f\n\"x\ny\",,tab\there";
+        for delimiter in ["tab", "semicolon", "pipe"] {
+            let converted = convert(csv, "comma", delimiter).unwrap();
+            assert_eq!(convert(&converted, delimiter, "comma").unwrap(), csv);
+        }
+        assert_eq!(
+            convert("a;\"b;c\";d", "semicolon", "comma").unwrap(),
+            "a,b;c,d"
+        );
+        assert_eq!(convert("a|b", "pipe", "pipe").unwrap(), "a|b");
+    }
+
+    #[test]
+    fn test_delimiter_convert_errors() {
+        assert!(convert("a,\"b", "comma", "tab").is_err());
+        assert!(convert("a,b", "space", "tab").is_err());
+        assert_eq!(CsvDelimiterConvert.transform("").unwrap(), "");
+    }
+}
@@ -0,0 +1,221 @@
+use crate::utils::csv::{self, DELIMITER_OPTION, HEADER_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const COLUMNS_OPTION: TransformOption = TransformOption {
+    key: "columns",
+    description: "Columns to keep, in order: header names or 1-based numbers and ranges such as 3-5, separated by commas (quote names containing commas). Empty keeps every column.",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+/// Picks and reorders CSV columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvSelectColumns;
+
+impl Transform for CsvSelectColumns {
+    fn name(&self) -> &'static str {
+        "CSV Select Columns"
+    }
+
+    fn id(&self) -> &'static str {
+        "csv_select_columns"
+    }
+
+    fn description(&self) -> &'static str {
+        "Keeps only the CSV columns listed in the columns option, by name or number, in the order given"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[COLUMNS_OPTION, DELIMITER_OPTION, HEADER_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let delimiter = csv::delimiter(options)?;
+        let records = csv::parse_with_cancel(input, delimiter, token)?;
+        let header = match records.first() {
+            Some(first) if options.bool_value(&HEADER_OPTION)? => Some(first.as_slice()),
+            Some(_) => None,
+            None => return Ok(String::new()),
+        };
+        let width = records.iter().map(Vec::len).max().unwrap_or(0);
+        let selection = select(options.value(&COLUMNS_OPTION), header, width)?;
+
+        // Short rows get empty fields for the columns they lack
+        let projected: Vec<Vec<&str>> = records
+            .iter()
+            .map(|record| {
+                selection
+                    .iter()
+                    .map(|&column| record.get(column).map_or("", String::as_str))
+                    .collect()
+            })
+            .collect();
+        Ok(csv::write(&projected, delimiter))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "id,name,email,city\n1,Ada Lovelace,ada@example.com,London\n2,\"Hopper, Grace\",grace@example.com,New York"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Every column",
+                input: "sku,price,stock\nBK-1,12.50,4\nPN-7,3.99,120",
+            },
+            Example {
+                name: "Quoted fields",
+                input: "name,quote\n\"Lovelace, Ada\",\"Say \"\"hi\"\"\"",
+            },
+        ]
+    }
+}
+
+// The 0-based indices named by `spec`: header names first, then numbers
+// and ranges of numbers counting from 1
+fn select(
+    spec: &str,
+    header: Option<&[String]>,
+    width: usize,
+) -> Result<Vec<usize>, TransformError> {
+    let items = csv::parse(spec, ',')?
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    if items.iter().all(|item| item.trim().is_empty()) {
+        return Ok((0..width).collect());
+    }
+    let mut selection = Vec::new();
+    for item in &items {
+        let item = item.trim();
+        if item.is_empty() {
+            continue;
+        }
+        if let Some(index) = header.and_then(|header| find_column(header, item)) {
+            selection.push(index);
+            continue;
+        }
+        let number = |text: &str| {
+            text.trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|&n| (1..=width).contains(&n))
+        };
+        let range = match item.split_once('-') {
+            Some((start, end)) => number(start).zip(number(end)),
+            None => number(item).map(|n| (n, n)),
+        };
+        match range {
+            Some((start, end)) if start <= end => selection.extend(start - 1..end),
+            Some((start, end)) => selection.extend((end - 1..start).rev()),
+            None => {
+                let known = match header {
+                    Some(header) => format!("columns are {} or 1 to {}", header.join(", "), width),
+                    None => format!("columns are 1 to {}", width),
+                };
+                return Err(TransformError::InvalidArgument(
+                    format!("Unknown column '{}' ({})", item, known).into(),
+                ));
+            }
+        }
+    }
+    Ok(selection)
+}
+
+// Exact name match first, then ignoring case
+fn find_column(header: &[String], name: &str) -> Option<usize> {
+    header
+        .iter()
+        .position(|column| column.trim() == name)
+        .or_else(|| {
+            header
+                .iter()
+                .position(|column| column.trim().eq_ignore_ascii_case(name))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select_columns(input: &str, settings: &[(&str, &str)]) -> Result<String, TransformError> {
+        let options: TransformOptions = settings.iter().copied().collect();
+        CsvSelectColumns.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_select_by_name() {
+        let input = CsvSelectColumns.default_test_input();
+        assert_eq!(
+            select_columns(input, &[("columns", "city, NAME")]).unwrap(),
+            "city,name\nLondon,Ada Lovelace\nNew York,\"Hopper, Grace\""
+        );
+        assert_eq!(CsvSelectColumns.transform(input).unwrap(), input);
+    }
+
+    #[test]
+    fn test_select_by_number() {
+        let input = "a,b,c,d\n1,2,3,4\n5,6";
+        assert_eq!(
+            select_columns(input, &[("columns", "4,2-3,1")]).unwrap(),
+            "d,b,c,a\n4,2,3,1\n,6,,5"
+        );
+        assert_eq!(
+            select_columns(input, &[("columns", "3-1")]).unwrap(),
+            "c,b,a\n3,2,1\n,6,5"
+        );
+        // A header named like a number wins over the position
+        assert_eq!(
+            select_columns("x,1\na,b", &[("columns", "1")]).unwrap(),
+            "1\nb"
+        );
+        assert_eq!(
+            select_columns("x,1\na,b", &[("columns", "1"), ("header", "false")]).unwrap(),
+            "x\na"
+        );
+    }
+
+    #[test]
+    fn test_select_quoted_names() {
+        assert_eq!(
+            select_columns(
+                "\"last, first\";age\n\"Lovelace, Ada\";36",
+                &[
+                    ("columns", "age,\"last, first\",age"),
+                    ("delimiter", "semicolon")
+                ]
+            )
+            .unwrap(),
+            "age;last, first;age\n36;Lovelace, Ada;36"
+        );
+    }
+
+    #[test]
+    fn test_select_unknown_column() {
+        assert_eq!(
+            select_columns("a,b\n1,2", &[("columns", "c")])
+                .unwrap_err()
+                .to_string(),
+            "Invalid argument: Unknown column 'c' (columns are a, b or 1 to 2)"
+        );
+        assert!(select_columns("a,b\n1,2", &[("columns", "3")]).is_err());
+        assert!(select_columns("a,b\n1,2", &[("columns", "0")]).is_err());
+        assert_eq!(select_columns("", &[("columns", "a")]).unwrap(), "");
+    }
+}
@@ -0,0 +1,336 @@
+use crate::utils::csv::{self, DELIMITER_OPTION, HEADER_OPTION};
+use crate::utils::time::parse_iso8601;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+use std::collections::HashSet;
+
+/// Values read as a missing value rather than as text
+const NULL_MARKERS: [&str; 5] = ["null", "na", "n/a", "nan", "none"];
+
+/// Per-column summary of CSV data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvStats;
+
+impl Transform for CsvStats {
+    fn name(&self) -> &'static str {
+        "CSV Stats"
+    }
+
+    fn id(&self) -> &'static str {
+        "csv_stats"
+    }
+
+    fn description(&self) -> &'static str {
+        "Summarizes each CSV column: inferred type, empty and null counts, distinct values, and min, max and mean of numbers"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[DELIMITER_OPTION, HEADER_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let delimiter = csv::delimiter(options)?;
+        let mut records = csv::parse_with_cancel(input, delimiter, token)?;
+        let header = if options.bool_value(&HEADER_OPTION)? && !records.is_empty() {
+            records.remove(0)
+        } else {
+            Vec::new()
+        };
+        let width = records
+            .iter()
+            .chain([&header])
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0);
+
+        let mut report = format!("Rows: {}\nColumns: {}", records.len(), width);
+        for column in 0..width {
+            let name = header
+                .get(column)
+                .filter(|name| !name.trim().is_empty())
+                .map_or_else(
+                    || format!("Column {}", column + 1),
+                    |name| format!("Column {}: {}", column + 1, name.trim()),
+                );
+            let values = records
+                .iter()
+                .map(|record| record.get(column).map_or("", |value| value.trim()));
+            report.push_str(&format!("\n\n{}\n", name));
+            report.push_str(&describe(values));
+        }
+        Ok(report)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "id,name,score,joined,active\n1,Ada,98.5,2024-01-15,true\n2,Grace,87,2023-11-02,false\n3,Alan,,2024-03-30,true\n4,Ada,NULL,2022-07-01,yes"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Sales",
+                input: "region,units,price\nNorth,120,9.99\nSouth,95,10.49\nNorth,143,9.49\nEast,n/a,11.00",
+            },
+            Example {
+                name: "Mixed column",
+                input: "code\n100\n200\nA-7\n",
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Kind {
+    Integer,
+    Decimal,
+    Boolean,
+    Date,
+    Text,
+}
+
+impl Kind {
+    fn of(value: &str) -> Self {
+        if parse_number(value).is_some() {
+            if value.contains(['.', 'e', 'E']) {
+                Kind::Decimal
+            } else {
+                Kind::Integer
+            }
+        } else if ["true", "false", "yes", "no"]
+            .iter()
+            .any(|word| value.eq_ignore_ascii_case(word))
+        {
+            Kind::Boolean
+        } else if parse_iso8601(value).is_some() {
+            Kind::Date
+        } else {
+            Kind::Text
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Integer => "integer",
+            Kind::Decimal => "decimal",
+            Kind::Boolean => "boolean",
+            Kind::Date => "date",
+            Kind::Text => "text",
+        }
+    }
+}
+
+// The summary lines for one column's values
+fn describe<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    let mut empty = 0;
+    let mut nulls = 0;
+    let mut present = Vec::new();
+    for value in values {
+        if value.is_empty() {
+            empty += 1;
+        } else if NULL_MARKERS
+            .iter()
+            .any(|marker| value.eq_ignore_ascii_case(marker))
+        {
+            nulls += 1;
+        } else {
+            present.push(value);
+        }
+    }
+    let distinct: HashSet<&str> = present.iter().copied().collect();
+
+    let mut kinds: Vec<(Kind, usize)> = Vec::new();
+    for value in &present {
+        let kind = Kind::of(value);
+        match kinds.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, count)) => *count += 1,
+            None => kinds.push((kind, 1)),
+        }
+    }
+    kinds.sort();
+    let numeric = !kinds.is_empty()
+        && kinds
+            .iter()
+            .all(|(kind, _)| matches!(kind, Kind::Integer | Kind::Decimal));
+    let kind = match &kinds[..] {
+        [] => "empty".to_string(),
+        [(kind, _)] => kind.name().to_string(),
+        _ if numeric => Kind::Decimal.name().to_string(),
+        _ => {
+            let counts: Vec<String> = kinds
+                .iter()
+                .map(|(kind, count)| format!("{} {}", count, kind.name()))
+                .collect();
+            format!("mixed ({})", counts.join(", "))
+        }
+    };
+
+    let mut lines = vec![
+        format!("  Type: {}", kind),
+        format!("  Values: {}", present.len()),
+        format!("  Empty: {}", empty),
+        format!("  Null: {}", nulls),
+        format!("  Distinct: {}", distinct.len()),
+    ];
+    if numeric {
+        let numbers: Vec<f64> = present.iter().filter_map(|v| parse_number(v)).collect();
+        let min = numbers.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = numbers.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let sum: f64 = numbers.iter().sum();
+        lines.push(format!("  Min: {}", format_number(min)));
+        lines.push(format!("  Max: {}", format_number(max)));
+        lines.push(format!(
+            "  Mean: {}",
+            format_number(sum / numbers.len() as f64)
+        ));
+    } else if kinds == [(Kind::Date, present.len())] {
+        // ISO 8601 dates in the same form sort as text
+        let min = present.iter().min().copied().unwrap_or_default();
+        let max = present.iter().max().copied().unwrap_or_default();
+        lines.push(format!("  Earliest: {}", min));
+        lines.push(format!("  Latest: {}", max));
+    } else if kinds == [(Kind::Boolean, present.len())] {
+        let truthy = present
+            .iter()
+            .filter(|value| value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("yes"))
+            .count();
+        lines.push(format!("  True: {}", truthy));
+        lines.push(format!("  False: {}", present.len() - truthy));
+    } else if !present.is_empty() {
+        let lengths = present.iter().map(|value| value.chars().count());
+        let shortest = lengths.clone().min().unwrap_or(0);
+        let longest = lengths.max().unwrap_or(0);
+        lines.push(format!("  Length: {} to {}", shortest, longest));
+    }
+    lines.join("\n")
+}
+
+// Plain decimal numbers, with no "inf" or "nan" that Rust would also
+// accept
+fn parse_number(value: &str) -> Option<f64> {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    if !digits.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        || !digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '-' | '+'))
+    {
+        return None;
+    }
+    value.parse().ok()
+}
+
+// Whole numbers without a fraction, others to at most 4 decimal places
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        let text = format!("{:.4}", value);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_stats() {
+        assert_eq!(
+            CsvStats.transform(CsvStats.default_test_input()).unwrap(),
+            "Rows: 4
+Columns: 5
+
+Column 1: id
+  Type: integer
+  Values: 4
+  Empty: 0
+  Null: 0
+  Distinct: 4
+  Min: 1
+  Max: 4
+  Mean: 2.5
+
+Column 2: name
+  Type: text
+  Values: 4
+  Empty: 0
+  Null: 0
+  Distinct: 3
+  Length: 3 to 5
+
+Column 3: score
+  Type: decimal
+  Values: 2
+  Empty: 1
+  Null: 1
+  Distinct: 2
+  Min: 87
+  Max: 98.5
+  Mean: 92.75
+
+Column 4: joined
+  Type: date
+  Values: 4
+  Empty: 0
+  Null: 0
+  Distinct: 4
+  Earliest: 2022-07-01
+  Latest: 2024-03-30
+
+Column 5: active
+  Type: boolean
+  Values: 4
+  Empty: 0
+  Null: 0
+  Distinct: 3
+  True: 3
+  False: 1"
+        );
+    }
+
+    #[test]
+    fn test_csv_stats_mixed_and_ragged() {
+        let output = CsvStats
+            .transform("code,extra\n100\n-2.5e1\nA-7,x")
+            .unwrap();
+        assert!(output.contains("Column 1: code\n  Type: mixed (1 integer, 1 decimal, 1 text)"));
+        assert!(output.contains("Column 2: extra\n  Type: text\n  Values: 1\n  Empty: 2"));
+        assert!(CsvStats
+            .transform("n\n1\n0.5")
+            .unwrap()
+            .ends_with("Min: 0.5\n  Max: 1\n  Mean: 0.75"));
+        assert!(CsvStats
+            .transform("n\ninf\nnan")
+            .unwrap()
+            .contains("Type: text\n  Values: 1\n  Empty: 0\n  Null: 1"));
+    }
+
+    #[test]
+    fn test_csv_stats_without_header() {
+        let mut options = TransformOptions::new();
+        options.set("header", "false");
+        options.set("delimiter", "tab");
+        let output = CsvStats
+            .transform_with_options("1\t\n2\t", &options)
+            .unwrap();
+        assert!(output.starts_with("Rows: 2\nColumns: 2\n\nColumn 1\n  Type: integer"));
+        assert!(output.ends_with(
+            "Column 2\n  Type: empty\n  Values: 0\n  Empty: 2\n  Null: 0\n  Distinct: 0"
+        ));
+        assert_eq!(CsvStats.transform("").unwrap(), "Rows: 0\nColumns: 0");
+    }
+}
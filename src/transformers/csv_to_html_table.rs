@@ -0,0 +1,159 @@
+use crate::utils::csv::{self, DELIMITER_OPTION, HEADER_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// CSV to HTML table transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvToHtmlTable;
+
+impl Transform for CsvToHtmlTable {
+    fn name(&self) -> &'static str {
+        "CSV to HTML Table"
+    }
+
+    fn id(&self) -> &'static str {
+        "csvtohtmltable"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts CSV into an HTML <table> with the header row in <thead>, escaping markup and turning line breaks inside fields into <br>"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Formatter
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[DELIMITER_OPTION, HEADER_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let delimiter = csv::delimiter(options)?;
+        let records = csv::parse_with_cancel(input, delimiter, token)?;
+        if records.is_empty() {
+            return Ok(String::new());
+        }
+        let (header, body) = if options.bool_value(&HEADER_OPTION)? {
+            (&records[..1], &records[1..])
+        } else {
+            (&[][..], &records[..])
+        };
+
+        let mut html = String::from("<table>\n");
+        for (section, cell, rows) in [("thead", "th", header), ("tbody", "td", body)] {
+            if rows.is_empty() {
+                continue;
+            }
+            html.push_str(&format!("  <{}>\n", section));
+            for row in rows {
+                html.push_str("    <tr>\n");
+                for field in row {
+                    html.push_str(&format!("      <{}>{}</{}>\n", cell, escape(field), cell));
+                }
+                html.push_str("    </tr>\n");
+            }
+            html.push_str(&format!("  </{}>\n", section));
+        }
+        html.push_str("</table>");
+        Ok(html)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "name,language,year\nAda Lovelace,\"Notes <on> the Engine\",1843\n\"Hopper, Grace\",COBOL & FLOW-MATIC,1959"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Price list",
+                input: "item,price\nCoffee,3.50\nTea,2.75",
+            },
+            Example {
+                name: "Multi-line cell",
+                input: "step,detail\n1,\"Open the lid\nPour the water\"\n2,Wait",
+            },
+        ]
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\n' => out.push_str("<br>"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_to_html_table() {
+        assert_eq!(
+            CsvToHtmlTable
+                .transform(CsvToHtmlTable.default_test_input())
+                .unwrap(),
+            "<table>
+  <thead>
+    <tr>
+      <th>name</th>
+      <th>language</th>
+      <th>year</th>
+    </tr>
+  </thead>
+  <tbody>
+    <tr>
+      <td>Ada Lovelace</td>
+      <td>Notes &lt;on&gt; the Engine</td>
+      <td>1843</td>
+    </tr>
+    <tr>
+      <td>Hopper, Grace</td>
+      <td>COBOL &amp; FLOW-MATIC</td>
+      <td>1959</td>
+    </tr>
+  </tbody>
+</table>"
+        );
+        assert_eq!(CsvToHtmlTable.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_csv_to_html_table_options() {
+        let mut options = TransformOptions::new();
+        options.set("header", "false");
+        options.set("delimiter", "tab");
+        assert_eq!(
+            CsvToHtmlTable
+                .transform_with_options("a\t\"x\ny\"", &options)
+                .unwrap(),
+            "<table>\n  <tbody>\n    <tr>\n      <td>a</td>\n      <td>x<br>y</td>\n    </tr>\n  </tbody>\n</table>"
+        );
+        // A header alone has no body
+        assert!(!CsvToHtmlTable
+            .transform("only,header")
+            .unwrap()
+            .contains("tbody"));
+        assert!(CsvToHtmlTable.transform("a,\"open").is_err());
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::utils::csv::{self, DELIMITER_OPTION};
+use crate::utils::json::{is_number, push_escaped};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
 
 /// CSV to JSON transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,150 +27,85 @@ impl Transform for CsvToJson {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        if input.trim().is_empty() {
-            return Ok("[]".to_string());
-        }
-
-        let mut lines = input.lines().collect::<Vec<_>>();
-        if lines.is_empty() {
-            return Ok("[]".to_string());
-        }
-
-        // Extract header row
-        let header = lines.remove(0);
-        let headers = parse_csv_row(header);
-
-        if headers.is_empty() {
-            return Ok("[]".to_string());
-        }
-
-        // Process data rows
-        let mut json = String::from("[");
-        let mut first_row = true;
-
-        for line in lines {
-            if line.trim().is_empty() {
-                continue;
-            }
-
-            let values = parse_csv_row(line);
-            if values.is_empty() {
-                continue;
-            }
-
-            if !first_row {
-                json.push(',');
-            } else {
-                first_row = false;
-            }
-
-            // Create JSON object for this row
-            json.push_str("\n  {");
-            let mut first_field = true;
-
-            for (i, value) in values.iter().enumerate() {
-                if i >= headers.len() {
-                    break;
-                }
-
-                if !first_field {
-                    json.push(',');
-                } else {
-                    first_field = false;
-                }
-
-                // Escape JSON field name
-                json.push_str(&format!("\n    \"{}\":", escape_json_string(&headers[i])));
-
-                // Handle value based on content
-                if value.trim().is_empty() {
-                    json.push_str("null");
-                } else if value == "true"
-                    || value == "false"
-                    || value == "null"
-                    || value.parse::<f64>().is_ok()
-                {
-                    // Numbers, booleans, and null can be added directly
-                    json.push_str(value);
-                } else {
-                    // String values need to be quoted and escaped
-                    json.push_str(&format!("\"{}\"", escape_json_string(value)));
-                }
-            }
-
-            json.push_str("\n  }");
-        }
+        self.transform_with_options(input, &TransformOptions::new())
+    }
 
-        if first_row {
-            // No rows were processed, return an empty array without newlines
-            return Ok("[]".to_string());
-        }
+    fn options(&self) -> &'static [TransformOption] {
+        &[DELIMITER_OPTION]
+    }
 
-        json.push_str("\n]");
-        Ok(json)
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let records = csv::parse_with_cancel(input, csv::delimiter(options)?, token)?;
+        Ok(records_to_json(&records))
     }
 
     fn default_test_input(&self) -> &'static str {
         "id,name,value\n1,apple,1.5\n2,banana,0.75"
     }
-}
-
-/// Parses a CSV row into fields, handling quoted values
-fn parse_csv_row(row: &str) -> Vec<String> {
-    let mut fields = Vec::new();
-    let mut current_field = String::new();
-    let mut in_quotes = false;
-    let mut chars = row.chars().peekable();
 
-    while let Some(c) = chars.next() {
-        match c {
-            '"' => {
-                if in_quotes && chars.peek() == Some(&'"') {
-                    // Escaped quote inside quoted field
-                    chars.next(); // Consume the second quote
-                    current_field.push('"');
-                } else {
-                    // Toggle quote mode
-                    in_quotes = !in_quotes;
-                }
-            }
-            ',' if !in_quotes => {
-                // End of field
-                fields.push(current_field);
-                current_field = String::new();
-            }
-            _ => {
-                current_field.push(c);
-            }
-        }
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Quoted fields",
+                input: "name,quote,year\n\"Lovelace, Ada\",\"The Analytical Engine weaves algebraic patterns\",1843\nTuring,\"Machines take me by surprise\",1950",
+            },
+            Example {
+                name: "Numbers and booleans",
+                input: "sku,price,in_stock\nBK-1,12.50,true\nPN-7,3.99,false",
+            },
+        ]
     }
-
-    // Add the last field
-    fields.push(current_field);
-    fields
 }
 
-/// Escapes special characters in a JSON string
-fn escape_json_string(s: &str) -> String {
-    let mut result = String::with_capacity(s.len() + 2);
+/// Converts records, the first of them a header row, to a JSON array of objects
+fn records_to_json(records: &[Vec<String>]) -> String {
+    let Some((headers, rows)) = records.split_first() else {
+        return "[]".to_string();
+    };
+    // A line of nothing but spaces is as good as blank
+    let rows: Vec<&Vec<String>> = rows
+        .iter()
+        .filter(|row| !matches!(row.as_slice(), [only] if only.trim().is_empty()))
+        .collect();
+    if rows.is_empty() {
+        return "[]".to_string();
+    }
 
-    for c in s.chars() {
-        match c {
-            '"' => result.push_str("\\\""),
-            '\\' => result.push_str("\\\\"),
-            '\n' => result.push_str("\\n"),
-            '\r' => result.push_str("\\r"),
-            '\t' => result.push_str("\\t"),
-            '\u{0008}' => result.push_str("\\b"),
-            '\u{000C}' => result.push_str("\\f"),
-            _ if c.is_control() => {
-                result.push_str(&format!("\\u{:04x}", c as u32));
+    let mut json = String::from("[");
+    for (i, row) in rows.into_iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str("\n  {");
+        // Fields beyond the header have no name and are dropped
+        for (j, (header, value)) in headers.iter().zip(row).enumerate() {
+            if j > 0 {
+                json.push(',');
+            }
+            json.push_str("\n    \"");
+            push_escaped(&mut json, header);
+            json.push_str("\":");
+
+            if value.trim().is_empty() {
+                json.push_str("null");
+            } else if value == "true" || value == "false" || value == "null" || is_number(value) {
+                // Numbers, booleans, and null can be added directly
+                json.push_str(value);
+            } else {
+                json.push('"');
+                push_escaped(&mut json, value);
+                json.push('"');
             }
-            _ => result.push(c),
         }
+        json.push_str("\n  }");
     }
-
-    result
+    json.push_str("\n]");
+    json
 }
 
 #[cfg(test)]
@@ -228,6 +168,15 @@ Bob,"Quoted ""text"" here""#;
         assert_eq!(transformer.transform(input).unwrap(), expected);
     }
 
+    #[test]
+    fn test_csv_to_json_number_like_strings() {
+        // Things Rust parses as floats but JSON doesn't accept as numbers
+        let transformer = CsvToJson;
+        let input = "a,b,c,d\nNaN,+5,007,-1.5e3";
+        let expected = "[\n  {\n    \"a\":\"NaN\",\n    \"b\":\"+5\",\n    \"c\":\"007\",\n    \"d\":-1.5e3\n  }\n]";
+        assert_eq!(transformer.transform(input).unwrap(), expected);
+    }
+
     #[test]
     fn test_csv_to_json_empty() {
         let transformer = CsvToJson;
@@ -256,4 +205,34 @@ Bob,"Quoted ""text"" here""#;
 ]"#;
         assert_eq!(transformer.transform(input).unwrap(), expected);
     }
+
+    #[test]
+    fn test_csv_to_json_delimiter_option() {
+        let transformer = CsvToJson;
+        let mut options = TransformOptions::new();
+        options.set("delimiter", "semicolon");
+        let expected = "[\n  {\n    \"a\":\"x,y\",\n    \"b\":2\n  }\n]";
+        assert_eq!(
+            transformer
+                .transform_with_options("a;b\nx,y;2", &options)
+                .unwrap(),
+            expected
+        );
+        options.set("delimiter", "tab");
+        assert_eq!(
+            transformer
+                .transform_with_options("a\tb\nx,y\t2", &options)
+                .unwrap(),
+            expected
+        );
+        options.set("delimiter", ";");
+        assert!(transformer.transform_with_options("a;b", &options).is_err());
+    }
+
+    #[test]
+    fn test_csv_to_json_multiline_field() {
+        let input = "name,bio\nAda,\"First line\nsecond, with a comma\"\nGrace,Admiral";
+        let expected = "[\n  {\n    \"name\":\"Ada\",\n    \"bio\":\"First line\\nsecond, with a comma\"\n  },\n  {\n    \"name\":\"Grace\",\n    \"bio\":\"Admiral\"\n  }\n]";
+        assert_eq!(CsvToJson.transform(input).unwrap(), expected);
+    }
 }
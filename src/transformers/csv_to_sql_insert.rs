@@ -0,0 +1,206 @@
+use crate::utils::csv::{self, DELIMITER_OPTION};
+use crate::utils::json::is_number;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const TABLE_OPTION: TransformOption = TransformOption {
+    key: "table",
+    description: "Table to insert into, optionally with a schema such as public.users",
+    default: "my_table",
+    kind: TransformOptionKind::Text,
+};
+
+const MULTI_ROW_OPTION: TransformOption = TransformOption {
+    key: "multi_row",
+    description: "Write one INSERT with every row instead of one INSERT per row",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// CSV to SQL INSERT statements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvToSqlInsert;
+
+impl Transform for CsvToSqlInsert {
+    fn name(&self) -> &'static str {
+        "CSV to SQL INSERT"
+    }
+
+    fn id(&self) -> &'static str {
+        "csvtosqlinsert"
+    }
+
+    fn description(&self) -> &'static str {
+        "Turns CSV with a header row into SQL INSERT statements. Columns whose values are all numbers are left unquoted, empty fields become NULL."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[TABLE_OPTION, DELIMITER_OPTION, MULTI_ROW_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let table = options.value(&TABLE_OPTION).trim();
+        if table.is_empty() || table.split('.').any(str::is_empty) {
+            return Err(TransformError::InvalidArgument(
+                format!("'{}' is not a table name", table).into(),
+            ));
+        }
+        let delimiter = csv::delimiter(options)?;
+        let records = csv::parse_with_cancel(input, delimiter, token)?;
+        let Some((header, rows)) = records.split_first() else {
+            return Ok(String::new());
+        };
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != header.len() {
+                return Err(TransformError::InvalidArgument(
+                    format!(
+                        "Row {} has {} fields but the header has {}",
+                        i + 2,
+                        row.len(),
+                        header.len()
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        // A column is numeric when every value in it is a number
+        let numeric: Vec<bool> = (0..header.len())
+            .map(|column| {
+                rows.iter()
+                    .map(|row| row[column].trim())
+                    .all(|value| value.is_empty() || is_number(value))
+            })
+            .collect();
+        let tuples: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                let values: Vec<String> = row
+                    .iter()
+                    .zip(&numeric)
+                    .map(|(value, &numeric)| match value.trim() {
+                        "" => "NULL".to_string(),
+                        number if numeric => number.to_string(),
+                        _ => format!("'{}'", value.replace('\'', "''")),
+                    })
+                    .collect();
+                format!("({})", values.join(", "))
+            })
+            .collect();
+
+        let table: Vec<String> = table.split('.').map(quote_identifier).collect();
+        let columns: Vec<String> = header.iter().map(|name| quote_identifier(name)).collect();
+        let insert = format!(
+            "INSERT INTO {} ({}) VALUES",
+            table.join("."),
+            columns.join(", ")
+        );
+        if tuples.is_empty() {
+            return Ok(String::new());
+        }
+        if options.bool_value(&MULTI_ROW_OPTION)? {
+            Ok(format!("{}\n  {};", insert, tuples.join(",\n  ")))
+        } else {
+            let statements: Vec<String> = tuples
+                .iter()
+                .map(|tuple| format!("{} {};", insert, tuple))
+                .collect();
+            Ok(statements.join("\n"))
+        }
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "id,name,email,score\n1,Ada Lovelace,ada@example.com,98.5\n2,Charles O'Brien,,87\n3,Grace Hopper,grace@example.com,"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Codes kept as text",
+                input: "zip,city\n02134,Boston\n10001,New York",
+            },
+            Example {
+                name: "Column names with spaces",
+                input: "Product Name,Unit Price\nCoffee,3.50\nTea,2.75",
+            },
+        ]
+    }
+}
+
+// Plain identifiers are left bare; anything else is double quoted as
+// standard SQL does
+fn quote_identifier(name: &str) -> String {
+    let name = name.trim();
+    if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(input: &str, settings: &[(&str, &str)]) -> Result<String, TransformError> {
+        let options: TransformOptions = settings.iter().copied().collect();
+        CsvToSqlInsert.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_csv_to_sql_insert() {
+        assert_eq!(
+            CsvToSqlInsert
+                .transform(CsvToSqlInsert.default_test_input())
+                .unwrap(),
+            "INSERT INTO my_table (id, name, email, score) VALUES (1, 'Ada Lovelace', 'ada@example.com', 98.5);\n\
+             INSERT INTO my_table (id, name, email, score) VALUES (2, 'Charles O''Brien', NULL, 87);\n\
+             INSERT INTO my_table (id, name, email, score) VALUES (3, 'Grace Hopper', 'grace@example.com', NULL);"
+        );
+    }
+
+    #[test]
+    fn test_csv_to_sql_insert_options() {
+        assert_eq!(
+            insert(
+                "Product Name;zip\nCoffee;02134\n\"Tea; green\";10001",
+                &[
+                    ("table", "shop.\"order\" items"),
+                    ("delimiter", "semicolon"),
+                    ("multi_row", "true")
+                ]
+            )
+            .unwrap(),
+            "INSERT INTO shop.\"\"\"order\"\" items\" (\"Product Name\", zip) VALUES\n  ('Coffee', '02134'),\n  ('Tea; green', '10001');"
+        );
+    }
+
+    #[test]
+    fn test_csv_to_sql_insert_edge_cases() {
+        assert_eq!(insert("", &[]).unwrap(), "");
+        assert_eq!(insert("a,b", &[]).unwrap(), "");
+        assert!(insert("a,b\n1", &[])
+            .unwrap_err()
+            .to_string()
+            .contains("Row 2 has 1 fields but the header has 2"));
+        assert!(insert("a\n1", &[("table", "")]).is_err());
+        assert!(insert("a\n1", &[("table", "schema.")]).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use super::date_parse::parse_date;
+use crate::utils::time::{days_in_month, DateTime, Timestamp};
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+const MILLIS_PER_SECOND: i64 = 1000;
+const MILLIS_PER_MINUTE: i64 = 60 * MILLIS_PER_SECOND;
+const MILLIS_PER_HOUR: i64 = 60 * MILLIS_PER_MINUTE;
+const MILLIS_PER_DAY: i64 = 24 * MILLIS_PER_HOUR;
+
+/// Difference between two dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateDiff;
+
+impl Transform for DateDiff {
+    fn name(&self) -> &'static str {
+        "Date Difference"
+    }
+
+    fn id(&self) -> &'static str {
+        "date_diff"
+    }
+
+    fn description(&self) -> &'static str {
+        "Works out the time between two dates or timestamps, one per line, in years, months and days and in total days, hours and seconds"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let dates = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let line = line.trim();
+                parse_date(line, "auto")
+                    .map(|(timestamp, _)| timestamp)
+                    .ok_or_else(|| {
+                        TransformError::InvalidArgument(
+                            format!("Line {}: '{}' is not a date format I know", i + 1, line)
+                                .into(),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, TransformError>>()?;
+        let [from, to] = dates[..] else {
+            return Err(TransformError::InvalidArgument(
+                format!("Expected two dates, one per line, got {}", dates.len()).into(),
+            ));
+        };
+
+        let (earlier, later) = if from <= to { (from, to) } else { (to, from) };
+        let total = later.as_millis() - earlier.as_millis();
+        let mut difference = calendar_difference(earlier, later);
+        if from > to {
+            difference.push_str(" (the second date is earlier)");
+        }
+        let sign = if from > to { "-" } else { "" };
+        Ok(format!(
+            "From: {}\nTo: {}\nDifference: {}\nTotal days: {}{}\nTotal hours: {}{}\nTotal seconds: {}{}",
+            from.to_rfc3339(),
+            to.to_rfc3339(),
+            difference,
+            sign,
+            format_total(total, MILLIS_PER_DAY, 2),
+            sign,
+            format_total(total, MILLIS_PER_HOUR, 2),
+            sign,
+            format_total(total, MILLIS_PER_SECOND, 3),
+        ))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "2024-01-15\n2025-03-18T04:05:06Z"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Across a leap day",
+                input: "2024-02-28\n2024-03-01",
+            },
+            Example {
+                name: "Mixed formats",
+                input: "1700000000\nTue, 14 Nov 2023 22:13:20 +0000",
+            },
+            Example {
+                name: "Backwards",
+                input: "2000-01-01\n1999-12-25 12:00",
+            },
+        ]
+    }
+}
+
+// Whole years, months and days from `earlier` to `later`, then the time
+// of day left over. Adding months keeps the day of month where it can and
+// otherwise moves it back to the end of the month, so January 31st plus
+// one month is the last day of February.
+fn calendar_difference(earlier: Timestamp, later: Timestamp) -> String {
+    let start = DateTime::from_timestamp(earlier, 0);
+    let end = DateTime::from_timestamp(later, 0);
+    let mut months = (end.year - start.year) * 12 + i64::from(end.month) - i64::from(start.month);
+    let time_of_month = |date_time: &DateTime| {
+        (
+            date_time.day,
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+            date_time.nanos,
+        )
+    };
+    if time_of_month(&end) < time_of_month(&start) {
+        months -= 1;
+    }
+    let anchor = add_months(start, months);
+    let mut rest = later.as_millis() - anchor.timestamp(0).as_millis();
+
+    let mut parts = Vec::new();
+    for (count, unit) in [(months / 12, "year"), (months % 12, "month")] {
+        push_part(&mut parts, count, unit);
+    }
+    for (size, unit) in [
+        (MILLIS_PER_DAY, "day"),
+        (MILLIS_PER_HOUR, "hour"),
+        (MILLIS_PER_MINUTE, "minute"),
+        (MILLIS_PER_SECOND, "second"),
+    ] {
+        push_part(&mut parts, rest / size, unit);
+        rest %= size;
+    }
+    if rest > 0 {
+        push_part(&mut parts, rest, "millisecond");
+    }
+    if parts.is_empty() {
+        return "0 seconds".to_string();
+    }
+    parts.join(", ")
+}
+
+fn add_months(date_time: DateTime, months: i64) -> DateTime {
+    let month_index = i64::from(date_time.month) - 1 + months;
+    let year = date_time.year + month_index.div_euclid(12);
+    let month = month_index.rem_euclid(12) as u32 + 1;
+    DateTime {
+        year,
+        month,
+        day: date_time.day.min(days_in_month(year, month)),
+        ..date_time
+    }
+}
+
+fn push_part(parts: &mut Vec<String>, count: i64, unit: &str) {
+    if count != 0 {
+        let plural = if count == 1 { "" } else { "s" };
+        parts.push(format!("{} {}{}", count, unit, plural));
+    }
+}
+
+// `millis` in `unit`s, rounded to at most `decimals` places with trailing
+// zeros dropped
+fn format_total(millis: i64, unit: i64, decimals: u32) -> String {
+    let scale = 10i64.pow(decimals);
+    let scaled = (i128::from(millis) * i128::from(scale) + i128::from(unit) / 2) / i128::from(unit);
+    let whole = scaled / i128::from(scale);
+    let fraction = scaled % i128::from(scale);
+    if fraction == 0 {
+        whole.to_string()
+    } else {
+        let digits = format!("{:0width$}", fraction, width = decimals as usize);
+        format!("{}.{}", whole, digits.trim_end_matches('0'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn difference(input: &str) -> String {
+        DateDiff
+            .transform(input)
+            .unwrap()
+            .lines()
+            .find_map(|line| line.strip_prefix("Difference: "))
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_date_diff_output() {
+        assert_eq!(
+            DateDiff.transform(DateDiff.default_test_input()).unwrap(),
+            "From: 2024-01-15T00:00:00Z\n\
+             To: 2025-03-18T04:05:06Z\n\
+             Difference: 1 year, 2 months, 3 days, 4 hours, 5 minutes, 6 seconds\n\
+             Total days: 428.17\n\
+             Total hours: 10276.09\n\
+             Total seconds: 36993906"
+        );
+    }
+
+    #[test]
+    fn test_date_diff_calendar() {
+        assert_eq!(difference("2024-02-28\n2024-03-01"), "2 days");
+        assert_eq!(difference("2024-01-31\n2024-02-29"), "29 days");
+        assert_eq!(difference("2024-01-31\n2024-03-01"), "1 month, 1 day");
+        assert_eq!(
+            difference("2023-03-31T12:00Z\n2023-04-30T11:00Z"),
+            "29 days, 23 hours"
+        );
+        // The 29th has not come round again, as in java.time.Period
+        assert_eq!(difference("2020-02-29\n2021-02-28"), "11 months, 30 days");
+        assert_eq!(difference("2020-02-29\n2021-03-01"), "1 year, 1 day");
+        assert_eq!(difference("2024-05-05\n2024-05-05"), "0 seconds");
+        assert_eq!(
+            difference("2024-05-05T00:00:00Z\n2024-05-05T00:00:01.25Z"),
+            "1 second, 250 milliseconds"
+        );
+    }
+
+    #[test]
+    fn test_date_diff_backwards_and_mixed() {
+        let output = DateDiff
+            .transform("2000-01-01\n\n1999-12-25 12:00")
+            .unwrap();
+        assert!(output.contains("Difference: 6 days, 12 hours (the second date is earlier)"));
+        assert!(output.ends_with("Total days: -6.5\nTotal hours: -156\nTotal seconds: -561600"));
+        assert!(DateDiff
+            .transform("1700000000\nTue, 14 Nov 2023 22:13:20 +0000")
+            .unwrap()
+            .contains("Difference: 0 seconds"));
+    }
+
+    #[test]
+    fn test_date_diff_invalid() {
+        assert!(DateDiff.transform("2024-01-01").is_err());
+        assert!(DateDiff
+            .transform("2024-01-01\n2024-02-01\n2024-03-01")
+            .is_err());
+        assert!(DateDiff.transform("2024-01-01\nyesterday").is_err());
+        assert!(DateDiff.transform("").is_err());
+        assert!(DateDiff
+            .transform("Mo€, 14 Nov 2023 22:13:20 +0000\n2024-01-01")
+            .is_err());
+    }
+}
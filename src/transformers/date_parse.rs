@@ -0,0 +1,367 @@
+use super::timestamp_to_iso::parse_timestamp;
+use crate::utils::time::{parse_iso8601, DateTime, Timestamp};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const NUMBERS_OPTION: TransformOption = TransformOption {
+    key: "numbers",
+    description: "How plain numbers are read: auto takes 18 digits as Windows FILETIME and anything else as Unix time",
+    default: "auto",
+    kind: TransformOptionKind::Choice(&["auto", "unix", "cocoa", "filetime"]),
+};
+
+/// Seconds from the Unix epoch to the Apple Cocoa (Core Data) epoch,
+/// 2001-01-01
+const COCOA_EPOCH: i64 = 978_307_200;
+
+/// Seconds from the Windows FILETIME epoch, 1601-01-01, to the Unix epoch
+const FILETIME_EPOCH: i64 = 11_644_473_600;
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+const WEEKDAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Zone names allowed in RFC 2822 dates, with their offsets in hours
+const ZONE_NAMES: [(&str, i32); 12] = [
+    ("UT", 0),
+    ("UTC", 0),
+    ("GMT", 0),
+    ("Z", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+];
+
+/// Date and time parser
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateParse;
+
+impl Transform for DateParse {
+    fn name(&self) -> &'static str {
+        "Date Parser"
+    }
+
+    fn id(&self) -> &'static str {
+        "date_parse"
+    }
+
+    fn description(&self) -> &'static str {
+        "Recognizes dates in many formats (ISO 8601, RFC 2822, YYYY/MM/DD, Twitter, Unix, Cocoa and FILETIME timestamps), one per line, and rewrites each as ISO 8601 UTC with the format it was read as"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[NUMBERS_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let numbers = options.value(&NUMBERS_OPTION);
+        let lines = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                token.check_every(i)?;
+                let line = line.trim();
+                if line.is_empty() {
+                    return Ok(String::new());
+                }
+                let (timestamp, format) = parse_date(line, numbers).ok_or_else(|| {
+                    TransformError::InvalidArgument(
+                        format!("Line {}: '{}' is not a date format I know", i + 1, line).into(),
+                    )
+                })?;
+                Ok(format!("{} ({})", timestamp.to_rfc3339(), format))
+            })
+            .collect::<Result<Vec<_>, TransformError>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Tue, 14 Nov 2023 22:13:20 +0000"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "A mix of formats",
+                input: "2023-11-15T09:13:20+11:00\n2023/11/14 22:13:20\nTue Nov 14 22:13:20 +0000 2023\n1700000000\n133444736000000000",
+            },
+            Example {
+                name: "Mail headers",
+                input: "Mon, 15 Jan 2024 09:30 EST\n1 Feb 2024 12:00:00 GMT",
+            },
+        ]
+    }
+}
+
+/// The instant `text` describes and the name of its format
+pub(crate) fn parse_date(text: &str, numbers: &str) -> Option<(Timestamp, String)> {
+    if text.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+')
+        && text[1..].chars().all(|c| c.is_ascii_digit() || c == '.')
+    {
+        return parse_number(text, numbers);
+    }
+    if let Some((date_time, offset)) = parse_iso8601(text) {
+        let format = match offset {
+            Some(_) => "ISO 8601",
+            None => "ISO 8601, taken as UTC",
+        };
+        return Some((date_time.timestamp(offset.unwrap_or(0)), format.to_string()));
+    }
+    if let Some(date_time) = parse_slashed(text) {
+        return Some((
+            date_time.timestamp(0),
+            "YYYY/MM/DD, taken as UTC".to_string(),
+        ));
+    }
+    let words: Vec<&str> = text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty())
+        .collect();
+    parse_rfc2822(&words)
+        .map(|timestamp| (timestamp, "RFC 2822".to_string()))
+        .or_else(|| parse_twitter(&words))
+}
+
+fn parse_number(text: &str, numbers: &str) -> Option<(Timestamp, String)> {
+    let digits = text.trim_start_matches(['-', '+']).len();
+    let numbers = match numbers {
+        "auto" if digits == 18 && !text.contains('.') => "filetime",
+        "auto" => "unix",
+        numbers => numbers,
+    };
+    match numbers {
+        "filetime" => {
+            let intervals: i64 = text.parse().ok()?;
+            Some((
+                Timestamp {
+                    seconds: intervals.div_euclid(10_000_000) - FILETIME_EPOCH,
+                    nanos: intervals.rem_euclid(10_000_000) as u32 * 100,
+                },
+                "Windows FILETIME".to_string(),
+            ))
+        }
+        "cocoa" => {
+            let (timestamp, unit) = parse_timestamp(text)?;
+            let timestamp = Timestamp {
+                seconds: timestamp.seconds + COCOA_EPOCH,
+                ..timestamp
+            };
+            Some((timestamp, format!("Apple Cocoa time, {}", unit)))
+        }
+        _ => {
+            let (timestamp, unit) = parse_timestamp(text)?;
+            Some((timestamp, format!("Unix time, {}", unit)))
+        }
+    }
+}
+
+// YYYY/MM/DD with an optional HH:MM[:SS] time
+fn parse_slashed(text: &str) -> Option<DateTime> {
+    let (date, time) = text.split_once(' ').unwrap_or((text, ""));
+    let mut parts = date.split('/');
+    let year = parts.next().filter(|year| year.len() == 4)?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let mut date_time = DateTime::from_date(year, month, day);
+    if !time.trim().is_empty() {
+        (date_time.hour, date_time.minute, date_time.second) = parse_time(time.trim())?;
+    }
+    date_time.is_valid().then_some(date_time)
+}
+
+// [Weekday,] D Mon YYYY HH:MM[:SS] zone
+fn parse_rfc2822(words: &[&str]) -> Option<Timestamp> {
+    let words = match words.first() {
+        Some(word) if weekday(word) => &words[1..],
+        _ => words,
+    };
+    let [day, month, year, time, zone] = words else {
+        return None;
+    };
+    let mut date_time = DateTime::from_date(
+        year.parse().ok().filter(|_| year.len() == 4)?,
+        month_number(month)?,
+        day.parse().ok()?,
+    );
+    (date_time.hour, date_time.minute, date_time.second) = parse_time(time)?;
+    let offset = parse_zone(zone)?;
+    date_time.is_valid().then(|| date_time.timestamp(offset))
+}
+
+// Twitter's "Wed Oct 10 20:19:24 +0000 2018", or C's asctime without a zone
+fn parse_twitter(words: &[&str]) -> Option<(Timestamp, String)> {
+    let (words, offset, format) = match words {
+        [weekday, month, day, time, zone, year] => (
+            [weekday, month, day, time, year],
+            parse_zone(zone)?,
+            "Twitter",
+        ),
+        [weekday, month, day, time, year] => (
+            [weekday, month, day, time, year],
+            0,
+            "asctime, taken as UTC",
+        ),
+        _ => return None,
+    };
+    let [day_name, month, day, time, year] = words;
+    if !weekday(day_name) {
+        return None;
+    }
+    let mut date_time = DateTime::from_date(
+        year.parse().ok().filter(|_| year.len() == 4)?,
+        month_number(month)?,
+        day.parse().ok()?,
+    );
+    (date_time.hour, date_time.minute, date_time.second) = parse_time(time)?;
+    date_time
+        .is_valid()
+        .then(|| (date_time.timestamp(offset), format.to_string()))
+}
+
+fn weekday(word: &str) -> bool {
+    word.get(..3)
+        .is_some_and(|prefix| WEEKDAYS.iter().any(|day| prefix.eq_ignore_ascii_case(day)))
+}
+
+fn month_number(word: &str) -> Option<u32> {
+    let prefix = word.get(..3)?;
+    MONTHS
+        .iter()
+        .position(|month| prefix.eq_ignore_ascii_case(month))
+        .map(|index| index as u32 + 1)
+}
+
+// HH:MM or HH:MM:SS
+fn parse_time(text: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = text.split(':');
+    let hour = parts.next()?.parse().ok()?;
+    let minute = parts.next()?.parse().ok()?;
+    let second = parts.next().map_or(Some(0), |second| second.parse().ok())?;
+    parts.next().is_none().then_some((hour, minute, second))
+}
+
+// +hhmm, -hhmm or a zone name, as seconds from UTC
+fn parse_zone(zone: &str) -> Option<i32> {
+    if let Some(&(_, hours)) = ZONE_NAMES
+        .iter()
+        .find(|(name, _)| zone.eq_ignore_ascii_case(name))
+    {
+        return Some(hours * 3600);
+    }
+    let sign = match zone.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits = &zone[1..];
+    if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[..2].parse().ok()?;
+    let minutes: i32 = digits[2..].parse().ok()?;
+    (hours < 24 && minutes < 60).then_some(sign * (hours * 3600 + minutes * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> String {
+        DateParse.transform(input).unwrap()
+    }
+
+    #[test]
+    fn test_date_parse_text_formats() {
+        assert_eq!(
+            parse(DateParse.default_test_input()),
+            "2023-11-14T22:13:20Z (RFC 2822)"
+        );
+        assert_eq!(
+            parse("14 Nov 2023 17:13 EST"),
+            "2023-11-14T22:13:00Z (RFC 2822)"
+        );
+        assert_eq!(
+            parse("Wed Nov 15 09:13:20 +1100 2023"),
+            "2023-11-14T22:13:20Z (Twitter)"
+        );
+        assert_eq!(
+            parse("Tue Nov 14 22:13:20 2023"),
+            "2023-11-14T22:13:20Z (asctime, taken as UTC)"
+        );
+        assert_eq!(
+            parse("2023/11/14\n2023/11/14 22:13"),
+            "2023-11-14T00:00:00Z (YYYY/MM/DD, taken as UTC)\n\
+             2023-11-14T22:13:00Z (YYYY/MM/DD, taken as UTC)"
+        );
+        assert_eq!(
+            parse("2023-11-15T09:13:20.5+11:00\n\n2023-11-14 22:13"),
+            "2023-11-14T22:13:20.500Z (ISO 8601)\n\n2023-11-14T22:13:00Z (ISO 8601, taken as UTC)"
+        );
+    }
+
+    #[test]
+    fn test_date_parse_numbers() {
+        assert_eq!(
+            parse("1700000000\n1700000000123\n133444736000000000"),
+            "2023-11-14T22:13:20Z (Unix time, seconds)\n\
+             2023-11-14T22:13:20.123Z (Unix time, milliseconds)\n\
+             2023-11-14T22:13:20Z (Windows FILETIME)"
+        );
+        let mut options = TransformOptions::new();
+        options.set("numbers", "cocoa");
+        assert_eq!(
+            DateParse
+                .transform_with_options("721692800.25", &options)
+                .unwrap(),
+            "2023-11-14T22:13:20.250Z (Apple Cocoa time, seconds)"
+        );
+        options.set("numbers", "filetime");
+        assert_eq!(
+            DateParse.transform_with_options("0", &options).unwrap(),
+            "1601-01-01T00:00:00Z (Windows FILETIME)"
+        );
+    }
+
+    #[test]
+    fn test_date_parse_invalid() {
+        assert!(DateParse.transform("next tuesday").is_err());
+        assert!(DateParse.transform("2023/02/30").is_err());
+        assert!(DateParse
+            .transform("Tue, 14 Nov 2023 25:13:20 +0000")
+            .is_err());
+        assert!(DateParse
+            .transform("Tue, 14 Nov 2023 22:13:20 +9999")
+            .is_err());
+        // Multibyte words must not be sliced mid-character
+        assert!(DateParse
+            .transform("Mo€, 14 Nov 2023 22:13:20 +0000")
+            .is_err());
+        assert!(DateParse.transform("Mo€ 14 Nov 2023").is_err());
+        assert!(DateParse.transform("Tué Nov 14 22:13:20 2023").is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use super::indent_text::split_lines;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const PREFIX_OPTION: TransformOption = TransformOption {
+    key: "prefix",
+    description: "Text to remove from the start of every line; leave empty to remove the leading whitespace all lines share",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+/// Dedent text transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DedentText;
+
+impl Transform for DedentText {
+    fn name(&self) -> &'static str {
+        "Dedent Text"
+    }
+
+    fn id(&self) -> &'static str {
+        "dedent_text"
+    }
+
+    fn description(&self) -> &'static str {
+        "Removes the leading whitespace every non-blank line has in common, or a given prefix such as \"> \" or \"// \" from each line that starts with it"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[PREFIX_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let prefix = options.value(&PREFIX_OPTION);
+        let mut out = String::with_capacity(input.len());
+
+        if prefix.is_empty() {
+            let common = common_indent(input);
+            for (i, (line, ending)) in split_lines(input).enumerate() {
+                token.check_every(i)?;
+                // Whitespace-only lines may be shorter than the common indent
+                if !line.trim().is_empty() {
+                    out.push_str(&line[common.len()..]);
+                }
+                out.push_str(ending);
+            }
+            return Ok(out);
+        }
+
+        // A quoted blank line is often just ">" without the trailing space
+        let blank_prefix = prefix.trim_end();
+        for (i, (line, ending)) in split_lines(input).enumerate() {
+            token.check_every(i)?;
+            let rest = match line.strip_prefix(prefix) {
+                Some(rest) => rest,
+                None if !blank_prefix.is_empty() && line.trim_end() == blank_prefix => "",
+                None => line,
+            };
+            out.push_str(rest);
+            out.push_str(ending);
+        }
+        Ok(out)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "        if ready {\n            start();\n        }\n"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Tabs",
+            input: "\t\t<ul>\n\t\t\t<li>One</li>\n\n\t\t\t<li>Two</li>\n\t\t</ul>",
+        }]
+    }
+}
+
+// The longest run of leading whitespace that every non-blank line starts
+// with. Tabs and spaces only match themselves.
+pub(crate) fn common_indent(input: &str) -> &str {
+    let mut common: Option<&str> = None;
+    for (line, _) in split_lines(input) {
+        let content = line.trim_start();
+        if content.is_empty() {
+            continue;
+        }
+        let indent = &line[..line.len() - content.len()];
+        common = Some(match common {
+            None => indent,
+            Some(common) => {
+                let shared = common
+                    .char_indices()
+                    .zip(indent.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map_or(common.len().min(indent.len()), |((i, _), _)| i);
+                &common[..shared]
+            }
+        });
+    }
+    common.unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::IndentText;
+
+    fn dedent(input: &str, prefix: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("prefix", prefix);
+        DedentText.transform_with_options(input, &options).unwrap()
+    }
+
+    #[test]
+    fn test_dedent_text() {
+        assert_eq!(
+            DedentText
+                .transform(DedentText.default_test_input())
+                .unwrap(),
+            "if ready {\n    start();\n}\n"
+        );
+        assert_eq!(
+            DedentText
+                .transform(DedentText.examples()[0].input)
+                .unwrap(),
+            "<ul>\n\t<li>One</li>\n\n\t<li>Two</li>\n</ul>"
+        );
+        // Mixed tabs and spaces only share what matches exactly
+        assert_eq!(
+            DedentText.transform("  \ta\r\n   b\r\n  \r\n").unwrap(),
+            "\ta\r\n b\r\n\r\n"
+        );
+        assert_eq!(DedentText.transform("a\n  b").unwrap(), "a\n  b");
+        assert_eq!(DedentText.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_dedent_text_prefix() {
+        assert_eq!(
+            dedent("> quoted\n>\n> text\nplain", "> "),
+            "quoted\n\ntext\nplain"
+        );
+        assert_eq!(dedent("// a\n//b", "// "), "a\n//b");
+    }
+
+    #[test]
+    fn test_indent_dedent_round_trip() {
+        let text = "fn f() {\n\n    body();\n}\n";
+        for prefix in ["    ", "\t", "> ", "// "] {
+            let mut options = TransformOptions::new();
+            options.set("prefix", prefix);
+            let indented = IndentText.transform_with_options(text, &options).unwrap();
+            assert_eq!(dedent(&indented, prefix), text);
+        }
+        let indented = IndentText.transform(text).unwrap();
+        assert_eq!(DedentText.transform(&indented).unwrap(), text);
+    }
+}
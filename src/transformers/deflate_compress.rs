@@ -1,4 +1,7 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
 // Import the shared base64 encoder
 use super::base64_encode;
 
@@ -159,28 +162,6 @@ pub(crate) fn reverse_bits(value: u16, num_bits: u8) -> u16 {
     result
 }
 
-// Returns the bit-reversed fixed Huffman code pattern and bit length for a given literal/length code (0-285).
-fn get_fixed_literal_length_huffman_code(code: u16) -> (u16, u8) {
-    let (pattern, num_bits) = match code {
-        0..=143 => (0b00110000 + code, 8),
-        144..=255 => (0b110010000 + (code - 144u16), 9),
-        256..=279 => (code - 256u16, 7),
-        280..=285 => (0b11000000 + (code - 280u16), 8),
-        _ => panic!("Invalid literal/length code for fixed Huffman: {}", code),
-    };
-    (reverse_bits(pattern, num_bits), num_bits)
-}
-
-// Returns the bit-reversed fixed Huffman code pattern (5 bits) and bit length for a given distance code (0-29).
-fn get_fixed_distance_huffman_code(distance_code: u16) -> (u16, u8) {
-    let num_bits = 5;
-    if distance_code <= 29 {
-        (reverse_bits(distance_code, num_bits), num_bits)
-    } else {
-        panic!("Invalid distance code for fixed Huffman: {}", distance_code);
-    }
-}
-
 // Writes bits LSB-first into a byte vector.
 struct BitWriter {
     bytes: Vec<u8>,
@@ -246,7 +227,102 @@ impl BitWriter {
 const MAX_WINDOW_SIZE: usize = 32 * 1024;
 const MIN_MATCH_LEN: usize = 3;
 const MAX_MATCH_LEN: usize = 258;
-const HASH_TABLE_SIZE: usize = 1 << 15;
+const MAX_STORED_BLOCK_LEN: usize = 65535;
+// The hash table is sized to the input between these bounds, so short inputs
+// (every keystroke in the web UI) don't pay for clearing 32K buckets
+const MIN_HASH_BITS: u32 = 8;
+const MAX_HASH_BITS: u32 = 15;
+// Marks an empty hash bucket or the end of a chain
+const NO_POS: u32 = u32::MAX;
+// A length 3 match further back than this costs more bits than 3 literals
+const TOO_FAR: usize = 4096;
+
+pub(crate) const MIN_LEVEL: u8 = 1;
+pub(crate) const MAX_LEVEL: u8 = 9;
+pub(crate) const DEFAULT_LEVEL: u8 = 6;
+
+pub(crate) const LEVEL_OPTION: TransformOption = TransformOption {
+    key: "level",
+    description: "Compression level, from 1 (fastest) to 9 (smallest output)",
+    default: "6",
+    kind: TransformOptionKind::Integer,
+};
+
+// How hard a compression level searches for matches
+struct LevelConfig {
+    // Search a quarter of the chain once the previous match is this long
+    good_length: usize,
+    // Lazy levels don't look past a match this long for a better one;
+    // greedy levels only hash the positions inside matches up to this long
+    max_lazy: usize,
+    // Stop searching once a match is this long
+    nice_length: usize,
+    // Most chain entries compared per search
+    max_chain: usize,
+    // Whether a match waits a byte in case the next position matches longer
+    lazy: bool,
+}
+
+// The same trade-offs as zlib's levels 1-9
+const LEVELS: [LevelConfig; MAX_LEVEL as usize] = [
+    LevelConfig::greedy(4, 4, 8, 4),
+    LevelConfig::greedy(4, 5, 16, 8),
+    LevelConfig::greedy(4, 6, 32, 32),
+    LevelConfig::lazy(4, 4, 16, 16),
+    LevelConfig::lazy(8, 16, 32, 32),
+    LevelConfig::lazy(8, 16, 128, 128),
+    LevelConfig::lazy(8, 32, 128, 256),
+    LevelConfig::lazy(32, 128, 258, 1024),
+    LevelConfig::lazy(32, 258, 258, 4096),
+];
+
+impl LevelConfig {
+    const fn greedy(
+        good_length: usize,
+        max_lazy: usize,
+        nice_length: usize,
+        max_chain: usize,
+    ) -> Self {
+        Self {
+            good_length,
+            max_lazy,
+            nice_length,
+            max_chain,
+            lazy: false,
+        }
+    }
+
+    const fn lazy(
+        good_length: usize,
+        max_lazy: usize,
+        nice_length: usize,
+        max_chain: usize,
+    ) -> Self {
+        Self {
+            lazy: true,
+            ..Self::greedy(good_length, max_lazy, nice_length, max_chain)
+        }
+    }
+
+    fn for_level(level: u8) -> &'static Self {
+        &LEVELS[usize::from(level.clamp(MIN_LEVEL, MAX_LEVEL) - MIN_LEVEL)]
+    }
+}
+
+/// Reads and range-checks the `level` option
+pub(crate) fn level_value(options: &TransformOptions) -> Result<u8, TransformError> {
+    let level = options.usize_value(&LEVEL_OPTION)?;
+    if !(usize::from(MIN_LEVEL)..=usize::from(MAX_LEVEL)).contains(&level) {
+        return Err(TransformError::InvalidArgument(
+            format!(
+                "Compression level must be between {} and {}",
+                MIN_LEVEL, MAX_LEVEL
+            )
+            .into(),
+        ));
+    }
+    Ok(level as u8)
+}
 
 #[derive(Debug, Clone, PartialEq)]
 enum Lz77Token {
@@ -254,167 +330,595 @@ enum Lz77Token {
     Match(u16, u16), // length, distance
 }
 
-fn lz77_compress(input: &[u8]) -> Vec<Lz77Token> {
-    if input.is_empty() {
-        return Vec::new();
+// Earlier positions of each 3 byte sequence: `head` holds the latest position
+// per hash and `prev` links every position to the one before it in its chain
+struct HashChains {
+    head: Vec<u32>,
+    prev: Vec<u32>,
+    hash_shift: u32,
+}
+
+impl HashChains {
+    fn new(input_len: usize) -> Self {
+        let hash_bits =
+            (usize::BITS - input_len.leading_zeros()).clamp(MIN_HASH_BITS, MAX_HASH_BITS);
+        Self {
+            head: vec![NO_POS; 1 << hash_bits],
+            prev: vec![NO_POS; input_len.clamp(1, MAX_WINDOW_SIZE)],
+            hash_shift: u32::BITS - hash_bits,
+        }
     }
-    let mut tokens = Vec::new();
-    let mut head: Vec<Option<usize>> = vec![None; HASH_TABLE_SIZE];
-    let mut prev: Vec<Option<usize>> = vec![None; MAX_WINDOW_SIZE];
-    let mut current_pos = 0;
-    while current_pos < input.len() {
-        let window_start = current_pos.saturating_sub(MAX_WINDOW_SIZE);
-        if current_pos + MIN_MATCH_LEN > input.len() {
-            tokens.extend(input[current_pos..].iter().map(|&b| Lz77Token::Literal(b)));
-            break;
+
+    #[inline]
+    fn hash(&self, input: &[u8], pos: usize) -> usize {
+        let key = (u32::from(input[pos]) << 16)
+            | (u32::from(input[pos + 1]) << 8)
+            | u32::from(input[pos + 2]);
+        (key.wrapping_mul(0x9e37_79b1) >> self.hash_shift) as usize
+    }
+
+    // Adds `pos` to its chain, returning the previous latest position there
+    #[inline]
+    fn insert(&mut self, input: &[u8], pos: usize) -> u32 {
+        if pos + MIN_MATCH_LEN > input.len() {
+            return NO_POS;
         }
-        let hash = calculate_hash(&input[current_pos..current_pos + MIN_MATCH_LEN]);
-        let mut best_match_len = 0;
-        let mut best_match_dist = 0;
-        let mut match_pos_opt = head[hash];
-        while let Some(match_pos) = match_pos_opt {
-            if match_pos < window_start {
+        let hash = self.hash(input, pos);
+        let previous = self.head[hash];
+        let slot = pos % self.prev.len();
+        self.prev[slot] = previous;
+        self.head[hash] = pos as u32;
+        previous
+    }
+
+    // Walks the chain from `candidate` (nearest first) for the longest match
+    // at `pos` that beats `prev_len`, as (length, distance)
+    fn longest_match(
+        &self,
+        input: &[u8],
+        pos: usize,
+        mut candidate: u32,
+        prev_len: usize,
+        config: &LevelConfig,
+    ) -> Option<(usize, usize)> {
+        let max_len = MAX_MATCH_LEN.min(input.len() - pos);
+        let nice_len = config.nice_length.min(max_len);
+        let mut best_len = prev_len.max(MIN_MATCH_LEN - 1);
+        let mut best = None;
+        let mut chain = if prev_len >= config.good_length {
+            config.max_chain / 4
+        } else {
+            config.max_chain
+        };
+
+        while candidate != NO_POS && chain > 0 && best_len < max_len {
+            let match_pos = candidate as usize;
+            // The slot of a position a whole window back has been reused
+            if pos - match_pos >= MAX_WINDOW_SIZE {
                 break;
             }
-            let current_match_len =
-                calculate_match_length(input, match_pos, current_pos, MAX_MATCH_LEN);
-            if current_match_len >= MIN_MATCH_LEN && current_match_len > best_match_len {
-                best_match_len = current_match_len;
-                best_match_dist = (current_pos - match_pos) as u16;
-                if best_match_len == MAX_MATCH_LEN {
-                    break;
+            // Cheap rejection: a longer match must also extend past `best_len`
+            if input[match_pos + best_len] == input[pos + best_len] {
+                let len = calculate_match_length(input, match_pos, pos, max_len);
+                if len > best_len {
+                    best_len = len;
+                    best = Some((len, pos - match_pos));
+                    if len >= nice_len {
+                        break;
+                    }
                 }
             }
-            match_pos_opt = prev[match_pos % MAX_WINDOW_SIZE];
-        }
-        prev[current_pos % MAX_WINDOW_SIZE] = head[hash];
-        head[hash] = Some(current_pos);
-        if best_match_len >= MIN_MATCH_LEN {
-            tokens.push(Lz77Token::Match(best_match_len as u16, best_match_dist));
-            // Lazy update hash table for skipped bytes
-            for i in 1..best_match_len {
-                let pos_to_update = current_pos + i;
-                if pos_to_update + MIN_MATCH_LEN <= input.len() {
-                    let next_hash =
-                        calculate_hash(&input[pos_to_update..pos_to_update + MIN_MATCH_LEN]);
-                    prev[pos_to_update % MAX_WINDOW_SIZE] = head[next_hash];
-                    head[next_hash] = Some(pos_to_update);
+            let next = self.prev[match_pos % self.prev.len()];
+            if next != NO_POS && next >= candidate {
+                break;
+            }
+            candidate = next;
+            chain -= 1;
+        }
+        best.filter(|&(len, distance)| len > MIN_MATCH_LEN || distance <= TOO_FAR)
+    }
+}
+
+// Splits `input` into literals and back-references, searching as hard as `level` asks
+fn lz77_compress(
+    input: &[u8],
+    level: u8,
+    token: &CancelToken,
+) -> Result<Vec<Lz77Token>, TransformError> {
+    let config = LevelConfig::for_level(level);
+    let mut chains = HashChains::new(input.len());
+    if config.lazy {
+        lz77_lazy(input, &mut chains, config, token)
+    } else {
+        lz77_greedy(input, &mut chains, config, token)
+    }
+}
+
+// Takes the longest match at each position as soon as it is found
+fn lz77_greedy(
+    input: &[u8],
+    chains: &mut HashChains,
+    config: &LevelConfig,
+    token: &CancelToken,
+) -> Result<Vec<Lz77Token>, TransformError> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < input.len() {
+        token.check_every(tokens.len())?;
+        let candidate = chains.insert(input, pos);
+        match chains.longest_match(input, pos, candidate, 0, config) {
+            Some((len, distance)) => {
+                tokens.push(Lz77Token::Match(len as u16, distance as u16));
+                // Leaving long matches out of the chains is what keeps these levels fast
+                if len <= config.max_lazy {
+                    for inner in pos + 1..pos + len {
+                        chains.insert(input, inner);
+                    }
                 }
+                pos += len;
+            }
+            None => {
+                tokens.push(Lz77Token::Literal(input[pos]));
+                pos += 1;
             }
-            current_pos += best_match_len;
-        } else {
-            tokens.push(Lz77Token::Literal(input[current_pos]));
-            current_pos += 1;
         }
     }
-    tokens
+    Ok(tokens)
 }
 
-#[inline]
-fn calculate_hash(bytes: &[u8]) -> usize {
-    (((bytes[0] as usize) << 8) | ((bytes[1] as usize) << 4) | (bytes[2] as usize))
-        % HASH_TABLE_SIZE
+// Holds each match back one byte: if the next position starts a longer match,
+// the held byte goes out as a literal and the longer match is held instead
+fn lz77_lazy(
+    input: &[u8],
+    chains: &mut HashChains,
+    config: &LevelConfig,
+    token: &CancelToken,
+) -> Result<Vec<Lz77Token>, TransformError> {
+    let mut tokens = Vec::new();
+    // Match starting at `pos - 1` that hasn't been emitted yet
+    let mut held: Option<(usize, usize)> = None;
+    let mut pos = 0;
+    let mut iteration = 0;
+    while pos < input.len() {
+        token.check_every(iteration)?;
+        iteration += 1;
+        let candidate = chains.insert(input, pos);
+        let held_len = held.map_or(0, |(len, _)| len);
+        let found = if held_len < config.max_lazy {
+            chains.longest_match(input, pos, candidate, held_len, config)
+        } else {
+            None
+        };
+
+        match (held, found) {
+            (Some((len, distance)), None) => {
+                tokens.push(Lz77Token::Match(len as u16, distance as u16));
+                let end = pos - 1 + len;
+                for inner in pos + 1..end {
+                    chains.insert(input, inner);
+                }
+                held = None;
+                pos = end;
+            }
+            (Some(_), Some(longer)) => {
+                tokens.push(Lz77Token::Literal(input[pos - 1]));
+                held = Some(longer);
+                pos += 1;
+            }
+            (None, found) => {
+                if found.is_none() {
+                    tokens.push(Lz77Token::Literal(input[pos]));
+                }
+                held = found;
+                pos += 1;
+            }
+        }
+    }
+    if let Some((len, distance)) = held {
+        tokens.push(Lz77Token::Match(len as u16, distance as u16));
+    }
+    Ok(tokens)
 }
 
 #[inline]
 fn calculate_match_length(input: &[u8], pos1: usize, pos2: usize, max_len: usize) -> usize {
-    let mut len = 0;
-    let input_len = input.len();
-    while len < max_len && pos2 + len < input_len && input[pos1 + len] == input[pos2 + len] {
-        len += 1;
-    }
-    len
+    input[pos1..pos1 + max_len]
+        .iter()
+        .zip(&input[pos2..pos2 + max_len])
+        .take_while(|(a, b)| a == b)
+        .count()
 }
 
 // Extracted core DEFLATE compression logic (without Base64 encoding)
 pub(crate) fn deflate_bytes(input_bytes: &[u8]) -> Result<Vec<u8>, TransformError> {
-    let mut writer = BitWriter::new();
+    deflate_bytes_with_cancel(input_bytes, DEFAULT_LEVEL, &CancelToken::new())
+}
+
+// --- Huffman codes (RFC 1951 Section 3.2.2) ---
+const MAX_CODE_BITS: u8 = 15;
+const MAX_CODE_LENGTH_BITS: u8 = 7;
+const END_OF_BLOCK: usize = 256;
+// Tokens per block; each block gets codes fitted to its own symbol frequencies
+const BLOCK_TOKENS: usize = 1 << 14;
+
+/// Order in which a dynamic block header lists the code length code lengths
+pub(crate) const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
 
-    if input_bytes.is_empty() {
-        // Minimal fixed block for empty input.
-        writer.write_bits(1, 1); // BFINAL
-        writer.write_bits(1, 2); // BTYPE=01 (Fixed Huffman)
-        let (reversed_eob_huff, eob_bits) = get_fixed_literal_length_huffman_code(256); // EOB
-        writer.write_bits(reversed_eob_huff as u32, eob_bits);
-        return Ok(writer.get_bytes());
+// Huffman code lengths for symbols with the given frequencies, none longer
+// than `max_bits`. Unused symbols get length 0, except that at least two
+// symbols always get a code so that the code is complete.
+fn huffman_code_lengths(freqs: &[u32], max_bits: u8) -> Vec<u8> {
+    let mut freqs = freqs.to_vec();
+    let mut used = freqs.iter().filter(|&&freq| freq > 0).count();
+    for freq in freqs.iter_mut() {
+        if used >= 2 {
+            break;
+        }
+        if *freq == 0 {
+            *freq = 1;
+            used += 1;
+        }
+    }
+    // Flattening the frequencies shortens the longest codes; all-equal
+    // frequencies always fit, as 2^max_bits exceeds the alphabet size
+    loop {
+        let lengths = unlimited_code_lengths(&freqs);
+        if lengths.iter().all(|&length| length <= max_bits) {
+            return lengths;
+        }
+        for freq in freqs.iter_mut().filter(|freq| **freq > 0) {
+            *freq = (*freq >> 1).max(1);
+        }
     }
+}
+
+// Plain Huffman code lengths: the depth of each symbol in the Huffman tree
+fn unlimited_code_lengths(freqs: &[u32]) -> Vec<u8> {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
 
-    let lz77_tokens = lz77_compress(input_bytes);
+    let mut lengths = vec![0u8; freqs.len()];
+    let mut parents: Vec<Option<usize>> = Vec::new();
+    let mut leaves = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for (symbol, &freq) in freqs.iter().enumerate().filter(|(_, &freq)| freq > 0) {
+        leaves.push((parents.len(), symbol));
+        heap.push(Reverse((freq as u64, parents.len())));
+        parents.push(None);
+    }
+    while let (Some(Reverse((freq_a, a))), Some(Reverse((freq_b, b)))) = (heap.pop(), heap.pop()) {
+        let node = parents.len();
+        parents.push(None);
+        parents[a] = Some(node);
+        parents[b] = Some(node);
+        heap.push(Reverse((freq_a + freq_b, node)));
+    }
+    for (mut node, symbol) in leaves {
+        let mut depth = 0usize;
+        while let Some(parent) = parents[node] {
+            node = parent;
+            depth += 1;
+        }
+        lengths[symbol] = depth.min(u8::MAX as usize) as u8;
+    }
+    lengths
+}
 
-    // Estimate size to choose between fixed Huffman and uncompressed block.
-    let mut estimated_bits = 0;
-    for token in &lz77_tokens {
-        match token {
-            Lz77Token::Literal(byte) => {
-                let (_, bits) = get_fixed_literal_length_huffman_code(*byte as u16);
-                estimated_bits += bits as usize;
+// Canonical codes for the given code lengths, bit-reversed for LSB-first output
+fn canonical_codes(lengths: &[u8]) -> Vec<u16> {
+    let mut length_counts = [0u16; MAX_CODE_BITS as usize + 1];
+    for &length in lengths.iter().filter(|&&length| length > 0) {
+        length_counts[length as usize] += 1;
+    }
+    let mut next_code = [0u16; MAX_CODE_BITS as usize + 1];
+    let mut code = 0u16;
+    for bits in 1..=MAX_CODE_BITS as usize {
+        code = (code + length_counts[bits - 1]) << 1;
+        next_code[bits] = code;
+    }
+    lengths
+        .iter()
+        .map(|&length| {
+            if length == 0 {
+                return 0;
             }
-            Lz77Token::Match(length, distance) => {
-                let (len_code, _, len_extra_bits) = get_length_code(*length);
-                let (_, len_huff_bits) = get_fixed_literal_length_huffman_code(len_code);
-                estimated_bits += len_huff_bits as usize + len_extra_bits as usize;
-
-                let (dist_code, _, dist_extra_bits) = get_distance_code(*distance);
-                let (_, dist_huff_bits) = get_fixed_distance_huffman_code(dist_code);
-                estimated_bits += dist_huff_bits as usize + dist_extra_bits as usize;
+            let code = next_code[length as usize];
+            next_code[length as usize] += 1;
+            reverse_bits(code, length)
+        })
+        .collect()
+}
+
+// A Huffman symbol with the value and number of its extra bits
+type CodedSymbol = (u16, u32, u8);
+
+// The literal/length symbol of a token, and its distance symbol for matches
+fn token_symbols(token: &Lz77Token) -> (CodedSymbol, Option<CodedSymbol>) {
+    match *token {
+        Lz77Token::Literal(byte) => ((byte as u16, 0, 0), None),
+        Lz77Token::Match(length, distance) => {
+            (get_length_code(length), Some(get_distance_code(distance)))
+        }
+    }
+}
+
+// The literal/length and distance codes of one compressed block
+struct BlockCodes {
+    litlen_lengths: Vec<u8>,
+    litlen_codes: Vec<u16>,
+    dist_lengths: Vec<u8>,
+    dist_codes: Vec<u16>,
+}
+
+impl BlockCodes {
+    fn from_lengths(litlen_lengths: Vec<u8>, dist_lengths: Vec<u8>) -> Self {
+        BlockCodes {
+            litlen_codes: canonical_codes(&litlen_lengths),
+            dist_codes: canonical_codes(&dist_lengths),
+            litlen_lengths,
+            dist_lengths,
+        }
+    }
+
+    // The fixed codes of RFC 1951 Section 3.2.6
+    fn fixed() -> Self {
+        let litlen_lengths = (0..288)
+            .map(|symbol| match symbol {
+                0..=143 => 8,
+                144..=255 => 9,
+                256..=279 => 7,
+                _ => 8,
+            })
+            .collect();
+        Self::from_lengths(litlen_lengths, vec![5; 30])
+    }
+
+    // Codes fitted to the symbol frequencies of `tokens`
+    fn dynamic(tokens: &[Lz77Token]) -> Self {
+        let mut litlen_freqs = [0u32; 286];
+        let mut dist_freqs = [0u32; 30];
+        litlen_freqs[END_OF_BLOCK] = 1;
+        for token in tokens {
+            let ((litlen, _, _), dist) = token_symbols(token);
+            litlen_freqs[litlen as usize] += 1;
+            if let Some((dist, _, _)) = dist {
+                dist_freqs[dist as usize] += 1;
+            }
+        }
+        Self::from_lengths(
+            huffman_code_lengths(&litlen_freqs, MAX_CODE_BITS),
+            huffman_code_lengths(&dist_freqs, MAX_CODE_BITS),
+        )
+    }
+
+    // Size of `tokens` and the end-of-block code in these codes
+    fn data_bits(&self, tokens: &[Lz77Token]) -> usize {
+        let mut bits = self.litlen_lengths[END_OF_BLOCK] as usize;
+        for token in tokens {
+            let ((litlen, _, litlen_extra_bits), dist) = token_symbols(token);
+            bits += (self.litlen_lengths[litlen as usize] + litlen_extra_bits) as usize;
+            if let Some((dist, _, dist_extra_bits)) = dist {
+                bits += (self.dist_lengths[dist as usize] + dist_extra_bits) as usize;
+            }
+        }
+        bits
+    }
+
+    fn write_tokens(&self, writer: &mut BitWriter, tokens: &[Lz77Token]) {
+        for token in tokens {
+            let ((litlen, litlen_extra, litlen_extra_bits), dist) = token_symbols(token);
+            writer.write_bits(
+                self.litlen_codes[litlen as usize] as u32,
+                self.litlen_lengths[litlen as usize],
+            );
+            writer.write_bits(litlen_extra, litlen_extra_bits);
+            if let Some((dist, dist_extra, dist_extra_bits)) = dist {
+                writer.write_bits(
+                    self.dist_codes[dist as usize] as u32,
+                    self.dist_lengths[dist as usize],
+                );
+                writer.write_bits(dist_extra, dist_extra_bits);
             }
         }
+        writer.write_bits(
+            self.litlen_codes[END_OF_BLOCK] as u32,
+            self.litlen_lengths[END_OF_BLOCK],
+        );
     }
-    let (_, eob_bits) = get_fixed_literal_length_huffman_code(256); // EOB marker
-    estimated_bits += eob_bits as usize;
-    estimated_bits += 3; // BFINAL + BTYPE bits
+}
 
-    let uncompressed_size_bytes = input_bytes.len() + 5;
-    let uncompressed_size_bits = uncompressed_size_bytes * 8;
+// The header of a dynamic Huffman block: the code lengths of its codes,
+// run-length encoded and then Huffman coded themselves (RFC 1951 Section 3.2.7)
+struct DynamicHeader {
+    hlit: usize,
+    hdist: usize,
+    hclen: usize,
+    // Code length symbols (0-18) with the value of their extra bits
+    symbols: Vec<(u8, u8)>,
+    code_length_lengths: Vec<u8>,
+    code_length_codes: Vec<u16>,
+}
 
-    // --- Write DEFLATE Stream ---
-    writer.write_bits(1, 1); // BFINAL = 1
+impl DynamicHeader {
+    fn new(codes: &BlockCodes) -> Self {
+        // Trailing unused codes are left out, down to the minimum counts
+        let hlit = 257.max(
+            codes
+                .litlen_lengths
+                .iter()
+                .rposition(|&l| l > 0)
+                .unwrap_or(0)
+                + 1,
+        );
+        let hdist = 1.max(codes.dist_lengths.iter().rposition(|&l| l > 0).unwrap_or(0) + 1);
+        let lengths: Vec<u8> = codes.litlen_lengths[..hlit]
+            .iter()
+            .chain(&codes.dist_lengths[..hdist])
+            .copied()
+            .collect();
+        let symbols = run_length_encode(&lengths);
 
-    if estimated_bits >= uncompressed_size_bits {
-        // Write uncompressed block (BTYPE=00).
-        writer.write_bits(0, 2); // BTYPE=00
-        writer.align_to_byte();
-        let len: u16 = input_bytes.len().try_into().map_err(|_| {
-            TransformError::CompressionError(
-                "Input too large for uncompressed block length (max 65535)".into(),
-            )
-        })?;
-        let nlen = !len;
-        writer.write_bytes_raw(&len.to_le_bytes());
-        writer.write_bytes_raw(&nlen.to_le_bytes());
-        writer.write_bytes_raw(input_bytes);
+        let mut freqs = [0u32; 19];
+        for &(symbol, _) in &symbols {
+            freqs[symbol as usize] += 1;
+        }
+        let code_length_lengths = huffman_code_lengths(&freqs, MAX_CODE_LENGTH_BITS);
+        let hclen = 4.max(
+            CODE_LENGTH_ORDER
+                .iter()
+                .rposition(|&symbol| code_length_lengths[symbol] > 0)
+                .unwrap_or(0)
+                + 1,
+        );
+        DynamicHeader {
+            hlit,
+            hdist,
+            hclen,
+            symbols,
+            code_length_codes: canonical_codes(&code_length_lengths),
+            code_length_lengths,
+        }
+    }
+
+    fn bits(&self) -> usize {
+        let mut bits = 5 + 5 + 4 + 3 * self.hclen;
+        for &(symbol, _) in &self.symbols {
+            bits += (self.code_length_lengths[symbol as usize] + code_length_extra_bits(symbol))
+                as usize;
+        }
+        bits
+    }
+
+    fn write(&self, writer: &mut BitWriter) {
+        writer.write_bits((self.hlit - 257) as u32, 5);
+        writer.write_bits((self.hdist - 1) as u32, 5);
+        writer.write_bits((self.hclen - 4) as u32, 4);
+        for &symbol in &CODE_LENGTH_ORDER[..self.hclen] {
+            writer.write_bits(self.code_length_lengths[symbol] as u32, 3);
+        }
+        for &(symbol, extra) in &self.symbols {
+            writer.write_bits(
+                self.code_length_codes[symbol as usize] as u32,
+                self.code_length_lengths[symbol as usize],
+            );
+            writer.write_bits(extra as u32, code_length_extra_bits(symbol));
+        }
+    }
+}
+
+fn code_length_extra_bits(symbol: u8) -> u8 {
+    match symbol {
+        16 => 2,
+        17 => 3,
+        18 => 7,
+        _ => 0,
+    }
+}
+
+// Encodes code lengths with the repeat symbols 16 (previous length 3-6
+// times), 17 (3-10 zeros) and 18 (11-138 zeros)
+fn run_length_encode(lengths: &[u8]) -> Vec<(u8, u8)> {
+    let mut symbols = Vec::new();
+    let mut i = 0;
+    while i < lengths.len() {
+        let length = lengths[i];
+        let run = lengths[i..].iter().take_while(|&&l| l == length).count();
+        let mut left = run;
+        if length == 0 {
+            while left >= 11 {
+                let repeat = left.min(138);
+                symbols.push((18, (repeat - 11) as u8));
+                left -= repeat;
+            }
+            if left >= 3 {
+                symbols.push((17, (left - 3) as u8));
+                left = 0;
+            }
+        } else {
+            symbols.push((length, 0));
+            left -= 1;
+            while left >= 3 {
+                let repeat = left.min(6);
+                symbols.push((16, (repeat - 3) as u8));
+                left -= repeat;
+            }
+        }
+        symbols.extend(std::iter::repeat_n((length, 0), left));
+        i += run;
+    }
+    symbols
+}
+
+// DEFLATE compression at `level` that periodically checks `token` during LZ77 matching
+pub(crate) fn deflate_bytes_with_cancel(
+    input_bytes: &[u8],
+    level: u8,
+    token: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    let mut writer = BitWriter::new();
+    let lz77_tokens = lz77_compress(input_bytes, level, token)?;
+    let fixed_codes = BlockCodes::fixed();
+
+    // Empty input still needs one (empty) final block
+    let blocks: Vec<&[Lz77Token]> = if lz77_tokens.is_empty() {
+        vec![&[]]
     } else {
-        // Write fixed Huffman block (BTYPE=01).
-        writer.write_bits(1, 2); // BTYPE=01
-        for token in lz77_tokens {
-            match token {
-                Lz77Token::Match(length, distance) => {
-                    let (len_code, len_extra_val, len_extra_bits) = get_length_code(length);
-                    let (reversed_len_huff, len_huff_bits) =
-                        get_fixed_literal_length_huffman_code(len_code);
-                    writer.write_bits(reversed_len_huff as u32, len_huff_bits);
-                    if len_extra_bits > 0 {
-                        writer.write_bits(len_extra_val, len_extra_bits);
-                    }
+        lz77_tokens.chunks(BLOCK_TOKENS).collect()
+    };
+    let mut block_start = 0;
+    for (i, block) in blocks.iter().enumerate() {
+        let is_final = (i + 1 == blocks.len()) as u32;
+        let block_len: usize = block
+            .iter()
+            .map(|token| match token {
+                Lz77Token::Literal(_) => 1,
+                Lz77Token::Match(length, _) => *length as usize,
+            })
+            .sum();
+        let block_bytes = &input_bytes[block_start..block_start + block_len];
+        block_start += block_len;
 
-                    let (dist_code, dist_extra_val, dist_extra_bits) = get_distance_code(distance);
-                    let (reversed_dist_huff, dist_huff_bits) =
-                        get_fixed_distance_huffman_code(dist_code);
-                    writer.write_bits(reversed_dist_huff as u32, dist_huff_bits);
-                    if dist_extra_bits > 0 {
-                        writer.write_bits(dist_extra_val, dist_extra_bits);
-                    }
-                }
-                Lz77Token::Literal(byte) => {
-                    let (reversed_huff, huff_bits) =
-                        get_fixed_literal_length_huffman_code(byte as u16);
-                    writer.write_bits(reversed_huff as u32, huff_bits);
-                }
+        // Each block is written whichever way is smallest. Stored blocks hold
+        // at most 65535 bytes, each with a 5 byte header.
+        let stored_block_count = block_len.div_ceil(MAX_STORED_BLOCK_LEN).max(1);
+        let stored_bits = (block_len + 5 * stored_block_count) * 8;
+        let fixed_bits = 3 + fixed_codes.data_bits(block);
+        let dynamic_codes = BlockCodes::dynamic(block);
+        let dynamic_header = DynamicHeader::new(&dynamic_codes);
+        let dynamic_bits = 3 + dynamic_header.bits() + dynamic_codes.data_bits(block);
+
+        if stored_bits <= fixed_bits.min(dynamic_bits) {
+            // Write uncompressed blocks (BTYPE=00).
+            let chunks: Vec<&[u8]> = if block_bytes.is_empty() {
+                vec![&[]]
+            } else {
+                block_bytes.chunks(MAX_STORED_BLOCK_LEN).collect()
+            };
+            for (j, chunk) in chunks.iter().enumerate() {
+                let is_last_chunk = (j + 1 == chunks.len()) as u32;
+                writer.write_bits(is_final & is_last_chunk, 1); // BFINAL
+                writer.write_bits(0, 2); // BTYPE=00
+                writer.align_to_byte();
+                let len = chunk.len() as u16;
+                let nlen = !len;
+                writer.write_bytes_raw(&len.to_le_bytes());
+                writer.write_bytes_raw(&nlen.to_le_bytes());
+                writer.write_bytes_raw(chunk);
             }
+        } else if dynamic_bits < fixed_bits {
+            // Write dynamic Huffman block (BTYPE=10).
+            writer.write_bits(is_final, 1);
+            writer.write_bits(0b10, 2);
+            dynamic_header.write(&mut writer);
+            dynamic_codes.write_tokens(&mut writer, block);
+        } else {
+            // Write fixed Huffman block (BTYPE=01).
+            writer.write_bits(is_final, 1);
+            writer.write_bits(0b01, 2);
+            fixed_codes.write_tokens(&mut writer, block);
         }
-        // EOB marker.
-        let (reversed_eob_huff, eob_bits) = get_fixed_literal_length_huffman_code(256);
-        writer.write_bits(reversed_eob_huff as u32, eob_bits);
     }
 
     Ok(writer.get_bytes())
@@ -448,9 +952,55 @@ impl Transform for DeflateCompress {
         Ok(base64_encode::base64_encode(&compressed_data))
     }
 
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let compressed_data = deflate_bytes_with_cancel(input.as_bytes(), DEFAULT_LEVEL, token)?;
+        Ok(base64_encode::base64_encode(&compressed_data))
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        deflate_bytes(input)
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[LEVEL_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let compressed_data = self.transform_bytes_configured(input.as_bytes(), options, token)?;
+        Ok(base64_encode::base64_encode(&compressed_data))
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let level = level_value(options)?;
+        deflate_bytes_with_cancel(input, level, token)
+    }
+
     fn default_test_input(&self) -> &'static str {
         "Hello, Deflate World!"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Repetitive text",
+                input: "Buup transforms text. Buup transforms text. Buup transforms text, again and again and again.",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -504,7 +1054,7 @@ mod tests {
         let transformer = DeflateCompress;
         let input =
             "This is a slightly longer test string to see how DEFLATE compression handles it.";
-        let expected_base64 = "C8nILFYAokSF4pzM9IySnEqFnPy89NQihZLU4hKF4pKizLx0hZJ8heLUVIWM/HIFF1c3H8cQV4Xk/NyCotTi4sz8PIWMxLyUnFSgOSV6AA==";
+        let expected_base64 = "DczBCYAwDAXQVf4E7iBYTx67QNHQBGIiTUDc3sI7v8oSmBpCpXPqB3XrNJAUicgh1pGOIAL7i63sx1oLTr+fQRHiBm52Kc0nlx8=";
         match transformer.transform(input) {
             Ok(actual_base64) => {
                 assert_eq!(actual_base64, expected_base64);
@@ -514,4 +1064,152 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_deflate_dynamic_round_trip() {
+        let input = "The quick brown fox jumps over the lazy dog. ".repeat(40)
+            + "Pack my box with five dozen liquor jugs.";
+        let compressed = deflate_bytes(input.as_bytes()).unwrap();
+        assert_eq!(
+            (compressed[0] >> 1) & 0b11,
+            0b10,
+            "expected a dynamic block"
+        );
+        let decompressed = DeflateDecompress.transform_bytes(&compressed).unwrap();
+        assert_eq!(decompressed, input.as_bytes());
+    }
+
+    #[test]
+    fn test_huffman_code_lengths() {
+        // Skewed frequencies that would need codes longer than 7 bits
+        let freqs: Vec<u32> = (0..19).map(|i| 1 << i).collect();
+        let lengths = huffman_code_lengths(&freqs, MAX_CODE_LENGTH_BITS);
+        assert!(lengths
+            .iter()
+            .all(|&l| (1..=MAX_CODE_LENGTH_BITS).contains(&l)));
+        // Kraft sum of exactly 1: the code is complete
+        let kraft: f64 = lengths.iter().map(|&l| 0.5f64.powi(l as i32)).sum();
+        assert_eq!(kraft, 1.0);
+
+        // A lone symbol still gets a partner so the code is complete
+        assert_eq!(huffman_code_lengths(&[0, 5, 0], MAX_CODE_BITS), [1, 1, 0]);
+    }
+
+    #[test]
+    fn test_run_length_encode() {
+        let mut lengths = vec![8; 10];
+        lengths.extend([0; 150]);
+        lengths.extend([5, 0, 0]);
+        assert_eq!(
+            run_length_encode(&lengths),
+            [
+                (8, 0),
+                (16, 3),
+                (16, 0),
+                (18, 127),
+                (18, 1),
+                (5, 0),
+                (0, 0),
+                (0, 0),
+            ]
+        );
+    }
+
+    // Rebuilds the input from LZ77 tokens
+    fn expand(tokens: &[Lz77Token]) -> Vec<u8> {
+        let mut output = Vec::new();
+        for token in tokens {
+            match *token {
+                Lz77Token::Literal(byte) => output.push(byte),
+                Lz77Token::Match(length, distance) => {
+                    assert!((MIN_MATCH_LEN..=MAX_MATCH_LEN).contains(&(length as usize)));
+                    assert!((1..=MAX_WINDOW_SIZE).contains(&(distance as usize)));
+                    for _ in 0..length {
+                        output.push(output[output.len() - distance as usize]);
+                    }
+                }
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn test_lz77_levels_round_trip() {
+        // Small alphabets produce plenty of matches, ties and hash collisions
+        let mut seed = 0x2545_f491_u32;
+        for len in [1, 2, 3, 4, 17, 100, 256, 513, 40_000] {
+            for alphabet in [2u32, 4, 26] {
+                let input: Vec<u8> = (0..len)
+                    .map(|_| {
+                        seed ^= seed << 13;
+                        seed ^= seed >> 17;
+                        seed ^= seed << 5;
+                        b'a' + (seed % alphabet) as u8
+                    })
+                    .collect();
+                for level in MIN_LEVEL..=MAX_LEVEL {
+                    let tokens = lz77_compress(&input, level, &CancelToken::new()).unwrap();
+                    assert_eq!(
+                        expand(&tokens),
+                        input,
+                        "level {} broke {} bytes over {} symbols",
+                        level,
+                        len,
+                        alphabet
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deflate_levels() {
+        let input = "The quick brown fox jumps over the lazy dog. ".repeat(50)
+            + &"Pack my box with five dozen liquor jugs! ".repeat(50);
+        let mut sizes = Vec::new();
+        for level in MIN_LEVEL..=MAX_LEVEL {
+            let mut options = TransformOptions::new();
+            options.set("level", level.to_string());
+            let compressed = DeflateCompress
+                .transform_with_options(&input, &options)
+                .unwrap();
+            assert_eq!(DeflateDecompress.transform(&compressed).unwrap(), input);
+            sizes.push(compressed.len());
+        }
+        assert!(sizes[MAX_LEVEL as usize - 1] <= sizes[0]);
+
+        // The default level matches the option default
+        assert_eq!(
+            DeflateCompress
+                .transform_with_options(&input, &TransformOptions::new())
+                .unwrap(),
+            DeflateCompress.transform(&input).unwrap()
+        );
+
+        for level in ["0", "10"] {
+            let mut options = TransformOptions::new();
+            options.set("level", level);
+            assert!(matches!(
+                DeflateCompress.transform_with_options(&input, &options),
+                Err(TransformError::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_deflate_cancelled() {
+        let token = CancelToken::new();
+        token.cancel();
+        let input = "abc".repeat(10_000);
+        assert_eq!(
+            DeflateCompress.transform_with_cancel(&input, &token),
+            Err(TransformError::Cancelled)
+        );
+        assert_eq!(
+            DeflateCompress
+                .transform_with_cancel(&input, &CancelToken::new())
+                .unwrap(),
+            DeflateCompress.transform(&input).unwrap()
+        );
+    }
 }
@@ -1,11 +1,23 @@
 use super::base64_decode;
 use super::deflate_compress;
-use crate::{Transform, TransformError, TransformerCategory};
-use std::collections::HashMap;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+use std::sync::OnceLock;
+
+/// Option limiting the decompressed output size, shared by the decompressors.
+/// Defaults to 256 MiB so tiny malicious inputs can't expand into gigabytes.
+pub(crate) const MAX_OUTPUT_OPTION: TransformOption = TransformOption {
+    key: "max_output",
+    description: "Maximum decompressed size in bytes",
+    default: "268435456",
+    kind: TransformOptionKind::Integer,
+};
 
 /// Decompresses DEFLATE compressed input (RFC 1951).
-/// Supports Base64 encoded input containing uncompressed (BTYPE=00)
-/// and fixed Huffman (BTYPE=01) blocks.
+/// Supports Base64 encoded input containing uncompressed (BTYPE=00),
+/// fixed Huffman (BTYPE=01) and dynamic Huffman (BTYPE=10) blocks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeflateDecompress;
 
@@ -72,6 +84,27 @@ impl<'a> BitReader<'a> {
         Ok(value)
     }
 
+    // Returns the next `num_bits` (up to 16) without consuming them. Bits past
+    // the end read as zeros, as they do for `read_bits`.
+    fn peek_bits(&self, num_bits: u8) -> u32 {
+        let window = (0..3).fold(0u32, |window, i| {
+            let byte = self.bytes.get(self.byte_index + i).copied().unwrap_or(0);
+            window | (byte as u32) << (8 * i)
+        });
+        (window >> self.bit_position) & ((1 << num_bits) - 1)
+    }
+
+    // Consumes `num_bits` previously returned by `peek_bits`, stopping at the end.
+    fn consume_bits(&mut self, num_bits: u8) {
+        let bit = self.bit_position as usize + num_bits as usize;
+        self.byte_index += bit / 8;
+        self.bit_position = (bit % 8) as u8;
+        if self.byte_index >= self.bytes.len() {
+            self.byte_index = self.bytes.len();
+            self.bit_position = 0;
+        }
+    }
+
     // Discards bits to align to the next byte boundary.
     fn align_to_byte(&mut self) {
         if self.bit_position > 0 {
@@ -86,139 +119,317 @@ impl<'a> BitReader<'a> {
     }
 }
 
-// --- Fixed Huffman Decode Tables ---
-const MAX_BITS_LITLEN: u8 = 9;
-const MAX_BITS_DIST: u8 = 5;
+// --- Huffman Decode Tables ---
+const MAX_CODE_BITS: usize = 15;
+// Bits resolved by one table lookup. Every fixed code fits, as do most codes
+// of dynamic blocks; longer codes fall back to a canonical bit-by-bit walk.
+const LOOKUP_BITS: u8 = 9;
 
-#[derive(Clone)]
-struct HuffmanCode {
+// Symbol for a run of bits, or length 0 if no code of up to `LOOKUP_BITS` bits starts them
+#[derive(Debug, Clone, Copy, Default)]
+struct LookupEntry {
     symbol: u16,
     length: u8,
 }
 
-// Fixed Huffman decoder using HashMap lookup.
-struct FixedHuffmanDecoder {
-    litlen_lookup: HashMap<u16, HuffmanCode>,
-    dist_lookup: HashMap<u16, HuffmanCode>,
+// Canonical Huffman code read from code lengths (RFC 1951 Section 3.2.2)
+struct HuffmanTable {
+    // Entry for every value of the next `LOOKUP_BITS` bits of the stream
+    lookup: Vec<LookupEntry>,
+    // Number of codes of each length
+    counts: [u16; MAX_CODE_BITS + 1],
+    // Symbols ordered by code length, then by symbol
+    symbols: Vec<u16>,
 }
 
-impl FixedHuffmanDecoder {
-    fn new() -> Self {
-        let (litlen_table, dist_table) = Self::build_fixed_tables();
-        FixedHuffmanDecoder {
-            litlen_lookup: litlen_table,
-            dist_lookup: dist_table,
+impl HuffmanTable {
+    // Symbols with length 0 don't occur. Incomplete codes are accepted (a
+    // single distance code is common); using a missing code fails to decode.
+    fn from_lengths(lengths: &[u8]) -> Result<Self, TransformError> {
+        let mut counts = [0u16; MAX_CODE_BITS + 1];
+        for &length in lengths {
+            counts[length as usize] += 1;
         }
-    }
+        counts[0] = 0;
 
-    /// Builds the lookup tables for Fixed Huffman codes as per RFC 1951 Sec 3.2.6
-    fn build_fixed_tables() -> (HashMap<u16, HuffmanCode>, HashMap<u16, HuffmanCode>) {
-        let mut litlen_lookup = HashMap::new();
-        let mut dist_lookup = HashMap::new();
+        let mut left = 1i32;
+        for &count in &counts[1..] {
+            left = (left << 1) - count as i32;
+            if left < 0 {
+                return Err(TransformError::CompressionError(
+                    "Over-subscribed Huffman code lengths".into(),
+                ));
+            }
+        }
 
-        // Literal/Length codes
-        for symbol in 0..=287u16 {
-            let (code, len) = match symbol {
-                0..=143 => (0x30 + symbol, 8),
-                144..=255 => (0x190 + (symbol - 144), 9),
-                256..=279 => (symbol - 256, 7),
-                280..=285 => (0xC0 + (symbol - 280), 8),
-                _ => (0, 0), // Unused symbols
-            };
-            if len > 0 {
-                let reversed_code = deflate_compress::reverse_bits(code, len);
-                litlen_lookup.insert(
-                    reversed_code,
-                    HuffmanCode {
-                        symbol,
-                        length: len,
-                    },
-                );
+        let mut offsets = [0u16; MAX_CODE_BITS + 1];
+        for length in 1..MAX_CODE_BITS {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols =
+            vec![0u16; offsets[MAX_CODE_BITS] as usize + counts[MAX_CODE_BITS] as usize];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length > 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
             }
         }
 
-        // Distance codes
-        for symbol in 0..=31u16 {
-            let code = symbol;
-            let len = 5;
-            let reversed_code = deflate_compress::reverse_bits(code, len);
-            dist_lookup.insert(
-                reversed_code,
-                HuffmanCode {
-                    symbol,
-                    length: len,
-                },
-            );
+        // Codes arrive LSB-first, so a short code fills every table slot whose
+        // low bits are the code reversed
+        let mut lookup = vec![LookupEntry::default(); 1 << LOOKUP_BITS];
+        let mut code = 0u16;
+        let mut index = 0;
+        for length in 1..=LOOKUP_BITS {
+            for &symbol in &symbols[index..index + counts[length as usize] as usize] {
+                let reversed = deflate_compress::reverse_bits(code, length) as usize;
+                for slot in (reversed..lookup.len()).step_by(1 << length) {
+                    lookup[slot] = LookupEntry { symbol, length };
+                }
+                code += 1;
+            }
+            index += counts[length as usize] as usize;
+            code <<= 1;
         }
 
-        (litlen_lookup, dist_lookup)
+        Ok(HuffmanTable {
+            lookup,
+            counts,
+            symbols,
+        })
     }
 
-    // Decodes the next literal/length symbol using bit-by-bit lookup.
-    fn decode_literal_length(&self, reader: &mut BitReader) -> Result<u16, TransformError> {
-        let mut current_bits = 0u16;
-        let mut len = 0u8;
-        loop {
-            let bit = reader.read_bits(1)? as u16;
-            current_bits |= bit << len;
-            len += 1;
-            if let Some(code) = self.litlen_lookup.get(&current_bits) {
-                if code.length == len {
-                    return Ok(code.symbol);
-                }
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, TransformError> {
+        let entry = self.lookup[reader.peek_bits(LOOKUP_BITS) as usize];
+        if entry.length > 0 {
+            reader.consume_bits(entry.length);
+            return Ok(entry.symbol);
+        }
+
+        // `code` is the code read so far, `first` the first code of that length
+        // and `index` the position of that first code in `symbols`
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for &count in &self.counts[1..] {
+            code |= reader.read_bits(1)? as i32;
+            let count = count as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(TransformError::CompressionError(
+            "Invalid Huffman code in compressed block".into(),
+        ))
+    }
+}
+
+// Literal/length and distance codes of a compressed block
+struct HuffmanDecoder {
+    litlen: HuffmanTable,
+    dist: HuffmanTable,
+}
+
+impl HuffmanDecoder {
+    // The fixed codes of RFC 1951 Section 3.2.6. They never change, so they
+    // are built once and reused; rebuilding them dominated the cost of
+    // decoding small inputs.
+    fn fixed() -> &'static Self {
+        static DECODER: OnceLock<HuffmanDecoder> = OnceLock::new();
+        DECODER.get_or_init(|| {
+            let litlen_lengths: Vec<u8> = (0..288)
+                .map(|symbol| match symbol {
+                    0..=143 => 8,
+                    144..=255 => 9,
+                    256..=279 => 7,
+                    _ => 8,
+                })
+                .collect();
+            HuffmanDecoder {
+                litlen: HuffmanTable::from_lengths(&litlen_lengths)
+                    .expect("fixed literal/length code is complete"),
+                dist: HuffmanTable::from_lengths(&[5; 32])
+                    .expect("fixed distance code is complete"),
             }
-            if len > MAX_BITS_LITLEN {
-                return Err(TransformError::CompressionError(format!(
-                    "Invalid Huffman code found (litlen prefix: {:b}, len: {})",
-                    current_bits, len
-                )));
+        })
+    }
+
+    // Reads a dynamic block's code length code, then the literal/length and
+    // distance code lengths it encodes (RFC 1951 Section 3.2.7)
+    fn read_dynamic(reader: &mut BitReader) -> Result<Self, TransformError> {
+        let hlit = reader.read_bits(5)? as usize + 257;
+        let hdist = reader.read_bits(5)? as usize + 1;
+        let hclen = reader.read_bits(4)? as usize + 4;
+        if hlit > 286 || hdist > 30 {
+            return Err(TransformError::CompressionError(format!(
+                "Too many codes in dynamic block header ({} literal/length, {} distance)",
+                hlit, hdist
+            )));
+        }
+
+        let mut code_length_lengths = [0u8; 19];
+        for &symbol in &deflate_compress::CODE_LENGTH_ORDER[..hclen] {
+            code_length_lengths[symbol] = reader.read_bits(3)? as u8;
+        }
+        let code_length_table = HuffmanTable::from_lengths(&code_length_lengths)?;
+
+        let mut lengths: Vec<u8> = Vec::with_capacity(hlit + hdist);
+        while lengths.len() < hlit + hdist {
+            let (length, repeat) = match code_length_table.decode(reader)? {
+                symbol @ 0..=15 => (symbol as u8, 1),
+                16 => {
+                    let previous = *lengths.last().ok_or_else(|| {
+                        TransformError::CompressionError(
+                            "Repeated code length with no previous length".into(),
+                        )
+                    })?;
+                    (previous, 3 + reader.read_bits(2)? as usize)
+                }
+                17 => (0, 3 + reader.read_bits(3)? as usize),
+                18 => (0, 11 + reader.read_bits(7)? as usize),
+                symbol => {
+                    return Err(TransformError::CompressionError(format!(
+                        "Invalid code length symbol {}",
+                        symbol
+                    )))
+                }
+            };
+            if lengths.len() + repeat > hlit + hdist {
+                return Err(TransformError::CompressionError(
+                    "Code lengths run past the end of the dynamic block header".into(),
+                ));
             }
+            lengths.extend(std::iter::repeat_n(length, repeat));
+        }
+        if lengths[256] == 0 {
+            return Err(TransformError::CompressionError(
+                "Dynamic block has no end-of-block code".into(),
+            ));
+        }
+
+        Ok(HuffmanDecoder {
+            litlen: HuffmanTable::from_lengths(&lengths[..hlit])?,
+            dist: HuffmanTable::from_lengths(&lengths[hlit..])?,
+        })
+    }
+
+    fn decode_literal_length(&self, reader: &mut BitReader) -> Result<u16, TransformError> {
+        match self.litlen.decode(reader)? {
+            symbol @ 0..=285 => Ok(symbol),
+            symbol => Err(TransformError::CompressionError(format!(
+                "Invalid literal/length symbol {} decoded",
+                symbol
+            ))),
         }
     }
 
-    // Decodes the next distance symbol using bit-by-bit lookup.
     fn decode_distance(&self, reader: &mut BitReader) -> Result<u16, TransformError> {
-        let mut current_bits = 0u16;
-        let mut len = 0u8;
-        loop {
-            let bit = reader.read_bits(1)? as u16;
-            current_bits |= bit << len;
-            len += 1;
-            if let Some(code) = self.dist_lookup.get(&current_bits) {
-                if code.length == len {
-                    if code.symbol <= 29 {
-                        // Check valid distance symbol range
-                        return Ok(code.symbol);
-                    } else {
-                        return Err(TransformError::CompressionError(format!(
-                            "Invalid distance symbol {} decoded",
-                            code.symbol
-                        )));
+        match self.dist.decode(reader)? {
+            symbol @ 0..=29 => Ok(symbol),
+            symbol => Err(TransformError::CompressionError(format!(
+                "Invalid distance symbol {} decoded",
+                symbol
+            ))),
+        }
+    }
+}
+
+// Decodes the symbols of one Huffman block up to its end-of-block marker
+fn inflate_block(
+    reader: &mut BitReader,
+    decoder: &HuffmanDecoder,
+    output: &mut Vec<u8>,
+    max_output: usize,
+    token: &CancelToken,
+) -> Result<(), TransformError> {
+    let mut symbols = 0;
+    loop {
+        token.check_every(symbols)?;
+        symbols += 1;
+        let lit_len_code = decoder.decode_literal_length(reader)?;
+        match lit_len_code {
+            0..=255 => {
+                check_output_limit(output.len(), 1, max_output)?;
+                output.push(lit_len_code as u8);
+            }
+            256 => {
+                return Ok(()); // EOB marker
+            }
+            257..=285 => {
+                // Length/Distance pair
+                let (len_base, len_extra_bits) = deflate_compress::get_length_info(lit_len_code);
+                let len_extra_val = if len_extra_bits > 0 {
+                    reader.read_bits(len_extra_bits)?
+                } else {
+                    0
+                };
+                let length = len_base + len_extra_val as u16;
+
+                let dist_code = decoder.decode_distance(reader)?;
+                let (dist_base, dist_extra_bits) = deflate_compress::get_distance_info(dist_code);
+                let dist_extra_val = if dist_extra_bits > 0 {
+                    reader.read_bits(dist_extra_bits)?
+                } else {
+                    0
+                };
+                let distance = dist_base + dist_extra_val as u16;
+
+                let current_len = output.len();
+                if distance as usize > current_len {
+                    return Err(TransformError::CompressionError(format!(
+                        "Invalid back-reference distance {} > {}",
+                        distance, current_len
+                    )));
+                }
+                let start = current_len - distance as usize;
+                check_output_limit(current_len, length as usize, max_output)?;
+                if distance >= length {
+                    output.extend_from_within(start..start + length as usize);
+                } else {
+                    // Overlapping copy: the match repeats bytes it is still writing
+                    output.reserve(length as usize);
+                    for i in 0..length {
+                        let copied_byte = output[start + i as usize];
+                        output.push(copied_byte);
                     }
                 }
             }
-            if len > MAX_BITS_DIST {
-                return Err(TransformError::CompressionError(format!(
-                    "Invalid fixed Huffman distance code found (prefix: {:b}, len: {})",
-                    current_bits, len
-                )));
-            }
+            _ => unreachable!(),
         }
     }
 }
 
-// Decodes raw DEFLATE data (supports BTYPE 00 and 01)
+// Fails once the output would grow past `max_output` bytes
+pub(crate) fn check_output_limit(
+    current: usize,
+    additional: usize,
+    max_output: usize,
+) -> Result<(), TransformError> {
+    if current.saturating_add(additional) > max_output {
+        return Err(TransformError::CompressionError(format!(
+            "Decompressed output exceeds the limit of {} bytes (raise it with the max_output option)",
+            max_output
+        )));
+    }
+    Ok(())
+}
+
+// Decodes raw DEFLATE data (BTYPE 00, 01 and 10), producing at most `max_output` bytes
+// and polling `token` as it goes.
 // Returns the decompressed data and the number of bytes consumed from the input.
 pub(crate) fn deflate_decode_bytes(
     compressed_bytes: &[u8],
+    max_output: usize,
+    token: &CancelToken,
 ) -> Result<(Vec<u8>, usize), TransformError> {
     if compressed_bytes.is_empty() {
         return Ok((Vec::new(), 0)); // Return 0 consumed bytes
     }
 
     let mut reader = BitReader::new(compressed_bytes);
-    let mut output: Vec<u8> = Vec::with_capacity(compressed_bytes.len() * 3);
-    let fixed_decoder = FixedHuffmanDecoder::new();
+    let mut output: Vec<u8> =
+        Vec::with_capacity(compressed_bytes.len().saturating_mul(3).min(max_output));
 
     loop {
         let bfinal = reader.read_bits(1)?;
@@ -247,8 +458,10 @@ pub(crate) fn deflate_decode_bytes(
                         "Unexpected end of stream reading uncompressed data".into(),
                     ));
                 }
+                check_output_limit(output.len(), len_usize, max_output)?;
                 output.reserve(len_usize);
-                for _ in 0..len_usize {
+                for i in 0..len_usize {
+                    token.check_every(i)?;
                     if reader.bit_position != 0 {
                         return Err(TransformError::CompressionError(
                             "Misaligned stream reading uncompressed data byte".into(),
@@ -260,59 +473,18 @@ pub(crate) fn deflate_decode_bytes(
             }
             0b01 => {
                 // Handle fixed Huffman block
-                loop {
-                    let lit_len_code = fixed_decoder.decode_literal_length(&mut reader)?;
-                    match lit_len_code {
-                        0..=255 => {
-                            output.push(lit_len_code as u8);
-                        }
-                        256 => {
-                            break; // EOB marker
-                        }
-                        257..=285 => {
-                            // Length/Distance pair
-                            let (len_base, len_extra_bits) =
-                                deflate_compress::get_length_info(lit_len_code);
-                            let len_extra_val = if len_extra_bits > 0 {
-                                reader.read_bits(len_extra_bits)?
-                            } else {
-                                0
-                            };
-                            let length = len_base + len_extra_val as u16;
-
-                            let dist_code = fixed_decoder.decode_distance(&mut reader)?;
-                            let (dist_base, dist_extra_bits) =
-                                deflate_compress::get_distance_info(dist_code);
-                            let dist_extra_val = if dist_extra_bits > 0 {
-                                reader.read_bits(dist_extra_bits)?
-                            } else {
-                                0
-                            };
-                            let distance = dist_base + dist_extra_val as u16;
-
-                            let current_len = output.len();
-                            if distance as usize > current_len {
-                                return Err(TransformError::CompressionError(format!(
-                                    "Invalid back-reference distance {} > {}",
-                                    distance, current_len
-                                )));
-                            }
-                            let start = current_len - distance as usize;
-                            output.reserve(length as usize);
-                            for i in 0..length {
-                                let copied_byte = output[start + i as usize];
-                                output.push(copied_byte);
-                            }
-                        }
-                        _ => unreachable!(),
-                    }
-                }
+                inflate_block(
+                    &mut reader,
+                    HuffmanDecoder::fixed(),
+                    &mut output,
+                    max_output,
+                    token,
+                )?;
             }
             0b10 => {
-                // Dynamic Huffman Tables - Not Supported
-                return Err(TransformError::CompressionError(
-                    "Dynamic Huffman codes (BTYPE=10) are not supported".into(),
-                ));
+                // Handle dynamic Huffman block
+                let decoder = HuffmanDecoder::read_dynamic(&mut reader)?;
+                inflate_block(&mut reader, &decoder, &mut output, max_output, token)?;
             }
             _ => {
                 // Reserved BTYPE=11
@@ -354,23 +526,59 @@ impl Transform for DeflateDecompress {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[MAX_OUTPUT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
         let compressed_bytes = base64_decode::base64_decode(input).map_err(|e| {
             TransformError::InvalidArgument(format!("Invalid Base64 input: {}", e).into())
         })?;
-        // Call modified function, ignore consumed bytes count here
-        let (output, _consumed_bytes) = deflate_decode_bytes(&compressed_bytes)?;
+        let output = self.transform_bytes_configured(&compressed_bytes, options, token)?;
         String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
     }
 
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let max_output = options.usize_value(&MAX_OUTPUT_OPTION)?;
+        // Ignore the consumed bytes count here
+        let (output, _consumed_bytes) = deflate_decode_bytes(input, max_output, token)?;
+        Ok(output)
+    }
+
     fn default_test_input(&self) -> &'static str {
         "80jNycnXUSjPL8pJUQQA" // "Hello, world!" compressed
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Repetitive text",
+            input: "cyotLVAoKUrMK07LL8otVihJrSjRU3AiWlRHITE9MTNPITEvBZ2lBwA=",
+        }]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::transformers::base64_encode;
+    use crate::transformers::deflate_compress::DeflateCompress;
 
     #[test]
     fn test_decompress_uncompressed_block() {
@@ -414,4 +622,94 @@ mod tests {
         assert!(decompressed_hi_result.is_ok()); // Check if it decodes without error
                                                  // assert_eq!(decompressed_hi_result.unwrap(), "Hi"); // Commented out due to current decoding issue: left: "Hajm"
     }
+
+    #[test]
+    fn test_decompress_dynamic_block() {
+        // zlib at level 9 (raw DEFLATE), a single dynamic Huffman block
+        let input = "zY1LFoMgEASv0rmA58gyCy8AOiAJMpGvcHrn5eUQrqu6et4IR3HLBzpyCzB84l32bwJXisiCvRodK9sJ8z3klxJv79AiNZc3GFdJ0KAA747CUbY2TXhyQ6XTBev7P78qkzFIR5V+B48L";
+        assert_eq!(
+            DeflateDecompress.transform(input).unwrap(),
+            "The quick brown fox jumps over the lazy dog. ".repeat(4)
+                + "Pack my box with five dozen liquor jugs. How vexingly quick daft zebras jump!"
+        );
+    }
+
+    #[test]
+    fn test_decompress_invalid_dynamic_header() {
+        // BTYPE=10 with every code length code length zero
+        let input = base64_encode::base64_encode(&[0x05, 0x00, 0x00, 0x00]);
+        assert!(matches!(
+            DeflateDecompress.transform(&input),
+            Err(TransformError::CompressionError(_))
+        ));
+    }
+
+    #[test]
+    fn test_decompress_invalid_fixed_symbols() {
+        // Fixed codes exist for literal/length symbols 286-287 and distance
+        // symbols 30-31, but they never occur in valid data
+        for (bytes, symbol) in [
+            ([0x1b, 0x03], "literal/length symbol 286"),
+            ([0x03, 0x3e], "distance symbol 30"),
+        ] {
+            let input = base64_encode::base64_encode(&bytes);
+            assert!(matches!(
+                DeflateDecompress.transform(&input),
+                Err(TransformError::CompressionError(msg)) if msg.contains(symbol)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_decompress_long_codes() {
+        // Geometrically distributed bytes: the rarest get codes longer than
+        // one table lookup resolves
+        let mut state = 0x2545_f491_u32;
+        let input: Vec<u8> = (0..50_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                b'a' + state.trailing_zeros() as u8
+            })
+            .collect();
+        let compressed = DeflateCompress.transform_bytes(&input).unwrap();
+        assert_eq!(
+            DeflateDecompress.transform_bytes(&compressed).unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test_deflate_output_limit() {
+        let input = "a".repeat(100_000);
+        let compressed = DeflateCompress.transform(&input).unwrap();
+
+        let mut options = TransformOptions::new();
+        options.set("max_output", "1000");
+        assert!(matches!(
+            DeflateDecompress.transform_with_options(&compressed, &options),
+            Err(TransformError::CompressionError(msg)) if msg.contains("limit of 1000 bytes")
+        ));
+
+        options.set("max_output", "100000");
+        assert_eq!(
+            DeflateDecompress
+                .transform_with_options(&compressed, &options)
+                .unwrap(),
+            input
+        );
+        assert_eq!(DeflateDecompress.transform(&compressed).unwrap(), input);
+    }
+
+    #[test]
+    fn test_deflate_decode_cancelled() {
+        let compressed = deflate_compress::deflate_bytes(&[b'a'; 100_000]).unwrap();
+        let token = CancelToken::new();
+        token.cancel();
+        assert_eq!(
+            deflate_decode_bytes(&compressed, usize::MAX, &token),
+            Err(TransformError::Cancelled)
+        );
+    }
 }
@@ -0,0 +1,392 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const TO_OPTION: TransformOption = TransformOption {
+    key: "to",
+    description: "Form to write durations in",
+    default: "human",
+    kind: TransformOptionKind::Choice(&["human", "seconds", "milliseconds", "iso"]),
+};
+
+const UNIT_OPTION: TransformOption = TransformOption {
+    key: "unit",
+    description: "Unit of plain numbers in the input",
+    default: "seconds",
+    kind: TransformOptionKind::Choice(&["seconds", "milliseconds"]),
+};
+
+const MILLIS_PER_SECOND: i128 = 1000;
+const MILLIS_PER_MINUTE: i128 = 60 * MILLIS_PER_SECOND;
+const MILLIS_PER_HOUR: i128 = 60 * MILLIS_PER_MINUTE;
+const MILLIS_PER_DAY: i128 = 24 * MILLIS_PER_HOUR;
+const MILLIS_PER_WEEK: i128 = 7 * MILLIS_PER_DAY;
+
+/// Longest duration accepted, about 30 million years
+const MAX_MILLIS: i128 = 1_000_000_000_000_000_000;
+
+/// Unit names for human durations, with their length in milliseconds
+const UNITS: [(&[&str], i128); 6] = [
+    (&["ms", "msec", "msecs", "millisecond", "milliseconds"], 1),
+    (
+        &["s", "sec", "secs", "second", "seconds"],
+        MILLIS_PER_SECOND,
+    ),
+    (
+        &["m", "min", "mins", "minute", "minutes"],
+        MILLIS_PER_MINUTE,
+    ),
+    (&["h", "hr", "hrs", "hour", "hours"], MILLIS_PER_HOUR),
+    (&["d", "day", "days"], MILLIS_PER_DAY),
+    (&["w", "wk", "wks", "week", "weeks"], MILLIS_PER_WEEK),
+];
+
+/// Duration converter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationFormat;
+
+impl Transform for DurationFormat {
+    fn name(&self) -> &'static str {
+        "Duration Format"
+    }
+
+    fn id(&self) -> &'static str {
+        "duration_format"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts durations, one per line, between seconds or milliseconds, human form (2h 30m 15s), clock form (2:30:15) and ISO 8601 (PT2H30M15S)"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[TO_OPTION, UNIT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let number_unit = match options.value(&UNIT_OPTION) {
+            "milliseconds" => 1,
+            _ => MILLIS_PER_SECOND,
+        };
+        let to = options.value(&TO_OPTION);
+        let lines = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                token.check_every(i)?;
+                let line = line.trim();
+                if line.is_empty() {
+                    return Ok(String::new());
+                }
+                let millis = parse_duration(line, number_unit).map_err(|message| {
+                    TransformError::InvalidArgument(format!("Line {}: {}", i + 1, message).into())
+                })?;
+                Ok(match to {
+                    "seconds" => format_decimal(millis, MILLIS_PER_SECOND),
+                    "milliseconds" => millis.to_string(),
+                    "iso" => format_iso(millis),
+                    _ => format_human(millis),
+                })
+            })
+            .collect::<Result<Vec<_>, TransformError>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "9015"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Mixed notations",
+                input: "9015s\n2h 30m 15s\n1 day 4 hours\n02:30:15.5\nPT2H30M",
+            },
+            Example {
+                name: "ISO 8601 with days and weeks",
+                input: "P1DT12H\nP2W",
+            },
+        ]
+    }
+}
+
+/// Milliseconds in a duration written in any of the supported forms
+fn parse_duration(text: &str, number_unit: i128) -> Result<i128, String> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, text),
+    };
+    let millis = if unsigned.starts_with(['P', 'p']) {
+        parse_iso(unsigned)
+    } else if unsigned.contains(':') {
+        parse_clock(unsigned)
+    } else if let Some(amount) = parse_decimal(unsigned, number_unit) {
+        Some(amount)
+    } else {
+        parse_human(unsigned)
+    }
+    .ok_or_else(|| format!("'{}' is not a duration", text))?;
+    if millis > MAX_MILLIS {
+        return Err(format!("'{}' is too long a duration", text));
+    }
+    Ok(if negative { -millis } else { millis })
+}
+
+// A decimal number of `unit` milliseconds, rounded to the millisecond
+fn parse_decimal(text: &str, unit: i128) -> Option<i128> {
+    let (whole, fraction) = text.split_once(['.', ',']).unwrap_or((text, ""));
+    if (whole.is_empty() && fraction.is_empty())
+        || whole.len() > 18
+        || !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut millis = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<i128>().ok()? * unit
+    };
+    let fraction = &fraction[..fraction.len().min(18)];
+    if !fraction.is_empty() {
+        let numerator: i128 = fraction.parse().ok()?;
+        let denominator = 10i128.pow(fraction.len() as u32);
+        millis += (numerator * unit * 2 + denominator) / (denominator * 2);
+    }
+    Some(millis)
+}
+
+// "2h 30m", "1.5 hours", "1d4h" and the like
+fn parse_human(text: &str) -> Option<i128> {
+    let mut rest = text.trim();
+    let mut total = 0;
+    let mut seen_any = false;
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number = &rest[..number_end];
+        rest = rest[number_end..].trim_start();
+        let unit_end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let unit = rest[..unit_end].to_ascii_lowercase();
+        let &(_, millis) = UNITS
+            .iter()
+            .find(|(names, _)| names.contains(&unit.as_str()))?;
+        total += parse_decimal(number, millis)?;
+        seen_any = true;
+        rest = rest[unit_end..].trim_start_matches([' ', ',']);
+        rest = rest.strip_prefix("and ").unwrap_or(rest);
+    }
+    seen_any.then_some(total)
+}
+
+// [[D:]H:]MM:SS[.fff], as on a stopwatch
+fn parse_clock(text: &str) -> Option<i128> {
+    let parts: Vec<&str> = text.split(':').collect();
+    if parts.len() > 4 || parts.iter().any(|part| part.is_empty()) {
+        return None;
+    }
+    let (seconds, larger) = parts.split_last()?;
+    let mut total = parse_decimal(seconds, MILLIS_PER_SECOND)?;
+    let units = [MILLIS_PER_MINUTE, MILLIS_PER_HOUR, MILLIS_PER_DAY];
+    for (part, unit) in larger.iter().rev().zip(units) {
+        if !part.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        total += part.parse::<i128>().ok()? * unit;
+    }
+    Some(total)
+}
+
+// PnW or PnDTnHnMnS; years and months have no fixed length so are refused
+fn parse_iso(text: &str) -> Option<i128> {
+    let upper = text.to_ascii_uppercase();
+    let body = upper.strip_prefix('P')?;
+    let (date, time) = match body.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, Some(time)),
+        Some(_) => return None,
+        None => (body, None),
+    };
+    let mut total = 0;
+    let mut fields = 0;
+    for (part, designators) in [
+        (date, &[('W', MILLIS_PER_WEEK), ('D', MILLIS_PER_DAY)][..]),
+        (
+            time.unwrap_or(""),
+            &[
+                ('H', MILLIS_PER_HOUR),
+                ('M', MILLIS_PER_MINUTE),
+                ('S', MILLIS_PER_SECOND),
+            ][..],
+        ),
+    ] {
+        let mut rest = part;
+        let mut next = 0;
+        while !rest.is_empty() {
+            let end = rest.find(|c: char| c.is_ascii_alphabetic())?;
+            let designator = rest[end..].chars().next()?;
+            // Designators must come in order, each at most once
+            let index = designators[next..]
+                .iter()
+                .position(|&(d, _)| d == designator)?
+                + next;
+            total += parse_decimal(&rest[..end], designators[index].1)?;
+            next = index + 1;
+            fields += 1;
+            rest = &rest[end + 1..];
+        }
+    }
+    (fields > 0).then_some(total)
+}
+
+// "2h 30m 15s 500ms", largest unit days
+fn format_human(millis: i128) -> String {
+    if millis == 0 {
+        return "0s".to_string();
+    }
+    let sign = if millis < 0 { "-" } else { "" };
+    let mut rest = millis.abs();
+    let mut parts = Vec::new();
+    for (unit, size) in [
+        ("d", MILLIS_PER_DAY),
+        ("h", MILLIS_PER_HOUR),
+        ("m", MILLIS_PER_MINUTE),
+        ("s", MILLIS_PER_SECOND),
+        ("ms", 1),
+    ] {
+        if rest >= size {
+            parts.push(format!("{}{}", rest / size, unit));
+            rest %= size;
+        }
+    }
+    format!("{}{}", sign, parts.join(" "))
+}
+
+// "P1DT2H30M15.5S", with "PT0S" for nothing
+fn format_iso(millis: i128) -> String {
+    let sign = if millis < 0 { "-" } else { "" };
+    let rest = millis.abs();
+    let days = rest / MILLIS_PER_DAY;
+    let hours = rest % MILLIS_PER_DAY / MILLIS_PER_HOUR;
+    let minutes = rest % MILLIS_PER_HOUR / MILLIS_PER_MINUTE;
+    let seconds = rest % MILLIS_PER_MINUTE;
+    let mut iso = format!("{}P", sign);
+    if days > 0 {
+        iso.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || days == 0 {
+        iso.push('T');
+        if hours > 0 {
+            iso.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            iso.push_str(&format!("{}M", minutes));
+        }
+        if seconds > 0 || (days == 0 && hours == 0 && minutes == 0) {
+            iso.push_str(&format!("{}S", format_decimal(seconds, MILLIS_PER_SECOND)));
+        }
+    }
+    iso
+}
+
+// `millis` in units of `unit` milliseconds, with only the decimals needed
+fn format_decimal(millis: i128, unit: i128) -> String {
+    let sign = if millis < 0 { "-" } else { "" };
+    let whole = millis.abs() / unit;
+    let fraction = millis.abs() % unit;
+    if fraction == 0 {
+        format!("{}{}", sign, whole)
+    } else {
+        let digits = format!("{:03}", fraction);
+        format!("{}{}.{}", sign, whole, digits.trim_end_matches('0'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, to: &str) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("to", to);
+        DurationFormat.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_duration_to_human() {
+        assert_eq!(
+            DurationFormat
+                .transform(DurationFormat.default_test_input())
+                .unwrap(),
+            "2h 30m 15s"
+        );
+        assert_eq!(
+            DurationFormat
+                .transform("9015s\n\n1 day, 4 hours and 5 minutes\n02:30:15.5\nPT2H30M\n0\n-90")
+                .unwrap(),
+            "2h 30m 15s\n\n1d 4h 5m\n2h 30m 15s 500ms\n2h 30m\n0s\n-1m 30s"
+        );
+        assert_eq!(
+            DurationFormat.transform("1.5h 250ms").unwrap(),
+            "1h 30m 250ms"
+        );
+    }
+
+    #[test]
+    fn test_duration_to_numbers() {
+        assert_eq!(
+            convert("2h 30m 15s\n1.25s", "seconds").unwrap(),
+            "9015\n1.25"
+        );
+        assert_eq!(convert("P1DT1S", "milliseconds").unwrap(), "86401000");
+        let mut options = TransformOptions::new();
+        options.set("unit", "milliseconds");
+        assert_eq!(
+            DurationFormat
+                .transform_with_options("9015500", &options)
+                .unwrap(),
+            "2h 30m 15s 500ms"
+        );
+    }
+
+    #[test]
+    fn test_duration_to_iso() {
+        assert_eq!(
+            convert("9015\n36h\n2w\n0\n0.5\n1d", "iso").unwrap(),
+            "PT2H30M15S\nP1DT12H\nP14D\nPT0S\nPT0.5S\nP1D"
+        );
+        assert_eq!(convert("pt1m30s", "iso").unwrap(), "PT1M30S");
+    }
+
+    #[test]
+    fn test_duration_invalid() {
+        for invalid in [
+            "soon",
+            "P1Y",
+            "P1M",
+            "PT",
+            "PT1S2H",
+            "5 parsecs",
+            "1:2:3:4:5",
+            "P",
+        ] {
+            assert!(DurationFormat.transform(invalid).is_err(), "{}", invalid);
+        }
+        assert!(DurationFormat.transform("99999999999999999999w").is_err());
+    }
+}
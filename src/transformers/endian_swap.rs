@@ -0,0 +1,178 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const WORD_SIZE_OPTION: TransformOption = TransformOption {
+    key: "word_size",
+    description: "Size in bits of the words whose bytes are reversed",
+    default: "32",
+    kind: TransformOptionKind::Choice(&["16", "32", "64"]),
+};
+
+/// Hex byte order swapper
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndianSwap;
+
+impl Transform for EndianSwap {
+    fn name(&self) -> &'static str {
+        "Endian Swap"
+    }
+
+    fn id(&self) -> &'static str {
+        "endianswap"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reverses the byte order of each 16, 32 or 64-bit word in hex, either per space separated group (78563412) or across single bytes (78 56 34 12)"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[WORD_SIZE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let word_bytes = options.usize_value(&WORD_SIZE_OPTION)? / 8;
+
+        // Each group with its "0x" prefix split off and the separator after it
+        let mut groups = Vec::new();
+        for (i, piece) in input.split_inclusive(char::is_whitespace).enumerate() {
+            token.check_every(i)?;
+            let group = piece.trim_end();
+            let separator = &piece[group.len()..];
+            let (prefix, digits) = match group.get(..2) {
+                Some("0x" | "0X") => group.split_at(2),
+                _ => ("", group),
+            };
+            if !digits.chars().all(|c| c.is_ascii_hexdigit()) || !digits.len().is_multiple_of(2) {
+                return Err(TransformError::HexDecodeError(format!(
+                    "'{}' is not a whole number of hex bytes",
+                    group
+                )));
+            }
+            groups.push((prefix, digits, separator));
+        }
+
+        let byte_per_group = groups.len() > 1
+            && groups
+                .iter()
+                .all(|(_, digits, _)| digits.is_empty() || digits.len() == 2);
+        let mut output = String::with_capacity(input.len());
+        if byte_per_group {
+            // A dump of single bytes: swap across groups, keeping the layout
+            let bytes: Vec<&str> = groups
+                .iter()
+                .map(|&(_, digits, _)| digits)
+                .filter(|digits| !digits.is_empty())
+                .collect();
+            check_whole_words(bytes.len(), word_bytes)?;
+            let mut swapped = bytes.chunks(word_bytes).flat_map(|word| word.iter().rev());
+            for (prefix, digits, separator) in groups {
+                output.push_str(prefix);
+                if !digits.is_empty() {
+                    output.push_str(swapped.next().unwrap_or(&""));
+                }
+                output.push_str(separator);
+            }
+        } else {
+            for (prefix, digits, separator) in groups {
+                check_whole_words(digits.len() / 2, word_bytes)?;
+                output.push_str(prefix);
+                for word in digits.as_bytes().chunks(word_bytes * 2) {
+                    for byte in word.chunks(2).rev() {
+                        output.push_str(std::str::from_utf8(byte).unwrap_or_default());
+                    }
+                }
+                output.push_str(separator);
+            }
+        }
+        Ok(output)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "78563412 efbeadde"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Byte dump",
+                input: "de ad be ef 01 02 03 04\n0a 0b 0c 0d 0e 0f 10 11",
+            },
+            Example {
+                name: "With 0x prefixes",
+                input: "0x0100007f 0x5000a8c0",
+            },
+        ]
+    }
+}
+
+fn check_whole_words(bytes: usize, word_bytes: usize) -> Result<(), TransformError> {
+    if bytes.is_multiple_of(word_bytes) {
+        Ok(())
+    } else {
+        Err(TransformError::InvalidArgument(
+            format!(
+                "{} bytes is not a whole number of {}-bit words",
+                bytes,
+                word_bytes * 8
+            )
+            .into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(input: &str, word_size: &str) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("word_size", word_size);
+        EndianSwap.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_endian_swap_groups() {
+        assert_eq!(
+            EndianSwap
+                .transform(EndianSwap.default_test_input())
+                .unwrap(),
+            "12345678 deadbeef"
+        );
+        assert_eq!(swap("0x3412 0xCDAB\n", "16").unwrap(), "0x1234 0xABCD\n");
+        assert_eq!(swap("0123456789abcdef", "64").unwrap(), "efcdab8967452301");
+        assert_eq!(swap("0123456789abcdef", "16").unwrap(), "23016745ab89efcd");
+    }
+
+    #[test]
+    fn test_endian_swap_byte_dump() {
+        assert_eq!(
+            EndianSwap.transform("de ad be ef 01 02\n03 04").unwrap(),
+            "ef be ad de 04 03\n02 01"
+        );
+        assert_eq!(swap("01  02", "16").unwrap(), "02  01");
+    }
+
+    #[test]
+    fn test_endian_swap_invalid() {
+        assert!(EndianSwap.transform("123456").is_err());
+        assert!(EndianSwap.transform("12 34 56").is_err());
+        assert!(EndianSwap.transform("1234567g").is_err());
+        assert!(swap("12345678", "8").is_err());
+        assert_eq!(EndianSwap.transform("").unwrap(), "");
+    }
+}
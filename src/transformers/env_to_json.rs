@@ -0,0 +1,236 @@
+use crate::utils::json::JsonValue;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// .env (dotenv) to JSON transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvToJson;
+
+impl Transform for EnvToJson {
+    fn name(&self) -> &'static str {
+        ".env to JSON"
+    }
+
+    fn id(&self) -> &'static str {
+        "envtojson"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts a .env file into a JSON object, handling quotes, escapes, multi-line values, export prefixes and comments"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let mut entries: Vec<(String, JsonValue)> = Vec::new();
+        for (key, value) in parse_env(input)? {
+            let value = JsonValue::String(value);
+            // A later assignment wins but keeps the first one's place
+            match entries.iter_mut().find(|(k, _)| *k == key) {
+                Some(entry) => entry.1 = value,
+                None => entries.push((key, value)),
+            }
+        }
+        Ok(JsonValue::Object(entries).to_pretty_string(2))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "# Database\nDB_HOST=localhost\nDB_PORT=5432\nexport API_KEY=\"s3cr3t\\nline two\"\nGREETING='Hello, $USER' # literal"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Multi-line value",
+                input: "PRIVATE_KEY=\"-----BEGIN KEY-----\nMIIBOgIBAAJBAK\n-----END KEY-----\"\nDEBUG=true",
+            },
+            Example {
+                name: "Comments and blanks",
+                input: "# App\nNAME=buup # inline comment\n\nURL=https://buup.io/#top\nEMPTY=",
+            },
+        ]
+    }
+}
+
+/// The assignments in a .env file, in order
+fn parse_env(input: &str) -> Result<Vec<(String, String)>, TransformError> {
+    let mut lines = input.lines().enumerate();
+    let mut assignments = Vec::new();
+    while let Some((i, line)) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: &str| {
+            TransformError::InvalidArgument(format!("Line {}: {}", i + 1, message).into())
+        };
+        let line = line
+            .strip_prefix("export")
+            .filter(|rest| rest.starts_with([' ', '\t']))
+            .map_or(line, str::trim_start);
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error(&format!("'{}' is not a KEY=value assignment", line)))?;
+        let key = key.trim_end();
+        if !is_valid_key(key) {
+            return Err(error(&format!("'{}' is not a valid variable name", key)));
+        }
+        let value = value.trim_start();
+
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                // The value runs to the closing quote, over several lines
+                // if need be
+                let mut text = value[1..].to_string();
+                let (content, rest) = loop {
+                    if let Some(end) = closing_quote(&text, quote) {
+                        let rest = text[end + 1..].to_string();
+                        text.truncate(end);
+                        break (text, rest);
+                    }
+                    let (_, next) = lines
+                        .next()
+                        .ok_or_else(|| error(&format!("{} has no closing {}", key, quote)))?;
+                    text.push('\n');
+                    text.push_str(next);
+                };
+                let rest = rest.trim_start();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(error(&format!(
+                        "unexpected '{}' after the quoted value of {}",
+                        rest, key
+                    )));
+                }
+                if quote == '"' {
+                    unescape(&content)
+                } else {
+                    content
+                }
+            }
+            // An unquoted value ends at a comment, which needs a space
+            // before its # so that URLs with fragments survive
+            _ => match value.find(" #").or_else(|| value.find("\t#")) {
+                Some(comment) => value[..comment].trim_end(),
+                None if value.starts_with('#') => "",
+                None => value.trim_end(),
+            }
+            .to_string(),
+        };
+        assignments.push((key.to_string(), value));
+    }
+    Ok(assignments)
+}
+
+/// Whether `key` can name an environment variable in a .env file
+pub(crate) fn is_valid_key(key: &str) -> bool {
+    key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+// Byte index of the quote ending a value that started with `quote`,
+// skipping backslash escapes inside double quotes
+fn closing_quote(text: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quote == '"' => escaped = true,
+            c if c == quote => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+// Double quoted values understand the usual backslash escapes; any other
+// escaped character stands for itself
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_to_json() {
+        assert_eq!(
+            EnvToJson.transform(EnvToJson.default_test_input()).unwrap(),
+            "{\n  \"DB_HOST\": \"localhost\",\n  \"DB_PORT\": \"5432\",\n  \"API_KEY\": \"s3cr3t\\nline two\",\n  \"GREETING\": \"Hello, $USER\"\n}"
+        );
+        assert_eq!(EnvToJson.transform("").unwrap(), "{}");
+        assert_eq!(EnvToJson.transform("# only a comment").unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_env_values() {
+        let parsed = parse_env(
+            "A=one two  # comment\nB=https://x.io/#frag\nC=\nD=#\nE = \"say \\\"hi\\\"\"\nexport_G=x\nexport H='\\n'\nA=again",
+        )
+        .unwrap();
+        let expected = [
+            ("A", "one two"),
+            ("B", "https://x.io/#frag"),
+            ("C", ""),
+            ("D", ""),
+            ("E", "say \"hi\""),
+            ("export_G", "x"),
+            ("H", "\\n"),
+            ("A", "again"),
+        ];
+        assert_eq!(
+            parsed,
+            expected
+                .iter()
+                .map(|&(k, v)| (k.to_string(), v.to_string()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            EnvToJson.transform("A=1\nB=2\nA=3").unwrap(),
+            "{\n  \"A\": \"3\",\n  \"B\": \"2\"\n}"
+        );
+    }
+
+    #[test]
+    fn test_env_multiline() {
+        let parsed = parse_env("KEY=\"line 1\nline 2\" # done\nNEXT='a\n\nb'").unwrap();
+        assert_eq!(parsed[0].1, "line 1\nline 2");
+        assert_eq!(parsed[1].1, "a\n\nb");
+    }
+
+    #[test]
+    fn test_env_invalid() {
+        for invalid in [
+            "JUST_A_WORD",
+            "1ABC=x",
+            "MY KEY=x",
+            "A=\"unclosed\nB=2",
+            "A=\"x\" trailing",
+            "A='it''s'",
+        ] {
+            assert!(EnvToJson.transform(invalid).is_err(), "{}", invalid);
+        }
+        assert_eq!(
+            EnvToJson.transform("A=1\n\nnope").unwrap_err().to_string(),
+            "Invalid argument: Line 3: 'nope' is not a KEY=value assignment"
+        );
+    }
+}
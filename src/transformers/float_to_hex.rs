@@ -0,0 +1,210 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const TYPE_OPTION: TransformOption = TransformOption {
+    key: "type",
+    description: "Single (f32) or double (f64) precision",
+    default: "f64",
+    kind: TransformOptionKind::Choice(&["f32", "f64"]),
+};
+
+/// Decimal to IEEE 754 hex converter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatToHex;
+
+impl Transform for FloatToHex {
+    fn name(&self) -> &'static str {
+        "Float to Hex"
+    }
+
+    fn id(&self) -> &'static str {
+        "float_to_hex"
+    }
+
+    fn description(&self) -> &'static str {
+        "Shows the IEEE 754 bits of a decimal number as hex, with its sign, exponent and mantissa"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[TYPE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let input = input.trim();
+        let value: f64 = input.parse().map_err(|_| {
+            TransformError::InvalidArgument(format!("'{}' is not a number", input).into())
+        })?;
+        let (format, bits) = match options.value(&TYPE_OPTION) {
+            "f32" => (&F32, u64::from((value as f32).to_bits())),
+            _ => (&F64, value.to_bits()),
+        };
+        Ok(format!(
+            "Hex: 0x{:0width$X}\n{}",
+            bits,
+            format.describe(bits),
+            width = format.bits / 4
+        ))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "3.14159"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Not exactly representable",
+                input: "0.1",
+            },
+            Example {
+                name: "Negative zero",
+                input: "-0",
+            },
+            Example {
+                name: "Infinity",
+                input: "inf",
+            },
+        ]
+    }
+}
+
+/// Layout of an IEEE 754 binary format
+#[derive(Debug)]
+pub(crate) struct FloatFormat {
+    pub(crate) bits: usize,
+    exponent_bits: u32,
+    mantissa_bits: u32,
+}
+
+pub(crate) const F32: FloatFormat = FloatFormat {
+    bits: 32,
+    exponent_bits: 8,
+    mantissa_bits: 23,
+};
+
+pub(crate) const F64: FloatFormat = FloatFormat {
+    bits: 64,
+    exponent_bits: 11,
+    mantissa_bits: 52,
+};
+
+impl FloatFormat {
+    /// The value of `bits` and its fields, one per line
+    pub(crate) fn describe(&self, bits: u64) -> String {
+        let sign = bits >> (self.bits - 1);
+        let exponent = (bits >> self.mantissa_bits) & ((1 << self.exponent_bits) - 1);
+        let mantissa = bits & ((1 << self.mantissa_bits) - 1);
+        let bias = (1i64 << (self.exponent_bits - 1)) - 1;
+        let max_exponent = (1 << self.exponent_bits) - 1;
+
+        let (value, class) = if self.bits == 32 {
+            let value = f32::from_bits(bits as u32);
+            (format!("{:?}", value), classify(value.classify()))
+        } else {
+            let value = f64::from_bits(bits);
+            (format!("{:?}", value), classify(value.classify()))
+        };
+        let exponent_note = if exponent == 0 {
+            format!("subnormal, 2^{}", 1 - bias)
+        } else if exponent == max_exponent {
+            "all ones, infinity or NaN".to_string()
+        } else {
+            format!("2^{}", exponent as i64 - bias)
+        };
+        format!(
+            "Value: {}\nClass: {}\nSign: {} ({})\nExponent: 0x{:X} ({}, {})\nMantissa: 0x{:0mantissa_width$X}\nBinary: {} {:0exponent_bits$b} {:0mantissa_bits$b}",
+            value,
+            class,
+            sign,
+            if sign == 1 { "-" } else { "+" },
+            exponent,
+            exponent,
+            exponent_note,
+            mantissa,
+            sign,
+            exponent,
+            mantissa,
+            mantissa_width = (self.mantissa_bits as usize).div_ceil(4),
+            exponent_bits = self.exponent_bits as usize,
+            mantissa_bits = self.mantissa_bits as usize,
+        )
+    }
+}
+
+fn classify(category: std::num::FpCategory) -> &'static str {
+    match category {
+        std::num::FpCategory::Nan => "NaN",
+        std::num::FpCategory::Infinite => "infinite",
+        std::num::FpCategory::Zero => "zero",
+        std::num::FpCategory::Subnormal => "subnormal",
+        std::num::FpCategory::Normal => "normal",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, float_type: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("type", float_type);
+        FloatToHex.transform_with_options(input, &options).unwrap()
+    }
+
+    #[test]
+    fn test_float_to_hex_f64() {
+        assert_eq!(
+            FloatToHex.transform("1.5").unwrap(),
+            "Hex: 0x3FF8000000000000\n\
+             Value: 1.5\n\
+             Class: normal\n\
+             Sign: 0 (+)\n\
+             Exponent: 0x3FF (1023, 2^0)\n\
+             Mantissa: 0x8000000000000\n\
+             Binary: 0 01111111111 1000000000000000000000000000000000000000000000000000"
+        );
+        assert!(FloatToHex
+            .transform("0.1")
+            .unwrap()
+            .starts_with("Hex: 0x3FB999999999999A\n"));
+    }
+
+    #[test]
+    fn test_float_to_hex_f32() {
+        assert_eq!(
+            convert("-2.5", "f32"),
+            "Hex: 0xC0200000\n\
+             Value: -2.5\n\
+             Class: normal\n\
+             Sign: 1 (-)\n\
+             Exponent: 0x80 (128, 2^1)\n\
+             Mantissa: 0x200000\n\
+             Binary: 1 10000000 01000000000000000000000"
+        );
+        assert!(convert("3.14159", "f32").starts_with("Hex: 0x40490FD0\nValue: 3.14159\n"));
+    }
+
+    #[test]
+    fn test_float_to_hex_special_values() {
+        assert!(convert("inf", "f32").starts_with("Hex: 0x7F800000\nValue: inf\nClass: infinite"));
+        assert!(convert("-0", "f64").contains("Class: zero\nSign: 1 (-)"));
+        assert!(convert("1e-40", "f32").contains("Class: subnormal"));
+        assert!(convert("1e-40", "f32").contains("Exponent: 0x0 (0, subnormal, 2^-126)"));
+        assert!(FloatToHex.transform("pi").is_err());
+    }
+}
@@ -1,13 +1,19 @@
 use super::base64_encode;
 use super::deflate_compress;
 use crate::utils::crc32::calculate_crc32;
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 const ID1: u8 = 0x1f;
 const ID2: u8 = 0x8b;
 const CM_DEFLATE: u8 = 8;
 const OS_UNKNOWN: u8 = 255;
+// XFL values for the slowest and fastest compression levels
+const XFL_MAX_COMPRESSION: u8 = 2;
+const XFL_FASTEST: u8 = 4;
 
 /// Compresses input using the Gzip algorithm (RFC 1952).
 /// Wraps DEFLATE-compressed data with a Gzip header and footer.
@@ -35,63 +41,122 @@ impl Transform for GzipCompress {
         "Hello, Gzip World!"
     }
 
-    fn transform(&self, input: &str) -> Result<String, TransformError> {
-        let input_bytes = input.as_bytes();
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Repetitive text",
+                input: "Buup transforms text. Buup transforms text. Buup transforms text, again and again and again.",
+            },
+        ]
+    }
 
-        // Compress the data using the core DEFLATE logic
-        let deflated_data = deflate_compress::deflate_bytes(input_bytes)
-            .map_err(|e| TransformError::CompressionError(format!("DEFLATE failed: {}", e)))?;
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
 
-        let crc32_checksum = calculate_crc32(input_bytes);
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let output = gzip_bytes(input.as_bytes(), deflate_compress::DEFAULT_LEVEL, token)?;
+        Ok(base64_encode::base64_encode(&output))
+    }
 
-        let isize: u32 = input_bytes.len().try_into().map_err(|_| {
-            TransformError::CompressionError("Input too large for ISIZE (max 2^32 - 1)".into())
-        })?;
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        gzip_bytes(input, deflate_compress::DEFAULT_LEVEL, &CancelToken::new())
+    }
 
-        // Get current timestamp (seconds since epoch) for MTIME
-        // RFC 1952 states, if the modification time is not available, MTIME is set to zero:
-        // MTIME (Modification TIME)
-        // This gives the most recent modification time of the original
-        // file being compressed.  The time is in Unix format, i.e.,
-        // seconds since 00:00:00 GMT, Jan.  1, 1970.  (Note that this
-        // may cause problems for MS-DOS and other systems that use
-        // local rather than Universal time.)  If the compressed data
-        // did not come from a file, MTIME is set to the time at which
-        // compression started.  MTIME = 0 means no time stamp is
-        // available.
-        #[cfg(target_arch = "wasm32")]
-        let mtime: u32 = 0;
-
-        #[cfg(not(target_arch = "wasm32"))]
-        let mtime = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|e| {
-                TransformError::CompressionError(format!("Failed to get system time: {}", e))
-            })?
-            .as_secs()
-            .try_into()
-            .unwrap_or(0u32); // Use 0 if conversion fails (e.g., time before epoch)
-
-        let mut output = Vec::with_capacity(10 + deflated_data.len() + 8);
-
-        // Write Gzip header
-        output.push(ID1);
-        output.push(ID2);
-        output.push(CM_DEFLATE);
-        output.push(0); // FLG (FTEXT=0, FHCRC=0, FEXTRA=0, FNAME=0, FCOMMENT=0)
-        output.extend_from_slice(&mtime.to_le_bytes());
-        output.push(0); // XFL (deflate flags, 0 for this strategy)
-        output.push(OS_UNKNOWN);
-
-        // Append compressed data
-        output.extend_from_slice(&deflated_data);
-
-        // Append Gzip footer
-        output.extend_from_slice(&crc32_checksum.to_le_bytes());
-        output.extend_from_slice(&isize.to_le_bytes());
+    fn options(&self) -> &'static [TransformOption] {
+        &[deflate_compress::LEVEL_OPTION]
+    }
 
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let output = self.transform_bytes_configured(input.as_bytes(), options, token)?;
         Ok(base64_encode::base64_encode(&output))
     }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let level = deflate_compress::level_value(options)?;
+        gzip_bytes(input, level, token)
+    }
+}
+
+// Wraps `input_bytes`, DEFLATE-compressed at `level`, in a Gzip header and footer
+pub(crate) fn gzip_bytes(
+    input_bytes: &[u8],
+    level: u8,
+    token: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    // Compress the data using the core DEFLATE logic
+    let deflated_data = deflate_compress::deflate_bytes_with_cancel(input_bytes, level, token)
+        .map_err(|e| match e {
+            TransformError::Cancelled => e,
+            e => TransformError::CompressionError(format!("DEFLATE failed: {}", e)),
+        })?;
+
+    let crc32_checksum = calculate_crc32(input_bytes);
+
+    let isize: u32 = input_bytes.len().try_into().map_err(|_| {
+        TransformError::CompressionError("Input too large for ISIZE (max 2^32 - 1)".into())
+    })?;
+
+    // Get current timestamp (seconds since epoch) for MTIME
+    // RFC 1952 states, if the modification time is not available, MTIME is set to zero:
+    // MTIME (Modification TIME)
+    // This gives the most recent modification time of the original
+    // file being compressed.  The time is in Unix format, i.e.,
+    // seconds since 00:00:00 GMT, Jan.  1, 1970.  (Note that this
+    // may cause problems for MS-DOS and other systems that use
+    // local rather than Universal time.)  If the compressed data
+    // did not come from a file, MTIME is set to the time at which
+    // compression started.  MTIME = 0 means no time stamp is
+    // available.
+    #[cfg(target_arch = "wasm32")]
+    let mtime: u32 = 0;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let mtime = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| TransformError::CompressionError(format!("Failed to get system time: {}", e)))?
+        .as_secs()
+        .try_into()
+        .unwrap_or(0u32); // Use 0 if conversion fails (e.g., time before epoch)
+
+    let mut output = Vec::with_capacity(10 + deflated_data.len() + 8);
+
+    // Write Gzip header
+    output.push(ID1);
+    output.push(ID2);
+    output.push(CM_DEFLATE);
+    output.push(0); // FLG (FTEXT=0, FHCRC=0, FEXTRA=0, FNAME=0, FCOMMENT=0)
+    output.extend_from_slice(&mtime.to_le_bytes());
+    // XFL (deflate flags)
+    output.push(match level {
+        deflate_compress::MAX_LEVEL => XFL_MAX_COMPRESSION,
+        deflate_compress::MIN_LEVEL => XFL_FASTEST,
+        _ => 0,
+    });
+    output.push(OS_UNKNOWN);
+
+    // Append compressed data
+    output.extend_from_slice(&deflated_data);
+
+    // Append Gzip footer
+    output.extend_from_slice(&crc32_checksum.to_le_bytes());
+    output.extend_from_slice(&isize.to_le_bytes());
+
+    Ok(output)
 }
 
 #[cfg(test)]
@@ -140,4 +205,38 @@ mod tests {
         );
         assert_eq!(decompressed_result.unwrap(), input);
     }
+
+    #[test]
+    fn test_gzip_bytes_round_trip() {
+        // Pseudo-random bytes are incompressible and span several stored blocks
+        let mut state = 0x2545_f491_u32;
+        let input: Vec<u8> = (0..150_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+        let compressed = GzipCompress.transform_bytes(&input).unwrap();
+        assert_eq!(&compressed[..2], &[ID1, ID2]);
+        assert_eq!(GzipDecompress.transform_bytes(&compressed).unwrap(), input);
+    }
+
+    #[test]
+    fn test_gzip_levels() {
+        let input = "Buup transforms text. ".repeat(100);
+        for (level, xfl) in [("1", XFL_FASTEST), ("6", 0), ("9", XFL_MAX_COMPRESSION)] {
+            let mut options = TransformOptions::new();
+            options.set("level", level);
+            let compressed = GzipCompress
+                .transform_bytes_with_options(input.as_bytes(), &options)
+                .unwrap();
+            assert_eq!(compressed[8], xfl);
+            assert_eq!(
+                GzipDecompress.transform_bytes(&compressed).unwrap(),
+                input.as_bytes()
+            );
+        }
+    }
 }
@@ -1,7 +1,10 @@
 use super::base64_decode;
 use super::deflate_decompress;
 use crate::utils::crc32::calculate_crc32;
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
 
 // Constants from Gzip spec (RFC 1952)
 const ID1: u8 = 0x1f;
@@ -44,214 +47,266 @@ impl Transform for GzipDecompress {
         "H4sIAAAAAAAA//NIzcnJ11Fwr8osUAjPL8pJUQQAcbBHdRIAAAA=" // This is "Hello, Gzip World!" gzipped and base64-encoded
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Repetitive text",
+            input:
+                "H4sIAAAAAAACA3MqLS1QKClKzCtOyy/KLVYoSa0o0VNwIlpURyExPTEzTyExLwWdpQcAUXhaTVwAAAA=",
+        }]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[deflate_decompress::MAX_OUTPUT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
         let compressed_bytes = base64_decode::base64_decode(input).map_err(|e| {
             TransformError::InvalidArgument(format!("Invalid Base64 input: {}", e).into())
         })?;
+        let decompressed_bytes =
+            self.transform_bytes_configured(&compressed_bytes, options, token)?;
 
-        if compressed_bytes.len() < 18 {
-            // Minimum Gzip size: 10 header + 8 footer + >=0 data
-            return Err(TransformError::CompressionError(
-                "Input too short to be Gzip".into(),
-            ));
-        }
+        // We assume the input was UTF-8 if FTEXT was set or by default.
+        // If FTEXT is *not* set, it could be binary, but this tool focuses on text.
+        String::from_utf8(decompressed_bytes).map_err(|_| TransformError::Utf8Error)
+    }
 
-        // --- Parse Header ---
-        let mut current_pos = 0;
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let max_output = options.usize_value(&deflate_decompress::MAX_OUTPUT_OPTION)?;
+        gunzip_bytes(input, max_output, token)
+    }
+}
 
-        // Magic number (2 bytes)
-        if compressed_bytes.get(current_pos) != Some(&ID1)
-            || compressed_bytes.get(current_pos + 1) != Some(&ID2)
-        {
+// Parses a Gzip member (RFC 1952) and returns the verified decompressed bytes,
+// failing if they would exceed `max_output`
+pub(crate) fn gunzip_bytes(
+    compressed_bytes: &[u8],
+    max_output: usize,
+    token: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    if compressed_bytes.len() < 18 {
+        // Minimum Gzip size: 10 header + 8 footer + >=0 data
+        return Err(TransformError::CompressionError(
+            "Input too short to be Gzip".into(),
+        ));
+    }
+
+    // --- Parse Header ---
+    let mut current_pos = 0;
+
+    // Magic number (2 bytes)
+    if compressed_bytes.get(current_pos) != Some(&ID1)
+        || compressed_bytes.get(current_pos + 1) != Some(&ID2)
+    {
+        return Err(TransformError::CompressionError(
+            "Invalid Gzip magic number".into(),
+        ));
+    }
+    current_pos += 2;
+
+    // Compression method (1 byte)
+    let cm = *compressed_bytes
+        .get(current_pos)
+        .ok_or_else(|| TransformError::CompressionError("Missing CM".into()))?;
+    if cm != CM_DEFLATE {
+        return Err(TransformError::CompressionError(format!(
+            "Unsupported compression method: {}",
+            cm
+        )));
+    }
+    current_pos += 1;
+
+    // Flags (1 byte)
+    let flg = *compressed_bytes
+        .get(current_pos)
+        .ok_or_else(|| TransformError::CompressionError("Missing FLG".into()))?;
+    current_pos += 1;
+
+    // MTIME (4 bytes), XFL (1 byte), OS (1 byte) - total 6 bytes
+    if compressed_bytes.len() < current_pos + 6 {
+        return Err(TransformError::CompressionError(
+            "Incomplete Gzip header (MTIME/XFL/OS)".into(),
+        ));
+    }
+    // let mtime = u32::from_le_bytes(compressed_bytes[current_pos..current_pos+4].try_into().unwrap()); // Assign to _ as unused
+    current_pos += 4; // Skip MTIME
+                      // let xfl = compressed_bytes[current_pos]; // Assign to _ as unused
+    current_pos += 1; // Skip XFL
+                      // let os = compressed_bytes[current_pos]; // Assign to _ as unused
+    current_pos += 1; // Skip OS
+
+    // --- Optional Header Fields ---
+
+    // FEXTRA (Variable length)
+    if flg & FEXTRA != 0 {
+        if compressed_bytes.len() < current_pos + 2 {
             return Err(TransformError::CompressionError(
-                "Invalid Gzip magic number".into(),
+                "Input too short for FEXTRA length".into(),
             ));
         }
+        let xlen = u16::from_le_bytes(
+            compressed_bytes[current_pos..current_pos + 2]
+                .try_into()
+                .unwrap(),
+        ) as usize;
         current_pos += 2;
-
-        // Compression method (1 byte)
-        let cm = *compressed_bytes
-            .get(current_pos)
-            .ok_or_else(|| TransformError::CompressionError("Missing CM".into()))?;
-        if cm != CM_DEFLATE {
-            return Err(TransformError::CompressionError(format!(
-                "Unsupported compression method: {}",
-                cm
-            )));
-        }
-        current_pos += 1;
-
-        // Flags (1 byte)
-        let flg = *compressed_bytes
-            .get(current_pos)
-            .ok_or_else(|| TransformError::CompressionError("Missing FLG".into()))?;
-        current_pos += 1;
-
-        // MTIME (4 bytes), XFL (1 byte), OS (1 byte) - total 6 bytes
-        if compressed_bytes.len() < current_pos + 6 {
+        if compressed_bytes.len() < current_pos + xlen {
             return Err(TransformError::CompressionError(
-                "Incomplete Gzip header (MTIME/XFL/OS)".into(),
+                "Input too short for FEXTRA data".into(),
             ));
         }
-        // let mtime = u32::from_le_bytes(compressed_bytes[current_pos..current_pos+4].try_into().unwrap()); // Assign to _ as unused
-        current_pos += 4; // Skip MTIME
-                          // let xfl = compressed_bytes[current_pos]; // Assign to _ as unused
-        current_pos += 1; // Skip XFL
-                          // let os = compressed_bytes[current_pos]; // Assign to _ as unused
-        current_pos += 1; // Skip OS
-
-        // --- Optional Header Fields ---
-
-        // FEXTRA (Variable length)
-        if flg & FEXTRA != 0 {
-            if compressed_bytes.len() < current_pos + 2 {
-                return Err(TransformError::CompressionError(
-                    "Input too short for FEXTRA length".into(),
-                ));
-            }
-            let xlen = u16::from_le_bytes(
-                compressed_bytes[current_pos..current_pos + 2]
-                    .try_into()
-                    .unwrap(),
-            ) as usize;
-            current_pos += 2;
-            if compressed_bytes.len() < current_pos + xlen {
-                return Err(TransformError::CompressionError(
-                    "Input too short for FEXTRA data".into(),
-                ));
-            }
-            current_pos += xlen; // Skip FEXTRA data
-        }
+        current_pos += xlen; // Skip FEXTRA data
+    }
 
-        // FNAME (Null-terminated string)
-        if flg & FNAME != 0 {
-            let _start = current_pos; // Mark as unused
-            while current_pos < compressed_bytes.len() && compressed_bytes[current_pos] != 0 {
-                current_pos += 1;
-            }
-            if current_pos >= compressed_bytes.len() {
-                // Need space for null terminator + footer
-                return Err(TransformError::CompressionError(
-                    "Unterminated FNAME field or missing footer".into(),
-                ));
-            }
-            current_pos += 1; // Skip null terminator
+    // FNAME (Null-terminated string)
+    if flg & FNAME != 0 {
+        let _start = current_pos; // Mark as unused
+        while current_pos < compressed_bytes.len() && compressed_bytes[current_pos] != 0 {
+            current_pos += 1;
         }
-
-        // FCOMMENT (Null-terminated string)
-        if flg & FCOMMENT != 0 {
-            let _start = current_pos; // Mark as unused
-            while current_pos < compressed_bytes.len() && compressed_bytes[current_pos] != 0 {
-                current_pos += 1;
-            }
-            if current_pos >= compressed_bytes.len() {
-                // Need space for null terminator + footer
-                return Err(TransformError::CompressionError(
-                    "Unterminated FCOMMENT field or missing footer".into(),
-                ));
-            }
-            current_pos += 1; // Skip null terminator
+        if current_pos >= compressed_bytes.len() {
+            // Need space for null terminator + footer
+            return Err(TransformError::CompressionError(
+                "Unterminated FNAME field or missing footer".into(),
+            ));
         }
+        current_pos += 1; // Skip null terminator
+    }
 
-        // FHCRC (2 bytes)
-        if flg & FHCRC != 0 {
-            if compressed_bytes.len() < current_pos + 2 {
-                return Err(TransformError::CompressionError(
-                    "Input too short for FHCRC field".into(),
-                ));
-            }
-            let header_crc16_expected = u16::from_le_bytes(
-                compressed_bytes[current_pos..current_pos + 2]
-                    .try_into()
-                    .unwrap(),
-            );
-            // CRC32 calculation reused for header CRC16 check (lower 16 bits of CRC32)
-            let header_crc32_actual = calculate_crc32(&compressed_bytes[0..current_pos]);
-            let header_crc16_actual = (header_crc32_actual & 0xFFFF) as u16; // Check lower 16 bits
-            if header_crc16_actual != header_crc16_expected {
-                return Err(TransformError::CompressionError(format!(
-                    "Gzip header CRC16 mismatch: expected {:04x}, got {:04x}",
-                    header_crc16_expected, header_crc16_actual
-                )));
-            }
-            current_pos += 2;
+    // FCOMMENT (Null-terminated string)
+    if flg & FCOMMENT != 0 {
+        let _start = current_pos; // Mark as unused
+        while current_pos < compressed_bytes.len() && compressed_bytes[current_pos] != 0 {
+            current_pos += 1;
         }
-
-        let header_len = current_pos;
-
-        // Minimum length check
-        if compressed_bytes.len() < header_len + 8 {
+        if current_pos >= compressed_bytes.len() {
+            // Need space for null terminator + footer
             return Err(TransformError::CompressionError(
-                "Input too short for Gzip footer".into(),
+                "Unterminated FCOMMENT field or missing footer".into(),
             ));
         }
+        current_pos += 1; // Skip null terminator
+    }
 
-        // --- Find the end of the DEFLATE stream ---
-        // Gzip always ends with a 8-byte footer: 4 bytes CRC32 + 4 bytes ISIZE
-        // DEFLATE will *always* end with a '1' bit followed by a valid EOB code (usually 0)
-        // We only need to process until we find a valid DEFLATE end, and then add 8 bytes for the footer
-
-        // Create a safety limit - in case there's extra data, don't read all the way to the end
-        // This allows us to handle cases where garbage data is appended to a valid Gzip stream
-        let deflate_data = &compressed_bytes[header_len..];
-
-        // Decompress and check if it succeeded
-        let (decompressed_bytes, consumed_deflate_bytes) =
-            deflate_decompress::deflate_decode_bytes(deflate_data).map_err(|e| {
-                TransformError::CompressionError(format!("DEFLATE decompression failed: {}", e))
-            })?;
-
-        // --- Parse Footer ---
-        // Since we successfully decompressed the DEFLATE stream, we need to extract the footer data
-        // Gzip footer is always 8 bytes (4 for CRC32, 4 for ISIZE) after the deflate stream
-        // We need to find the position right after the DEFLATE data to locate the footer
-
-        // Since the footer is 8 bytes, ensure we have enough data
-        // DEFLATE decoder should have stopped exactly at the end of the DEFLATE stream,
-        // the next 8 bytes should be the footer
-        let deflate_end_pos = header_len + consumed_deflate_bytes;
-
-        if compressed_bytes.len() < deflate_end_pos + 8 {
+    // FHCRC (2 bytes)
+    if flg & FHCRC != 0 {
+        if compressed_bytes.len() < current_pos + 2 {
             return Err(TransformError::CompressionError(
-                "Input too short for Gzip footer after DEFLATE stream".into(),
+                "Input too short for FHCRC field".into(),
             ));
         }
-
-        let crc32_expected = u32::from_le_bytes(
-            compressed_bytes[deflate_end_pos..deflate_end_pos + 4]
-                .try_into()
-                .unwrap(),
-        );
-        let isize_expected = u32::from_le_bytes(
-            compressed_bytes[deflate_end_pos + 4..deflate_end_pos + 8]
+        let header_crc16_expected = u16::from_le_bytes(
+            compressed_bytes[current_pos..current_pos + 2]
                 .try_into()
                 .unwrap(),
         );
-
-        // --- Verify Footer ---
-        let crc32_actual = calculate_crc32(&decompressed_bytes);
-        if crc32_actual != crc32_expected {
+        // CRC32 calculation reused for header CRC16 check (lower 16 bits of CRC32)
+        let header_crc32_actual = calculate_crc32(&compressed_bytes[0..current_pos]);
+        let header_crc16_actual = (header_crc32_actual & 0xFFFF) as u16; // Check lower 16 bits
+        if header_crc16_actual != header_crc16_expected {
             return Err(TransformError::CompressionError(format!(
-                "CRC32 checksum mismatch: expected {:08x}, got {:08x}",
-                crc32_expected, crc32_actual
+                "Gzip header CRC16 mismatch: expected {:04x}, got {:04x}",
+                header_crc16_expected, header_crc16_actual
             )));
         }
+        current_pos += 2;
+    }
 
-        // ISIZE is the size of the original (uncompressed) input data modulo 2^32.
-        let isize_actual = (decompressed_bytes.len() as u64 % (1u64 << 32)) as u32;
-        if isize_actual != isize_expected {
-            return Err(TransformError::CompressionError(format!(
-                "ISIZE mismatch: expected {}, got {} (from decompressed length {})",
-                isize_expected,
-                isize_actual,
-                decompressed_bytes.len()
-            )));
-        }
+    let header_len = current_pos;
 
-        // We assume the input was UTF-8 if FTEXT was set or by default.
-        // If FTEXT is *not* set, it could be binary, but this tool focuses on text.
-        String::from_utf8(decompressed_bytes).map_err(|_| TransformError::Utf8Error)
+    // Minimum length check
+    if compressed_bytes.len() < header_len + 8 {
+        return Err(TransformError::CompressionError(
+            "Input too short for Gzip footer".into(),
+        ));
     }
+
+    // --- Find the end of the DEFLATE stream ---
+    // Gzip always ends with a 8-byte footer: 4 bytes CRC32 + 4 bytes ISIZE
+    // DEFLATE will *always* end with a '1' bit followed by a valid EOB code (usually 0)
+    // We only need to process until we find a valid DEFLATE end, and then add 8 bytes for the footer
+
+    // Create a safety limit - in case there's extra data, don't read all the way to the end
+    // This allows us to handle cases where garbage data is appended to a valid Gzip stream
+    let deflate_data = &compressed_bytes[header_len..];
+
+    // Decompress and check if it succeeded
+    let (decompressed_bytes, consumed_deflate_bytes) =
+        deflate_decompress::deflate_decode_bytes(deflate_data, max_output, token).map_err(|e| {
+            match e {
+                TransformError::Cancelled => e,
+                e => {
+                    TransformError::CompressionError(format!("DEFLATE decompression failed: {}", e))
+                }
+            }
+        })?;
+
+    // --- Parse Footer ---
+    // Since we successfully decompressed the DEFLATE stream, we need to extract the footer data
+    // Gzip footer is always 8 bytes (4 for CRC32, 4 for ISIZE) after the deflate stream
+    // We need to find the position right after the DEFLATE data to locate the footer
+
+    // Since the footer is 8 bytes, ensure we have enough data
+    // DEFLATE decoder should have stopped exactly at the end of the DEFLATE stream,
+    // the next 8 bytes should be the footer
+    let deflate_end_pos = header_len + consumed_deflate_bytes;
+
+    if compressed_bytes.len() < deflate_end_pos + 8 {
+        return Err(TransformError::CompressionError(
+            "Input too short for Gzip footer after DEFLATE stream".into(),
+        ));
+    }
+
+    let crc32_expected = u32::from_le_bytes(
+        compressed_bytes[deflate_end_pos..deflate_end_pos + 4]
+            .try_into()
+            .unwrap(),
+    );
+    let isize_expected = u32::from_le_bytes(
+        compressed_bytes[deflate_end_pos + 4..deflate_end_pos + 8]
+            .try_into()
+            .unwrap(),
+    );
+
+    // --- Verify Footer ---
+    let crc32_actual = calculate_crc32(&decompressed_bytes);
+    if crc32_actual != crc32_expected {
+        return Err(TransformError::CompressionError(format!(
+            "CRC32 checksum mismatch: expected {:08x}, got {:08x}",
+            crc32_expected, crc32_actual
+        )));
+    }
+
+    // ISIZE is the size of the original (uncompressed) input data modulo 2^32.
+    let isize_actual = (decompressed_bytes.len() as u64 % (1u64 << 32)) as u32;
+    if isize_actual != isize_expected {
+        return Err(TransformError::CompressionError(format!(
+            "ISIZE mismatch: expected {}, got {} (from decompressed length {})",
+            isize_expected,
+            isize_actual,
+            decompressed_bytes.len()
+        )));
+    }
+
+    Ok(decompressed_bytes)
 }
 
 #[cfg(test)]
@@ -275,6 +330,17 @@ mod tests {
         assert_eq!(result.unwrap(), expected_output);
     }
 
+    #[test]
+    fn test_decompress_standard_gzip() {
+        // Python's gzip.compress(..., mtime=0), which emits a dynamic Huffman block
+        let input = "H4sIAAAAAAACA82NSxaDIBAEr9K5gOfIMgsvADogCTKRr3B65+XlEK6runreCEdxywc6cgswfOJd9m8CV4rIgr0aHSvbCfM95JcSb+/QIjWXNxhXSdCgAO+OwlG2Nk14ckOl0wXr+z+/KpMxSEeVfgePC6ptZBgBAQAA";
+        assert_eq!(
+            GzipDecompress.transform(input).unwrap(),
+            "The quick brown fox jumps over the lazy dog. ".repeat(4)
+                + "Pack my box with five dozen liquor jugs. How vexingly quick daft zebras jump!"
+        );
+    }
+
     #[test]
     fn test_decompress_repeated() {
         let compressor = GzipCompress;
@@ -312,7 +378,7 @@ mod tests {
             0x2f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 255, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ]; // Marked unused
         let base64_input = "L4sIAAAAAAAAAAAPAwAAAAAAAAAAAA==";
-        let result = GzipDecompress.transform(&base64_input);
+        let result = GzipDecompress.transform(base64_input);
         assert!(matches!(result, Err(TransformError::CompressionError(_))));
         assert!(result
             .unwrap_err()
@@ -327,7 +393,7 @@ mod tests {
             0x1f, 0x8b, 9, 0, 0, 0, 0, 0, 0, 255, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ]; // Marked unused
         let base64_input = "H4sJAAAAAAAAAAADAAAAAAAAAAAA";
-        let result = GzipDecompress.transform(&base64_input);
+        let result = GzipDecompress.transform(base64_input);
         assert!(matches!(result, Err(TransformError::CompressionError(_))));
         assert!(result
             .unwrap_err()
@@ -378,7 +444,7 @@ mod tests {
         let _short_data = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0]; // Only 9 bytes (less than header)
                                                                  // let base64_input = base64_encode::base64_encode(&short_data); // Removed call
         let base64_input = "H4sIAAAAAAAAAAA="; // Manually encoded base64 of the 9 bytes
-        let result = GzipDecompress.transform(&base64_input);
+        let result = GzipDecompress.transform(base64_input);
         assert!(matches!(result, Err(TransformError::CompressionError(_))));
         assert!(result.unwrap_err().to_string().contains("Input too short"));
     }
@@ -411,4 +477,26 @@ mod tests {
     // REMOVED Helper function placeholders
     // fn base64_decode(input: &str, buffer: &mut Vec<u8>) -> Result<(), ()> { ... }
     // fn base64_encode(input: &[u8]) -> String { ... }
+
+    #[test]
+    fn test_gzip_output_limit() {
+        let input = "a".repeat(100_000);
+        let compressed = GzipCompress.transform_bytes(input.as_bytes()).unwrap();
+
+        let mut options = TransformOptions::new();
+        options.set("max_output", "99999");
+        assert!(GzipDecompress
+            .transform_bytes_with_options(&compressed, &options)
+            .is_err());
+        assert_eq!(
+            GzipDecompress.transform_bytes(&compressed).unwrap(),
+            input.as_bytes()
+        );
+
+        options.set("max_output", "lots");
+        assert!(matches!(
+            GzipDecompress.transform_bytes_with_options(&compressed, &options),
+            Err(TransformError::InvalidArgument(_))
+        ));
+    }
 }
@@ -0,0 +1,225 @@
+use super::base64_decode::base64_decode;
+use crate::utils::crc::{
+    CRC16_CCITT_FALSE, CRC16_KERMIT, CRC16_MODBUS, CRC16_XMODEM, CRC64_ECMA_182, CRC64_ISO,
+    CRC64_XZ,
+};
+use crate::utils::crc32::calculate_crc32;
+use crate::utils::{Digest, Md5, Sha1, Sha256};
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+type HashFn = fn(&[u8]) -> Vec<u8>;
+
+/// Every checksum the verifier tries, with how to compute it
+const ALGORITHMS: &[(&str, HashFn)] = &[
+    ("MD5", |data| Md5::digest(data).to_vec()),
+    ("SHA-1", |data| Sha1::digest(data).to_vec()),
+    ("SHA-256", |data| Sha256::digest(data).to_vec()),
+    ("CRC-32", |data| {
+        calculate_crc32(data).to_be_bytes().to_vec()
+    }),
+    ("CRC-16/CCITT-FALSE", |data| {
+        crc16(CRC16_CCITT_FALSE.checksum(data))
+    }),
+    ("CRC-16/KERMIT", |data| crc16(CRC16_KERMIT.checksum(data))),
+    ("CRC-16/XMODEM", |data| crc16(CRC16_XMODEM.checksum(data))),
+    ("CRC-16/MODBUS", |data| crc16(CRC16_MODBUS.checksum(data))),
+    ("CRC-64/ECMA-182", |data| {
+        CRC64_ECMA_182.checksum(data).to_be_bytes().to_vec()
+    }),
+    ("CRC-64/XZ", |data| {
+        CRC64_XZ.checksum(data).to_be_bytes().to_vec()
+    }),
+    ("CRC-64/GO-ISO", |data| {
+        CRC64_ISO.checksum(data).to_be_bytes().to_vec()
+    }),
+];
+
+fn crc16(crc: u64) -> Vec<u8> {
+    (crc as u16).to_be_bytes().to_vec()
+}
+
+/// Identifies which hash algorithm produced a checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashVerify;
+
+impl Transform for HashVerify {
+    fn name(&self) -> &'static str {
+        "Hash Verify"
+    }
+
+    fn id(&self) -> &'static str {
+        "hashverify"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks an expected hash (first line, hex or Base64) against the text after it with every supported algorithm and reports which one matches"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let output = self.transform_bytes(input.as_bytes())?;
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    // The data after the first line may be binary
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let (first_line, data) = match input.iter().position(|&byte| byte == b'\n') {
+            Some(end) => (&input[..end], &input[end + 1..]),
+            None => (input, &[][..]),
+        };
+        let first_line = std::str::from_utf8(first_line).map_err(|_| {
+            TransformError::InvalidArgument("The expected hash must be text".into())
+        })?;
+        let expected = parse_expected(first_line)?;
+
+        let candidates: Vec<_> = ALGORITHMS
+            .iter()
+            .map(|&(name, hash)| (name, hash(data)))
+            .filter(|(_, hash)| hash.len() == expected.len())
+            .collect();
+        if candidates.is_empty() {
+            return Err(TransformError::InvalidArgument(
+                format!(
+                    "No supported algorithm produces {}-bit hashes",
+                    expected.len() * 8
+                )
+                .into(),
+            ));
+        }
+        let matches: Vec<&str> = candidates
+            .iter()
+            .filter(|(_, hash)| *hash == expected)
+            .map(|&(name, _)| name)
+            .collect();
+        let names: Vec<&str> = candidates.iter().map(|&(name, _)| name).collect();
+        let report = if matches.is_empty() {
+            format!("No match (checked {})", names.join(", "))
+        } else {
+            format!("Match: {}", matches.join(", "))
+        };
+        Ok(report.into_bytes())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "5e884898da28047151d0e56f8dc6292773603d0d6aabbdd62a11ef721d1542d8\npassword"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "MD5 in uppercase",
+                input: "5F4DCC3B5AA765D61D8327DEB882CF99\npassword",
+            },
+            Example {
+                name: "sha256sum line",
+                input: "5e884898da28047151d0e56f8dc6292773603d0d6aabbdd62a11ef721d1542d8  password.txt\npassword",
+            },
+            Example {
+                name: "Tampered text",
+                input: "5e884898da28047151d0e56f8dc6292773603d0d6aabbdd62a11ef721d1542d8\nPassword",
+            },
+        ]
+    }
+}
+
+// The expected hash in hex or Base64, ignoring a following file name (as
+// in sha256sum output) and an algorithm prefix such as "sha256:" or the
+// "sha256-" of subresource integrity
+fn parse_expected(line: &str) -> Result<Vec<u8>, TransformError> {
+    let hash = line.split_whitespace().next().ok_or_else(|| {
+        TransformError::InvalidArgument(
+            "Expected the hash on the first line and the text after it".into(),
+        )
+    })?;
+    let hash = match hash.split_once([':', '-']) {
+        Some((algorithm, rest)) if algorithm.chars().all(|c| c.is_ascii_alphanumeric()) => rest,
+        _ => hash,
+    };
+    let hash = hash.strip_prefix("0x").unwrap_or(hash);
+    if hash.len() % 2 == 0 && hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Ok((0..hash.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hash[i..i + 2], 16).unwrap_or_default())
+            .collect());
+    }
+    base64_decode(hash).map_err(|_| {
+        TransformError::InvalidArgument(format!("'{}' is neither hex nor Base64", hash).into())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_verify_matches() {
+        assert_eq!(
+            HashVerify
+                .transform(HashVerify.default_test_input())
+                .unwrap(),
+            "Match: SHA-256"
+        );
+        assert_eq!(
+            HashVerify
+                .transform("5F4DCC3B5AA765D61D8327DEB882CF99\npassword")
+                .unwrap(),
+            "Match: MD5"
+        );
+        assert_eq!(
+            HashVerify.transform("cbf43926\n123456789").unwrap(),
+            "Match: CRC-32"
+        );
+        assert_eq!(
+            HashVerify.transform("0x4b37\n123456789").unwrap(),
+            "Match: CRC-16/MODBUS"
+        );
+    }
+
+    #[test]
+    fn test_hash_verify_formats() {
+        // Subresource integrity style Base64 and a sha256sum line
+        assert_eq!(
+            HashVerify
+                .transform("sha256-XohImNooBHFR0OVvjcYpJ3NgPQ1qq73WKhHvch0VQtg=\npassword")
+                .unwrap(),
+            "Match: SHA-256"
+        );
+        assert_eq!(
+            HashVerify
+                .transform("5baa61e4c9b93f3f0682250b6cf8331b7ee68fd8  pw.txt\npassword")
+                .unwrap(),
+            "Match: SHA-1"
+        );
+    }
+
+    #[test]
+    fn test_hash_verify_mismatch() {
+        assert_eq!(
+            HashVerify
+                .transform("5F4DCC3B5AA765D61D8327DEB882CF99\nPassword")
+                .unwrap(),
+            "No match (checked MD5)"
+        );
+        assert_eq!(
+            HashVerify.transform("0000\nbuup").unwrap(),
+            "No match (checked CRC-16/CCITT-FALSE, CRC-16/KERMIT, CRC-16/XMODEM, CRC-16/MODBUS)"
+        );
+        assert!(HashVerify.transform("abcdef\nbuup").is_err());
+        assert!(HashVerify.transform("!!!!\nbuup").is_err());
+        assert!(HashVerify.transform("").is_err());
+    }
+
+    #[test]
+    fn test_hash_verify_binary_data() {
+        let data = [0, 255, 128];
+        let mut input = format!("{:08x}\n", calculate_crc32(&data)).into_bytes();
+        input.extend_from_slice(&data);
+        assert_eq!(
+            HashVerify.transform_bytes(&input).unwrap(),
+            b"Match: CRC-32"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Hex decode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,25 +25,42 @@ impl Transform for HexDecode {
         "48656c6c6f2c20576f726c6421"
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Unicode text",
+            input: "48c3a96c6c6f2077c3b6726c6420f09f918b",
+        }]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        // Ignore whitespace in the input
-        let input = input.replace(' ', "");
+        let bytes = decode(input)?;
+        String::from_utf8(bytes).map_err(|_| TransformError::Utf8Error)
+    }
 
-        // Validate input is valid hex
-        if input.is_empty() {
-            return Ok(String::new());
-        }
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let text = std::str::from_utf8(input).map_err(|_| {
+            TransformError::HexDecodeError("Input contains non-hex characters".to_string())
+        })?;
+        decode(text)
+    }
+}
 
-        if !input.len().is_multiple_of(2) {
-            return Err(TransformError::HexDecodeError(
-                "Hex string must have an even length".to_string(),
-            ));
-        }
+// Decodes hex text to bytes, ignoring spaces
+pub(crate) fn decode(input: &str) -> Result<Vec<u8>, TransformError> {
+    let input = input.replace(' ', "");
 
-        let bytes = hex_decode(&input)?;
+    // Validate input is valid hex
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
 
-        String::from_utf8(bytes).map_err(|_| TransformError::Utf8Error)
+    if !input.len().is_multiple_of(2) {
+        return Err(TransformError::HexDecodeError(
+            "Hex string must have an even length".to_string(),
+        ));
     }
+
+    hex_decode(&input)
 }
 
 /// Decodes a hexadecimal string without external dependencies
@@ -104,4 +121,17 @@ mod tests {
         assert!(transformer.transform("4").is_err()); // Odd length
         assert!(transformer.transform("xy").is_err()); // Invalid characters
     }
+
+    #[test]
+    fn test_hex_decode_binary() {
+        let transformer = HexDecode;
+        assert_eq!(
+            transformer.transform("ff00"),
+            Err(TransformError::Utf8Error)
+        );
+        assert_eq!(
+            transformer.transform_bytes(b"ff 00").unwrap(),
+            vec![0xff, 0]
+        );
+    }
 }
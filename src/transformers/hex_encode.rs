@@ -1,4 +1,14 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const UPPERCASE_OPTION: TransformOption = TransformOption {
+    key: "uppercase",
+    description: "Use uppercase hex digits (A-F)",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
 
 /// Hex encode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,22 +32,47 @@ impl Transform for HexEncode {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        Ok(hex_encode(input.as_bytes()))
+        Ok(hex_encode(input.as_bytes(), false))
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[UPPERCASE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let uppercase = options.bool_value(&UPPERCASE_OPTION)?;
+        Ok(hex_encode(input.as_bytes(), uppercase))
     }
 
     fn default_test_input(&self) -> &'static str {
         "Hello, World!"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Unicode text",
+            input: "Héllo wörld 👋",
+        }]
+    }
 }
 
 /// Encodes bytes to hexadecimal without external dependencies
-fn hex_encode(input: &[u8]) -> String {
-    const HEX_CHARS: &[u8] = b"0123456789abcdef";
+fn hex_encode(input: &[u8], uppercase: bool) -> String {
+    let hex_chars: &[u8] = if uppercase {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
     let mut output = Vec::with_capacity(input.len() * 2);
 
     for &byte in input {
-        output.push(HEX_CHARS[(byte >> 4) as usize]);
-        output.push(HEX_CHARS[(byte & 0xf) as usize]);
+        output.push(hex_chars[(byte >> 4) as usize]);
+        output.push(hex_chars[(byte & 0xf) as usize]);
     }
 
     String::from_utf8(output).unwrap()
@@ -60,4 +95,19 @@ mod tests {
         assert_eq!(transformer.transform("a").unwrap(), "61");
         assert_eq!(transformer.transform("AB").unwrap(), "4142");
     }
+
+    #[test]
+    fn test_hex_encode_uppercase_option() {
+        let transformer = HexEncode;
+        let mut options = TransformOptions::new();
+        options.set("uppercase", "true");
+        assert_eq!(
+            transformer
+                .transform_with_options("\u{ff}", &options)
+                .unwrap(),
+            "C3BF"
+        );
+        options.set("uppercase", "yes");
+        assert!(transformer.transform_with_options("a", &options).is_err());
+    }
 }
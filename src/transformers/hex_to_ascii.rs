@@ -34,33 +34,44 @@ impl Transform for HexToAscii {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        // Ensure input has an even number of characters
-        if !input.len().is_multiple_of(2) {
-            return Err(TransformError::InvalidArgument(
-                "Input hex string must have an even number of characters".into(),
-            ));
-        }
+        let bytes = decode(input)?;
+        String::from_utf8(bytes).map_err(|_| TransformError::Utf8Error)
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let text = std::str::from_utf8(input).map_err(|_| {
+            TransformError::InvalidArgument("Input contains non-hex characters".into())
+        })?;
+        decode(text)
+    }
+}
 
-        // Remove common prefixes like 0x or spaces
-        let cleaned_input = input.trim().trim_start_matches("0x");
-
-        let mut bytes = Vec::with_capacity(cleaned_input.len() / 2);
-        let mut chars = cleaned_input.chars();
-
-        while let (Some(h), Some(l)) = (chars.next(), chars.next()) {
-            let hex_pair = format!("{}{}", h, l);
-            match u8::from_str_radix(&hex_pair, 16) {
-                Ok(byte) => bytes.push(byte),
-                Err(_) => {
-                    return Err(TransformError::InvalidArgument(
-                        format!("Invalid hex character sequence found: '{}'", hex_pair).into(),
-                    ))
-                }
+fn decode(input: &str) -> Result<Vec<u8>, TransformError> {
+    // Ensure input has an even number of characters
+    if !input.len().is_multiple_of(2) {
+        return Err(TransformError::InvalidArgument(
+            "Input hex string must have an even number of characters".into(),
+        ));
+    }
+
+    // Remove common prefixes like 0x or spaces
+    let cleaned_input = input.trim().trim_start_matches("0x");
+
+    let mut bytes = Vec::with_capacity(cleaned_input.len() / 2);
+    let mut chars = cleaned_input.chars();
+
+    while let (Some(h), Some(l)) = (chars.next(), chars.next()) {
+        let hex_pair = format!("{}{}", h, l);
+        match u8::from_str_radix(&hex_pair, 16) {
+            Ok(byte) => bytes.push(byte),
+            Err(_) => {
+                return Err(TransformError::InvalidArgument(
+                    format!("Invalid hex character sequence found: '{}'", hex_pair).into(),
+                ))
             }
         }
-
-        String::from_utf8(bytes).map_err(|_| TransformError::Utf8Error)
     }
+    Ok(bytes)
 }
 
 #[cfg(test)]
@@ -111,6 +122,10 @@ mod tests {
             transformer.transform("c0"),
             Err(TransformError::Utf8Error)
         )); // Overlong encoding start
+        assert_eq!(
+            transformer.transform_bytes(b"80c0").unwrap(),
+            vec![0x80, 0xc0]
+        );
     }
 
     #[test]
@@ -0,0 +1,112 @@
+use super::float_to_hex::{F32, F64};
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// IEEE 754 hex to decimal converter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexToFloat;
+
+impl Transform for HexToFloat {
+    fn name(&self) -> &'static str {
+        "Hex to Float"
+    }
+
+    fn id(&self) -> &'static str {
+        "hex_to_float"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reads 8 hex digits as an f32 or 16 as an f64 (IEEE 754) and shows the value with its sign, exponent and mantissa"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        // Also accepts the output of Float to Hex, whose first line is the hex
+        let line = input.trim_start().lines().next().unwrap_or_default();
+        let line = line.strip_prefix("Hex:").unwrap_or(line).trim();
+        let digits: String = line
+            .strip_prefix("0x")
+            .or_else(|| line.strip_prefix("0X"))
+            .unwrap_or(line)
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '_'))
+            .collect();
+        let format = match digits.len() {
+            8 => &F32,
+            16 => &F64,
+            _ => {
+                return Err(TransformError::InvalidArgument(
+                    format!(
+                        "Expected 8 hex digits (f32) or 16 (f64), got {}",
+                        digits.len()
+                    )
+                    .into(),
+                ))
+            }
+        };
+        let bits = u64::from_str_radix(&digits, 16).map_err(|_| {
+            TransformError::HexDecodeError(format!("'{}' is not a hex number", line))
+        })?;
+        Ok(format.describe(bits))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "0x400921F9F01B866E"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Single precision",
+                input: "40490FDB",
+            },
+            Example {
+                name: "Quiet NaN",
+                input: "7FC00000",
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::FloatToHex;
+
+    #[test]
+    fn test_hex_to_float() {
+        assert!(HexToFloat
+            .transform(HexToFloat.default_test_input())
+            .unwrap()
+            .starts_with("Value: 3.14159\nClass: normal\n"));
+        assert_eq!(
+            HexToFloat.transform("4049 0FDB").unwrap(),
+            "Value: 3.1415927\n\
+             Class: normal\n\
+             Sign: 0 (+)\n\
+             Exponent: 0x80 (128, 2^1)\n\
+             Mantissa: 0x490FDB\n\
+             Binary: 0 10000000 10010010000111111011011"
+        );
+        assert!(HexToFloat
+            .transform("7fc00000")
+            .unwrap()
+            .starts_with("Value: NaN\nClass: NaN\n"));
+    }
+
+    #[test]
+    fn test_hex_to_float_reads_float_to_hex_output() {
+        let hex = FloatToHex.transform("-1234.5678").unwrap();
+        let float = HexToFloat.transform(&hex).unwrap();
+        assert_eq!(hex.split_once('\n').unwrap().1, float);
+    }
+
+    #[test]
+    fn test_hex_to_float_invalid() {
+        assert!(HexToFloat.transform("1234").is_err());
+        assert!(HexToFloat.transform("4049GFDB").is_err());
+        assert!(HexToFloat.transform("").is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// Hex to HSV color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexToHsv;
+
+impl Transform for HexToHsv {
+    fn name(&self) -> &'static str {
+        "Hex to HSV"
+    }
+
+    fn id(&self) -> &'static str {
+        "hex_to_hsv"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts hex color code to HSV (HSB) format"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with('#') {
+            return Err(TransformError::InvalidArgument(
+                "Invalid hex color format. Must start with #".into(),
+            ));
+        }
+
+        let color = Color::from_hex(input)?;
+        Ok(color.to_hsv())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "#FF8000"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_to_hsv() {
+        let transformer = HexToHsv;
+        assert_eq!(
+            transformer.transform("#FF0000").unwrap(),
+            "hsv(0deg,100%,100%)"
+        );
+        assert_eq!(
+            transformer.transform("#FF8000").unwrap(),
+            "hsv(30deg,100%,100%)"
+        );
+        assert_eq!(
+            transformer.transform("#336699").unwrap(),
+            "hsv(210deg,67%,60%)"
+        );
+        assert_eq!(transformer.transform("#000000").unwrap(), "hsv(0deg,0%,0%)");
+    }
+
+    #[test]
+    fn test_with_alpha() {
+        let transformer = HexToHsv;
+        assert_eq!(
+            transformer.transform("#00FF0080").unwrap(),
+            "hsv(120deg,100%,100%,0.50)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = HexToHsv;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("FF0000").is_err()); // Missing #
+        assert!(transformer.transform("#GG0000").is_err()); // Invalid hex
+        assert!(transformer.transform("#🎉F0a0").is_err()); // Six bytes, not six digits
+        assert!(transformer.transform("#F0🎉a0").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// Hex to Lab color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexToLab;
+
+impl Transform for HexToLab {
+    fn name(&self) -> &'static str {
+        "Hex to Lab"
+    }
+
+    fn id(&self) -> &'static str {
+        "hex_to_lab"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts hex color code to CIELAB (CSS lab(), D50)"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with('#') {
+            return Err(TransformError::InvalidArgument(
+                "Invalid hex color format. Must start with #".into(),
+            ));
+        }
+
+        let color = Color::from_hex(input)?;
+        Ok(color.to_lab())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "#FF0000"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_to_lab() {
+        let transformer = HexToLab;
+        assert_eq!(
+            transformer.transform("#FF0000").unwrap(),
+            "lab(54.29% 80.80 69.89)"
+        );
+        assert_eq!(
+            transformer.transform("#FFFFFF").unwrap(),
+            "lab(100.00% 0.00 0.00)"
+        );
+        assert_eq!(
+            transformer.transform("#000000").unwrap(),
+            "lab(0.00% 0.00 0.00)"
+        );
+        assert_eq!(
+            transformer.transform("#336699").unwrap(),
+            "lab(41.52% -4.57 -33.49)"
+        );
+        assert_eq!(
+            transformer.transform("#0000FF80").unwrap(),
+            "lab(29.57% 68.29 -112.03 / 0.50)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = HexToLab;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("FF0000").is_err());
+        assert!(transformer.transform("#GG0000").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::utils::Color;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// Hex to nearest CSS color name transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexToNearestColorName;
+
+impl Transform for HexToNearestColorName {
+    fn name(&self) -> &'static str {
+        "Hex to Nearest Color Name"
+    }
+
+    fn id(&self) -> &'static str {
+        "hex_to_nearest_color_name"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Finds the CSS color name nearest to a hex color, with its distance (CIE76 ΔE, 0 is exact)"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with('#') {
+            return Err(TransformError::InvalidArgument(
+                "Invalid hex color format. Must start with #".into(),
+            ));
+        }
+
+        let color = Color::from_hex(input)?;
+        let (name, named, distance) = color.nearest_name();
+        Ok(format!("{} {} (ΔE {:.2})", name, named.to_hex(), distance))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "#6495ED"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Brand blue",
+            input: "#1E88E5",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_match() {
+        let transformer = HexToNearestColorName;
+        assert_eq!(
+            transformer.transform("#6495ED").unwrap(),
+            "cornflowerblue #6495ed (ΔE 0.00)"
+        );
+        assert_eq!(
+            transformer.transform("#663399").unwrap(),
+            "rebeccapurple #663399 (ΔE 0.00)"
+        );
+        // Duplicate colors resolve to the first name alphabetically
+        assert_eq!(
+            transformer.transform("#00ffff").unwrap(),
+            "aqua #00ffff (ΔE 0.00)"
+        );
+        // Alpha is ignored
+        assert_eq!(
+            transformer.transform("#ff000080").unwrap(),
+            "red #ff0000 (ΔE 0.00)"
+        );
+    }
+
+    #[test]
+    fn test_nearest_match() {
+        let transformer = HexToNearestColorName;
+        assert_eq!(
+            transformer.transform("#fe0101").unwrap(),
+            "red #ff0000 (ΔE 0.59)"
+        );
+        assert!(transformer
+            .transform("#1E88E5")
+            .unwrap()
+            .starts_with("dodgerblue #1e90ff"));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = HexToNearestColorName;
+        assert!(transformer.transform("red").is_err());
+        assert!(transformer.transform("#GG0000").is_err());
+    }
+}
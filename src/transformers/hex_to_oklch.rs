@@ -0,0 +1,78 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// Hex to OKLCH color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexToOklch;
+
+impl Transform for HexToOklch {
+    fn name(&self) -> &'static str {
+        "Hex to OKLCH"
+    }
+
+    fn id(&self) -> &'static str {
+        "hex_to_oklch"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts hex color code to OKLCH (CSS oklch())"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with('#') {
+            return Err(TransformError::InvalidArgument(
+                "Invalid hex color format. Must start with #".into(),
+            ));
+        }
+
+        let color = Color::from_hex(input)?;
+        Ok(color.to_oklch())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "#FF0000"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_to_oklch() {
+        let transformer = HexToOklch;
+        assert_eq!(
+            transformer.transform("#FF0000").unwrap(),
+            "oklch(62.80% 0.2577 29.23)"
+        );
+        assert_eq!(
+            transformer.transform("#FFFFFF").unwrap(),
+            "oklch(100.00% 0.0000 0.00)"
+        );
+        assert_eq!(
+            transformer.transform("#808080").unwrap(),
+            "oklch(59.99% 0.0000 0.00)"
+        );
+        assert_eq!(
+            transformer.transform("#336699").unwrap(),
+            "oklch(49.93% 0.0987 250.43)"
+        );
+        assert_eq!(
+            transformer.transform("#00FF0080").unwrap(),
+            "oklch(86.64% 0.2948 142.50 / 0.50)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = HexToOklch;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("FF0000").is_err());
+        assert!(transformer.transform("#GG0000").is_err());
+    }
+}
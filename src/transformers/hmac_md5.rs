@@ -0,0 +1,87 @@
+use super::hmac_sha256::{hmac_signature, HMAC_EXAMPLES, HMAC_OPTIONS};
+use crate::utils::Md5;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// HMAC-MD5 transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HmacMd5;
+
+impl Transform for HmacMd5 {
+    fn name(&self) -> &'static str {
+        "HMAC-MD5"
+    }
+
+    fn id(&self) -> &'static str {
+        "hmac_md5"
+    }
+
+    fn description(&self) -> &'static str {
+        "Signs the input with HMAC-MD5 using the key option, for APIs that still use it"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        HMAC_OPTIONS
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        hmac_signature::<Md5>(input.as_bytes(), options)
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        Ok(hmac_signature::<Md5>(input, options)?.into_bytes())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "The quick brown fox jumps over the lazy dog"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        HMAC_EXAMPLES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_md5() {
+        let mut options = TransformOptions::new();
+        options.set("key", "key");
+        let input = "The quick brown fox jumps over the lazy dog";
+        assert_eq!(
+            HmacMd5.transform_with_options(input, &options).unwrap(),
+            "80070713463e7749b90c2dc24911e275"
+        );
+        options.set("output", "base64");
+        assert_eq!(
+            HmacMd5.transform_with_options(input, &options).unwrap(),
+            "gAcHE0Y+d0m5DC3CSRHidQ=="
+        );
+    }
+}
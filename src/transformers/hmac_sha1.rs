@@ -0,0 +1,87 @@
+use super::hmac_sha256::{hmac_signature, HMAC_EXAMPLES, HMAC_OPTIONS};
+use crate::utils::Sha1;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// HMAC-SHA1 transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HmacSha1;
+
+impl Transform for HmacSha1 {
+    fn name(&self) -> &'static str {
+        "HMAC-SHA1"
+    }
+
+    fn id(&self) -> &'static str {
+        "hmac_sha1"
+    }
+
+    fn description(&self) -> &'static str {
+        "Signs the input with HMAC-SHA1 using the key option, for APIs that still use it"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        HMAC_OPTIONS
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        hmac_signature::<Sha1>(input.as_bytes(), options)
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        Ok(hmac_signature::<Sha1>(input, options)?.into_bytes())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "The quick brown fox jumps over the lazy dog"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        HMAC_EXAMPLES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha1() {
+        let mut options = TransformOptions::new();
+        options.set("key", "key");
+        let input = "The quick brown fox jumps over the lazy dog";
+        assert_eq!(
+            HmacSha1.transform_with_options(input, &options).unwrap(),
+            "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9"
+        );
+        options.set("output", "base64");
+        assert_eq!(
+            HmacSha1.transform_with_options(input, &options).unwrap(),
+            "3nybhbi3iqa8ino29wqQcBydtNk="
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use super::{base64_decode, base64_encode::base64_encode, hex_decode};
+use crate::utils::digest::{hmac, to_hex, Digest};
+use crate::utils::Sha256;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+pub(crate) const KEY_OPTION: TransformOption = TransformOption {
+    key: "key",
+    description: "Secret key",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+pub(crate) const KEY_FORMAT_OPTION: TransformOption = TransformOption {
+    key: "key_format",
+    description: "How the key is written: as text, hex or Base64",
+    default: "text",
+    kind: TransformOptionKind::Choice(&["text", "hex", "base64"]),
+};
+
+pub(crate) const OUTPUT_OPTION: TransformOption = TransformOption {
+    key: "output",
+    description: "Encoding of the signature",
+    default: "hex",
+    kind: TransformOptionKind::Choice(&["hex", "base64"]),
+};
+
+/// The options shared by the HMAC transformers
+pub(crate) const HMAC_OPTIONS: &[TransformOption] = &[KEY_OPTION, KEY_FORMAT_OPTION, OUTPUT_OPTION];
+
+pub(crate) const HMAC_EXAMPLES: &[Example] = &[Example {
+    name: "Webhook payload",
+    input: r#"{"event":"push","ref":"refs/heads/main"}"#,
+}];
+
+/// Signs `message` with the key from `options`, encoded as they ask
+pub(crate) fn hmac_signature<D: Digest>(
+    message: &[u8],
+    options: &TransformOptions,
+) -> Result<String, TransformError> {
+    let key = key_bytes(options, &KEY_OPTION)?;
+    let signature = hmac::<D>(&key, message);
+    Ok(match options.value(&OUTPUT_OPTION) {
+        "base64" => base64_encode(signature.as_ref()),
+        _ => to_hex(signature.as_ref()),
+    })
+}
+
+/// The value of `key_option` decoded as `key_format` says
+pub(crate) fn key_bytes(
+    options: &TransformOptions,
+    key_option: &TransformOption,
+) -> Result<Vec<u8>, TransformError> {
+    let key = options.value(key_option);
+    Ok(match options.value(&KEY_FORMAT_OPTION) {
+        "hex" => hex_decode::decode(key.trim())?,
+        "base64" => base64_decode::base64_decode(key.trim()).map_err(|e| {
+            TransformError::InvalidArgument(format!("Invalid Base64 key: {}", e).into())
+        })?,
+        _ => key.as_bytes().to_vec(),
+    })
+}
+
+/// HMAC-SHA256 transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HmacSha256;
+
+impl Transform for HmacSha256 {
+    fn name(&self) -> &'static str {
+        "HMAC-SHA256"
+    }
+
+    fn id(&self) -> &'static str {
+        "hmac_sha256"
+    }
+
+    fn description(&self) -> &'static str {
+        "Signs the input with HMAC-SHA256 using the key option, e.g. to check webhook signatures"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        HMAC_OPTIONS
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        hmac_signature::<Sha256>(input.as_bytes(), options)
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        Ok(hmac_signature::<Sha256>(input, options)?.into_bytes())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "what do ya want for nothing?"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        HMAC_EXAMPLES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha256() {
+        let message = "what do ya want for nothing?";
+        let expected = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+        assert_eq!(
+            HmacSha256
+                .transform_with_options(message, &TransformOptions::from_iter([("key", "Jefe")]))
+                .unwrap(),
+            expected
+        );
+        // The same key written in hex and Base64
+        assert_eq!(
+            HmacSha256
+                .transform_with_options(
+                    message,
+                    &TransformOptions::from_iter([("key", "4a656665"), ("key_format", "hex")])
+                )
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            HmacSha256
+                .transform_with_options(
+                    message,
+                    &TransformOptions::from_iter([("key", "SmVmZQ=="), ("key_format", "base64")])
+                )
+                .unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha256_base64_output() {
+        assert_eq!(
+            HmacSha256
+                .transform_with_options(
+                    "what do ya want for nothing?",
+                    &TransformOptions::from_iter([("key", "Jefe"), ("output", "base64")])
+                )
+                .unwrap(),
+            "W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM="
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha256_invalid_key() {
+        assert!(HmacSha256
+            .transform_with_options(
+                "data",
+                &TransformOptions::from_iter([("key", "xyz"), ("key_format", "hex")])
+            )
+            .is_err());
+        assert!(HmacSha256
+            .transform_with_options(
+                "data",
+                &TransformOptions::from_iter([("key", "@@"), ("key_format", "base64")])
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// HSV to Hex color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HsvToHex;
+
+impl Transform for HsvToHex {
+    fn name(&self) -> &'static str {
+        "HSV to Hex"
+    }
+
+    fn id(&self) -> &'static str {
+        "hsv_to_hex"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts HSV (HSB) color to hex format"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with("hsv(") && !input.starts_with("hsb(") {
+            return Err(TransformError::InvalidArgument(
+                "Invalid HSV format. Must start with hsv( or hsb(".into(),
+            ));
+        }
+
+        let color = Color::from_hsv(input)?;
+        Ok(color.to_hex())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "hsv(30deg, 100%, 100%)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hsv_to_hex() {
+        let transformer = HsvToHex;
+        assert_eq!(
+            transformer.transform("hsv(0deg, 100%, 100%)").unwrap(),
+            "#ff0000"
+        );
+        assert_eq!(
+            transformer.transform("hsv(30deg, 100%, 100%)").unwrap(),
+            "#ff8000"
+        );
+        assert_eq!(
+            transformer.transform("hsb(210, 66.67%, 60%)").unwrap(),
+            "#336699"
+        );
+        assert_eq!(
+            transformer.transform("hsv(-90deg, 100%, 100%)").unwrap(),
+            "#8000ff"
+        );
+    }
+
+    #[test]
+    fn test_with_alpha() {
+        let transformer = HsvToHex;
+        assert_eq!(
+            transformer
+                .transform("hsv(120deg, 100%, 100%, 0.5)")
+                .unwrap(),
+            "#00ff0080"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = HsvToHex;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("hsl(0deg, 100%, 50%)").is_err()); // Wrong model
+        assert!(transformer.transform("hsv(0deg, 100%, abc)").is_err());
+    }
+}
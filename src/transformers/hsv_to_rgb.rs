@@ -0,0 +1,90 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// HSV to RGB color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HsvToRgb;
+
+impl Transform for HsvToRgb {
+    fn name(&self) -> &'static str {
+        "HSV to RGB"
+    }
+
+    fn id(&self) -> &'static str {
+        "hsv_to_rgb"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts HSV (HSB) color to RGB format"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with("hsv(") && !input.starts_with("hsb(") {
+            return Err(TransformError::InvalidArgument(
+                "Invalid HSV format. Must start with hsv( or hsb(".into(),
+            ));
+        }
+
+        let color = Color::from_hsv(input)?;
+        Ok(color.to_rgb())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "hsv(30deg, 100%, 100%)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hsv_to_rgb() {
+        let transformer = HsvToRgb;
+        assert_eq!(
+            transformer.transform("hsv(0deg, 100%, 100%)").unwrap(),
+            "rgb(255,0,0)"
+        );
+        assert_eq!(
+            transformer.transform("hsv(120deg, 100%, 50%)").unwrap(),
+            "rgb(0,128,0)"
+        );
+        assert_eq!(
+            transformer.transform("hsb(240, 50%, 100%)").unwrap(),
+            "rgb(128,128,255)"
+        );
+        assert_eq!(
+            transformer.transform("hsv(0deg, 0%, 0%)").unwrap(),
+            "rgb(0,0,0)"
+        );
+        assert_eq!(
+            transformer.transform("hsv(360deg, 0%, 100%)").unwrap(),
+            "rgb(255,255,255)"
+        );
+    }
+
+    #[test]
+    fn test_with_alpha() {
+        let transformer = HsvToRgb;
+        assert_eq!(
+            transformer
+                .transform("hsv(300deg, 100%, 100%, 0.5)")
+                .unwrap(),
+            "rgb(255,0,255,128)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = HsvToRgb;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("0deg, 100%, 100%").is_err()); // Missing hsv(
+        assert!(transformer.transform("hsv(0deg, 150%, 100%)").is_err()); // Out of range
+        assert!(transformer.transform("hsv(0deg, 100%)").is_err());
+    }
+}
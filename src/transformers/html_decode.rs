@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// HTML decode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,6 +28,19 @@ impl Transform for HtmlDecode {
         "&lt;p&gt;Hello &amp; Welcome!&lt;&#47;p&gt;"
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Escaped markup",
+                input: "&lt;a href=&quot;https://buup.io&quot;&gt;buup&lt;/a&gt;",
+            },
+            Example {
+                name: "Numeric entities",
+                input: "Caf&#233; &#x2615; &amp; cr&#232;me",
+            },
+        ]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
         if input.is_empty() {
             return Ok(String::new());
@@ -74,7 +87,7 @@ impl Transform for HtmlDecode {
 }
 
 // Decodes a single HTML entity to a character
-fn decode_html_entity(entity: &str) -> Option<char> {
+pub(crate) fn decode_html_entity(entity: &str) -> Option<char> {
     match entity {
         "&amp;" => Some('&'),
         "&lt;" => Some('<'),
@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// HTML encode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,6 +25,19 @@ impl Transform for HtmlEncode {
         "<p>Hello & Welcome!</p>"
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Script tag",
+                input: "<script>alert(\"hi\")</script>",
+            },
+            Example {
+                name: "Quotes and ampersands",
+                input: "Tom & Jerry's \"Cat & Mouse\" <show>",
+            },
+        ]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
         let mut result = String::with_capacity(input.len() * 2);
 
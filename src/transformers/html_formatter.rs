@@ -0,0 +1,630 @@
+use super::json_formatter::{indent_value, DEFAULT_INDENT, INDENT_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// Widest line (indentation included) an element is kept on one line for
+const MAX_INLINE_WIDTH: usize = 80;
+
+/// Elements that never have content
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose content is copied through untouched
+const RAW_TEXT_ELEMENTS: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// Elements that flow with the surrounding text instead of starting a line
+const INLINE_ELEMENTS: [&str; 31] = [
+    "a", "abbr", "b", "bdi", "bdo", "br", "button", "cite", "code", "data", "del", "dfn", "em",
+    "i", "img", "input", "ins", "kbd", "label", "mark", "q", "s", "samp", "select", "small",
+    "span", "strong", "sub", "sup", "time", "u",
+];
+
+/// HTML Formatter transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HtmlFormatter;
+
+impl Transform for HtmlFormatter {
+    fn name(&self) -> &'static str {
+        "HTML Formatter"
+    }
+
+    fn id(&self) -> &'static str {
+        "htmlformatter"
+    }
+
+    fn description(&self) -> &'static str {
+        "Formats (pretty-prints) HTML, keeping inline markup on its line and the contents of pre, script and style untouched."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Formatter
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>buup</title></head><body><h1>Hello</h1><p>Some <b>bold</b> text<br>and a <a href=\"/docs\">link</a>.</p><ul><li>One<li>Two</ul></body></html>"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Form",
+                input: "<form action=\"/login\" method=\"post\"><label>Email <input type=\"email\" name=\"email\"></label><label>Password <input type=\"password\" name=\"password\"></label><button type=\"submit\">Sign in</button></form>",
+            },
+            Example {
+                name: "Code sample",
+                input: "<article><h2>Usage</h2><p>Run it with <code>cargo run</code>:</p><pre>$ cargo run\n   Compiling buup\n</pre><script>document.title = \"Usage\";</script></article>",
+            },
+        ]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        format(input, DEFAULT_INDENT, token)
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[INDENT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        format(input, indent_value(options)?, token)
+    }
+}
+
+// Parses `input` into a tree and prints it, nesting each element's children
+// one level deeper unless the element fits on one line
+fn format(input: &str, indent: usize, cancel: &CancelToken) -> Result<String, TransformError> {
+    let tokens = tokenize(input, cancel)?;
+    let nodes = build_tree(input, tokens)?;
+    let mut printer = Printer {
+        indent,
+        out: String::new(),
+    };
+    printer.write_children(&nodes, 0);
+    Ok(printer.out)
+}
+
+/// A piece of HTML as the tokenizer sees it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    Text(&'a str),
+    /// Contents of a `<pre>`, `<script>`, `<style>` or `<textarea>`
+    Raw(&'a str),
+    Comment(&'a str),
+    /// `<!DOCTYPE ...>` and other `<!...>` or `<?...>` markup
+    Declaration(&'a str),
+    /// `tag` is the tag as written, with runs of whitespace outside
+    /// attribute values collapsed
+    Open {
+        name: String,
+        tag: String,
+        self_closing: bool,
+    },
+    /// `start` is the byte offset of the `<`
+    Close {
+        name: String,
+        tag: String,
+        start: usize,
+    },
+}
+
+/// Splits HTML into tags, text, comments and raw element contents
+pub(crate) fn tokenize<'a>(
+    input: &'a str,
+    cancel: &CancelToken,
+) -> Result<Vec<Token<'a>>, TransformError> {
+    let bytes = input.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    let mut text_start = 0;
+    let mut iteration = 0;
+
+    while pos < input.len() {
+        cancel.check_every(iteration)?;
+        iteration += 1;
+        if bytes[pos] != b'<' {
+            pos += 1;
+            continue;
+        }
+        let rest = &input[pos..];
+        let after = |offset: usize| bytes.get(pos + offset).copied().unwrap_or(0);
+        let (token, end) = if let Some(comment) = rest.strip_prefix("<!--") {
+            let end = comment.find("-->").map_or(input.len(), |i| pos + i + 7);
+            (Token::Comment(&input[pos..end]), end)
+        } else if after(1) == b'!' || after(1) == b'?' {
+            let end = rest.find('>').map_or(input.len(), |i| pos + i + 1);
+            (Token::Declaration(&input[pos..end]), end)
+        } else if after(1).is_ascii_alphabetic()
+            || (after(1) == b'/' && after(2).is_ascii_alphabetic())
+        {
+            let end = pos
+                + tag_len(rest).ok_or_else(|| {
+                    TransformError::InvalidArgument(
+                        format!("Unterminated tag at position {}", char_position(input, pos))
+                            .into(),
+                    )
+                })?;
+            let (name, tag) = normalize_tag(&input[pos..end]);
+            if after(1) == b'/' {
+                (
+                    Token::Close {
+                        name,
+                        tag,
+                        start: pos,
+                    },
+                    end,
+                )
+            } else {
+                let self_closing = tag.ends_with("/>");
+                (
+                    Token::Open {
+                        name,
+                        tag,
+                        self_closing,
+                    },
+                    end,
+                )
+            }
+        } else {
+            // A '<' that doesn't start markup, as in "a < b"
+            pos += 1;
+            continue;
+        };
+
+        if text_start < pos {
+            tokens.push(Token::Text(&input[text_start..pos]));
+        }
+        pos = end;
+        text_start = end;
+
+        // Everything up to the matching end tag is content, not markup
+        if let Token::Open {
+            name,
+            self_closing: false,
+            ..
+        } = &token
+        {
+            if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                let close = format!("</{}", name);
+                let len = input[pos..]
+                    .to_ascii_lowercase()
+                    .find(&close)
+                    .unwrap_or(input.len() - pos);
+                tokens.push(token);
+                if len > 0 {
+                    tokens.push(Token::Raw(&input[pos..pos + len]));
+                }
+                pos += len;
+                text_start = pos;
+                continue;
+            }
+        }
+        tokens.push(token);
+    }
+
+    if text_start < input.len() {
+        tokens.push(Token::Text(&input[text_start..]));
+    }
+    Ok(tokens)
+}
+
+// Length of the tag at the start of `text`, up to and including the '>' that
+// isn't inside a quoted attribute value
+fn tag_len(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+// The lowercase element name and the tag with whitespace outside attribute
+// values collapsed, e.g. ("a", "<a href=\"x\">") for "<A\n  href=\"x\" >"
+fn normalize_tag(tag: &str) -> (String, String) {
+    let inner = &tag[1..tag.len() - 1];
+    let name_start = usize::from(inner.starts_with('/'));
+    let name: String = inner[name_start..]
+        .chars()
+        .take_while(|c| !c.is_ascii_whitespace() && *c != '/')
+        .collect();
+
+    let mut out = String::from("<");
+    let mut quote = None;
+    let mut pending_space = false;
+    for c in inner.chars() {
+        if quote.is_none() && c.is_ascii_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            out.push(' ');
+        }
+        pending_space = false;
+        match quote {
+            Some(q) if c == q => quote = None,
+            None if c == '"' || c == '\'' => quote = Some(c),
+            _ => {}
+        }
+        out.push(c);
+    }
+    (name.to_ascii_lowercase(), out + ">")
+}
+
+// 1-based character position of the byte offset `byte`
+fn char_position(input: &str, byte: usize) -> usize {
+    input[..byte].chars().count() + 1
+}
+
+pub(crate) fn is_inline_element(name: &str) -> bool {
+    INLINE_ELEMENTS.contains(&name)
+}
+
+/// Pushes `text` with every run of HTML whitespace replaced by one space
+pub(crate) fn push_collapsed(out: &mut String, text: &str) {
+    let mut in_space = false;
+    for c in text.chars() {
+        if c.is_ascii_whitespace() {
+            if !in_space {
+                out.push(' ');
+            }
+            in_space = true;
+        } else {
+            out.push(c);
+            in_space = false;
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Node<'a> {
+    Text(&'a str),
+    Raw(&'a str),
+    Comment(&'a str),
+    Declaration(&'a str),
+    Element(Element<'a>),
+}
+
+#[derive(Debug)]
+struct Element<'a> {
+    name: String,
+    open: String,
+    /// `None` for void and self-closed elements
+    close: Option<String>,
+    children: Vec<Node<'a>>,
+}
+
+// Whether the element `open` ends where a `next` element starts, as in
+// `<li>One<li>Two`
+fn closed_by(open: &str, next: &str) -> bool {
+    match open {
+        "p" | "li" | "option" | "tr" => open == next,
+        "dt" | "dd" => matches!(next, "dt" | "dd"),
+        "td" | "th" => matches!(next, "td" | "th" | "tr"),
+        "thead" | "tbody" => matches!(next, "tbody" | "tfoot"),
+        _ => false,
+    }
+}
+
+// Nests the tokens into elements, closing the ones the markup leaves open
+fn build_tree<'a>(input: &str, tokens: Vec<Token<'a>>) -> Result<Vec<Node<'a>>, TransformError> {
+    let mut root = Vec::new();
+    let mut stack: Vec<Element<'a>> = Vec::new();
+
+    fn close_top<'a>(stack: &mut Vec<Element<'a>>, root: &mut Vec<Node<'a>>) {
+        if let Some(element) = stack.pop() {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(Node::Element(element)),
+                None => root.push(Node::Element(element)),
+            }
+        }
+    }
+
+    for token in tokens {
+        let node = match token {
+            Token::Open {
+                name,
+                tag,
+                self_closing,
+            } => {
+                while stack.last().is_some_and(|top| closed_by(&top.name, &name)) {
+                    close_top(&mut stack, &mut root);
+                }
+                let written = &tag[1..=name.len()];
+                let close = (!self_closing && !VOID_ELEMENTS.contains(&name.as_str()))
+                    .then(|| format!("</{}>", written));
+                let element = Element {
+                    name,
+                    open: tag,
+                    close,
+                    children: Vec::new(),
+                };
+                if element.close.is_some() {
+                    stack.push(element);
+                    continue;
+                }
+                Node::Element(element)
+            }
+            Token::Close { name, tag, start } => {
+                match stack.iter().rposition(|element| element.name == name) {
+                    Some(index) => {
+                        while stack.len() > index + 1 {
+                            close_top(&mut stack, &mut root);
+                        }
+                        stack[index].close = Some(tag);
+                        close_top(&mut stack, &mut root);
+                    }
+                    // `</br>` and friends close nothing
+                    None if VOID_ELEMENTS.contains(&name.as_str()) => {}
+                    None => {
+                        return Err(TransformError::InvalidArgument(
+                            format!(
+                                "Unexpected closing tag {} at position {}",
+                                tag,
+                                char_position(input, start)
+                            )
+                            .into(),
+                        ))
+                    }
+                }
+                continue;
+            }
+            Token::Text(text) => Node::Text(text),
+            Token::Raw(text) => Node::Raw(text),
+            Token::Comment(text) => Node::Comment(text),
+            Token::Declaration(text) => Node::Declaration(text),
+        };
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => root.push(node),
+        }
+    }
+    while !stack.is_empty() {
+        close_top(&mut stack, &mut root);
+    }
+    Ok(root)
+}
+
+// Whether `node` flows with the text around it rather than taking its own lines
+fn is_inline(node: &Node) -> bool {
+    match node {
+        Node::Text(_) | Node::Raw(_) | Node::Comment(_) => true,
+        Node::Declaration(_) => false,
+        Node::Element(element) => {
+            is_inline_element(&element.name) && element.children.iter().all(is_inline)
+        }
+    }
+}
+
+// Writes `node` on one line, with its text's whitespace collapsed
+fn push_inline(out: &mut String, node: &Node) {
+    match node {
+        Node::Text(text) => push_collapsed(out, text),
+        Node::Raw(text) | Node::Comment(text) | Node::Declaration(text) => out.push_str(text),
+        Node::Element(element) => {
+            out.push_str(&element.open);
+            for child in &element.children {
+                push_inline(out, child);
+            }
+            if let Some(close) = &element.close {
+                out.push_str(close);
+            }
+        }
+    }
+}
+
+struct Printer {
+    indent: usize,
+    out: String,
+}
+
+impl Printer {
+    fn line(&mut self, level: usize, text: &str) {
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out.push_str(&" ".repeat(level * self.indent));
+        self.out.push_str(text);
+    }
+
+    // Block children get a line each, runs of inline ones share one
+    fn write_children(&mut self, children: &[Node], level: usize) {
+        let mut run = String::new();
+        for child in children {
+            if is_inline(child) {
+                push_inline(&mut run, child);
+            } else {
+                self.flush_run(&mut run, level);
+                self.write_block(child, level);
+            }
+        }
+        self.flush_run(&mut run, level);
+    }
+
+    fn flush_run(&mut self, run: &mut String, level: usize) {
+        let text = run.trim_matches(|c: char| c.is_ascii_whitespace());
+        if !text.is_empty() {
+            self.line(level, text);
+        }
+        run.clear();
+    }
+
+    fn write_block(&mut self, node: &Node, level: usize) {
+        let Node::Element(element) = node else {
+            let mut text = String::new();
+            push_inline(&mut text, node);
+            self.line(level, &text);
+            return;
+        };
+        let Some(close) = &element.close else {
+            self.line(level, &element.open);
+            return;
+        };
+
+        // Raw contents keep their exact whitespace
+        if RAW_TEXT_ELEMENTS.contains(&element.name.as_str()) {
+            let mut text = element.open.clone();
+            for child in &element.children {
+                push_inline(&mut text, child);
+            }
+            text.push_str(close);
+            self.line(level, &text);
+            return;
+        }
+
+        if element.children.iter().all(is_inline) {
+            let mut inner = String::new();
+            for child in &element.children {
+                push_inline(&mut inner, child);
+            }
+            let inner = inner.trim_matches(|c: char| c.is_ascii_whitespace());
+            let text = format!("{}{}{}", element.open, inner, close);
+            if !inner.contains('\n')
+                && level * self.indent + text.chars().count() <= MAX_INLINE_WIDTH
+            {
+                self.line(level, &text);
+                return;
+            }
+        }
+        self.line(level, &element.open);
+        self.write_children(&element.children, level + 1);
+        self.line(level, close);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_formatter() {
+        assert_eq!(
+            HtmlFormatter
+                .transform(HtmlFormatter.default_test_input())
+                .unwrap(),
+            "<!DOCTYPE html>
+<html>
+  <head>
+    <meta charset=\"utf-8\">
+    <title>buup</title>
+  </head>
+  <body>
+    <h1>Hello</h1>
+    <p>Some <b>bold</b> text<br>and a <a href=\"/docs\">link</a>.</p>
+    <ul>
+      <li>One</li>
+      <li>Two</li>
+    </ul>
+  </body>
+</html>"
+        );
+        assert_eq!(HtmlFormatter.transform("").unwrap(), "");
+        assert_eq!(HtmlFormatter.transform("  \n ").unwrap(), "");
+    }
+
+    #[test]
+    fn test_html_formatter_preserves_raw_text() {
+        let input = "<div><pre>  a\n    b  </pre><script>if (a < b) {\n  go();\n}</script><style>p{color:red}</style></div>";
+        assert_eq!(
+            HtmlFormatter.transform(input).unwrap(),
+            "<div>\n  <pre>  a\n    b  </pre>\n  <script>if (a < b) {\n  go();\n}</script>\n  <style>p{color:red}</style>\n</div>"
+        );
+        // Tags inside pre stay exactly as written
+        assert_eq!(
+            HtmlFormatter
+                .transform("<pre><b>x</b>\n  <i>y</i></pre>")
+                .unwrap(),
+            "<pre><b>x</b>\n  <i>y</i></pre>"
+        );
+    }
+
+    #[test]
+    fn test_html_formatter_layout() {
+        // Whitespace in tags and text collapses, void and self-closed tags stay open
+        assert_eq!(
+            HtmlFormatter
+                .transform("<DIV   class = \"a  b\"\n>\n  one\n  two <img src=x.png/><hr><svg><path d=\"M0 0\"/></svg></DIV>")
+                .unwrap(),
+            "<DIV class = \"a  b\">\n  one two <img src=x.png/>\n  <hr>\n  <svg>\n    <path d=\"M0 0\"/>\n  </svg>\n</DIV>"
+        );
+        // Long inline content moves onto its own line
+        let long = format!("<p>{}</p>", "word ".repeat(20));
+        assert_eq!(
+            HtmlFormatter.transform(&long).unwrap(),
+            format!("<p>\n  {}\n</p>", "word ".repeat(20).trim_end())
+        );
+        // Block elements inside inline ones break the line
+        assert_eq!(
+            HtmlFormatter
+                .transform("<a href=\"/\"><div>card</div></a><!-- end -->")
+                .unwrap(),
+            "<a href=\"/\">\n  <div>card</div>\n</a>\n<!-- end -->"
+        );
+    }
+
+    #[test]
+    fn test_html_formatter_implied_end_tags() {
+        assert_eq!(
+            HtmlFormatter
+                .transform("<table><tr><td>1<td>2<tr><td>3</table><p>a<p>b")
+                .unwrap(),
+            "<table>\n  <tr>\n    <td>1</td>\n    <td>2</td>\n  </tr>\n  <tr>\n    <td>3</td>\n  </tr>\n</table>\n<p>a</p>\n<p>b</p>"
+        );
+    }
+
+    #[test]
+    fn test_html_formatter_indent_option() {
+        let mut options = TransformOptions::new();
+        options.set("indent", "4");
+        assert_eq!(
+            HtmlFormatter
+                .transform_with_options("<ul><li>a</li></ul>", &options)
+                .unwrap(),
+            "<ul>\n    <li>a</li>\n</ul>"
+        );
+        options.set("indent", "17");
+        assert!(HtmlFormatter
+            .transform_with_options("<p></p>", &options)
+            .is_err());
+    }
+
+    #[test]
+    fn test_html_formatter_errors() {
+        let err = HtmlFormatter.transform("<div>\n</span></div>").unwrap_err();
+        assert!(err.to_string().contains("Unexpected closing tag </span>"));
+        assert_eq!(err.position(), Some(7));
+
+        let err = HtmlFormatter
+            .transform("<p>ok</p><a href=\"x>")
+            .unwrap_err();
+        assert!(err.to_string().contains("Unterminated tag"));
+        assert_eq!(err.position(), Some(10));
+
+        // A lone '<' is text
+        assert_eq!(
+            HtmlFormatter.transform("<p>a < b</p>").unwrap(),
+            "<p>a < b</p>"
+        );
+    }
+}
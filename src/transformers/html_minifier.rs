@@ -0,0 +1,124 @@
+use super::html_formatter::{is_inline_element, push_collapsed, tokenize, Token};
+use crate::{CancelToken, Example, Transform, TransformError, TransformerCategory};
+
+/// HTML Minifier transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HtmlMinifier;
+
+impl Transform for HtmlMinifier {
+    fn name(&self) -> &'static str {
+        "HTML Minifier"
+    }
+
+    fn id(&self) -> &'static str {
+        "htmlminifier"
+    }
+
+    fn description(&self) -> &'static str {
+        "Minifies HTML by removing comments and the whitespace between block elements, keeping the contents of pre, script and style untouched."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Formatter
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let tokens: Vec<Token> = tokenize(input, token)?
+            .into_iter()
+            .filter(|token| !matches!(token, Token::Comment(_)))
+            .collect();
+
+        // Whitespace next to a block element's tag doesn't render
+        let at_block_edge = |token: Option<&Token>| match token {
+            None | Some(Token::Declaration(_)) => true,
+            Some(Token::Open { name, .. } | Token::Close { name, .. }) => !is_inline_element(name),
+            Some(Token::Text(_) | Token::Raw(_) | Token::Comment(_)) => false,
+        };
+
+        let mut out = String::new();
+        for (index, token) in tokens.iter().enumerate() {
+            match token {
+                Token::Text(text) => {
+                    let mut collapsed = String::new();
+                    push_collapsed(&mut collapsed, text);
+                    let mut text = collapsed.as_str();
+                    // out can end in a space when a removed comment split the text
+                    let previous = index.checked_sub(1).map(|i| &tokens[i]);
+                    if out.ends_with(' ') || at_block_edge(previous) {
+                        text = text.trim_start_matches(' ');
+                    }
+                    if at_block_edge(tokens.get(index + 1)) {
+                        text = text.trim_end_matches(' ');
+                    }
+                    out.push_str(text);
+                }
+                Token::Raw(text) | Token::Declaration(text) => out.push_str(text),
+                Token::Open { tag, .. } | Token::Close { tag, .. } => out.push_str(tag),
+                Token::Comment(_) => {}
+            }
+        }
+        Ok(out)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "<!DOCTYPE html>\n<html>\n  <head>\n    <meta charset=\"utf-8\">\n    <title>buup</title>\n  </head>\n  <body>\n    <!-- greeting -->\n    <h1>Hello</h1>\n    <p>\n      Some <b>bold</b> text<br>and a <a href=\"/docs\">link</a>.\n    </p>\n  </body>\n</html>"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Preformatted text",
+            input: "<section>\n  <h2>Output</h2>\n  <pre>\n  indented\n    lines\n  </pre>\n  <p>Spacing   is   collapsed.</p>\n</section>",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::HtmlFormatter;
+
+    #[test]
+    fn test_html_minifier() {
+        assert_eq!(
+            HtmlMinifier
+                .transform(HtmlMinifier.default_test_input())
+                .unwrap(),
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>buup</title></head><body><h1>Hello</h1><p>Some <b>bold</b> text<br>and a <a href=\"/docs\">link</a>.</p></body></html>"
+        );
+        assert_eq!(HtmlMinifier.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_html_minifier_whitespace() {
+        // Spaces between inline elements render, so one is kept
+        assert_eq!(
+            HtmlMinifier
+                .transform("<p>\n  <b>a</b>   <i>b</i> <!-- x --> c\n</p>\n<div>  </div>")
+                .unwrap(),
+            "<p><b>a</b> <i>b</i> c</p><div></div>"
+        );
+        assert_eq!(
+            HtmlMinifier
+                .transform("<div>\n  <pre>  keep\n   this </pre>\n  <script>\n  run();\n  </script>\n</div>")
+                .unwrap(),
+            "<div><pre>  keep\n   this </pre><script>\n  run();\n  </script></div>"
+        );
+        assert!(HtmlMinifier.transform("<a href=\"x>").is_err());
+    }
+
+    #[test]
+    fn test_html_minifier_round_trip() {
+        let html = HtmlFormatter.default_test_input();
+        let formatted = HtmlFormatter.transform(html).unwrap();
+        let minified = HtmlMinifier.transform(&formatted).unwrap();
+        assert_eq!(HtmlFormatter.transform(&minified).unwrap(), formatted);
+    }
+}
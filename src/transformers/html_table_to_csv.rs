@@ -0,0 +1,268 @@
+use super::html_decode::decode_html_entity;
+use crate::utils::csv;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// Longest entity looked for, e.g. `&#x1F600;`
+const MAX_ENTITY_LEN: usize = 12;
+
+/// HTML table to CSV transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HtmlTableToCsv;
+
+impl Transform for HtmlTableToCsv {
+    fn name(&self) -> &'static str {
+        "HTML Table to CSV"
+    }
+
+    fn id(&self) -> &'static str {
+        "htmltabletocsv"
+    }
+
+    fn description(&self) -> &'static str {
+        "Extracts the first HTML <table> as CSV, one row per <tr>, keeping cell text, turning <br> into line breaks and padding colspan with empty cells"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Formatter
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let rows = table_rows(input);
+        if rows.is_empty() {
+            return Err(TransformError::InvalidArgument(
+                "No table rows (<tr>) found".into(),
+            ));
+        }
+        Ok(csv::write(&rows, ','))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "<table>\n  <thead><tr><th>name</th><th>language</th></tr></thead>\n  <tbody>\n    <tr><td>Ada <b>Lovelace</b></td><td>Notes &lt;on&gt; the Engine</td></tr>\n    <tr><td>Hopper, Grace</td><td>COBOL &amp; FLOW-MATIC</td></tr>\n  </tbody>\n</table>"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Unclosed cells",
+                input: "<table><tr><th>item<th>price<tr><td>Coffee<td>3.50<tr><td>Tea<td>2.75</table>",
+            },
+            Example {
+                name: "Colspan and line breaks",
+                input: "<table><tr><td colspan=\"2\">Total</td><td>9</td></tr><tr><td>a<br>b</td><td></td><td>1</td></tr></table>",
+            },
+        ]
+    }
+}
+
+/// The rows of the first table in `html`, or of loose rows if there is
+/// no `<table>` tag
+fn table_rows(html: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row: Option<Vec<String>> = None;
+    // The open cell's text and how many columns it spans
+    let mut cell: Option<(String, usize)> = None;
+    let mut pos = 0;
+
+    while let Some(offset) = html[pos..].find(['<', '&']) {
+        if let Some((text, _)) = &mut cell {
+            push_text(text, &html[pos..pos + offset]);
+        }
+        pos += offset;
+        let rest = &html[pos..];
+
+        if rest.starts_with('&') {
+            let entity = rest
+                .char_indices()
+                .take(MAX_ENTITY_LEN)
+                .find(|&(_, c)| c == ';')
+                .and_then(|(end, _)| Some((end, decode_entity(&rest[..=end])?)));
+            let (len, decoded) = entity.unwrap_or((0, '&'));
+            if let Some((text, _)) = &mut cell {
+                push_text(text, decoded.encode_utf8(&mut [0; 4]));
+            }
+            pos += len + 1;
+            continue;
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            pos += 4 + comment.find("-->").map_or(comment.len(), |end| end + 3);
+            continue;
+        }
+        let Some(tag) = Tag::parse(rest) else {
+            if let Some((text, _)) = &mut cell {
+                text.push('<');
+            }
+            pos += 1;
+            continue;
+        };
+        pos += tag.len;
+
+        match (tag.name.as_str(), tag.end) {
+            ("script" | "style", false) => {
+                let close = format!("</{}", tag.name);
+                pos += find_ignore_case(&html[pos..], &close).unwrap_or(html.len() - pos);
+            }
+            ("br", _) => {
+                if let Some((text, _)) = &mut cell {
+                    text.truncate(text.trim_end_matches(' ').len());
+                    text.push('\n');
+                }
+            }
+            ("td" | "th", false) => {
+                finish_cell(&mut cell, &mut row);
+                row.get_or_insert_with(Vec::new);
+                cell = Some((String::new(), tag.colspan));
+            }
+            ("td" | "th", true) => finish_cell(&mut cell, &mut row),
+            ("tr", _) => {
+                finish_cell(&mut cell, &mut row);
+                rows.extend(row.take());
+            }
+            ("table", true) if !rows.is_empty() || row.is_some() => break,
+            _ => {}
+        }
+    }
+    finish_cell(&mut cell, &mut row);
+    rows.extend(row);
+    rows
+}
+
+fn finish_cell(cell: &mut Option<(String, usize)>, row: &mut Option<Vec<String>>) {
+    if let Some((text, colspan)) = cell.take() {
+        let text: Vec<&str> = text.split('\n').map(str::trim).collect();
+        let row = row.get_or_insert_with(Vec::new);
+        row.push(text.join("\n"));
+        row.extend(std::iter::repeat_n(String::new(), colspan - 1));
+    }
+}
+
+// Appends text as a browser shows it, collapsing runs of whitespace
+fn push_text(out: &mut String, text: &str) {
+    for c in text.chars() {
+        if c.is_whitespace() && c != '\u{00A0}' {
+            if !out.is_empty() && !out.ends_with([' ', '\n']) {
+                out.push(' ');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "&nbsp;" => Some(' '),
+        "&apos;" => Some('\''),
+        _ => decode_html_entity(entity),
+    }
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// A start or end tag, with the one attribute tables care about
+struct Tag {
+    name: String,
+    end: bool,
+    colspan: usize,
+    len: usize,
+}
+
+impl Tag {
+    // `<name ...>` or `</name>` at the start of `text`
+    fn parse(text: &str) -> Option<Self> {
+        let body = text.strip_prefix('<')?;
+        let (end, body) = match body.strip_prefix('/') {
+            Some(body) => (true, body),
+            None => (false, body),
+        };
+        if !body.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let len = text.find('>')? + 1;
+        let inside = &body[..len - 2 - usize::from(end)];
+        let name_len = inside
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(inside.len());
+        let colspan = attribute(&inside[name_len..], "colspan")
+            .and_then(|value| value.parse().ok())
+            .filter(|&span| (1..=1000).contains(&span))
+            .unwrap_or(1);
+        Some(Self {
+            name: inside[..name_len].to_ascii_lowercase(),
+            end,
+            colspan,
+            len,
+        })
+    }
+}
+
+// The value of `name` in a tag's attribute list, quoted or not
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = find_ignore_case(attributes, name)? + name.len();
+    let rest = attributes[start..]
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start();
+    match rest.chars().next()? {
+        quote @ ('"' | '\'') => rest[1..].split(quote).next(),
+        _ => rest.split(|c: char| c.is_whitespace() || c == '/').next(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::CsvToHtmlTable;
+
+    #[test]
+    fn test_html_table_to_csv() {
+        assert_eq!(
+            HtmlTableToCsv
+                .transform(HtmlTableToCsv.default_test_input())
+                .unwrap(),
+            "name,language\nAda Lovelace,Notes <on> the Engine\n\"Hopper, Grace\",COBOL & FLOW-MATIC"
+        );
+    }
+
+    #[test]
+    fn test_html_table_sloppy_markup() {
+        assert_eq!(
+            HtmlTableToCsv
+                .transform(
+                    "<TABLE><TR><TH>item<TH>price<TR><TD>Coffee<td>3.50<tr><td>Tea<td>2.75</table>"
+                )
+                .unwrap(),
+            "item,price\nCoffee,3.50\nTea,2.75"
+        );
+        assert_eq!(
+            HtmlTableToCsv
+                .transform("<table><tr><td colspan=2>Total</td><td>9</td></tr><tr><td>a <br/> b</td><td><!-- x --></td><td>&nbsp;1 &lt; 2 &bogus;</td></tr></table>")
+                .unwrap(),
+            "Total,,9\n\"a\nb\",,1 < 2 &bogus;"
+        );
+        // Only the first table is read, and text outside cells is ignored
+        assert_eq!(
+            HtmlTableToCsv
+                .transform("<p>Intro</p><table><caption>c</caption><tr><td>1</td></tr></table><table><tr><td>2</td></tr></table>")
+                .unwrap(),
+            "1"
+        );
+    }
+
+    #[test]
+    fn test_html_table_round_trip() {
+        let csv = "name,note\n\"Lovelace, Ada\",\"a < b & \"\"c\"\"\"\nx,\"two\nlines\"";
+        let html = CsvToHtmlTable.transform(csv).unwrap();
+        assert_eq!(HtmlTableToCsv.transform(&html).unwrap(), csv);
+    }
+
+    #[test]
+    fn test_html_table_no_rows() {
+        assert!(HtmlTableToCsv.transform("<p>No table here</p>").is_err());
+        assert!(HtmlTableToCsv.transform("").is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const PREFIX_OPTION: TransformOption = TransformOption {
+    key: "prefix",
+    description: "Text to put before every line, such as spaces, a tab, \"> \" or \"// \"",
+    default: "    ",
+    kind: TransformOptionKind::Text,
+};
+
+/// Indent text transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndentText;
+
+impl Transform for IndentText {
+    fn name(&self) -> &'static str {
+        "Indent Text"
+    }
+
+    fn id(&self) -> &'static str {
+        "indent_text"
+    }
+
+    fn description(&self) -> &'static str {
+        "Adds a prefix to the start of every line, four spaces by default; blank lines only get the prefix's visible part, so quoting with \"> \" leaves a bare \">\""
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[PREFIX_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let prefix = options.value(&PREFIX_OPTION);
+        if prefix.contains(['\n', '\r']) {
+            return Err(TransformError::InvalidArgument(
+                "Prefix can't contain a line break".into(),
+            ));
+        }
+        // Trailing whitespace on an otherwise empty line is noise
+        let blank_prefix = prefix.trim_end();
+
+        let mut out = String::with_capacity(input.len() + prefix.len() * 16);
+        for (i, (line, ending)) in split_lines(input).enumerate() {
+            token.check_every(i)?;
+            if line.trim().is_empty() {
+                out.push_str(blank_prefix);
+            } else {
+                out.push_str(prefix);
+                out.push_str(line);
+            }
+            out.push_str(ending);
+        }
+        Ok(out)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "fn main() {\n    println!(\"Hello\");\n}\n"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Paragraphs",
+            input: "First paragraph.\n\nSecond paragraph,\nover two lines.",
+        }]
+    }
+}
+
+/// Splits text into (line, line ending) pairs so the original endings can be
+/// written back unchanged. A final line without an ending gets "".
+pub(crate) fn split_lines(input: &str) -> impl Iterator<Item = (&str, &str)> {
+    input.split_inclusive('\n').map(|line| {
+        let content = line
+            .strip_suffix("\r\n")
+            .or_else(|| line.strip_suffix('\n'))
+            .unwrap_or(line);
+        (content, &line[content.len()..])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indent(input: &str, prefix: &str) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("prefix", prefix);
+        IndentText.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_indent_text() {
+        assert_eq!(
+            IndentText
+                .transform(IndentText.default_test_input())
+                .unwrap(),
+            "    fn main() {\n        println!(\"Hello\");\n    }\n"
+        );
+        assert_eq!(indent("a\r\n\r\n  b", "\t").unwrap(), "\ta\r\n\r\n\t  b");
+        assert_eq!(IndentText.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_indent_text_prefixes() {
+        let paragraphs = IndentText.examples()[0].input;
+        assert_eq!(
+            indent(paragraphs, "> ").unwrap(),
+            "> First paragraph.\n>\n> Second paragraph,\n> over two lines."
+        );
+        assert_eq!(
+            indent("let a = 1;\n   \nlet b = 2;\n", "// ").unwrap(),
+            "// let a = 1;\n//\n// let b = 2;\n"
+        );
+        assert!(indent("a", "x\ny").is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use crate::utils::time::parse_iso8601;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const UNIT_OPTION: TransformOption = TransformOption {
+    key: "unit",
+    description: "Unit of the timestamps produced",
+    default: "seconds",
+    kind: TransformOptionKind::Choice(&["seconds", "milliseconds", "microseconds"]),
+};
+
+/// ISO 8601 to Unix timestamp converter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsoToTimestamp;
+
+impl Transform for IsoToTimestamp {
+    fn name(&self) -> &'static str {
+        "ISO 8601 to Timestamp"
+    }
+
+    fn id(&self) -> &'static str {
+        "iso_to_timestamp"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts ISO 8601 dates and times, one per line, to Unix timestamps. Times without an offset are taken as UTC."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[UNIT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let nanos_per_unit: i128 = match options.value(&UNIT_OPTION) {
+            "milliseconds" => 1_000_000,
+            "microseconds" => 1000,
+            _ => 1_000_000_000,
+        };
+        let lines = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                token.check_every(i)?;
+                let line = line.trim();
+                if line.is_empty() {
+                    return Ok(String::new());
+                }
+                let (date_time, offset) = parse_iso8601(line).ok_or_else(|| {
+                    TransformError::InvalidArgument(
+                        format!("Line {}: '{}' is not an ISO 8601 date", i + 1, line).into(),
+                    )
+                })?;
+                let timestamp = date_time.timestamp(offset.unwrap_or(0));
+                let nanos =
+                    i128::from(timestamp.seconds) * 1_000_000_000 + i128::from(timestamp.nanos);
+                Ok(format_units(nanos, nanos_per_unit))
+            })
+            .collect::<Result<Vec<_>, TransformError>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "2023-11-14T22:13:20Z"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "With offsets and fractions",
+                input: "2023-11-15T09:13:20+11:00\n2023-11-14T22:13:20.5Z",
+            },
+            Example {
+                name: "Dates only",
+                input: "1970-01-01\n2000-01-01\n2038-01-19",
+            },
+        ]
+    }
+}
+
+// A whole number of units, with a decimal fraction only when needed
+fn format_units(nanos: i128, nanos_per_unit: i128) -> String {
+    let whole = nanos.div_euclid(nanos_per_unit);
+    let remainder = nanos.rem_euclid(nanos_per_unit);
+    if remainder == 0 {
+        return whole.to_string();
+    }
+    let digits = nanos_per_unit.ilog10() as usize;
+    // Keep the sign on the number as a whole for negative fractions
+    let (whole, remainder) = if nanos < 0 {
+        (whole + 1, nanos_per_unit - remainder)
+    } else {
+        (whole, remainder)
+    };
+    let sign = if nanos < 0 && whole == 0 { "-" } else { "" };
+    let fraction = format!("{:0digits$}", remainder, digits = digits);
+    format!("{}{}.{}", sign, whole, fraction.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, unit: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("unit", unit);
+        IsoToTimestamp
+            .transform_with_options(input, &options)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_iso_to_timestamp() {
+        assert_eq!(
+            IsoToTimestamp
+                .transform(IsoToTimestamp.default_test_input())
+                .unwrap(),
+            "1700000000"
+        );
+        assert_eq!(
+            IsoToTimestamp
+                .transform("2023-11-15T09:13:20+11:00\n\n1970-01-01\n1969-12-31T23:59:58.5Z")
+                .unwrap(),
+            "1700000000\n\n0\n-1.5"
+        );
+        assert_eq!(
+            IsoToTimestamp.transform("1969-12-31T23:59:59.75Z").unwrap(),
+            "-0.25"
+        );
+    }
+
+    #[test]
+    fn test_iso_to_timestamp_units() {
+        assert_eq!(
+            convert("2023-11-14T22:13:20.123456Z", "milliseconds"),
+            "1700000000123.456"
+        );
+        assert_eq!(
+            convert("2023-11-14T22:13:20.123456Z", "microseconds"),
+            "1700000000123456"
+        );
+    }
+
+    #[test]
+    fn test_iso_to_timestamp_round_trip() {
+        use crate::transformers::timestamp_to_iso::parse_timestamp;
+        let (timestamp, _) = parse_timestamp("1700000000123").unwrap();
+        assert_eq!(
+            convert(&timestamp.to_rfc3339(), "milliseconds"),
+            "1700000000123"
+        );
+    }
+
+    #[test]
+    fn test_iso_to_timestamp_invalid() {
+        assert!(IsoToTimestamp.transform("14/11/2023").is_err());
+        assert!(IsoToTimestamp.transform("2023-02-30").is_err());
+    }
+}
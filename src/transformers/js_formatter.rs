@@ -0,0 +1,1032 @@
+use super::json_formatter::{indent_value, DEFAULT_INDENT, INDENT_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// Longest object or array literal (roughly, in characters) kept on one line
+const MAX_INLINE_LEN: usize = 60;
+/// Calls a method chain needs before each one goes on its own line
+const CHAIN_BREAK_CALLS: usize = 3;
+
+/// JavaScript Formatter transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsFormatter;
+
+impl Transform for JsFormatter {
+    fn name(&self) -> &'static str {
+        "JavaScript Formatter"
+    }
+
+    fn id(&self) -> &'static str {
+        "jsformatter"
+    }
+
+    fn description(&self) -> &'static str {
+        "Formats (pretty-prints) JavaScript code."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Formatter
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "const greet=(name)=>{if(!name){return `Hello, stranger`;}return `Hello, ${name}!`;};console.log(greet(\"buup\"));"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Promise chain",
+                input: "fetch(url).then(res=>res.json()).then(data=>{render(data.items)}).catch(err=>console.error(err));",
+            },
+            Example {
+                name: "Class",
+                input: "class Counter{#count=0;increment(by=1){this.#count+=by;return this}get value(){return this.#count}}",
+            },
+            Example {
+                name: "Object literal",
+                input: "const config={port:8080,hosts:[\"a.example\",\"b.example\"],retry:{attempts:3,delay:250},onError(e){log(e)}};",
+            },
+        ]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        format(input, DEFAULT_INDENT, token)
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[INDENT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        format(input, indent_value(options)?, token)
+    }
+}
+
+// Tokenizes `input`, works out which literals and chains fit on one line,
+// then prints it
+fn format(input: &str, indent: usize, cancel: &CancelToken) -> Result<String, TransformError> {
+    // Skip empty input
+    if input.trim().is_empty() {
+        return Ok(String::new());
+    }
+
+    let tokens = tokenize(input, cancel)?;
+    let layout = Layout::new(input, &tokens)?;
+    let mut printer = Printer::new(&tokens, &layout, indent);
+    for index in 0..tokens.len() {
+        cancel.check_every(index)?;
+        printer.print(index);
+    }
+    Ok(printer.out)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Identifiers and keywords
+    Word,
+    /// A name after `.`, which is never a keyword (`promise.catch`)
+    Property,
+    Number,
+    String,
+    Template,
+    Regex,
+    Punct,
+    LineComment,
+    BlockComment,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+    /// Byte offset in the input
+    start: usize,
+    /// Line breaks between this token and the previous one, capped at 2
+    /// (a blank line)
+    newlines_before: u8,
+}
+
+impl Token<'_> {
+    fn is(&self, punct: &str) -> bool {
+        self.kind == Kind::Punct && self.text == punct
+    }
+
+    fn is_word(&self, word: &str) -> bool {
+        self.kind == Kind::Word && self.text == word
+    }
+
+    fn is_comment(&self) -> bool {
+        matches!(self.kind, Kind::LineComment | Kind::BlockComment)
+    }
+}
+
+// Reserved words that can't end an expression
+const KEYWORDS: [&str; 34] = [
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "export",
+    "extends",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "let",
+    "new",
+    "of",
+    "return",
+    "switch",
+    "throw",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+// Multi-character punctuators, longest first so the first match wins
+const PUNCTUATORS: [&str; 33] = [
+    ">>>=", "...", "===", "!==", "**=", "<<=", ">>=", ">>>", "&&=", "||=", "??=", "=>", "==", "!=",
+    "<=", ">=", "&&", "||", "??", "?.", "++", "--", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=",
+    "**", "<<", ">>",
+];
+
+fn is_keyword(word: &str) -> bool {
+    KEYWORDS.contains(&word)
+}
+
+// Whether `token` can be the last token of an expression, e.g. an
+// identifier or a closing bracket, as opposed to an operator or keyword
+fn ends_expression(token: &Token) -> bool {
+    match token.kind {
+        Kind::Word => !is_keyword(token.text),
+        Kind::Property => true,
+        Kind::Punct => matches!(token.text, ")" | "]" | "}"),
+        Kind::LineComment | Kind::BlockComment => false,
+        Kind::Number | Kind::String | Kind::Template | Kind::Regex => true,
+    }
+}
+
+// 1-based character position of a byte offset, for error messages
+fn position(input: &str, byte: usize) -> usize {
+    input[..byte].chars().count() + 1
+}
+
+fn unterminated(input: &str, what: &str, start: usize) -> TransformError {
+    TransformError::InvalidArgument(
+        format!(
+            "Unterminated {} starting at position {}",
+            what,
+            position(input, start)
+        )
+        .into(),
+    )
+}
+
+fn tokenize<'a>(input: &'a str, cancel: &CancelToken) -> Result<Vec<Token<'a>>, TransformError> {
+    let bytes = input.as_bytes();
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut pos = 0;
+    let mut newlines = 0u8;
+
+    while let Some(c) = input[pos..].chars().next() {
+        cancel.check_every(pos)?;
+        if c == '\n' {
+            newlines = newlines.saturating_add(1);
+            pos += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            pos += c.len_utf8();
+            continue;
+        }
+
+        let start = pos;
+        let next = bytes.get(pos + 1).copied();
+        let kind = match c {
+            '/' if next == Some(b'/') => {
+                pos = input[pos..].find('\n').map_or(input.len(), |end| pos + end);
+                Kind::LineComment
+            }
+            '/' if next == Some(b'*') => {
+                let end = input[pos + 2..]
+                    .find("*/")
+                    .ok_or_else(|| unterminated(input, "comment", start))?;
+                pos += end + 4;
+                Kind::BlockComment
+            }
+            '"' | '\'' => {
+                pos = skip_string(input, pos)?;
+                Kind::String
+            }
+            '`' => {
+                pos = skip_template(input, pos)?;
+                Kind::Template
+            }
+            '/' if regex_allowed(tokens.iter().rev().find(|t| !t.is_comment())) => {
+                pos = skip_regex(input, pos)?;
+                Kind::Regex
+            }
+            '0'..='9' => {
+                pos = skip_number(bytes, pos);
+                Kind::Number
+            }
+            '.' if next.is_some_and(|b| b.is_ascii_digit()) => {
+                pos = skip_number(bytes, pos);
+                Kind::Number
+            }
+            c if c.is_alphabetic() || matches!(c, '_' | '$' | '#' | '\\') => {
+                pos += c.len_utf8();
+                while let Some(c) = input[pos..].chars().next() {
+                    if !(c.is_alphanumeric() || matches!(c, '_' | '$' | '\\')) {
+                        break;
+                    }
+                    pos += c.len_utf8();
+                }
+                match tokens.last() {
+                    Some(prev) if prev.is(".") || prev.is("?.") => Kind::Property,
+                    _ => Kind::Word,
+                }
+            }
+            _ => {
+                let rest = &input[pos..];
+                // `a?.5:b` is a conditional, not optional chaining
+                let optional_chain = rest.starts_with("?.")
+                    && !bytes.get(pos + 2).is_some_and(|b| b.is_ascii_digit());
+                pos += PUNCTUATORS
+                    .iter()
+                    .find(|p| rest.starts_with(**p) && (**p != "?." || optional_chain))
+                    .map_or(c.len_utf8(), |p| p.len());
+                Kind::Punct
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            text: &input[start..pos],
+            start,
+            newlines_before: newlines.min(2),
+        });
+        newlines = 0;
+    }
+
+    Ok(tokens)
+}
+
+// A `/` starts a regular expression where a value is expected, and is
+// division after one
+fn regex_allowed(prev: Option<&Token>) -> bool {
+    prev.is_none_or(|prev| !ends_expression(prev) || prev.is("}"))
+}
+
+fn skip_string(input: &str, start: usize) -> Result<usize, TransformError> {
+    let bytes = input.as_bytes();
+    let quote = bytes[start];
+    let mut pos = start + 1;
+    loop {
+        match bytes.get(pos) {
+            None | Some(b'\n') => return Err(unterminated(input, "string", start)),
+            Some(b'\\') => pos += 2,
+            Some(&b) if b == quote => return Ok(pos + 1),
+            Some(_) => pos += 1,
+        }
+    }
+}
+
+// Template literals are kept as written, `${...}` expressions included
+fn skip_template(input: &str, start: usize) -> Result<usize, TransformError> {
+    let bytes = input.as_bytes();
+    let mut pos = start + 1;
+    loop {
+        match bytes.get(pos) {
+            None => return Err(unterminated(input, "template literal", start)),
+            Some(b'\\') => pos += 2,
+            Some(b'`') => return Ok(pos + 1),
+            Some(b'$') if bytes.get(pos + 1) == Some(&b'{') => {
+                pos = skip_substitution(input, pos + 2, start)?;
+            }
+            Some(_) => pos += 1,
+        }
+    }
+}
+
+// Skips to just past the `}` closing a template substitution
+fn skip_substitution(
+    input: &str,
+    mut pos: usize,
+    template_start: usize,
+) -> Result<usize, TransformError> {
+    let bytes = input.as_bytes();
+    let mut depth = 0;
+    loop {
+        match bytes.get(pos) {
+            None => return Err(unterminated(input, "template literal", template_start)),
+            Some(b'{') => {
+                depth += 1;
+                pos += 1;
+            }
+            Some(b'}') if depth == 0 => return Ok(pos + 1),
+            Some(b'}') => {
+                depth -= 1;
+                pos += 1;
+            }
+            Some(b'"' | b'\'') => pos = skip_string(input, pos)?,
+            Some(b'`') => pos = skip_template(input, pos)?,
+            Some(_) => pos += 1,
+        }
+    }
+}
+
+fn skip_regex(input: &str, start: usize) -> Result<usize, TransformError> {
+    let bytes = input.as_bytes();
+    let mut pos = start + 1;
+    let mut in_class = false;
+    loop {
+        match bytes.get(pos) {
+            None | Some(b'\n') => return Err(unterminated(input, "regular expression", start)),
+            Some(b'\\') => pos += 2,
+            Some(b'[') => {
+                in_class = true;
+                pos += 1;
+            }
+            Some(b']') => {
+                in_class = false;
+                pos += 1;
+            }
+            Some(b'/') if !in_class => break,
+            Some(_) => pos += 1,
+        }
+    }
+    pos += 1;
+    // Flags
+    while bytes.get(pos).is_some_and(|b| b.is_ascii_alphanumeric()) {
+        pos += 1;
+    }
+    Ok(pos)
+}
+
+// Decimal, hex, octal and binary literals, separators, exponents and BigInts
+fn skip_number(bytes: &[u8], start: usize) -> usize {
+    let hex = bytes[start..].starts_with(b"0x") || bytes[start..].starts_with(b"0X");
+    let mut pos = start;
+    while let Some(&b) = bytes.get(pos) {
+        let exponent_sign =
+            matches!(b, b'+' | b'-') && !hex && matches!(bytes[pos - 1], b'e' | b'E');
+        if !(b.is_ascii_alphanumeric() || b == b'_' || b == b'.' || exponent_sign) {
+            break;
+        }
+        pos += 1;
+    }
+    pos
+}
+
+/// Decisions that need to look ahead past the token being printed
+struct Layout {
+    /// Object and array literals that get one entry per line
+    multiline: Vec<bool>,
+    /// `.` tokens that start a new line in a method chain
+    chain_break: Vec<bool>,
+}
+
+impl Layout {
+    fn new(input: &str, tokens: &[Token]) -> Result<Self, TransformError> {
+        let count = tokens.len();
+        let closer = match_brackets(input, tokens)?;
+
+        // Running totals, so any bracketed range can be measured in O(1)
+        let mut width = vec![0; count + 1];
+        let mut nested = vec![0; count + 1];
+        for (i, token) in tokens.iter().enumerate() {
+            // Function bodies, nested objects and comments never fit on one line
+            let forces_lines =
+                token.is("{") || token.is("=>") || token.is_word("function") || token.is_comment();
+            width[i + 1] = width[i] + token.text.len() + 1;
+            nested[i + 1] = nested[i] + usize::from(forces_lines);
+        }
+
+        let mut multiline = vec![false; count];
+        for (i, token) in tokens.iter().enumerate() {
+            if !(token.is("{") || token.is("[")) {
+                continue;
+            }
+            let end = closer[i];
+            // Like a hand-written object, keep one spread over lines that way
+            let written_multiline = token.is("{") && tokens[i + 1].newlines_before > 0;
+            multiline[i] = end > i + 1
+                && (nested[end] > nested[i + 1]
+                    || width[end] - width[i + 1] > MAX_INLINE_LEN
+                    || written_multiline);
+        }
+
+        let mut chain_break = vec![false; count];
+        let mut i = 1;
+        while i < count {
+            if tokens[i].newlines_before > 0 && (tokens[i].is(".") || tokens[i].is("?.")) {
+                chain_break[i] = true;
+            }
+            if !(tokens[i].is(".") || tokens[i].is("?.")) || !tokens[i - 1].is(")") {
+                i += 1;
+                continue;
+            }
+            // Follow the chain: `.name`, call arguments and indexing
+            let mut calls = Vec::new();
+            let mut j = i;
+            while j < count {
+                if tokens[j].is(".") || tokens[j].is("?.") {
+                    if tokens[j - 1].is(")") {
+                        calls.push(j);
+                    }
+                    j += 1;
+                    if j < count && tokens[j].kind == Kind::Property {
+                        j += 1;
+                    }
+                } else if tokens[j].is("(") || tokens[j].is("[") {
+                    j = closer[j] + 1;
+                } else {
+                    break;
+                }
+            }
+            if calls.len() >= CHAIN_BREAK_CALLS {
+                for &call in &calls {
+                    chain_break[call] = true;
+                }
+            }
+            i = j.max(i + 1);
+        }
+
+        Ok(Self {
+            multiline,
+            chain_break,
+        })
+    }
+}
+
+// Index of the closing bracket for every opening one, checking they pair up
+fn match_brackets(input: &str, tokens: &[Token]) -> Result<Vec<usize>, TransformError> {
+    let mut closer = vec![0; tokens.len()];
+    let mut open: Vec<usize> = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        if token.kind != Kind::Punct {
+            continue;
+        }
+        let expected = match token.text {
+            "(" | "[" | "{" => {
+                open.push(i);
+                continue;
+            }
+            ")" => "(",
+            "]" => "[",
+            "}" => "{",
+            _ => continue,
+        };
+        match open.pop() {
+            Some(start) if tokens[start].text == expected => closer[start] = i,
+            _ => {
+                return Err(TransformError::InvalidArgument(
+                    format!(
+                        "Unexpected '{}' at position {}",
+                        token.text,
+                        position(input, token.start)
+                    )
+                    .into(),
+                ))
+            }
+        }
+    }
+    if let Some(&start) = open.last() {
+        return Err(TransformError::InvalidArgument(
+            format!(
+                "Unclosed '{}' at position {}",
+                tokens[start].text,
+                position(input, tokens[start].start)
+            )
+            .into(),
+        ));
+    }
+    Ok(closer)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    Block,
+    Switch,
+    Object,
+    Array,
+    Paren,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Context<'a> {
+    scope: Scope,
+    multiline: bool,
+    /// Keyword just before the opening bracket, e.g. `switch` or `do`
+    keyword: Option<&'a str>,
+    /// `?` still waiting for their `:`
+    ternaries: usize,
+    /// A method chain is broken over lines, one level deeper
+    chain: bool,
+}
+
+impl<'a> Context<'a> {
+    fn new(scope: Scope, multiline: bool, keyword: Option<&'a str>) -> Self {
+        Self {
+            scope,
+            multiline,
+            keyword,
+            ternaries: 0,
+            chain: false,
+        }
+    }
+
+    fn indent(&self) -> usize {
+        let levels = match self.scope {
+            Scope::Switch => 2,
+            Scope::Paren => 0,
+            _ => usize::from(self.multiline),
+        };
+        levels + usize::from(self.chain)
+    }
+
+    fn holds_statements(&self) -> bool {
+        matches!(self.scope, Scope::Block | Scope::Switch)
+    }
+}
+
+/// Writes tokens out one at a time, deciding the spacing and line breaks
+/// before each from the tokens around it
+struct Printer<'a> {
+    tokens: &'a [Token<'a>],
+    layout: &'a Layout,
+    indent: usize,
+    out: String,
+    /// Innermost last; the first is the top level
+    stack: Vec<Context<'a>>,
+    /// Line breaks to write before the next token
+    newlines: u8,
+    /// Last token printed, skipping comments
+    prev: Option<Token<'a>>,
+    /// `prev` is a prefix operator, so the next token follows it directly
+    prev_unary: bool,
+    /// `prev` closes a block rather than an object literal
+    prev_block_end: bool,
+    /// Keyword before the parenthesis that was closed last
+    closed_paren_keyword: Option<&'a str>,
+    /// Between a `case` or `default` and its colon
+    case_label: bool,
+}
+
+impl<'a> Printer<'a> {
+    fn new(tokens: &'a [Token<'a>], layout: &'a Layout, indent: usize) -> Self {
+        Self {
+            tokens,
+            layout,
+            indent,
+            out: String::new(),
+            stack: vec![Context::new(Scope::Block, false, None)],
+            newlines: 0,
+            prev: None,
+            prev_unary: false,
+            prev_block_end: false,
+            closed_paren_keyword: None,
+            case_label: false,
+        }
+    }
+
+    fn context(&mut self) -> &mut Context<'a> {
+        self.stack.last_mut().unwrap()
+    }
+
+    fn print(&mut self, index: usize) {
+        let token = self.tokens[index];
+        if token.is_comment() {
+            self.comment(index);
+            return;
+        }
+
+        self.keep_line_break(&token);
+        let block_end = self.prev_block_end;
+        self.prev_block_end = false;
+        match token.kind {
+            Kind::Punct => self.punct(index, block_end),
+            Kind::Word => {
+                let dedent = matches!(token.text, "case" | "default")
+                    && self.context().scope == Scope::Switch
+                    && self.newlines > 0;
+                if dedent {
+                    self.case_label = true;
+                }
+                let space = self.space_before(&token);
+                self.write(token.text, space, dedent);
+            }
+            _ => {
+                let space = self.space_before(&token);
+                self.write(token.text, space, false);
+            }
+        }
+        self.prev = Some(token);
+    }
+
+    // Keeps a line break from the input where it ends a statement (JavaScript
+    // doesn't need semicolons) and a blank line between statements
+    fn keep_line_break(&mut self, token: &Token) {
+        if token.newlines_before == 0 || !self.context().holds_statements() {
+            return;
+        }
+        let Some(prev) = self.prev else {
+            return;
+        };
+        if self.newlines > 0 {
+            if token.newlines_before > 1 && !prev.is("{") {
+                self.newlines = 2;
+            }
+            return;
+        }
+        if !self.prev_block_end && starts_statement(&prev, token) {
+            self.newlines = token.newlines_before;
+            self.context().chain = false;
+        }
+    }
+
+    fn punct(&mut self, index: usize, block_end: bool) {
+        let token = self.tokens[index];
+        match token.text {
+            "{" => self.open_brace(index, block_end),
+            "}" => self.close_brace(index),
+            "(" | "[" => {
+                let space = self.space_before(&token);
+                let keyword = self.prev.filter(|p| p.kind == Kind::Word).map(|p| p.text);
+                self.write(token.text, space, false);
+                if token.text == "(" {
+                    self.stack.push(Context::new(Scope::Paren, false, keyword));
+                } else {
+                    let multiline = self.layout.multiline[index];
+                    self.stack.push(Context::new(Scope::Array, multiline, None));
+                    if multiline {
+                        self.newlines = 1;
+                    }
+                }
+            }
+            ")" | "]" => {
+                let context = self.pop();
+                if context.multiline {
+                    self.newlines = 1;
+                }
+                self.closed_paren_keyword = context.keyword;
+                self.write(token.text, false, false);
+            }
+            ";" => {
+                self.write(";", false, false);
+                let context = self.context();
+                context.chain = false;
+                context.ternaries = 0;
+                if context.holds_statements() {
+                    self.newlines = 1;
+                }
+            }
+            "," => {
+                self.write(",", false, false);
+                let context = self.context();
+                context.chain = false;
+                if context.multiline && matches!(context.scope, Scope::Object | Scope::Array) {
+                    self.newlines = 1;
+                }
+            }
+            "?" => {
+                self.context().ternaries += 1;
+                self.write("?", true, false);
+            }
+            ":" => {
+                if self.context().ternaries > 0 {
+                    self.context().ternaries -= 1;
+                    self.write(":", true, false);
+                } else {
+                    // Object keys, case labels and statement labels
+                    self.write(":", false, false);
+                    if std::mem::take(&mut self.case_label) {
+                        self.newlines = 1;
+                    }
+                }
+            }
+            "." | "?." if self.layout.chain_break[index] => {
+                self.context().chain = true;
+                self.newlines = 1;
+                self.write(token.text, false, false);
+            }
+            _ => {
+                let space = self.space_before(&token);
+                let unary = match token.text {
+                    "!" | "~" | "..." => true,
+                    "+" | "-" | "++" | "--" => {
+                        self.prev.is_none_or(|prev| !ends_expression(&prev))
+                            || (token.text.len() == 2 && token.newlines_before > 0)
+                    }
+                    _ => false,
+                };
+                self.write(token.text, space, false);
+                self.prev_unary = unary;
+            }
+        }
+    }
+
+    fn open_brace(&mut self, index: usize, block_end: bool) {
+        let block = self.opens_block(block_end);
+        let empty = self.tokens.get(index + 1).is_some_and(|t| t.is("}"));
+        let space = self
+            .prev
+            .is_some_and(|prev| !(prev.is("(") || prev.is("[") || self.prev_unary));
+        let keyword = self.prev.filter(|p| p.kind == Kind::Word).map(|p| p.text);
+
+        self.write("{", space, false);
+        let context = if !block {
+            Context::new(Scope::Object, self.layout.multiline[index], None)
+        } else if self.prev.is_some_and(|p| p.is(")"))
+            && self.closed_paren_keyword == Some("switch")
+        {
+            Context::new(Scope::Switch, !empty, None)
+        } else {
+            Context::new(Scope::Block, !empty, keyword)
+        };
+        if context.multiline {
+            self.newlines = 1;
+        }
+        self.stack.push(context);
+    }
+
+    // Whether a `{` starts a block (or class body) rather than an object literal
+    fn opens_block(&mut self, block_end: bool) -> bool {
+        let Some(prev) = self.prev else {
+            return true;
+        };
+        if self.newlines > 0 && self.context().holds_statements() {
+            return true;
+        }
+        match prev.kind {
+            Kind::Word => {
+                !is_keyword(prev.text)
+                    || matches!(prev.text, "else" | "try" | "finally" | "do" | "class")
+            }
+            Kind::Punct => matches!(prev.text, ")" | "=>" | ";") || block_end,
+            _ => false,
+        }
+    }
+
+    fn close_brace(&mut self, index: usize) {
+        let context = self.pop();
+        if context.multiline {
+            self.newlines = 1;
+        }
+        let inline_object = context.scope == Scope::Object
+            && !context.multiline
+            && self.prev.is_some_and(|prev| !prev.is("{"));
+        self.write("}", inline_object, false);
+
+        if matches!(context.scope, Scope::Block | Scope::Switch) {
+            self.prev_block_end = true;
+            let next = self.tokens[index + 1..].iter().find(|t| !t.is_comment());
+            let continues = next.is_some_and(|next| {
+                matches!(next.text, "else" | "catch" | "finally") && next.kind == Kind::Word
+                    || (next.is_word("while") && context.keyword == Some("do"))
+                    || (next.kind == Kind::Punct
+                        && matches!(next.text, ")" | "]" | "," | ";" | "." | "?." | "("))
+            });
+            if !continues {
+                self.newlines = self.newlines.max(1);
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Context<'a> {
+        // Brackets were matched up front, so the top level is never popped
+        self.stack.pop().unwrap()
+    }
+
+    fn comment(&mut self, index: usize) {
+        let token = self.tokens[index];
+        if token.newlines_before == 0 && !self.out.is_empty() {
+            // Stays at the end of the line it was on
+            let newlines = std::mem::take(&mut self.newlines);
+            self.write_comment(token.text, true);
+            self.newlines = newlines;
+        } else {
+            let blank_line = self.prev.is_some_and(|prev| !prev.is("{"));
+            let wanted = if blank_line { token.newlines_before } else { 1 };
+            self.newlines = self.newlines.max(wanted);
+            self.write_comment(token.text, false);
+        }
+
+        let line_follows = self
+            .tokens
+            .get(index + 1)
+            .is_some_and(|next| next.newlines_before > 0);
+        if token.kind == Kind::LineComment || (line_follows && self.context().holds_statements()) {
+            self.newlines = self.newlines.max(1);
+        }
+    }
+
+    // Block comments spanning lines have their `*` lines re-indented
+    fn write_comment(&mut self, text: &str, space: bool) {
+        let prev_unary = self.prev_unary;
+        let mut lines = text.trim_end().split('\n');
+        self.write(lines.next().unwrap_or_default(), space, false);
+        let indent = self.indent_width(false);
+        for line in lines {
+            self.out.push('\n');
+            let trimmed = line.trim_start();
+            if trimmed.starts_with('*') {
+                self.out.extend(std::iter::repeat_n(' ', indent + 1));
+                self.out.push_str(trimmed.trim_end());
+            } else {
+                self.out.push_str(line.trim_end());
+            }
+        }
+        self.prev_unary = prev_unary;
+    }
+
+    fn space_before(&self, token: &Token) -> bool {
+        let Some(prev) = self.prev else {
+            return false;
+        };
+        if self.prev_unary
+            || prev.is("(")
+            || prev.is("[")
+            || prev.is(".")
+            || prev.is("?.")
+            || prev.is("#")
+            || prev.is("@")
+        {
+            return false;
+        }
+        match token.kind {
+            Kind::Punct => match token.text {
+                ")" | "]" | "," | ";" | "." | "?." => false,
+                // `if (`, `function (` and `async (`, but `call(` and `this(`
+                "(" if prev.kind == Kind::Word
+                    && (is_keyword(prev.text) || prev.text == "async") =>
+                {
+                    prev.text != "import"
+                }
+                "(" | "[" => !ends_expression(&prev),
+                // Postfix
+                "++" | "--" => !(ends_expression(&prev) && token.newlines_before == 0),
+                // Generators
+                "*" => !(prev.is_word("function") || prev.is_word("yield")),
+                _ => true,
+            },
+            // A tagged template
+            Kind::Template => {
+                !(matches!(prev.kind, Kind::Word | Kind::Property) && ends_expression(&prev))
+            }
+            _ => true,
+        }
+    }
+
+    fn indent_width(&self, dedent: bool) -> usize {
+        let levels: usize = self.stack.iter().map(Context::indent).sum();
+        (levels - usize::from(dedent && levels > 0)) * self.indent
+    }
+
+    fn write(&mut self, text: &str, space: bool, dedent: bool) {
+        if self.newlines > 0 && !self.out.is_empty() {
+            for _ in 0..self.newlines {
+                self.out.push('\n');
+            }
+            let width = self.indent_width(dedent);
+            self.out.extend(std::iter::repeat_n(' ', width));
+        } else if space && !self.out.is_empty() {
+            self.out.push(' ');
+        }
+        self.newlines = 0;
+        self.prev_unary = false;
+        self.out.push_str(text);
+    }
+}
+
+// Whether a line break in the input between `prev` and `token` ends a
+// statement. Breaks before a token that can only continue an expression
+// (an operator, `.`, a call's `(`) are dropped.
+fn starts_statement(prev: &Token, token: &Token) -> bool {
+    // `return`, `throw` and friends end at a line break
+    if prev.kind == Kind::Word
+        && matches!(
+            prev.text,
+            "return" | "throw" | "break" | "continue" | "yield"
+        )
+    {
+        return true;
+    }
+    if !ends_expression(prev) {
+        return false;
+    }
+    match token.kind {
+        Kind::Punct => matches!(token.text, "++" | "--" | "!" | "~" | "#" | "@"),
+        Kind::Word => !matches!(token.text, "in" | "instanceof" | "of"),
+        Kind::Template => false,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(input: &str) -> String {
+        JsFormatter.transform(input).unwrap()
+    }
+
+    #[test]
+    fn test_js_formatter_empty() {
+        assert_eq!(format(""), "");
+        assert_eq!(format("  \n"), "");
+    }
+
+    #[test]
+    fn test_js_formatter_default_input() {
+        let transformer = JsFormatter;
+        let expected = "const greet = (name) => {\n  if (!name) {\n    return `Hello, stranger`;\n  }\n  return `Hello, ${name}!`;\n};\nconsole.log(greet(\"buup\"));";
+        assert_eq!(format(transformer.default_test_input()), expected);
+    }
+
+    #[test]
+    fn test_js_formatter_operators() {
+        assert_eq!(
+            format("let x=a+-b*c++;x+=y?1:-1;if(!(a&&b)||c!==d)i--;"),
+            "let x = a + -b * c++;\nx += y ? 1 : -1;\nif (!(a && b) || c !== d) i--;"
+        );
+        assert_eq!(
+            format("a?.b?.[0]??f?.(...args)"),
+            "a?.b?.[0] ?? f?.(...args)"
+        );
+    }
+
+    #[test]
+    fn test_js_formatter_literals() {
+        assert_eq!(format("x={a:1,b:[1,2]}"), "x = { a: 1, b: [1, 2] }");
+        assert_eq!(format("x={};y=[]"), "x = {};\ny = []");
+        // Regular expressions and division
+        assert_eq!(
+            format("r=/[/]+\\//g.test(s)?a/b:c"),
+            "r = /[/]+\\//g.test(s) ? a / b : c"
+        );
+    }
+
+    #[test]
+    fn test_js_formatter_without_semicolons() {
+        assert_eq!(
+            format("let a = 1\nlet b = a\n  + 2\nreturn\nb"),
+            "let a = 1\nlet b = a + 2\nreturn\nb"
+        );
+    }
+
+    #[test]
+    fn test_js_formatter_errors() {
+        let err = JsFormatter.transform("f(a, [b)").unwrap_err();
+        assert_eq!(err.position(), Some(8));
+        let err = JsFormatter.transform("x = {").unwrap_err();
+        assert_eq!(err.position(), Some(5));
+        let err = JsFormatter.transform("s = 'open").unwrap_err();
+        assert!(err.to_string().contains("Unterminated string"));
+    }
+
+    #[test]
+    fn test_js_formatter_indent_option() {
+        let mut options = TransformOptions::new();
+        options.set("indent", "4");
+        assert_eq!(
+            JsFormatter
+                .transform_with_options("if(a){b()}", &options)
+                .unwrap(),
+            "if (a) {\n    b()\n}"
+        );
+        options.set("indent", "100");
+        assert!(JsFormatter.transform_with_options("a", &options).is_err());
+    }
+}
@@ -1,4 +1,20 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::utils::json::{normalize_quotes, push_escaped, unescape, Token, Tokenizer};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// Spaces per level when no `indent` is given
+pub(crate) const DEFAULT_INDENT: usize = 2;
+/// Largest accepted `indent`
+const MAX_INDENT: usize = 16;
+
+pub(crate) const INDENT_OPTION: TransformOption = TransformOption {
+    key: "indent",
+    description: "Spaces per indentation level",
+    default: "2",
+    kind: TransformOptionKind::Integer,
+};
 
 /// JSON Formatter transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,306 +41,136 @@ impl Transform for JsonFormatter {
         r#"{"name":"buup","version":0.1,"features":["cli","web","lib"],"active":true,"config":null}"#
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Nested objects",
+                input: "{\"order\":{\"id\":1042,\"customer\":{\"name\":\"Ada\",\"email\":\"ada@example.com\"},\"items\":[{\"sku\":\"BK-1\",\"qty\":2,\"price\":12.5},{\"sku\":\"PN-7\",\"qty\":1,\"price\":3.99}],\"paid\":false,\"notes\":null}}",
+            },
+            Example {
+                name: "Array of records",
+                input: "[{\"id\":1,\"name\":\"Ada\",\"languages\":[\"en\",\"fr\"]},{\"id\":2,\"name\":\"Grace\",\"languages\":[]}]",
+            },
+            Example {
+                name: "Escaped strings",
+                input: "{\"path\":\"C:\\\\Users\\\\ada\",\"quote\":\"She said \\\"hi\\\"\",\"emoji\":\"\\u263A\",\"newline\":\"a\\nb\"}",
+            },
+        ]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        // Skip empty input
-        if input.trim().is_empty() {
-            return Ok(String::new());
-        }
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
 
-        // Replace smart quotes with regular quotes
-        let normalized_input = input.replace(['\u{201C}', '\u{201D}'], "\"");
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        format(input, DEFAULT_INDENT, token)
+    }
 
-        // First, parse the JSON into tokens
-        let tokens = tokenize_json(&normalized_input)?;
+    fn options(&self) -> &'static [TransformOption] {
+        &[INDENT_OPTION]
+    }
 
-        // Then format the tokens with indentation
-        format_json(&tokens)
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        format(input, indent_value(options)?, token)
     }
 }
 
-/// Different types of JSON tokens
-#[derive(Debug, PartialEq, Eq)]
-enum JsonToken {
-    OpenBrace,    // {
-    CloseBrace,   // }
-    OpenBracket,  // [
-    CloseBracket, // ]
-    Colon,        // :
-    Comma,        // ,
-    String(String),
-    Number(String),
-    Bool(bool),
-    Null,
-    Whitespace,
+/// Reads and range-checks the `indent` option
+pub(crate) fn indent_value(options: &TransformOptions) -> Result<usize, TransformError> {
+    let indent = options.usize_value(&INDENT_OPTION)?;
+    if indent > MAX_INDENT {
+        return Err(TransformError::InvalidArgument(
+            format!("Indent must be at most {} spaces", MAX_INDENT).into(),
+        ));
+    }
+    Ok(indent)
 }
 
-/// Tokenize JSON string into tokens
-fn tokenize_json(input: &str) -> Result<Vec<JsonToken>, TransformError> {
-    let mut tokens = Vec::new();
-    let mut chars = input.chars().peekable();
-    let mut pos = 0;
-
-    while let Some(c) = chars.next() {
-        pos += 1;
-
-        match c {
-            '{' => tokens.push(JsonToken::OpenBrace),
-            '}' => tokens.push(JsonToken::CloseBrace),
-            '[' => tokens.push(JsonToken::OpenBracket),
-            ']' => tokens.push(JsonToken::CloseBracket),
-            ':' => tokens.push(JsonToken::Colon),
-            ',' => tokens.push(JsonToken::Comma),
-            '"' => {
-                let mut string = String::new();
-                let mut escaped = false;
-
-                while let Some(ch) = chars.next() {
-                    pos += 1;
-                    if escaped {
-                        // Handle escape sequences
-                        string.push(match ch {
-                            '"' | '\\' | '/' => ch,
-                            'b' => '\u{0008}',
-                            'f' => '\u{000C}',
-                            'n' => '\n',
-                            'r' => '\r',
-                            't' => '\t',
-                            'u' => {
-                                // Unicode escape: \uXXXX
-                                let mut hex = String::new();
-                                for _ in 0..4 {
-                                    if let Some(h) = chars.next() {
-                                        pos += 1;
-                                        hex.push(h);
-                                    } else {
-                                        return Err(TransformError::JsonParseError(
-                                            "Unexpected end of unicode escape sequence".into(),
-                                        ));
-                                    }
-                                }
-
-                                // Parse the hex digits to a char
-                                match u32::from_str_radix(&hex, 16) {
-                                    Ok(n) => match char::from_u32(n) {
-                                        Some(unicode_char) => unicode_char,
-                                        None => {
-                                            return Err(TransformError::JsonParseError(
-                                                "Invalid unicode escape sequence".into(),
-                                            ))
-                                        }
-                                    },
-                                    Err(_) => {
-                                        return Err(TransformError::JsonParseError(
-                                            "Invalid unicode escape sequence".into(),
-                                        ))
-                                    }
-                                }
-                            }
-                            _ => {
-                                return Err(TransformError::JsonParseError(format!(
-                                    "Invalid escape sequence: \\{}",
-                                    ch
-                                )))
-                            }
-                        });
-                        escaped = false;
-                    } else if ch == '\\' {
-                        escaped = true;
-                    } else if ch == '"' {
-                        break;
-                    } else {
-                        string.push(ch);
-                    }
-                }
-
-                tokens.push(JsonToken::String(string));
-            }
-            '-' | '0'..='9' => {
-                let mut number = String::new();
-                number.push(c);
-
-                // Parse the rest of the number
-                while let Some(&ch) = chars.peek() {
-                    if ch.is_ascii_digit()
-                        || ch == '.'
-                        || ch == 'e'
-                        || ch == 'E'
-                        || ch == '+'
-                        || ch == '-'
-                    {
-                        number.push(ch);
-                        chars.next();
-                        pos += 1;
-                    } else {
-                        break;
-                    }
-                }
+// Pretty-prints `input` with `indent` spaces per level, writing each token
+// out as soon as it is read
+fn format(input: &str, indent: usize, cancel: &CancelToken) -> Result<String, TransformError> {
+    // Skip empty input
+    if input.trim().is_empty() {
+        return Ok(String::new());
+    }
 
-                tokens.push(JsonToken::Number(number));
-            }
-            't' => {
-                // Parse "true"
-                if chars.next() == Some('r')
-                    && chars.next() == Some('u')
-                    && chars.next() == Some('e')
-                {
-                    pos += 3;
-                    tokens.push(JsonToken::Bool(true));
-                } else {
-                    return Err(TransformError::JsonParseError(format!(
-                        "Invalid token at position {}",
-                        pos
-                    )));
-                }
-            }
-            'f' => {
-                // Parse "false"
-                if chars.next() == Some('a')
-                    && chars.next() == Some('l')
-                    && chars.next() == Some('s')
-                    && chars.next() == Some('e')
-                {
-                    pos += 4;
-                    tokens.push(JsonToken::Bool(false));
-                } else {
-                    return Err(TransformError::JsonParseError(format!(
-                        "Invalid token at position {}",
-                        pos
-                    )));
-                }
-            }
-            'n' => {
-                // Parse "null"
-                if chars.next() == Some('u')
-                    && chars.next() == Some('l')
-                    && chars.next() == Some('l')
-                {
-                    pos += 3;
-                    tokens.push(JsonToken::Null);
-                } else {
-                    return Err(TransformError::JsonParseError(format!(
-                        "Invalid token at position {}",
-                        pos
-                    )));
-                }
-            }
-            // Skip whitespace
-            ' ' | '\t' | '\n' | '\r' => {
-                tokens.push(JsonToken::Whitespace);
-            }
-            _ => {
-                return Err(TransformError::JsonParseError(format!(
-                    "Invalid character at position {}",
-                    pos
-                )))
+    let input = normalize_quotes(input);
+    let mut result = String::with_capacity(input.len() * 2);
+    let mut level = 0;
+    // An opening bracket only breaks the line once we know its container
+    // isn't empty
+    let mut pending_open = false;
+
+    for (count, token) in Tokenizer::new(&input).enumerate() {
+        cancel.check_every(count)?;
+        let token = token?;
+
+        if std::mem::take(&mut pending_open) {
+            if matches!(token, Token::EndObject | Token::EndArray) {
+                result.push(if token == Token::EndObject { '}' } else { ']' });
+                continue;
             }
+            level += 1;
+            push_line(&mut result, indent, level);
         }
-    }
-
-    Ok(tokens)
-}
-
-/// Format JSON tokens with proper indentation
-fn format_json(tokens: &[JsonToken]) -> Result<String, TransformError> {
-    let mut result = String::new();
-    let mut indent_level = 0;
-    let indent = "  "; // Two spaces per indent level
-    let mut idx = 0;
-    let tokens_len = tokens.len();
-
-    while idx < tokens_len {
-        let token = &tokens[idx];
 
         match token {
-            JsonToken::OpenBrace | JsonToken::OpenBracket => {
-                result.push(if token == &JsonToken::OpenBrace {
+            Token::BeginObject | Token::BeginArray => {
+                result.push(if token == Token::BeginObject {
                     '{'
                 } else {
                     '['
                 });
-
-                // Check if the next non-whitespace token is a closing bracket
-                let mut peek_idx = idx + 1;
-                let mut empty = false;
-                while peek_idx < tokens_len {
-                    match &tokens[peek_idx] {
-                        JsonToken::Whitespace => peek_idx += 1,
-                        JsonToken::CloseBrace | JsonToken::CloseBracket => {
-                            empty = true;
-                            break;
-                        }
-                        _ => break,
-                    }
-                }
-
-                if !empty {
-                    indent_level += 1;
-                    result.push('\n');
-                    result.push_str(&indent.repeat(indent_level));
-                }
+                pending_open = true;
             }
-            JsonToken::CloseBrace | JsonToken::CloseBracket => {
-                if indent_level > 0 {
-                    // Check if previous non-whitespace token was an opening bracket (empty array/object)
-                    let mut peek_idx = idx - 1;
-                    let mut is_empty = false;
-                    while peek_idx > 0 {
-                        match &tokens[peek_idx] {
-                            JsonToken::Whitespace => peek_idx -= 1,
-                            JsonToken::OpenBrace | JsonToken::OpenBracket => {
-                                is_empty = true;
-                                break;
-                            }
-                            _ => break,
-                        }
-                    }
-
-                    if !is_empty {
-                        indent_level -= 1;
-                        result.push('\n');
-                        result.push_str(&indent.repeat(indent_level));
-                    }
+            Token::EndObject | Token::EndArray => {
+                if level > 0 {
+                    level -= 1;
+                    push_line(&mut result, indent, level);
                 }
-                result.push(if token == &JsonToken::CloseBrace {
-                    '}'
-                } else {
-                    ']'
-                });
-            }
-            JsonToken::Colon => {
-                result.push(':');
-                result.push(' '); // Add space after colon
+                result.push(if token == Token::EndObject { '}' } else { ']' });
             }
-            JsonToken::Comma => {
+            Token::Colon => result.push_str(": "),
+            Token::Comma => {
                 result.push(',');
-                result.push('\n');
-                result.push_str(&indent.repeat(indent_level));
+                push_line(&mut result, indent, level);
             }
-            JsonToken::String(s) => {
+            Token::String(raw) => {
+                // Decoded and escaped again so escapes come out the same way
+                // whichever form the input used
                 result.push('"');
-                result.push_str(s);
+                push_escaped(&mut result, &unescape(raw)?);
                 result.push('"');
             }
-            JsonToken::Number(n) => {
-                result.push_str(n);
-            }
-            JsonToken::Bool(b) => {
-                result.push_str(if *b { "true" } else { "false" });
-            }
-            JsonToken::Null => {
-                result.push_str("null");
-            }
-            JsonToken::Whitespace => {
-                // Skip whitespace tokens
-            }
+            Token::Number(number) => result.push_str(number),
+            Token::Bool(value) => result.push_str(if value { "true" } else { "false" }),
+            Token::Null => result.push_str("null"),
         }
+    }
 
-        idx += 1;
+    // Input cut off right after an opening bracket
+    if pending_open {
+        push_line(&mut result, indent, level + 1);
     }
 
     Ok(result)
 }
 
+// Starts a new line indented to `level`
+fn push_line(result: &mut String, indent: usize, level: usize) {
+    result.push('\n');
+    result.extend(std::iter::repeat_n(' ', indent * level));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,4 +231,62 @@ mod tests {
         let expected = "{\n  \"name\": \"buup\",\n  \"message\": \"Hello world\",\n  \"smart_left\": \"testing\",\n  \"smart_right\": \"more testing\"\n}";
         assert_eq!(transformer.transform(input).unwrap(), expected);
     }
+
+    #[test]
+    fn test_json_formatter_preserves_escapes() {
+        let transformer = JsonFormatter;
+        let input = r#"{"quote":"Li \"Lee\" Wei","path":"C:\\tmp","lines":"a\nb\tc"}"#;
+        let expected = "{\n  \"quote\": \"Li \\\"Lee\\\" Wei\",\n  \"path\": \"C:\\\\tmp\",\n  \"lines\": \"a\\nb\\tc\"\n}";
+        assert_eq!(transformer.transform(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_json_formatter_surrogate_pairs() {
+        let transformer = JsonFormatter;
+        assert_eq!(
+            transformer.transform(r#"["\ud83d\ude00"]"#).unwrap(),
+            "[\n  \"😀\"\n]"
+        );
+        assert!(transformer.transform(r#"["\ud83d"]"#).is_err());
+    }
+
+    #[test]
+    fn test_json_formatter_large_input() {
+        // A few megabytes, formatted in one pass and minified back
+        let record =
+            r#"{"id":1,"tags":["a","b\"c"],"nested":{"x":1.5,"ok":true,"n":null},"empty":[]}"#;
+        let input = format!("[{}]", vec![record; 40_000].join(","));
+        let formatted = JsonFormatter.transform(&input).unwrap();
+        assert!(formatted.starts_with("[\n  {\n    \"id\": 1,"));
+        assert_eq!(
+            crate::transformers::JsonMinifier
+                .transform(&formatted)
+                .unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test_json_formatter_indent_option() {
+        let transformer = JsonFormatter;
+        let mut options = TransformOptions::new();
+        options.set("indent", "4");
+        assert_eq!(
+            transformer
+                .transform_with_options(r#"{"a":[1]}"#, &options)
+                .unwrap(),
+            "{\n    \"a\": [\n        1\n    ]\n}"
+        );
+        options.set("indent", "0");
+        assert_eq!(
+            transformer
+                .transform_with_options(r#"{"a":1}"#, &options)
+                .unwrap(),
+            "{\n\"a\": 1\n}"
+        );
+        options.set("indent", "100");
+        assert!(transformer
+            .transform_with_options(r#"{"a":1}"#, &options)
+            .is_err());
+    }
 }
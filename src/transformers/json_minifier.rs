@@ -1,4 +1,5 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::utils::json::{normalize_quotes, Token, Tokenizer};
+use crate::{CancelToken, Example, Transform, TransformError, TransformerCategory};
 
 /// JSON Minifier transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -35,78 +36,62 @@ impl Transform for JsonMinifier {
 }"#
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Formatted object",
+                input: "{\n  \"name\": \"buup\",\n  \"tags\": [\n    \"cli\",\n    \"web\"\n  ],\n  \"private\": false\n}",
+            },
+            Example {
+                name: "Strings with spaces",
+                input: "{ \"greeting\" : \"hello,   world\" , \"list\" : [ 1 , 2 , 3 ] }",
+            },
+        ]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
         // Skip empty input
         if input.trim().is_empty() {
             return Ok(String::new());
         }
 
-        // Replace smart quotes with regular quotes
-        let normalized_input = input.replace(['\u{201C}', '\u{201D}'], "\"");
-
-        minify_json(&normalized_input)
+        minify_json(&normalize_quotes(input), token)
     }
 }
 
-/// Minify JSON by removing all unnecessary whitespace
-fn minify_json(input: &str) -> Result<String, TransformError> {
+/// Minify JSON by removing all unnecessary whitespace. Strings and numbers
+/// are copied exactly as written.
+fn minify_json(input: &str, cancel: &CancelToken) -> Result<String, TransformError> {
     let mut result = String::with_capacity(input.len());
-    let chars = input.chars();
-    let mut in_string = false;
-    let mut escaped = false;
-
-    for c in chars {
-        if in_string {
-            // Always include characters within strings
-            result.push(c);
-
-            if escaped {
-                // Previous character was escape - this character is always included
-                escaped = false;
-            } else if c == '\\' {
-                escaped = true;
-            } else if c == '"' {
-                in_string = false;
-            }
-        } else {
-            match c {
-                // Start of a string - always include the quote and set flag
-                '"' => {
-                    result.push(c);
-                    in_string = true;
-                }
-                // Structural characters - always include
-                '{' | '}' | '[' | ']' | ':' | ',' => {
-                    result.push(c);
-                }
-                // Whitespace outside a string - skip
-                ' ' | '\t' | '\n' | '\r' => {
-                    // Skip whitespace
-                }
-                // Numbers, booleans, null - include
-                '0'..='9' | '-' | '+' | '.' | 'e' | 'E' | 't' | 'f' | 'n' => {
-                    result.push(c);
-                }
-                // Other characters - could be part of literals (true, false, null)
-                'a'..='z' | 'A'..='Z' => {
-                    result.push(c);
-                }
-                // Invalid characters
-                _ => {
-                    return Err(TransformError::JsonParseError(format!(
-                        "Invalid character: '{}'",
-                        c
-                    )))
-                }
+
+    for (count, token) in Tokenizer::new(input).enumerate() {
+        cancel.check_every(count)?;
+        match token? {
+            Token::BeginObject => result.push('{'),
+            Token::EndObject => result.push('}'),
+            Token::BeginArray => result.push('['),
+            Token::EndArray => result.push(']'),
+            Token::Colon => result.push(':'),
+            Token::Comma => result.push(','),
+            Token::String(raw) => {
+                result.push('"');
+                result.push_str(raw);
+                result.push('"');
             }
+            Token::Number(number) => result.push_str(number),
+            Token::Bool(value) => result.push_str(if value { "true" } else { "false" }),
+            Token::Null => result.push_str("null"),
         }
     }
 
-    // Ensure we're not in the middle of a string
-    if in_string {
-        return Err(TransformError::JsonParseError("Unterminated string".into()));
-    }
-
     Ok(result)
 }
 
@@ -180,4 +165,14 @@ mod tests {
         let expected = r#"{"test":"value"}"#;
         assert_eq!(transformer.transform(input).unwrap(), expected);
     }
+
+    #[test]
+    fn test_json_minifier_error_position() {
+        let transformer = JsonMinifier;
+        let err = transformer.transform("{\n  \"a\": #1\n}").unwrap_err();
+        assert_eq!(err.position(), Some(10));
+
+        let err = transformer.transform(r#"{"a": "open}"#).unwrap_err();
+        assert_eq!(err.position(), Some(7));
+    }
 }
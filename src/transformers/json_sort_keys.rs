@@ -0,0 +1,258 @@
+use crate::utils::json::{normalize_quotes, push_escaped, JsonValue};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const CANONICAL_OPTION: TransformOption = TransformOption {
+    key: "canonical",
+    description: "Write the RFC 8785 (JCS) canonical form: compact, keys in UTF-16 order, numbers as JavaScript prints them",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// Sorts JSON object keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonSortKeys;
+
+impl Transform for JsonSortKeys {
+    fn name(&self) -> &'static str {
+        "JSON Sort Keys"
+    }
+
+    fn id(&self) -> &'static str {
+        "json_sort_keys"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sorts the keys of every JSON object, at any depth, so documents can be diffed or hashed. Optionally writes the RFC 8785 canonical form."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Formatter
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[CANONICAL_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let mut value = JsonValue::parse_with_cancel(&normalize_quotes(input), token)?;
+        if options.bool_value(&CANONICAL_OPTION)? {
+            let mut out = String::new();
+            write_canonical(&value, &mut out)?;
+            Ok(out)
+        } else {
+            sort_keys(&mut value);
+            Ok(value.to_pretty_string(2))
+        }
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        r#"{"name":"buup","version":"1.0","dependencies":{"zlib":"^1","anyhow":"^1"},"authors":[{"role":"dev","name":"Ada"}]}"#
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Config file",
+                input: "{\"server\":{\"port\":8080,\"host\":\"0.0.0.0\"},\"debug\":false,\"cache\":{\"ttl\":60,\"enabled\":true}}",
+            },
+            Example {
+                name: "Numbers and escapes",
+                input: "{\"b\":1.50,\"a\":1e21,\"\\u00e9\":\"caf\\u00e9\",\"c\":[3,2,1]}",
+            },
+        ]
+    }
+}
+
+// Sorts keys by code point, keeping repeated keys in their original order
+fn sort_keys(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(entries) => {
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (_, value) in entries {
+                sort_keys(value);
+            }
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(sort_keys),
+        _ => {}
+    }
+}
+
+// RFC 8785: keys sorted by UTF-16 code units, no whitespace, minimal string
+// escapes and ECMAScript number formatting. Duplicate keys are an error as
+// canonical JSON must be I-JSON.
+fn write_canonical(value: &JsonValue, out: &mut String) -> Result<(), TransformError> {
+    match value {
+        JsonValue::Number(text) => out.push_str(&canonical_number(text)?),
+        JsonValue::String(text) => {
+            out.push('"');
+            push_escaped(out, text);
+            out.push('"');
+        }
+        JsonValue::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out)?;
+            }
+            out.push(']');
+        }
+        JsonValue::Object(entries) => {
+            let mut sorted: Vec<&(String, JsonValue)> = entries.iter().collect();
+            sorted.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            if let Some(pair) = sorted.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+                return Err(TransformError::InvalidArgument(
+                    format!("Duplicate key '{}' has no canonical form", pair[0].0).into(),
+                ));
+            }
+            out.push('{');
+            for (i, (key, value)) in sorted.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push('"');
+                push_escaped(out, key);
+                out.push_str("\":");
+                write_canonical(value, out)?;
+            }
+            out.push('}');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+    Ok(())
+}
+
+// A JSON number as JavaScript's Number.prototype.toString writes the
+// nearest double, as RFC 8785 section 3.2.2.3 requires
+fn canonical_number(text: &str) -> Result<String, TransformError> {
+    let value: f64 = text
+        .parse()
+        .ok()
+        .filter(|value: &f64| value.is_finite())
+        .ok_or_else(|| {
+            TransformError::InvalidArgument(
+                format!("{} is out of range for canonical JSON", text).into(),
+            )
+        })?;
+    if value == 0.0 {
+        return Ok("0".to_string());
+    }
+    // Shortest round-trip digits and the exponent of the first one
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().unwrap_or(0) + 1;
+    let sign = if value < 0.0 { "-" } else { "" };
+
+    let body = if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat(-n as usize), digits)
+    } else {
+        let exponent = n - 1;
+        let exponent_sign = if exponent < 0 { '-' } else { '+' };
+        let fraction = if k > 1 {
+            format!("{}.{}", &digits[..1], &digits[1..])
+        } else {
+            digits.clone()
+        };
+        format!("{}e{}{}", fraction, exponent_sign, exponent.abs())
+    };
+    Ok(format!("{}{}", sign, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(input: &str) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("canonical", "true");
+        JsonSortKeys.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_sort_keys() {
+        assert_eq!(
+            JsonSortKeys
+                .transform(JsonSortKeys.default_test_input())
+                .unwrap(),
+            "{\n  \"authors\": [\n    {\n      \"name\": \"Ada\",\n      \"role\": \"dev\"\n    }\n  ],\n  \"dependencies\": {\n    \"anyhow\": \"^1\",\n    \"zlib\": \"^1\"\n  },\n  \"name\": \"buup\",\n  \"version\": \"1.0\"\n}"
+        );
+        // Number text is kept outside canonical mode
+        assert_eq!(
+            JsonSortKeys.transform(r#"[{"b":1.50,"a":1E3}]"#).unwrap(),
+            "[\n  {\n    \"a\": 1E3,\n    \"b\": 1.50\n  }\n]"
+        );
+    }
+
+    #[test]
+    fn test_canonical_form() {
+        // The example from RFC 8785 section 3.2.3
+        assert_eq!(
+            canonical(
+                r#"{
+                    "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+                    "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+                    "literals": [null, true, false]
+                }"#
+            )
+            .unwrap(),
+            r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+        );
+        // Keys sort by UTF-16 code units, so U+1F600 comes before U+FB33
+        assert_eq!(
+            canonical("{\"\u{fb33}\":1,\"\u{1f600}\":2,\"a\":3}").unwrap(),
+            "{\"a\":3,\"\u{1f600}\":2,\"\u{fb33}\":1}"
+        );
+    }
+
+    #[test]
+    fn test_canonical_numbers() {
+        for (input, expected) in [
+            ("0", "0"),
+            ("-0", "0"),
+            ("1", "1"),
+            ("-1.5", "-1.5"),
+            ("100", "100"),
+            ("1e21", "1e+21"),
+            ("1e20", "100000000000000000000"),
+            ("123456789012345678901234", "1.2345678901234569e+23"),
+            ("0.000001", "0.000001"),
+            ("0.0000001", "1e-7"),
+            ("1.25e-8", "1.25e-8"),
+            ("9007199254740993", "9007199254740992"),
+        ] {
+            assert_eq!(canonical_number(input).unwrap(), expected, "{}", input);
+        }
+        assert!(canonical_number("1e400").is_err());
+    }
+
+    #[test]
+    fn test_canonical_errors() {
+        assert!(canonical(r#"{"a":1,"a":2}"#).is_err());
+        assert!(canonical("[1e999]").is_err());
+        assert!(JsonSortKeys.transform("{").is_err());
+        // Duplicates are fine when only sorting
+        assert_eq!(
+            JsonSortKeys.transform(r#"{"b":1,"a":2,"b":3}"#).unwrap(),
+            "{\n  \"a\": 2,\n  \"b\": 1,\n  \"b\": 3\n}"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use super::bencode_to_json::HEX_KEY;
+use super::hex_decode;
+use crate::utils::json::JsonValue;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// JSON to Bencode transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonToBencode;
+
+impl Transform for JsonToBencode {
+    fn name(&self) -> &'static str {
+        "JSON to Bencode"
+    }
+
+    fn id(&self) -> &'static str {
+        "jsontobencode"
+    }
+
+    fn description(&self) -> &'static str {
+        "Encodes JSON as Bencode with sorted dictionary keys. Integers, strings, arrays and objects only; {\"$hex\": \"...\"} objects become raw byte strings."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let output = self.transform_bytes(input.as_bytes())?;
+        String::from_utf8(output).map_err(|_| {
+            TransformError::InvalidArgument(
+                "The Bencode holds binary strings, write it as raw bytes instead".into(),
+            )
+        })
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let text = std::str::from_utf8(input).map_err(|_| TransformError::Utf8Error)?;
+        if text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut out = Vec::new();
+        encode(&JsonValue::parse(text)?, "$", &mut out)?;
+        Ok(out)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        r#"{"announce": "http://tracker.example.com/announce", "info": {"name": "notes.txt", "piece length": 262144, "length": 1048576}}"#
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Tracker response",
+            input: r#"{"interval": 1800, "peers": [{"ip": "203.0.113.7", "port": 6881}], "complete": 12, "incomplete": 3}"#,
+        }]
+    }
+}
+
+// Appends the Bencode for `value`, found at `path` (for error messages)
+fn encode(value: &JsonValue, path: &str, out: &mut Vec<u8>) -> Result<(), TransformError> {
+    let unsupported = |what: &str| {
+        TransformError::InvalidArgument(format!("Bencode has no {} (at {})", what, path).into())
+    };
+    match value {
+        JsonValue::Number(number) => {
+            let integer = number.trim_start_matches('-');
+            if integer.is_empty() || !integer.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(TransformError::InvalidArgument(
+                    format!("{} at {} is not an integer", number, path).into(),
+                ));
+            }
+            let number = if integer == "0" { "0" } else { number };
+            out.push(b'i');
+            out.extend(number.as_bytes());
+            out.push(b'e');
+        }
+        JsonValue::String(text) => push_string(out, text.as_bytes()),
+        JsonValue::Array(items) => {
+            out.push(b'l');
+            for (index, item) in items.iter().enumerate() {
+                encode(item, &format!("{}[{}]", path, index), out)?;
+            }
+            out.push(b'e');
+        }
+        JsonValue::Object(entries) => {
+            if let [(key, JsonValue::String(hex))] = entries.as_slice() {
+                if key == HEX_KEY {
+                    let bytes = hex_decode::decode(hex).map_err(|_| {
+                        TransformError::InvalidArgument(
+                            format!("{}.{} is not valid hex", path, HEX_KEY).into(),
+                        )
+                    })?;
+                    push_string(out, &bytes);
+                    return Ok(());
+                }
+            }
+            // Keys are sorted as raw bytes, which matches sorting UTF-8 strings
+            let mut sorted: Vec<&(String, JsonValue)> = entries.iter().collect();
+            sorted.sort_by(|a, b| a.0.cmp(&b.0));
+            if let Some(pair) = sorted.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+                return Err(TransformError::InvalidArgument(
+                    format!("Duplicate key \"{}\" at {}", pair[0].0, path).into(),
+                ));
+            }
+            out.push(b'd');
+            for (key, value) in sorted {
+                push_string(out, key.as_bytes());
+                encode(value, &format!("{}.{}", path, key), out)?;
+            }
+            out.push(b'e');
+        }
+        JsonValue::Bool(_) => return Err(unsupported("booleans")),
+        JsonValue::Null => return Err(unsupported("null")),
+    }
+    Ok(())
+}
+
+fn push_string(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend(bytes.len().to_string().as_bytes());
+    out.push(b':');
+    out.extend(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::BencodeToJson;
+
+    #[test]
+    fn test_json_to_bencode() {
+        assert_eq!(
+            JsonToBencode
+                .transform(JsonToBencode.default_test_input())
+                .unwrap(),
+            BencodeToJson.default_test_input()
+        );
+        assert_eq!(
+            JsonToBencode
+                .transform(r#"["é", -7, -0, [], {}, {"b": 1, "a": 2, "B": 3}]"#)
+                .unwrap(),
+            "l2:éi-7ei0eleded1:Bi3e1:ai2e1:bi1eee"
+        );
+        assert_eq!(JsonToBencode.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_json_to_bencode_binary() {
+        let json = "{\"pieces\": {\"$hex\": \"00ff1080\"}, \"name\": \"x\"}";
+        let bytes = JsonToBencode.transform_bytes(json.as_bytes()).unwrap();
+        assert_eq!(bytes, b"d4:name1:x6:pieces4:\x00\xff\x10\x80e");
+        assert!(JsonToBencode.transform(json).is_err());
+
+        // Round trip through BencodeToJson, which keeps the sorted key order
+        let json = String::from_utf8(BencodeToJson.transform_bytes(&bytes).unwrap()).unwrap();
+        assert_eq!(
+            JsonToBencode.transform_bytes(json.as_bytes()).unwrap(),
+            bytes
+        );
+    }
+
+    #[test]
+    fn test_json_to_bencode_errors() {
+        let error = |input: &str| JsonToBencode.transform(input).unwrap_err().to_string();
+        assert_eq!(
+            error(r#"{"a": [1, 2.5]}"#),
+            "Invalid argument: 2.5 at $.a[1] is not an integer"
+        );
+        assert_eq!(
+            error(r#"{"private": true}"#),
+            "Invalid argument: Bencode has no booleans (at $.private)"
+        );
+        assert_eq!(
+            error("[null]"),
+            "Invalid argument: Bencode has no null (at $[0])"
+        );
+        assert_eq!(
+            error(r#"{"a": 1, "a": 2}"#),
+            "Invalid argument: Duplicate key \"a\" at $"
+        );
+        assert!(JsonToBencode.transform(r#"{"$hex": "zz"}"#).is_err());
+        assert!(JsonToBencode.transform("1e5").is_err());
+    }
+}
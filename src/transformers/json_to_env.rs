@@ -0,0 +1,178 @@
+use super::env_to_json::is_valid_key;
+use crate::utils::json::JsonValue;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const EXPORT_OPTION: TransformOption = TransformOption {
+    key: "export",
+    description: "Start each line with 'export ' so the file can be sourced by a shell",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// JSON to .env (dotenv) transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonToEnv;
+
+impl Transform for JsonToEnv {
+    fn name(&self) -> &'static str {
+        "JSON to .env"
+    }
+
+    fn id(&self) -> &'static str {
+        "jsontoenv"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts a flat JSON object into .env lines, quoting values only when they need it. Nested arrays and objects are written as compact JSON."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[EXPORT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let prefix = if options.bool_value(&EXPORT_OPTION)? {
+            "export "
+        } else {
+            ""
+        };
+        if input.trim().is_empty() {
+            return Ok(String::new());
+        }
+        let JsonValue::Object(entries) = JsonValue::parse_with_cancel(input.trim(), token)? else {
+            return Err(TransformError::JsonParseError(
+                "Input must be a JSON object".to_string(),
+            ));
+        };
+        let lines = entries
+            .iter()
+            .map(|(key, value)| {
+                if !is_valid_key(key) {
+                    return Err(TransformError::InvalidArgument(
+                        format!("'{}' is not a valid variable name", key).into(),
+                    ));
+                }
+                let value = match value {
+                    JsonValue::Null => String::new(),
+                    JsonValue::String(text) => text.clone(),
+                    other => other.to_string(),
+                };
+                Ok(format!("{}{}={}", prefix, key, quote(&value)))
+            })
+            .collect::<Result<Vec<_>, TransformError>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        r#"{"DB_HOST": "localhost", "DB_PORT": 5432, "API_KEY": "s3cr3t\nline two", "GREETING": "Hello, $USER"}"#
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Flags and lists",
+                input: r#"{"DEBUG": true, "ALLOWED_HOSTS": ["buup.io", "localhost"], "TIMEOUT": null}"#,
+            },
+            Example {
+                name: "Quotes inside values",
+                input: r#"{"MOTTO": "Don't \"panic\"", "PATH_HINT": "C:\\tools"}"#,
+            },
+        ]
+    }
+}
+
+// Plain values are left bare. Others go in single quotes, which keep
+// everything literally, or in double quotes with escapes when they hold a
+// single quote or line break.
+fn quote(value: &str) -> String {
+    if value.chars().all(|c| {
+        c.is_ascii_alphanumeric()
+            || matches!(c, '_' | '-' | '.' | ',' | '/' | ':' | '@' | '+' | '%')
+    }) {
+        return value.to_string();
+    }
+    if !value.contains(['\'', '\n', '\r']) {
+        return format!("'{}'", value);
+    }
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::EnvToJson;
+
+    #[test]
+    fn test_json_to_env() {
+        assert_eq!(
+            JsonToEnv.transform(JsonToEnv.default_test_input()).unwrap(),
+            "DB_HOST=localhost\nDB_PORT=5432\nAPI_KEY=\"s3cr3t\\nline two\"\nGREETING='Hello, $USER'"
+        );
+        assert_eq!(
+            JsonToEnv
+                .transform(r#"{"DEBUG": true, "HOSTS": ["a", "b"], "TIMEOUT": null, "EMPTY": ""}"#)
+                .unwrap(),
+            "DEBUG=true\nHOSTS='[\"a\",\"b\"]'\nTIMEOUT=\nEMPTY="
+        );
+        assert_eq!(JsonToEnv.transform("").unwrap(), "");
+        assert_eq!(JsonToEnv.transform("{}").unwrap(), "");
+    }
+
+    #[test]
+    fn test_json_to_env_export() {
+        let mut options = TransformOptions::new();
+        options.set("export", "true");
+        assert_eq!(
+            JsonToEnv
+                .transform_with_options(r#"{"A": "1", "B": "two words"}"#, &options)
+                .unwrap(),
+            "export A=1\nexport B='two words'"
+        );
+    }
+
+    #[test]
+    fn test_json_to_env_round_trip() {
+        let json = r##"{"A": "it's \"quoted\"", "B": "C:\\dir\\n", "C": "a\nb\tc", "D": "# not a comment"}"##;
+        let env = JsonToEnv.transform(json).unwrap();
+        assert_eq!(
+            EnvToJson.transform(&env).unwrap(),
+            JsonValue::parse(json).unwrap().to_pretty_string(2)
+        );
+    }
+
+    #[test]
+    fn test_json_to_env_invalid() {
+        assert!(JsonToEnv.transform("[1, 2]").is_err());
+        assert!(JsonToEnv.transform(r#"{"MY KEY": 1}"#).is_err());
+        assert!(JsonToEnv.transform("{").is_err());
+    }
+}
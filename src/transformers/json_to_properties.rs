@@ -0,0 +1,211 @@
+use crate::utils::json::JsonValue;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const ESCAPE_UNICODE_OPTION: TransformOption = TransformOption {
+    key: "escape_unicode",
+    description: "Write non-ASCII characters as \\uXXXX, as Java 8 and earlier expect",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// JSON to Java .properties transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonToProperties;
+
+impl Transform for JsonToProperties {
+    fn name(&self) -> &'static str {
+        "JSON to Properties"
+    }
+
+    fn id(&self) -> &'static str {
+        "jsontoproperties"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts a JSON object into Java .properties lines, flattening nested objects to dotted keys and arrays to key[0], key[1], ..."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[ESCAPE_UNICODE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let escape_unicode = options.bool_value(&ESCAPE_UNICODE_OPTION)?;
+        if input.trim().is_empty() {
+            return Ok(String::new());
+        }
+        let value = JsonValue::parse_with_cancel(input.trim(), token)?;
+        if !matches!(value, JsonValue::Object(_)) {
+            return Err(TransformError::JsonParseError(
+                "Input must be a JSON object".to_string(),
+            ));
+        }
+        let mut properties = Vec::new();
+        flatten(&value, String::new(), &mut properties);
+        let lines: Vec<String> = properties
+            .iter()
+            .map(|(key, value)| {
+                format!(
+                    "{}={}",
+                    escape(key, true, escape_unicode),
+                    escape(value, false, escape_unicode)
+                )
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        r#"{"spring": {"datasource": {"url": "jdbc:postgresql://localhost/app", "username": "admin"}}, "greeting": "Grüße, world!", "servers": ["alpha", "beta"]}"#
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Flat keys",
+                input: r#"{"server.port": 8080, "debug": true, "banner": "  padded"}"#,
+            },
+            Example {
+                name: "Keys needing escapes",
+                input: r#"{"key with spaces": "a=b", "multi": "line 1\nline 2"}"#,
+            },
+        ]
+    }
+}
+
+// Every leaf of `value` under its dotted path, arrays indexed like
+// Spring's server[0]. Empty arrays and objects become empty values.
+fn flatten(value: &JsonValue, path: String, out: &mut Vec<(String, String)>) {
+    match value {
+        JsonValue::Object(entries) if !entries.is_empty() => {
+            for (key, value) in entries {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                flatten(value, path, out);
+            }
+        }
+        JsonValue::Array(items) if !items.is_empty() => {
+            for (i, item) in items.iter().enumerate() {
+                flatten(item, format!("{}[{}]", path, i), out);
+            }
+        }
+        JsonValue::Object(_) | JsonValue::Array(_) | JsonValue::Null => {
+            out.push((path, String::new()))
+        }
+        JsonValue::String(text) => out.push((path, text.clone())),
+        other => out.push((path, other.to_string())),
+    }
+}
+
+// Escapes as java.util.Properties.store does: separators and comment
+// characters in keys, leading spaces in values, and control characters
+fn escape(text: &str, is_key: bool, escape_unicode: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\u{000C}' => out.push_str("\\f"),
+            ' ' if is_key || i == 0 => out.push_str("\\ "),
+            '=' | ':' | '#' | '!' if is_key => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if escape_unicode && !c.is_ascii() || c.is_control() => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    out.push_str(&format!("\\u{:04x}", unit));
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::PropertiesToJson;
+
+    #[test]
+    fn test_json_to_properties() {
+        assert_eq!(
+            JsonToProperties
+                .transform(JsonToProperties.default_test_input())
+                .unwrap(),
+            "spring.datasource.url=jdbc:postgresql://localhost/app\nspring.datasource.username=admin\ngreeting=Grüße, world!\nservers[0]=alpha\nservers[1]=beta"
+        );
+        assert_eq!(
+            JsonToProperties
+                .transform(r#"{"n": 1.5, "b": false, "z": null, "e": [], "o": {}}"#)
+                .unwrap(),
+            "n=1.5\nb=false\nz=\ne=\no="
+        );
+        assert_eq!(JsonToProperties.transform(" ").unwrap(), "");
+    }
+
+    #[test]
+    fn test_json_to_properties_escapes() {
+        assert_eq!(
+            JsonToProperties
+                .transform(
+                    r#"{"a key=x:y#!": "  two spaces", "path": "C:\\dir", "lines": "1\n2\t3"}"#
+                )
+                .unwrap(),
+            "a\\ key\\=x\\:y\\#\\!=\\  two spaces\npath=C:\\\\dir\nlines=1\\n2\\t3"
+        );
+        let mut options = TransformOptions::new();
+        options.set("escape_unicode", "true");
+        assert_eq!(
+            JsonToProperties
+                .transform_with_options(r#"{"ä": "é😀"}"#, &options)
+                .unwrap(),
+            "\\u00e4=\\u00e9\\ud83d\\ude00"
+        );
+    }
+
+    #[test]
+    fn test_json_to_properties_round_trip() {
+        let json = r#"{"a key=x": "  lead", "b": "C:\\dir\\", "c": "x\ny", "d#": "é😀", "e": ""}"#;
+        for escape_unicode in ["false", "true"] {
+            let mut options = TransformOptions::new();
+            options.set("escape_unicode", escape_unicode);
+            let properties = JsonToProperties
+                .transform_with_options(json, &options)
+                .unwrap();
+            assert_eq!(
+                PropertiesToJson.transform(&properties).unwrap(),
+                JsonValue::parse(json).unwrap().to_pretty_string(2)
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_to_properties_invalid() {
+        assert!(JsonToProperties.transform("[1]").is_err());
+        assert!(JsonToProperties.transform("\"text\"").is_err());
+        assert!(JsonToProperties.transform("{").is_err());
+    }
+}
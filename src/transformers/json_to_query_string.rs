@@ -0,0 +1,166 @@
+use super::url_encode::url_encode;
+use crate::utils::json::JsonValue;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const ARRAYS_OPTION: TransformOption = TransformOption {
+    key: "arrays",
+    description:
+        "How array items are written: brackets (tags[]=a&tags[]=b) or repeat (tags=a&tags=b)",
+    default: "brackets",
+    kind: TransformOptionKind::Choice(&["brackets", "repeat"]),
+};
+
+/// JSON to URL query string transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonToQueryString;
+
+impl Transform for JsonToQueryString {
+    fn name(&self) -> &'static str {
+        "JSON to Query String"
+    }
+
+    fn id(&self) -> &'static str {
+        "jsontoquerystring"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts a JSON object into a URL query string, percent-encoding keys and values and writing nested objects as key[sub]=value"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[ARRAYS_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let repeat = options.value(&ARRAYS_OPTION) == "repeat";
+        if input.trim().is_empty() {
+            return Ok(String::new());
+        }
+        let JsonValue::Object(entries) = JsonValue::parse_with_cancel(input, token)? else {
+            return Err(TransformError::JsonParseError(
+                "Input must be a JSON object".to_string(),
+            ));
+        };
+        let mut pairs = Vec::new();
+        for (key, value) in &entries {
+            write_pairs(&url_encode(key), value, repeat, &mut pairs);
+        }
+        Ok(pairs.join("&"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        r#"{"q": "rust wasm", "page": 2, "tags": ["cli", "web"], "filter": {"lang": "en", "sort": "new first"}}"#
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Tracking parameters",
+                input: r#"{"utm_source": "mail", "utm_campaign": "spring 2024", "ref": "a&b=c"}"#,
+            },
+            Example {
+                name: "Flags",
+                input: r#"{"debug": true, "limit": 50, "cursor": null}"#,
+            },
+        ]
+    }
+}
+
+// `key=value` pairs for `value` under the already encoded `key`. Brackets
+// are left readable rather than encoded as %5B and %5D.
+fn write_pairs(key: &str, value: &JsonValue, repeat: bool, pairs: &mut Vec<String>) {
+    match value {
+        JsonValue::Object(entries) => {
+            for (name, value) in entries {
+                write_pairs(
+                    &format!("{}[{}]", key, url_encode(name)),
+                    value,
+                    repeat,
+                    pairs,
+                );
+            }
+        }
+        JsonValue::Array(items) => {
+            let key = if repeat {
+                key.to_string()
+            } else {
+                format!("{}[]", key)
+            };
+            for item in items {
+                write_pairs(&key, item, repeat, pairs);
+            }
+        }
+        JsonValue::Null => pairs.push(format!("{}=", key)),
+        JsonValue::String(text) => pairs.push(format!("{}={}", key, url_encode(text))),
+        other => pairs.push(format!("{}={}", key, other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::QueryStringToJson;
+
+    #[test]
+    fn test_json_to_query_string() {
+        assert_eq!(
+            JsonToQueryString
+                .transform(JsonToQueryString.default_test_input())
+                .unwrap(),
+            "q=rust+wasm&page=2&tags[]=cli&tags[]=web&filter[lang]=en&filter[sort]=new+first"
+        );
+        assert_eq!(
+            JsonToQueryString
+                .transform(r#"{"a&b": "c=d", "é": "ü", "n": null, "t": true, "e": []}"#)
+                .unwrap(),
+            "a%26b=c%3Dd&%C3%A9=%C3%BC&n=&t=true"
+        );
+        assert_eq!(JsonToQueryString.transform("{}").unwrap(), "");
+    }
+
+    #[test]
+    fn test_json_to_query_string_repeat() {
+        let mut options = TransformOptions::new();
+        options.set("arrays", "repeat");
+        assert_eq!(
+            JsonToQueryString
+                .transform_with_options(r#"{"color": ["red", "blue"], "size": "M"}"#, &options)
+                .unwrap(),
+            "color=red&color=blue&size=M"
+        );
+    }
+
+    #[test]
+    fn test_json_to_query_string_round_trip() {
+        let json = r#"{"q":"a b&c","tags":["x","y"],"user":{"name":"Ada","langs":["en"]},"items":[{"id":"1"},{"id":"2"}]}"#;
+        let query = JsonToQueryString.transform(json).unwrap();
+        assert_eq!(
+            JsonValue::parse(&QueryStringToJson.transform(&query).unwrap())
+                .unwrap()
+                .to_string(),
+            json
+        );
+    }
+
+    #[test]
+    fn test_json_to_query_string_invalid() {
+        assert!(JsonToQueryString.transform("[1]").is_err());
+        assert!(JsonToQueryString.transform("{").is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::utils::json::JsonValue;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const MINIFY_OPTION: TransformOption = TransformOption {
+    key: "minify",
+    description: "Write the array on one line instead of pretty-printing it",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// JSON Lines to JSON array transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonlToArray;
+
+impl Transform for JsonlToArray {
+    fn name(&self) -> &'static str {
+        "JSON Lines to Array"
+    }
+
+    fn id(&self) -> &'static str {
+        "jsonltoarray"
+    }
+
+    fn description(&self) -> &'static str {
+        "Collects newline-delimited JSON (JSON Lines, NDJSON) into a single JSON array, skipping blank lines"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[MINIFY_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let mut items = Vec::new();
+        // Characters before the current line, to turn positions within a
+        // line into positions in the whole input
+        let mut offset = 0;
+        for (i, line) in input.split_inclusive('\n').enumerate() {
+            token.check_every(i)?;
+            let start = offset;
+            offset += line.chars().count();
+            if line.trim().is_empty() {
+                continue;
+            }
+            let item =
+                JsonValue::parse(line).map_err(|error| match error {
+                    TransformError::JsonParseError(details) => TransformError::JsonParseError(
+                        format!("Line {}: {}", i + 1, shift_position(&details, start)),
+                    ),
+                    other => other,
+                })?;
+            items.push(item);
+        }
+        let array = JsonValue::Array(items);
+        if options.bool_value(&MINIFY_OPTION)? {
+            Ok(array.to_string())
+        } else {
+            Ok(array.to_pretty_string(2))
+        }
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "{\"id\":1,\"event\":\"login\",\"user\":\"ada\"}\n{\"id\":2,\"event\":\"upload\",\"user\":\"grace\",\"files\":3}\n\n{\"id\":3,\"event\":\"logout\",\"user\":\"ada\"}"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Log records",
+                input: "{\"level\":\"info\",\"msg\":\"started\"}\n{\"level\":\"warn\",\"msg\":\"disk 90% full\"}",
+            },
+            Example {
+                name: "Any JSON values",
+                input: "1\n\"two\"\n[3]\nnull",
+            },
+        ]
+    }
+}
+
+// Moves the "position N" in a parse error along by `offset` characters
+fn shift_position(details: &str, offset: usize) -> String {
+    let Some((before, rest)) = details.split_once("position ") else {
+        return details.to_string();
+    };
+    let digits = rest.chars().take_while(char::is_ascii_digit).count();
+    match rest[..digits].parse::<usize>() {
+        Ok(position) => format!(
+            "{}position {}{}",
+            before,
+            position + offset,
+            &rest[digits..]
+        ),
+        Err(_) => details.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonl_to_array() {
+        assert_eq!(
+            JsonlToArray
+                .transform("{\"a\":1}\n\n  \n[true, null]\r\n\"x\"\n")
+                .unwrap(),
+            "[\n  {\n    \"a\": 1\n  },\n  [\n    true,\n    null\n  ],\n  \"x\"\n]"
+        );
+        let mut options = TransformOptions::new();
+        options.set("minify", "true");
+        assert_eq!(
+            JsonlToArray
+                .transform_with_options(JsonlToArray.default_test_input(), &options)
+                .unwrap(),
+            r#"[{"id":1,"event":"login","user":"ada"},{"id":2,"event":"upload","user":"grace","files":3},{"id":3,"event":"logout","user":"ada"}]"#
+        );
+        assert_eq!(JsonlToArray.transform("").unwrap(), "[]");
+    }
+
+    #[test]
+    fn test_jsonl_errors_name_the_line() {
+        let error = JsonlToArray
+            .transform("{\"a\":1}\n\n{\"b\" 2}")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "JSON parse error: Line 3: Expected ':' at position 15"
+        );
+        assert_eq!(error.position(), Some(15));
+    }
+}
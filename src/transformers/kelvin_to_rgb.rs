@@ -0,0 +1,160 @@
+use crate::utils::Color;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// Temperatures the approximation is fitted for
+const MIN_KELVIN: f64 = 1000.0;
+const MAX_KELVIN: f64 = 40000.0;
+
+const FORMAT_OPTION: TransformOption = TransformOption {
+    key: "format",
+    description: "Notation of the resulting color",
+    default: "hex",
+    kind: TransformOptionKind::Choice(&["hex", "rgb"]),
+};
+
+/// Color temperature (Kelvin) to RGB transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KelvinToRgb;
+
+impl Transform for KelvinToRgb {
+    fn name(&self) -> &'static str {
+        "Kelvin to RGB"
+    }
+
+    fn id(&self) -> &'static str {
+        "kelvin_to_rgb"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Approximates the color of light at a temperature such as 6500K (1000K to 40000K)"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[FORMAT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let kelvin = parse_kelvin(input)?;
+        let (r, g, b) = kelvin_to_rgb(kelvin);
+        let color = Color { r, g, b, a: None };
+        Ok(match options.value(&FORMAT_OPTION) {
+            "rgb" => color.to_rgb(),
+            _ => color.to_hex(),
+        })
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "6500K"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Candle flame",
+                input: "1900K",
+            },
+            Example {
+                name: "Warm white bulb",
+                input: "2700K",
+            },
+            Example {
+                name: "Overcast sky",
+                input: "7500K",
+            },
+        ]
+    }
+}
+
+// A temperature such as "6500K", "6500 k" or "6500"
+fn parse_kelvin(input: &str) -> Result<f64, TransformError> {
+    let input = input.trim();
+    let number = input.strip_suffix(['K', 'k']).unwrap_or(input).trim_end();
+    let kelvin: f64 = number.parse().map_err(|_| {
+        TransformError::InvalidArgument(
+            format!("Invalid color temperature '{}', expected e.g. 6500K", input).into(),
+        )
+    })?;
+    if !(MIN_KELVIN..=MAX_KELVIN).contains(&kelvin) {
+        return Err(TransformError::InvalidArgument(
+            format!(
+                "Color temperature must be between {}K and {}K",
+                MIN_KELVIN, MAX_KELVIN
+            )
+            .into(),
+        ));
+    }
+    Ok(kelvin)
+}
+
+// Tanner Helland's curve fit of blackbody colors, accurate to within a few
+// units per channel over its range
+fn kelvin_to_rgb(kelvin: f64) -> (u8, u8, u8) {
+    let t = kelvin / 100.0;
+    let red = if t <= 66.0 {
+        255.0
+    } else {
+        329.698_727_446 * (t - 60.0).powf(-0.133_204_759_2)
+    };
+    let green = if t <= 66.0 {
+        99.470_802_586_1 * t.ln() - 161.119_568_166_1
+    } else {
+        288.122_169_528_3 * (t - 60.0).powf(-0.075_514_849_2)
+    };
+    let blue = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.517_731_223_1 * (t - 10.0).ln() - 305.044_792_730_7
+    };
+    let channel = |value: f64| value.round().clamp(0.0, 255.0) as u8;
+    (channel(red), channel(green), channel(blue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kelvin_to_rgb() {
+        assert_eq!(KelvinToRgb.transform("6500K").unwrap(), "#fffefa");
+        assert_eq!(KelvinToRgb.transform("1000").unwrap(), "#ff4400");
+        assert_eq!(KelvinToRgb.transform(" 2700 k ").unwrap(), "#ffa757");
+        assert_eq!(KelvinToRgb.transform("40000K").unwrap(), "#98baff");
+    }
+
+    #[test]
+    fn test_kelvin_to_rgb_format() {
+        let mut options = TransformOptions::new();
+        options.set("format", "rgb");
+        assert_eq!(
+            KelvinToRgb
+                .transform_with_options("6500K", &options)
+                .unwrap(),
+            "rgb(255,254,250)"
+        );
+    }
+
+    #[test]
+    fn test_kelvin_to_rgb_invalid() {
+        assert!(KelvinToRgb.transform("warm").is_err());
+        assert!(KelvinToRgb.transform("500K").is_err());
+        assert!(KelvinToRgb.transform("50000K").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// Lab to Hex color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LabToHex;
+
+impl Transform for LabToHex {
+    fn name(&self) -> &'static str {
+        "Lab to Hex"
+    }
+
+    fn id(&self) -> &'static str {
+        "lab_to_hex"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts CIELAB (CSS lab(), D50) color to hex format"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let color = Color::from_lab(input)?;
+        Ok(color.to_hex())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "lab(54.29% 80.8 69.89)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lab_to_hex() {
+        let transformer = LabToHex;
+        assert_eq!(
+            transformer.transform("lab(54.29% 80.8 69.89)").unwrap(),
+            "#ff0000"
+        );
+        assert_eq!(transformer.transform("lab(100 0 0)").unwrap(), "#ffffff");
+        assert_eq!(transformer.transform("lab(0, 0, 0)").unwrap(), "#000000");
+        assert_eq!(
+            transformer.transform("lab(50% 100 -100)").unwrap(),
+            "#c900ff"
+        );
+        assert_eq!(
+            transformer
+                .transform("lab(29.57% 68.29 -112.03 / 0.5)")
+                .unwrap(),
+            "#0000ff80"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = LabToHex;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("lab(50 0)").is_err());
+        assert!(transformer.transform("lab(120 0 0)").is_err());
+        assert!(transformer.transform("lab(50 a 0)").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// Lab to RGB color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LabToRgb;
+
+impl Transform for LabToRgb {
+    fn name(&self) -> &'static str {
+        "Lab to RGB"
+    }
+
+    fn id(&self) -> &'static str {
+        "lab_to_rgb"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts CIELAB (CSS lab(), D50) color to RGB format"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let color = Color::from_lab(input)?;
+        Ok(color.to_rgb())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "lab(54.29% 80.8 69.89)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lab_to_rgb() {
+        let transformer = LabToRgb;
+        assert_eq!(
+            transformer.transform("lab(54.29% 80.8 69.89)").unwrap(),
+            "rgb(255,0,0)"
+        );
+        assert_eq!(
+            transformer.transform("lab(53.59 0 0)").unwrap(),
+            "rgb(128,128,128)"
+        );
+        assert_eq!(
+            transformer.transform("lab(87.82 -79.28 80.99)").unwrap(),
+            "rgb(0,255,0)"
+        );
+        assert_eq!(
+            transformer
+                .transform("lab(54.29 80.8 69.89 / 50%)")
+                .unwrap(),
+            "rgb(255,0,0,128)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = LabToRgb;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("rgb(255, 0, 0)").is_err());
+        assert!(transformer.transform("lab(50 0 0 0 0)").is_err());
+    }
+}
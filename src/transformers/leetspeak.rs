@@ -0,0 +1,140 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const INTENSITY_OPTION: TransformOption = TransformOption {
+    key: "intensity",
+    description: "basic swaps vowels for digits, full replaces every letter",
+    default: "basic",
+    kind: TransformOptionKind::Choice(&["basic", "full"]),
+};
+
+/// Vowel substitutions used by the basic intensity
+const BASIC: [(char, &str); 4] = [('a', "4"), ('e', "3"), ('i', "1"), ('o', "0")];
+
+/// Replacement for every letter at full intensity
+pub(crate) const FULL: [(char, &str); 26] = [
+    ('a', "4"),
+    ('b', "8"),
+    ('c', "("),
+    ('d', "|)"),
+    ('e', "3"),
+    ('f', "|="),
+    ('g', "6"),
+    ('h', "|-|"),
+    ('i', "1"),
+    ('j', "_|"),
+    ('k', "|<"),
+    ('l', "|_"),
+    ('m', "|\\/|"),
+    ('n', "|\\|"),
+    ('o', "0"),
+    ('p', "|*"),
+    ('q', "(,)"),
+    ('r', "|2"),
+    ('s', "5"),
+    ('t', "7"),
+    ('u', "|_|"),
+    ('v', "\\/"),
+    ('w', "\\/\\/"),
+    ('x', "><"),
+    ('y', "`/"),
+    ('z', "2"),
+];
+
+/// Leetspeak transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Leetspeak;
+
+impl Transform for Leetspeak {
+    fn name(&self) -> &'static str {
+        "Leetspeak"
+    }
+
+    fn id(&self) -> &'static str {
+        "leetspeak"
+    }
+
+    fn description(&self) -> &'static str {
+        "Writes text in leetspeak, either swapping vowels for digits (h3ll0) or replacing every letter (|-|3|_|_0)"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Encoder
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[INTENSITY_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let table: &[(char, &str)] = match options.value(&INTENSITY_OPTION) {
+            "full" => &FULL,
+            _ => &BASIC,
+        };
+        let mut output = String::with_capacity(input.len() * 2);
+        for (i, c) in input.chars().enumerate() {
+            token.check_every(i)?;
+            let lower = c.to_ascii_lowercase();
+            match table.iter().find(|&&(letter, _)| letter == lower) {
+                Some(&(_, leet)) => output.push_str(leet),
+                None => output.push(c),
+            }
+        }
+        Ok(output)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Hello, elite hackers!"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Leave numbers alone",
+            input: "Room 101 opens at 9",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leetspeak_basic() {
+        assert_eq!(
+            Leetspeak.transform(Leetspeak.default_test_input()).unwrap(),
+            "H3ll0, 3l1t3 h4ck3rs!"
+        );
+    }
+
+    #[test]
+    fn test_leetspeak_full() {
+        let mut options = TransformOptions::new();
+        options.set("intensity", "full");
+        assert_eq!(
+            Leetspeak
+                .transform_with_options("Leet Speak", &options)
+                .unwrap(),
+            "|_337 5|*34|<"
+        );
+        assert_eq!(
+            Leetspeak
+                .transform_with_options("wow, 42", &options)
+                .unwrap(),
+            "\\/\\/0\\/\\/, 42"
+        );
+        options.set("intensity", "extreme");
+        assert!(Leetspeak.transform_with_options("leet", &options).is_err());
+    }
+}
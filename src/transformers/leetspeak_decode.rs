@@ -0,0 +1,103 @@
+use super::leetspeak::FULL;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// Leetspeak decode transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeetspeakDecode;
+
+impl Transform for LeetspeakDecode {
+    fn name(&self) -> &'static str {
+        "Leetspeak Decode"
+    }
+
+    fn id(&self) -> &'static str {
+        "leetspeakdecode"
+    }
+
+    fn description(&self) -> &'static str {
+        "Turns leetspeak back into plain lowercase letters as best it can. Words made only of digits are kept as numbers."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Decoder
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let mut output = String::with_capacity(input.len());
+        for token in input.split_inclusive(char::is_whitespace) {
+            let word = token.trim_end();
+            if is_number(word) {
+                output.push_str(token);
+                continue;
+            }
+            let mut rest = token;
+            while let Some(c) = rest.chars().next() {
+                // Longest symbol first, so \/\/ reads as w rather than vv
+                match FULL
+                    .iter()
+                    .filter(|(_, leet)| rest.starts_with(leet))
+                    .max_by_key(|(_, leet)| leet.len())
+                {
+                    Some(&(letter, leet)) => {
+                        output.push(letter);
+                        rest = &rest[leet.len()..];
+                    }
+                    None => {
+                        output.push(c);
+                        rest = &rest[c.len_utf8()..];
+                    }
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "H3ll0, 3l1t3 h4ck3rs!"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Full leetspeak",
+            input: "|_337 5|*34|<",
+        }]
+    }
+}
+
+// Plain numbers such as 2024 or 3.14, possibly followed by punctuation
+fn is_number(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_ascii_digit())
+        && word
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | ':' | '!' | '?'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::Leetspeak;
+    use crate::{Transform, TransformOptions};
+
+    #[test]
+    fn test_leetspeak_decode() {
+        assert_eq!(
+            LeetspeakDecode
+                .transform(LeetspeakDecode.default_test_input())
+                .unwrap(),
+            "Hello, elite hackers!"
+        );
+        assert_eq!(
+            LeetspeakDecode.transform("R00m 101 0p3ns 4t 9").unwrap(),
+            "Room 101 opens at 9"
+        );
+    }
+
+    #[test]
+    fn test_leetspeak_decode_full() {
+        let mut options = TransformOptions::new();
+        options.set("intensity", "full");
+        let text = "the quick brown fox jumps over the lazy dog";
+        let encoded = Leetspeak.transform_with_options(text, &options).unwrap();
+        assert_eq!(LeetspeakDecode.transform(&encoded).unwrap(), text);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const TO_OPTION: TransformOption = TransformOption {
+    key: "to",
+    description: "Line ending to convert to (lf, crlf, cr), or report to count the ones in use",
+    default: "lf",
+    kind: TransformOptionKind::Choice(&["lf", "crlf", "cr", "report"]),
+};
+
+/// Line ending converter and inspector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineEndingConvert;
+
+impl Transform for LineEndingConvert {
+    fn name(&self) -> &'static str {
+        "Line Ending Convert"
+    }
+
+    fn id(&self) -> &'static str {
+        "line_ending_convert"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts line endings between LF (Unix), CRLF (Windows) and CR (classic Mac), or reports which ones a text uses and whether it ends with a newline"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[TO_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let output = self.transform_bytes_configured(input.as_bytes(), options, token)?;
+        // Only ASCII line breaks changed, so the output is still UTF-8
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    // Works on raw bytes so files in any ASCII compatible encoding can be converted
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let ending: &[u8] = match options.value(&TO_OPTION) {
+            "report" => return Ok(report(input).into_bytes()),
+            "crlf" => b"\r\n",
+            "cr" => b"\r",
+            _ => b"\n",
+        };
+        let mut out = Vec::with_capacity(input.len());
+        let mut bytes = input.iter().peekable();
+        let mut i = 0;
+        while let Some(&byte) = bytes.next() {
+            token.check_every(i)?;
+            i += 1;
+            match byte {
+                b'\r' => {
+                    bytes.next_if(|&&next| next == b'\n');
+                    out.extend(ending);
+                }
+                b'\n' => out.extend(ending),
+                _ => out.push(byte),
+            }
+        }
+        Ok(out)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "first line\r\nsecond line\r\nthird line\r\n"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Mixed endings",
+            input: "unix\nwindows\r\nclassic mac\rno newline at the end",
+        }]
+    }
+}
+
+// Counts of each line ending, whether they're mixed and whether the text
+// ends with one
+fn report(input: &[u8]) -> String {
+    let (mut lf, mut crlf, mut cr) = (0, 0, 0);
+    let mut bytes = input.iter().peekable();
+    while let Some(&byte) = bytes.next() {
+        match byte {
+            b'\r' if bytes.next_if(|&&next| next == b'\n').is_some() => crlf += 1,
+            b'\r' => cr += 1,
+            b'\n' => lf += 1,
+            _ => {}
+        }
+    }
+
+    let kinds = [("LF", lf), ("CRLF", crlf), ("CR", cr)];
+    let used: Vec<&str> = kinds
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(name, _)| *name)
+        .collect();
+    let style = match used[..] {
+        [] => "none".to_string(),
+        [only] => only.to_string(),
+        _ => format!("mixed ({})", used.join(", ")),
+    };
+    let trailing = if input.is_empty() {
+        "no (empty input)"
+    } else if input.ends_with(b"\n") || input.ends_with(b"\r") {
+        "yes"
+    } else {
+        "no"
+    };
+    format!(
+        "LF (\\n): {}\nCRLF (\\r\\n): {}\nCR (\\r): {}\nLine endings: {}\nEnds with a newline: {}",
+        lf, crlf, cr, style, trailing
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, to: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("to", to);
+        LineEndingConvert
+            .transform_with_options(input, &options)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_line_ending_convert() {
+        assert_eq!(
+            LineEndingConvert
+                .transform(LineEndingConvert.default_test_input())
+                .unwrap(),
+            "first line\nsecond line\nthird line\n"
+        );
+        let mixed = LineEndingConvert.examples()[0].input;
+        assert_eq!(
+            convert(mixed, "crlf"),
+            "unix\r\nwindows\r\nclassic mac\r\nno newline at the end"
+        );
+        assert_eq!(
+            convert(mixed, "cr"),
+            "unix\rwindows\rclassic mac\rno newline at the end"
+        );
+        // \n\r is two line endings, not one
+        assert_eq!(convert("a\n\rb\r\r\n", "lf"), "a\n\nb\n\n");
+        assert_eq!(convert("", "crlf"), "");
+    }
+
+    #[test]
+    fn test_line_ending_report() {
+        assert_eq!(
+            convert(LineEndingConvert.examples()[0].input, "report"),
+            "LF (\\n): 1\nCRLF (\\r\\n): 1\nCR (\\r): 1\nLine endings: mixed (LF, CRLF, CR)\nEnds with a newline: no"
+        );
+        assert_eq!(
+            convert("a\r\nb\r\n", "report"),
+            "LF (\\n): 0\nCRLF (\\r\\n): 2\nCR (\\r): 0\nLine endings: CRLF\nEnds with a newline: yes"
+        );
+        assert!(convert("", "report")
+            .ends_with("Line endings: none\nEnds with a newline: no (empty input)"));
+    }
+
+    #[test]
+    fn test_line_ending_bytes() {
+        let mut options = TransformOptions::new();
+        options.set("to", "crlf");
+        assert_eq!(
+            LineEndingConvert
+                .transform_bytes_with_options(b"\xff\n\xfe\r\n", &options)
+                .unwrap(),
+            b"\xff\r\n\xfe\r\n"
+        );
+    }
+}
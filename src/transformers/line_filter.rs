@@ -0,0 +1,198 @@
+use super::line_shuffle::join_lines;
+use crate::utils::regex::Regex;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const PATTERN_OPTION: TransformOption = TransformOption {
+    key: "pattern",
+    description: "Text to look for in each line (empty matches every line)",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+const REGEX_OPTION: TransformOption = TransformOption {
+    key: "regex",
+    description: "Treat the pattern as a regular expression, such as ^(GET|POST) or \\d{3}$",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+const INVERT_OPTION: TransformOption = TransformOption {
+    key: "invert",
+    description: "Keep the lines that don't match instead, like grep -v",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+const IGNORE_CASE_OPTION: TransformOption = TransformOption {
+    key: "ignore_case",
+    description: "Match letters regardless of case",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// Grep-style line filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineFilter;
+
+enum Matcher {
+    Substring { needle: String, ignore_case: bool },
+    Regex(Regex),
+}
+
+impl Transform for LineFilter {
+    fn name(&self) -> &'static str {
+        "Line Filter"
+    }
+
+    fn id(&self) -> &'static str {
+        "line_filter"
+    }
+
+    fn description(&self) -> &'static str {
+        "Keeps the lines containing a piece of text or matching a regular expression, or drops them when inverted, like grep"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[
+            PATTERN_OPTION,
+            REGEX_OPTION,
+            INVERT_OPTION,
+            IGNORE_CASE_OPTION,
+        ]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let pattern = options.value(&PATTERN_OPTION);
+        let ignore_case = options.bool_value(&IGNORE_CASE_OPTION)?;
+        let invert = options.bool_value(&INVERT_OPTION)?;
+        let matcher = if options.bool_value(&REGEX_OPTION)? {
+            Matcher::Regex(Regex::new(pattern, ignore_case)?)
+        } else {
+            Matcher::Substring {
+                needle: if ignore_case {
+                    pattern.to_lowercase()
+                } else {
+                    pattern.to_string()
+                },
+                ignore_case,
+            }
+        };
+
+        let mut kept = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            token.check_every(index)?;
+            let matched = match &matcher {
+                Matcher::Substring {
+                    needle,
+                    ignore_case: true,
+                } => line.to_lowercase().contains(needle.as_str()),
+                Matcher::Substring { needle, .. } => line.contains(needle.as_str()),
+                Matcher::Regex(regex) => regex.is_match(line, token)?,
+            };
+            if matched != invert {
+                kept.push(line);
+            }
+        }
+        Ok(join_lines(&kept, input))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "INFO  server started on :8080\nWARN  config file missing, using defaults\nINFO  GET /index.html 200\nERROR database connection refused\nINFO  GET /favicon.ico 404"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Shell history",
+            input: "git status\ncargo build\ngit commit -m \"Fix parser\"\ncargo test\ngit push\n",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(input: &str, settings: &[(&str, &str)]) -> Result<String, TransformError> {
+        let options: TransformOptions = settings.iter().copied().collect();
+        LineFilter.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_line_filter_substring() {
+        let log = LineFilter.default_test_input();
+        assert_eq!(LineFilter.transform(log).unwrap(), log);
+        assert_eq!(
+            filter(log, &[("pattern", "GET")]).unwrap(),
+            "INFO  GET /index.html 200\nINFO  GET /favicon.ico 404"
+        );
+        assert_eq!(
+            filter(
+                log,
+                &[
+                    ("pattern", "info"),
+                    ("invert", "true"),
+                    ("ignore_case", "true")
+                ]
+            )
+            .unwrap(),
+            "WARN  config file missing, using defaults\nERROR database connection refused"
+        );
+        assert_eq!(filter(log, &[("pattern", "info")]).unwrap(), "");
+        // Regex syntax is literal text without the regex option
+        assert_eq!(filter("a.b\naxb", &[("pattern", "a.b")]).unwrap(), "a.b");
+    }
+
+    #[test]
+    fn test_line_filter_regex() {
+        let log = LineFilter.default_test_input();
+        assert_eq!(
+            filter(log, &[("pattern", "^(WARN|ERROR)\\b"), ("regex", "true")]).unwrap(),
+            "WARN  config file missing, using defaults\nERROR database connection refused"
+        );
+        assert_eq!(
+            filter(log, &[("pattern", " [45]\\d\\d$"), ("regex", "true")]).unwrap(),
+            "INFO  GET /favicon.ico 404"
+        );
+        let history = LineFilter.examples()[0].input;
+        assert_eq!(
+            filter(
+                history,
+                &[
+                    ("pattern", "^GIT "),
+                    ("regex", "true"),
+                    ("ignore_case", "true"),
+                    ("invert", "true")
+                ]
+            )
+            .unwrap(),
+            "cargo build\ncargo test\n"
+        );
+        assert!(filter(log, &[("pattern", "(GET"), ("regex", "true")]).is_err());
+    }
+
+    #[test]
+    fn test_line_filter_cancelled() {
+        let options = TransformOptions::new();
+        options.cancel_token().cancel();
+        assert_eq!(
+            LineFilter.transform_with_options("a\nb", &options),
+            Err(TransformError::Cancelled)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use super::line_shuffle::join_lines;
+use crate::utils::random::{rng_from_options, SEED_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const KEEP_OPTION: TransformOption = TransformOption {
+    key: "keep",
+    description: "How many lines to keep, as a count such as 10 or a share such as 25%",
+    default: "10",
+    kind: TransformOptionKind::Text,
+};
+
+/// Random line sample transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineSample;
+
+impl Transform for LineSample {
+    fn name(&self) -> &'static str {
+        "Line Sample"
+    }
+
+    fn id(&self) -> &'static str {
+        "line_sample"
+    }
+
+    fn description(&self) -> &'static str {
+        "Keeps a random selection of N lines or N% of them, in their original order"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[KEEP_OPTION, SEED_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let keep = parse_keep(options.value(&KEEP_OPTION), lines.len())?;
+
+        let mut picked: Vec<usize> = (0..lines.len()).collect();
+        rng_from_options(options)?.shuffle(&mut picked, token)?;
+        picked.truncate(keep);
+        picked.sort_unstable();
+        let sample: Vec<&str> = picked.into_iter().map(|i| lines[i]).collect();
+        Ok(join_lines(&sample, input))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "line 1\nline 2\nline 3\nline 4\nline 5\nline 6\nline 7\nline 8\nline 9\nline 10\nline 11\nline 12\nline 13\nline 14\nline 15\nline 16\nline 17\nline 18\nline 19\nline 20"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "CSV rows",
+            input: "id,name\n1,Ada\n2,Barbara\n3,Edsger\n4,Grace\n5,Karen\n6,Linus\n",
+        }]
+    }
+}
+
+// Number of lines to keep out of `total`; a percentage is rounded to the
+// nearest line and a count larger than the input keeps every line
+fn parse_keep(keep: &str, total: usize) -> Result<usize, TransformError> {
+    let keep = keep.trim();
+    let invalid = || {
+        TransformError::InvalidArgument(
+            format!(
+                "Keep must be a line count such as 10 or a percentage such as 25%, got '{}'",
+                keep
+            )
+            .into(),
+        )
+    };
+    match keep.strip_suffix('%') {
+        Some(percent) => {
+            let percent: f64 = percent.trim().parse().map_err(|_| invalid())?;
+            if !(0.0..=100.0).contains(&percent) {
+                return Err(TransformError::InvalidArgument(
+                    "Percentage must be between 0 and 100".into(),
+                ));
+            }
+            Ok((total as f64 * percent / 100.0).round() as usize)
+        }
+        None => Ok(keep.parse::<usize>().map_err(|_| invalid())?.min(total)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(input: &str, keep: &str) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("keep", keep);
+        options.set("seed", "5");
+        LineSample.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_line_sample() {
+        let input = LineSample.default_test_input();
+        let kept = LineSample.transform(input).unwrap();
+        let kept: Vec<&str> = kept.lines().collect();
+        assert_eq!(kept.len(), 10);
+        // Lines stay in their original order
+        let positions: Vec<usize> = kept
+            .iter()
+            .map(|line| input.lines().position(|l| l == *line).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert_eq!(sample(input, "25%").unwrap().lines().count(), 5);
+        assert_eq!(sample(input, "12.5 %").unwrap().lines().count(), 3);
+        assert_eq!(sample(input, "100%").unwrap(), input);
+        assert_eq!(sample(input, "50").unwrap(), input);
+        assert_eq!(sample(input, "0").unwrap(), "");
+        assert_eq!(sample(input, "3").unwrap(), sample(input, "3").unwrap());
+    }
+
+    #[test]
+    fn test_line_sample_trailing_newline() {
+        let rows = LineSample.examples()[0].input;
+        let kept = sample(rows, "2").unwrap();
+        assert_eq!(kept.lines().count(), 2);
+        assert!(kept.ends_with('\n'));
+        assert_eq!(sample("", "5").unwrap(), "");
+    }
+
+    #[test]
+    fn test_line_sample_errors() {
+        assert!(sample("a", "ten").is_err());
+        assert!(sample("a", "-1").is_err());
+        assert!(sample("a", "150%").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::utils::random::{rng_from_options, SEED_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// Line shuffle transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineShuffle;
+
+impl Transform for LineShuffle {
+    fn name(&self) -> &'static str {
+        "Line Shuffle"
+    }
+
+    fn id(&self) -> &'static str {
+        "line_shuffle"
+    }
+
+    fn description(&self) -> &'static str {
+        "Puts lines in a random order, seeded from the system's secure random source unless a seed is given for a repeatable order"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[SEED_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        rng_from_options(options)?.shuffle(&mut lines, token)?;
+        Ok(join_lines(&lines, input))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Ada\nBarbara\nEdsger\nGrace\nKaren\nLinus"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Playlist",
+            input: "Intro\nVerse\nChorus\nBridge\nOutro\n",
+        }]
+    }
+}
+
+/// Joins lines with "\n", ending with one if `input` did
+pub(crate) fn join_lines(lines: &[&str], input: &str) -> String {
+    let mut out = lines.join("\n");
+    if !lines.is_empty() && input.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shuffle(input: &str, seed: &str) -> String {
+        let mut options = TransformOptions::new();
+        options.set("seed", seed);
+        LineShuffle.transform_with_options(input, &options).unwrap()
+    }
+
+    #[test]
+    fn test_line_shuffle() {
+        let input = LineShuffle.default_test_input();
+        let shuffled = LineShuffle.transform(input).unwrap();
+        let mut lines: Vec<&str> = shuffled.lines().collect();
+        lines.sort_unstable();
+        assert_eq!(lines.join("\n"), input);
+
+        let playlist = LineShuffle.examples()[0].input;
+        assert!(shuffle(playlist, "7").ends_with('\n'));
+        assert_eq!(shuffle("", "7"), "");
+        assert_eq!(shuffle("only", "7"), "only");
+    }
+
+    #[test]
+    fn test_line_shuffle_seed() {
+        let input = LineShuffle.default_test_input();
+        assert_eq!(shuffle(input, "42"), shuffle(input, "42"));
+        assert_ne!(shuffle(input, "42"), shuffle(input, "43"));
+    }
+}
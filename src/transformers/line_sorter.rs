@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Sorts lines of text alphabetically.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -25,6 +25,19 @@ impl Transform for LineSorter {
         "banana\napple\norange\ngrape"
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Version numbers",
+                input: "v1.10.0\nv1.2.0\nv1.9.3\nv1.2.10",
+            },
+            Example {
+                name: "Names",
+                input: "Grace\nalan\nAda\nlinus",
+            },
+        ]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
         if input.is_empty() {
             return Ok(String::new());
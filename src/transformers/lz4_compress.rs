@@ -0,0 +1,234 @@
+use super::base64_encode;
+use crate::{CancelToken, Example, Transform, TransformError, TransformerCategory};
+
+// Block format limits (lz4_Block_format.md)
+pub(crate) const MIN_MATCH_LEN: usize = 4;
+pub(crate) const MAX_OFFSET: usize = 65535;
+// The last 5 bytes are always literals, and the last match starts at least
+// 12 bytes before the end, so decoders can copy in wide words
+const LAST_LITERALS: usize = 5;
+const MF_LIMIT: usize = 12;
+// A token nibble of 15 means more length bytes follow
+pub(crate) const RUN_MASK: usize = 15;
+// The hash table is sized to the input between these bounds, as for DEFLATE
+const MIN_HASH_BITS: u32 = 8;
+const MAX_HASH_BITS: u32 = 16;
+// Marks an empty hash bucket
+const NO_POS: u32 = u32::MAX;
+
+/// Compresses input into an LZ4 block (the raw format, without the frame
+/// header), encoded as Base64. Much faster than DEFLATE, at a lower ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lz4Compress;
+
+impl Transform for Lz4Compress {
+    fn name(&self) -> &'static str {
+        "LZ4 Compress"
+    }
+
+    fn id(&self) -> &'static str {
+        "lz4compress"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Compression
+    }
+
+    fn description(&self) -> &'static str {
+        "Compresses input into an LZ4 block and encodes the output as Base64."
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Hello, LZ4 World! Hello, LZ4 World!"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Repetitive text",
+            input: "Buup transforms text. Buup transforms text. Buup transforms text, again and again and again.",
+        }]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let output = lz4_compress_block(input.as_bytes(), token)?;
+        Ok(base64_encode::base64_encode(&output))
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        lz4_compress_block(input, &CancelToken::new())
+    }
+}
+
+// Greedy single-probe matching, as in the reference LZ4 "fast" mode: each
+// position remembers the latest earlier position with the same 4 bytes
+pub(crate) fn lz4_compress_block(
+    input: &[u8],
+    token: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    let mut output = Vec::with_capacity(input.len() / 2 + 16);
+    if input.len() <= MF_LIMIT {
+        write_sequence(&mut output, input, None);
+        return Ok(output);
+    }
+
+    let hash_bits = (usize::BITS - input.len().leading_zeros()).clamp(MIN_HASH_BITS, MAX_HASH_BITS);
+    let mut table = vec![NO_POS; 1 << hash_bits];
+    let hash = |pos: usize| {
+        let key = u32::from_le_bytes([input[pos], input[pos + 1], input[pos + 2], input[pos + 3]]);
+        (key.wrapping_mul(0x9e37_79b1) >> (u32::BITS - hash_bits)) as usize
+    };
+    // Last position a match may start at, and the end it must stop before
+    let last_match_start = input.len() - MF_LIMIT;
+    let match_limit = input.len() - LAST_LITERALS;
+
+    let mut anchor = 0;
+    let mut pos = 0;
+    let mut iteration = 0;
+    while pos <= last_match_start {
+        token.check_every(iteration)?;
+        iteration += 1;
+        let slot = hash(pos);
+        let candidate = table[slot];
+        table[slot] = pos as u32;
+        if candidate == NO_POS
+            || pos - candidate as usize > MAX_OFFSET
+            || input[candidate as usize..candidate as usize + MIN_MATCH_LEN]
+                != input[pos..pos + MIN_MATCH_LEN]
+        {
+            pos += 1;
+            continue;
+        }
+
+        let offset = pos - candidate as usize;
+        // Take back bytes that were about to go out as literals
+        let mut start = pos;
+        while start > anchor && start > offset && input[start - 1] == input[start - 1 - offset] {
+            start -= 1;
+        }
+        let mut end = pos + MIN_MATCH_LEN;
+        while end < match_limit && input[end] == input[end - offset] {
+            end += 1;
+        }
+        write_sequence(
+            &mut output,
+            &input[anchor..start],
+            Some((offset, end - start)),
+        );
+
+        // Remember a position near the end so the next repeat finds this one
+        if end - 2 <= last_match_start {
+            table[hash(end - 2)] = (end - 2) as u32;
+        }
+        anchor = end;
+        pos = end;
+    }
+    write_sequence(&mut output, &input[anchor..], None);
+    Ok(output)
+}
+
+// Writes a token, the literals and, unless this is the last sequence, the
+// match as (offset, length)
+fn write_sequence(output: &mut Vec<u8>, literals: &[u8], matched: Option<(usize, usize)>) {
+    let literal_len = literals.len();
+    let match_len = matched.map_or(0, |(_, len)| len - MIN_MATCH_LEN);
+    output.push(((literal_len.min(RUN_MASK) << 4) | match_len.min(RUN_MASK)) as u8);
+    write_length(output, literal_len);
+    output.extend_from_slice(literals);
+    if let Some((offset, _)) = matched {
+        output.extend_from_slice(&(offset as u16).to_le_bytes());
+        write_length(output, match_len);
+    }
+}
+
+// Lengths of 15 and up continue in bytes of 255 ended by a smaller one
+fn write_length(output: &mut Vec<u8>, len: usize) {
+    if len < RUN_MASK {
+        return;
+    }
+    let mut rest = len - RUN_MASK;
+    while rest >= 255 {
+        output.push(255);
+        rest -= 255;
+    }
+    output.push(rest as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::lz4_decompress::Lz4Decompress;
+
+    #[test]
+    fn test_lz4_empty() {
+        assert_eq!(Lz4Compress.transform_bytes(b"").unwrap(), [0]);
+        assert_eq!(
+            Lz4Decompress
+                .transform(&Lz4Compress.transform("").unwrap())
+                .unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_lz4_short_input_is_literals() {
+        let compressed = Lz4Compress.transform_bytes(b"aaaaaaaaaaaa").unwrap();
+        assert_eq!(compressed[0], 0xc0);
+        assert_eq!(&compressed[1..], b"aaaaaaaaaaaa");
+    }
+
+    #[test]
+    fn test_lz4_repeated() {
+        let input = "a".repeat(1000);
+        let compressed = Lz4Compress.transform_bytes(input.as_bytes()).unwrap();
+        // One overlapping match, then the last five literals
+        assert_eq!(compressed.len(), 1 + 1 + 2 + 4 + 1 + 5);
+        assert_eq!(
+            Lz4Decompress.transform_bytes(&compressed).unwrap(),
+            input.as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_lz4_round_trip() {
+        let compressor = Lz4Compress;
+        let input = compressor.default_test_input();
+        let compressed = compressor.transform(input).unwrap();
+        assert_eq!(Lz4Decompress.transform(&compressed).unwrap(), input);
+
+        // Pseudo-random bytes with repeats spread over more than the window
+        let mut state = 0x2545_f491_u32;
+        let mut input: Vec<u8> = Vec::new();
+        while input.len() < 300_000 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            if state.is_multiple_of(4) && input.len() > 70_000 {
+                let start = input.len() - 70_000 + (state as usize >> 8) % 60_000;
+                input.extend_from_within(start..start + 300);
+            } else {
+                input.push(state as u8);
+            }
+        }
+        let compressed = compressor.transform_bytes(&input).unwrap();
+        assert!(compressed.len() < input.len());
+        assert_eq!(Lz4Decompress.transform_bytes(&compressed).unwrap(), input);
+    }
+
+    #[test]
+    fn test_lz4_cancelled() {
+        let token = CancelToken::new();
+        token.cancel();
+        assert!(matches!(
+            Lz4Compress.transform_with_cancel(&"text ".repeat(100), &token),
+            Err(TransformError::Cancelled)
+        ));
+    }
+}
@@ -0,0 +1,241 @@
+use super::base64_decode;
+use super::deflate_decompress::{self, check_output_limit};
+use super::lz4_compress::{MIN_MATCH_LEN, RUN_MASK};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// Decompresses an LZ4 block (the raw format, without the frame header).
+/// Expects Base64 input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lz4Decompress;
+
+impl Transform for Lz4Decompress {
+    fn name(&self) -> &'static str {
+        "LZ4 Decompress"
+    }
+
+    fn id(&self) -> &'static str {
+        "lz4decompress"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Compression
+    }
+
+    fn description(&self) -> &'static str {
+        "Decompresses an LZ4 block. Expects Base64 input."
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "+ANIZWxsbywgTFo0IFdvcmxkISASAFBvcmxkIQ==" // "Hello, LZ4 World! Hello, LZ4 World!"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Repetitive text",
+            input: "/wdCdXVwIHRyYW5zZm9ybXMgdGV4dC4gFgAXuCwgYWdhaW4gYW5kCgBQZ2Fpbi4=",
+        }]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[deflate_decompress::MAX_OUTPUT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let compressed_bytes = base64_decode::base64_decode(input).map_err(|e| {
+            TransformError::InvalidArgument(format!("Invalid Base64 input: {}", e).into())
+        })?;
+        let output = self.transform_bytes_configured(&compressed_bytes, options, token)?;
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let max_output = options.usize_value(&deflate_decompress::MAX_OUTPUT_OPTION)?;
+        lz4_decompress_block(input, max_output, token)
+    }
+}
+
+// Decodes a whole LZ4 block, producing at most `max_output` bytes and polling
+// `cancel` between sequences. The block doesn't record its decompressed size,
+// so it ends with the input.
+pub(crate) fn lz4_decompress_block(
+    input: &[u8],
+    max_output: usize,
+    cancel: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    if input.is_empty() {
+        return Err(TransformError::CompressionError(
+            "Empty input is not an LZ4 block".into(),
+        ));
+    }
+    let mut output = Vec::with_capacity(input.len().saturating_mul(3).min(max_output));
+    let mut pos = 0;
+    let mut sequences = 0;
+    loop {
+        cancel.check_every(sequences)?;
+        sequences += 1;
+        let token = input[pos] as usize;
+        pos += 1;
+
+        let literal_len = read_length(input, &mut pos, token >> 4)?;
+        let literals = input
+            .get(pos..pos.saturating_add(literal_len))
+            .ok_or_else(|| truncated("literals"))?;
+        check_output_limit(output.len(), literal_len, max_output)?;
+        output.extend_from_slice(literals);
+        pos += literal_len;
+
+        // The last sequence has no match
+        if pos == input.len() {
+            return Ok(output);
+        }
+
+        let offset = input
+            .get(pos..pos + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+            .ok_or_else(|| truncated("match offset"))?;
+        pos += 2;
+        if offset == 0 || offset > output.len() {
+            return Err(TransformError::CompressionError(format!(
+                "Invalid match offset {} at position {}",
+                offset,
+                pos - 2
+            )));
+        }
+        let match_len = read_length(input, &mut pos, token & RUN_MASK)? + MIN_MATCH_LEN;
+        check_output_limit(output.len(), match_len, max_output)?;
+        // The match may overlap the bytes it produces, so copy it in chunks
+        // no longer than the offset
+        let start = output.len() - offset;
+        let mut copied = 0;
+        while copied < match_len {
+            let chunk = offset.min(match_len - copied);
+            output.extend_from_within(start + copied..start + copied + chunk);
+            copied += chunk;
+        }
+
+        if pos >= input.len() {
+            return Err(truncated("final literals"));
+        }
+    }
+}
+
+// Adds the bytes after a token that continue a length of 15
+fn read_length(input: &[u8], pos: &mut usize, nibble: usize) -> Result<usize, TransformError> {
+    let mut len = nibble;
+    if nibble == RUN_MASK {
+        loop {
+            let byte = *input.get(*pos).ok_or_else(|| truncated("length"))?;
+            *pos += 1;
+            len = len.saturating_add(byte as usize);
+            if byte != 255 {
+                break;
+            }
+        }
+    }
+    Ok(len)
+}
+
+fn truncated(what: &str) -> TransformError {
+    TransformError::CompressionError(format!("LZ4 block ends in the middle of its {}", what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::lz4_compress::Lz4Compress;
+
+    #[test]
+    fn test_decompress_default_input() {
+        assert_eq!(
+            Lz4Decompress
+                .transform(Lz4Decompress.default_test_input())
+                .unwrap(),
+            Lz4Compress.default_test_input()
+        );
+        assert_eq!(
+            Lz4Compress
+                .transform(Lz4Compress.default_test_input())
+                .unwrap(),
+            Lz4Decompress.default_test_input()
+        );
+    }
+
+    #[test]
+    fn test_decompress_overlapping_match() {
+        // Three literals, a 16 byte match reaching back 3, then the last literals
+        let block = [
+            0x3c, b'a', b'b', b'c', 0x03, 0x00, 0x50, b'b', b'c', b'a', b'b', b'c',
+        ];
+        assert_eq!(
+            Lz4Decompress.transform_bytes(&block).unwrap(),
+            b"abc".repeat(8)
+        );
+    }
+
+    #[test]
+    fn test_decompress_invalid() {
+        for (block, message) in [
+            (&[][..], "Empty input"),
+            (&[0x20, b'a'][..], "literals"),
+            (&[0x10, b'a', 0x01][..], "match offset"),
+            (
+                &[0x14, b'a', 0x02, 0x00, 0x00][..],
+                "Invalid match offset 2",
+            ),
+            (&[0x14, b'a', 0x01, 0x00][..], "final literals"),
+            (&[0xf0, 0xff][..], "length"),
+        ] {
+            let error = Lz4Decompress.transform_bytes(block).unwrap_err();
+            assert!(
+                matches!(&error, TransformError::CompressionError(e) if e.contains(message)),
+                "{:?}: {}",
+                block,
+                error
+            );
+        }
+        assert!(matches!(
+            Lz4Decompress.transform("not base64!"),
+            Err(TransformError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_decompress_output_limit() {
+        let input = "a".repeat(100_000);
+        let compressed = Lz4Compress.transform_bytes(input.as_bytes()).unwrap();
+
+        let mut options = TransformOptions::new();
+        options.set("max_output", "99999");
+        assert!(Lz4Decompress
+            .transform_bytes_with_options(&compressed, &options)
+            .is_err());
+        options.set("max_output", "100000");
+        assert_eq!(
+            Lz4Decompress
+                .transform_bytes_with_options(&compressed, &options)
+                .unwrap(),
+            input.as_bytes()
+        );
+    }
+}
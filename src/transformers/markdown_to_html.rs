@@ -1,4 +1,5 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::utils::markdown::Document;
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Markdown to HTML transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,316 +23,25 @@ impl Transform for MarkdownToHtml {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        let mut html = String::new();
-        let mut in_code_block = false;
-        let mut code_language = String::new();
-        let mut in_list = false;
-        let mut in_ordered_list = false;
-        let mut in_blockquote = false;
-        let lines = input.lines();
-
-        for line in lines {
-            // Handle code blocks
-            if line.trim().starts_with("```") {
-                if in_code_block {
-                    html.push_str("</code></pre>\n");
-                    in_code_block = false;
-                    code_language.clear();
-                } else {
-                    in_code_block = true;
-                    code_language.clear();
-                    // Extract language if specified
-                    let language_start = line.trim_start().chars().skip(3).collect::<String>();
-                    if !language_start.is_empty() {
-                        code_language = language_start.trim().to_string();
-                        if !code_language.is_empty() {
-                            html.push_str(&format!(
-                                "<pre><code class=\"language-{}\">",
-                                code_language
-                            ));
-                        } else {
-                            html.push_str("<pre><code>");
-                        }
-                    } else {
-                        html.push_str("<pre><code>");
-                    }
-                }
-                continue;
-            }
-
-            if in_code_block {
-                html.push_str(&line.replace('<', "&lt;").replace('>', "&gt;"));
-                html.push('\n');
-                continue;
-            }
-
-            // Handle horizontal rules
-            if line.trim() == "---" || line.trim() == "***" || line.trim() == "___" {
-                html.push_str("<hr>\n");
-                continue;
-            }
-
-            // Handle blockquotes
-            if line.trim().starts_with('>') {
-                if !in_blockquote {
-                    html.push_str("<blockquote>\n");
-                    in_blockquote = true;
-                }
-                let content = line.trim()[1..].trim_start();
-                let processed_content = process_inline_markdown(content);
-                html.push_str(&format!("<p>{}</p>\n", processed_content));
-                continue;
-            } else if in_blockquote && line.trim().is_empty() {
-                html.push_str("</blockquote>\n");
-                in_blockquote = false;
-                continue;
-            }
-
-            // Handle headers
-            let level = line.chars().take_while(|&c| c == '#').count();
-            if level > 0 && level <= 6 && line.chars().nth(level) == Some(' ') {
-                let content = line[level..].trim();
-                let processed_content = process_inline_markdown(content);
-                html.push_str(&format!("<h{}>{}</h{}>\n", level, processed_content, level));
-                continue;
-            }
-
-            // Handle ordered lists
-            if let Some(content) = line.trim().strip_prefix("1. ") {
-                if !in_ordered_list {
-                    if in_list {
-                        html.push_str("</ul>\n");
-                        in_list = false;
-                    }
-                    html.push_str("<ol>\n");
-                    in_ordered_list = true;
-                }
-                let processed_content = process_inline_markdown(content);
-                html.push_str(&format!("<li>{}</li>\n", processed_content));
-                continue;
-            } else if in_ordered_list && line.trim().len() >= 3 {
-                // Check for any number followed by a dot and space (e.g., "2. ", "10. ")
-                let parts: Vec<&str> = line.trim().splitn(2, ". ").collect();
-                if parts.len() == 2 && parts[0].parse::<usize>().is_ok() {
-                    let processed_content = process_inline_markdown(parts[1]);
-                    html.push_str(&format!("<li>{}</li>\n", processed_content));
-                    continue;
-                } else if in_ordered_list {
-                    html.push_str("</ol>\n");
-                    in_ordered_list = false;
-                }
-            } else if in_ordered_list && line.trim().is_empty() {
-                html.push_str("</ol>\n");
-                in_ordered_list = false;
-            }
-
-            // Handle unordered lists
-            if line.trim().starts_with("- ") || line.trim().starts_with("* ") {
-                if !in_list {
-                    if in_ordered_list {
-                        html.push_str("</ol>\n");
-                        in_ordered_list = false;
-                    }
-                    html.push_str("<ul>\n");
-                    in_list = true;
-                }
-                let marker_len = 2; // Both "- " and "* " are 2 chars long
-                let content = line.trim()[marker_len..].trim();
-                let processed_content = process_inline_markdown(content);
-                html.push_str(&format!("<li>{}</li>\n", processed_content));
-                continue;
-            } else if in_list && line.trim().is_empty() {
-                html.push_str("</ul>\n");
-                in_list = false;
-                continue;
-            }
-
-            // Handle paragraphs
-            if !line.trim().is_empty() {
-                let processed_line = process_inline_markdown(line);
-
-                // Skip adding paragraph tags around certain elements that are already block-level
-                if !processed_line.starts_with("<h")
-                    && !processed_line.starts_with("<ul")
-                    && !processed_line.starts_with("<ol")
-                    && !processed_line.starts_with("<li")
-                    && !processed_line.starts_with("<blockquote")
-                {
-                    html.push_str("<p>");
-                    html.push_str(&processed_line);
-                    html.push_str("</p>\n");
-                } else {
-                    html.push_str(&processed_line);
-                    html.push('\n');
-                }
-            } else if !in_list && !in_ordered_list && !in_blockquote && !line.trim().is_empty() {
-                html.push('\n');
-            }
-        }
-
-        // Close any open tags
-        if in_list {
-            html.push_str("</ul>\n");
-        }
-        if in_ordered_list {
-            html.push_str("</ol>\n");
-        }
-        if in_blockquote {
-            html.push_str("</blockquote>\n");
-        }
-        if in_code_block {
-            html.push_str("</code></pre>\n");
-        }
-
-        Ok(html)
+        Ok(Document::parse(input).to_html())
     }
 
     fn default_test_input(&self) -> &'static str {
         "# Hello World\n\nThis is a **bold** and *italic* text with ~~strikethrough~~ and `inline code`.\n\n- List item 1\n- List item 2\n\n1. Ordered item 1\n2. Ordered item 2\n\n> This is a blockquote\n\n[Link text](https://example.com)\n\n---\n\n```rust\nfn main() {\n    println!(\"Hello, world!\");\n}\n```"
     }
-}
-
-// Helper function to process inline Markdown elements
-fn process_inline_markdown(input: &str) -> String {
-    let mut result = input.to_string();
-
-    // Process inline code (backticks)
-    while let Some(start) = result.find('`') {
-        if let Some(end) = result[start + 1..].find('`') {
-            let code_content = &result[start + 1..start + 1 + end];
-            let code_html = format!("<code>{}</code>", code_content);
-            result.replace_range(start..=start + 1 + end, &code_html);
-        } else {
-            break;
-        }
-    }
-
-    // Process bold (double asterisks)
-    while let Some(start) = result.find("**") {
-        if let Some(end) = result[start + 2..].find("**") {
-            let bold_content = &result[start + 2..start + 2 + end];
-            let bold_html = format!("<strong>{}</strong>", bold_content);
-            result.replace_range(start..=start + 2 + end + 1, &bold_html);
-        } else {
-            break;
-        }
-    }
-
-    // Process italic (single asterisk)
-    while let Some(start) = result.find('*') {
-        if let Some(end) = result[start + 1..].find('*') {
-            let italic_content = &result[start + 1..start + 1 + end];
-            let italic_html = format!("<em>{}</em>", italic_content);
-            result.replace_range(start..=start + 1 + end, &italic_html);
-        } else {
-            break;
-        }
-    }
-
-    // Process strikethrough (double tilde)
-    while let Some(start) = result.find("~~") {
-        if let Some(end) = result[start + 2..].find("~~") {
-            let strike_content = &result[start + 2..start + 2 + end];
-            let strike_html = format!("<del>{}</del>", strike_content);
-            result.replace_range(start..=start + 2 + end + 1, &strike_html);
-        } else {
-            break;
-        }
-    }
-
-    // Process links
-    while let Some(start) = result.find('[') {
-        if let Some(text_end) = result[start..].find(']') {
-            let text_end = start + text_end;
-            if result.len() > text_end + 1 && result.as_bytes()[text_end + 1] == b'(' {
-                if let Some(url_end) = result[text_end + 1..].find(')') {
-                    let url_end = text_end + 1 + url_end;
-                    let link_text = &result[start + 1..text_end];
-                    let url = &result[text_end + 2..url_end];
-                    let link_html = format!("<a href=\"{}\">{}</a>", url, link_text);
-                    result.replace_range(start..=url_end, &link_html);
-                } else {
-                    break;
-                }
-            } else {
-                break;
-            }
-        } else {
-            break;
-        }
-    }
-
-    // Sanitize angle brackets for HTML entities, but preserve HTML tags we've already created
-    let mut final_result = String::new();
-    let mut i = 0;
-    let bytes = result.as_bytes();
-
-    while i < bytes.len() {
-        // Check for HTML tag start
-        if bytes[i] == b'<' && i + 1 < bytes.len() {
-            if is_start_of_html_tag(&bytes[i + 1..]) {
-                // This is an HTML tag, add it as is
-                final_result.push('<');
-                i += 1;
-
-                // Add characters until we reach the end of tag
-                while i < bytes.len() && bytes[i] != b'>' {
-                    final_result.push(bytes[i] as char);
-                    i += 1;
-                }
 
-                if i < bytes.len() {
-                    final_result.push('>');
-                    i += 1;
-                }
-            } else {
-                // Not an HTML tag, escape it
-                final_result.push_str("&lt;");
-                i += 1;
-            }
-        } else if bytes[i] == b'>' && (i == 0 || bytes[i - 1] != b'/') {
-            // Only escape '>' that are not part of a closing tag
-            let preceding_is_tag = i >= 2 && bytes[i - 1] == b'/' && bytes[i - 2] == b'<';
-            if !preceding_is_tag {
-                final_result.push_str("&gt;");
-            } else {
-                final_result.push('>');
-            }
-            i += 1;
-        } else {
-            final_result.push(bytes[i] as char);
-            i += 1;
-        }
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "README",
+                input: "# buup\n\nText transformations for the **CLI**, the web and Rust.\n\n## Install\n\n```sh\ncargo install buup\n```\n\n- Zero dependencies\n- Works offline",
+            },
+            Example {
+                name: "Table",
+                input: "| Command | Effect |\n| --- | --- |\n| `buup base64encode` | Encode |\n| `buup base64decode` | Decode |",
+            },
+        ]
     }
-
-    final_result
-}
-
-// Helper function to determine if we're at the start of an HTML tag
-fn is_start_of_html_tag(bytes: &[u8]) -> bool {
-    let html_tags = &[
-        b"a " as &[u8],
-        b"a>" as &[u8],
-        b"a href" as &[u8],
-        b"/a>" as &[u8],
-        b"strong" as &[u8],
-        b"/strong" as &[u8],
-        b"em" as &[u8],
-        b"/em" as &[u8],
-        b"del" as &[u8],
-        b"/del" as &[u8],
-        b"code" as &[u8],
-        b"/code" as &[u8],
-        b"p>" as &[u8],
-        b"/p>" as &[u8],
-    ];
-
-    for &tag in html_tags {
-        if bytes.len() >= tag.len() && bytes[..tag.len()] == *tag {
-            return true;
-        }
-    }
-    false
 }
 
 #[cfg(test)]
@@ -401,4 +111,37 @@ mod tests {
         let expected = "<p>This is <code>inline code</code> text</p>\n";
         assert_eq!(transformer.transform(input).unwrap(), expected);
     }
+
+    #[test]
+    fn test_nested_list() {
+        let transformer = MarkdownToHtml;
+        let input = "- One\n  spans lines\n  1. Nested\n  2. Items\n- Two";
+        let expected = "<ul>\n<li>One\nspans lines\n<ol>\n<li>Nested</li>\n<li>Items</li>\n</ol>\n</li>\n<li>Two</li>\n</ul>\n";
+        assert_eq!(transformer.transform(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_reference_link() {
+        let transformer = MarkdownToHtml;
+        let input = "Read [the docs][docs].\n\n[docs]: https://example.com \"Docs\"";
+        let expected = "<p>Read <a href=\"https://example.com\" title=\"Docs\">the docs</a>.</p>\n";
+        assert_eq!(transformer.transform(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_table() {
+        let transformer = MarkdownToHtml;
+        let input = "| Key | Value |\n| --- | ---: |\n| `a` | 1 |";
+        let expected = "<table>\n<thead>\n<tr>\n<th>Key</th>\n<th align=\"right\">Value</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><code>a</code></td>\n<td align=\"right\">1</td>\n</tr>\n</tbody>\n</table>\n";
+        assert_eq!(transformer.transform(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_escaping() {
+        let transformer = MarkdownToHtml;
+        let input = "<b>not html</b> & \\*not emphasis\\* but *this*";
+        let expected =
+            "<p>&lt;b&gt;not html&lt;/b&gt; &amp; *not emphasis* but <em>this</em></p>\n";
+        assert_eq!(transformer.transform(input).unwrap(), expected);
+    }
 }
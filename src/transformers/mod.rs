@@ -1,44 +1,120 @@
+pub mod ansi_strip;
+pub mod ansi_to_html;
+pub mod argon2_hash;
+pub mod argon2_verify;
+pub mod array_to_jsonl;
 pub mod ascii_to_hex;
 pub mod base64_decode;
 pub mod base64_encode;
+pub mod bencode_to_json;
 pub mod bin_to_dec;
 pub mod bin_to_hex;
 pub mod binary_decode;
 pub mod binary_encode;
 pub mod camel_to_snake;
+pub mod case_convert;
 pub mod color_code_convert;
+pub mod color_mix;
+pub mod color_name_to_hex;
+pub mod color_random;
+pub mod comment_add;
+pub mod comment_remove;
+pub mod compression_analyze;
+pub mod crc16;
+pub mod crc64;
+pub mod cron_describe;
+pub mod css_formatter;
+pub mod css_minifier;
+pub mod csv_delimiter_convert;
+pub mod csv_select_columns;
+pub mod csv_stats;
+pub mod csv_to_html_table;
 pub mod csv_to_json;
+pub mod csv_to_sql_insert;
+pub mod date_diff;
+pub mod date_parse;
 pub mod dec_to_bin;
 pub mod dec_to_hex;
+pub mod dedent_text;
 pub mod deflate_compress;
 pub mod deflate_decompress;
+pub mod duration_format;
+pub mod endian_swap;
+pub mod env_to_json;
+pub mod float_to_hex;
 pub mod gzip_compress;
 pub mod gzip_decompress;
+pub mod hash_verify;
 pub mod hex_decode;
 pub mod hex_encode;
 pub mod hex_to_ascii;
 pub mod hex_to_bin;
 pub mod hex_to_dec;
+pub mod hex_to_float;
 pub mod hex_to_hsl;
+pub mod hex_to_hsv;
+pub mod hex_to_lab;
+pub mod hex_to_nearest_color_name;
+pub mod hex_to_oklch;
 pub mod hex_to_rgb;
+pub mod hmac_md5;
+pub mod hmac_sha1;
+pub mod hmac_sha256;
 pub mod hsl_to_hex;
 pub mod hsl_to_rgb;
+pub mod hsv_to_hex;
+pub mod hsv_to_rgb;
 pub mod html_decode;
 pub mod html_encode;
+pub mod html_formatter;
+pub mod html_minifier;
+pub mod html_table_to_csv;
 pub mod html_to_markdown;
+pub mod indent_text;
+pub mod iso_to_timestamp;
+pub mod js_formatter;
 pub mod json_formatter;
 pub mod json_minifier;
+pub mod json_sort_keys;
+pub mod json_to_bencode;
 pub mod json_to_csv;
+pub mod json_to_env;
+pub mod json_to_properties;
+pub mod json_to_query_string;
+pub mod jsonl_to_array;
 pub mod jwt_decode;
+pub mod kelvin_to_rgb;
+pub mod lab_to_hex;
+pub mod lab_to_rgb;
+pub mod leetspeak;
+pub mod leetspeak_decode;
+pub mod line_ending_convert;
+pub mod line_filter;
 pub mod line_number_adder;
 pub mod line_number_remover;
+pub mod line_sample;
+pub mod line_shuffle;
 pub mod line_sorter;
+pub mod lz4_compress;
+pub mod lz4_decompress;
 pub mod markdown_to_html;
 pub mod md5_hash;
 pub mod morse_decode;
 pub mod morse_encode;
+pub mod nato_decode;
+pub mod nato_encode;
+pub mod oklch_to_hex;
+pub mod oklch_to_rgb;
+pub mod password_generate;
+pub mod pig_latin_decode;
+pub mod pig_latin_encode;
+pub mod properties_to_json;
+pub mod query_string_to_json;
 pub mod rgb_to_hex;
 pub mod rgb_to_hsl;
+pub mod rgb_to_hsv;
+pub mod rgb_to_lab;
+pub mod rgb_to_oklch;
 pub mod rot13;
 pub mod sha1_hash;
 pub mod sha256_hash;
@@ -47,37 +123,79 @@ pub mod snake_to_camel;
 pub mod sql_formatter;
 pub mod sql_minifier;
 pub mod text_reverse;
+pub mod text_similarity;
 pub mod text_stats;
+pub mod timestamp_to_iso;
+pub mod ulid_generate;
+pub mod ulid_inspect;
 pub mod unique_lines;
 pub mod url_decode;
 pub mod url_encode;
 pub mod url_parser;
 pub mod uuid5_generate;
+pub mod uuid7_generate;
 pub mod uuid_generate;
+pub mod uuid_inspect;
 pub mod whitespace_remover;
+pub mod word_frequency;
+pub mod word_wrap;
 pub mod xml_formatter;
 pub mod xml_minifier;
+pub mod xor_cipher;
+pub mod zip_extract_file;
+pub mod zip_list;
+pub mod zstd_decompress;
 
 pub use self::{
-    ascii_to_hex::AsciiToHex, base64_decode::Base64Decode, base64_encode::Base64Encode,
+    ansi_strip::AnsiStrip, ansi_to_html::AnsiToHtml, argon2_hash::Argon2Hash,
+    argon2_verify::Argon2Verify, array_to_jsonl::ArrayToJsonl, ascii_to_hex::AsciiToHex,
+    base64_decode::Base64Decode, base64_encode::Base64Encode, bencode_to_json::BencodeToJson,
     bin_to_dec::BinToDecTransformer, bin_to_hex::BinToHexTransformer, binary_decode::BinaryDecode,
-    binary_encode::BinaryEncode, camel_to_snake::CamelToSnake,
-    color_code_convert::ColorCodeConvert, csv_to_json::CsvToJson, dec_to_bin::DecToBinTransformer,
-    dec_to_hex::DecToHexTransformer, deflate_compress::DeflateCompress,
-    deflate_decompress::DeflateDecompress, gzip_compress::GzipCompress,
-    gzip_decompress::GzipDecompress, hex_decode::HexDecode, hex_encode::HexEncode,
+    binary_encode::BinaryEncode, camel_to_snake::CamelToSnake, case_convert::CaseConvert,
+    color_code_convert::ColorCodeConvert, color_mix::ColorMix, color_name_to_hex::ColorNameToHex,
+    color_random::ColorRandom, comment_add::CommentAdd, comment_remove::CommentRemove,
+    compression_analyze::CompressionAnalyze, crc16::Crc16, crc64::Crc64,
+    cron_describe::CronDescribe, css_formatter::CssFormatter, css_minifier::CssMinifier,
+    csv_delimiter_convert::CsvDelimiterConvert, csv_select_columns::CsvSelectColumns,
+    csv_stats::CsvStats, csv_to_html_table::CsvToHtmlTable, csv_to_json::CsvToJson,
+    csv_to_sql_insert::CsvToSqlInsert, date_diff::DateDiff, date_parse::DateParse,
+    dec_to_bin::DecToBinTransformer, dec_to_hex::DecToHexTransformer, dedent_text::DedentText,
+    deflate_compress::DeflateCompress, deflate_decompress::DeflateDecompress,
+    duration_format::DurationFormat, endian_swap::EndianSwap, env_to_json::EnvToJson,
+    float_to_hex::FloatToHex, gzip_compress::GzipCompress, gzip_decompress::GzipDecompress,
+    hash_verify::HashVerify, hex_decode::HexDecode, hex_encode::HexEncode,
     hex_to_ascii::HexToAscii, hex_to_bin::HexToBinTransformer, hex_to_dec::HexToDecTransformer,
-    hex_to_hsl::HexToHsl, hex_to_rgb::HexToRgb, hsl_to_hex::HslToHex, hsl_to_rgb::HslToRgb,
-    html_decode::HtmlDecode, html_encode::HtmlEncode, html_to_markdown::HtmlToMarkdown,
-    json_formatter::JsonFormatter, json_minifier::JsonMinifier, json_to_csv::JsonToCsv,
-    jwt_decode::JwtDecode, line_number_adder::LineNumberAdder,
-    line_number_remover::LineNumberRemover, line_sorter::LineSorter,
+    hex_to_float::HexToFloat, hex_to_hsl::HexToHsl, hex_to_hsv::HexToHsv, hex_to_lab::HexToLab,
+    hex_to_nearest_color_name::HexToNearestColorName, hex_to_oklch::HexToOklch,
+    hex_to_rgb::HexToRgb, hmac_md5::HmacMd5, hmac_sha1::HmacSha1, hmac_sha256::HmacSha256,
+    hsl_to_hex::HslToHex, hsl_to_rgb::HslToRgb, hsv_to_hex::HsvToHex, hsv_to_rgb::HsvToRgb,
+    html_decode::HtmlDecode, html_encode::HtmlEncode, html_formatter::HtmlFormatter,
+    html_minifier::HtmlMinifier, html_table_to_csv::HtmlTableToCsv,
+    html_to_markdown::HtmlToMarkdown, indent_text::IndentText, iso_to_timestamp::IsoToTimestamp,
+    js_formatter::JsFormatter, json_formatter::JsonFormatter, json_minifier::JsonMinifier,
+    json_sort_keys::JsonSortKeys, json_to_bencode::JsonToBencode, json_to_csv::JsonToCsv,
+    json_to_env::JsonToEnv, json_to_properties::JsonToProperties,
+    json_to_query_string::JsonToQueryString, jsonl_to_array::JsonlToArray, jwt_decode::JwtDecode,
+    kelvin_to_rgb::KelvinToRgb, lab_to_hex::LabToHex, lab_to_rgb::LabToRgb, leetspeak::Leetspeak,
+    leetspeak_decode::LeetspeakDecode, line_ending_convert::LineEndingConvert,
+    line_filter::LineFilter, line_number_adder::LineNumberAdder,
+    line_number_remover::LineNumberRemover, line_sample::LineSample, line_shuffle::LineShuffle,
+    line_sorter::LineSorter, lz4_compress::Lz4Compress, lz4_decompress::Lz4Decompress,
     markdown_to_html::MarkdownToHtml, md5_hash::Md5HashTransformer, morse_decode::MorseDecode,
-    morse_encode::MorseEncode, rgb_to_hex::RgbToHex, rgb_to_hsl::RgbToHsl, rot13::Rot13,
-    sha1_hash::Sha1Hash, sha256_hash::Sha256HashTransformer, slugify::Slugify,
-    snake_to_camel::SnakeToCamel, sql_formatter::SqlFormatter, sql_minifier::SqlMinifier,
-    text_reverse::TextReverse, text_stats::TextStats, unique_lines::UniqueLines,
+    morse_encode::MorseEncode, nato_decode::NatoDecode, nato_encode::NatoEncode,
+    oklch_to_hex::OklchToHex, oklch_to_rgb::OklchToRgb, password_generate::PasswordGenerate,
+    pig_latin_decode::PigLatinDecode, pig_latin_encode::PigLatinEncode,
+    properties_to_json::PropertiesToJson, query_string_to_json::QueryStringToJson,
+    rgb_to_hex::RgbToHex, rgb_to_hsl::RgbToHsl, rgb_to_hsv::RgbToHsv, rgb_to_lab::RgbToLab,
+    rgb_to_oklch::RgbToOklch, rot13::Rot13, sha1_hash::Sha1Hash,
+    sha256_hash::Sha256HashTransformer, slugify::Slugify, snake_to_camel::SnakeToCamel,
+    sql_formatter::SqlFormatter, sql_minifier::SqlMinifier, text_reverse::TextReverse,
+    text_similarity::TextSimilarity, text_stats::TextStats, timestamp_to_iso::TimestampToIso,
+    ulid_generate::UlidGenerate, ulid_inspect::UlidInspect, unique_lines::UniqueLines,
     url_decode::UrlDecode, url_encode::UrlEncode, url_parser::UrlParser,
-    uuid5_generate::Uuid5Generate, uuid_generate::UuidGenerate,
-    whitespace_remover::WhitespaceRemover, xml_formatter::XmlFormatter, xml_minifier::XmlMinifier,
+    uuid5_generate::Uuid5Generate, uuid7_generate::UuidV7Generate, uuid_generate::UuidGenerate,
+    uuid_inspect::UuidInspect, whitespace_remover::WhitespaceRemover,
+    word_frequency::WordFrequency, word_wrap::WordWrap, xml_formatter::XmlFormatter,
+    xml_minifier::XmlMinifier, xor_cipher::XorCipher, zip_extract_file::ZipExtractFile,
+    zip_list::ZipList, zstd_decompress::ZstdDecompress,
 };
@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 use std::collections::HashMap;
 use std::sync::OnceLock;
 
@@ -75,6 +75,13 @@ impl Transform for MorseDecode {
         ".... . .-.. .-.. --- / .-- --- .-. .-.. -.."
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Distress signal",
+            input: "... --- ...",
+        }]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
         if input.is_empty() {
             return Ok(String::new());
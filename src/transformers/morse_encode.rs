@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Morse encode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -85,6 +85,19 @@ impl Transform for MorseEncode {
     fn default_test_input(&self) -> &'static str {
         "Hello World"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Distress signal",
+                input: "SOS",
+            },
+            Example {
+                name: "Numbers",
+                input: "CALL 911",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
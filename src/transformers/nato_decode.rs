@@ -0,0 +1,111 @@
+use super::nato_encode::NATO_ALPHABET;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// Common spellings that differ from the official code words
+const ALIASES: [(&str, char); 6] = [
+    ("alpha", 'A'),
+    ("juliet", 'J'),
+    ("xray", 'X'),
+    ("niner", '9'),
+    ("tree", '3'),
+    ("fife", '5'),
+];
+
+/// NATO phonetic alphabet decode transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NatoDecode;
+
+impl Transform for NatoDecode {
+    fn name(&self) -> &'static str {
+        "NATO Phonetic Decode"
+    }
+
+    fn id(&self) -> &'static str {
+        "natodecode"
+    }
+
+    fn description(&self) -> &'static str {
+        "Turns NATO phonetic alphabet code words back into text, with / between words"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Decoder
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let mut output = String::new();
+        for token in input.split_whitespace() {
+            if token == "/" {
+                output.push(' ');
+                continue;
+            }
+            let lower = token.to_lowercase();
+            let letter = NATO_ALPHABET
+                .iter()
+                .find(|(_, code)| code.to_lowercase() == lower)
+                .map(|&(letter, _)| letter)
+                .or_else(|| {
+                    ALIASES
+                        .iter()
+                        .find(|(alias, _)| *alias == lower)
+                        .map(|&(_, letter)| letter)
+                });
+            match letter {
+                Some(letter) => output.push(letter),
+                // Punctuation passed through by the encoder
+                None if !token.chars().any(char::is_alphanumeric) => output.push_str(token),
+                None => {
+                    return Err(TransformError::InvalidArgument(
+                        format!("'{}' is not a NATO phonetic code word", token).into(),
+                    ))
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Bravo Uniform Uniform Papa / Four Two"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Common spellings",
+            input: "alpha juliet xray niner",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::NatoEncode;
+
+    #[test]
+    fn test_nato_decode() {
+        assert_eq!(NatoDecode.transform("Alfa Bravo Charlie").unwrap(), "ABC");
+        assert_eq!(
+            NatoDecode
+                .transform(NatoDecode.default_test_input())
+                .unwrap(),
+            "BUUP 42"
+        );
+        assert_eq!(
+            NatoDecode
+                .transform("ALPHA juliet X-RAY Xray niner")
+                .unwrap(),
+            "AJXX9"
+        );
+    }
+
+    #[test]
+    fn test_nato_round_trip() {
+        let encoded = NatoEncode.transform("G-ABCD 2024").unwrap();
+        assert_eq!(NatoDecode.transform(&encoded).unwrap(), "G-ABCD 2024");
+    }
+
+    #[test]
+    fn test_nato_decode_unknown_word() {
+        assert!(NatoDecode.transform("Alfa Banana").is_err());
+    }
+}
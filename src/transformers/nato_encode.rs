@@ -0,0 +1,125 @@
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// Code words of the NATO (ICAO) spelling alphabet, with plain English digits
+pub(crate) const NATO_ALPHABET: [(char, &str); 36] = [
+    ('A', "Alfa"),
+    ('B', "Bravo"),
+    ('C', "Charlie"),
+    ('D', "Delta"),
+    ('E', "Echo"),
+    ('F', "Foxtrot"),
+    ('G', "Golf"),
+    ('H', "Hotel"),
+    ('I', "India"),
+    ('J', "Juliett"),
+    ('K', "Kilo"),
+    ('L', "Lima"),
+    ('M', "Mike"),
+    ('N', "November"),
+    ('O', "Oscar"),
+    ('P', "Papa"),
+    ('Q', "Quebec"),
+    ('R', "Romeo"),
+    ('S', "Sierra"),
+    ('T', "Tango"),
+    ('U', "Uniform"),
+    ('V', "Victor"),
+    ('W', "Whiskey"),
+    ('X', "X-ray"),
+    ('Y', "Yankee"),
+    ('Z', "Zulu"),
+    ('0', "Zero"),
+    ('1', "One"),
+    ('2', "Two"),
+    ('3', "Three"),
+    ('4', "Four"),
+    ('5', "Five"),
+    ('6', "Six"),
+    ('7', "Seven"),
+    ('8', "Eight"),
+    ('9', "Nine"),
+];
+
+/// NATO phonetic alphabet encode transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NatoEncode;
+
+impl Transform for NatoEncode {
+    fn name(&self) -> &'static str {
+        "NATO Phonetic Encode"
+    }
+
+    fn id(&self) -> &'static str {
+        "natoencode"
+    }
+
+    fn description(&self) -> &'static str {
+        "Spells text out in the NATO phonetic alphabet (abc becomes Alfa Bravo Charlie), with / between words"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Encoder
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let words: Vec<String> = input
+            .split_whitespace()
+            .map(|word| {
+                word.chars()
+                    .map(|c| {
+                        let upper = c.to_ascii_uppercase();
+                        NATO_ALPHABET
+                            .iter()
+                            .find(|&&(letter, _)| letter == upper)
+                            .map_or_else(|| c.to_string(), |&(_, code)| code.to_string())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+        Ok(words.join(" / "))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Buup 42"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Booking reference",
+                input: "QX7ZK9",
+            },
+            Example {
+                name: "Call sign",
+                input: "G-ABCD",
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nato_encode() {
+        assert_eq!(NatoEncode.transform("abc").unwrap(), "Alfa Bravo Charlie");
+        assert_eq!(
+            NatoEncode
+                .transform(NatoEncode.default_test_input())
+                .unwrap(),
+            "Bravo Uniform Uniform Papa / Four Two"
+        );
+        assert_eq!(NatoEncode.transform("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_nato_encode_keeps_other_characters() {
+        assert_eq!(
+            NatoEncode.transform("G-ABCD").unwrap(),
+            "Golf - Alfa Bravo Charlie Delta"
+        );
+        assert_eq!(NatoEncode.transform("x é").unwrap(), "X-ray / é");
+    }
+}
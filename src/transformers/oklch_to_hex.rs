@@ -0,0 +1,71 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// OKLCH to Hex color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OklchToHex;
+
+impl Transform for OklchToHex {
+    fn name(&self) -> &'static str {
+        "OKLCH to Hex"
+    }
+
+    fn id(&self) -> &'static str {
+        "oklch_to_hex"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts OKLCH (CSS oklch()) color to hex format"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let color = Color::from_oklch(input)?;
+        Ok(color.to_hex())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "oklch(62.8% 0.2577 29.23)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oklch_to_hex() {
+        let transformer = OklchToHex;
+        assert_eq!(
+            transformer.transform("oklch(62.8% 0.2577 29.23)").unwrap(),
+            "#ff0000"
+        );
+        assert_eq!(transformer.transform("oklch(1 0 0)").unwrap(), "#ffffff");
+        assert_eq!(
+            transformer.transform("oklch(0.5, 0.1, 250deg)").unwrap(),
+            "#32669a"
+        );
+        assert_eq!(
+            transformer.transform("oklch(70% 0.4 150)").unwrap(),
+            "#00d600"
+        );
+        assert_eq!(
+            transformer
+                .transform("oklch(62.8% 0.2577 29.23 / 0.5)")
+                .unwrap(),
+            "#ff000080"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = OklchToHex;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("oklch(0.5 0.1)").is_err());
+        assert!(transformer.transform("oklch(2 0.1 0)").is_err());
+        assert!(transformer.transform("oklch(0.5 -0.1 0)").is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// OKLCH to RGB color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OklchToRgb;
+
+impl Transform for OklchToRgb {
+    fn name(&self) -> &'static str {
+        "OKLCH to RGB"
+    }
+
+    fn id(&self) -> &'static str {
+        "oklch_to_rgb"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts OKLCH (CSS oklch()) color to RGB format"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let color = Color::from_oklch(input)?;
+        Ok(color.to_rgb())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "oklch(62.8% 0.2577 29.23)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oklch_to_rgb() {
+        let transformer = OklchToRgb;
+        assert_eq!(
+            transformer.transform("oklch(62.8% 0.2577 29.23)").unwrap(),
+            "rgb(255,0,0)"
+        );
+        assert_eq!(
+            transformer.transform("oklch(45.2% 0.3132 264.05)").unwrap(),
+            "rgb(0,0,255)"
+        );
+        assert_eq!(
+            transformer.transform("oklch(0% 0 0)").unwrap(),
+            "rgb(0,0,0)"
+        );
+        assert_eq!(
+            transformer.transform("oklch(0.6 0.15 30 / 20%)").unwrap(),
+            "rgb(202,87,71,51)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = OklchToRgb;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("hsl(0, 100%, 50%)").is_err());
+        assert!(transformer.transform("oklch(50% 0.1 abc)").is_err());
+    }
+}
@@ -0,0 +1,312 @@
+use crate::utils::random::{entropy_source, EntropySource};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// Longest password, and most passwords, generated in one go
+const MAX_LENGTH: usize = 1024;
+const MAX_COUNT: usize = 1000;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!#$%&*+-=?@^_~()[]{}<>.,:;/|";
+/// Characters easily mistaken for one another in many fonts
+const AMBIGUOUS: &str = "Il1|O0o";
+
+const LENGTH_OPTION: TransformOption = TransformOption {
+    key: "length",
+    description: "Characters per password (at most 1024)",
+    default: "20",
+    kind: TransformOptionKind::Integer,
+};
+
+const COUNT_OPTION: TransformOption = TransformOption {
+    key: "count",
+    description: "Number of passwords to generate (at most 1000)",
+    default: "1",
+    kind: TransformOptionKind::Integer,
+};
+
+const SYMBOLS_OPTION: TransformOption = TransformOption {
+    key: "symbols",
+    description: "Include symbols such as ! and #",
+    default: "true",
+    kind: TransformOptionKind::Boolean,
+};
+
+const EXCLUDE_AMBIGUOUS_OPTION: TransformOption = TransformOption {
+    key: "exclude_ambiguous",
+    description: "Leave out look-alike characters such as l, 1, O and 0",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// Random password generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordGenerate;
+
+impl Transform for PasswordGenerate {
+    fn name(&self) -> &'static str {
+        "Password Generator"
+    }
+
+    fn id(&self) -> &'static str {
+        "password_generate"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Generator
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates cryptographically random passwords, one per line. Input may override the options, e.g. \"24 no-symbols\"."
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[
+            LENGTH_OPTION,
+            COUNT_OPTION,
+            SYMBOLS_OPTION,
+            EXCLUDE_AMBIGUOUS_OPTION,
+        ]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let mut spec = Spec {
+            length: options.usize_value(&LENGTH_OPTION)?,
+            count: options.usize_value(&COUNT_OPTION)?,
+            digits: true,
+            symbols: options.bool_value(&SYMBOLS_OPTION)?,
+            exclude_ambiguous: options.bool_value(&EXCLUDE_AMBIGUOUS_OPTION)?,
+        };
+        spec.apply(input)?;
+        if spec.length == 0 || spec.length > MAX_LENGTH {
+            return Err(TransformError::InvalidArgument(
+                format!("Length must be between 1 and {}", MAX_LENGTH).into(),
+            ));
+        }
+        if spec.count == 0 || spec.count > MAX_COUNT {
+            return Err(TransformError::InvalidArgument(
+                format!("Count must be between 1 and {}", MAX_COUNT).into(),
+            ));
+        }
+
+        let classes = spec.classes();
+        let mut random = RandomBytes::new(entropy_source());
+        let passwords = (0..spec.count)
+            .map(|_| generate(spec.length, &classes, &mut random))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(passwords.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        ""
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Long, letters and digits only",
+                input: "32 no-symbols",
+            },
+            Example {
+                name: "Easy to read aloud",
+                input: "16 no-ambiguous",
+            },
+        ]
+    }
+}
+
+#[derive(Debug)]
+struct Spec {
+    length: usize,
+    count: usize,
+    digits: bool,
+    symbols: bool,
+    exclude_ambiguous: bool,
+}
+
+impl Spec {
+    // Words from the input: a length, then toggles like "no-symbols"
+    fn apply(&mut self, input: &str) -> Result<(), TransformError> {
+        for word in input.split_whitespace() {
+            match word.to_ascii_lowercase().as_str() {
+                "symbols" => self.symbols = true,
+                "no-symbols" => self.symbols = false,
+                "digits" => self.digits = true,
+                "no-digits" => self.digits = false,
+                "no-ambiguous" => self.exclude_ambiguous = true,
+                "ambiguous" => self.exclude_ambiguous = false,
+                word => {
+                    self.length = word.parse().map_err(|_| {
+                        TransformError::InvalidArgument(
+                            format!(
+                                "Unknown password spec '{}', expected a length or one of \
+                                 symbols, no-symbols, digits, no-digits, no-ambiguous",
+                                word
+                            )
+                            .into(),
+                        )
+                    })?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The character classes to draw from, each one used at least once
+    fn classes(&self) -> Vec<Vec<char>> {
+        let mut classes = vec![LOWERCASE, UPPERCASE];
+        if self.digits {
+            classes.push(DIGITS);
+        }
+        if self.symbols {
+            classes.push(SYMBOLS);
+        }
+        classes
+            .into_iter()
+            .map(|class| {
+                class
+                    .chars()
+                    .filter(|c| !(self.exclude_ambiguous && AMBIGUOUS.contains(*c)))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+// Draws every character uniformly from all classes, retrying until each
+// class appears (when the password is long enough to hold them all)
+fn generate(
+    length: usize,
+    classes: &[Vec<char>],
+    random: &mut RandomBytes,
+) -> Result<String, TransformError> {
+    let alphabet: Vec<char> = classes.concat();
+    loop {
+        let password = (0..length)
+            .map(|_| Ok(alphabet[random.below(alphabet.len())?]))
+            .collect::<Result<Vec<char>, TransformError>>()?;
+        if length < classes.len()
+            || classes
+                .iter()
+                .all(|class| password.iter().any(|c| class.contains(c)))
+        {
+            return Ok(password.into_iter().collect());
+        }
+    }
+}
+
+/// Bytes straight from the entropy source, fetched a batch at a time. A
+/// source that fails fails the password too; nothing weaker stands in for it.
+struct RandomBytes<'a> {
+    source: &'a dyn EntropySource,
+    buffer: [u8; 256],
+    position: usize,
+}
+
+impl<'a> RandomBytes<'a> {
+    fn new(source: &'a dyn EntropySource) -> Self {
+        Self {
+            source,
+            buffer: [0; 256],
+            position: 256,
+        }
+    }
+
+    // Uniform index below `bound` (at most 256), rejecting bytes from the
+    // incomplete top range so no character is favoured
+    fn below(&mut self, bound: usize) -> Result<usize, TransformError> {
+        let limit = 256 - 256 % bound;
+        loop {
+            if self.position == self.buffer.len() {
+                self.source.fill(&mut self.buffer)?;
+                self.position = 0;
+            }
+            let byte = usize::from(self.buffer[self.position]);
+            self.position += 1;
+            if byte < limit {
+                return Ok(byte % bound);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate_with(input: &str, settings: &[(&str, &str)]) -> Result<String, TransformError> {
+        let options: TransformOptions = settings.iter().copied().collect();
+        PasswordGenerate.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_password_defaults() {
+        let password = PasswordGenerate.transform("").unwrap();
+        assert_eq!(password.chars().count(), 20);
+        assert!(password.chars().any(|c| c.is_ascii_lowercase()));
+        assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+        assert!(password.chars().any(|c| c.is_ascii_digit()));
+        assert!(password.chars().any(|c| SYMBOLS.contains(c)));
+        assert_ne!(password, PasswordGenerate.transform("").unwrap());
+    }
+
+    #[test]
+    fn test_password_options() {
+        let output = generate_with(
+            "",
+            &[
+                ("count", "50"),
+                ("length", "12"),
+                ("symbols", "false"),
+                ("exclude_ambiguous", "true"),
+            ],
+        )
+        .unwrap();
+        let passwords: Vec<&str> = output.lines().collect();
+        assert_eq!(passwords.len(), 50);
+        for password in passwords {
+            assert_eq!(password.len(), 12);
+            assert!(password.chars().all(|c| c.is_ascii_alphanumeric()));
+            assert!(!password.chars().any(|c| AMBIGUOUS.contains(c)));
+        }
+    }
+
+    #[test]
+    fn test_password_spec_input() {
+        let password = generate_with("24 no-symbols no-digits", &[]).unwrap();
+        assert_eq!(password.len(), 24);
+        assert!(password.chars().all(|c| c.is_ascii_alphabetic()));
+        // Too short to hold every class is still fine
+        assert_eq!(generate_with("2", &[]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_password_invalid() {
+        assert!(generate_with("twenty", &[]).is_err());
+        assert!(generate_with("0", &[]).is_err());
+        assert!(generate_with("", &[("length", "5000")]).is_err());
+        assert!(generate_with("", &[("count", "0")]).is_err());
+    }
+
+    #[test]
+    fn test_password_without_entropy() {
+        let mut random = RandomBytes::new(&crate::utils::random::NoEntropy);
+        assert_eq!(
+            generate(20, &[SYMBOLS.chars().collect()], &mut random),
+            Err(TransformError::EntropyError("none in tests".to_string()))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use super::pig_latin_encode::{is_vowel, map_words, HYPHENATE_OPTION};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+/// Pig Latin decode transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PigLatinDecode;
+
+impl Transform for PigLatinDecode {
+    fn name(&self) -> &'static str {
+        "Pig Latin Decode"
+    }
+
+    fn id(&self) -> &'static str {
+        "piglatindecode"
+    }
+
+    fn description(&self) -> &'static str {
+        "Translates Pig Latin back into English. Without hyphens the split is a best guess (orldway reads as orld, not world)."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Decoder
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[HYPHENATE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let hyphenate = options.bool_value(&HYPHENATE_OPTION)?;
+        map_words(input, hyphenate, token, |word| {
+            if hyphenate {
+                match word.rsplit_once('-') {
+                    Some((rest, suffix)) => format!("{}{}", &suffix[..suffix.len() - 2], rest),
+                    None => word.to_string(),
+                }
+            } else {
+                decode_word(word)
+            }
+        })
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Ellohay, iendsfray! Igpay Atinlay isway itequay implesay."
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Shouting and apostrophes",
+            input: "ON'TDAY ANICPAY, it'sway onlyway away ingstray",
+        }]
+    }
+}
+
+/// Consonant clusters that can start an English word, beyond single letters
+const ONSETS: &[&str] = &[
+    "bl", "br", "ch", "cl", "cr", "dr", "dw", "fl", "fr", "gl", "gn", "gr", "kl", "kn", "ph", "pl",
+    "pr", "ps", "rh", "sc", "sh", "sk", "sl", "sm", "sn", "sp", "st", "sw", "th", "tr", "tw", "wh",
+    "wr", "chr", "phr", "sch", "scr", "shr", "spl", "spr", "str", "thr",
+];
+
+// Reads "way" after a vowel as a vowel-initial word; otherwise the
+// consonants before "ay" went to the end, and the longest run of them that
+// can start a word goes back to the front
+fn decode_word(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("way") {
+        if stem.starts_with(is_vowel) {
+            return stem.to_string();
+        }
+    }
+    let Some(stem) = word.strip_suffix("ay").filter(|stem| !stem.is_empty()) else {
+        return word.to_string();
+    };
+    if let Some(stem) = stem.strip_suffix("qu") {
+        // "qu" was the whole onset unless an s came with it, as in square
+        return match stem.strip_suffix('s').filter(|rest| !rest.is_empty()) {
+            Some(rest) => format!("squ{}", rest),
+            None => format!("qu{}", stem),
+        };
+    }
+    let bytes = stem.as_bytes();
+    let is_consonant = |b: u8| b.is_ascii_alphabetic() && !is_vowel(char::from(b)) && b != b'y';
+    let mut run = bytes.len();
+    while run > 1 && is_consonant(bytes[run - 1]) {
+        run -= 1;
+    }
+    let split = if run == bytes.len() && run > 1 && stem.ends_with('y') {
+        // "y" only counts as a consonant at the start of a word
+        stem.len() - 1
+    } else {
+        (run..bytes.len())
+            .find(|&i| bytes.len() - i == 1 || ONSETS.contains(&&stem[i..]))
+            .unwrap_or(bytes.len())
+    };
+    format!("{}{}", &stem[split..], &stem[..split])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::PigLatinEncode;
+
+    #[test]
+    fn test_pig_latin_decode() {
+        assert_eq!(
+            PigLatinDecode
+                .transform(PigLatinDecode.default_test_input())
+                .unwrap(),
+            "Hello, friends! Pig Latin is quite simple."
+        );
+        assert_eq!(
+            PigLatinDecode
+                .transform("ON'TDAY ANICPAY, it'sway OKWAY TV")
+                .unwrap(),
+            "DON'T PANIC, it's OK TV"
+        );
+    }
+
+    #[test]
+    fn test_pig_latin_decode_clusters() {
+        assert_eq!(
+            PigLatinDecode
+                .transform("ythmrhay oolschay eesquay ingsstray ellowyay")
+                .unwrap(),
+            "rhythm school squee strings yellow"
+        );
+        // The vowel reading wins when a word could have started with w
+        assert_eq!(PigLatinDecode.transform("orldway").unwrap(), "orld");
+    }
+
+    #[test]
+    fn test_pig_latin_decode_qu() {
+        for word in ["quick", "queen", "quiet", "square", "Quick"] {
+            let encoded = PigLatinEncode.transform(word).unwrap();
+            assert_eq!(PigLatinDecode.transform(&encoded).unwrap(), word);
+        }
+        assert_eq!(
+            PigLatinDecode.transform("ickquay Eenquay").unwrap(),
+            "quick Queen"
+        );
+    }
+
+    #[test]
+    fn test_pig_latin_round_trip() {
+        let text = "The quick school yard: three strings, a square and yellow eggs!";
+        let encoded = PigLatinEncode.transform(text).unwrap();
+        assert_eq!(PigLatinDecode.transform(&encoded).unwrap(), text);
+    }
+
+    #[test]
+    fn test_pig_latin_decode_hyphenated() {
+        let mut options = TransformOptions::new();
+        options.set("hyphenate", "true");
+        // Words starting with w, which are ambiguous without hyphens
+        let text = "Well, we were eating well-known apples";
+        let encoded = PigLatinEncode
+            .transform_with_options(text, &options)
+            .unwrap();
+        assert_eq!(
+            PigLatinDecode
+                .transform_with_options(&encoded, &options)
+                .unwrap(),
+            text
+        );
+    }
+}
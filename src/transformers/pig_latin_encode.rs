@@ -0,0 +1,190 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+pub(crate) const HYPHENATE_OPTION: TransformOption = TransformOption {
+    key: "hyphenate",
+    description: "Mark the moved letters with a hyphen (ello-hay, apple-ay), which decodes exactly",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// Pig Latin encode transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PigLatinEncode;
+
+impl Transform for PigLatinEncode {
+    fn name(&self) -> &'static str {
+        "Pig Latin Encode"
+    }
+
+    fn id(&self) -> &'static str {
+        "piglatinencode"
+    }
+
+    fn description(&self) -> &'static str {
+        "Translates text into Pig Latin (hello becomes ellohay, apple becomes appleway), keeping capitals and punctuation"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Encoder
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[HYPHENATE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let hyphenate = options.bool_value(&HYPHENATE_OPTION)?;
+        map_words(input, false, token, |word| match onset_len(word) {
+            // Words without vowels (TV, hmm) are left alone
+            None => word.to_string(),
+            Some(split) if hyphenate => format!("{}-{}ay", &word[split..], &word[..split]),
+            Some(0) => format!("{}way", word),
+            Some(split) => format!("{}{}ay", &word[split..], &word[..split]),
+        })
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Hello, friends! Pig Latin is quite simple."
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Shouting and apostrophes",
+            input: "DON'T PANIC, it's only a string",
+        }]
+    }
+}
+
+pub(crate) fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+// Length of the consonants before the first vowel, counting "qu" as a
+// consonant and "y" as a vowel except at the start of the word
+fn onset_len(word: &str) -> Option<usize> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut i = 0;
+    while i < chars.len() && !is_vowel(chars[i]) && !(chars[i] == 'y' && i > 0) {
+        if chars[i] == 'q' && chars.get(i + 1) == Some(&'u') {
+            i += 1;
+        }
+        i += 1;
+    }
+    (i < chars.len()).then_some(i)
+}
+
+/// Applies `translate` to the lowercase form of every word (letters with
+/// apostrophes inside them) and restores the word's capitalization after;
+/// everything between words is kept as is. With `hyphenated`, a following
+/// "-...ay" suffix is taken as part of the word. Polls `cancel` between words.
+pub(crate) fn map_words(
+    input: &str,
+    hyphenated: bool,
+    cancel: &CancelToken,
+    translate: impl Fn(&str) -> String,
+) -> Result<String, TransformError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::with_capacity(input.len() + input.len() / 2);
+    let mut i = 0;
+    let mut words = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_alphabetic() {
+            output.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        cancel.check_every(words)?;
+        words += 1;
+        let start = i;
+        while i < chars.len()
+            && (chars[i].is_ascii_alphabetic()
+                || (chars[i] == '\'' && chars.get(i + 1).is_some_and(|c| c.is_ascii_alphabetic())))
+        {
+            i += 1;
+        }
+        if hyphenated && chars.get(i) == Some(&'-') {
+            let suffix_end = (i + 1..chars.len())
+                .find(|&j| !chars[j].is_ascii_alphabetic())
+                .unwrap_or(chars.len());
+            let suffix: String = chars[i + 1..suffix_end].iter().collect();
+            if suffix.to_ascii_lowercase().ends_with("ay") {
+                i = suffix_end;
+            }
+        }
+        let word: String = chars[start..i].iter().collect();
+        let translated = translate(&word.to_ascii_lowercase());
+        output.push_str(&restore_case(&word, &translated));
+    }
+    Ok(output)
+}
+
+fn restore_case(original: &str, translated: &str) -> String {
+    let letters = || original.chars().filter(char::is_ascii_alphabetic);
+    if letters().count() > 1 && letters().all(|c| c.is_ascii_uppercase()) {
+        translated.to_ascii_uppercase()
+    } else if original.starts_with(|c: char| c.is_ascii_uppercase()) {
+        let mut chars = translated.chars();
+        chars.next().map_or_else(String::new, |first| {
+            first.to_ascii_uppercase().to_string() + chars.as_str()
+        })
+    } else {
+        translated.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pig_latin_encode() {
+        assert_eq!(
+            PigLatinEncode
+                .transform(PigLatinEncode.default_test_input())
+                .unwrap(),
+            "Ellohay, iendsfray! Igpay Atinlay isway itequay implesay."
+        );
+        assert_eq!(
+            PigLatinEncode
+                .transform("string yellow rhythm square")
+                .unwrap(),
+            "ingstray ellowyay ythmrhay aresquay"
+        );
+    }
+
+    #[test]
+    fn test_pig_latin_encode_case_and_apostrophes() {
+        assert_eq!(
+            PigLatinEncode.transform("DON'T PANIC, it's OK").unwrap(),
+            "ON'TDAY ANICPAY, it'sway OKWAY"
+        );
+        assert_eq!(
+            PigLatinEncode.transform("I saw TV").unwrap(),
+            "Iway awsay TV"
+        );
+    }
+
+    #[test]
+    fn test_pig_latin_encode_hyphenated() {
+        let mut options = TransformOptions::new();
+        options.set("hyphenate", "true");
+        assert_eq!(
+            PigLatinEncode
+                .transform_with_options("Hello apple, well-known", &options)
+                .unwrap(),
+            "Ello-hay apple-ay, ell-way-own-knay"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::utils::json::JsonValue;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// Java .properties to JSON transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropertiesToJson;
+
+impl Transform for PropertiesToJson {
+    fn name(&self) -> &'static str {
+        "Properties to JSON"
+    }
+
+    fn id(&self) -> &'static str {
+        "propertiestojson"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts a Java .properties file into a flat JSON object, handling = : and space separators, line continuations and \\uXXXX escapes"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let mut entries: Vec<(String, JsonValue)> = Vec::new();
+        for (i, line) in logical_lines(input) {
+            let (key, value) = split_entry(&line);
+            let key = unescape(key).map_err(|e| line_error(i, e))?;
+            let value = JsonValue::String(unescape(value).map_err(|e| line_error(i, e))?);
+            // As in java.util.Properties, a repeated key takes the last value
+            match entries.iter_mut().find(|(k, _)| *k == key) {
+                Some(entry) => entry.1 = value,
+                None => entries.push((key, value)),
+            }
+        }
+        Ok(JsonValue::Object(entries).to_pretty_string(2))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "# Spring settings\nspring.datasource.url=jdbc:postgresql://localhost/app\nspring.datasource.username : admin\ngreeting = Gr\\u00fc\\u00dfe, \\\n    world!\nempty.value="
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Separators",
+                input: "key1=value1\nkey2: value2\nkey3 value3\nkey\\ with\\ spaces = yes",
+            },
+            Example {
+                name: "Continued list",
+                input: "! Fruits\nfruits = apple, banana, \\\n         cherry, \\\n         durian",
+            },
+        ]
+    }
+}
+
+fn line_error(line: usize, message: String) -> TransformError {
+    TransformError::InvalidArgument(format!("Line {}: {}", line, message).into())
+}
+
+// The logical lines of the file with their starting line numbers: comments
+// and blank lines dropped, and lines ending in an odd number of
+// backslashes joined to the next with its leading whitespace removed
+fn logical_lines(input: &str) -> Vec<(usize, String)> {
+    let mut logical = Vec::new();
+    let mut lines = input.lines().enumerate();
+    while let Some((i, line)) = lines.next() {
+        let line = line.trim_start_matches([' ', '\t', '\u{000C}']);
+        if line.is_empty() || line.starts_with(['#', '!']) {
+            continue;
+        }
+        let mut text = line.to_string();
+        while ends_with_continuation(&text) {
+            text.pop();
+            match lines.next() {
+                Some((_, next)) => text.push_str(next.trim_start_matches([' ', '\t', '\u{000C}'])),
+                None => break,
+            }
+        }
+        logical.push((i + 1, text));
+    }
+    logical
+}
+
+fn ends_with_continuation(line: &str) -> bool {
+    line.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1
+}
+
+// Splits a logical line at the first unescaped `=`, `:` or whitespace,
+// dropping the separator and the whitespace around it
+fn split_entry(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+    let mut key_end = line.len();
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' | ':' | ' ' | '\t' | '\u{000C}' => {
+                key_end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    let rest = line[key_end..].trim_start_matches([' ', '\t', '\u{000C}']);
+    let rest = rest.strip_prefix(['=', ':']).map_or(rest, |rest| {
+        rest.trim_start_matches([' ', '\t', '\u{000C}'])
+    });
+    (&line[..key_end], rest)
+}
+
+// Resolves \t \n \r \f and \uXXXX escapes; a backslash before any other
+// character just keeps that character
+fn unescape(text: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('f') => out.push('\u{000C}'),
+            Some('u') => {
+                let unit = read_unit(&mut chars)?;
+                // Characters outside the BMP arrive as a surrogate pair
+                let c = if (0xD800..0xDC00).contains(&unit) {
+                    let low = match (chars.next(), chars.next()) {
+                        (Some('\\'), Some('u')) => read_unit(&mut chars)?,
+                        _ => return Err(format!("\\u{:04x} is half a surrogate pair", unit)),
+                    };
+                    char::from_u32(0x10000 + ((unit - 0xD800) << 10) + (low.wrapping_sub(0xDC00)))
+                        .filter(|_| (0xDC00..0xE000).contains(&low))
+                } else {
+                    char::from_u32(unit)
+                };
+                out.push(c.ok_or_else(|| format!("\\u{:04x} is not a character", unit))?);
+            }
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    Ok(out)
+}
+
+fn read_unit(chars: &mut std::str::Chars) -> Result<u32, String> {
+    let digits: String = chars.by_ref().take(4).collect();
+    if digits.len() == 4 && digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(u32::from_str_radix(&digits, 16).unwrap_or_default())
+    } else {
+        Err(format!("'\\u{}' is not a valid unicode escape", digits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(input: &str) -> Vec<(String, String)> {
+        match JsonValue::parse(&PropertiesToJson.transform(input).unwrap()).unwrap() {
+            JsonValue::Object(entries) => entries
+                .into_iter()
+                .map(|(k, v)| (k, v.as_str().unwrap().to_string()))
+                .collect(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_properties_to_json() {
+        assert_eq!(
+            PropertiesToJson
+                .transform(PropertiesToJson.default_test_input())
+                .unwrap(),
+            "{\n  \"spring.datasource.url\": \"jdbc:postgresql://localhost/app\",\n  \"spring.datasource.username\": \"admin\",\n  \"greeting\": \"Grüße, world!\",\n  \"empty.value\": \"\"\n}"
+        );
+        assert_eq!(PropertiesToJson.transform("").unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_properties_separators() {
+        let pairs = |list: &[(&str, &str)]| -> Vec<(String, String)> {
+            list.iter()
+                .map(|&(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        assert_eq!(
+            entries(
+                "a=1\nb: 2\nc 3\n  d\t=\t4\ne\nf==5\ng=x:y=z\nk\\ ey\\=x\\:y = v\n! bang\n# hash"
+            ),
+            pairs(&[
+                ("a", "1"),
+                ("b", "2"),
+                ("c", "3"),
+                ("d", "4"),
+                ("e", ""),
+                ("f", "=5"),
+                ("g", "x:y=z"),
+                ("k ey=x:y", "v"),
+            ])
+        );
+        assert_eq!(entries("a=1\na=2"), pairs(&[("a", "2")]));
+    }
+
+    #[test]
+    fn test_properties_continuations_and_escapes() {
+        let parsed = entries(
+            "list = a, \\\n    b, \\\n\tc\npath=C:\\\\dir\\\\\nnext=\\u00e9\\t\\n\\q\nemoji=\\ud83d\\ude00\ntrailing=x\\",
+        );
+        assert_eq!(parsed[0].1, "a, b, c");
+        // An even number of backslashes is not a continuation
+        assert_eq!(parsed[1].1, "C:\\dir\\");
+        assert_eq!(parsed[2].1, "é\t\nq");
+        assert_eq!(parsed[3].1, "😀");
+        assert_eq!(parsed[4].1, "x");
+    }
+
+    #[test]
+    fn test_properties_invalid() {
+        assert!(PropertiesToJson.transform("a=\\u12").is_err());
+        assert!(PropertiesToJson.transform("a=\\uzzzz").is_err());
+        assert_eq!(
+            PropertiesToJson
+                .transform("ok=1\nbad=\\ud800")
+                .unwrap_err()
+                .to_string(),
+            "Invalid argument: Line 2: \\ud800 is half a surrogate pair"
+        );
+    }
+}
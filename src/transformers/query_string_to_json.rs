@@ -0,0 +1,241 @@
+use super::url_decode::url_decode;
+use crate::utils::json::JsonValue;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// URL query string to JSON transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryStringToJson;
+
+impl Transform for QueryStringToJson {
+    fn name(&self) -> &'static str {
+        "Query String to JSON"
+    }
+
+    fn id(&self) -> &'static str {
+        "querystringtojson"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts a URL query string (or a whole URL) into a JSON object. Repeated keys and key[] collect into arrays, key[sub] builds nested objects."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let query = input.trim();
+        let query = query.split_once('?').map_or(query, |(_, query)| query);
+        let query = query.split_once('#').map_or(query, |(query, _)| query);
+
+        let mut root = JsonValue::Object(Vec::new());
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let decode = |text: &str| {
+                url_decode(text).map_err(|_| {
+                    TransformError::InvalidArgument(
+                        format!("'{}' is not valid percent-encoding", text).into(),
+                    )
+                })
+            };
+            let (key, value) = (decode(key)?, decode(value)?);
+            let path = parse_key(&key);
+            insert(&mut root, &path, value).map_err(|(depth, conflict)| {
+                TransformError::InvalidArgument(
+                    format!("'{}' {}", key_path(&path[..depth]), conflict).into(),
+                )
+            })?;
+        }
+        Ok(root.to_pretty_string(2))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "?q=rust+wasm&page=2&tags[]=cli&tags[]=web&filter[lang]=en&filter[sort]=new%20first"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Whole URL",
+                input: "https://example.com/search?utm_source=mail&utm_campaign=spring%202024#top",
+            },
+            Example {
+                name: "Repeated keys",
+                input: "color=red&color=blue&size=M",
+            },
+        ]
+    }
+}
+
+/// One step of a bracketed key like `a[b][]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Append,
+}
+
+// `a[b][]` becomes a, b, append. A key whose brackets don't close is taken
+// literally.
+fn parse_key(key: &str) -> Vec<Segment> {
+    let Some(open) = key.find('[').filter(|&open| open > 0) else {
+        return vec![Segment::Key(key.to_string())];
+    };
+    let mut segments = vec![Segment::Key(key[..open].to_string())];
+    let mut rest = &key[open..];
+    while let Some(inner) = rest.strip_prefix('[') {
+        let Some(close) = inner.find(']') else {
+            return vec![Segment::Key(key.to_string())];
+        };
+        segments.push(match &inner[..close] {
+            "" => Segment::Append,
+            name => Segment::Key(name.to_string()),
+        });
+        rest = &inner[close + 1..];
+    }
+    if !rest.is_empty() {
+        return vec![Segment::Key(key.to_string())];
+    }
+    segments
+}
+
+// Writes segments back out as a bracketed key
+fn key_path(path: &[Segment]) -> String {
+    let mut key = String::new();
+    for (index, segment) in path.iter().enumerate() {
+        match segment {
+            Segment::Key(name) if index == 0 => key.push_str(name),
+            Segment::Key(name) => key.push_str(&format!("[{}]", name)),
+            Segment::Append => key.push_str("[]"),
+        }
+    }
+    key
+}
+
+// Puts `value` at `path` under `target`, creating objects and arrays on
+// the way. A key given twice collects its values into an array. A conflict
+// comes back with how many segments of `path` lead to the clashing value.
+fn insert(
+    target: &mut JsonValue,
+    path: &[Segment],
+    value: String,
+) -> Result<(), (usize, &'static str)> {
+    let (segment, rest) = path.split_first().ok_or((0, "is empty"))?;
+    let empty_container = || match rest.first() {
+        Some(Segment::Append) => JsonValue::Array(Vec::new()),
+        _ => JsonValue::Object(Vec::new()),
+    };
+    match (segment, target) {
+        (Segment::Key(key), JsonValue::Object(entries)) => {
+            let index = match entries.iter().position(|(k, _)| k == key) {
+                Some(index) => index,
+                None if rest.is_empty() => {
+                    entries.push((key.clone(), JsonValue::String(value)));
+                    return Ok(());
+                }
+                None => {
+                    entries.push((key.clone(), empty_container()));
+                    entries.len() - 1
+                }
+            };
+            let existing = &mut entries[index].1;
+            if !rest.is_empty() {
+                return insert(existing, rest, value)
+                    .map_err(|(depth, conflict)| (depth + 1, conflict));
+            }
+            match existing {
+                JsonValue::Array(items) => items.push(JsonValue::String(value)),
+                JsonValue::String(_) => {
+                    let first = std::mem::replace(existing, JsonValue::Null);
+                    *existing = JsonValue::Array(vec![first, JsonValue::String(value)]);
+                }
+                _ => return Err((1, "is used both as a value and as an object")),
+            }
+            Ok(())
+        }
+        (Segment::Append, JsonValue::Array(items)) => {
+            if rest.is_empty() {
+                items.push(JsonValue::String(value));
+                Ok(())
+            } else {
+                items.push(empty_container());
+                insert(items.last_mut().unwrap(), rest, value)
+                    .map_err(|(depth, conflict)| (depth + 1, conflict))
+            }
+        }
+        (Segment::Key(_), _) => Err((0, "is used both as a value or list and as an object")),
+        (Segment::Append, _) => Err((0, "is used both as a value or object and as a list")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_string_to_json() {
+        assert_eq!(
+            QueryStringToJson
+                .transform(QueryStringToJson.default_test_input())
+                .unwrap(),
+            "{\n  \"q\": \"rust wasm\",\n  \"page\": \"2\",\n  \"tags\": [\n    \"cli\",\n    \"web\"\n  ],\n  \"filter\": {\n    \"lang\": \"en\",\n    \"sort\": \"new first\"\n  }\n}"
+        );
+        assert_eq!(QueryStringToJson.transform("").unwrap(), "{}");
+        assert_eq!(QueryStringToJson.transform("?").unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_query_string_shapes() {
+        let to_json = |input: &str| {
+            JsonValue::parse(&QueryStringToJson.transform(input).unwrap())
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(
+            to_json("https://x.io/p?a=1&a=2&a=3&flag&empty=#frag"),
+            r#"{"a":["1","2","3"],"flag":"","empty":""}"#
+        );
+        assert_eq!(
+            to_json("user[name]=Ada&user[langs][]=en&user[langs][]=fr&items[][id]=1&items[][id]=2"),
+            r#"{"user":{"name":"Ada","langs":["en","fr"]},"items":[{"id":"1"},{"id":"2"}]}"#
+        );
+        // Encoded brackets count too, broken ones are literal
+        assert_eq!(
+            to_json("a%5Bb%5D=1&c[d=2&[e]=3&f[g]h=4"),
+            r#"{"a":{"b":"1"},"c[d":"2","[e]":"3","f[g]h":"4"}"#
+        );
+    }
+
+    #[test]
+    fn test_query_string_conflicts() {
+        assert_eq!(
+            QueryStringToJson
+                .transform("a=1&a[b]=2")
+                .unwrap_err()
+                .to_string(),
+            "Invalid argument: 'a' is used both as a value or list and as an object"
+        );
+        assert_eq!(
+            QueryStringToJson
+                .transform("a[b][c]=1&a[b][c][d]=2")
+                .unwrap_err()
+                .to_string(),
+            "Invalid argument: 'a[b][c]' is used both as a value or list and as an object"
+        );
+        assert_eq!(
+            QueryStringToJson
+                .transform("a[b]=1&a=2")
+                .unwrap_err()
+                .to_string(),
+            "Invalid argument: 'a' is used both as a value and as an object"
+        );
+        assert_eq!(
+            QueryStringToJson
+                .transform("a[b]=1&a[b][]=2")
+                .unwrap_err()
+                .to_string(),
+            "Invalid argument: 'a[b]' is used both as a value or object and as a list"
+        );
+        assert!(QueryStringToJson.transform("a[b]=1&a[]=2").is_err());
+        assert!(QueryStringToJson.transform("a=%zz").is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// RGB to HSV color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RgbToHsv;
+
+impl Transform for RgbToHsv {
+    fn name(&self) -> &'static str {
+        "RGB to HSV"
+    }
+
+    fn id(&self) -> &'static str {
+        "rgb_to_hsv"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts RGB color to HSV (HSB) format"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with("rgb(") {
+            return Err(TransformError::InvalidArgument(
+                "Invalid RGB format. Must start with rgb(".into(),
+            ));
+        }
+
+        let color = Color::from_rgb(input)?;
+        Ok(color.to_hsv())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "rgb(255, 128, 0)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_to_hsv() {
+        let transformer = RgbToHsv;
+        assert_eq!(
+            transformer.transform("rgb(255, 0, 0)").unwrap(),
+            "hsv(0deg,100%,100%)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(0, 128, 0)").unwrap(),
+            "hsv(120deg,100%,50%)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(128, 128, 255)").unwrap(),
+            "hsv(240deg,50%,100%)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(0, 0, 0)").unwrap(),
+            "hsv(0deg,0%,0%)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(255, 255, 255)").unwrap(),
+            "hsv(0deg,0%,100%)"
+        );
+    }
+
+    #[test]
+    fn test_with_alpha() {
+        let transformer = RgbToHsv;
+        assert_eq!(
+            transformer.transform("rgb(255, 0, 255, 128)").unwrap(),
+            "hsv(300deg,100%,100%,0.50)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = RgbToHsv;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("255, 0, 0").is_err()); // Missing rgb(
+        assert!(transformer.transform("rgb(300, 0, 0)").is_err()); // Invalid value
+    }
+}
@@ -0,0 +1,74 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// RGB to Lab color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RgbToLab;
+
+impl Transform for RgbToLab {
+    fn name(&self) -> &'static str {
+        "RGB to Lab"
+    }
+
+    fn id(&self) -> &'static str {
+        "rgb_to_lab"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts RGB color to CIELAB (CSS lab(), D50)"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with("rgb(") {
+            return Err(TransformError::InvalidArgument(
+                "Invalid RGB format. Must start with rgb(".into(),
+            ));
+        }
+
+        let color = Color::from_rgb(input)?;
+        Ok(color.to_lab())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "rgb(255, 0, 0)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_to_lab() {
+        let transformer = RgbToLab;
+        assert_eq!(
+            transformer.transform("rgb(255, 0, 0)").unwrap(),
+            "lab(54.29% 80.80 69.89)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(0, 255, 0)").unwrap(),
+            "lab(87.82% -79.27 80.99)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(128, 128, 128)").unwrap(),
+            "lab(53.59% 0.00 0.00)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(0, 0, 255, 128)").unwrap(),
+            "lab(29.57% 68.29 -112.03 / 0.50)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = RgbToLab;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("255, 0, 0").is_err());
+        assert!(transformer.transform("rgb(300, 0, 0)").is_err());
+    }
+}
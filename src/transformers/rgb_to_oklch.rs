@@ -0,0 +1,74 @@
+use crate::utils::Color;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// RGB to OKLCH color transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RgbToOklch;
+
+impl Transform for RgbToOklch {
+    fn name(&self) -> &'static str {
+        "RGB to OKLCH"
+    }
+
+    fn id(&self) -> &'static str {
+        "rgb_to_oklch"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Color
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts RGB color to OKLCH (CSS oklch())"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let input = input.trim();
+        if !input.starts_with("rgb(") {
+            return Err(TransformError::InvalidArgument(
+                "Invalid RGB format. Must start with rgb(".into(),
+            ));
+        }
+
+        let color = Color::from_rgb(input)?;
+        Ok(color.to_oklch())
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "rgb(255, 0, 0)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_to_oklch() {
+        let transformer = RgbToOklch;
+        assert_eq!(
+            transformer.transform("rgb(255, 0, 0)").unwrap(),
+            "oklch(62.80% 0.2577 29.23)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(0, 0, 255)").unwrap(),
+            "oklch(45.20% 0.3132 264.05)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(0, 0, 0)").unwrap(),
+            "oklch(0.00% 0.0000 0.00)"
+        );
+        assert_eq!(
+            transformer.transform("rgb(255, 255, 0, 51)").unwrap(),
+            "oklch(96.80% 0.2110 109.77 / 0.20)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transformer = RgbToOklch;
+        assert!(transformer.transform("invalid").is_err());
+        assert!(transformer.transform("255, 0, 0").is_err());
+        assert!(transformer.transform("rgb(300, 0, 0)").is_err());
+    }
+}
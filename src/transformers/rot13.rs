@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Rot13 transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,6 +38,13 @@ impl Transform for Rot13 {
     fn default_test_input(&self) -> &'static str {
         "The quick brown fox jumps over the lazy dog"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Riddle",
+            input: "Why did the chicken cross the road? Gb trg gb gur bgure fvqr!",
+        }]
+    }
 }
 
 #[cfg(test)]
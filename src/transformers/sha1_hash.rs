@@ -1,87 +1,11 @@
-use crate::{Transform, TransformError, TransformerCategory};
-
-// SHA-1 constants
-const H0: u32 = 0x67452301;
-const H1: u32 = 0xEFCDAB89;
-const H2: u32 = 0x98BADCFE;
-const H3: u32 = 0x10325476;
-const H4: u32 = 0xC3D2E1F0;
+use crate::utils::digest::{hex_digest, HexDigestStream};
+use crate::utils::Sha1;
+use crate::{Example, StreamTransform, Transform, TransformError, TransformerCategory};
 
 /// SHA-1 hash transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Sha1Hash;
 
-// Default test input for SHA1 Hash
-// pub const DEFAULT_TEST_INPUT: &str = "buup text utility";
-
-impl Sha1Hash {
-    // Pads the message according to SHA-1 standard (RFC 3174)
-    fn pad_message(message: &[u8]) -> Vec<u8> {
-        let message_len_bits = (message.len() as u64) * 8;
-        let mut padded = message.to_vec();
-        padded.push(0x80); // Append '1' bit
-
-        // Append '0' bits until message length is congruent to 448 (mod 512)
-        while padded.len() % 64 != 56 {
-            padded.push(0x00);
-        }
-
-        // Append original message length as 64-bit big-endian integer
-        padded.extend_from_slice(&message_len_bits.to_be_bytes());
-
-        padded
-    }
-
-    // Processes a single 512-bit (64-byte) block
-    fn process_block(h: &mut [u32; 5], block: &[u8]) {
-        assert_eq!(block.len(), 64);
-
-        let mut w = [0u32; 80];
-        for (i, chunk) in block.chunks_exact(4).enumerate() {
-            w[i] = u32::from_be_bytes(chunk.try_into().unwrap());
-        }
-
-        for i in 16..80 {
-            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
-        }
-
-        let mut a = h[0];
-        let mut b = h[1];
-        let mut c = h[2];
-        let mut d = h[3];
-        let mut e = h[4];
-
-        for (i, w_i) in w.iter().enumerate() {
-            let (f, k) = match i {
-                0..=19 => (((b & c) | (!b & d)), 0x5A827999),
-                20..=39 => ((b ^ c ^ d), 0x6ED9EBA1),
-                40..=59 => (((b & c) | (b & d) | (c & d)), 0x8F1BBCDC),
-                60..=79 => ((b ^ c ^ d), 0xCA62C1D6),
-                _ => unreachable!(), // Should not happen
-            };
-
-            let temp = a
-                .rotate_left(5)
-                .wrapping_add(f)
-                .wrapping_add(e)
-                .wrapping_add(k)
-                .wrapping_add(*w_i);
-
-            e = d;
-            d = c;
-            c = b.rotate_left(30);
-            b = a;
-            a = temp;
-        }
-
-        h[0] = h[0].wrapping_add(a);
-        h[1] = h[1].wrapping_add(b);
-        h[2] = h[2].wrapping_add(c);
-        h[3] = h[3].wrapping_add(d);
-        h[4] = h[4].wrapping_add(e);
-    }
-}
-
 impl Transform for Sha1Hash {
     fn name(&self) -> &'static str {
         "SHA-1 Hash"
@@ -100,27 +24,27 @@ impl Transform for Sha1Hash {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        let message = input.as_bytes();
-        let padded_message = Self::pad_message(message);
-
-        let mut h = [H0, H1, H2, H3, H4]; // Initial hash values
-
-        for block in padded_message.chunks_exact(64) {
-            Self::process_block(&mut h, block);
-        }
+        Ok(hex_digest::<Sha1>(input.as_bytes()))
+    }
 
-        // Convert the final hash state (h0-h4) to a hex string
-        let mut result = String::with_capacity(40); // SHA-1 output is 160 bits = 20 bytes = 40 hex chars
-        for val in h.iter() {
-            result.push_str(&format!("{:08x}", val));
-        }
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        Ok(hex_digest::<Sha1>(input).into_bytes())
+    }
 
-        Ok(result)
+    fn stream(&self) -> Option<Box<dyn StreamTransform>> {
+        Some(Box::new(HexDigestStream::<Sha1>::default()))
     }
 
     fn default_test_input(&self) -> &'static str {
         "buup"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Pangram",
+            input: "The quick brown fox jumps over the lazy dog",
+        }]
+    }
 }
 
 #[cfg(test)]
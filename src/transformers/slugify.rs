@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Slugify transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -62,6 +62,19 @@ impl Transform for Slugify {
     fn default_test_input(&self) -> &'static str {
         "This is a Test String! 123?"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Title with accents",
+                input: "Ça va? Crème Brûlée Recipes (2024 Edition)",
+            },
+            Example {
+                name: "Messy spacing",
+                input: "  Hello,   World!!  ",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
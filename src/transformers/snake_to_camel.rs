@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// SnakeToCamel transformer converts snake_case to camelCase
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -51,6 +51,19 @@ impl Transform for SnakeToCamel {
     fn default_test_input(&self) -> &'static str {
         "convert_this_snake_case_string"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Constant",
+                input: "MAX_RETRY_COUNT",
+            },
+            Example {
+                name: "Database column",
+                input: "created_at_utc",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
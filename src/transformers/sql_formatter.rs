@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{CancelToken, Example, Transform, TransformError, TransformerCategory};
 
 /// SQL Formatter transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,13 +25,38 @@ impl Transform for SqlFormatter {
         "SELECT id, username, email FROM users WHERE status = 'active' AND created_at > '2023-01-01' ORDER BY created_at DESC LIMIT 10"
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Join with aggregate",
+                input: "SELECT u.name, COUNT(o.id) AS orders FROM users u LEFT JOIN orders o ON o.user_id = u.id WHERE u.active = 1 GROUP BY u.name HAVING COUNT(o.id) > 5 ORDER BY orders DESC",
+            },
+            Example {
+                name: "Insert",
+                input: "INSERT INTO users (name, email) VALUES ('Ada', 'ada@example.com'), ('Grace', 'grace@example.com')",
+            },
+            Example {
+                name: "Update",
+                input: "UPDATE products SET price = price * 1.1, updated_at = NOW() WHERE category = 'books' AND stock > 0",
+            },
+        ]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        cancel: &CancelToken,
+    ) -> Result<String, TransformError> {
         // Skip empty input
         if input.trim().is_empty() {
             return Ok(String::new());
         }
 
-        format_sql(input)
+        format_sql(input, cancel)
     }
 }
 
@@ -72,7 +97,7 @@ const MAJOR_KEYWORDS: [&str; 7] = [
 ];
 
 // Format SQL query with proper indentation and spacing
-fn format_sql(input: &str) -> Result<String, TransformError> {
+fn format_sql(input: &str, cancel: &CancelToken) -> Result<String, TransformError> {
     let mut result = String::with_capacity(input.len() * 2);
     let mut input_chars = input.chars().peekable();
     let mut indent_level: usize = 0;
@@ -84,8 +109,12 @@ fn format_sql(input: &str) -> Result<String, TransformError> {
     let mut in_comment = false;
     let mut in_multiline_comment = false;
     let mut pending_whitespace = false;
+    let mut iteration = 0;
 
     while let Some(c) = input_chars.next() {
+        cancel.check_every(iteration)?;
+        iteration += 1;
+
         // Handle strings (quoted literals)
         if (c == '\'' || c == '"') && !in_comment && !in_multiline_comment {
             if !in_string {
@@ -102,6 +131,7 @@ fn format_sql(input: &str) -> Result<String, TransformError> {
                 }
 
                 result.push(c);
+                at_beginning_of_line = false;
             } else if c == string_quote_char {
                 // Check for escaped quotes
                 if input_chars.peek() == Some(&c) {
@@ -500,4 +530,13 @@ mod tests {
             "SELECT  * \nFROM  users\nWHERE  name = 'John''s' AND  department = \"Sales\"";
         assert_eq!(transformer.transform(input).unwrap(), expected);
     }
+
+    #[test]
+    fn test_sql_formatter_string_list() {
+        let transformer = SqlFormatter;
+        let input = "SELECT * FROM t WHERE status IN ('active', 'trial')";
+        let output = transformer.transform(input).unwrap();
+        assert!(output.contains("'active'"));
+        assert!(output.contains("'trial'"));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// SQL Minifier transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +30,15 @@ ORDER BY created_at DESC
 LIMIT 10"#
     }
 
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Formatted query",
+                input: "SELECT\n    id,\n    name\nFROM\n    users\n-- only active users\nWHERE\n    active = 1\nORDER BY\n    name;",
+            },
+        ]
+    }
+
     fn transform(&self, input: &str) -> Result<String, TransformError> {
         // Skip empty input
         if input.trim().is_empty() {
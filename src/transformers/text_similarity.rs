@@ -0,0 +1,244 @@
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// Most character pairs compared; the longest common subsequence keeps a
+/// table with one entry per pair
+const MAX_PAIRS: usize = 4_000_000;
+
+const OTHER_OPTION: TransformOption = TransformOption {
+    key: "other",
+    description: "Text to compare the input with. When empty, the input's two lines are compared",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+const IGNORE_CASE_OPTION: TransformOption = TransformOption {
+    key: "ignore_case",
+    description: "Treat upper and lower case letters as equal",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// String similarity calculator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextSimilarity;
+
+impl Transform for TextSimilarity {
+    fn name(&self) -> &'static str {
+        "Text Similarity"
+    }
+
+    fn id(&self) -> &'static str {
+        "text_similarity"
+    }
+
+    fn description(&self) -> &'static str {
+        "Compares two texts, given as two lines or through the other option: Levenshtein distance, similarity percentage and longest common subsequence"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[OTHER_OPTION, IGNORE_CASE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let other = options.value(&OTHER_OPTION);
+        let (first, second) = if other.is_empty() {
+            let lines: Vec<&str> = input.lines().collect();
+            match lines[..] {
+                [first, second] => (first, second),
+                _ => {
+                    return Err(TransformError::InvalidArgument(
+                        format!(
+                            "Expected two lines to compare, got {} (or set the other option)",
+                            lines.len()
+                        )
+                        .into(),
+                    ))
+                }
+            }
+        } else {
+            (input, other)
+        };
+
+        let ignore_case = options.bool_value(&IGNORE_CASE_OPTION)?;
+        let chars = |text: &str| -> Vec<char> {
+            if ignore_case {
+                text.chars().flat_map(char::to_lowercase).collect()
+            } else {
+                text.chars().collect()
+            }
+        };
+        let (a, b) = (chars(first), chars(second));
+        if a.len().saturating_mul(b.len()) > MAX_PAIRS {
+            return Err(TransformError::InvalidArgument(
+                format!(
+                    "Texts of {} and {} characters are too long to compare, the limit is {} character pairs",
+                    a.len(),
+                    b.len(),
+                    MAX_PAIRS
+                )
+                .into(),
+            ));
+        }
+
+        let distance = levenshtein(&a, &b, token)?;
+        let common = longest_common_subsequence(&a, &b, token)?;
+        let longest = a.len().max(b.len());
+        let similarity = if longest == 0 {
+            100.0
+        } else {
+            100.0 * (1.0 - distance as f64 / longest as f64)
+        };
+        Ok(format!(
+            "Lengths: {} and {} characters\nLevenshtein distance: {}\nSimilarity: {:.2}%\nLongest common subsequence: {} characters\nLCS: {:?}",
+            a.len(),
+            b.len(),
+            distance,
+            similarity,
+            common.len(),
+            common.iter().collect::<String>()
+        ))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "kitten\nsitting"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Near duplicate titles",
+                input: "How to parse JSON in Rust\nHow to parse JSON with Rust",
+            },
+            Example {
+                name: "Typo",
+                input: "recieve\nreceive",
+            },
+        ]
+    }
+}
+
+// Fewest single character insertions, deletions and substitutions turning
+// `a` into `b`, keeping one row of the table at a time
+fn levenshtein(a: &[char], b: &[char], cancel: &CancelToken) -> Result<usize, TransformError> {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        cancel.check_every(i)?;
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    Ok(row[b.len()])
+}
+
+// One longest sequence of characters appearing in order (not necessarily
+// together) in both `a` and `b`
+fn longest_common_subsequence(
+    a: &[char],
+    b: &[char],
+    cancel: &CancelToken,
+) -> Result<Vec<char>, TransformError> {
+    // lengths[i][j] is the LCS length of a[i..] and b[j..]
+    let width = b.len() + 1;
+    let mut lengths = vec![0u32; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        cancel.check_every(i)?;
+        for j in (0..b.len()).rev() {
+            lengths[i * width + j] = if a[i] == b[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut common = Vec::with_capacity(lengths[0] as usize);
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            common.push(a[i]);
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    Ok(common)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compare(input: &str, other: &str, ignore_case: bool) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("other", other);
+        options.set("ignore_case", if ignore_case { "true" } else { "false" });
+        TextSimilarity.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_text_similarity() {
+        assert_eq!(
+            TextSimilarity
+                .transform(TextSimilarity.default_test_input())
+                .unwrap(),
+            "Lengths: 6 and 7 characters\nLevenshtein distance: 3\nSimilarity: 57.14%\nLongest common subsequence: 4 characters\nLCS: \"ittn\""
+        );
+        assert_eq!(
+            TextSimilarity.transform("same\nsame").unwrap(),
+            "Lengths: 4 and 4 characters\nLevenshtein distance: 0\nSimilarity: 100.00%\nLongest common subsequence: 4 characters\nLCS: \"same\""
+        );
+    }
+
+    #[test]
+    fn test_text_similarity_other_option() {
+        // The other option compares the whole input, line breaks included
+        assert_eq!(
+            compare("a\nb", "a b", false).unwrap(),
+            "Lengths: 3 and 3 characters\nLevenshtein distance: 1\nSimilarity: 66.67%\nLongest common subsequence: 2 characters\nLCS: \"ab\""
+        );
+        assert_eq!(
+            compare("", "xy", false).unwrap(),
+            "Lengths: 0 and 2 characters\nLevenshtein distance: 2\nSimilarity: 0.00%\nLongest common subsequence: 0 characters\nLCS: \"\""
+        );
+        assert!(compare("Straße", "STRASSE", true)
+            .unwrap()
+            .contains("Levenshtein distance: 2\n"));
+        assert!(compare("ÄBC", "äbc", true)
+            .unwrap()
+            .contains("Levenshtein distance: 0\n"));
+        assert!(compare("ÄBC", "äbc", false)
+            .unwrap()
+            .contains("Levenshtein distance: 3\n"));
+    }
+
+    #[test]
+    fn test_text_similarity_errors() {
+        assert!(TextSimilarity.transform("only one line").is_err());
+        assert!(TextSimilarity.transform("a\nb\nc").is_err());
+        let long = "x".repeat(2001);
+        assert!(compare(&long, &long, false).is_err());
+    }
+}
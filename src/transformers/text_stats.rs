@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Text Stats transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -46,6 +46,15 @@ impl Transform for TextStats {
     fn default_test_input(&self) -> &'static str {
         "Buup is great. Buup is fast! Is buup easy? Yes."
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Paragraph",
+                input: "Buup is a text transformation toolkit. It runs in the terminal, in the browser, and inside Rust programs. It has no dependencies!",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,256 @@
+use crate::utils::time::{local_offset_seconds, Timestamp};
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+pub(crate) const TIMEZONE_OPTION: TransformOption = TransformOption {
+    key: "timezone",
+    description: "Zone for the second time shown: local, or an offset such as +05:30",
+    default: "local",
+    kind: TransformOptionKind::Text,
+};
+
+/// Units a timestamp may be in, by the size of the number
+const UNITS: [(&str, i128); 4] = [
+    ("seconds", 1_000_000_000),
+    ("milliseconds", 1_000_000),
+    ("microseconds", 1000),
+    ("nanoseconds", 1),
+];
+
+/// Unix timestamp to ISO 8601 converter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampToIso;
+
+impl Transform for TimestampToIso {
+    fn name(&self) -> &'static str {
+        "Timestamp to ISO 8601"
+    }
+
+    fn id(&self) -> &'static str {
+        "timestamp_to_iso"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts Unix timestamps, one per line, to ISO 8601 in UTC and local time. Seconds, milliseconds and microseconds are told apart by size."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[TIMEZONE_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let zone = Zone::parse(options.value(&TIMEZONE_OPTION))?;
+        let lines = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                token.check_every(i)?;
+                let line = line.trim();
+                if line.is_empty() {
+                    return Ok(String::new());
+                }
+                let (timestamp, unit) = parse_timestamp(line).ok_or_else(|| {
+                    TransformError::InvalidArgument(
+                        format!("Line {}: '{}' is not a Unix timestamp", i + 1, line).into(),
+                    )
+                })?;
+                Ok(format!(
+                    "{} ({} {}, {})",
+                    timestamp.to_rfc3339(),
+                    zone.label(),
+                    timestamp.to_rfc3339_with_offset(zone.offset_at(timestamp)),
+                    unit
+                ))
+            })
+            .collect::<Result<Vec<_>, TransformError>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "1700000000"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Mixed units",
+                input: "1700000000\n1700000000123\n1700000000123456",
+            },
+            Example {
+                name: "Fractional and negative",
+                input: "1700000000.25\n-86400",
+            },
+        ]
+    }
+}
+
+/// Where the second time is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Zone {
+    Local,
+    Fixed(i32),
+}
+
+impl Zone {
+    /// `local`, `UTC`/`Z` or an offset like `+05:30`, `-0800` or `+10`
+    pub(crate) fn parse(text: &str) -> Result<Self, TransformError> {
+        let text = text.trim();
+        if text.eq_ignore_ascii_case("local") || text.is_empty() {
+            return Ok(Self::Local);
+        }
+        if text.eq_ignore_ascii_case("utc") || text.eq_ignore_ascii_case("z") {
+            return Ok(Self::Fixed(0));
+        }
+        let invalid = || {
+            TransformError::InvalidArgument(
+                format!(
+                    "Unknown time zone '{}', expected local, UTC or an offset like +05:30",
+                    text
+                )
+                .into(),
+            )
+        };
+        let (sign, rest) = match text.as_bytes()[0] {
+            b'+' => (1, &text[1..]),
+            b'-' => (-1, &text[1..]),
+            _ => return Err(invalid()),
+        };
+        let digits: String = rest.chars().filter(|&c| c != ':').collect();
+        if !(digits.len() == 2 || digits.len() == 4) || !digits.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        let hours: i32 = digits[..2].parse().map_err(|_| invalid())?;
+        let minutes: i32 = digits[2..].parse().unwrap_or(0);
+        if hours > 23 || minutes > 59 {
+            return Err(invalid());
+        }
+        Ok(Self::Fixed(sign * (hours * 3600 + minutes * 60)))
+    }
+
+    pub(crate) fn offset_at(self, timestamp: Timestamp) -> i32 {
+        match self {
+            Self::Local => local_offset_seconds(timestamp.seconds),
+            Self::Fixed(offset) => offset,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Fixed(_) => "at",
+        }
+    }
+}
+
+/// A Unix timestamp with the unit its size suggests: up to 11 digits are
+/// seconds (until the year 5138), then milliseconds, microseconds and
+/// nanoseconds
+pub(crate) fn parse_timestamp(text: &str) -> Option<(Timestamp, &'static str)> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if whole.is_empty()
+        || whole.len() > 20
+        || !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: i128 = whole.parse().ok()?;
+    let index = match whole {
+        0..=99_999_999_999 => 0,
+        100_000_000_000..=99_999_999_999_999 => 1,
+        100_000_000_000_000..=99_999_999_999_999_999 => 2,
+        _ => 3,
+    };
+    let (unit, nanos_per_unit) = UNITS[index];
+    // Fraction digits beyond nanosecond precision are dropped
+    let mut nanos = whole * nanos_per_unit;
+    let mut scale = nanos_per_unit;
+    for digit in fraction.bytes() {
+        scale /= 10;
+        nanos += i128::from(digit - b'0') * scale;
+    }
+    if negative {
+        nanos = -nanos;
+    }
+    let seconds = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+    // Beyond what the calendar arithmetic is meant for
+    if seconds.abs() > 1 << 50 {
+        return None;
+    }
+    Some((
+        Timestamp {
+            seconds,
+            nanos: nanos.rem_euclid(1_000_000_000) as u32,
+        },
+        unit,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, timezone: &str) -> Result<String, TransformError> {
+        let mut options = TransformOptions::new();
+        options.set("timezone", timezone);
+        TimestampToIso.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_timestamp_to_iso_units() {
+        assert_eq!(
+            convert("1700000000\n\n1700000000123\n1700000000123456", "+11:00").unwrap(),
+            "2023-11-14T22:13:20Z (at 2023-11-15T09:13:20+11:00, seconds)\n\
+             \n\
+             2023-11-14T22:13:20.123Z (at 2023-11-15T09:13:20.123+11:00, milliseconds)\n\
+             2023-11-14T22:13:20.123456Z (at 2023-11-15T09:13:20.123456+11:00, microseconds)"
+        );
+        assert_eq!(
+            convert("1700000000123456789", "utc").unwrap(),
+            "2023-11-14T22:13:20.123456789Z (at 2023-11-14T22:13:20.123456789Z, nanoseconds)"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_to_iso_fractions_and_negatives() {
+        assert_eq!(
+            convert("1700000000.25\n-1.5", "-08:00").unwrap(),
+            "2023-11-14T22:13:20.250Z (at 2023-11-14T14:13:20.250-08:00, seconds)\n\
+             1969-12-31T23:59:58.500Z (at 1969-12-31T15:59:58.500-08:00, seconds)"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_to_iso_local() {
+        let output = TimestampToIso.transform("0").unwrap();
+        assert!(output.starts_with("1970-01-01T00:00:00Z (local "));
+    }
+
+    #[test]
+    fn test_timestamp_to_iso_invalid() {
+        assert!(TimestampToIso.transform("yesterday").is_err());
+        assert!(TimestampToIso.transform("1700000000\n17e9").is_err());
+        assert!(convert("0", "Mars/Olympus").is_err());
+        assert!(convert("0", "+25:00").is_err());
+    }
+}
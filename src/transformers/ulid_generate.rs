@@ -0,0 +1,181 @@
+use crate::utils::random::fill_random;
+use crate::utils::time::now_millis;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// Most ULIDs generated in one go
+const MAX_COUNT: usize = 1000;
+
+/// Crockford's Base32, which leaves out I, L, O and U
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Length of a ULID in characters
+pub(crate) const ULID_LEN: usize = 26;
+
+const COUNT_OPTION: TransformOption = TransformOption {
+    key: "count",
+    description: "Number of ULIDs to generate (at most 1000)",
+    default: "1",
+    kind: TransformOptionKind::Integer,
+};
+
+/// ULID generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UlidGenerate;
+
+impl Transform for UlidGenerate {
+    fn name(&self) -> &'static str {
+        "ULID Generate"
+    }
+
+    fn id(&self) -> &'static str {
+        "ulid_generate"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Generator
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates ULIDs (sortable, timestamped unique IDs), one per line and in increasing order. Ignores its input."
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[COUNT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        _input: &str,
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let count = options.usize_value(&COUNT_OPTION)?;
+        if count == 0 || count > MAX_COUNT {
+            return Err(TransformError::InvalidArgument(
+                format!("Count must be between 1 and {}", MAX_COUNT).into(),
+            ));
+        }
+
+        let timestamp = now_millis().clamp(0, (1 << 48) - 1) as u128;
+        let mut randomness = [0u8; 16];
+        fill_random(&mut randomness[6..])?;
+        let mut ulid = timestamp << 80 | u128::from_be_bytes(randomness);
+        let mut ulids = Vec::with_capacity(count);
+        for _ in 0..count {
+            ulids.push(encode(ulid));
+            // Monotonic within the batch: same millisecond, next random value
+            let next = ulid + 1;
+            if next >> 80 != timestamp {
+                return Err(TransformError::InvalidArgument(
+                    "Ran out of ULIDs for this millisecond".into(),
+                ));
+            }
+            ulid = next;
+        }
+        Ok(ulids.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        ""
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "A sorted batch",
+            input: "",
+        }]
+    }
+}
+
+/// The 26 character Crockford Base32 form of a ULID
+pub(crate) fn encode(ulid: u128) -> String {
+    (0..ULID_LEN)
+        .rev()
+        .map(|i| char::from(CROCKFORD[(ulid >> (i * 5)) as usize & 31]))
+        .collect()
+}
+
+/// Parses a ULID, reading it case-insensitively and I, L and O as 1, 1 and 0
+/// like Crockford's Base32 does
+pub(crate) fn decode(text: &str) -> Result<u128, TransformError> {
+    if text.len() != ULID_LEN {
+        return Err(TransformError::InvalidArgument(
+            format!(
+                "A ULID has {} characters, got {}",
+                ULID_LEN,
+                text.chars().count()
+            )
+            .into(),
+        ));
+    }
+    let mut ulid = 0u128;
+    for (i, c) in text.chars().enumerate() {
+        let c = match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        };
+        let Some(value) = CROCKFORD.iter().position(|&d| char::from(d) == c) else {
+            return Err(TransformError::InvalidArgument(
+                format!("'{}' is not a Crockford Base32 character", c).into(),
+            ));
+        };
+        // 26 characters hold 130 bits, so the first can only be 0 to 7
+        if i == 0 && value > 7 {
+            return Err(TransformError::InvalidArgument(
+                "ULID is larger than 128 bits".into(),
+            ));
+        }
+        ulid = ulid << 5 | value as u128;
+    }
+    Ok(ulid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ulid_generate() {
+        let ulid = UlidGenerate.transform("").unwrap();
+        assert_eq!(ulid.len(), ULID_LEN);
+        assert!(ulid.bytes().all(|b| CROCKFORD.contains(&b)));
+        // The timestamp is now, to within a few seconds
+        let timestamp = (decode(&ulid).unwrap() >> 80) as i64;
+        assert!((now_millis() - timestamp).abs() < 10_000);
+        assert_ne!(ulid, UlidGenerate.transform("").unwrap());
+    }
+
+    #[test]
+    fn test_ulid_generate_batch_is_sorted() {
+        let mut options = TransformOptions::new();
+        options.set("count", "100");
+        let output = UlidGenerate.transform_with_options("", &options).unwrap();
+        let ulids: Vec<&str> = output.lines().collect();
+        assert_eq!(ulids.len(), 100);
+        assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+
+        options.set("count", "0");
+        assert!(UlidGenerate.transform_with_options("", &options).is_err());
+    }
+
+    #[test]
+    fn test_ulid_encoding() {
+        // From the ULID spec's example
+        let ulid = decode("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        assert_eq!(ulid >> 80, 1_469_922_850_259);
+        assert_eq!(encode(ulid), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_eq!(decode("01arz3ndektsv4rrffq69g5fav").unwrap(), ulid);
+        assert_eq!(encode(u128::MAX), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+        assert!(decode("8ZZZZZZZZZZZZZZZZZZZZZZZZZ").is_err());
+        assert!(decode("01ARZ3NDEKTSV4RRFFQ69G5FA").is_err());
+        assert!(decode("01ARZ3NDEKTSV4RRFFQ69G5FAU").is_err());
+    }
+}
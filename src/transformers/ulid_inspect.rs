@@ -0,0 +1,73 @@
+use super::ulid_generate::decode;
+use crate::utils::time::format_rfc3339;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// ULID inspector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UlidInspect;
+
+impl Transform for UlidInspect {
+    fn name(&self) -> &'static str {
+        "ULID Inspect"
+    }
+
+    fn id(&self) -> &'static str {
+        "ulid_inspect"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Generator
+    }
+
+    fn description(&self) -> &'static str {
+        "Shows when a ULID was generated and its random part"
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let ulid = decode(input.trim())?;
+        let timestamp = (ulid >> 80) as i64;
+        Ok(format!(
+            "Timestamp: {}\nUnix milliseconds: {}\nRandomness: {:020x}",
+            format_rfc3339(timestamp),
+            timestamp,
+            ulid & ((1 << 80) - 1)
+        ))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "01ARZ3NDEKTSV4RRFFQ69G5FAV"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Lowercase",
+            input: "01hqkzr1mbqz0t7qv0v5x8a6ce",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::UlidGenerate;
+
+    #[test]
+    fn test_ulid_inspect() {
+        assert_eq!(
+            UlidInspect
+                .transform(UlidInspect.default_test_input())
+                .unwrap(),
+            "Timestamp: 2016-07-30T23:54:10.259Z\n\
+             Unix milliseconds: 1469922850259\n\
+             Randomness: d6764c61efb99302bd5b"
+        );
+    }
+
+    #[test]
+    fn test_ulid_inspect_generated() {
+        let ulid = UlidGenerate.transform("").unwrap();
+        let report = UlidInspect.transform(&format!(" {}\n", ulid)).unwrap();
+        assert!(report.starts_with("Timestamp: 20"));
+        assert!(UlidInspect.transform("not a ulid").is_err());
+    }
+}
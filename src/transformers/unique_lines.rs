@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// Removes duplicate lines from text, preserving the order of the first occurrence.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -40,6 +40,15 @@ impl Transform for UniqueLines {
     fn default_test_input(&self) -> &'static str {
         "apple\nbanana\napple\norange\nbanana"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Log lines",
+                input: "GET /index.html 200\nGET /style.css 200\nGET /index.html 200\nGET /missing 404\nGET /style.css 200",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// URL decode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,16 +25,44 @@ impl Transform for UrlDecode {
         url_decode(input).map_err(|_e| TransformError::UrlDecodeError)
     }
 
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        url_decode_bytes(input).map_err(|_e| TransformError::UrlDecodeError)
+    }
+
     fn default_test_input(&self) -> &'static str {
         "Hello%2C+World%21"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Query string",
+                input: "name%3DAda%20Lovelace%26city%3DS%C3%A3o%20Paulo%26tags%3Dmath%2Ccode",
+            },
+            Example {
+                name: "Plus signs",
+                input: "q=hello+world&lang=en",
+            },
+            Example {
+                name: "Nested URL",
+                input: "https%3A%2F%2Fexample.com%2Fsearch%3Fq%3Drust%20%26%20wasm",
+            },
+        ]
+    }
 }
 
 /// URL decodes a string without external dependencies
-fn url_decode(input: &str) -> Result<String, &'static str> {
-    // We'll collect decoded bytes and convert to UTF-8 at the end
+pub(crate) fn url_decode(input: &str) -> Result<String, &'static str> {
+    let decoded_bytes = url_decode_bytes(input.as_bytes())?;
+
+    // Convert the collected bytes to a UTF-8 string
+    String::from_utf8(decoded_bytes).map_err(|_| "Invalid UTF-8 sequence in decoded URL")
+}
+
+// Decodes percent escapes and '+' to raw bytes
+fn url_decode_bytes(input: &[u8]) -> Result<Vec<u8>, &'static str> {
     let mut decoded_bytes = Vec::with_capacity(input.len());
-    let mut bytes = input.bytes();
+    let mut bytes = input.iter().copied();
 
     while let Some(byte) = bytes.next() {
         match byte {
@@ -68,9 +96,7 @@ fn url_decode(input: &str) -> Result<String, &'static str> {
             _ => decoded_bytes.push(byte),
         }
     }
-
-    // Convert the collected bytes to a UTF-8 string
-    String::from_utf8(decoded_bytes).map_err(|_| "Invalid UTF-8 sequence in decoded URL")
+    Ok(decoded_bytes)
 }
 
 #[cfg(test)]
@@ -89,4 +115,14 @@ mod tests {
         assert_eq!(transformer.transform("a+b").unwrap(), "a b");
         assert_eq!(transformer.transform("100%25").unwrap(), "100%");
     }
+
+    #[test]
+    fn test_url_decode_binary() {
+        let transformer = UrlDecode;
+        assert!(transformer.transform("%FF%00").is_err());
+        assert_eq!(
+            transformer.transform_bytes(b"%FF%00+").unwrap(),
+            vec![0xff, 0, b' ']
+        );
+    }
 }
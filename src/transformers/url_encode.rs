@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// URL encode transformer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,28 +22,50 @@ impl Transform for UrlEncode {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
-        let mut encoded = String::new();
-        for byte in input.bytes() {
-            match byte {
-                // Alphanumeric characters are not encoded
-                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => encoded.push(byte as char),
-                // Specific characters are not encoded (- _ . ~)
-                b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
-                // Space is encoded as '+' (common practice, though %20 is also valid)
-                b' ' => encoded.push('+'),
-                // All other characters are percent-encoded
-                _ => {
-                    encoded.push('%');
-                    encoded.push_str(&format!("{:02X}", byte));
-                }
-            }
-        }
-        Ok(encoded)
+        Ok(url_encode(input))
     }
 
     fn default_test_input(&self) -> &'static str {
         "Hello, World! This is a test + example?"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Query string",
+                input: "name=Ada Lovelace&city=São Paulo&tags=math,code",
+            },
+            Example {
+                name: "Path with symbols",
+                input: "/files/report 2024 (final).pdf",
+            },
+            Example {
+                name: "Nested URL",
+                input: "https://example.com/search?q=rust & wasm",
+            },
+        ]
+    }
+}
+
+/// Percent-encodes everything but unreserved characters, with spaces as '+'
+pub(crate) fn url_encode(input: &str) -> String {
+    let mut encoded = String::new();
+    for byte in input.bytes() {
+        match byte {
+            // Alphanumeric characters are not encoded
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => encoded.push(byte as char),
+            // Specific characters are not encoded (- _ . ~)
+            b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            // Space is encoded as '+' (common practice, though %20 is also valid)
+            b' ' => encoded.push('+'),
+            // All other characters are percent-encoded
+            _ => {
+                encoded.push('%');
+                encoded.push_str(&format!("{:02X}", byte));
+            }
+        }
+    }
+    encoded
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::utils::{Digest, Sha1};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 use std::fmt::Write;
 
 // Predefined namespace UUIDs (RFC 4122)
@@ -26,102 +27,6 @@ fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, TransformError> {
     Ok(bytes)
 }
 
-// Implementing SHA-1 hash for UUID5 (since UUID5 uses SHA-1)
-// This SHA-1 implementation is based on RFC 3174
-fn sha1_hash(data: &[u8]) -> [u8; 20] {
-    // Initialize variables
-    let mut h0: u32 = 0x67452301;
-    let mut h1: u32 = 0xEFCDAB89;
-    let mut h2: u32 = 0x98BADCFE;
-    let mut h3: u32 = 0x10325476;
-    let mut h4: u32 = 0xC3D2E1F0;
-
-    // Pre-processing: padding the message
-    let mut padded = data.to_vec();
-    let original_len_bits = (data.len() as u64) * 8;
-
-    // Append bit '1'
-    padded.push(0x80);
-
-    // Append '0' bits until message length is congruent to 448 (mod 512)
-    while padded.len() % 64 != 56 {
-        padded.push(0);
-    }
-
-    // Append original length as 64-bit big-endian
-    padded.extend_from_slice(&original_len_bits.to_be_bytes());
-
-    // Process message in 512-bit (64-byte) chunks
-    for chunk_start in (0..padded.len()).step_by(64) {
-        let chunk = &padded[chunk_start..chunk_start + 64];
-
-        // Prepare message schedule (80 words)
-        let mut w = [0u32; 80];
-
-        // Copy chunk into first 16 words of schedule
-        for (i, chunk_bytes) in chunk.chunks_exact(4).enumerate().take(16) {
-            w[i] = u32::from_be_bytes([
-                chunk_bytes[0],
-                chunk_bytes[1],
-                chunk_bytes[2],
-                chunk_bytes[3],
-            ]);
-        }
-
-        // Extend the sixteen 32-bit words into eighty 32-bit words
-        for i in 16..80 {
-            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
-        }
-
-        // Initialize working variables
-        let mut a = h0;
-        let mut b = h1;
-        let mut c = h2;
-        let mut d = h3;
-        let mut e = h4;
-
-        // Main loop
-        for (i, &word) in w.iter().enumerate() {
-            let (f, k) = match i {
-                0..=19 => ((b & c) | ((!b) & d), 0x5A827999),
-                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
-                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
-                _ => (b ^ c ^ d, 0xCA62C1D6),
-            };
-
-            let temp = a
-                .rotate_left(5)
-                .wrapping_add(f)
-                .wrapping_add(e)
-                .wrapping_add(k)
-                .wrapping_add(word);
-
-            e = d;
-            d = c;
-            c = b.rotate_left(30);
-            b = a;
-            a = temp;
-        }
-
-        // Add the compressed chunk to the current hash value
-        h0 = h0.wrapping_add(a);
-        h1 = h1.wrapping_add(b);
-        h2 = h2.wrapping_add(c);
-        h3 = h3.wrapping_add(d);
-        h4 = h4.wrapping_add(e);
-    }
-
-    // Produce the final hash value (big-endian)
-    let mut result = [0u8; 20];
-    result[0..4].copy_from_slice(&h0.to_be_bytes());
-    result[4..8].copy_from_slice(&h1.to_be_bytes());
-    result[8..12].copy_from_slice(&h2.to_be_bytes());
-    result[12..16].copy_from_slice(&h3.to_be_bytes());
-    result[16..20].copy_from_slice(&h4.to_be_bytes());
-
-    result
-}
-
 /// UUID v5 generator (namespace-based with SHA-1)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Uuid5Generate;
@@ -183,7 +88,7 @@ impl Uuid5Generate {
         input.extend_from_slice(name.as_bytes());
 
         // Generate SHA-1 hash
-        let hash = sha1_hash(&input);
+        let hash = Sha1::digest(&input);
 
         // Take first 16 bytes and set version and variant
         let mut uuid_bytes = [0u8; 16];
@@ -248,6 +153,19 @@ impl Transform for Uuid5Generate {
     fn default_test_input(&self) -> &'static str {
         "dns|example.com"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "URL namespace",
+                input: "url|https://buup.io",
+            },
+            Example {
+                name: "DNS namespace",
+                input: "dns|buup.io",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
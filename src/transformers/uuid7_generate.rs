@@ -0,0 +1,66 @@
+use super::uuid_generate::{format_uuid, set_version};
+use crate::utils::random::fill_random;
+use crate::utils::time::now_millis;
+use crate::{Transform, TransformError, TransformerCategory};
+
+/// UUID v7 generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UuidV7Generate;
+
+impl Transform for UuidV7Generate {
+    fn name(&self) -> &'static str {
+        "UUID Generate (v7)"
+    }
+
+    fn id(&self) -> &'static str {
+        "uuid7_generate"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates a version 7 UUID, which starts with the current time so newer UUIDs sort after older ones. Input is ignored."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Generator
+    }
+
+    fn transform(&self, _input: &str) -> Result<String, TransformError> {
+        Ok(format_uuid(uuid_v7(now_millis())?))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        ""
+    }
+}
+
+// 48 bits of Unix milliseconds, then random bits around the version and
+// variant (RFC 9562 section 5.7)
+fn uuid_v7(millis: i64) -> Result<[u8; 16], TransformError> {
+    let mut bytes = [0u8; 16];
+    fill_random(&mut bytes[6..])?;
+    let timestamp = millis.clamp(0, (1 << 48) - 1) as u64;
+    bytes[..6].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+    Ok(set_version(bytes, 7))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_v7_layout() {
+        let uuid = format_uuid(uuid_v7(1_709_208_000_123).unwrap());
+        assert!(uuid.starts_with("018df4bc-567b-7"), "{}", uuid);
+        assert!(matches!(uuid.as_bytes()[19], b'8' | b'9' | b'a' | b'b'));
+    }
+
+    #[test]
+    fn test_uuid_v7_generate() {
+        let first = UuidV7Generate.transform("").unwrap();
+        assert_eq!(first.len(), 36);
+        assert_eq!(first.as_bytes()[14], b'7');
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second = UuidV7Generate.transform("").unwrap();
+        assert!(first < second);
+    }
+}
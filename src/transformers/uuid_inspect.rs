@@ -0,0 +1,208 @@
+use crate::utils::time::format_rfc3339;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+/// 100 nanosecond intervals between the Gregorian calendar's adoption
+/// (1582-10-15), where v1 and v6 timestamps start, and the Unix epoch
+const GREGORIAN_OFFSET: i64 = 0x01B2_1DD2_1381_4000;
+
+/// UUID inspector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UuidInspect;
+
+impl Transform for UuidInspect {
+    fn name(&self) -> &'static str {
+        "UUID Inspect"
+    }
+
+    fn id(&self) -> &'static str {
+        "uuid_inspect"
+    }
+
+    fn description(&self) -> &'static str {
+        "Shows the version and variant of a UUID, and the timestamp, clock sequence and node embedded in time-based ones"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Generator
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let bytes = parse_uuid(input)?;
+        if bytes == [0; 16] {
+            return Ok("Version: none (the nil UUID)".to_string());
+        }
+        if bytes == [0xFF; 16] {
+            return Ok("Version: none (the max UUID)".to_string());
+        }
+
+        let variant = match bytes[8] {
+            0x00..=0x7F => "NCS (reserved for backward compatibility)",
+            0x80..=0xBF => "RFC 9562 (formerly RFC 4122)",
+            0xC0..=0xDF => "Microsoft (reserved)",
+            _ => "Reserved for future definition",
+        };
+        // Only the RFC variant has a version field
+        if !(0x80..=0xBF).contains(&bytes[8]) {
+            return Ok(format!("Variant: {}", variant));
+        }
+        let version = bytes[6] >> 4;
+        let version_name = match version {
+            1 => "Gregorian time-based",
+            2 => "DCE security",
+            3 => "name-based, MD5",
+            4 => "random",
+            5 => "name-based, SHA-1",
+            6 => "reordered Gregorian time-based",
+            7 => "Unix time-based",
+            8 => "custom",
+            _ => "unknown",
+        };
+        let mut lines = vec![
+            format!("Version: {} ({})", version, version_name),
+            format!("Variant: {}", variant),
+        ];
+
+        let field = |range: std::ops::Range<usize>| {
+            bytes[range]
+                .iter()
+                .fold(0i64, |value, &byte| value << 8 | i64::from(byte))
+        };
+        let gregorian = match version {
+            1 => Some((field(6..8) & 0x0FFF) << 48 | field(4..6) << 32 | field(0..4)),
+            6 => Some(field(0..4) << 28 | field(4..6) << 12 | (field(6..8) & 0x0FFF)),
+            _ => None,
+        };
+        if let Some(intervals) = gregorian {
+            let millis = (intervals - GREGORIAN_OFFSET).div_euclid(10_000);
+            lines.push(format!("Timestamp: {}", format_rfc3339(millis)));
+            lines.push(format!("Clock sequence: {}", field(8..10) & 0x3FFF));
+            let node: Vec<String> = bytes[10..].iter().map(|b| format!("{:02x}", b)).collect();
+            // The multicast bit marks a random node rather than a MAC address
+            let kind = if bytes[10] & 1 == 1 {
+                "random"
+            } else {
+                "MAC address"
+            };
+            lines.push(format!("Node: {} ({})", node.join(":"), kind));
+        } else if version == 7 {
+            let millis = field(0..6);
+            lines.push(format!("Timestamp: {}", format_rfc3339(millis)));
+            lines.push(format!("Unix milliseconds: {}", millis));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "018df4bc-567b-7cc3-98c4-dc0c0c07398f"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Version 1 with a MAC address",
+                input: "c232ab00-9414-11ec-b3c8-9f6bdeced846",
+            },
+            Example {
+                name: "Version 4 in braces",
+                input: "{919108F7-52D1-4320-9BAC-F847DB4148A8}",
+            },
+            Example {
+                name: "URN",
+                input: "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            },
+        ]
+    }
+}
+
+// Accepts hyphens or not, braces and the urn:uuid: prefix
+fn parse_uuid(input: &str) -> Result<[u8; 16], TransformError> {
+    let text = input.trim();
+    let text = text
+        .get(..9)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("urn:uuid:"))
+        .map_or(text, |_| &text[9..]);
+    let text = text
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .unwrap_or(text);
+    let digits: Vec<u8> = text.bytes().filter(|&b| b != b'-').collect();
+    if digits.len() != 32 || !digits.iter().all(u8::is_ascii_hexdigit) {
+        return Err(TransformError::InvalidArgument(
+            format!("'{}' is not a UUID", text).into(),
+        ));
+    }
+    let mut bytes = [0u8; 16];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).unwrap_or_default(), 16)
+            .unwrap_or_default();
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::{UuidGenerate, UuidV7Generate};
+
+    #[test]
+    fn test_uuid_inspect_v7() {
+        assert_eq!(
+            UuidInspect
+                .transform(UuidInspect.default_test_input())
+                .unwrap(),
+            "Version: 7 (Unix time-based)\n\
+             Variant: RFC 9562 (formerly RFC 4122)\n\
+             Timestamp: 2024-02-29T12:00:00.123Z\n\
+             Unix milliseconds: 1709208000123"
+        );
+        let generated = UuidV7Generate.transform("").unwrap();
+        assert!(UuidInspect
+            .transform(&generated)
+            .unwrap()
+            .contains("Timestamp: 20"));
+    }
+
+    #[test]
+    fn test_uuid_inspect_v1_and_v6() {
+        // The examples from RFC 9562 appendix A
+        assert_eq!(
+            UuidInspect
+                .transform("C232AB00-9414-11EC-B3C8-9F6BDECED846")
+                .unwrap(),
+            "Version: 1 (Gregorian time-based)\n\
+             Variant: RFC 9562 (formerly RFC 4122)\n\
+             Timestamp: 2022-02-22T19:22:22Z\n\
+             Clock sequence: 13256\n\
+             Node: 9f:6b:de:ce:d8:46 (random)"
+        );
+        assert!(UuidInspect
+            .transform("1EC9414C-232A-6B00-B3C8-9F6BDECED846")
+            .unwrap()
+            .contains("Timestamp: 2022-02-22T19:22:22Z"));
+    }
+
+    #[test]
+    fn test_uuid_inspect_other_versions() {
+        let v4 = UuidGenerate.transform("").unwrap();
+        assert_eq!(
+            UuidInspect.transform(&v4).unwrap(),
+            "Version: 4 (random)\nVariant: RFC 9562 (formerly RFC 4122)"
+        );
+        assert!(UuidInspect
+            .transform("urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8")
+            .unwrap()
+            .contains("Node: 00:c0:4f:d4:30:c8 (MAC address)"));
+        assert_eq!(
+            UuidInspect
+                .transform("00000000000000000000000000000000")
+                .unwrap(),
+            "Version: none (the nil UUID)"
+        );
+        assert!(UuidInspect
+            .transform("{919108f7-52d1-4320-1bac-f847db4148a8}")
+            .unwrap()
+            .starts_with("Variant: NCS"));
+        assert!(UuidInspect.transform("not-a-uuid").is_err());
+        assert!(UuidInspect.transform("919108f7-52d1-4320-9bac").is_err());
+    }
+}
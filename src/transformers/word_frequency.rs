@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+/// Common English words left out by the `stop_words` option
+const STOP_WORDS: [&str; 126] = [
+    "a",
+    "about",
+    "above",
+    "after",
+    "again",
+    "against",
+    "all",
+    "am",
+    "an",
+    "and",
+    "any",
+    "are",
+    "as",
+    "at",
+    "be",
+    "because",
+    "been",
+    "before",
+    "being",
+    "below",
+    "between",
+    "both",
+    "but",
+    "by",
+    "can",
+    "could",
+    "did",
+    "do",
+    "does",
+    "doing",
+    "down",
+    "during",
+    "each",
+    "few",
+    "for",
+    "from",
+    "further",
+    "had",
+    "has",
+    "have",
+    "having",
+    "he",
+    "her",
+    "here",
+    "hers",
+    "herself",
+    "him",
+    "himself",
+    "his",
+    "how",
+    "i",
+    "if",
+    "in",
+    "into",
+    "is",
+    "it",
+    "its",
+    "itself",
+    "just",
+    "me",
+    "more",
+    "most",
+    "my",
+    "myself",
+    "no",
+    "nor",
+    "not",
+    "now",
+    "of",
+    "off",
+    "on",
+    "once",
+    "only",
+    "or",
+    "other",
+    "our",
+    "ours",
+    "ourselves",
+    "out",
+    "over",
+    "own",
+    "same",
+    "she",
+    "should",
+    "so",
+    "some",
+    "such",
+    "than",
+    "that",
+    "the",
+    "their",
+    "theirs",
+    "them",
+    "themselves",
+    "then",
+    "there",
+    "these",
+    "they",
+    "this",
+    "those",
+    "through",
+    "to",
+    "too",
+    "under",
+    "until",
+    "up",
+    "very",
+    "was",
+    "we",
+    "were",
+    "what",
+    "when",
+    "where",
+    "which",
+    "while",
+    "who",
+    "whom",
+    "why",
+    "will",
+    "with",
+    "would",
+    "you",
+    "your",
+    "yours",
+    "yourself",
+    "yourselves",
+];
+
+const IGNORE_CASE_OPTION: TransformOption = TransformOption {
+    key: "ignore_case",
+    description: "Count words regardless of case, so The and the are one word",
+    default: "true",
+    kind: TransformOptionKind::Boolean,
+};
+
+const STOP_WORDS_OPTION: TransformOption = TransformOption {
+    key: "stop_words",
+    description: "Leave out common English words such as the, and and of",
+    default: "false",
+    kind: TransformOptionKind::Boolean,
+};
+
+const TOP_OPTION: TransformOption = TransformOption {
+    key: "top",
+    description: "Number of words to list, 0 for all of them",
+    default: "0",
+    kind: TransformOptionKind::Integer,
+};
+
+/// Word frequency analyzer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordFrequency;
+
+impl Transform for WordFrequency {
+    fn name(&self) -> &'static str {
+        "Word Frequency"
+    }
+
+    fn id(&self) -> &'static str {
+        "word_frequency"
+    }
+
+    fn description(&self) -> &'static str {
+        "Counts how often each word occurs and lists them from most to least frequent, with each word's share of the total"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Other
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[IGNORE_CASE_OPTION, STOP_WORDS_OPTION, TOP_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let ignore_case = options.bool_value(&IGNORE_CASE_OPTION)?;
+        let skip_stop_words = options.bool_value(&STOP_WORDS_OPTION)?;
+        let top = options.usize_value(&TOP_OPTION)?;
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        let mut total = 0;
+        let mut stop_words = 0;
+        for (i, word) in words(input).enumerate() {
+            token.check_every(i)?;
+            if skip_stop_words && STOP_WORDS.contains(&word.to_lowercase().as_str()) {
+                stop_words += 1;
+                continue;
+            }
+            let word = if ignore_case {
+                word.to_lowercase()
+            } else {
+                word.to_string()
+            };
+            *counts.entry(word).or_default() += 1;
+            total += 1;
+        }
+        if total == 0 {
+            return Ok("No words found".to_string());
+        }
+
+        let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let distinct = ranked.len();
+        if top > 0 {
+            ranked.truncate(top);
+        }
+
+        // Equal counts share a rank, as in 1, 2, 2, 4
+        let mut rows: Vec<[String; 4]> = Vec::with_capacity(ranked.len());
+        for (index, (word, count)) in ranked.iter().enumerate() {
+            let rank = match rows.last() {
+                Some([rank, _, previous, _]) if *previous == count.to_string() => rank.clone(),
+                _ => (index + 1).to_string(),
+            };
+            let share = format!("{:.2}%", 100.0 * *count as f64 / total as f64);
+            rows.push([rank, word.clone(), count.to_string(), share]);
+        }
+        let header = ["Rank", "Word", "Count", "Share"].map(String::from);
+        let mut widths = header.clone().map(|cell| cell.chars().count());
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut out = String::new();
+        for row in std::iter::once(&header).chain(&rows) {
+            out.push_str(&format!(
+                "{:>w0$}  {:<w1$}  {:>w2$}  {:>w3$}\n",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+            ));
+        }
+        out.push_str(&format!(
+            "\nWords: {} counted, {} distinct",
+            total, distinct
+        ));
+        if rows.len() < distinct {
+            out.push_str(&format!(", top {} shown", rows.len()));
+        }
+        if skip_stop_words {
+            out.push_str(&format!("\nStop words left out: {}", stop_words));
+        }
+        Ok(out)
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "The quick brown fox jumps over the lazy dog. The dog sleeps; the fox doesn't."
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Paragraph",
+            input: "Buup is a text transformation toolkit. It runs in the terminal, in the browser, and inside Rust programs. It has no dependencies, and it is fast!",
+        }]
+    }
+}
+
+// Runs of letters and digits, keeping apostrophes and hyphens inside a word
+// as in "don't" and "well-known"
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    let joiner = |c: char| matches!(c, '\'' | '\u{2019}' | '-');
+    text.split(move |c: char| !c.is_alphanumeric() && !joiner(c))
+        .map(move |word| word.trim_matches(joiner))
+        .filter(|word| !word.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frequency(input: &str, settings: &[(&str, &str)]) -> String {
+        let options: TransformOptions = settings.iter().copied().collect();
+        WordFrequency
+            .transform_with_options(input, &options)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_word_frequency() {
+        assert_eq!(
+            WordFrequency
+                .transform(WordFrequency.default_test_input())
+                .unwrap(),
+            "Rank  Word     Count   Share
+   1  the          4  26.67%
+   2  dog          2  13.33%
+   2  fox          2  13.33%
+   4  brown        1   6.67%
+   4  doesn't      1   6.67%
+   4  jumps        1   6.67%
+   4  lazy         1   6.67%
+   4  over         1   6.67%
+   4  quick        1   6.67%
+   4  sleeps       1   6.67%
+
+Words: 15 counted, 10 distinct"
+        );
+        assert_eq!(WordFrequency.transform(" ... ").unwrap(), "No words found");
+    }
+
+    #[test]
+    fn test_word_frequency_options() {
+        let input = WordFrequency.default_test_input();
+        assert_eq!(
+            frequency(input, &[("stop_words", "true"), ("top", "2")]),
+            "Rank  Word  Count   Share
+   1  dog       2  20.00%
+   1  fox       2  20.00%
+
+Words: 10 counted, 8 distinct, top 2 shown
+Stop words left out: 5"
+        );
+        assert!(frequency(input, &[("ignore_case", "false")])
+            .starts_with("Rank  Word     Count   Share\n   1  The          2  13.33%\n   1  dog          2  13.33%\n   1  fox          2  13.33%\n   1  the          2  13.33%\n"));
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(
+            words("'Quoted' well-known -- don’t stop... über 42").collect::<Vec<_>>(),
+            ["Quoted", "well-known", "don’t", "stop", "über", "42"]
+        );
+    }
+}
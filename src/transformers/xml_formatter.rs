@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{CancelToken, Example, Transform, TransformError, TransformerCategory};
 
 /// A transformer that formats XML code with proper indentation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +22,14 @@ impl Transform for XmlFormatter {
     }
 
     fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_cancel(input, &CancelToken::new())
+    }
+
+    fn transform_with_cancel(
+        &self,
+        input: &str,
+        cancel: &CancelToken,
+    ) -> Result<String, TransformError> {
         if input.trim().is_empty() {
             return Ok(String::new());
         }
@@ -60,8 +68,16 @@ impl Transform for XmlFormatter {
         let mut in_processing = false;
         let mut in_doctype = false;
         let mut has_content = false;
+        let mut iteration = 0;
+        // Open elements with the 1-based position of their '<', to report
+        // mismatched and unclosed tags
+        let mut open_tags: Vec<(String, usize)> = Vec::new();
+        let mut tag_start = 0;
 
         while let Some(c) = chars.next() {
+            cancel.check_every(iteration)?;
+            iteration += 1;
+
             // Handle comments
             if in_comment {
                 buffer.push(c);
@@ -163,8 +179,9 @@ impl Transform for XmlFormatter {
             }
 
             // Check for DOCTYPE
-            if in_tag && buffer.ends_with("!DOCTYPE") {
+            if in_tag && !in_doctype && buffer.ends_with("!DOCTYPE") {
                 in_doctype = true;
+                buffer.push(c);
                 continue;
             }
 
@@ -183,6 +200,7 @@ impl Transform for XmlFormatter {
             // Tag start
             if c == '<' && !in_tag && !in_comment && !in_cdata {
                 in_tag = true;
+                tag_start = iteration;
 
                 // Check if we have buffered text content
                 if !buffer.trim().is_empty() {
@@ -219,6 +237,35 @@ impl Transform for XmlFormatter {
                 // Check for self-closing tag
                 let is_self_closing = buffer.ends_with("/>") || buffer.starts_with("<?");
 
+                // Whitespace before the tag may still be buffered
+                let tag = buffer.trim_start();
+                if is_closing_tag {
+                    let name = tag_name(&tag[2..]);
+                    match open_tags.pop() {
+                        Some((open, _)) if open == name => {}
+                        Some((open, _)) => {
+                            return Err(TransformError::InvalidArgument(
+                                format!(
+                                    "Mismatched closing tag </{}> at position {}, expected </{}>",
+                                    name, tag_start, open
+                                )
+                                .into(),
+                            ))
+                        }
+                        None => {
+                            return Err(TransformError::InvalidArgument(
+                                format!(
+                                    "Unexpected closing tag </{}> at position {}",
+                                    name, tag_start
+                                )
+                                .into(),
+                            ))
+                        }
+                    }
+                } else if !is_self_closing && !tag.starts_with("<!") {
+                    open_tags.push((tag_name(&tag[1..]).to_string(), tag_start));
+                }
+
                 // Add to result
                 result.push_str(&buffer);
                 buffer.clear();
@@ -240,6 +287,12 @@ impl Transform for XmlFormatter {
             buffer.push(c);
         }
 
+        if let Some((name, position)) = open_tags.pop() {
+            return Err(TransformError::InvalidArgument(
+                format!("Unclosed tag <{}> at position {}", name, position).into(),
+            ));
+        }
+
         // Add any remaining buffer content
         if !buffer.is_empty() {
             result.push_str(&buffer);
@@ -251,6 +304,27 @@ impl Transform for XmlFormatter {
     fn default_test_input(&self) -> &'static str {
         r#"<?xml version="1.0" encoding="UTF-8"?><root><element attribute="value">text</element><empty-element/><nested><child>content</child></nested></root>"#
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "RSS feed",
+                input: "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>buup</title><item><title>Release</title><link>https://buup.io</link></item></channel></rss>",
+            },
+            Example {
+                name: "SVG",
+                input: "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\" height=\"24\"><g fill=\"none\"><circle cx=\"12\" cy=\"12\" r=\"10\"/><path d=\"M8 12h8\"/></g></svg>",
+            },
+        ]
+    }
+}
+
+// The element name at the start of a tag's contents, e.g. "a" for `a href="#">`
+fn tag_name(tag: &str) -> &str {
+    let end = tag
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(tag.len());
+    &tag[..end]
 }
 
 #[cfg(test)]
@@ -273,4 +347,32 @@ mod tests {
 </root>"#;
         assert_eq!(transformer.transform(input).unwrap(), expected);
     }
+
+    #[test]
+    fn test_xml_formatter_doctype() {
+        let transformer = XmlFormatter;
+        let input = "<!DOCTYPE html><html><body/></html>";
+        let output = transformer.transform(input).unwrap();
+        assert!(output.starts_with("<!DOCTYPE html>\n"));
+        assert!(output.contains("<body/>"));
+    }
+
+    #[test]
+    fn test_xml_formatter_tag_errors() {
+        let transformer = XmlFormatter;
+
+        let err = transformer.transform("<a>\n  <b></c>\n</a>").unwrap_err();
+        assert!(err.to_string().contains("Mismatched closing tag </c>"));
+        assert_eq!(err.position(), Some(10));
+
+        let err = transformer.transform("<a><b/></a></b>").unwrap_err();
+        assert!(err.to_string().contains("Unexpected closing tag </b>"));
+        assert_eq!(err.position(), Some(12));
+
+        let err = transformer
+            .transform("<root>\n<item>text</item>")
+            .unwrap_err();
+        assert!(err.to_string().contains("Unclosed tag <root>"));
+        assert_eq!(err.position(), Some(1));
+    }
 }
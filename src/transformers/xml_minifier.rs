@@ -1,4 +1,4 @@
-use crate::{Transform, TransformError, TransformerCategory};
+use crate::{Example, Transform, TransformError, TransformerCategory};
 
 /// A transformer that compresses XML by removing unnecessary whitespace
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -137,6 +137,15 @@ impl Transform for XmlMinifier {
     </nested>
 </root>"#
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                name: "Formatted document",
+                input: "<?xml version=\"1.0\"?>\n<catalog>\n  <!-- two books -->\n  <book id=\"1\">\n    <title>Dune</title>\n  </book>\n  <book id=\"2\">\n    <title>Emma</title>\n  </book>\n</catalog>",
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,174 @@
+use super::hmac_sha256::{key_bytes, KEY_FORMAT_OPTION};
+use super::{base64_decode, base64_encode::base64_encode, hex_decode};
+use crate::utils::digest::to_hex;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const KEY_OPTION: TransformOption = TransformOption {
+    key: "key",
+    description: "Key repeated over the input",
+    default: "key",
+    kind: TransformOptionKind::Text,
+};
+
+const INPUT_FORMAT_OPTION: TransformOption = TransformOption {
+    key: "input_format",
+    description: "How the input is written: as text, hex or Base64",
+    default: "text",
+    kind: TransformOptionKind::Choice(&["text", "hex", "base64"]),
+};
+
+const OUTPUT_FORMAT_OPTION: TransformOption = TransformOption {
+    key: "output_format",
+    description: "Encoding of the result (text must be valid UTF-8)",
+    default: "hex",
+    kind: TransformOptionKind::Choice(&["hex", "base64", "text"]),
+};
+
+/// Repeating-key XOR cipher transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XorCipher;
+
+impl Transform for XorCipher {
+    fn name(&self) -> &'static str {
+        "XOR Cipher"
+    }
+
+    fn id(&self) -> &'static str {
+        "xorcipher"
+    }
+
+    fn description(&self) -> &'static str {
+        "XORs the input with a repeating key. Applying it again with the same key undoes it."
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Crypto
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[
+            KEY_OPTION,
+            KEY_FORMAT_OPTION,
+            INPUT_FORMAT_OPTION,
+            OUTPUT_FORMAT_OPTION,
+        ]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let data = match options.value(&INPUT_FORMAT_OPTION) {
+            "hex" => hex_decode::decode(input.trim())?,
+            "base64" => base64_decode::base64_decode(input).map_err(|e| {
+                TransformError::InvalidArgument(format!("Invalid Base64 input: {}", e).into())
+            })?,
+            _ => input.as_bytes().to_vec(),
+        };
+        let output = self.transform_bytes_configured(&data, options, token)?;
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    // Raw bytes in, encoded as the output format asks (raw bytes for text)
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        _token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let key = key_bytes(options, &KEY_OPTION)?;
+        if key.is_empty() {
+            return Err(TransformError::InvalidArgument(
+                "XOR needs a non-empty key".into(),
+            ));
+        }
+        let xored: Vec<u8> = input
+            .iter()
+            .zip(key.iter().cycle())
+            .map(|(byte, key)| byte ^ key)
+            .collect();
+        Ok(match options.value(&OUTPUT_FORMAT_OPTION) {
+            "base64" => base64_encode(&xored).into_bytes(),
+            "text" => xored,
+            _ => to_hex(&xored).into_bytes(),
+        })
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "Hello, World!"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Attack at dawn",
+            input: "attack at dawn",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xor_with(input: &str, settings: &[(&str, &str)]) -> Result<String, TransformError> {
+        let options: TransformOptions = settings.iter().copied().collect();
+        XorCipher.transform_with_options(input, &options)
+    }
+
+    #[test]
+    fn test_xor_cipher() {
+        assert_eq!(xor_with("Hello", &[]).unwrap(), "230015070a");
+        assert_eq!(
+            xor_with("Hello", &[("output_format", "base64")]).unwrap(),
+            "IwAVBwo="
+        );
+        assert_eq!(
+            xor_with("Hello", &[("key", "20"), ("key_format", "hex")]).unwrap(),
+            "68454c4c4f"
+        );
+    }
+
+    #[test]
+    fn test_xor_cipher_round_trip() {
+        let encrypted = xor_with("attack at dawn", &[]).unwrap();
+        assert_eq!(
+            xor_with(
+                &encrypted,
+                &[("input_format", "hex"), ("output_format", "text")]
+            )
+            .unwrap(),
+            "attack at dawn"
+        );
+    }
+
+    #[test]
+    fn test_xor_cipher_errors() {
+        assert!(xor_with("data", &[("key", "")]).is_err());
+        assert!(xor_with("zz", &[("input_format", "hex")]).is_err());
+        assert!(matches!(
+            xor_with(
+                "A",
+                &[
+                    ("key", "80"),
+                    ("key_format", "hex"),
+                    ("output_format", "text")
+                ]
+            ),
+            Err(TransformError::Utf8Error)
+        ));
+        assert_eq!(XorCipher.transform_bytes(&[0x4b]).unwrap(), b"20");
+    }
+}
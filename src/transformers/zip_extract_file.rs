@@ -0,0 +1,232 @@
+use super::base64_decode;
+use super::deflate_decompress::{self, check_output_limit, deflate_decode_bytes};
+use super::zip_list::{
+    self, entry_data, read_central_directory, ZipEntry, METHOD_DEFLATED, METHOD_STORED,
+    METHOD_ZSTANDARD,
+};
+use super::zstd_decompress::zstd_decompress_bytes;
+use crate::utils::crc32::calculate_crc32;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptionKind,
+    TransformOptions, TransformerCategory,
+};
+
+const FILE_OPTION: TransformOption = TransformOption {
+    key: "file",
+    description: "Path of the entry to extract (empty for the first file)",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+/// Extracts a single stored, deflated or Zstandard compressed file from a
+/// ZIP archive. Expects Base64 input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZipExtractFile;
+
+impl Transform for ZipExtractFile {
+    fn name(&self) -> &'static str {
+        "ZIP Extract File"
+    }
+
+    fn id(&self) -> &'static str {
+        "zipextractfile"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Compression
+    }
+
+    fn description(&self) -> &'static str {
+        "Extracts one file from a ZIP archive, the first unless the file option names another. Expects Base64 input."
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        zip_list::DEFAULT_TEST_INPUT
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Archive with a folder",
+            input: zip_list::DEFAULT_TEST_INPUT,
+        }]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[FILE_OPTION, deflate_decompress::MAX_OUTPUT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let archive = base64_decode::base64_decode(input).map_err(|e| {
+            TransformError::InvalidArgument(format!("Invalid Base64 input: {}", e).into())
+        })?;
+        let output = self.transform_bytes_configured(&archive, options, token)?;
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let max_output = options.usize_value(&deflate_decompress::MAX_OUTPUT_OPTION)?;
+        let entries = read_central_directory(input)?;
+        let entry = find_entry(&entries, options.value(&FILE_OPTION))?;
+        extract_entry(input, entry, max_output, token)
+    }
+}
+
+// The named entry, or the first file when no name is given
+fn find_entry<'a>(entries: &'a [ZipEntry], name: &str) -> Result<&'a ZipEntry, TransformError> {
+    let name = name.trim().trim_start_matches('/');
+    if name.is_empty() {
+        return entries
+            .iter()
+            .find(|entry| !entry.is_dir())
+            .ok_or_else(|| TransformError::CompressionError("ZIP archive has no files".into()));
+    }
+    let entry = entries
+        .iter()
+        .find(|entry| entry.name == name)
+        .ok_or_else(|| {
+            let names: Vec<&str> = entries
+                .iter()
+                .filter(|entry| !entry.is_dir())
+                .map(|entry| entry.name.as_str())
+                .collect();
+            TransformError::InvalidArgument(
+                format!(
+                    "No file '{}' in the archive (files: {})",
+                    name,
+                    names.join(", ")
+                )
+                .into(),
+            )
+        })?;
+    if entry.is_dir() {
+        return Err(TransformError::InvalidArgument(
+            format!("'{}' is a directory", name).into(),
+        ));
+    }
+    Ok(entry)
+}
+
+fn extract_entry(
+    archive: &[u8],
+    entry: &ZipEntry,
+    max_output: usize,
+    token: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    if entry.is_encrypted() {
+        return Err(TransformError::CompressionError(format!(
+            "'{}' is encrypted",
+            entry.name
+        )));
+    }
+    let size = usize::try_from(entry.size).unwrap_or(usize::MAX);
+    check_output_limit(0, size, max_output)?;
+    let data = entry_data(archive, entry)?;
+    let output = match entry.method {
+        METHOD_STORED => data.to_vec(),
+        METHOD_DEFLATED => deflate_decode_bytes(data, max_output, token)?.0,
+        METHOD_ZSTANDARD => zstd_decompress_bytes(data, max_output, token)?,
+        _ => {
+            return Err(TransformError::CompressionError(format!(
+                "'{}' uses unsupported compression method {}",
+                entry.name,
+                entry.method_name()
+            )))
+        }
+    };
+
+    if output.len() != size {
+        return Err(TransformError::CompressionError(format!(
+            "'{}' decompressed to {} bytes, expected {}",
+            entry.name,
+            output.len(),
+            entry.size
+        )));
+    }
+    let crc = calculate_crc32(&output);
+    if crc != entry.crc32 {
+        return Err(TransformError::CompressionError(format!(
+            "CRC32 mismatch for '{}': expected {:08x}, got {:08x}",
+            entry.name, entry.crc32, crc
+        )));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_file(name: &str) -> TransformOptions {
+        let mut options = TransformOptions::new();
+        options.set("file", name);
+        options
+    }
+
+    #[test]
+    fn test_extract_first_file() {
+        assert_eq!(
+            ZipExtractFile
+                .transform(ZipExtractFile.default_test_input())
+                .unwrap(),
+            "Hello, Zip World!"
+        );
+    }
+
+    #[test]
+    fn test_extract_deflated_file() {
+        assert_eq!(
+            ZipExtractFile
+                .transform_with_options(zip_list::DEFAULT_TEST_INPUT, &with_file("docs/notes.md"))
+                .unwrap(),
+            format!("# Notes\n\n{}\n", "Buup transforms text. ".repeat(6))
+        );
+    }
+
+    #[test]
+    fn test_extract_missing_or_directory() {
+        let error = ZipExtractFile
+            .transform_with_options(zip_list::DEFAULT_TEST_INPUT, &with_file("nope.txt"))
+            .unwrap_err();
+        assert!(error.to_string().contains("hello.txt, docs/notes.md"));
+        assert!(matches!(
+            ZipExtractFile
+                .transform_with_options(zip_list::DEFAULT_TEST_INPUT, &with_file("docs/")),
+            Err(TransformError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_extract_checks_crc_and_limit() {
+        let mut archive = base64_decode::base64_decode(zip_list::DEFAULT_TEST_INPUT).unwrap();
+        // Corrupt the stored data of hello.txt, right after its 39 byte local header
+        archive[39] ^= 0xFF;
+        assert!(matches!(
+            ZipExtractFile.transform_bytes(&archive),
+            Err(TransformError::CompressionError(e)) if e.contains("CRC32 mismatch")
+        ));
+
+        let mut options = with_file("docs/notes.md");
+        options.set("max_output", "100");
+        assert!(ZipExtractFile
+            .transform_with_options(zip_list::DEFAULT_TEST_INPUT, &options)
+            .is_err());
+    }
+}
@@ -0,0 +1,398 @@
+use super::base64_decode;
+use crate::{Example, Transform, TransformError, TransformerCategory};
+
+// Record signatures (APPNOTE.TXT sections 4.3.7, 4.3.12, 4.3.14-4.3.16)
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const END_SIGNATURE: u32 = 0x0605_4b50;
+const ZIP64_END_SIGNATURE: u32 = 0x0606_4b50;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x0706_4b50;
+
+const END_RECORD_LEN: usize = 22;
+const ZIP64_LOCATOR_LEN: usize = 20;
+const LOCAL_HEADER_LEN: usize = 30;
+const CENTRAL_HEADER_LEN: usize = 46;
+// The end record is followed by a comment of up to this many bytes
+const MAX_COMMENT_LEN: usize = 0xFFFF;
+// Extra field holding 64-bit sizes and offsets
+const ZIP64_EXTRA_ID: u16 = 0x0001;
+
+pub(crate) const METHOD_STORED: u16 = 0;
+pub(crate) const METHOD_DEFLATED: u16 = 8;
+pub(crate) const METHOD_ZSTANDARD: u16 = 93;
+
+const FLAG_ENCRYPTED: u16 = 0x0001;
+const FLAG_UTF8: u16 = 0x0800;
+
+/// Lists the entries of a ZIP archive with their sizes, compression method
+/// and modification time. Expects Base64 input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZipList;
+
+/// A file or directory recorded in the central directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ZipEntry {
+    pub name: String,
+    pub method: u16,
+    pub flags: u16,
+    pub crc32: u32,
+    pub compressed_size: u64,
+    pub size: u64,
+    // MS-DOS date and time fields
+    pub date: u16,
+    pub time: u16,
+    pub local_header_offset: u64,
+}
+
+impl ZipEntry {
+    pub fn is_dir(&self) -> bool {
+        self.name.ends_with('/')
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.flags & FLAG_ENCRYPTED != 0
+    }
+
+    pub fn method_name(&self) -> String {
+        let name = match self.method {
+            METHOD_STORED => "Stored",
+            1 => "Shrunk",
+            6 => "Imploded",
+            METHOD_DEFLATED => "Deflated",
+            9 => "Deflate64",
+            12 => "BZIP2",
+            14 => "LZMA",
+            METHOD_ZSTANDARD => "Zstandard",
+            95 => "XZ",
+            98 => "PPMd",
+            99 => "AES",
+            other => return format!("Method {}", other),
+        };
+        name.to_string()
+    }
+
+    // `YYYY-MM-DD HH:MM:SS`; MS-DOS times have two second precision
+    fn modified(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            1980 + (self.date >> 9),
+            (self.date >> 5) & 0x0F,
+            self.date & 0x1F,
+            self.time >> 11,
+            (self.time >> 5) & 0x3F,
+            (self.time & 0x1F) * 2
+        )
+    }
+}
+
+impl Transform for ZipList {
+    fn name(&self) -> &'static str {
+        "ZIP List"
+    }
+
+    fn id(&self) -> &'static str {
+        "ziplist"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Compression
+    }
+
+    fn description(&self) -> &'static str {
+        "Lists the files in a ZIP archive with their sizes and compression method. Expects Base64 input."
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        DEFAULT_TEST_INPUT
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Archive with a folder",
+            input: DEFAULT_TEST_INPUT,
+        }]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        let bytes = base64_decode::base64_decode(input).map_err(|e| {
+            TransformError::InvalidArgument(format!("Invalid Base64 input: {}", e).into())
+        })?;
+        let output = self.transform_bytes(&bytes)?;
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let entries = read_central_directory(input)?;
+        Ok(format_listing(&entries).into_bytes())
+    }
+}
+
+/// hello.txt (stored), docs/ and docs/notes.md (deflated), made with Python's zipfile
+pub(crate) const DEFAULT_TEST_INPUT: &str = "UEsDBBQAAAAAAMBjoViP2pfYEQAAABEAAAAJAAAAaGVsbG8udHh0SGVsbG8sIFppcCBXb3JsZCFQSwMEFAAAAAAAwGOhWAAAAAAAAAAAAAAAAAUAAABkb2NzL1BLAwQUAAAACAAFQKJYjbGNWCQAAACOAAAADQAAAGRvY3Mvbm90ZXMubWRTVvDLL0kt5uJyKi0tUCgpSswrTssvyi1WKEmtKNFToK8oFwBQSwECFAMUAAAAAADAY6FYj9qX2BEAAAARAAAACQAAAAAAAAAAAAAAgAEAAAAAaGVsbG8udHh0UEsBAhQDFAAAAAAAwGOhWAAAAAAAAAAAAAAAAAUAAAAAAAAAAAAQAP1BOAAAAGRvY3MvUEsBAhQDFAAAAAgABUCiWI2xjVgkAAAAjgAAAA0AAAAAAAAAAAAAAIABWwAAAGRvY3Mvbm90ZXMubWRQSwUGAAAAAAMAAwClAAAAqgAAAAAA";
+
+// One row per entry, columns aligned, then totals
+fn format_listing(entries: &[ZipEntry]) -> String {
+    let header = ["Size", "Compressed", "Method", "Modified", "Name"];
+    let rows: Vec<[String; 5]> = entries
+        .iter()
+        .map(|entry| {
+            [
+                entry.size.to_string(),
+                entry.compressed_size.to_string(),
+                entry.method_name(),
+                entry.modified(),
+                entry.name.clone(),
+            ]
+        })
+        .collect();
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: [&str; 5]| {
+        format!(
+            "{:>w0$}  {:>w1$}  {:<w2$}  {:<w3$}  {}",
+            cells[0],
+            cells[1],
+            cells[2],
+            cells[3],
+            cells[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        )
+    };
+    let mut out = line(header);
+    for row in &rows {
+        out.push('\n');
+        out.push_str(&line([&row[0], &row[1], &row[2], &row[3], &row[4]]));
+    }
+
+    let files = entries.iter().filter(|e| !e.is_dir()).count();
+    let total: u64 = entries.iter().map(|e| e.size).sum();
+    let compressed: u64 = entries.iter().map(|e| e.compressed_size).sum();
+    out.push_str(&format!(
+        "\n{} {}, {} {}, {} bytes ({} compressed)",
+        files,
+        if files == 1 { "file" } else { "files" },
+        entries.len() - files,
+        if entries.len() - files == 1 {
+            "directory"
+        } else {
+            "directories"
+        },
+        total,
+        compressed
+    ));
+    out
+}
+
+fn corrupt(details: impl Into<String>) -> TransformError {
+    TransformError::CompressionError(details.into())
+}
+
+// Little-endian field of `N` bytes at `pos`
+fn field<const N: usize>(data: &[u8], pos: usize) -> Result<u64, TransformError> {
+    let bytes = data
+        .get(pos..pos + N)
+        .ok_or_else(|| corrupt("ZIP archive is truncated"))?;
+    Ok(bytes
+        .iter()
+        .rev()
+        .fold(0, |value, &byte| value << 8 | u64::from(byte)))
+}
+
+/// Reads the entries from the central directory at the end of `data`
+pub(crate) fn read_central_directory(data: &[u8]) -> Result<Vec<ZipEntry>, TransformError> {
+    let end = find_end_record(data)?;
+    let mut count = field::<2>(data, end + 10)?;
+    let mut directory_size = field::<4>(data, end + 12)?;
+    let mut directory_offset = field::<4>(data, end + 16)?;
+
+    // ZIP64 archives keep the real values in a record located just before
+    if (count == 0xFFFF || directory_size == 0xFFFF_FFFF || directory_offset == 0xFFFF_FFFF)
+        && end >= ZIP64_LOCATOR_LEN
+        && field::<4>(data, end - ZIP64_LOCATOR_LEN)? == u64::from(ZIP64_LOCATOR_SIGNATURE)
+    {
+        let record = to_usize(field::<8>(data, end - ZIP64_LOCATOR_LEN + 8)?)?;
+        if field::<4>(data, record)? != u64::from(ZIP64_END_SIGNATURE) {
+            return Err(corrupt("Invalid ZIP64 end of central directory record"));
+        }
+        count = field::<8>(data, record + 32)?;
+        directory_size = field::<8>(data, record + 40)?;
+        directory_offset = field::<8>(data, record + 48)?;
+    }
+
+    let start = to_usize(directory_offset)?;
+    let directory = start
+        .checked_add(to_usize(directory_size)?)
+        .and_then(|end| data.get(start..end))
+        .ok_or_else(|| corrupt("Central directory lies outside the archive"))?;
+
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    while (entries.len() as u64) < count {
+        if field::<4>(directory, pos)? != u64::from(CENTRAL_HEADER_SIGNATURE) {
+            return Err(corrupt(format!(
+                "Invalid central directory header at position {}",
+                start + pos
+            )));
+        }
+        let flags = field::<2>(directory, pos + 8)? as u16;
+        let name_len = field::<2>(directory, pos + 28)? as usize;
+        let extra_len = field::<2>(directory, pos + 30)? as usize;
+        let comment_len = field::<2>(directory, pos + 32)? as usize;
+        let name_start = pos + CENTRAL_HEADER_LEN;
+        let name = directory
+            .get(name_start..name_start + name_len)
+            .ok_or_else(|| corrupt("ZIP archive is truncated"))?;
+        let extra = directory
+            .get(name_start + name_len..name_start + name_len + extra_len)
+            .ok_or_else(|| corrupt("ZIP archive is truncated"))?;
+
+        let mut entry = ZipEntry {
+            // Names without the UTF-8 flag are meant as code page 437, which
+            // agrees with UTF-8 for the ASCII names nearly everyone uses
+            name: if flags & FLAG_UTF8 != 0 {
+                String::from_utf8(name.to_vec())
+                    .map_err(|_| corrupt("Entry name is not valid UTF-8"))?
+            } else {
+                String::from_utf8_lossy(name).into_owned()
+            },
+            method: field::<2>(directory, pos + 10)? as u16,
+            flags,
+            time: field::<2>(directory, pos + 12)? as u16,
+            date: field::<2>(directory, pos + 14)? as u16,
+            crc32: field::<4>(directory, pos + 16)? as u32,
+            compressed_size: field::<4>(directory, pos + 20)?,
+            size: field::<4>(directory, pos + 24)?,
+            local_header_offset: field::<4>(directory, pos + 42)?,
+        };
+        apply_zip64_extra(&mut entry, extra)?;
+        entries.push(entry);
+        pos = name_start + name_len + extra_len + comment_len;
+    }
+    Ok(entries)
+}
+
+// The end of central directory record, searched for backwards since a
+// comment of unknown length follows it
+fn find_end_record(data: &[u8]) -> Result<usize, TransformError> {
+    let last = data
+        .len()
+        .checked_sub(END_RECORD_LEN)
+        .ok_or_else(|| corrupt("Not a ZIP archive (too short)"))?;
+    let first = last.saturating_sub(MAX_COMMENT_LEN);
+    (first..=last)
+        .rev()
+        .find(|&pos| data[pos..pos + 4] == END_SIGNATURE.to_le_bytes())
+        .ok_or_else(|| corrupt("Not a ZIP archive (no end of central directory record)"))
+}
+
+// Replaces the 32-bit fields that overflowed with those from the ZIP64 extra
+// field, which stores only those, in this order
+fn apply_zip64_extra(entry: &mut ZipEntry, mut extra: &[u8]) -> Result<(), TransformError> {
+    while extra.len() >= 4 {
+        let id = field::<2>(extra, 0)? as u16;
+        let len = field::<2>(extra, 2)? as usize;
+        let body = extra
+            .get(4..4 + len)
+            .ok_or_else(|| corrupt("Truncated extra field"))?;
+        if id == ZIP64_EXTRA_ID {
+            let mut pos = 0;
+            for value in [
+                &mut entry.size,
+                &mut entry.compressed_size,
+                &mut entry.local_header_offset,
+            ] {
+                if *value == 0xFFFF_FFFF {
+                    *value = field::<8>(body, pos)?;
+                    pos += 8;
+                }
+            }
+        }
+        extra = &extra[4 + len..];
+    }
+    Ok(())
+}
+
+fn to_usize(value: u64) -> Result<usize, TransformError> {
+    usize::try_from(value).map_err(|_| corrupt("ZIP offset too large"))
+}
+
+/// The still compressed data of `entry`, found after its local header
+pub(crate) fn entry_data<'a>(data: &'a [u8], entry: &ZipEntry) -> Result<&'a [u8], TransformError> {
+    let header = to_usize(entry.local_header_offset)?;
+    if field::<4>(data, header)? != u64::from(LOCAL_HEADER_SIGNATURE) {
+        return Err(corrupt(format!(
+            "Invalid local header for '{}' at position {}",
+            entry.name, header
+        )));
+    }
+    // The local name and extra field may differ in length from the central ones
+    let name_len = field::<2>(data, header + 26)? as usize;
+    let extra_len = field::<2>(data, header + 28)? as usize;
+    let start = header + LOCAL_HEADER_LEN + name_len + extra_len;
+    start
+        .checked_add(to_usize(entry.compressed_size)?)
+        .and_then(|end| data.get(start..end))
+        .ok_or_else(|| corrupt(format!("Data of '{}' lies outside the archive", entry.name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_list() {
+        assert_eq!(
+            ZipList.transform(DEFAULT_TEST_INPUT).unwrap(),
+            "Size  Compressed  Method    Modified             Name\n\
+             \x20 17          17  Stored    2024-05-01 12:30:00  hello.txt\n\
+             \x20  0           0  Stored    2024-05-01 12:30:00  docs/\n\
+             \x20142          36  Deflated  2024-05-02 08:00:10  docs/notes.md\n\
+             2 files, 1 directory, 159 bytes (53 compressed)"
+        );
+    }
+
+    #[test]
+    fn test_read_central_directory() {
+        let data = base64_decode::base64_decode(DEFAULT_TEST_INPUT).unwrap();
+        let entries = read_central_directory(&data).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert!(entries[1].is_dir());
+        assert_eq!(entries[2].method, METHOD_DEFLATED);
+        assert_eq!(
+            entry_data(&data, &entries[0]).unwrap(),
+            b"Hello, Zip World!"
+        );
+    }
+
+    #[test]
+    fn test_zip_list_empty_archive() {
+        let empty = [
+            0x50, 0x4b, 0x05, 0x06, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert_eq!(
+            String::from_utf8(ZipList.transform_bytes(&empty).unwrap()).unwrap(),
+            "Size  Compressed  Method  Modified  Name\n0 files, 0 directories, 0 bytes (0 compressed)"
+        );
+    }
+
+    #[test]
+    fn test_zip_list_invalid() {
+        assert!(matches!(
+            ZipList.transform_bytes(b"not a zip file at all, sorry"),
+            Err(TransformError::CompressionError(e)) if e.contains("Not a ZIP archive")
+        ));
+        let mut data = base64_decode::base64_decode(DEFAULT_TEST_INPUT).unwrap();
+        // Point the central directory past the end
+        let end = data.len() - END_RECORD_LEN;
+        data[end + 16] = 0xFF;
+        assert!(ZipList.transform_bytes(&data).is_err());
+    }
+}
@@ -0,0 +1,1117 @@
+use super::base64_decode;
+use super::deflate_decompress::{self, check_output_limit};
+use crate::utils::xxhash::xxh64;
+use crate::{
+    CancelToken, Example, Transform, TransformError, TransformOption, TransformOptions,
+    TransformerCategory,
+};
+
+// Frame magic numbers (RFC 8878 Section 3.1)
+const FRAME_MAGIC: u32 = 0xFD2F_B528;
+// Skippable frames use any of 16 magic numbers ending in 0-F
+const SKIPPABLE_MAGIC: u32 = 0x184D_2A50;
+const SKIPPABLE_MASK: u32 = 0xFFFF_FFF0;
+
+const BLOCK_RAW: u8 = 0;
+const BLOCK_RLE: u8 = 1;
+const BLOCK_COMPRESSED: u8 = 2;
+const MAX_BLOCK_SIZE: usize = 128 * 1024;
+
+const LITERALS_RAW: u8 = 0;
+const LITERALS_RLE: u8 = 1;
+const LITERALS_TREELESS: u8 = 3;
+
+const MAX_HUFFMAN_BITS: u8 = 11;
+const MAX_HUFFMAN_WEIGHT_LOG: u8 = 6;
+
+// Largest accuracy logs and symbols of the sequence codes
+const MAX_LITERAL_LENGTH_LOG: u8 = 9;
+const MAX_MATCH_LENGTH_LOG: u8 = 9;
+const MAX_OFFSET_LOG: u8 = 8;
+const MAX_LITERAL_LENGTH_CODE: usize = 35;
+const MAX_MATCH_LENGTH_CODE: usize = 52;
+const MAX_OFFSET_CODE: usize = 31;
+
+// Default distributions (RFC 8878 Section 3.1.1.3.2.2), -1 meaning "less than 1"
+const LITERAL_LENGTH_DEFAULT: (u8, &[i16]) = (
+    6,
+    &[
+        4, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 1, 1, 1,
+        1, 1, -1, -1, -1, -1,
+    ],
+);
+const MATCH_LENGTH_DEFAULT: (u8, &[i16]) = (
+    6,
+    &[
+        1, 4, 3, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1, -1, -1,
+    ],
+);
+const OFFSET_DEFAULT: (u8, &[i16]) = (
+    5,
+    &[
+        1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1,
+    ],
+);
+
+// Baseline and extra bits of the literal length codes from 16 on; codes
+// below 16 are the length itself
+const LITERAL_LENGTH_CODES: [(u32, u8); 20] = [
+    (16, 1),
+    (18, 1),
+    (20, 1),
+    (22, 1),
+    (24, 2),
+    (28, 2),
+    (32, 3),
+    (40, 3),
+    (48, 4),
+    (64, 6),
+    (128, 7),
+    (256, 8),
+    (512, 9),
+    (1024, 10),
+    (2048, 11),
+    (4096, 12),
+    (8192, 13),
+    (16384, 14),
+    (32768, 15),
+    (65536, 16),
+];
+
+// Baseline and extra bits of the match length codes from 32 on; codes below
+// 32 are the length minus 3
+const MATCH_LENGTH_CODES: [(u32, u8); 21] = [
+    (35, 1),
+    (37, 1),
+    (39, 1),
+    (41, 1),
+    (43, 2),
+    (47, 2),
+    (51, 3),
+    (59, 3),
+    (67, 4),
+    (83, 4),
+    (99, 5),
+    (131, 7),
+    (259, 8),
+    (515, 9),
+    (1027, 10),
+    (2051, 11),
+    (4099, 12),
+    (8195, 13),
+    (16387, 14),
+    (32771, 15),
+    (65539, 16),
+];
+
+/// Decompresses Zstandard frames (RFC 8878), expects Base64 input.
+/// Frames that need a dictionary are rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZstdDecompress;
+
+impl Transform for ZstdDecompress {
+    fn name(&self) -> &'static str {
+        "Zstandard Decompress"
+    }
+
+    fn id(&self) -> &'static str {
+        "zstddecompress"
+    }
+
+    fn category(&self) -> TransformerCategory {
+        TransformerCategory::Compression
+    }
+
+    fn description(&self) -> &'static str {
+        "Decompresses Zstandard input (RFC 8878) without a dictionary. Expects Base64 input."
+    }
+
+    fn default_test_input(&self) -> &'static str {
+        "KLUv/QRYkQAASGVsbG8sIFpzdGQgV29ybGQh4VILvQ==" // "Hello, Zstd World!"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            name: "Repetitive text",
+            input: "KLUv/QRYZQEAJAJCdXVwIHRyYW5zZm9ybXMgdGV4dC4gLCBhZ2FpbiBhbmQuAgAtiDeh2QQ4Fpiv",
+        }]
+    }
+
+    fn transform(&self, input: &str) -> Result<String, TransformError> {
+        self.transform_with_options(input, &TransformOptions::new())
+    }
+
+    fn transform_bytes(&self, input: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.transform_bytes_with_options(input, &TransformOptions::new())
+    }
+
+    fn options(&self) -> &'static [TransformOption] {
+        &[deflate_decompress::MAX_OUTPUT_OPTION]
+    }
+
+    fn transform_configured(
+        &self,
+        input: &str,
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<String, TransformError> {
+        let compressed_bytes = base64_decode::base64_decode(input).map_err(|e| {
+            TransformError::InvalidArgument(format!("Invalid Base64 input: {}", e).into())
+        })?;
+        let output = self.transform_bytes_configured(&compressed_bytes, options, token)?;
+        String::from_utf8(output).map_err(|_| TransformError::Utf8Error)
+    }
+
+    fn transform_bytes_configured(
+        &self,
+        input: &[u8],
+        options: &TransformOptions,
+        token: &CancelToken,
+    ) -> Result<Vec<u8>, TransformError> {
+        let max_output = options.usize_value(&deflate_decompress::MAX_OUTPUT_OPTION)?;
+        zstd_decompress_bytes(input, max_output, token)
+    }
+}
+
+fn corrupt(details: impl Into<String>) -> TransformError {
+    TransformError::CompressionError(details.into())
+}
+
+// Decodes every frame of `input`, concatenating their output, producing at
+// most `max_output` bytes and checking `token` between blocks
+pub(crate) fn zstd_decompress_bytes(
+    input: &[u8],
+    max_output: usize,
+    token: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    if input.len() < 4 {
+        return Err(corrupt("Input too short to be Zstandard"));
+    }
+    let mut reader = ByteReader::new(input);
+    let mut output = Vec::new();
+    while !reader.is_empty() {
+        let magic = reader.u32()?;
+        if magic & SKIPPABLE_MASK == SKIPPABLE_MAGIC {
+            let len = reader.u32()? as usize;
+            reader.take(len)?;
+        } else if magic == FRAME_MAGIC {
+            decode_frame(&mut reader, &mut output, max_output, token)?;
+        } else {
+            return Err(corrupt(format!(
+                "Invalid Zstandard magic number {:08x} at position {}",
+                magic,
+                reader.pos - 4
+            )));
+        }
+    }
+    Ok(output)
+}
+
+// Reads little-endian fields front to back
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], TransformError> {
+        let end = self.pos.saturating_add(len);
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or_else(|| corrupt("Unexpected end of Zstandard data"))?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, TransformError> {
+        Ok(self.take(1)?[0])
+    }
+
+    // An unsigned integer of `len` (up to 8) bytes
+    fn uint(&mut self, len: usize) -> Result<u64, TransformError> {
+        let bytes = self.take(len)?;
+        Ok(bytes
+            .iter()
+            .rev()
+            .fold(0, |value, &byte| value << 8 | u64::from(byte)))
+    }
+
+    fn u32(&mut self) -> Result<u32, TransformError> {
+        Ok(self.uint(4)? as u32)
+    }
+}
+
+// Tables and offsets that carry over from one block to the next
+#[derive(Default)]
+struct FrameState {
+    huffman: Option<HuffmanTable>,
+    literal_lengths: Option<FseTable>,
+    offsets: Option<FseTable>,
+    match_lengths: Option<FseTable>,
+    repeat_offsets: [usize; 3],
+}
+
+fn decode_frame(
+    reader: &mut ByteReader,
+    output: &mut Vec<u8>,
+    max_output: usize,
+    token: &CancelToken,
+) -> Result<(), TransformError> {
+    // Frame header (RFC 8878 Section 3.1.1.1)
+    let descriptor = reader.u8()?;
+    let content_size_flag = descriptor >> 6;
+    let single_segment = descriptor & 0x20 != 0;
+    if descriptor & 0x08 != 0 {
+        return Err(corrupt("Reserved bit set in Zstandard frame header"));
+    }
+    let has_checksum = descriptor & 0x04 != 0;
+    if !single_segment {
+        // Window descriptor: the whole output is kept, so any window fits
+        reader.u8()?;
+    }
+    let dictionary_id = reader.uint([0, 1, 2, 4][usize::from(descriptor & 3)])?;
+    if dictionary_id != 0 {
+        return Err(TransformError::CompressionError(format!(
+            "Frame needs dictionary {}, but dictionaries aren't supported",
+            dictionary_id
+        )));
+    }
+    let content_size = match (content_size_flag, single_segment) {
+        (0, false) => None,
+        (0, true) => Some(reader.uint(1)?),
+        (1, _) => Some(reader.uint(2)? + 256),
+        (2, _) => Some(reader.uint(4)?),
+        _ => Some(reader.uint(8)?),
+    };
+    if let Some(size) = content_size {
+        check_output_limit(
+            output.len(),
+            usize::try_from(size).unwrap_or(usize::MAX),
+            max_output,
+        )?;
+    }
+
+    let frame_start = output.len();
+    let mut state = FrameState {
+        repeat_offsets: [1, 4, 8],
+        ..FrameState::default()
+    };
+    loop {
+        token.check()?;
+        let header = reader.uint(3)? as u32;
+        let last = header & 1 != 0;
+        let block_type = ((header >> 1) & 3) as u8;
+        let block_size = (header >> 3) as usize;
+        if block_size > MAX_BLOCK_SIZE {
+            return Err(corrupt(format!(
+                "Block of {} bytes exceeds the maximum of {}",
+                block_size, MAX_BLOCK_SIZE
+            )));
+        }
+        match block_type {
+            BLOCK_RAW => {
+                check_output_limit(output.len(), block_size, max_output)?;
+                output.extend_from_slice(reader.take(block_size)?);
+            }
+            BLOCK_RLE => {
+                check_output_limit(output.len(), block_size, max_output)?;
+                let byte = reader.u8()?;
+                output.resize(output.len() + block_size, byte);
+            }
+            BLOCK_COMPRESSED => {
+                let block = reader.take(block_size)?;
+                decode_block(block, &mut state, output, frame_start, max_output)?;
+            }
+            _ => return Err(corrupt("Reserved Zstandard block type")),
+        }
+        if last {
+            break;
+        }
+    }
+
+    let frame_output = &output[frame_start..];
+    if let Some(size) = content_size {
+        if frame_output.len() as u64 != size {
+            return Err(TransformError::CompressionError(format!(
+                "Frame content size mismatch: expected {}, got {}",
+                size,
+                frame_output.len()
+            )));
+        }
+    }
+    if has_checksum {
+        let expected = reader.u32()?;
+        let actual = xxh64(frame_output, 0) as u32;
+        if actual != expected {
+            return Err(TransformError::CompressionError(format!(
+                "Content checksum mismatch: expected {:08x}, got {:08x}",
+                expected, actual
+            )));
+        }
+    }
+    Ok(())
+}
+
+// A compressed block: literals, then the sequences that interleave them with
+// matches (RFC 8878 Section 3.1.1.3)
+fn decode_block(
+    block: &[u8],
+    state: &mut FrameState,
+    output: &mut Vec<u8>,
+    frame_start: usize,
+    max_output: usize,
+) -> Result<(), TransformError> {
+    let mut reader = ByteReader::new(block);
+    let literals = decode_literals(&mut reader, state)?;
+    let sequences = decode_sequences(&mut reader, state)?;
+
+    let mut literals_pos = 0;
+    for sequence in sequences {
+        let literal_end = literals_pos + sequence.literal_length;
+        let copied = literals
+            .get(literals_pos..literal_end)
+            .ok_or_else(|| corrupt("Sequence uses more literals than the block has"))?;
+        check_output_limit(
+            output.len(),
+            copied.len() + sequence.match_length,
+            max_output,
+        )?;
+        output.extend_from_slice(copied);
+        literals_pos = literal_end;
+
+        let offset = resolve_offset(&mut state.repeat_offsets, &sequence)?;
+        if offset > output.len() - frame_start {
+            return Err(TransformError::CompressionError(format!(
+                "Match offset {} reaches before the start of the frame",
+                offset
+            )));
+        }
+        // The match may overlap the bytes it produces
+        let start = output.len() - offset;
+        let mut copied = 0;
+        while copied < sequence.match_length {
+            let chunk = offset.min(sequence.match_length - copied);
+            output.extend_from_within(start + copied..start + copied + chunk);
+            copied += chunk;
+        }
+    }
+    let rest = &literals[literals_pos..];
+    check_output_limit(output.len(), rest.len(), max_output)?;
+    output.extend_from_slice(rest);
+    Ok(())
+}
+
+// Turns an offset value into a distance, updating the repeat offsets
+// (RFC 8878 Section 3.1.1.5)
+fn resolve_offset(repeats: &mut [usize; 3], sequence: &Sequence) -> Result<usize, TransformError> {
+    if sequence.offset_value > 3 {
+        let offset = sequence.offset_value - 3;
+        *repeats = [offset, repeats[0], repeats[1]];
+        return Ok(offset);
+    }
+    // Without literals, the repeat offsets shift by one
+    let index = sequence.offset_value + usize::from(sequence.literal_length == 0);
+    let offset = match index {
+        1 => return Ok(repeats[0]),
+        2 => repeats[1],
+        3 => repeats[2],
+        _ => repeats[0].wrapping_sub(1),
+    };
+    if offset == 0 {
+        return Err(corrupt("Repeat offset of zero"));
+    }
+    if index != 2 {
+        repeats[2] = repeats[1];
+    }
+    repeats[1] = repeats[0];
+    repeats[0] = offset;
+    Ok(offset)
+}
+
+fn decode_literals(
+    reader: &mut ByteReader,
+    state: &mut FrameState,
+) -> Result<Vec<u8>, TransformError> {
+    let first = reader.u8()?;
+    let block_type = first & 3;
+    let size_format = (first >> 2) & 3;
+
+    if block_type == LITERALS_RAW || block_type == LITERALS_RLE {
+        let size = match size_format {
+            0 | 2 => usize::from(first >> 3),
+            1 => usize::from(first >> 4) | usize::from(reader.u8()?) << 4,
+            _ => usize::from(first >> 4) | (reader.uint(2)? as usize) << 4,
+        };
+        return if block_type == LITERALS_RAW {
+            Ok(reader.take(size)?.to_vec())
+        } else {
+            Ok(vec![reader.u8()?; size])
+        };
+    }
+
+    // Regenerated and compressed sizes share the header bits after the type
+    let (streams, header_bytes, size_bits) = match size_format {
+        0 => (1, 2, 10),
+        1 => (4, 2, 10),
+        2 => (4, 3, 14),
+        _ => (4, 4, 18),
+    };
+    let header = u64::from(first >> 4) | reader.uint(header_bytes)? << 4;
+    let mask = (1 << size_bits) - 1;
+    let regenerated_size = (header & mask) as usize;
+    let compressed_size = (header >> size_bits & mask) as usize;
+    let mut data = reader.take(compressed_size)?;
+
+    // Treeless literals reuse the previous block's table
+    let table = match block_type {
+        LITERALS_TREELESS => state
+            .huffman
+            .as_ref()
+            .ok_or_else(|| corrupt("Treeless literals block without a previous Huffman table"))?,
+        _ => {
+            let (table, used) = HuffmanTable::read(data)?;
+            data = &data[used..];
+            state.huffman.insert(table)
+        }
+    };
+
+    let mut literals = Vec::with_capacity(regenerated_size);
+    if streams == 1 {
+        table.decode_stream(data, regenerated_size, &mut literals)?;
+    } else {
+        // A jump table gives the sizes of the first three streams
+        if data.len() < 6 {
+            return Err(corrupt("Literals too short for the jump table"));
+        }
+        let mut sizes = [0; 4];
+        for (i, size) in sizes.iter_mut().take(3).enumerate() {
+            *size = usize::from(u16::from_le_bytes([data[2 * i], data[2 * i + 1]]));
+        }
+        let total: usize = sizes.iter().sum::<usize>() + 6;
+        sizes[3] = data
+            .len()
+            .checked_sub(total)
+            .ok_or_else(|| corrupt("Literal streams overrun the literals section"))?;
+        let per_stream = regenerated_size.div_ceil(4);
+        let mut start = 6;
+        for (i, size) in sizes.into_iter().enumerate() {
+            let count = if i < 3 {
+                per_stream
+            } else {
+                regenerated_size
+                    .checked_sub(3 * per_stream)
+                    .ok_or_else(|| corrupt("Too few literals for four streams"))?
+            };
+            table.decode_stream(&data[start..start + size], count, &mut literals)?;
+            start += size;
+        }
+    }
+    Ok(literals)
+}
+
+// Reads bits from the end of a stream towards its start, as Huffman and FSE
+// streams are written. The last byte's highest set bit marks where they begin.
+struct BackwardBits<'a> {
+    bytes: &'a [u8],
+    // Bits not read yet; negative once reads have run past the start, which
+    // yield zeros
+    remaining: isize,
+}
+
+impl<'a> BackwardBits<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, TransformError> {
+        let last = *bytes.last().ok_or_else(|| corrupt("Empty bitstream"))?;
+        if last == 0 {
+            return Err(corrupt("Bitstream is missing its end marker"));
+        }
+        let padding = last.leading_zeros() as isize + 1;
+        Ok(Self {
+            bytes,
+            remaining: bytes.len() as isize * 8 - padding,
+        })
+    }
+
+    // Reads `count` bits (up to 56), the first read being the most significant
+    fn read(&mut self, count: u8) -> u64 {
+        if count == 0 {
+            return 0;
+        }
+        self.remaining -= isize::from(count);
+        let start = self.remaining;
+        // Bits before the start of the stream are zeros
+        let (start, skipped) = if start < 0 {
+            (0, (-start) as u32)
+        } else {
+            (start as usize, 0)
+        };
+        if skipped >= u32::from(count) {
+            return 0;
+        }
+        let byte = start / 8;
+        let window = (0..8).fold(0u64, |window, i| {
+            let value = self.bytes.get(byte + i).copied().unwrap_or(0);
+            window | u64::from(value) << (8 * i)
+        });
+        let wanted = u32::from(count) - skipped;
+        let value = (window >> (start % 8)) & ((1 << wanted) - 1);
+        value << skipped
+    }
+
+    fn overflowed(&self) -> bool {
+        self.remaining < 0
+    }
+}
+
+// Reads bits from the start of a stream, lowest bit first
+struct ForwardBits<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl ForwardBits<'_> {
+    fn read(&mut self, count: u8) -> Result<u32, TransformError> {
+        let mut value = 0;
+        for i in 0..count {
+            let byte = self
+                .bytes
+                .get(self.pos / 8)
+                .ok_or_else(|| corrupt("Unexpected end of table description"))?;
+            value |= u32::from(byte >> (self.pos % 8) & 1) << i;
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+
+    fn bytes_used(&self) -> usize {
+        self.pos.div_ceil(8)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FseEntry {
+    symbol: u8,
+    bits: u8,
+    baseline: u16,
+}
+
+// Finite State Entropy decoding table (RFC 8878 Section 4.1)
+#[derive(Debug, Clone)]
+struct FseTable {
+    accuracy_log: u8,
+    entries: Vec<FseEntry>,
+}
+
+impl FseTable {
+    // Reads a table description, returning the table and the bytes it used
+    fn read(
+        bytes: &[u8],
+        max_accuracy_log: u8,
+        max_symbol: usize,
+    ) -> Result<(Self, usize), TransformError> {
+        let mut bits = ForwardBits { bytes, pos: 0 };
+        let accuracy_log = bits.read(4)? as u8 + 5;
+        if accuracy_log > max_accuracy_log {
+            return Err(TransformError::CompressionError(format!(
+                "FSE accuracy log {} exceeds the maximum of {}",
+                accuracy_log, max_accuracy_log
+            )));
+        }
+        let mut remaining = (1i32 << accuracy_log) + 1;
+        let mut threshold = 1i32 << accuracy_log;
+        let mut width = accuracy_log + 1;
+        let mut counts = Vec::new();
+        while remaining > 1 {
+            if counts.len() > max_symbol {
+                return Err(corrupt("FSE table has too many symbols"));
+            }
+            // Small values take one bit less
+            let max = 2 * threshold - 1 - remaining;
+            let low = bits.read(width - 1)? as i32;
+            let value = if low < max {
+                low
+            } else {
+                let value = low | (bits.read(1)? as i32) << (width - 1);
+                if value >= threshold {
+                    value - max
+                } else {
+                    value
+                }
+            };
+            let count = value - 1;
+            remaining -= count.abs();
+            counts.push(count as i16);
+            if count == 0 {
+                // Runs of zero counts are given 2 bits at a time
+                loop {
+                    let repeat = bits.read(2)?;
+                    counts.extend(std::iter::repeat_n(0, repeat as usize));
+                    if repeat != 3 {
+                        break;
+                    }
+                }
+            }
+            while remaining < threshold && threshold > 1 {
+                width -= 1;
+                threshold >>= 1;
+            }
+        }
+        if remaining != 1 || counts.len() > max_symbol + 1 {
+            return Err(corrupt("Invalid FSE table description"));
+        }
+        Ok((Self::build(accuracy_log, &counts)?, bits.bytes_used()))
+    }
+
+    // Spreads the symbols over the states (RFC 8878 Section 4.1.1)
+    fn build(accuracy_log: u8, counts: &[i16]) -> Result<Self, TransformError> {
+        let size = 1usize << accuracy_log;
+        let mut entries = vec![
+            FseEntry {
+                symbol: 0,
+                bits: 0,
+                baseline: 0,
+            };
+            size
+        ];
+        // Symbols with a "less than 1" count take the last states
+        let mut high = size;
+        for (symbol, &count) in counts.iter().enumerate() {
+            if count == -1 {
+                high = high
+                    .checked_sub(1)
+                    .ok_or_else(|| corrupt("FSE table is over-subscribed"))?;
+                entries[high].symbol = symbol as u8;
+            }
+        }
+        let step = (size >> 1) + (size >> 3) + 3;
+        let mut pos = 0;
+        for (symbol, &count) in counts.iter().enumerate() {
+            for _ in 0..count.max(0) {
+                entries[pos].symbol = symbol as u8;
+                pos = (pos + step) & (size - 1);
+                while pos >= high {
+                    pos = (pos + step) & (size - 1);
+                }
+            }
+        }
+        if pos != 0 {
+            return Err(corrupt("FSE counts don't add up to the table size"));
+        }
+
+        let mut next: Vec<u32> = counts.iter().map(|&c| c.unsigned_abs() as u32).collect();
+        for entry in &mut entries {
+            let state = next[usize::from(entry.symbol)];
+            next[usize::from(entry.symbol)] += 1;
+            let bits = accuracy_log - (u32::BITS - 1 - state.leading_zeros()) as u8;
+            entry.bits = bits;
+            entry.baseline = ((state << bits) - size as u32) as u16;
+        }
+        Ok(Self {
+            accuracy_log,
+            entries,
+        })
+    }
+
+    fn predefined((accuracy_log, counts): (u8, &[i16])) -> Self {
+        Self::build(accuracy_log, counts).expect("predefined distributions are valid")
+    }
+
+    // A table that always decodes `symbol` and reads no bits
+    fn rle(symbol: u8) -> Self {
+        Self {
+            accuracy_log: 0,
+            entries: vec![FseEntry {
+                symbol,
+                bits: 0,
+                baseline: 0,
+            }],
+        }
+    }
+
+    fn init(&self, bits: &mut BackwardBits) -> usize {
+        bits.read(self.accuracy_log) as usize
+    }
+
+    fn symbol(&self, state: usize) -> u8 {
+        self.entries[state].symbol
+    }
+
+    fn update(&self, state: usize, bits: &mut BackwardBits) -> usize {
+        let entry = self.entries[state];
+        usize::from(entry.baseline) + bits.read(entry.bits) as usize
+    }
+}
+
+// Literal decoding table indexed by the next `max_bits` bits
+#[derive(Debug, Clone)]
+struct HuffmanTable {
+    max_bits: u8,
+    symbols: Vec<u8>,
+    lengths: Vec<u8>,
+}
+
+impl HuffmanTable {
+    // Reads a tree description (RFC 8878 Section 4.2.1), returning the table
+    // and the bytes it used
+    fn read(bytes: &[u8]) -> Result<(Self, usize), TransformError> {
+        let header = *bytes
+            .first()
+            .ok_or_else(|| corrupt("Missing Huffman tree description"))?;
+        let (weights, used) = if header >= 128 {
+            // Weights stored directly, two per byte
+            let count = usize::from(header - 127);
+            let packed = bytes
+                .get(1..1 + count.div_ceil(2))
+                .ok_or_else(|| corrupt("Truncated Huffman weights"))?;
+            let weights = (0..count)
+                .map(|i| {
+                    let byte = packed[i / 2];
+                    if i % 2 == 0 {
+                        byte >> 4
+                    } else {
+                        byte & 15
+                    }
+                })
+                .collect();
+            (weights, 1 + packed.len())
+        } else {
+            let size = usize::from(header);
+            let data = bytes
+                .get(1..1 + size)
+                .ok_or_else(|| corrupt("Truncated Huffman weights"))?;
+            (Self::fse_weights(data)?, 1 + size)
+        };
+        Ok((Self::from_weights(weights)?, used))
+    }
+
+    // Weights compressed with FSE, decoded by two interleaved states
+    fn fse_weights(data: &[u8]) -> Result<Vec<u8>, TransformError> {
+        let (table, used) = FseTable::read(data, MAX_HUFFMAN_WEIGHT_LOG, 255)?;
+        let mut bits = BackwardBits::new(&data[used..])?;
+        let mut states = [table.init(&mut bits), table.init(&mut bits)];
+        let mut weights = Vec::new();
+        // Once a state update runs past the start, the other state holds the
+        // last weight
+        for i in (0..2).cycle() {
+            if weights.len() > 255 {
+                return Err(corrupt("Too many Huffman weights"));
+            }
+            weights.push(table.symbol(states[i]));
+            states[i] = table.update(states[i], &mut bits);
+            if bits.overflowed() {
+                weights.push(table.symbol(states[1 - i]));
+                break;
+            }
+        }
+        Ok(weights)
+    }
+
+    fn from_weights(mut weights: Vec<u8>) -> Result<Self, TransformError> {
+        if weights.iter().any(|&w| w > MAX_HUFFMAN_BITS) {
+            return Err(corrupt("Huffman weight too large"));
+        }
+        // The last weight is implied by the others adding up to a power of two
+        let total: u32 = weights
+            .iter()
+            .filter(|&&w| w > 0)
+            .map(|&w| 1 << (w - 1))
+            .sum();
+        if total == 0 {
+            return Err(corrupt("Huffman weights are all zero"));
+        }
+        let max_bits = (u32::BITS - total.leading_zeros()) as u8;
+        let left = (1 << max_bits) - total;
+        if max_bits > MAX_HUFFMAN_BITS || !left.is_power_of_two() {
+            return Err(corrupt("Huffman weights don't form a complete tree"));
+        }
+        weights.push(left.trailing_zeros() as u8 + 1);
+
+        // Longest codes first, each symbol filling 2^(weight - 1) entries
+        let size = 1usize << max_bits;
+        let mut symbols = vec![0; size];
+        let mut lengths = vec![0; size];
+        let mut pos = 0;
+        for weight in 1..=max_bits {
+            for (symbol, _) in weights.iter().enumerate().filter(|(_, &w)| w == weight) {
+                let span = 1usize << (weight - 1);
+                symbols[pos..pos + span].fill(symbol as u8);
+                lengths[pos..pos + span].fill(max_bits + 1 - weight);
+                pos += span;
+            }
+        }
+        Ok(Self {
+            max_bits,
+            symbols,
+            lengths,
+        })
+    }
+
+    // Decodes `count` literals from one backward stream
+    fn decode_stream(
+        &self,
+        data: &[u8],
+        count: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), TransformError> {
+        let mut bits = BackwardBits::new(data)?;
+        let mask = (1usize << self.max_bits) - 1;
+        let mut state = bits.read(self.max_bits) as usize;
+        for _ in 0..count {
+            output.push(self.symbols[state]);
+            let length = self.lengths[state];
+            state = ((state << length) & mask) | bits.read(length) as usize;
+        }
+        // Decoding reads `max_bits` ahead of the last code
+        if bits.remaining != -isize::from(self.max_bits) {
+            return Err(corrupt("Huffman stream length doesn't match its literals"));
+        }
+        Ok(())
+    }
+}
+
+struct Sequence {
+    literal_length: usize,
+    match_length: usize,
+    offset_value: usize,
+}
+
+fn decode_sequences(
+    reader: &mut ByteReader,
+    state: &mut FrameState,
+) -> Result<Vec<Sequence>, TransformError> {
+    let first = usize::from(reader.u8()?);
+    let count = match first {
+        0 => return Ok(Vec::new()),
+        1..=127 => first,
+        128..=254 => ((first - 128) << 8) + usize::from(reader.u8()?),
+        _ => reader.uint(2)? as usize + 0x7F00,
+    };
+
+    let modes = reader.u8()?;
+    if modes & 3 != 0 {
+        return Err(corrupt("Reserved bits set in sequence compression modes"));
+    }
+    state.literal_lengths = Some(read_sequence_table(
+        reader,
+        modes >> 6,
+        state.literal_lengths.take(),
+        LITERAL_LENGTH_DEFAULT,
+        MAX_LITERAL_LENGTH_LOG,
+        MAX_LITERAL_LENGTH_CODE,
+    )?);
+    state.offsets = Some(read_sequence_table(
+        reader,
+        (modes >> 4) & 3,
+        state.offsets.take(),
+        OFFSET_DEFAULT,
+        MAX_OFFSET_LOG,
+        MAX_OFFSET_CODE,
+    )?);
+    state.match_lengths = Some(read_sequence_table(
+        reader,
+        (modes >> 2) & 3,
+        state.match_lengths.take(),
+        MATCH_LENGTH_DEFAULT,
+        MAX_MATCH_LENGTH_LOG,
+        MAX_MATCH_LENGTH_CODE,
+    )?);
+    let (Some(literal_lengths), Some(offsets), Some(match_lengths)) =
+        (&state.literal_lengths, &state.offsets, &state.match_lengths)
+    else {
+        unreachable!("all three tables were just set");
+    };
+
+    let mut bits = BackwardBits::new(reader.take(reader.bytes.len() - reader.pos)?)?;
+    let mut literal_state = literal_lengths.init(&mut bits);
+    let mut offset_state = offsets.init(&mut bits);
+    let mut match_state = match_lengths.init(&mut bits);
+
+    let mut sequences = Vec::with_capacity(count.min(MAX_BLOCK_SIZE));
+    for i in 0..count {
+        let offset_code = offsets.symbol(offset_state);
+        let literal_code = usize::from(literal_lengths.symbol(literal_state));
+        let match_code = usize::from(match_lengths.symbol(match_state));
+        if usize::from(offset_code) > MAX_OFFSET_CODE
+            || literal_code > MAX_LITERAL_LENGTH_CODE
+            || match_code > MAX_MATCH_LENGTH_CODE
+        {
+            return Err(corrupt("Invalid sequence code"));
+        }
+
+        let offset_value = (1usize << offset_code) + bits.read(offset_code) as usize;
+        let match_length = match match_code {
+            0..=31 => match_code + 3,
+            _ => {
+                let (base, extra) = MATCH_LENGTH_CODES[match_code - 32];
+                base as usize + bits.read(extra) as usize
+            }
+        };
+        let literal_length = match literal_code {
+            0..=15 => literal_code,
+            _ => {
+                let (base, extra) = LITERAL_LENGTH_CODES[literal_code - 16];
+                base as usize + bits.read(extra) as usize
+            }
+        };
+        sequences.push(Sequence {
+            literal_length,
+            match_length,
+            offset_value,
+        });
+
+        if i + 1 < count {
+            literal_state = literal_lengths.update(literal_state, &mut bits);
+            match_state = match_lengths.update(match_state, &mut bits);
+            offset_state = offsets.update(offset_state, &mut bits);
+        }
+    }
+    if bits.remaining != 0 {
+        return Err(corrupt(
+            "Sequence stream length doesn't match its sequences",
+        ));
+    }
+    Ok(sequences)
+}
+
+// Reads or reuses the table of one sequence code, per its compression mode
+fn read_sequence_table(
+    reader: &mut ByteReader,
+    mode: u8,
+    previous: Option<FseTable>,
+    default: (u8, &[i16]),
+    max_accuracy_log: u8,
+    max_symbol: usize,
+) -> Result<FseTable, TransformError> {
+    match mode {
+        0 => Ok(FseTable::predefined(default)),
+        1 => Ok(FseTable::rle(reader.u8()?)),
+        2 => {
+            let rest = &reader.bytes[reader.pos..];
+            let (table, used) = FseTable::read(rest, max_accuracy_log, max_symbol)?;
+            reader.take(used)?;
+            Ok(table)
+        }
+        _ => previous.ok_or_else(|| corrupt("Repeated sequence table without a previous one")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transformers::base64_encode::base64_encode;
+
+    #[test]
+    fn test_decompress_default_input() {
+        assert_eq!(
+            ZstdDecompress
+                .transform(ZstdDecompress.default_test_input())
+                .unwrap(),
+            "Hello, Zstd World!"
+        );
+        assert_eq!(
+            ZstdDecompress
+                .transform(ZstdDecompress.examples()[0].input)
+                .unwrap(),
+            "Buup transforms text. Buup transforms text. Buup transforms text, again and again and again."
+        );
+    }
+
+    #[test]
+    fn test_decompress_compressed_literals() {
+        // `zstd -19 --no-check` of a short pangram: Huffman-coded literals
+        // and FSE-coded sequences
+        let input = "KLUv/QBoZQIA8gMOEZB9UPpD6Q+tdFtmFsJN7pADAT3FvqmEncb/Rv0BBI3rPZp/qj7/QqfR2eUZHn8YTuKsKCv9kwgGAEkQuIkb8AU9rbd5jBFMPQ==";
+        assert_eq!(
+            ZstdDecompress.transform(input).unwrap(),
+            "The quick brown fox jumps over the lazy dog, then the lazy dog sleeps while the fox keeps jumping."
+        );
+    }
+
+    #[test]
+    fn test_decompress_multiple_frames() {
+        // A frame, a skippable frame, then another frame
+        let first =
+            base64_decode::base64_decode("KLUv/QRYkQAASGVsbG8sIFpzdGQgV29ybGQh4VILvQ==").unwrap();
+        let mut input = first.clone();
+        input.extend_from_slice(&[0x5a, 0x2a, 0x4d, 0x18, 2, 0, 0, 0, 0xaa, 0xbb]);
+        input.extend_from_slice(&first);
+        assert_eq!(
+            ZstdDecompress.transform_bytes(&input).unwrap(),
+            b"Hello, Zstd World!Hello, Zstd World!"
+        );
+    }
+
+    #[test]
+    fn test_decompress_rle_block() {
+        // Single segment frame of 1000 bytes made of one RLE block
+        let mut input = vec![0x28, 0xb5, 0x2f, 0xfd, 0x60, 0xe8, 0x02];
+        let header: u32 = 1 | (u32::from(BLOCK_RLE) << 1) | (1000 << 3);
+        input.extend_from_slice(&header.to_le_bytes()[..3]);
+        input.push(b'z');
+        assert_eq!(
+            ZstdDecompress.transform_bytes(&input).unwrap(),
+            vec![b'z'; 1000]
+        );
+    }
+
+    #[test]
+    fn test_decompress_errors() {
+        let valid =
+            base64_decode::base64_decode("KLUv/QRYkQAASGVsbG8sIFpzdGQgV29ybGQh4VILvQ==").unwrap();
+
+        let mut bad_checksum = valid.clone();
+        *bad_checksum.last_mut().unwrap() ^= 1;
+        let mut bad_magic = valid.clone();
+        bad_magic[0] = 0;
+        let mut dictionary = valid.clone();
+        dictionary[4] |= 1;
+        dictionary.insert(6, 7);
+
+        for (input, message) in [
+            (&valid[..3], "too short"),
+            (&valid[..valid.len() - 2], "Unexpected end"),
+            (&bad_checksum[..], "checksum mismatch"),
+            (&bad_magic[..], "magic number"),
+            (&dictionary[..], "dictionary 7"),
+        ] {
+            let error = ZstdDecompress.transform_bytes(input).unwrap_err();
+            assert!(
+                matches!(&error, TransformError::CompressionError(e) if e.contains(message)),
+                "{}: {}",
+                base64_encode(input),
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_decompress_output_limit() {
+        let mut input = vec![0x28, 0xb5, 0x2f, 0xfd, 0x00, 0x00];
+        let header: u32 = 1 | (u32::from(BLOCK_RLE) << 1) | (100_000 << 3);
+        input.extend_from_slice(&header.to_le_bytes()[..3]);
+        input.push(b'a');
+
+        let mut options = TransformOptions::new();
+        options.set("max_output", "99999");
+        assert!(ZstdDecompress
+            .transform_bytes_with_options(&input, &options)
+            .is_err());
+        options.set("max_output", "100000");
+        assert_eq!(
+            ZstdDecompress
+                .transform_bytes_with_options(&input, &options)
+                .unwrap()
+                .len(),
+            100_000
+        );
+    }
+}
@@ -0,0 +1,542 @@
+//! Argon2id (RFC 9106), version 1.3.
+//!
+//! Lanes are filled one after another rather than on separate threads; the
+//! result is the same since a lane only reads other lanes' finished slices.
+
+use super::blake2b::{Blake2b, MAX_OUTPUT_LEN};
+use crate::transformers::{base64_decode::base64_decode, base64_encode::base64_encode};
+use crate::{CancelToken, TransformError};
+
+/// The Argon2 version implemented (1.3)
+pub const VERSION: u32 = 0x13;
+
+/// Argon2 type number of Argon2id
+const ARGON2ID: u32 = 2;
+
+/// Argon2 memory is made of 1 KiB blocks of 128 words
+const BLOCK_WORDS: usize = 128;
+
+/// Each lane is split into this many slices, synchronised between lanes
+const SYNC_POINTS: usize = 4;
+
+type Block = [u64; BLOCK_WORDS];
+
+/// Cost parameters of an Argon2id hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Memory in KiB, at least 8 per lane
+    pub memory: u32,
+    /// Number of passes over the memory
+    pub iterations: u32,
+    /// Number of lanes
+    pub parallelism: u32,
+    /// Length of the hash in bytes, at least 4
+    pub output_len: usize,
+}
+
+impl Params {
+    /// Checks the parameters against the limits RFC 9106 sets
+    pub fn validate(&self) -> Result<(), TransformError> {
+        let invalid = |message: String| Err(TransformError::InvalidArgument(message.into()));
+        if self.parallelism == 0 || self.parallelism > 0xFF_FFFF {
+            return invalid("Argon2 parallelism must be between 1 and 16777215".to_string());
+        }
+        if self.iterations == 0 {
+            return invalid("Argon2 needs at least one iteration".to_string());
+        }
+        if self.memory < 8 * self.parallelism {
+            return invalid(format!(
+                "Argon2 memory must be at least 8 KiB per lane ({} KiB)",
+                8 * self.parallelism
+            ));
+        }
+        if self.output_len < 4 {
+            return invalid("Argon2 hashes are at least 4 bytes".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Argon2id hash of `password` with `salt`, polling `token` between segments
+pub fn argon2id(
+    password: &[u8],
+    salt: &[u8],
+    params: &Params,
+    token: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    argon2id_keyed(password, salt, &[], &[], params, token)
+}
+
+/// Argon2id with the optional secret key and associated data of RFC 9106
+pub fn argon2id_keyed(
+    password: &[u8],
+    salt: &[u8],
+    secret: &[u8],
+    associated_data: &[u8],
+    params: &Params,
+    token: &CancelToken,
+) -> Result<Vec<u8>, TransformError> {
+    params.validate()?;
+    if salt.len() < 8 {
+        return Err(TransformError::InvalidArgument(
+            "Argon2 salts are at least 8 bytes".into(),
+        ));
+    }
+
+    let mut h0 = Blake2b::new(64);
+    for value in [
+        params.parallelism,
+        params.output_len as u32,
+        params.memory,
+        params.iterations,
+        VERSION,
+        ARGON2ID,
+    ] {
+        h0.update(&value.to_le_bytes());
+    }
+    for input in [password, salt, secret, associated_data] {
+        h0.update(&(input.len() as u32).to_le_bytes());
+        h0.update(input);
+    }
+    let h0 = h0.finalize();
+
+    let lanes = params.parallelism as usize;
+    let segment_len = params.memory as usize / (lanes * SYNC_POINTS);
+    let lane_len = segment_len * SYNC_POINTS;
+    let mut memory = Memory {
+        blocks: vec![[0; BLOCK_WORDS]; lane_len * lanes],
+        lanes,
+        lane_len,
+        segment_len,
+        passes: params.iterations as usize,
+    };
+
+    for lane in 0..lanes {
+        for column in 0..2 {
+            let mut seed = h0.clone();
+            seed.extend_from_slice(&(column as u32).to_le_bytes());
+            seed.extend_from_slice(&(lane as u32).to_le_bytes());
+            let bytes = hash_long(&seed, BLOCK_WORDS * 8);
+            memory.blocks[lane * lane_len + column] = block_from_bytes(&bytes);
+        }
+    }
+
+    for pass in 0..memory.passes {
+        for slice in 0..SYNC_POINTS {
+            for lane in 0..lanes {
+                token.check()?;
+                memory.fill_segment(pass, lane, slice);
+            }
+        }
+    }
+
+    let mut last = memory.blocks[lane_len - 1];
+    for lane in 1..lanes {
+        xor_into(&mut last, &memory.blocks[lane * lane_len + lane_len - 1]);
+    }
+    let bytes: Vec<u8> = last.iter().flat_map(|word| word.to_le_bytes()).collect();
+    Ok(hash_long(&bytes, params.output_len))
+}
+
+/// An Argon2id hash in the PHC string format,
+/// `$argon2id$v=19$m=<memory>,t=<iterations>,p=<parallelism>$<salt>$<hash>`
+/// with the salt and hash in unpadded Base64
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhcHash {
+    pub params: Params,
+    pub salt: Vec<u8>,
+    pub hash: Vec<u8>,
+}
+
+impl PhcHash {
+    /// Parses a PHC string, which must be for Argon2id version 1.3
+    pub fn parse(phc: &str) -> Result<Self, TransformError> {
+        let invalid = |message: &str| {
+            TransformError::InvalidArgument(format!("Invalid Argon2 hash: {}", message).into())
+        };
+        let fields: Vec<&str> = phc.trim().split('$').collect();
+        // The version field is optional, with 1.0 implied when absent
+        let (algorithm, version, params, salt, hash) = match fields.as_slice() {
+            ["", algorithm, version, params, salt, hash] => {
+                (*algorithm, Some(*version), *params, *salt, *hash)
+            }
+            ["", algorithm, params, salt, hash] => (*algorithm, None, *params, *salt, *hash),
+            _ => return Err(invalid("expected $argon2id$v=19$m=..,t=..,p=..$salt$hash")),
+        };
+        if algorithm != "argon2id" {
+            return Err(invalid("only argon2id is supported"));
+        }
+        if version != Some("v=19") {
+            return Err(invalid("only version 19 (1.3) is supported"));
+        }
+
+        let (mut memory, mut iterations, mut parallelism) = (None, None, None);
+        for param in params.split(',') {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or_else(|| invalid("parameters must look like m=19456,t=2,p=1"))?;
+            let value = value
+                .parse::<u32>()
+                .map_err(|_| invalid("parameter values must be numbers"))?;
+            match key {
+                "m" => memory = Some(value),
+                "t" => iterations = Some(value),
+                "p" => parallelism = Some(value),
+                _ => return Err(invalid("unknown parameter")),
+            }
+        }
+        let decode =
+            |field: &str| base64_decode(field).map_err(|_| invalid("salt and hash must be Base64"));
+        let hash = decode(hash)?;
+        let params = Params {
+            memory: memory.ok_or_else(|| invalid("missing m"))?,
+            iterations: iterations.ok_or_else(|| invalid("missing t"))?,
+            parallelism: parallelism.ok_or_else(|| invalid("missing p"))?,
+            output_len: hash.len(),
+        };
+        params.validate()?;
+        Ok(Self {
+            params,
+            salt: decode(salt)?,
+            hash,
+        })
+    }
+
+    /// Whether `password` hashes to this hash
+    pub fn verify(&self, password: &[u8], token: &CancelToken) -> Result<bool, TransformError> {
+        let hash = argon2id(password, &self.salt, &self.params, token)?;
+        // Compare every byte so the time taken doesn't reveal where they differ
+        let difference = hash
+            .iter()
+            .zip(&self.hash)
+            .fold(0, |difference, (a, b)| difference | (a ^ b));
+        Ok(difference == 0)
+    }
+}
+
+impl std::fmt::Display for PhcHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unpadded = |bytes: &[u8]| base64_encode(bytes).trim_end_matches('=').to_string();
+        write!(
+            f,
+            "$argon2id$v={}$m={},t={},p={}${}${}",
+            VERSION,
+            self.params.memory,
+            self.params.iterations,
+            self.params.parallelism,
+            unpadded(&self.salt),
+            unpadded(&self.hash)
+        )
+    }
+}
+
+struct Memory {
+    blocks: Vec<Block>,
+    lanes: usize,
+    lane_len: usize,
+    segment_len: usize,
+    passes: usize,
+}
+
+impl Memory {
+    fn fill_segment(&mut self, pass: usize, lane: usize, slice: usize) {
+        // Argon2id picks reference blocks independently of the data during
+        // the first half pass, and from the data afterwards
+        let data_independent = pass == 0 && slice < SYNC_POINTS / 2;
+        let mut addresses = [0u64; BLOCK_WORDS];
+        let mut address_input = [0u64; BLOCK_WORDS];
+        if data_independent {
+            address_input[..6].copy_from_slice(&[
+                pass as u64,
+                lane as u64,
+                slice as u64,
+                self.blocks.len() as u64,
+                self.passes as u64,
+                u64::from(ARGON2ID),
+            ]);
+        }
+
+        let start = if pass == 0 && slice == 0 { 2 } else { 0 };
+        if data_independent && start == 2 {
+            next_addresses(&mut address_input, &mut addresses);
+        }
+        for index in start..self.segment_len {
+            let column = slice * self.segment_len + index;
+            let current = lane * self.lane_len + column;
+            let previous = if column == 0 {
+                current + self.lane_len - 1
+            } else {
+                current - 1
+            };
+
+            let random = if data_independent {
+                if index % BLOCK_WORDS == 0 {
+                    next_addresses(&mut address_input, &mut addresses);
+                }
+                addresses[index % BLOCK_WORDS]
+            } else {
+                self.blocks[previous][0]
+            };
+            let reference = self.reference_block(pass, lane, slice, index, random);
+
+            let mut block = compress(&self.blocks[previous], &self.blocks[reference]);
+            if pass > 0 {
+                xor_into(&mut block, &self.blocks[current]);
+            }
+            self.blocks[current] = block;
+        }
+    }
+
+    // Maps the pseudo-random word to a block that is already computed
+    fn reference_block(
+        &self,
+        pass: usize,
+        lane: usize,
+        slice: usize,
+        index: usize,
+        random: u64,
+    ) -> usize {
+        let ref_lane = if pass == 0 && slice == 0 {
+            lane
+        } else {
+            ((random >> 32) % self.lanes as u64) as usize
+        };
+        let same_lane = ref_lane == lane;
+
+        // Blocks finished so far that may be referenced: whole slices, plus
+        // the current segment up to the previous block when in the same lane
+        let finished = if pass == 0 {
+            slice * self.segment_len
+        } else {
+            self.lane_len - self.segment_len
+        };
+        let area = if same_lane {
+            finished + index - 1
+        } else if index == 0 {
+            finished - 1
+        } else {
+            finished
+        };
+
+        // Biased towards recent blocks
+        let j1 = random & 0xFFFF_FFFF;
+        let x = (j1 * j1) >> 32;
+        let y = (area as u64 * x) >> 32;
+        let relative = area - 1 - y as usize;
+
+        let start = if pass == 0 || slice == SYNC_POINTS - 1 {
+            0
+        } else {
+            (slice + 1) * self.segment_len
+        };
+        ref_lane * self.lane_len + (start + relative) % self.lane_len
+    }
+}
+
+// Advances the counter and refills the block of data independent addresses
+fn next_addresses(input: &mut Block, addresses: &mut Block) {
+    input[6] += 1;
+    let zero = [0; BLOCK_WORDS];
+    *addresses = compress(&zero, &compress(&zero, input));
+}
+
+// The compression function G
+fn compress(x: &Block, y: &Block) -> Block {
+    let mut r = *x;
+    xor_into(&mut r, y);
+    let mut q = r;
+    // Rows of eight 16-byte registers, then columns
+    for row in 0..8 {
+        let mut indices = [0; 16];
+        for (i, index) in indices.iter_mut().enumerate() {
+            *index = row * 16 + i;
+        }
+        permute(&mut q, &indices);
+    }
+    for column in 0..8 {
+        let mut indices = [0; 16];
+        for (i, index) in indices.iter_mut().enumerate() {
+            *index = (i / 2) * 16 + column * 2 + i % 2;
+        }
+        permute(&mut q, &indices);
+    }
+    xor_into(&mut q, &r);
+    q
+}
+
+// The BLAKE2b round P on sixteen words of the block
+fn permute(block: &mut Block, indices: &[usize; 16]) {
+    let mut v: [u64; 16] = indices.map(|index| block[index]);
+    mix(&mut v, 0, 4, 8, 12);
+    mix(&mut v, 1, 5, 9, 13);
+    mix(&mut v, 2, 6, 10, 14);
+    mix(&mut v, 3, 7, 11, 15);
+    mix(&mut v, 0, 5, 10, 15);
+    mix(&mut v, 1, 6, 11, 12);
+    mix(&mut v, 2, 7, 8, 13);
+    mix(&mut v, 3, 4, 9, 14);
+    for (&index, word) in indices.iter().zip(v) {
+        block[index] = word;
+    }
+}
+
+// BLAKE2b's G with the additions hardened by a multiplication (GB)
+fn mix(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize) {
+    let add = |x: u64, y: u64| {
+        x.wrapping_add(y).wrapping_add(
+            2u64.wrapping_mul(x & 0xFFFF_FFFF)
+                .wrapping_mul(y & 0xFFFF_FFFF),
+        )
+    };
+    v[a] = add(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = add(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = add(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = add(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+fn xor_into(block: &mut Block, other: &Block) {
+    for (word, other) in block.iter_mut().zip(other) {
+        *word ^= other;
+    }
+}
+
+fn block_from_bytes(bytes: &[u8]) -> Block {
+    std::array::from_fn(|i| u64::from_le_bytes(bytes[i * 8..i * 8 + 8].try_into().unwrap()))
+}
+
+// The variable length hash H' built from BLAKE2b
+fn hash_long(input: &[u8], output_len: usize) -> Vec<u8> {
+    let mut prefixed = (output_len as u32).to_le_bytes().to_vec();
+    prefixed.extend_from_slice(input);
+    if output_len <= MAX_OUTPUT_LEN {
+        return Blake2b::digest(output_len, &prefixed);
+    }
+
+    // Chain 64-byte hashes, keeping the first half of each
+    let mut output = Vec::with_capacity(output_len);
+    let mut hash = Blake2b::digest(MAX_OUTPUT_LEN, &prefixed);
+    while output_len - output.len() > MAX_OUTPUT_LEN {
+        output.extend_from_slice(&hash[..MAX_OUTPUT_LEN / 2]);
+        let remaining = output_len - output.len();
+        hash = Blake2b::digest(remaining.min(MAX_OUTPUT_LEN), &hash);
+    }
+    output.extend_from_slice(&hash);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::digest::to_hex;
+
+    #[test]
+    fn test_argon2id_rfc9106_vector() {
+        let params = Params {
+            memory: 32,
+            iterations: 3,
+            parallelism: 4,
+            output_len: 32,
+        };
+        let tag = argon2id_keyed(
+            &[0x01; 32],
+            &[0x02; 16],
+            &[0x03; 8],
+            &[0x04; 12],
+            &params,
+            &CancelToken::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            to_hex(&tag),
+            "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659"
+        );
+    }
+
+    #[test]
+    fn test_argon2id_single_lane() {
+        let params = Params {
+            memory: 1024,
+            iterations: 2,
+            parallelism: 1,
+            output_len: 32,
+        };
+        let tag = argon2id(b"password", b"somesalt", &params, &CancelToken::new()).unwrap();
+        assert_eq!(
+            to_hex(&tag),
+            "ec57ec9c0eaf51eeea2e92ffdcaa9cdee478f1927215b515b7b8d66657f41ed9"
+        );
+    }
+
+    #[test]
+    fn test_argon2id_uneven_memory_and_long_output() {
+        // 100 KiB rounds down to 96 over 3 lanes; 100 bytes chains BLAKE2b
+        let params = Params {
+            memory: 100,
+            iterations: 3,
+            parallelism: 3,
+            output_len: 100,
+        };
+        let tag = argon2id(b"password", b"somesalt", &params, &CancelToken::new()).unwrap();
+        assert_eq!(
+            to_hex(&tag),
+            "d927bda7760c043cdef876bf501ff201a22c97ba963c03759ade2d61692a3cc3\
+             3f543e9392ec75b848c49037a79183eee1b0b291e0896d8c0d4315f7dd8e656b\
+             ba104c556417c84997f019df288f7fdd8b35f7ae6fdcfdddbb7c692f0e143eb7\
+             95962c2e"
+        );
+    }
+
+    #[test]
+    fn test_phc_round_trip() {
+        let phc =
+            "$argon2id$v=19$m=1024,t=2,p=1$c29tZXNhbHQ$7FfsnA6vUe7qLpL/3Kqc3uR48ZJyFbUVt7jWZlf0Htk";
+        let parsed = PhcHash::parse(phc).unwrap();
+        assert_eq!(parsed.salt, b"somesalt");
+        assert_eq!(parsed.params.memory, 1024);
+        assert_eq!(parsed.to_string(), phc);
+
+        let token = CancelToken::new();
+        assert!(parsed.verify(b"password", &token).unwrap());
+        assert!(!parsed.verify(b"Password", &token).unwrap());
+    }
+
+    #[test]
+    fn test_phc_parse_errors() {
+        for phc in [
+            "argon2id",
+            "$argon2i$v=19$m=1024,t=2,p=1$c29tZXNhbHQ$7FfsnA6vUe7qLpL",
+            "$argon2id$v=16$m=1024,t=2,p=1$c29tZXNhbHQ$7FfsnA6vUe7qLpL",
+            "$argon2id$v=19$m=1024,t=2$c29tZXNhbHQ$7FfsnA6vUe7qLpL",
+            "$argon2id$v=19$m=1024,t=2,p=1,x=3$c29tZXNhbHQ$7FfsnA6vUe7qLpL",
+            "$argon2id$v=19$m=1024,t=2,p=1$c29tZXNhbHQ$!!",
+        ] {
+            assert!(PhcHash::parse(phc).is_err(), "{}", phc);
+        }
+    }
+
+    #[test]
+    fn test_argon2id_rejects_bad_params() {
+        let params = Params {
+            memory: 16,
+            iterations: 1,
+            parallelism: 4,
+            output_len: 32,
+        };
+        let token = CancelToken::new();
+        assert!(argon2id(b"password", b"somesalt", &params, &token).is_err());
+        let params = Params {
+            memory: 64,
+            ..params
+        };
+        assert!(argon2id(b"password", b"short", &params, &token).is_err());
+        token.cancel();
+        assert!(matches!(
+            argon2id(b"password", b"somesalt", &params, &token),
+            Err(TransformError::Cancelled)
+        ));
+    }
+}
@@ -0,0 +1,174 @@
+//! BLAKE2b (RFC 7693), the hash inside Argon2.
+//!
+//! Only the unkeyed form is implemented, with any digest length from 1 to 64
+//! bytes. Unlike MD5 and the SHAs it works on 128-byte blocks and pads
+//! with zeros, so it is not a [`Digest`](super::Digest).
+
+const IV: [u64; 8] = [
+    0x6a09_e667_f3bc_c908,
+    0xbb67_ae85_84ca_a73b,
+    0x3c6e_f372_fe94_f82b,
+    0xa54f_f53a_5f1d_36f1,
+    0x510e_527f_ade6_82d1,
+    0x9b05_688c_2b3e_6c1f,
+    0x1f83_d9ab_fb41_bd6b,
+    0x5be0_cd19_137e_2179,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// Size of the blocks BLAKE2b compresses
+const BLOCK_SIZE: usize = 128;
+
+/// Largest digest BLAKE2b produces, in bytes
+pub const MAX_OUTPUT_LEN: usize = 64;
+
+/// Incremental BLAKE2b hasher
+#[derive(Debug, Clone)]
+pub struct Blake2b {
+    h: [u64; 8],
+    block: [u8; BLOCK_SIZE],
+    len: usize,
+    total_len: u128,
+    output_len: usize,
+}
+
+impl Blake2b {
+    /// A hasher producing `output_len` bytes (1 to 64)
+    pub fn new(output_len: usize) -> Self {
+        assert!(
+            (1..=MAX_OUTPUT_LEN).contains(&output_len),
+            "BLAKE2b digests are 1 to 64 bytes"
+        );
+        let mut h = IV;
+        // Parameter block: digest length, no key, fanout and depth of 1
+        h[0] ^= 0x0101_0000 ^ output_len as u64;
+        Self {
+            h,
+            block: [0; BLOCK_SIZE],
+            len: 0,
+            total_len: 0,
+            output_len,
+        }
+    }
+
+    /// Hashes more data
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // The last block is held back: it has to be compressed as final
+            if self.len == BLOCK_SIZE {
+                self.total_len += BLOCK_SIZE as u128;
+                self.compress(false);
+                self.len = 0;
+            }
+            let taken = data.len().min(BLOCK_SIZE - self.len);
+            self.block[self.len..self.len + taken].copy_from_slice(&data[..taken]);
+            self.len += taken;
+            data = &data[taken..];
+        }
+    }
+
+    /// The digest of everything hashed so far
+    pub fn finalize(mut self) -> Vec<u8> {
+        self.total_len += self.len as u128;
+        self.block[self.len..].fill(0);
+        self.compress(true);
+        self.h
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .take(self.output_len)
+            .collect()
+    }
+
+    /// `output_len` byte digest of `data` in one go
+    pub fn digest(output_len: usize, data: &[u8]) -> Vec<u8> {
+        let mut hasher = Self::new(output_len);
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    fn compress(&mut self, last: bool) {
+        let m: [u64; 16] = std::array::from_fn(|i| {
+            u64::from_le_bytes(self.block[i * 8..i * 8 + 8].try_into().unwrap())
+        });
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&self.h);
+        v[8..].copy_from_slice(&IV);
+        v[12] ^= self.total_len as u64;
+        v[13] ^= (self.total_len >> 64) as u64;
+        if last {
+            v[14] = !v[14];
+        }
+
+        for round in 0..12 {
+            let s = &SIGMA[round % 10];
+            mix(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+            mix(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+            mix(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+            mix(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+            mix(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+            mix(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+            mix(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+            mix(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        }
+        for i in 0..8 {
+            self.h[i] ^= v[i] ^ v[i + 8];
+        }
+    }
+}
+
+// The G mixing function
+fn mix(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::digest::to_hex;
+
+    #[test]
+    fn test_blake2b_known_values() {
+        // RFC 7693 appendix A, and the empty string
+        assert_eq!(
+            to_hex(&Blake2b::digest(64, b"abc")),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+        assert_eq!(
+            to_hex(&Blake2b::digest(32, b"")),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_chunked() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        for len in [127, 128, 129, 256, 1000] {
+            let expected = Blake2b::digest(48, &data[..len]);
+            let mut hasher = Blake2b::new(48);
+            for chunk in data[..len].chunks(50) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected, "{} bytes", len);
+        }
+    }
+}
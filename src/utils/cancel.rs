@@ -0,0 +1,106 @@
+//! Cooperative cancellation for long running transforms.
+//!
+//! A [`CancelToken`] is passed to [`Transform::transform_with_cancel`](crate::Transform::transform_with_cancel).
+//! Transformers with expensive loops (LZ77 matching, formatters) poll it
+//! periodically and bail out with [`TransformError::Cancelled`] once it fires,
+//! either because [`CancelToken::cancel`] was called from another thread or
+//! because its deadline passed.
+
+use crate::TransformError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+
+/// Number of loop iterations between two checks in [`CancelToken::check_every`]
+pub const CHECK_INTERVAL: usize = 4096;
+
+/// Shared flag (and optional deadline) used to abort a running transform
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    #[cfg(not(target_arch = "wasm32"))]
+    deadline: Option<Instant>,
+}
+
+impl CancelToken {
+    /// Creates a token that only fires when [`CancelToken::cancel`] is called
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a token that fires automatically once `timeout` has elapsed
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_timeout(timeout: Duration) -> Self {
+        CancelToken {
+            cancelled: Arc::default(),
+            deadline: Instant::now().checked_add(timeout),
+        }
+    }
+
+    /// Requests cancellation; every clone of this token observes it
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if cancellation was requested or the deadline has passed
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(deadline) = self.deadline {
+            return Instant::now() >= deadline;
+        }
+        false
+    }
+
+    /// Returns `Err(TransformError::Cancelled)` if the token has fired
+    pub fn check(&self) -> Result<(), TransformError> {
+        if self.is_cancelled() {
+            Err(TransformError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Like [`CancelToken::check`], but only every [`CHECK_INTERVAL`] iterations
+    /// so it can be called from tight loops
+    #[inline]
+    pub fn check_every(&self, iteration: usize) -> Result<(), TransformError> {
+        if iteration.is_multiple_of(CHECK_INTERVAL) {
+            self.check()
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_is_shared_between_clones() {
+        let token = CancelToken::new();
+        let clone = token.clone();
+        assert!(token.check().is_ok());
+        clone.cancel();
+        assert_eq!(token.check(), Err(TransformError::Cancelled));
+    }
+
+    #[test]
+    fn test_timeout() {
+        assert!(CancelToken::with_timeout(Duration::ZERO).is_cancelled());
+        assert!(!CancelToken::with_timeout(Duration::from_secs(3600)).is_cancelled());
+    }
+
+    #[test]
+    fn test_check_every_skips_between_intervals() {
+        let token = CancelToken::new();
+        token.cancel();
+        assert!(token.check_every(1).is_ok());
+        assert!(token.check_every(CHECK_INTERVAL).is_err());
+    }
+}
@@ -1,5 +1,10 @@
 use crate::TransformError;
 
+mod names;
+mod perceptual;
+
+pub use names::CSS_NAMED_COLORS;
+
 /// Common Color struct for all color transformers
 #[derive(Debug, Clone)]
 pub struct Color {
@@ -10,9 +15,34 @@ pub struct Color {
 }
 
 impl Color {
+    /// Parses a color in any supported notation: hex, `rgb()`, `hsl()`,
+    /// `hsv()`, `cmyk()`, `lab()`, `oklch()` or a CSS color name
+    pub fn parse(input: &str) -> Result<Self, TransformError> {
+        let input = input.trim();
+        let lower = input.to_ascii_lowercase();
+        if input.starts_with('#') {
+            Self::from_hex(input)
+        } else if lower.starts_with("rgb(") {
+            Self::from_rgb(&lower)
+        } else if lower.starts_with("hsl(") {
+            Self::from_hsl(&lower)
+        } else if lower.starts_with("hsv(") || lower.starts_with("hsb(") {
+            Self::from_hsv(&lower)
+        } else if lower.starts_with("cmyk(") {
+            Self::from_cmyk(&lower)
+        } else if lower.starts_with("lab(") {
+            Self::from_lab(&lower)
+        } else if lower.starts_with("oklch(") {
+            Self::from_oklch(&lower)
+        } else {
+            Self::from_name(input)
+        }
+    }
+
     pub fn from_hex(hex: &str) -> Result<Self, TransformError> {
         let hex = hex.trim_start_matches('#');
-        if hex.len() != 6 && hex.len() != 8 {
+        // Checked before slicing by byte, which would split a multibyte char
+        if !hex.is_ascii() || (hex.len() != 6 && hex.len() != 8) {
             return Err(TransformError::InvalidArgument(
                 "Invalid hex color format".into(),
             ));
@@ -104,6 +134,139 @@ impl Color {
         Ok(Color { r, g, b, a })
     }
 
+    /// Parses `hsv(h, s%, v%)` (or the same as `hsb(...)`) with an optional alpha
+    pub fn from_hsv(hsv: &str) -> Result<Self, TransformError> {
+        let hsv = hsv
+            .trim_start_matches("hsv(")
+            .trim_start_matches("hsb(")
+            .trim_end_matches(')');
+        let parts: Vec<&str> = hsv.split(',').map(|s| s.trim()).collect();
+
+        if parts.len() != 3 && parts.len() != 4 {
+            return Err(TransformError::InvalidArgument("Invalid HSV format".into()));
+        }
+
+        let invalid = || TransformError::InvalidArgument("Invalid HSV value".into());
+        let h = parts[0]
+            .trim_end_matches("deg")
+            .parse::<f64>()
+            .map_err(|_| invalid())?;
+        let s = parts[1]
+            .trim_end_matches('%')
+            .parse::<f64>()
+            .map_err(|_| invalid())?
+            / 100.0;
+        let v = parts[2]
+            .trim_end_matches('%')
+            .parse::<f64>()
+            .map_err(|_| invalid())?
+            / 100.0;
+        if !(0.0..=1.0).contains(&s) || !(0.0..=1.0).contains(&v) {
+            return Err(TransformError::InvalidArgument(
+                "HSV saturation and value must be between 0% and 100%".into(),
+            ));
+        }
+        let a = if parts.len() == 4 {
+            Some(
+                (parts[3]
+                    .parse::<f64>()
+                    .map_err(|_| invalid())?
+                    .clamp(0.0, 1.0)
+                    * 255.0)
+                    .round() as u8,
+            )
+        } else {
+            None
+        };
+
+        let (r, g, b) = Self::hsv_to_rgb(h, s, v);
+        Ok(Color { r, g, b, a })
+    }
+
+    /// Parses CSS `lab(L a b)` or `lab(L a b / alpha)`, L being 0 to 100
+    /// (optionally as a percentage). Commas between the values are accepted.
+    pub fn from_lab(lab: &str) -> Result<Self, TransformError> {
+        let (parts, a) = css_components(lab, "lab", "Lab")?;
+        let invalid = || TransformError::InvalidArgument("Invalid Lab value".into());
+        let l = parts[0]
+            .trim_end_matches('%')
+            .parse::<f64>()
+            .map_err(|_| invalid())?;
+        let a_axis = parts[1].parse::<f64>().map_err(|_| invalid())?;
+        let b_axis = parts[2].parse::<f64>().map_err(|_| invalid())?;
+        if !(0.0..=100.0).contains(&l) {
+            return Err(TransformError::InvalidArgument(
+                "Lab lightness must be between 0 and 100".into(),
+            ));
+        }
+
+        let (r, g, b) = perceptual::lab_to_rgb(l, a_axis, b_axis);
+        Ok(Color { r, g, b, a })
+    }
+
+    /// Parses CSS `oklch(L C H)` or `oklch(L C H / alpha)`, L being 0 to 1
+    /// or a percentage. Commas between the values are accepted.
+    pub fn from_oklch(oklch: &str) -> Result<Self, TransformError> {
+        let (parts, a) = css_components(oklch, "oklch", "OKLCH")?;
+        let invalid = || TransformError::InvalidArgument("Invalid OKLCH value".into());
+        let l = match parts[0].strip_suffix('%') {
+            Some(percent) => percent.parse::<f64>().map_err(|_| invalid())? / 100.0,
+            None => parts[0].parse::<f64>().map_err(|_| invalid())?,
+        };
+        let c = parts[1].parse::<f64>().map_err(|_| invalid())?;
+        let h = parts[2]
+            .trim_end_matches("deg")
+            .parse::<f64>()
+            .map_err(|_| invalid())?;
+        if !(0.0..=1.0).contains(&l) || c < 0.0 {
+            return Err(TransformError::InvalidArgument(
+                "OKLCH lightness must be between 0 and 1 (0% to 100%) and chroma positive".into(),
+            ));
+        }
+
+        let (sin, cos) = h.to_radians().sin_cos();
+        let (r, g, b) = perceptual::oklab_to_rgb(l, c * cos, c * sin);
+        Ok(Color { r, g, b, a })
+    }
+
+    /// Looks up a CSS color keyword such as `rebeccapurple`, ignoring case
+    pub fn from_name(name: &str) -> Result<Self, TransformError> {
+        let name = name.trim().to_ascii_lowercase();
+        let index = CSS_NAMED_COLORS
+            .binary_search_by(|(known, _)| (*known).cmp(name.as_str()))
+            .map_err(|_| {
+                TransformError::InvalidArgument(format!("Unknown CSS color name '{}'", name).into())
+            })?;
+        Ok(Self::from_u32(CSS_NAMED_COLORS[index].1))
+    }
+
+    fn from_u32(rgb: u32) -> Self {
+        let [_, r, g, b] = rgb.to_be_bytes();
+        Color { r, g, b, a: None }
+    }
+
+    /// The CSS named color closest to this one, returned with the CIE76 ΔE
+    /// distance between them (their Euclidean distance in CIELAB). Alpha is
+    /// ignored.
+    pub fn nearest_name(&self) -> (&'static str, Color, f64) {
+        let (l, a, b) = perceptual::rgb_to_lab((self.r, self.g, self.b));
+        let distance = |rgb: u32| {
+            let named = Self::from_u32(rgb);
+            let (nl, na, nb) = perceptual::rgb_to_lab((named.r, named.g, named.b));
+            ((l - nl).powi(2) + (a - na).powi(2) + (b - nb).powi(2)).sqrt()
+        };
+        // The first of equally near names wins, e.g. `aqua` over `cyan`
+        let (mut nearest, mut nearest_distance) = (0, distance(CSS_NAMED_COLORS[0].1));
+        for (index, &(_, rgb)) in CSS_NAMED_COLORS.iter().enumerate().skip(1) {
+            let distance = distance(rgb);
+            if distance < nearest_distance {
+                (nearest, nearest_distance) = (index, distance);
+            }
+        }
+        let (name, rgb) = CSS_NAMED_COLORS[nearest];
+        (name, Self::from_u32(rgb), nearest_distance)
+    }
+
     pub fn from_cmyk(cmyk: &str) -> Result<Self, TransformError> {
         let cmyk = cmyk.trim_start_matches("cmyk(").trim_end_matches(')');
         let parts: Vec<&str> = cmyk.split(',').map(|s| s.trim()).collect();
@@ -184,6 +347,57 @@ impl Color {
         }
     }
 
+    pub fn to_hsv(&self) -> String {
+        let (h, s, v) = Self::rgb_to_hsv(self.r, self.g, self.b);
+        if let Some(a) = self.a {
+            format!(
+                "hsv({:.0}deg,{:.0}%,{:.0}%,{:.2})",
+                h,
+                s * 100.0,
+                v * 100.0,
+                a as f64 / 255.0
+            )
+        } else {
+            format!("hsv({:.0}deg,{:.0}%,{:.0}%)", h, s * 100.0, v * 100.0)
+        }
+    }
+
+    pub fn to_lab(&self) -> String {
+        let (l, a, b) = perceptual::rgb_to_lab((self.r, self.g, self.b));
+        format!(
+            "lab({:.2}% {:.2} {:.2}{})",
+            l,
+            zero_if_negligible(a),
+            zero_if_negligible(b),
+            self.css_alpha()
+        )
+    }
+
+    pub fn to_oklch(&self) -> String {
+        let (l, a, b) = perceptual::rgb_to_oklab((self.r, self.g, self.b));
+        let c = a.hypot(b);
+        // Grays have no meaningful hue
+        let h = if c < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+        format!(
+            "oklch({:.2}% {:.4} {:.2}{})",
+            l * 100.0,
+            c,
+            h,
+            self.css_alpha()
+        )
+    }
+
+    // ` / alpha` in the space separated CSS syntax, if there is an alpha
+    fn css_alpha(&self) -> String {
+        self.a
+            .map(|a| format!(" / {:.2}", a as f64 / 255.0))
+            .unwrap_or_default()
+    }
+
     pub fn to_cmyk(&self) -> String {
         let (c, m, y, k) = Self::rgb_to_cmyk(self.r, self.g, self.b);
         if let Some(a) = self.a {
@@ -257,6 +471,51 @@ impl Color {
         (h.rem_euclid(360.0), s, l)
     }
 
+    pub fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
+        let h = h.rem_euclid(360.0);
+        let c = v * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r, g, b) = match (h / 60.0) as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        (
+            ((r + m) * 255.0).round() as u8,
+            ((g + m) * 255.0).round() as u8,
+            ((b + m) * 255.0).round() as u8,
+        )
+    }
+
+    pub fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (f64, f64, f64) {
+        let r = r as f64 / 255.0;
+        let g = g as f64 / 255.0;
+        let b = b as f64 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta)
+        } else if max == g {
+            60.0 * (2.0 + (b - r) / delta)
+        } else {
+            60.0 * (4.0 + (r - g) / delta)
+        };
+
+        (h.rem_euclid(360.0), s, max)
+    }
+
     pub fn rgb_to_cmyk(r: u8, g: u8, b: u8) -> (f64, f64, f64, f64) {
         let r = r as f64 / 255.0;
         let g = g as f64 / 255.0;
@@ -274,3 +533,55 @@ impl Color {
         }
     }
 }
+
+// Splits `name(x y z / alpha)` into its three components and the alpha as a byte
+fn css_components<'a>(
+    input: &'a str,
+    name: &str,
+    label: &str,
+) -> Result<(Vec<&'a str>, Option<u8>), TransformError> {
+    let format_error =
+        || TransformError::InvalidArgument(format!("Invalid {} format", label).into());
+    let inner = input
+        .trim()
+        .strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix('('))
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or_else(format_error)?;
+    let (values, alpha) = match inner.split_once('/') {
+        Some((values, alpha)) => (values, Some(alpha.trim())),
+        None => (inner, None),
+    };
+    let mut parts: Vec<&str> = values
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect();
+    // The comma separated form carries the alpha as a fourth value
+    let alpha = match (alpha, parts.len()) {
+        (None, 4) => parts.pop(),
+        (alpha, 3) => alpha,
+        _ => return Err(format_error()),
+    };
+    let alpha = alpha
+        .map(|alpha| {
+            let value = match alpha.strip_suffix('%') {
+                Some(percent) => percent.parse::<f64>().map(|p| p / 100.0),
+                None => alpha.parse::<f64>(),
+            }
+            .map_err(|_| {
+                TransformError::InvalidArgument(format!("Invalid {} alpha", label).into())
+            })?;
+            Ok::<_, TransformError>((value.clamp(0.0, 1.0) * 255.0).round() as u8)
+        })
+        .transpose()?;
+    Ok((parts, alpha))
+}
+
+// Avoids printing `-0.00` for values that round to zero
+fn zero_if_negligible(value: f64) -> f64 {
+    if value.abs() < 0.005 {
+        0.0
+    } else {
+        value
+    }
+}
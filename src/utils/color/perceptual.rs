@@ -0,0 +1,200 @@
+//! Perceptual color spaces: CIELAB (D50, as CSS `lab()` uses) and OKLab.
+//!
+//! Both start from linear-light sRGB, so gamma encoded channels are
+//! linearized first. Matrices are those of CSS Color Module Level 4.
+
+// CIELAB thresholds, as exact fractions (CIE 15)
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+// D50 reference white
+const WHITE_D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+type Matrix = [[f64; 3]; 3];
+
+// Linear sRGB to CIE XYZ D50 (the D65 matrix followed by Bradford adaptation)
+const SRGB_TO_XYZ_D50: Matrix = [
+    [
+        0.436_065_746_874_269,
+        0.385_151_509_590_160,
+        0.143_078_419_965_139,
+    ],
+    [
+        0.222_493_177_110_565,
+        0.716_887_013_094_482,
+        0.060_619_809_794_952,
+    ],
+    [
+        0.013_923_921_463_169,
+        0.097_081_324_231_410,
+        0.714_099_356_815_881,
+    ],
+];
+const XYZ_D50_TO_SRGB: Matrix = [
+    [
+        3.134_135_852_900_118,
+        -1.617_385_998_018_043,
+        -0.490_662_217_911_098,
+    ],
+    [
+        -0.978_795_476_555_778,
+        1.916_254_377_395_989,
+        0.033_442_873_390_367,
+    ],
+    [
+        0.071_955_392_557_947,
+        -0.228_976_759_815_182,
+        1.405_386_035_113_118,
+    ],
+];
+
+// OKLab (Björn Ottosson): linear sRGB to cone responses, and their cube
+// roots to Lab
+const SRGB_TO_LMS: Matrix = [
+    [0.412_221_470_8, 0.536_332_536_3, 0.051_445_992_9],
+    [0.211_903_498_2, 0.680_699_545_1, 0.107_396_956_6],
+    [0.088_302_461_9, 0.281_718_837_6, 0.629_978_700_5],
+];
+const LMS_TO_OKLAB: Matrix = [
+    [0.210_454_255_3, 0.793_617_785, -0.004_072_046_8],
+    [1.977_998_495_1, -2.428_592_205, 0.450_593_709_9],
+    [0.025_904_037_1, 0.782_771_766_2, -0.808_675_766],
+];
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.396_337_777_4, 0.215_803_757_3],
+    [1.0, -0.105_561_345_8, -0.063_854_172_8],
+    [1.0, -0.089_484_177_5, -1.291_485_548],
+];
+const LMS_TO_SRGB: Matrix = [
+    [4.076_741_662_1, -3.307_711_591_3, 0.230_969_929_2],
+    [-1.268_438_004_6, 2.609_757_401_1, -0.341_319_396_5],
+    [-0.004_196_086_3, -0.703_418_614_7, 1.707_614_701],
+];
+
+fn multiply(m: &Matrix, v: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+/// Removes the sRGB transfer function from a channel in `0..=1`
+pub fn linearize(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Applies the sRGB transfer function to a linear channel
+pub fn delinearize(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn to_linear(rgb: (u8, u8, u8)) -> [f64; 3] {
+    [rgb.0, rgb.1, rgb.2].map(|c| linearize(f64::from(c) / 255.0))
+}
+
+// Colors outside the sRGB gamut are clipped channel by channel
+fn from_linear(linear: [f64; 3]) -> (u8, u8, u8) {
+    let [r, g, b] = linear.map(|c| (delinearize(c).clamp(0.0, 1.0) * 255.0).round() as u8);
+    (r, g, b)
+}
+
+/// sRGB to CIELAB `(L, a, b)`, with L from 0 to 100
+pub fn rgb_to_lab(rgb: (u8, u8, u8)) -> (f64, f64, f64) {
+    let xyz = multiply(&SRGB_TO_XYZ_D50, to_linear(rgb));
+    let [fx, fy, fz] = [0, 1, 2].map(|i| {
+        let t = xyz[i] / WHITE_D50[i];
+        if t > EPSILON {
+            t.cbrt()
+        } else {
+            (KAPPA * t + 16.0) / 116.0
+        }
+    });
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// CIELAB to sRGB
+pub fn lab_to_rgb(l: f64, a: f64, b: f64) -> (u8, u8, u8) {
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let inverse = |f: f64| {
+        if f.powi(3) > EPSILON {
+            f.powi(3)
+        } else {
+            (116.0 * f - 16.0) / KAPPA
+        }
+    };
+    let y = if l > KAPPA * EPSILON {
+        fy.powi(3)
+    } else {
+        l / KAPPA
+    };
+    let xyz = [inverse(fx) * WHITE_D50[0], y, inverse(fz) * WHITE_D50[2]];
+    from_linear(multiply(&XYZ_D50_TO_SRGB, xyz))
+}
+
+/// sRGB to OKLab `(L, a, b)`, with L from 0 to 1
+pub fn rgb_to_oklab(rgb: (u8, u8, u8)) -> (f64, f64, f64) {
+    let lms = multiply(&SRGB_TO_LMS, to_linear(rgb)).map(f64::cbrt);
+    let [l, a, b] = multiply(&LMS_TO_OKLAB, lms);
+    (l, a, b)
+}
+
+/// OKLab to sRGB
+pub fn oklab_to_rgb(l: f64, a: f64, b: f64) -> (u8, u8, u8) {
+    let lms = multiply(&OKLAB_TO_LMS, [l, a, b]).map(|c| c.powi(3));
+    from_linear(multiply(&LMS_TO_SRGB, lms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: (f64, f64, f64), expected: (f64, f64, f64)) {
+        let close = |x: f64, y: f64| (x - y).abs() < 0.01;
+        assert!(
+            close(actual.0, expected.0)
+                && close(actual.1, expected.1)
+                && close(actual.2, expected.2),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_lab_reference_values() {
+        // Values from the CSS Color 4 conversion code
+        assert_close(rgb_to_lab((255, 255, 255)), (100.0, 0.0, 0.0));
+        assert_close(rgb_to_lab((0, 0, 0)), (0.0, 0.0, 0.0));
+        assert_close(rgb_to_lab((255, 0, 0)), (54.29, 80.80, 69.89));
+        assert_close(rgb_to_lab((0, 0, 255)), (29.57, 68.29, -112.03));
+    }
+
+    #[test]
+    fn test_oklab_reference_values() {
+        assert_close(rgb_to_oklab((255, 255, 255)), (1.0, 0.0, 0.0));
+        assert_close(rgb_to_oklab((255, 0, 0)), (0.628, 0.2249, 0.1258));
+    }
+
+    #[test]
+    fn test_round_trips() {
+        for rgb in [
+            (0, 0, 0),
+            (255, 255, 255),
+            (255, 0, 0),
+            (18, 52, 86),
+            (200, 150, 3),
+            (1, 2, 3),
+        ] {
+            let (l, a, b) = rgb_to_lab(rgb);
+            assert_eq!(lab_to_rgb(l, a, b), rgb);
+            let (l, a, b) = rgb_to_oklab(rgb);
+            assert_eq!(oklab_to_rgb(l, a, b), rgb);
+        }
+    }
+}
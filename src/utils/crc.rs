@@ -0,0 +1,275 @@
+//! Table-driven CRCs of any width from 8 to 64 bits.
+//!
+//! Each [`CrcAlgorithm`] is described by the usual Rocksoft parameters (as
+//! listed in the CRC RevEng catalogue) and gets its lookup table at compile
+//! time through [`Crc::new`]. `crc32` keeps its own dedicated implementation.
+
+/// Parameters of a CRC in the Rocksoft model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrcAlgorithm {
+    /// Catalogue name, e.g. `CRC-16/MODBUS`
+    pub name: &'static str,
+    /// Width in bits (8 to 64)
+    pub width: u32,
+    /// Generator polynomial, without its top bit
+    pub poly: u64,
+    /// Register value before the first byte
+    pub init: u64,
+    /// Whether bytes are processed least significant bit first (and the
+    /// result reflected to match)
+    pub reflect: bool,
+    /// Value XORed into the final register
+    pub xorout: u64,
+    /// CRC of the ASCII string `123456789`
+    pub check: u64,
+}
+
+/// A CRC algorithm with its precomputed lookup table
+#[derive(Debug, Clone)]
+pub struct Crc {
+    pub algorithm: CrcAlgorithm,
+    table: [u64; 256],
+}
+
+impl Crc {
+    /// Builds the lookup table for `algorithm`
+    pub const fn new(algorithm: CrcAlgorithm) -> Self {
+        assert!(algorithm.width >= 8 && algorithm.width <= 64);
+        let mut table = [0u64; 256];
+        let mut i = 0;
+        while i < 256 {
+            table[i] = if algorithm.reflect {
+                let poly = reflect(algorithm.poly, algorithm.width);
+                let mut crc = i as u64;
+                let mut bit = 0;
+                while bit < 8 {
+                    crc = if crc & 1 == 1 {
+                        (crc >> 1) ^ poly
+                    } else {
+                        crc >> 1
+                    };
+                    bit += 1;
+                }
+                crc
+            } else {
+                // Unreflected CRCs run with the register in the top bits
+                let poly = algorithm.poly << (64 - algorithm.width);
+                let mut crc = (i as u64) << 56;
+                let mut bit = 0;
+                while bit < 8 {
+                    crc = if crc >> 63 == 1 {
+                        (crc << 1) ^ poly
+                    } else {
+                        crc << 1
+                    };
+                    bit += 1;
+                }
+                crc
+            };
+            i += 1;
+        }
+        Self { algorithm, table }
+    }
+
+    /// Register value before any data
+    pub fn start(&self) -> u64 {
+        let CrcAlgorithm {
+            width,
+            init,
+            reflect: reflected,
+            ..
+        } = self.algorithm;
+        if reflected {
+            reflect(init, width)
+        } else {
+            init << (64 - width)
+        }
+    }
+
+    /// Feeds `data` into a register obtained from [`Crc::start`]
+    pub fn update(&self, mut crc: u64, data: &[u8]) -> u64 {
+        if self.algorithm.reflect {
+            for &byte in data {
+                crc = self.table[((crc ^ u64::from(byte)) & 0xFF) as usize] ^ (crc >> 8);
+            }
+        } else {
+            for &byte in data {
+                crc = self.table[((crc >> 56) ^ u64::from(byte)) as usize] ^ (crc << 8);
+            }
+        }
+        crc
+    }
+
+    /// The CRC of everything fed into `crc`
+    pub fn finish(&self, crc: u64) -> u64 {
+        let CrcAlgorithm { width, xorout, .. } = self.algorithm;
+        let crc = if self.algorithm.reflect {
+            crc
+        } else {
+            crc >> (64 - width)
+        };
+        (crc ^ xorout) & mask(width)
+    }
+
+    /// The CRC of `data`
+    pub fn checksum(&self, data: &[u8]) -> u64 {
+        self.finish(self.update(self.start(), data))
+    }
+
+    /// The CRC of `data` as zero padded lowercase hex
+    pub fn hex_checksum(&self, data: &[u8]) -> String {
+        format!(
+            "{:0width$x}",
+            self.checksum(data),
+            width = self.algorithm.width.div_ceil(4) as usize
+        )
+    }
+}
+
+const fn mask(width: u32) -> u64 {
+    u64::MAX >> (64 - width)
+}
+
+// Mirrors the low `width` bits of `value`
+const fn reflect(value: u64, width: u32) -> u64 {
+    value.reverse_bits() >> (64 - width)
+}
+
+/// CRC-16/IBM-3740, widely called CRC-16/CCITT-FALSE
+pub static CRC16_CCITT_FALSE: Crc = Crc::new(CrcAlgorithm {
+    name: "CRC-16/CCITT-FALSE",
+    width: 16,
+    poly: 0x1021,
+    init: 0xFFFF,
+    reflect: false,
+    xorout: 0,
+    check: 0x29B1,
+});
+
+/// CRC-16/KERMIT, the reflected CCITT CRC used by Kermit and Bluetooth
+pub static CRC16_KERMIT: Crc = Crc::new(CrcAlgorithm {
+    name: "CRC-16/KERMIT",
+    width: 16,
+    poly: 0x1021,
+    init: 0,
+    reflect: true,
+    xorout: 0,
+    check: 0x2189,
+});
+
+/// CRC-16/XMODEM, the CCITT polynomial with a zero initial value
+pub static CRC16_XMODEM: Crc = Crc::new(CrcAlgorithm {
+    name: "CRC-16/XMODEM",
+    width: 16,
+    poly: 0x1021,
+    init: 0,
+    reflect: false,
+    xorout: 0,
+    check: 0x31C3,
+});
+
+/// CRC-16/MODBUS, which Modbus RTU frames send low byte first
+pub static CRC16_MODBUS: Crc = Crc::new(CrcAlgorithm {
+    name: "CRC-16/MODBUS",
+    width: 16,
+    poly: 0x8005,
+    init: 0xFFFF,
+    reflect: true,
+    xorout: 0,
+    check: 0x4B37,
+});
+
+/// CRC-64/ECMA-182, as used by DLT-1 tapes
+pub static CRC64_ECMA_182: Crc = Crc::new(CrcAlgorithm {
+    name: "CRC-64/ECMA-182",
+    width: 64,
+    poly: 0x42F0_E1EB_A9EA_3693,
+    init: 0,
+    reflect: false,
+    xorout: 0,
+    check: 0x6C40_DF5F_0B49_7347,
+});
+
+/// CRC-64/XZ, the ECMA-182 polynomial as used by xz and 7-Zip
+pub static CRC64_XZ: Crc = Crc::new(CrcAlgorithm {
+    name: "CRC-64/XZ",
+    width: 64,
+    poly: 0x42F0_E1EB_A9EA_3693,
+    init: u64::MAX,
+    reflect: true,
+    xorout: u64::MAX,
+    check: 0x995D_C9BB_DF19_39FA,
+});
+
+/// CRC-64/GO-ISO, with the ISO 3309 (HDLC) polynomial
+pub static CRC64_ISO: Crc = Crc::new(CrcAlgorithm {
+    name: "CRC-64/GO-ISO",
+    width: 64,
+    poly: 0x1B,
+    init: u64::MAX,
+    reflect: true,
+    xorout: u64::MAX,
+    check: 0xB909_56C7_75A4_1001,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::crc32::calculate_crc32;
+
+    #[test]
+    fn test_catalogue_check_values() {
+        for crc in [
+            &CRC16_CCITT_FALSE,
+            &CRC16_KERMIT,
+            &CRC16_XMODEM,
+            &CRC16_MODBUS,
+            &CRC64_ECMA_182,
+            &CRC64_XZ,
+            &CRC64_ISO,
+        ] {
+            assert_eq!(
+                crc.checksum(b"123456789"),
+                crc.algorithm.check,
+                "{}",
+                crc.algorithm.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_crc32_and_odd_widths() {
+        let crc32 = Crc::new(CrcAlgorithm {
+            name: "CRC-32/ISO-HDLC",
+            width: 32,
+            poly: 0x04C1_1DB7,
+            init: 0xFFFF_FFFF,
+            reflect: true,
+            xorout: 0xFFFF_FFFF,
+            check: 0xCBF4_3926,
+        });
+        let data = b"The quick brown fox jumps over the lazy dog";
+        assert_eq!(crc32.checksum(data), u64::from(calculate_crc32(data)));
+
+        let crc8 = Crc::new(CrcAlgorithm {
+            name: "CRC-8/SMBUS",
+            width: 8,
+            poly: 0x07,
+            init: 0,
+            reflect: false,
+            xorout: 0,
+            check: 0xF4,
+        });
+        assert_eq!(crc8.checksum(b"123456789"), 0xF4);
+        assert_eq!(crc8.hex_checksum(b"123456789"), "f4");
+    }
+
+    #[test]
+    fn test_incremental_update() {
+        let crc = &CRC16_MODBUS;
+        let register = crc.update(crc.start(), b"1234");
+        let register = crc.update(register, b"56789");
+        assert_eq!(crc.finish(register), 0x4B37);
+        assert_eq!(CRC16_MODBUS.hex_checksum(b""), "ffff");
+    }
+}
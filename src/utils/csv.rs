@@ -0,0 +1,246 @@
+//! CSV reading and writing shared by the CSV transformers.
+//!
+//! Follows RFC 4180 with any single-character delimiter: fields holding
+//! the delimiter, quotes or line breaks are quoted, and quotes inside them
+//! are doubled. Reading is lenient where the RFC is strict: a quote in the
+//! middle of an unquoted field is kept as text, and `\n` and `\r\n` line
+//! endings are both accepted.
+
+use crate::{CancelToken, TransformError, TransformOption, TransformOptionKind, TransformOptions};
+
+/// Names accepted for delimiters, with the character each stands for
+pub const DELIMITERS: [(&str, char); 4] = [
+    ("comma", ','),
+    ("tab", '\t'),
+    ("semicolon", ';'),
+    ("pipe", '|'),
+];
+
+/// Field separator option shared by the CSV transformers, naming one of
+/// [`DELIMITERS`]
+pub(crate) const DELIMITER_OPTION: TransformOption = TransformOption {
+    key: "delimiter",
+    description: "Field separator",
+    default: "comma",
+    kind: TransformOptionKind::Choice(&["comma", "tab", "semicolon", "pipe"]),
+};
+
+/// Whether the first row names the columns, for the CSV transformers that
+/// can do without a header
+pub(crate) const HEADER_OPTION: TransformOption = TransformOption {
+    key: "header",
+    description: "The first row names the columns",
+    default: "true",
+    kind: TransformOptionKind::Boolean,
+};
+
+/// The delimiter [`DELIMITER_OPTION`] is set to
+pub(crate) fn delimiter(options: &TransformOptions) -> Result<char, TransformError> {
+    delimiter_value(options, &DELIMITER_OPTION)
+}
+
+/// The delimiter a delimiter option such as [`DELIMITER_OPTION`] is set to
+pub(crate) fn delimiter_value(
+    options: &TransformOptions,
+    option: &TransformOption,
+) -> Result<char, TransformError> {
+    let name = options.value(option);
+    delimiter_from_name(name).ok_or_else(|| {
+        TransformError::InvalidArgument(
+            format!("Unknown delimiter '{}' for option '{}'", name, option.key).into(),
+        )
+    })
+}
+
+/// The delimiter called `name` in [`DELIMITERS`], also accepting the
+/// character itself
+pub fn delimiter_from_name(name: &str) -> Option<char> {
+    DELIMITERS
+        .iter()
+        .find(|&&(n, c)| n == name || name.chars().eq(std::iter::once(c)))
+        .map(|&(_, c)| c)
+}
+
+/// Splits `input` into records of fields. Lines that are entirely empty
+/// are skipped, and a final line break does not start a record.
+pub fn parse(input: &str, delimiter: char) -> Result<Vec<Vec<String>>, TransformError> {
+    parse_with_cancel(input, delimiter, &CancelToken::new())
+}
+
+/// Like [`parse`], but checks `token` between records
+pub fn parse_with_cancel(
+    input: &str,
+    delimiter: char,
+    token: &CancelToken,
+) -> Result<Vec<Vec<String>>, TransformError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut chars = input.chars().peekable();
+    let mut line = 1;
+    // Whether the current record has any content, to skip blank lines
+    let mut started = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() => {
+                // A quoted field, which may span lines
+                let start_line = line;
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        }
+                        None => {
+                            return Err(TransformError::InvalidArgument(
+                                format!(
+                                    "Unterminated quoted field starting on line {}",
+                                    start_line
+                                )
+                                .into(),
+                            ))
+                        }
+                    }
+                }
+                started = true;
+            }
+            c if c == delimiter => {
+                record.push(std::mem::take(&mut field));
+                started = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                line += 1;
+                if started || !field.is_empty() {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                    token.check_every(records.len())?;
+                }
+                started = false;
+            }
+            c => {
+                field.push(c);
+                started = true;
+            }
+        }
+    }
+    if started || !field.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Appends `field`, quoted if it holds the delimiter, a quote or a line
+/// break
+pub fn write_field(out: &mut String, field: &str, delimiter: char) {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        out.push('"');
+        out.push_str(&field.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(field);
+    }
+}
+
+/// Writes records one per line, without a final line break
+pub fn write<S: AsRef<str>>(records: &[Vec<S>], delimiter: char) -> String {
+    let mut out = String::new();
+    for (i, record) in records.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        for (j, field) in record.iter().enumerate() {
+            if j > 0 {
+                out.push(delimiter);
+            }
+            write_field(&mut out, field.as_ref(), delimiter);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let records = parse(
+            "a,b,c\r\n\"x, y\",\"say \"\"hi\"\"\",\"two\nlines\"\n\n,,\nlast,5\"6,",
+            ',',
+        )
+        .unwrap();
+        assert_eq!(
+            records,
+            vec![
+                vec!["a", "b", "c"],
+                vec!["x, y", "say \"hi\"", "two\nlines"],
+                vec!["", "", ""],
+                vec!["last", "5\"6", ""],
+            ]
+        );
+        assert_eq!(parse("", ',').unwrap(), Vec::<Vec<String>>::new());
+        assert_eq!(parse("a\tb\n", '\t').unwrap(), vec![vec!["a", "b"]]);
+        assert_eq!(parse("\"\"\n", ',').unwrap(), vec![vec![""]]);
+    }
+
+    #[test]
+    fn test_parse_unterminated() {
+        assert_eq!(
+            parse("a,b\n1,\"open\n2,3", ',').unwrap_err().to_string(),
+            "Invalid argument: Unterminated quoted field starting on line 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_cancelled() {
+        let token = CancelToken::new();
+        token.cancel();
+        let input = "a,b\n".repeat(crate::utils::cancel::CHECK_INTERVAL);
+        assert_eq!(
+            parse_with_cancel(&input, ',', &token),
+            Err(TransformError::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let records = vec![
+            vec!["plain", "with;semi", "q\"uote"],
+            vec!["line\nbreak", "", "a,b"],
+        ];
+        assert_eq!(
+            write(&records, ';'),
+            "plain;\"with;semi\";\"q\"\"uote\"\n\"line\nbreak\";;a,b"
+        );
+        assert_eq!(parse(&write(&records, ';'), ';').unwrap(), records);
+    }
+
+    #[test]
+    fn test_delimiter() {
+        assert_eq!(delimiter(&TransformOptions::new()).unwrap(), ',');
+        let options = TransformOptions::from_iter([("delimiter", "pipe")]);
+        assert_eq!(delimiter(&options).unwrap(), '|');
+        let options = TransformOptions::from_iter([("delimiter", "space")]);
+        assert_eq!(
+            delimiter(&options).unwrap_err().to_string(),
+            "Invalid argument: Unknown delimiter 'space' for option 'delimiter'"
+        );
+    }
+
+    #[test]
+    fn test_delimiter_from_name() {
+        assert_eq!(delimiter_from_name("tab"), Some('\t'));
+        assert_eq!(delimiter_from_name(";"), Some(';'));
+        assert_eq!(delimiter_from_name("space"), None);
+    }
+}
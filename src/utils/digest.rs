@@ -0,0 +1,231 @@
+//! Incremental hashing shared by the hash transformers.
+//!
+//! A [`Digest`] is fed data in pieces of any size with [`Digest::update`] and
+//! produces the hash with [`Digest::finalize`], so large inputs can be hashed
+//! as they are read. MD5, SHA-1 and SHA-256 all work on 64-byte blocks with
+//! the same final padding; `BlockBuffer` handles both for them.
+
+use crate::{StreamTransform, TransformError};
+
+/// Size of the blocks MD5, SHA-1 and SHA-256 compress
+pub(crate) const BLOCK_SIZE: usize = 64;
+
+/// A hash function computed incrementally
+pub trait Digest: Default {
+    /// The finished hash, e.g. `[u8; 32]` for SHA-256
+    type Output: AsRef<[u8]>;
+
+    /// Hashes more data
+    fn update(&mut self, data: &[u8]);
+
+    /// Pads the data hashed so far and returns the hash
+    fn finalize(self) -> Self::Output;
+
+    /// Hashes `data` in one go
+    fn digest(data: &[u8]) -> Self::Output {
+        let mut hasher = Self::default();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+/// Hash of `data` as lowercase hex
+pub fn hex_digest<D: Digest>(data: &[u8]) -> String {
+    to_hex(D::digest(data).as_ref())
+}
+
+/// HMAC (RFC 2104) of `message` keyed with `key`
+pub fn hmac<D: Digest>(key: &[u8], message: &[u8]) -> D::Output {
+    // Keys longer than a block are hashed first, shorter ones zero padded
+    let mut block_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        let hashed = D::digest(key);
+        block_key[..hashed.as_ref().len()].copy_from_slice(hashed.as_ref());
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = D::default();
+    inner.update(&block_key.map(|byte| byte ^ 0x36));
+    inner.update(message);
+    let inner_hash = inner.finalize();
+
+    let mut outer = D::default();
+    outer.update(&block_key.map(|byte| byte ^ 0x5c));
+    outer.update(inner_hash.as_ref());
+    outer.finalize()
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        hex.push(DIGITS[(byte >> 4) as usize] as char);
+        hex.push(DIGITS[(byte & 0xf) as usize] as char);
+    }
+    hex
+}
+
+/// Byte order of the message length that ends the padding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LengthOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+/// Cuts data into blocks, holding back a partial block between updates.
+/// Whole blocks are compressed straight from the caller's slice.
+#[derive(Debug, Clone)]
+pub(crate) struct BlockBuffer {
+    block: [u8; BLOCK_SIZE],
+    len: usize,
+    total_len: u64,
+}
+
+impl Default for BlockBuffer {
+    fn default() -> Self {
+        Self {
+            block: [0; BLOCK_SIZE],
+            len: 0,
+            total_len: 0,
+        }
+    }
+}
+
+impl BlockBuffer {
+    pub(crate) fn update(&mut self, mut data: &[u8], mut compress: impl FnMut(&[u8; BLOCK_SIZE])) {
+        self.total_len = self.total_len.wrapping_add(data.len() as u64);
+
+        if self.len > 0 {
+            let taken = data.len().min(BLOCK_SIZE - self.len);
+            self.block[self.len..self.len + taken].copy_from_slice(&data[..taken]);
+            self.len += taken;
+            data = &data[taken..];
+            if self.len < BLOCK_SIZE {
+                return;
+            }
+            compress(&self.block);
+            self.len = 0;
+        }
+
+        let blocks = data.chunks_exact(BLOCK_SIZE);
+        let rest = blocks.remainder();
+        for block in blocks {
+            compress(block.try_into().unwrap());
+        }
+        self.block[..rest.len()].copy_from_slice(rest);
+        self.len = rest.len();
+    }
+
+    /// Appends the '1' bit, zeros, and the message length in bits, then
+    /// compresses the last one or two blocks
+    pub(crate) fn finish(
+        mut self,
+        order: LengthOrder,
+        mut compress: impl FnMut(&[u8; BLOCK_SIZE]),
+    ) {
+        let bit_len = self.total_len.wrapping_mul(8);
+        let length = match order {
+            LengthOrder::BigEndian => bit_len.to_be_bytes(),
+            LengthOrder::LittleEndian => bit_len.to_le_bytes(),
+        };
+
+        self.block[self.len] = 0x80;
+        self.len += 1;
+        if self.len > BLOCK_SIZE - length.len() {
+            self.block[self.len..].fill(0);
+            compress(&self.block);
+            self.len = 0;
+        }
+        self.block[self.len..BLOCK_SIZE - length.len()].fill(0);
+        self.block[BLOCK_SIZE - length.len()..].copy_from_slice(&length);
+        compress(&self.block);
+    }
+}
+
+/// Streams input through a [`Digest`], producing the hex hash at the end
+/// (what the hash transformers output)
+#[derive(Debug, Default)]
+pub(crate) struct HexDigestStream<D>(D);
+
+impl<D: Digest> StreamTransform for HexDigestStream<D> {
+    fn update(&mut self, input: &[u8], _output: &mut Vec<u8>) -> Result<(), TransformError> {
+        self.0.update(input);
+        Ok(())
+    }
+
+    fn finish(&mut self, output: &mut Vec<u8>) -> Result<(), TransformError> {
+        let digest = std::mem::take(&mut self.0).finalize();
+        output.extend_from_slice(to_hex(digest.as_ref()).as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{Md5, Sha1, Sha256};
+
+    // Hashing in uneven pieces must match hashing all at once, including
+    // pieces that straddle block boundaries and the padding cut-off
+    fn check_chunked<D: Digest>() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        for len in [0, 1, 55, 56, 63, 64, 65, 119, 120, 1000] {
+            let expected = hex_digest::<D>(&data[..len]);
+            for chunk_size in [1, 7, 63, 64, 100] {
+                let mut hasher = D::default();
+                for chunk in data[..len].chunks(chunk_size) {
+                    hasher.update(chunk);
+                }
+                assert_eq!(to_hex(hasher.finalize().as_ref()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunked_updates() {
+        check_chunked::<Md5>();
+        check_chunked::<Sha1>();
+        check_chunked::<Sha256>();
+    }
+
+    #[test]
+    fn test_hex_digest() {
+        assert_eq!(hex_digest::<Md5>(b""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(
+            hex_digest::<Sha1>(b"abc"),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hex_digest::<Sha256>(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_hmac() {
+        // RFC 4231 test case 2 and RFC 2202 test case 2
+        let (key, message) = (b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            to_hex(&hmac::<Sha256>(key, message)),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(
+            to_hex(&hmac::<Sha1>(key, message)),
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+        );
+        assert_eq!(
+            to_hex(&hmac::<Md5>(key, message)),
+            "750c783e6ab0b503eaa86e310a5db738"
+        );
+
+        // RFC 4231 test case 6: a key longer than the block size
+        assert_eq!(
+            to_hex(&hmac::<Sha256>(
+                &[0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First"
+            )),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Offset/hex/ASCII dumps of binary data, in the style of `xxd`.
+
+/// Number of bytes shown on each line of a dump
+pub const BYTES_PER_LINE: usize = 16;
+
+/// Renders `bytes` as an `xxd`-style dump, one line per 16 bytes:
+///
+/// ```text
+/// 00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a       Hello, world!.
+/// ```
+///
+/// Bytes outside printable ASCII are shown as `.` in the text column.
+pub fn hexdump(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(BYTES_PER_LINE) * 68);
+    for (line, chunk) in bytes.chunks(BYTES_PER_LINE).enumerate() {
+        out.push_str(&format!("{:08x}:", line * BYTES_PER_LINE));
+        for i in 0..BYTES_PER_LINE {
+            if i % 2 == 0 {
+                out.push(' ');
+            }
+            match chunk.get(i) {
+                Some(byte) => out.push_str(&format!("{:02x}", byte)),
+                None => out.push_str("  "),
+            }
+        }
+        out.push_str("  ");
+        out.extend(chunk.iter().map(|&b| printable(b)));
+        out.push('\n');
+    }
+    out
+}
+
+/// The character shown for `byte` in the text column of a dump
+pub fn printable(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        '.'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hexdump_matches_xxd() {
+        assert_eq!(hexdump(b""), "");
+        assert_eq!(
+            hexdump(b"Hello, world!\n"),
+            "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a       Hello, world!.\n"
+        );
+
+        let bytes: Vec<u8> = (0..=17).collect();
+        assert_eq!(
+            hexdump(&bytes),
+            "00000000: 0001 0203 0405 0607 0809 0a0b 0c0d 0e0f  ................\n\
+             00000010: 1011                                     ..\n"
+        );
+    }
+}
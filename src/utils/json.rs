@@ -0,0 +1,633 @@
+//! Pull tokenizer shared by the JSON transformers.
+//!
+//! [`Tokenizer`] walks its input once and hands out one [`Token`] at a time,
+//! borrowing strings and numbers from the input instead of copying them, so
+//! callers can write their output as they go without building a tree first.
+//! It checks that each token is well formed but not how tokens are arranged;
+//! that is left to the caller.
+//!
+//! Tools that need the whole document parse it into a [`JsonValue`] instead,
+//! which checks the full grammar.
+
+use crate::{CancelToken, TransformError};
+use std::borrow::Cow;
+use std::fmt;
+
+/// Deepest nesting of arrays and objects [`JsonValue::parse`] accepts
+const MAX_DEPTH: usize = 256;
+
+/// A lexical JSON token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    BeginObject,
+    EndObject,
+    BeginArray,
+    EndArray,
+    Colon,
+    Comma,
+    /// Contents between the quotes, escapes still as written (see [`unescape`])
+    String(&'a str),
+    /// Digits, sign, decimal point and exponent as written
+    Number(&'a str),
+    Bool(bool),
+    Null,
+}
+
+/// Reads [`Token`]s from JSON text, skipping the whitespace between them
+#[derive(Debug, Clone)]
+pub struct Tokenizer<'a> {
+    input: &'a str,
+    pos: usize,
+    // Where the last token started, for errors about it
+    start: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            pos: 0,
+            start: 0,
+        }
+    }
+
+    /// The next token, or `None` once only whitespace is left
+    pub fn next_token(&mut self) -> Result<Option<Token<'a>>, TransformError> {
+        let bytes = self.input.as_bytes();
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = bytes.get(self.pos) {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        self.start = start;
+        let Some(&byte) = bytes.get(start) else {
+            return Ok(None);
+        };
+        self.pos += 1;
+
+        let token = match byte {
+            b'{' => Token::BeginObject,
+            b'}' => Token::EndObject,
+            b'[' => Token::BeginArray,
+            b']' => Token::EndArray,
+            b':' => Token::Colon,
+            b',' => Token::Comma,
+            b'"' => Token::String(self.string(start)?),
+            b'-' | b'0'..=b'9' => {
+                while let Some(b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-') = bytes.get(self.pos)
+                {
+                    self.pos += 1;
+                }
+                Token::Number(&self.input[start..self.pos])
+            }
+            b't' => self.literal(start, "true", Token::Bool(true))?,
+            b'f' => self.literal(start, "false", Token::Bool(false))?,
+            b'n' => self.literal(start, "null", Token::Null)?,
+            _ => {
+                let c = self.input[start..].chars().next().unwrap_or_default();
+                return Err(self.error(&format!("Invalid character '{}'", c), start));
+            }
+        };
+        Ok(Some(token))
+    }
+
+    // Finds the closing quote of the string opened at `start`, stepping over
+    // escaped characters
+    fn string(&mut self, start: usize) -> Result<&'a str, TransformError> {
+        let bytes = self.input.as_bytes();
+        let mut end = self.pos;
+        loop {
+            let found = bytes
+                .get(end..)
+                .and_then(|rest| rest.iter().position(|&b| b == b'"' || b == b'\\'));
+            let Some(offset) = found else {
+                return Err(self.error("Unterminated string starting", start));
+            };
+            end += offset;
+            if bytes[end] == b'"' {
+                break;
+            }
+            end += 2;
+        }
+        let contents = &self.input[self.pos..end];
+        self.pos = end + 1;
+        Ok(contents)
+    }
+
+    fn literal(
+        &mut self,
+        start: usize,
+        word: &str,
+        token: Token<'a>,
+    ) -> Result<Token<'a>, TransformError> {
+        if !self.input[start..].starts_with(word) {
+            return Err(self.error("Invalid token", start));
+        }
+        self.pos = start + word.len();
+        Ok(token)
+    }
+
+    // An error about the last token read
+    fn token_error(&self, message: &str) -> TransformError {
+        self.error(message, self.start)
+    }
+
+    // Positions in messages count characters from 1, like an editor column
+    fn error(&self, message: &str, byte_pos: usize) -> TransformError {
+        let position = self.input[..byte_pos].chars().count() + 1;
+        TransformError::JsonParseError(format!("{} at position {}", message, position))
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Token<'a>, TransformError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token().transpose()
+    }
+}
+
+/// A parsed JSON document. Numbers keep their text and objects their key
+/// order, so a value is written back out (by `Display`) as it was read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Parses a complete JSON document, rejecting anything after it
+    pub fn parse(input: &str) -> Result<Self, TransformError> {
+        Self::parse_with_cancel(input, &CancelToken::new())
+    }
+
+    /// Like [`JsonValue::parse`], but checks `cancel` as values are read
+    pub fn parse_with_cancel(input: &str, cancel: &CancelToken) -> Result<Self, TransformError> {
+        let mut tokens = Tokenizer::new(input);
+        let first = expect_token(&mut tokens)?;
+        let value = parse_value(&mut tokens, first, 0, &mut 0, cancel)?;
+        match tokens.next_token()? {
+            None => Ok(value),
+            Some(_) => Err(tokens.token_error("Unexpected data after the JSON value")),
+        }
+    }
+
+    /// The value of `key` if this is an object containing it
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Serializes over several lines, `indent` spaces per level. Empty
+    /// arrays and objects stay on one line as `[]` and `{}`.
+    pub fn to_pretty_string(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, indent, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize, level: usize) {
+        let newline = |out: &mut String, level: usize| {
+            out.push('\n');
+            out.extend(std::iter::repeat_n(' ', indent * level));
+        };
+        match self {
+            JsonValue::Array(items) if !items.is_empty() => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    newline(out, level + 1);
+                    item.write_pretty(out, indent, level + 1);
+                }
+                newline(out, level);
+                out.push(']');
+            }
+            JsonValue::Object(entries) if !entries.is_empty() => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    newline(out, level + 1);
+                    out.push('"');
+                    push_escaped(out, key);
+                    out.push_str("\": ");
+                    value.write_pretty(out, indent, level + 1);
+                }
+                newline(out, level);
+                out.push('}');
+            }
+            value => out.push_str(&value.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for JsonValue {
+    // Compact serialization on a single line
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => f.write_str("null"),
+            JsonValue::Bool(b) => write!(f, "{}", b),
+            JsonValue::Number(n) => f.write_str(n),
+            JsonValue::String(s) => write_string(f, s),
+            JsonValue::Array(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                f.write_str("]")
+            }
+            JsonValue::Object(entries) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    push_escaped(&mut escaped, value);
+    escaped.push('"');
+    f.write_str(&escaped)
+}
+
+fn expect_token<'a>(tokens: &mut Tokenizer<'a>) -> Result<Token<'a>, TransformError> {
+    tokens
+        .next_token()?
+        .ok_or_else(|| TransformError::JsonParseError("Unexpected end of input".into()))
+}
+
+// The value starting with `token`, reading the rest of it from `tokens`.
+// `values` counts the values read so far, to poll `cancel` every so often.
+fn parse_value(
+    tokens: &mut Tokenizer,
+    token: Token,
+    depth: usize,
+    values: &mut usize,
+    cancel: &CancelToken,
+) -> Result<JsonValue, TransformError> {
+    cancel.check_every(*values)?;
+    *values += 1;
+    if matches!(token, Token::BeginArray | Token::BeginObject) && depth >= MAX_DEPTH {
+        return Err(tokens.token_error("Nesting too deep"));
+    }
+
+    Ok(match token {
+        Token::Null => JsonValue::Null,
+        Token::Bool(b) => JsonValue::Bool(b),
+        Token::Number(n) if is_number(n) => JsonValue::Number(n.to_string()),
+        Token::Number(_) => return Err(tokens.token_error("Invalid number")),
+        Token::String(raw) => JsonValue::String(string_value(tokens, raw)?),
+        Token::BeginArray => {
+            let mut items = Vec::new();
+            let mut token = expect_token(tokens)?;
+            if token != Token::EndArray {
+                loop {
+                    items.push(parse_value(tokens, token, depth + 1, values, cancel)?);
+                    match expect_token(tokens)? {
+                        Token::Comma => token = expect_token(tokens)?,
+                        Token::EndArray => break,
+                        _ => return Err(tokens.token_error("Expected ',' or ']'")),
+                    }
+                }
+            }
+            JsonValue::Array(items)
+        }
+        Token::BeginObject => {
+            let mut entries = Vec::new();
+            let mut token = expect_token(tokens)?;
+            if token != Token::EndObject {
+                loop {
+                    let Token::String(raw) = token else {
+                        return Err(tokens.token_error("Expected a string key"));
+                    };
+                    let key = string_value(tokens, raw)?;
+                    if expect_token(tokens)? != Token::Colon {
+                        return Err(tokens.token_error("Expected ':'"));
+                    }
+                    let first = expect_token(tokens)?;
+                    entries.push((key, parse_value(tokens, first, depth + 1, values, cancel)?));
+                    match expect_token(tokens)? {
+                        Token::Comma => token = expect_token(tokens)?,
+                        Token::EndObject => break,
+                        _ => return Err(tokens.token_error("Expected ',' or '}'")),
+                    }
+                }
+            }
+            JsonValue::Object(entries)
+        }
+        Token::EndObject | Token::EndArray | Token::Colon | Token::Comma => {
+            return Err(tokens.token_error("Expected a value"))
+        }
+    })
+}
+
+fn string_value(tokens: &Tokenizer, raw: &str) -> Result<String, TransformError> {
+    if raw.bytes().any(|b| b < 0x20) {
+        return Err(tokens.token_error("Unescaped control character in string"));
+    }
+    Ok(unescape(raw)?.into_owned())
+}
+
+/// Whether `text` is a number as JSON writes them: an optional minus, an
+/// integer without leading zeros, then an optional fraction and exponent
+pub fn is_number(text: &str) -> bool {
+    fn digits(bytes: &[u8]) -> usize {
+        bytes.iter().take_while(|b| b.is_ascii_digit()).count()
+    }
+
+    let bytes = text.strip_prefix('-').unwrap_or(text).as_bytes();
+    let int = digits(bytes);
+    if int == 0 || (int > 1 && bytes[0] == b'0') {
+        return false;
+    }
+    let mut rest = &bytes[int..];
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = digits(fraction);
+        if len == 0 {
+            return false;
+        }
+        rest = &fraction[len..];
+    }
+    if let Some(exponent) = rest.strip_prefix(b"e").or(rest.strip_prefix(b"E")) {
+        let exponent = exponent
+            .strip_prefix(b"+")
+            .or(exponent.strip_prefix(b"-"))
+            .unwrap_or(exponent);
+        let len = digits(exponent);
+        if len == 0 {
+            return false;
+        }
+        rest = &exponent[len..];
+    }
+    rest.is_empty()
+}
+
+/// Decodes the escape sequences in the contents of a [`Token::String`],
+/// borrowing it when there are none
+pub fn unescape(raw: &str) -> Result<Cow<'_, str>, TransformError> {
+    if !raw.contains('\\') {
+        return Ok(Cow::Borrowed(raw));
+    }
+
+    let mut value = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        value.push(match chars.next() {
+            Some(c @ ('"' | '\\' | '/')) => c,
+            Some('b') => '\u{0008}',
+            Some('f') => '\u{000C}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => unicode_escape(&mut chars)?,
+            Some(c) => {
+                return Err(TransformError::JsonParseError(format!(
+                    "Invalid escape sequence: \\{}",
+                    c
+                )))
+            }
+            None => {
+                return Err(TransformError::JsonParseError(
+                    "Unexpected end of escape sequence".into(),
+                ))
+            }
+        });
+    }
+    Ok(Cow::Owned(value))
+}
+
+// The character of a `\uXXXX` escape whose `\u` has been read, combining a
+// UTF-16 surrogate pair written as two escapes
+fn unicode_escape(chars: &mut std::str::Chars) -> Result<char, TransformError> {
+    let invalid = || TransformError::JsonParseError("Invalid unicode escape sequence".into());
+
+    let unit = hex_unit(chars)?;
+    let code = match unit {
+        0xD800..=0xDBFF => {
+            if chars.next() != Some('\\') || chars.next() != Some('u') {
+                return Err(invalid());
+            }
+            let low = hex_unit(chars)?;
+            if !(0xDC00..=0xDFFF).contains(&low) {
+                return Err(invalid());
+            }
+            0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00)
+        }
+        unit => unit,
+    };
+    char::from_u32(code).ok_or_else(invalid)
+}
+
+fn hex_unit(chars: &mut std::str::Chars) -> Result<u32, TransformError> {
+    let mut unit = 0;
+    for _ in 0..4 {
+        let c = chars.next().ok_or_else(|| {
+            TransformError::JsonParseError("Unexpected end of unicode escape sequence".into())
+        })?;
+        let digit = c.to_digit(16).ok_or_else(|| {
+            TransformError::JsonParseError("Invalid unicode escape sequence".into())
+        })?;
+        unit = unit * 16 + digit;
+    }
+    Ok(unit)
+}
+
+/// Replaces smart quotes pasted from documents with regular quotes
+pub(crate) fn normalize_quotes(input: &str) -> Cow<'_, str> {
+    if input.contains(['\u{201C}', '\u{201D}']) {
+        Cow::Owned(input.replace(['\u{201C}', '\u{201D}'], "\""))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Appends `value` escaped as the contents of a JSON string
+pub fn push_escaped(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => {
+                out.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(input: &str) -> Result<Vec<Token<'_>>, TransformError> {
+        Tokenizer::new(input).collect()
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        assert_eq!(
+            tokens(" {\"a\\\"b\": [-1.5e3, true, null]}\n").unwrap(),
+            [
+                Token::BeginObject,
+                Token::String("a\\\"b"),
+                Token::Colon,
+                Token::BeginArray,
+                Token::Number("-1.5e3"),
+                Token::Comma,
+                Token::Bool(true),
+                Token::Comma,
+                Token::Null,
+                Token::EndArray,
+                Token::EndObject,
+            ]
+        );
+        assert!(tokens("  ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_tokenizer_errors() {
+        let err = tokens("[\"é\", tru]").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "JSON parse error: Invalid token at position 7"
+        );
+        let err = tokens("{\"é\": #}").unwrap_err();
+        assert_eq!(err.position(), Some(7));
+        let err = tokens("[\"open\\\"]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unterminated string starting at position 2"));
+    }
+
+    #[test]
+    fn test_json_value_parse() {
+        let value = JsonValue::parse(
+            r#" {"b": [1, -2.5e3, true, null], "a": "x\ny\ud83d\ude00", "b": {}} "#,
+        )
+        .unwrap();
+        assert_eq!(value.get("a").and_then(JsonValue::as_str), Some("x\ny😀"));
+        // Key order and number text survive a round trip
+        assert_eq!(
+            value.to_string(),
+            r#"{"b":[1,-2.5e3,true,null],"a":"x\ny😀","b":{}}"#
+        );
+        assert_eq!(
+            value.get("b"),
+            Some(&JsonValue::Array(vec![
+                JsonValue::Number("1".into()),
+                JsonValue::Number("-2.5e3".into()),
+                JsonValue::Bool(true),
+                JsonValue::Null,
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_json_value_pretty() {
+        let value = JsonValue::parse(r#"{"a":[1,{"b":null}],"c":{},"d":[],"e":"x\"y"}"#).unwrap();
+        assert_eq!(
+            value.to_pretty_string(2),
+            "{\n  \"a\": [\n    1,\n    {\n      \"b\": null\n    }\n  ],\n  \"c\": {},\n  \"d\": [],\n  \"e\": \"x\\\"y\"\n}"
+        );
+        assert_eq!(JsonValue::Bool(true).to_pretty_string(4), "true");
+    }
+
+    #[test]
+    fn test_json_value_errors() {
+        for input in [
+            "",
+            "[1, 2",
+            "{\"a\" 1}",
+            "{1: 2}",
+            "[1,]",
+            "1 2",
+            "tru",
+            "01",
+            "1.",
+            "-",
+            "\"a\tb\"",
+            "\"\\x\"",
+        ] {
+            assert!(
+                JsonValue::parse(input).is_err(),
+                "{:?} should not parse",
+                input
+            );
+        }
+        let err = JsonValue::parse("{\"a\": 1 \"b\": 2}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "JSON parse error: Expected ',' or '}' at position 9"
+        );
+        let deep = "[".repeat(MAX_DEPTH + 1);
+        assert!(JsonValue::parse(&deep)
+            .unwrap_err()
+            .to_string()
+            .contains("Nesting too deep"));
+    }
+
+    #[test]
+    fn test_is_number() {
+        for n in ["0", "-0", "12", "1.5", "1e5", "-1.25E-3", "0.0"] {
+            assert!(is_number(n), "{}", n);
+        }
+        for n in [
+            "", "-", "01", "1.", ".5", "+1", "1e", "1e+", "NaN", "inf", "1-2",
+        ] {
+            assert!(!is_number(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert!(matches!(unescape("plain").unwrap(), Cow::Borrowed("plain")));
+        assert_eq!(unescape(r#"a\"b\\c\/d\n\u263A"#).unwrap(), "a\"b\\c/d\n☺");
+        assert_eq!(unescape(r"\ud83d\ude00").unwrap(), "😀");
+        assert!(unescape(r"\ud83d").is_err());
+        assert!(unescape(r"\u12").is_err());
+        assert!(unescape(r"\x").is_err());
+    }
+
+    #[test]
+    fn test_push_escaped() {
+        let mut out = String::new();
+        push_escaped(&mut out, "a\"b\\\n\u{1}/");
+        assert_eq!(out, r#"a\"b\\\n\u0001/"#);
+    }
+}
@@ -0,0 +1,221 @@
+//! Markdown documents shared by the Markdown and HTML converters.
+//!
+//! [`Document::parse`] reads CommonMark, plus GitHub's tables and
+//! strikethrough, into a tree of [`Block`]s and [`Inline`]s.
+//! [`Document::from_html`] builds the same tree from HTML. Either can be
+//! written out with [`Document::to_html`] or [`Document::to_markdown`], so
+//! converting one way and back keeps the structure. HTML written inside
+//! Markdown is escaped as text rather than passed through.
+
+mod html;
+mod inline;
+mod parse;
+mod render;
+
+/// A parsed document
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    pub blocks: Vec<Block>,
+}
+
+/// A block-level element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    /// `# Title`, level 1 to 6
+    Heading {
+        level: u8,
+        content: Vec<Inline>,
+    },
+    Paragraph(Vec<Inline>),
+    /// Fenced or indented code; `code` ends with a newline unless empty
+    CodeBlock {
+        language: Option<String>,
+        code: String,
+    },
+    Quote(Vec<Block>),
+    List(List),
+    Table(Table),
+    /// `---`
+    Rule,
+}
+
+/// A bullet or numbered list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct List {
+    /// First number of a numbered list, `None` for bullets
+    pub start: Option<u64>,
+    /// No blank lines between the items, so HTML leaves out their `<p>` tags
+    pub tight: bool,
+    pub items: Vec<Vec<Block>>,
+}
+
+/// A GitHub-style table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    /// One per column
+    pub alignments: Vec<Option<Alignment>>,
+    pub header: Vec<Vec<Inline>>,
+    /// Each as long as `header`
+    pub rows: Vec<Vec<Vec<Inline>>>,
+}
+
+/// Text alignment of a table column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// Text and its formatting within a block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inline {
+    Text(String),
+    Code(String),
+    Emphasis(Vec<Inline>),
+    Strong(Vec<Inline>),
+    Strikethrough(Vec<Inline>),
+    Link {
+        url: String,
+        title: Option<String>,
+        content: Vec<Inline>,
+    },
+    Image {
+        url: String,
+        title: Option<String>,
+        alt: String,
+    },
+    /// A line break that is kept in HTML (`<br>`)
+    LineBreak,
+    /// A line break within a paragraph, shown as a space
+    SoftBreak,
+}
+
+impl Document {
+    /// Parses Markdown
+    pub fn parse(markdown: &str) -> Self {
+        Self {
+            blocks: parse::parse(markdown),
+        }
+    }
+
+    /// Reads the structure of an HTML document or fragment. Unknown elements
+    /// are replaced by their contents, and scripts and styles are dropped.
+    pub fn from_html(html: &str) -> Self {
+        Self {
+            blocks: html::parse(html),
+        }
+    }
+
+    /// HTML, one block element per line
+    pub fn to_html(&self) -> String {
+        render::html(&self.blocks)
+    }
+
+    /// Markdown, with blocks separated by blank lines
+    pub fn to_markdown(&self) -> String {
+        render::markdown(&self.blocks)
+    }
+}
+
+// Text of some inlines without their formatting, e.g. for image descriptions
+fn plain_text(inlines: &[Inline]) -> String {
+    let mut text = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text(t) | Inline::Code(t) => text.push_str(t),
+            Inline::Emphasis(content)
+            | Inline::Strong(content)
+            | Inline::Strikethrough(content)
+            | Inline::Link { content, .. } => text.push_str(&plain_text(content)),
+            Inline::Image { alt, .. } => text.push_str(alt),
+            Inline::LineBreak | Inline::SoftBreak => text.push(' '),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(t: &str) -> Inline {
+        Inline::Text(t.to_string())
+    }
+
+    #[test]
+    fn test_parse_nested_lists() {
+        let doc = Document::parse("- one\n  continued\n  1. a\n  2. b\n- two");
+        let Block::List(list) = &doc.blocks[0] else {
+            panic!("expected a list: {:?}", doc.blocks);
+        };
+        assert!(list.tight);
+        assert_eq!(list.start, None);
+        assert_eq!(list.items.len(), 2);
+        assert_eq!(
+            list.items[0][0],
+            Block::Paragraph(vec![text("one"), Inline::SoftBreak, text("continued")])
+        );
+        let Block::List(inner) = &list.items[0][1] else {
+            panic!("expected a nested list: {:?}", list.items[0]);
+        };
+        assert_eq!(inner.start, Some(1));
+        assert_eq!(inner.items.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_reference_links() {
+        let doc = Document::parse(
+            "See [the docs][Docs] and [docs].\n\n[docs]: https://example.com/docs \"Docs\"",
+        );
+        let link = Inline::Link {
+            url: "https://example.com/docs".to_string(),
+            title: Some("Docs".to_string()),
+            content: vec![text("the docs")],
+        };
+        let Block::Paragraph(content) = &doc.blocks[0] else {
+            panic!("expected a paragraph: {:?}", doc.blocks);
+        };
+        assert_eq!(doc.blocks.len(), 1);
+        assert_eq!(content[1], link);
+        assert!(
+            matches!(&content[3], Inline::Link { url, .. } if url == "https://example.com/docs")
+        );
+    }
+
+    #[test]
+    fn test_markdown_round_trip() {
+        let markdown = "# Notes\n\nSome **bold**, *italic* and `code` with a [link](https://example.com \"Title\").\\\nNext line\n\n- one\n  - nested\n- two\n\n3. three\n4. four\n\n> Quoted\n>\n> - list\n\n| Name | Size |\n| :--- | ---: |\n| a \\| b | 1 |\n\n```rust\nfn main() {}\n```\n\n---\n\nEscaped \\*stars\\* and snake_case";
+        let doc = Document::parse(markdown);
+        assert_eq!(doc.to_markdown(), markdown);
+        // And through HTML
+        assert_eq!(Document::from_html(&doc.to_html()), doc);
+    }
+
+    #[test]
+    fn test_html_round_trip() {
+        let html = "<h2>Title</h2>\n<ul>\n<li>\n<p>loose</p>\n</li>\n<li>\n<p>items</p>\n<ol start=\"2\">\n<li>two</li>\n</ol>\n</li>\n</ul>\n<table>\n<thead>\n<tr>\n<th align=\"center\">A</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td align=\"center\">1 &lt; 2</td>\n</tr>\n</tbody>\n</table>\n<p><img src=\"a.png\" alt=\"An image\"> and <del>old</del><br>\nnew</p>\n";
+        let doc = Document::from_html(html);
+        assert_eq!(doc.to_html(), html);
+        assert_eq!(Document::parse(&doc.to_markdown()), doc);
+    }
+
+    #[test]
+    fn test_to_markdown_keeps_ambiguous_lines() {
+        for html in [
+            "<ul>\n<li>\n<hr>\n</li>\n</ul>\n<ul>\n<li>a</li>\n</ul>\n",
+            "<h1>C #</h1>\n<p>a</p>\n<table>\n<thead>\n<tr>\n<th>b</th>\n</tr>\n</thead>\n</table>\n",
+        ] {
+            let doc = Document::from_html(html);
+            assert_eq!(Document::parse(&doc.to_markdown()), doc, "{}", html);
+        }
+    }
+
+    #[test]
+    fn test_from_html_tolerates_sloppy_markup() {
+        let doc = Document::from_html(
+            "<!DOCTYPE html><html><head><title>t</title><style>p{}</style></head><body><div><p>One<p>Two <b>bold</i></b><ul><li>a<li>b</ul></div></body>",
+        );
+        assert_eq!(doc.to_markdown(), "One\n\nTwo **bold**\n\n- a\n- b");
+    }
+}
@@ -0,0 +1,626 @@
+//! Reads HTML into a [`Block`] tree. The markup is first parsed into a small
+//! element tree, forgiving the usual sloppiness (unclosed `<p>` and `<li>`,
+//! stray end tags), and then mapped onto the blocks Markdown can express.
+
+use super::inline::decode_entity;
+use super::{Alignment, Block, Inline, List, Table};
+
+/// Elements that never have content
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose content isn't shown as text
+const HIDDEN_ELEMENTS: [&str; 6] = ["head", "noscript", "script", "style", "template", "title"];
+
+/// Elements that hold blocks rather than text, besides the ones with a
+/// Markdown equivalent. They're replaced by their contents.
+const CONTAINER_ELEMENTS: [&str; 22] = [
+    "address",
+    "article",
+    "aside",
+    "body",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "header",
+    "html",
+    "main",
+    "nav",
+    "section",
+    "summary",
+    "li",
+];
+
+#[derive(Debug)]
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+impl Element {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+}
+
+pub(super) fn parse(html: &str) -> Vec<Block> {
+    let root = parse_elements(html);
+    blocks(&root.children)
+}
+
+fn is_block_element(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "pre"
+            | "blockquote"
+            | "ul"
+            | "ol"
+            | "table"
+            | "hr"
+    ) || CONTAINER_ELEMENTS.contains(&name)
+}
+
+// Builds the element tree under a nameless root
+fn parse_elements(html: &str) -> Element {
+    let mut stack = vec![Element::default()];
+    let mut pos = 0;
+    while pos < html.len() {
+        let rest = &html[pos..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            pos += 4 + comment.find("-->").map_or(comment.len(), |end| end + 3);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            pos += rest.find('>').map_or(rest.len(), |end| end + 1);
+        } else if let Some((name, len)) = end_tag(rest) {
+            close(&mut stack, &name);
+            pos += len;
+        } else if let Some((element, self_closing, len)) = start_tag(rest) {
+            pos += len;
+            let name = element.name.clone();
+            implied_end_tags(&mut stack, &name);
+            if HIDDEN_ELEMENTS.contains(&name.as_str()) && !self_closing {
+                // Skip the contents, which may be code
+                let end = format!("</{}", name);
+                let rest = &html[pos..];
+                pos += find_ignore_case(rest, &end)
+                    .and_then(|start| Some(start + rest[start..].find('>')? + 1))
+                    .unwrap_or(rest.len());
+            } else if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                append(&mut stack, Node::Element(element));
+            } else {
+                stack.push(element);
+            }
+        } else {
+            let len = rest
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '<')
+                .map_or(rest.len(), |(end, _)| end);
+            append(&mut stack, Node::Text(decode_entities(&rest[..len])));
+            pos += len;
+        }
+    }
+    while stack.len() > 1 {
+        close_top(&mut stack);
+    }
+    stack.pop().unwrap_or_default()
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+fn append(stack: &mut [Element], node: Node) {
+    if let Some(parent) = stack.last_mut() {
+        parent.children.push(node);
+    }
+}
+
+fn close_top(stack: &mut Vec<Element>) {
+    if let Some(element) = stack.pop() {
+        append(stack, Node::Element(element));
+    }
+}
+
+// Closes the innermost open `name` and anything opened inside it; an end
+// tag without a matching start tag is ignored
+fn close(stack: &mut Vec<Element>, name: &str) {
+    if let Some(index) = stack.iter().skip(1).rposition(|e| e.name == name) {
+        while stack.len() > index + 1 {
+            close_top(stack);
+        }
+    }
+}
+
+// Elements whose end tag can be left out, like a `<p>` followed by a block
+fn implied_end_tags(stack: &mut Vec<Element>, name: &str) {
+    let closes: &[&str] = match name {
+        "li" => &["li"],
+        "td" | "th" => &["td", "th"],
+        "tr" => &["tr", "td", "th"],
+        "thead" | "tbody" | "tfoot" => &["thead", "tbody", "tfoot", "tr", "td", "th"],
+        "dt" | "dd" => &["dt", "dd"],
+        _ => &[],
+    };
+    // Only within the nearest list or table
+    let boundary = match name {
+        "li" => &["ul", "ol"][..],
+        "dt" | "dd" => &["dl"][..],
+        _ => &["table"][..],
+    };
+    if let Some(index) = stack
+        .iter()
+        .rposition(|e| closes.contains(&e.name.as_str()) || boundary.contains(&e.name.as_str()))
+    {
+        if closes.contains(&stack[index].name.as_str()) {
+            while stack.len() > index {
+                close_top(stack);
+            }
+        }
+    }
+    if is_block_element(name) && stack.last().is_some_and(|e| e.name == "p") {
+        close_top(stack);
+    }
+}
+
+// `</name>`, with its length
+fn end_tag(text: &str) -> Option<(String, usize)> {
+    let rest = text.strip_prefix("</")?;
+    let len = rest.find(|c: char| !c.is_ascii_alphanumeric())?;
+    if len == 0 {
+        return None;
+    }
+    let end = rest.find('>')?;
+    Some((rest[..len].to_ascii_lowercase(), end + 3))
+}
+
+// `<name attr="value">`, whether it ends in `/>`, and its length
+fn start_tag(text: &str) -> Option<(Element, bool, usize)> {
+    let rest = text.strip_prefix('<')?;
+    if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let name_len = rest
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+        .unwrap_or(rest.len());
+    let mut element = Element {
+        name: rest[..name_len].to_ascii_lowercase(),
+        ..Element::default()
+    };
+
+    let mut pos = name_len;
+    loop {
+        let attrs = &rest[pos..];
+        let trimmed = attrs.trim_start();
+        pos += attrs.len() - trimmed.len();
+        if let Some(after) = trimmed.strip_prefix("/>") {
+            return Some((element, true, text.len() - after.len()));
+        }
+        if let Some(after) = trimmed.strip_prefix('>') {
+            return Some((element, false, text.len() - after.len()));
+        }
+        if trimmed.is_empty() {
+            return None;
+        }
+        // An attribute, optionally with a quoted or bare value
+        let key_len = trimmed
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(trimmed.len())
+            .max(1);
+        let key = trimmed[..key_len].to_ascii_lowercase();
+        pos += key_len;
+        let after_key = &rest[pos..];
+        let mut value = String::new();
+        if let Some(after_equals) = after_key.trim_start().strip_prefix('=') {
+            let value_text = after_equals.trim_start();
+            let (raw, len) = match value_text.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let end = value_text[1..].find(quote)?;
+                    (&value_text[1..end + 1], end + 2)
+                }
+                _ => {
+                    let end = value_text
+                        .find(|c: char| c.is_whitespace() || c == '>')
+                        .unwrap_or(value_text.len());
+                    (&value_text[..end], end)
+                }
+            };
+            value = decode_entities(raw);
+            pos = rest.len() - value_text.len() + len;
+        }
+        if key != "/" {
+            element.attributes.push((key, value));
+        }
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        match decode_entity(&rest[start..]) {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[start + len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// Blocks from a mix of block elements and text; loose text becomes paragraphs
+fn blocks(nodes: &[Node]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut pending = Vec::new();
+    for node in nodes {
+        match node {
+            Node::Element(element) if is_block_element(&element.name) => {
+                flush_paragraph(&mut pending, &mut blocks);
+                block(element, &mut blocks);
+            }
+            _ => inline(node, &mut pending),
+        }
+    }
+    flush_paragraph(&mut pending, &mut blocks);
+    blocks
+}
+
+fn flush_paragraph(pending: &mut Vec<Inline>, blocks: &mut Vec<Block>) {
+    let content = tidy(std::mem::take(pending));
+    if !content.is_empty() {
+        blocks.push(Block::Paragraph(content));
+    }
+}
+
+fn block(element: &Element, blocks: &mut Vec<Block>) {
+    match element.name.as_str() {
+        "p" => flush_paragraph(&mut inlines(&element.children), blocks),
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => blocks.push(Block::Heading {
+            level: element.name.as_bytes()[1] - b'0',
+            content: tidy(inlines(&element.children)),
+        }),
+        "pre" => blocks.push(code_block(element)),
+        "blockquote" => blocks.push(Block::Quote(self::blocks(&element.children))),
+        "ul" | "ol" => blocks.push(Block::List(list(element))),
+        "table" => blocks.extend(table(element).map(Block::Table)),
+        "hr" => blocks.push(Block::Rule),
+        _ => blocks.extend(self::blocks(&element.children)),
+    }
+}
+
+fn code_block(pre: &Element) -> Block {
+    // The language is usually a class on the <code> inside, as in
+    // `<code class="language-rust">`
+    let language = std::iter::once(pre)
+        .chain(pre.elements().filter(|e| e.name == "code"))
+        .filter_map(|e| e.attribute("class"))
+        .flat_map(str::split_whitespace)
+        .find_map(|class| class.strip_prefix("language-"))
+        .map(str::to_string);
+    let mut code = text_content(&pre.children);
+    // A newline straight after <pre> is ignored by browsers
+    if code.starts_with('\n') {
+        code.remove(0);
+    }
+    if !code.is_empty() && !code.ends_with('\n') {
+        code.push('\n');
+    }
+    Block::CodeBlock { language, code }
+}
+
+fn list(element: &Element) -> List {
+    let items: Vec<&Element> = element.elements().filter(|e| e.name == "li").collect();
+    let start = (element.name == "ol").then(|| {
+        element
+            .attribute("start")
+            .and_then(|start| start.trim().parse().ok())
+            .unwrap_or(1)
+    });
+    // Paragraph tags in items mean there were blank lines between them
+    let tight = !items
+        .iter()
+        .any(|item| item.elements().any(|child| child.name == "p"));
+    List {
+        start,
+        tight,
+        items: items.iter().map(|item| blocks(&item.children)).collect(),
+    }
+}
+
+fn table(element: &Element) -> Option<Table> {
+    let mut rows: Vec<(&Element, bool)> = Vec::new();
+    for child in element.elements() {
+        match child.name.as_str() {
+            "tr" => rows.push((child, false)),
+            "thead" | "tbody" | "tfoot" => rows.extend(
+                child
+                    .elements()
+                    .filter(|e| e.name == "tr")
+                    .map(|row| (row, child.name == "thead")),
+            ),
+            _ => {}
+        }
+    }
+    // Markdown tables need a header, so the first row is used if there's none
+    let header_rows = rows
+        .iter()
+        .take_while(|(_, in_head)| *in_head)
+        .count()
+        .max(1);
+    let mut rows = rows.into_iter().map(|(row, _)| row);
+    let header_row = rows.next()?;
+
+    let header_cells = cells(header_row);
+    let mut body: Vec<Vec<Vec<Inline>>> = rows
+        .skip(header_rows - 1)
+        .map(|row| {
+            cells(row)
+                .iter()
+                .map(|cell| tidy(inlines(&cell.children)))
+                .collect()
+        })
+        .collect();
+    let columns = body
+        .iter()
+        .map(Vec::len)
+        .chain([header_cells.len()])
+        .max()
+        .unwrap_or(0);
+    let mut header: Vec<Vec<Inline>> = header_cells
+        .iter()
+        .map(|cell| tidy(inlines(&cell.children)))
+        .collect();
+    header.resize(columns, Vec::new());
+    for row in &mut body {
+        row.resize(columns, Vec::new());
+    }
+    let mut alignments: Vec<Option<Alignment>> =
+        header_cells.iter().map(|c| alignment(c)).collect();
+    alignments.resize(columns, None);
+    Some(Table {
+        alignments,
+        header,
+        rows: body,
+    })
+}
+
+fn cells(row: &Element) -> Vec<&Element> {
+    row.elements()
+        .filter(|e| e.name == "td" || e.name == "th")
+        .collect()
+}
+
+// From `align="right"` or `style="text-align: right"`
+fn alignment(cell: &Element) -> Option<Alignment> {
+    let style = cell
+        .attribute("style")
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let value = cell
+        .attribute("align")
+        .map(str::to_ascii_lowercase)
+        .or_else(|| {
+            let (_, rest) = style.split_once("text-align")?;
+            let value = rest.trim_start().strip_prefix(':')?;
+            Some(value.split(';').next()?.trim().to_string())
+        })?;
+    match value.as_str() {
+        "left" => Some(Alignment::Left),
+        "center" => Some(Alignment::Center),
+        "right" => Some(Alignment::Right),
+        _ => None,
+    }
+}
+
+fn inlines(nodes: &[Node]) -> Vec<Inline> {
+    let mut out = Vec::new();
+    for node in nodes {
+        inline(node, &mut out);
+    }
+    out
+}
+
+fn inline(node: &Node, out: &mut Vec<Inline>) {
+    let element = match node {
+        Node::Text(text) => {
+            out.push(Inline::Text(collapse_whitespace(text)));
+            return;
+        }
+        Node::Element(element) => element,
+    };
+    let children = || inlines(&element.children);
+    match element.name.as_str() {
+        "strong" | "b" => out.push(Inline::Strong(children())),
+        "em" | "i" => out.push(Inline::Emphasis(children())),
+        "del" | "s" | "strike" => out.push(Inline::Strikethrough(children())),
+        "code" | "kbd" | "samp" | "tt" => out.push(Inline::Code(collapse_whitespace(
+            &text_content(&element.children),
+        ))),
+        "a" => match element.attribute("href") {
+            Some(url) => out.push(Inline::Link {
+                url: url.to_string(),
+                title: element.attribute("title").map(str::to_string),
+                content: children(),
+            }),
+            None => out.extend(children()),
+        },
+        "img" => out.push(Inline::Image {
+            url: element.attribute("src").unwrap_or_default().to_string(),
+            title: element.attribute("title").map(str::to_string),
+            alt: element.attribute("alt").unwrap_or_default().to_string(),
+        }),
+        "br" => out.push(Inline::LineBreak),
+        // Blocks inside text, like a <div> in a <span>, are kept as text
+        _ => {
+            if is_block_element(&element.name) && !out.is_empty() {
+                out.push(Inline::Text(" ".to_string()));
+            }
+            out.extend(children());
+        }
+    }
+}
+
+// Text as shown in a <pre>, where line breaks count
+fn text_content(nodes: &[Node]) -> String {
+    let mut text = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(t) => text.push_str(t),
+            Node::Element(e) if e.name == "br" => text.push('\n'),
+            Node::Element(e) => text.push_str(&text_content(&e.children)),
+        }
+    }
+    text
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut space = false;
+    for c in text.chars() {
+        if c.is_ascii_whitespace() {
+            space = true;
+        } else {
+            if space {
+                out.push(' ');
+                space = false;
+            }
+            out.push(c);
+        }
+    }
+    if space {
+        out.push(' ');
+    }
+    out
+}
+
+// Merges adjacent text and drops spaces that HTML wouldn't show: at the
+// start and end, twice in a row, and around line breaks
+fn tidy(inlines: Vec<Inline>) -> Vec<Inline> {
+    let mut out = Vec::with_capacity(inlines.len());
+    let mut after_space = true;
+    tidy_into(inlines, &mut out, &mut after_space);
+    trim_end(&mut out);
+    out
+}
+
+fn tidy_into(inlines: Vec<Inline>, out: &mut Vec<Inline>, after_space: &mut bool) {
+    for inline in inlines {
+        let inline = match inline {
+            Inline::Text(text) => {
+                let text = if *after_space {
+                    text.trim_start_matches(' ').to_string()
+                } else {
+                    text
+                };
+                if text.is_empty() {
+                    continue;
+                }
+                *after_space = text.ends_with(' ');
+                if let Some(Inline::Text(last)) = out.last_mut() {
+                    last.push_str(&text);
+                    continue;
+                }
+                Inline::Text(text)
+            }
+            Inline::Emphasis(content) => Inline::Emphasis(tidy_nested(content, after_space)),
+            Inline::Strong(content) => Inline::Strong(tidy_nested(content, after_space)),
+            Inline::Strikethrough(content) => {
+                Inline::Strikethrough(tidy_nested(content, after_space))
+            }
+            Inline::Link {
+                url,
+                title,
+                content,
+            } => Inline::Link {
+                url,
+                title,
+                content: tidy_nested(content, after_space),
+            },
+            Inline::LineBreak => {
+                trim_end(out);
+                *after_space = true;
+                Inline::LineBreak
+            }
+            other => {
+                *after_space = false;
+                other
+            }
+        };
+        out.push(inline);
+    }
+}
+
+fn tidy_nested(content: Vec<Inline>, after_space: &mut bool) -> Vec<Inline> {
+    let mut out = Vec::with_capacity(content.len());
+    tidy_into(content, &mut out, after_space);
+    out
+}
+
+// Drops trailing spaces, and formatting left empty by that
+fn trim_end(inlines: &mut Vec<Inline>) {
+    while let Some(last) = inlines.last_mut() {
+        match last {
+            Inline::Text(text) => {
+                text.truncate(text.trim_end_matches(' ').len());
+                if !text.is_empty() {
+                    return;
+                }
+            }
+            Inline::Emphasis(content)
+            | Inline::Strong(content)
+            | Inline::Strikethrough(content) => {
+                trim_end(content);
+                if !content.is_empty() {
+                    return;
+                }
+            }
+            Inline::Link { content, .. } => {
+                trim_end(content);
+                return;
+            }
+            _ => return,
+        }
+        inlines.pop();
+    }
+}
@@ -0,0 +1,547 @@
+//! Inline Markdown: code spans, emphasis, links and images, escapes and line
+//! breaks. Emphasis uses CommonMark's delimiter algorithm. Runs of `*`, `_` and
+//! `~` are recorded as they are met and paired up once the surrounding text is
+//! known.
+
+use super::parse::References;
+use super::{plain_text, Inline};
+use crate::transformers::html_decode::decode_html_entity;
+use std::borrow::Cow;
+
+/// Longest entity reference looked up, e.g. `&#x1F600;`
+const MAX_ENTITY_LEN: usize = 12;
+
+/// Inline content before emphasis has been resolved
+enum Node {
+    Inline(Inline),
+    /// A run of `*`, `_` or `~`
+    Delimiter {
+        marker: char,
+        count: usize,
+        /// Length of the whole run, for CommonMark's "multiple of 3" rule
+        run: usize,
+        can_open: bool,
+        can_close: bool,
+    },
+    /// `[` or `![`, waiting for its `]`
+    Bracket {
+        image: bool,
+        /// Cleared inside a link, since links can't contain links
+        active: bool,
+        /// Byte offset after the bracket, for the link's label
+        start: usize,
+    },
+}
+
+pub(super) fn parse(text: &str, refs: &References) -> Vec<Inline> {
+    let mut parser = Parser {
+        text,
+        refs,
+        nodes: Vec::new(),
+        pending: String::new(),
+    };
+    parser.run();
+    parser.flush();
+    resolve_emphasis(parser.nodes)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    refs: &'a References,
+    nodes: Vec<Node>,
+    /// Text not yet added to `nodes`
+    pending: String,
+}
+
+impl Parser<'_> {
+    fn run(&mut self) {
+        let text = self.text;
+        let mut pos = 0;
+        while let Some(c) = text[pos..].chars().next() {
+            pos = match c {
+                '\\' => self.escape(pos),
+                '`' => self.code_span(pos),
+                '*' | '_' | '~' => self.delimiter(pos, c),
+                '[' => self.open_bracket(pos, false),
+                '!' if text[pos + 1..].starts_with('[') => self.open_bracket(pos + 1, true),
+                ']' => self.close_bracket(pos),
+                '<' => self.autolink(pos),
+                '&' => self.entity(pos),
+                '\n' => self.line_break(pos),
+                _ => {
+                    self.pending.push(c);
+                    pos + c.len_utf8()
+                }
+            };
+        }
+    }
+
+    fn flush(&mut self) {
+        if !self.pending.is_empty() {
+            let text = std::mem::take(&mut self.pending);
+            self.nodes.push(Node::Inline(Inline::Text(text)));
+        }
+    }
+
+    fn push(&mut self, node: Node) {
+        self.flush();
+        self.nodes.push(node);
+    }
+
+    fn escape(&mut self, pos: usize) -> usize {
+        match self.text[pos + 1..].chars().next() {
+            Some('\n') => {
+                self.push(Node::Inline(Inline::LineBreak));
+                skip_spaces(self.text, pos + 2)
+            }
+            Some(c) if c.is_ascii_punctuation() => {
+                self.pending.push(c);
+                pos + 2
+            }
+            _ => {
+                self.pending.push('\\');
+                pos + 1
+            }
+        }
+    }
+
+    // A code span ends at the next run of exactly as many backticks
+    fn code_span(&mut self, pos: usize) -> usize {
+        let text = self.text;
+        let len = run_length(text, pos, '`');
+        let content_start = pos + len;
+        let mut search = content_start;
+        while let Some(offset) = text[search..].find('`') {
+            let close = search + offset;
+            let close_len = run_length(text, close, '`');
+            if close_len == len {
+                let code = text[content_start..close].replace('\n', " ");
+                let code = match code.strip_prefix(' ').and_then(|c| c.strip_suffix(' ')) {
+                    Some(inner) if !code.trim().is_empty() => inner.to_string(),
+                    _ => code,
+                };
+                self.push(Node::Inline(Inline::Code(code)));
+                return close + len;
+            }
+            search = close + close_len;
+        }
+        self.pending.push_str(&text[pos..content_start]);
+        content_start
+    }
+
+    fn delimiter(&mut self, pos: usize, marker: char) -> usize {
+        let text = self.text;
+        let run = run_length(text, pos, marker);
+        let end = pos + run;
+        // GFM strikethrough is `~` or `~~`
+        if marker == '~' && run > 2 {
+            self.pending.push_str(&text[pos..end]);
+            return end;
+        }
+
+        let before = text[..pos].chars().next_back().unwrap_or(' ');
+        let after = text[end..].chars().next().unwrap_or(' ');
+        let left = !after.is_whitespace()
+            && (!is_punctuation(after) || before.is_whitespace() || is_punctuation(before));
+        let right = !before.is_whitespace()
+            && (!is_punctuation(before) || after.is_whitespace() || is_punctuation(after));
+        // `_` doesn't work within words, so snake_case stays as it is
+        let (can_open, can_close) = if marker == '_' {
+            (
+                left && (!right || is_punctuation(before)),
+                right && (!left || is_punctuation(after)),
+            )
+        } else {
+            (left, right)
+        };
+        self.push(Node::Delimiter {
+            marker,
+            count: run,
+            run,
+            can_open,
+            can_close,
+        });
+        end
+    }
+
+    fn open_bracket(&mut self, pos: usize, image: bool) -> usize {
+        self.push(Node::Bracket {
+            image,
+            active: true,
+            start: pos + 1,
+        });
+        pos + 1
+    }
+
+    fn close_bracket(&mut self, pos: usize) -> usize {
+        self.flush();
+        let opener = self
+            .nodes
+            .iter()
+            .rposition(|node| matches!(node, Node::Bracket { .. }));
+        let Some(opener) = opener else {
+            self.pending.push(']');
+            return pos + 1;
+        };
+        let Node::Bracket {
+            image,
+            active,
+            start,
+        } = self.nodes[opener]
+        else {
+            unreachable!()
+        };
+
+        let target = if active {
+            self.link_target(&self.text[start..pos], &self.text[pos + 1..])
+        } else {
+            None
+        };
+        let Some((url, title, len)) = target else {
+            // Not a link after all
+            self.nodes[opener] = Node::Inline(Inline::Text(if image { "![" } else { "[" }.into()));
+            self.pending.push(']');
+            return pos + 1;
+        };
+
+        let content = resolve_emphasis(self.nodes.drain(opener + 1..).collect());
+        self.nodes.pop();
+        let inline = if image {
+            Inline::Image {
+                url,
+                title,
+                alt: plain_text(&content),
+            }
+        } else {
+            // Links can't contain links
+            for node in &mut self.nodes {
+                if let Node::Bracket {
+                    image: false,
+                    active,
+                    ..
+                } = node
+                {
+                    *active = false;
+                }
+            }
+            Inline::Link {
+                url,
+                title,
+                content,
+            }
+        };
+        self.nodes.push(Node::Inline(inline));
+        pos + 1 + len
+    }
+
+    // `(url "title")`, `[label]`, `[]` or nothing (using the link text as
+    // the label), with the length of what follows the `]`
+    fn link_target(&self, label: &str, rest: &str) -> Option<(String, Option<String>, usize)> {
+        if let Some(inline) = inline_link(rest) {
+            return Some(inline);
+        }
+        let (label, len) = match rest.strip_prefix('[').and_then(|r| Some((r, r.find(']')?))) {
+            Some((_, 0)) => (label, 2),
+            Some((r, end)) => (&r[..end], end + 2),
+            None => (label, 0),
+        };
+        let (url, title) = self.refs.get(&normalize_label(label))?;
+        Some((url.clone(), title.clone(), len))
+    }
+
+    // `<https://example.com>` or `<someone@example.com>`
+    fn autolink(&mut self, pos: usize) -> usize {
+        let text = self.text;
+        let link = text[pos + 1..].find('>').and_then(|end| {
+            let target = &text[pos + 1..pos + 1 + end];
+            if target.is_empty() || target.contains(|c: char| c.is_whitespace() || c == '<') {
+                return None;
+            }
+            let url = if is_uri(target) {
+                target.to_string()
+            } else if target.contains('@') && !target.contains(':') {
+                format!("mailto:{}", target)
+            } else {
+                return None;
+            };
+            Some((url, target, end))
+        });
+        let Some((url, target, end)) = link else {
+            self.pending.push('<');
+            return pos + 1;
+        };
+        self.push(Node::Inline(Inline::Link {
+            url,
+            title: None,
+            content: vec![Inline::Text(target.to_string())],
+        }));
+        pos + end + 2
+    }
+
+    fn entity(&mut self, pos: usize) -> usize {
+        match decode_entity(&self.text[pos..]) {
+            Some((c, len)) => {
+                self.pending.push(c);
+                pos + len
+            }
+            None => {
+                self.pending.push('&');
+                pos + 1
+            }
+        }
+    }
+
+    // Two or more spaces before a line break keep it
+    fn line_break(&mut self, pos: usize) -> usize {
+        let trimmed = self.pending.trim_end_matches(' ').len();
+        let hard = self.pending.len() - trimmed >= 2;
+        self.pending.truncate(trimmed);
+        self.push(Node::Inline(if hard {
+            Inline::LineBreak
+        } else {
+            Inline::SoftBreak
+        }));
+        skip_spaces(self.text, pos + 1)
+    }
+}
+
+fn run_length(text: &str, pos: usize, c: char) -> usize {
+    text[pos..].len() - text[pos..].trim_start_matches(c).len()
+}
+
+fn skip_spaces(text: &str, pos: usize) -> usize {
+    text.len() - text[pos..].trim_start_matches(' ').len()
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || (!c.is_ascii() && !c.is_alphanumeric() && !c.is_whitespace())
+}
+
+// A scheme like `https:` followed by anything
+fn is_uri(text: &str) -> bool {
+    text.split_once(':').is_some_and(|(scheme, _)| {
+        (2..=32).contains(&scheme.len())
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'))
+    })
+}
+
+/// Decodes an entity reference like `&amp;` at the start of `text`, returning
+/// the character and the reference's length
+pub(super) fn decode_entity(text: &str) -> Option<(char, usize)> {
+    let end = text.bytes().take(MAX_ENTITY_LEN).position(|b| b == b';')?;
+    decode_html_entity(&text[..=end]).map(|c| (c, end + 1))
+}
+
+/// Removes backslash escapes and decodes entities, e.g. in link URLs
+pub(super) fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['\\', '&']) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+    while let Some(c) = text[pos..].chars().next() {
+        let next = text[pos + c.len_utf8()..].chars().next();
+        match c {
+            '\\' if next.is_some_and(|n| n.is_ascii_punctuation()) => {
+                out.extend(next);
+                pos += 2;
+            }
+            '&' => match decode_entity(&text[pos..]) {
+                Some((decoded, len)) => {
+                    out.push(decoded);
+                    pos += len;
+                }
+                None => {
+                    out.push('&');
+                    pos += 1;
+                }
+            },
+            _ => {
+                out.push(c);
+                pos += c.len_utf8();
+            }
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Labels match case-insensitively, with runs of whitespace counting as one
+/// space
+pub(super) fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+// `(url "title")` after a link's `]`, with its length
+fn inline_link(rest: &str) -> Option<(String, Option<String>, usize)> {
+    let inner = rest.strip_prefix('(')?;
+    let after_space = inner.trim_start();
+    if let Some(after) = after_space.strip_prefix(')') {
+        return Some((String::new(), None, rest.len() - after.len()));
+    }
+    let (url, after_url) = link_destination(after_space)?;
+    let after_url_trimmed = after_url.trim_start();
+    let (title, after_title) = if after_url_trimmed.len() < after_url.len() {
+        link_title(after_url_trimmed).unwrap_or((String::new(), after_url_trimmed))
+    } else {
+        (String::new(), after_url)
+    };
+    let after = after_title.trim_start().strip_prefix(')')?;
+    let title = (!title.is_empty()).then_some(title);
+    Some((url, title, rest.len() - after.len()))
+}
+
+/// A link URL, `<in angle brackets>` or up to the first space, with the text
+/// after it
+pub(super) fn link_destination(text: &str) -> Option<(String, &str)> {
+    if let Some(inner) = text.strip_prefix('<') {
+        let end = inner.find(['>', '\n', '<'])?;
+        if !inner[end..].starts_with('>') {
+            return None;
+        }
+        return Some((unescape(&inner[..end]).into_owned(), &inner[end + 1..]));
+    }
+    // Parentheses are allowed if they balance
+    let mut depth = 0;
+    let mut end = text.len();
+    let mut chars = text.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                end = i;
+                break;
+            }
+            ')' => depth -= 1,
+            c if c.is_whitespace() || c.is_control() => {
+                end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    (end > 0).then(|| (unescape(&text[..end]).into_owned(), &text[end..]))
+}
+
+/// A link title in `"quotes"`, `'quotes'` or `(parentheses)`, with the text
+/// after it
+pub(super) fn link_title(text: &str) -> Option<(String, &str)> {
+    let close = match text.chars().next()? {
+        '"' => '"',
+        '\'' => '\'',
+        '(' => ')',
+        _ => return None,
+    };
+    let mut chars = text.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if c == close {
+            return Some((unescape(&text[1..i]).into_owned(), &text[i + 1..]));
+        }
+    }
+    None
+}
+
+// Pairs up emphasis delimiters: each closer, left to right, takes the nearest
+// opener of the same kind before it
+fn resolve_emphasis(mut nodes: Vec<Node>) -> Vec<Inline> {
+    let mut closer = 0;
+    while closer < nodes.len() {
+        let Node::Delimiter {
+            marker,
+            count,
+            run,
+            can_open,
+            can_close: true,
+        } = nodes[closer]
+        else {
+            closer += 1;
+            continue;
+        };
+        let opener = (0..closer).rev().find(|&i| match nodes[i] {
+            Node::Delimiter {
+                marker: m,
+                count: opener_count,
+                run: opener_run,
+                can_open: true,
+                can_close: opener_can_close,
+            } if m == marker => {
+                if marker == '~' {
+                    return opener_count == count;
+                }
+                // A run that can both open and close doesn't pair with one
+                // making a multiple of 3 (so `*a**b*` isn't `<em>a</em><em>b`)
+                let both = can_open || opener_can_close;
+                !(both && (run + opener_run) % 3 == 0 && !(run % 3 == 0 && opener_run % 3 == 0))
+            }
+            _ => false,
+        });
+        let Some(opener) = opener else {
+            closer += 1;
+            continue;
+        };
+
+        let Node::Delimiter {
+            count: opener_count,
+            ..
+        } = nodes[opener]
+        else {
+            unreachable!()
+        };
+        let used = if marker == '~' || (count >= 2 && opener_count >= 2) {
+            count.min(2)
+        } else {
+            1
+        };
+        let content = finish(nodes.drain(opener + 1..closer).collect());
+        let inline = match (marker, used) {
+            ('~', _) => Inline::Strikethrough(content),
+            (_, 2) => Inline::Strong(content),
+            _ => Inline::Emphasis(content),
+        };
+        nodes.insert(opener + 1, Node::Inline(inline));
+
+        closer = opener + 2;
+        for index in [closer, opener] {
+            if let Node::Delimiter { count, .. } = &mut nodes[index] {
+                *count -= used;
+                if *count == 0 {
+                    nodes.remove(index);
+                    if index == opener {
+                        closer -= 1;
+                    }
+                }
+            }
+        }
+    }
+    finish(nodes)
+}
+
+// Turns what's left of delimiters and brackets back into text
+fn finish(nodes: Vec<Node>) -> Vec<Inline> {
+    let mut inlines: Vec<Inline> = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        let inline = match node {
+            Node::Inline(inline) => inline,
+            Node::Delimiter { marker, count, .. } => {
+                Inline::Text(std::iter::repeat_n(marker, count).collect())
+            }
+            Node::Bracket { image, .. } => Inline::Text(if image { "![" } else { "[" }.into()),
+        };
+        match (inlines.last_mut(), inline) {
+            (Some(Inline::Text(last)), Inline::Text(text)) => last.push_str(&text),
+            (_, inline) => inlines.push(inline),
+        }
+    }
+    inlines
+}
@@ -0,0 +1,588 @@
+//! Splits Markdown into blocks. Containers (quotes and list items) collect
+//! their lines with the container's prefix removed and are parsed
+//! recursively. Text is parsed into inlines once the whole document has been
+//! read, so links can refer to definitions further down.
+
+use super::inline;
+use super::{Alignment, Block, List, Table};
+use std::collections::HashMap;
+
+/// Link reference definitions: normalized label to URL and title
+pub(super) type References = HashMap<String, (String, Option<String>)>;
+
+/// Columns of indentation that make a line indented code
+const CODE_INDENT: usize = 4;
+
+/// A block whose text hasn't been parsed into inlines yet
+enum RawBlock {
+    Heading(u8, String),
+    Paragraph(String),
+    Code(Option<String>, String),
+    Quote(Vec<RawBlock>),
+    List {
+        start: Option<u64>,
+        tight: bool,
+        items: Vec<Vec<RawBlock>>,
+    },
+    Table {
+        alignments: Vec<Option<Alignment>>,
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+    Rule,
+}
+
+impl RawBlock {
+    fn resolve(self, refs: &References) -> Block {
+        let resolve_all =
+            |blocks: Vec<RawBlock>| blocks.into_iter().map(|b| b.resolve(refs)).collect();
+        match self {
+            RawBlock::Heading(level, text) => Block::Heading {
+                level,
+                content: inline::parse(&text, refs),
+            },
+            RawBlock::Paragraph(text) => Block::Paragraph(inline::parse(&text, refs)),
+            RawBlock::Code(language, code) => Block::CodeBlock { language, code },
+            RawBlock::Quote(blocks) => Block::Quote(resolve_all(blocks)),
+            RawBlock::List {
+                start,
+                tight,
+                items,
+            } => Block::List(List {
+                start,
+                tight,
+                items: items.into_iter().map(resolve_all).collect(),
+            }),
+            RawBlock::Table {
+                alignments,
+                header,
+                rows,
+            } => {
+                let cells = |row: Vec<String>| -> Vec<_> {
+                    row.iter().map(|cell| inline::parse(cell, refs)).collect()
+                };
+                Block::Table(Table {
+                    alignments,
+                    header: cells(header),
+                    rows: rows.into_iter().map(cells).collect(),
+                })
+            }
+            RawBlock::Rule => Block::Rule,
+        }
+    }
+}
+
+pub(super) fn parse(input: &str) -> Vec<Block> {
+    let lines: Vec<String> = input.lines().map(expand_tabs).collect();
+    let mut refs = References::new();
+    let (blocks, _) = parse_blocks(&lines, &mut refs);
+    blocks.into_iter().map(|b| b.resolve(&refs)).collect()
+}
+
+// Tabs in indentation count as spaces up to the next multiple of 4
+fn expand_tabs(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        match c {
+            ' ' => out.push(' '),
+            '\t' => {
+                let width = 4 - out.len() % 4;
+                out.extend(std::iter::repeat_n(' ', width));
+            }
+            _ => break,
+        }
+        rest = &rest[1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Also returns whether blank lines separate any of the blocks, which makes
+/// a list item loose
+fn parse_blocks(lines: &[String], refs: &mut References) -> (Vec<RawBlock>, bool) {
+    let mut blocks = Vec::new();
+    let mut separated = false;
+    let mut blank = false;
+    let mut i = 0;
+    while i < lines.len() {
+        if is_blank(&lines[i]) {
+            blank = true;
+            i += 1;
+            continue;
+        }
+        separated |= blank && !blocks.is_empty();
+        blank = false;
+        let (block, next) = parse_block(lines, i, refs);
+        blocks.extend(block);
+        i = next;
+    }
+    (blocks, separated)
+}
+
+// Parses the block starting at the non-blank line `i`, returning it (if it
+// isn't just link definitions) and the line after it
+fn parse_block(lines: &[String], i: usize, refs: &mut References) -> (Option<RawBlock>, usize) {
+    let line = &lines[i];
+    if indent(line) >= CODE_INDENT {
+        return indented_code(lines, i);
+    }
+    if let Some(fence) = Fence::open(line) {
+        return fence.parse(lines, i);
+    }
+    if let Some((level, text)) = atx_heading(line) {
+        return (Some(RawBlock::Heading(level, text.to_string())), i + 1);
+    }
+    if is_rule(line) {
+        return (Some(RawBlock::Rule), i + 1);
+    }
+    if strip_quote(line).is_some() {
+        return quote(lines, i, refs);
+    }
+    if let Some(marker) = ListMarker::parse(line) {
+        return list(lines, i, marker, refs);
+    }
+    if let Some((table, next)) = table(lines, i) {
+        return (Some(table), next);
+    }
+    paragraph(lines, i, refs)
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+// Whether `line` starts a block that ends a paragraph above it
+fn interrupts_paragraph(line: &str) -> bool {
+    indent(line) < CODE_INDENT
+        && (Fence::open(line).is_some()
+            || atx_heading(line).is_some()
+            || is_rule(line)
+            || strip_quote(line).is_some()
+            // Only a list starting at 1 can, so "2012. A year" in a
+            // paragraph stays text
+            || ListMarker::parse(line).is_some_and(|m| !m.empty && m.number.is_none_or(|n| n == 1)))
+}
+
+// Whether `line` can continue a paragraph inside a container whose prefix it
+// lacks ("lazy" continuation)
+fn continues_lazily(line: &str, previous: Option<&String>) -> bool {
+    let in_paragraph = previous.is_some_and(|p| {
+        !is_blank(p) && indent(p) < CODE_INDENT && Fence::open(p).is_none() && !is_rule(p)
+    });
+    in_paragraph
+        && !is_blank(line)
+        && !interrupts_paragraph(line)
+        && ListMarker::parse(line).is_none()
+}
+
+fn indented_code(lines: &[String], start: usize) -> (Option<RawBlock>, usize) {
+    let mut end = start;
+    let mut last_text = start;
+    while end < lines.len() && (is_blank(&lines[end]) || indent(&lines[end]) >= CODE_INDENT) {
+        if !is_blank(&lines[end]) {
+            last_text = end;
+        }
+        end += 1;
+    }
+    let mut code = String::new();
+    for line in &lines[start..=last_text] {
+        code.push_str(line.get(CODE_INDENT..).unwrap_or_default());
+        code.push('\n');
+    }
+    (Some(RawBlock::Code(None, code)), last_text + 1)
+}
+
+/// The opening line of a fenced code block
+struct Fence<'a> {
+    marker: char,
+    len: usize,
+    indent: usize,
+    info: &'a str,
+}
+
+impl<'a> Fence<'a> {
+    fn open(line: &'a str) -> Option<Self> {
+        let indent = indent(line);
+        let rest = &line[indent..];
+        let marker = rest.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+        let len = rest.len() - rest.trim_start_matches(marker).len();
+        let info = rest[len..].trim();
+        (len >= 3 && !(marker == '`' && info.contains('`'))).then_some(Self {
+            marker,
+            len,
+            indent,
+            info,
+        })
+    }
+
+    fn closes(&self, line: &str) -> bool {
+        let rest = line.trim_start();
+        let len = rest.len() - rest.trim_start_matches(self.marker).len();
+        indent(line) < CODE_INDENT && len >= self.len && rest[len..].trim().is_empty()
+    }
+
+    // An unclosed fence runs to the end of the document
+    fn parse(&self, lines: &[String], start: usize) -> (Option<RawBlock>, usize) {
+        let mut code = String::new();
+        let mut i = start + 1;
+        while i < lines.len() && !self.closes(&lines[i]) {
+            let line = &lines[i];
+            code.push_str(&line[indent(line).min(self.indent)..]);
+            code.push('\n');
+            i += 1;
+        }
+        let language = self
+            .info
+            .split_whitespace()
+            .next()
+            .map(|word| inline::unescape(word).into_owned());
+        (
+            Some(RawBlock::Code(language, code)),
+            (i + 1).min(lines.len()),
+        )
+    }
+}
+
+fn atx_heading(line: &str) -> Option<(u8, &str)> {
+    if indent(line) >= CODE_INDENT {
+        return None;
+    }
+    let rest = line.trim_start();
+    let level = rest.len() - rest.trim_start_matches('#').len();
+    let text = &rest[level..];
+    if !(1..=6).contains(&level) || !(text.is_empty() || text.starts_with([' ', '\t'])) {
+        return None;
+    }
+    // An optional closing run of #s
+    let text = text.trim();
+    let without_closing = text.trim_end_matches('#');
+    let text = if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        without_closing.trim_end()
+    } else {
+        text
+    };
+    Some((level as u8, text))
+}
+
+// `---`, `***` or `___`, possibly with spaces between
+pub(super) fn is_rule(line: &str) -> bool {
+    let rest = line.trim_start();
+    let Some(marker) = rest.chars().next().filter(|c| matches!(c, '-' | '*' | '_')) else {
+        return false;
+    };
+    indent(line) < CODE_INDENT
+        && rest.chars().filter(|&c| c == marker).count() >= 3
+        && rest.chars().all(|c| c == marker || c == ' ' || c == '\t')
+}
+
+// `===` or `---` under a paragraph, making it a heading
+fn setext_level(line: &str) -> Option<u8> {
+    let rest = line.trim();
+    if indent(line) >= CODE_INDENT || rest.is_empty() {
+        return None;
+    }
+    if rest.chars().all(|c| c == '=') {
+        Some(1)
+    } else if rest.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+// The rest of a line starting with `>`, without one following space
+fn strip_quote(line: &str) -> Option<&str> {
+    if indent(line) >= CODE_INDENT {
+        return None;
+    }
+    let rest = line.trim_start().strip_prefix('>')?;
+    Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
+
+fn quote(lines: &[String], start: usize, refs: &mut References) -> (Option<RawBlock>, usize) {
+    let mut inner: Vec<String> = Vec::new();
+    let mut i = start;
+    while i < lines.len() {
+        if let Some(rest) = strip_quote(&lines[i]) {
+            inner.push(rest.to_string());
+        } else if continues_lazily(&lines[i], inner.last()) {
+            inner.push(lines[i].trim_start().to_string());
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    let (blocks, _) = parse_blocks(&inner, refs);
+    (Some(RawBlock::Quote(blocks)), i)
+}
+
+/// The start of a list item, e.g. `- ` or `12. `
+struct ListMarker {
+    /// `None` for bullets
+    number: Option<u64>,
+    /// The bullet, or the `.` or `)` after the number
+    symbol: char,
+    /// Column the item's content starts at
+    width: usize,
+    /// Nothing follows the marker on its line
+    empty: bool,
+}
+
+impl ListMarker {
+    fn parse(line: &str) -> Option<Self> {
+        let indent = indent(line);
+        if indent >= CODE_INDENT {
+            return None;
+        }
+        let rest = &line[indent..];
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let (number, symbol) = match rest[digits..].chars().next()? {
+            '-' | '+' | '*' if digits == 0 => (None, rest.as_bytes()[0] as char),
+            '.' | ')' if (1..=9).contains(&digits) => {
+                (rest[..digits].parse().ok(), rest.as_bytes()[digits] as char)
+            }
+            _ => return None,
+        };
+        let after = &rest[digits + 1..];
+        if after.trim().is_empty() {
+            return Some(Self {
+                number,
+                symbol,
+                width: indent + digits + 2,
+                empty: true,
+            });
+        }
+        let spaces = after.len() - after.trim_start_matches(' ').len();
+        if spaces == 0 {
+            return None;
+        }
+        // Content indented further is code inside the item
+        let spaces = if spaces > CODE_INDENT { 1 } else { spaces };
+        Some(Self {
+            number,
+            symbol,
+            width: indent + digits + 1 + spaces,
+            empty: false,
+        })
+    }
+
+    fn same_list(&self, other: &ListMarker) -> bool {
+        self.symbol == other.symbol && self.number.is_some() == other.number.is_some()
+    }
+}
+
+fn list(
+    lines: &[String],
+    start: usize,
+    first: ListMarker,
+    refs: &mut References,
+) -> (Option<RawBlock>, usize) {
+    let mut items = Vec::new();
+    let mut loose = false;
+    let mut i = start;
+    let mut marker = first;
+    let start_number = marker.number;
+    loop {
+        let (item_lines, next) = list_item(lines, i, &marker);
+        let (blocks, separated) = parse_blocks(&item_lines, refs);
+        loose |= separated;
+        items.push(blocks);
+        i = next;
+
+        // The next item may follow blank lines
+        let mut j = i;
+        while j < lines.len() && is_blank(&lines[j]) {
+            j += 1;
+        }
+        match lines.get(j).and_then(|line| ListMarker::parse(line)) {
+            Some(next_marker) if next_marker.same_list(&marker) && !is_rule(&lines[j]) => {
+                loose |= j > i;
+                marker = next_marker;
+                i = j;
+            }
+            _ => break,
+        }
+    }
+    let list = RawBlock::List {
+        start: start_number,
+        tight: !loose,
+        items,
+    };
+    (Some(list), i)
+}
+
+// Lines of the item starting at line `start`, with its indentation removed,
+// and the line after the item
+fn list_item(lines: &[String], start: usize, marker: &ListMarker) -> (Vec<String>, usize) {
+    let first = if marker.empty {
+        String::new()
+    } else {
+        lines[start][marker.width..].to_string()
+    };
+    let mut item = vec![first];
+    let mut i = start + 1;
+    while i < lines.len() {
+        let line = &lines[i];
+        if is_blank(line) {
+            // An item can start with at most one blank line
+            if marker.empty && item.len() == 1 {
+                break;
+            }
+            item.push(String::new());
+        } else if indent(line) >= marker.width {
+            item.push(line[marker.width..].to_string());
+        } else if continues_lazily(line, item.last()) {
+            item.push(line.trim_start().to_string());
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    // Trailing blank lines are between blocks, not part of the item
+    while item.len() > 1 && item.last().is_some_and(|line| line.is_empty()) {
+        item.pop();
+        i -= 1;
+    }
+    (item, i)
+}
+
+// A header row, a delimiter row like `| --- | :-: |`, and body rows
+fn table(lines: &[String], start: usize) -> Option<(RawBlock, usize)> {
+    let header_line = &lines[start];
+    if !header_line.contains('|') {
+        return None;
+    }
+    let alignments = lines.get(start + 1).and_then(|line| delimiter_row(line))?;
+    let header = split_row(header_line);
+    if header.len() != alignments.len() {
+        return None;
+    }
+
+    let mut rows = Vec::new();
+    let mut i = start + 2;
+    while i < lines.len() && !is_blank(&lines[i]) && !interrupts_paragraph(&lines[i]) {
+        let mut row = split_row(&lines[i]);
+        row.resize(alignments.len(), String::new());
+        rows.push(row);
+        i += 1;
+    }
+    let table = RawBlock::Table {
+        alignments,
+        header,
+        rows,
+    };
+    Some((table, i))
+}
+
+fn delimiter_row(line: &str) -> Option<Vec<Option<Alignment>>> {
+    if indent(line) >= CODE_INDENT || !line.contains('|') {
+        return None;
+    }
+    split_row(line)
+        .iter()
+        .map(|cell| {
+            let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+            Some(match (cell.starts_with(':'), cell.ends_with(':')) {
+                (true, true) => Some(Alignment::Center),
+                (true, false) => Some(Alignment::Left),
+                (false, true) => Some(Alignment::Right),
+                (false, false) => None,
+            })
+        })
+        .collect()
+}
+
+// Cells of a table row. Escaped pipes (`\|`) stay in the cell text.
+fn split_row(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                cell.push(c);
+                cell.extend(chars.next());
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    // Without a trailing pipe the last cell is still open
+    if !cell.trim().is_empty() || !line.ends_with('|') {
+        cells.push(cell.trim().to_string());
+    }
+    cells
+}
+
+fn paragraph(lines: &[String], start: usize, refs: &mut References) -> (Option<RawBlock>, usize) {
+    let mut text: Vec<&str> = vec![lines[start].trim_start()];
+    let mut i = start + 1;
+    let mut heading = None;
+    while i < lines.len() && !is_blank(&lines[i]) {
+        if let Some(level) = setext_level(&lines[i]) {
+            heading = Some(level);
+            i += 1;
+            break;
+        }
+        // A table's header row can directly follow a paragraph
+        if interrupts_paragraph(&lines[i]) || table(lines, i).is_some() {
+            break;
+        }
+        text.push(lines[i].trim_start());
+        i += 1;
+    }
+
+    // Link definitions at the start are not part of the text
+    let definitions = text
+        .iter()
+        .take_while(|line| reference_definition(line, refs))
+        .count();
+    let text = text[definitions..].join("\n");
+    let text = text.trim_end();
+    let block = match heading {
+        _ if text.is_empty() => None,
+        Some(level) => Some(RawBlock::Heading(level, text.to_string())),
+        None => Some(RawBlock::Paragraph(text.to_string())),
+    };
+    (block, i)
+}
+
+// Records `[label]: url "title"` if `line` is one. The first definition of a
+// label wins.
+fn reference_definition(line: &str, refs: &mut References) -> bool {
+    let Some(rest) = line.strip_prefix('[') else {
+        return false;
+    };
+    let Some(end) = rest.find("]:") else {
+        return false;
+    };
+    let label = inline::normalize_label(&rest[..end]);
+    let rest = rest[end + 2..].trim();
+    if label.is_empty() || rest.is_empty() {
+        return false;
+    }
+    let Some((url, after_url)) = inline::link_destination(rest) else {
+        return false;
+    };
+    let after_url = after_url.trim();
+    let title = if after_url.is_empty() {
+        None
+    } else {
+        match inline::link_title(after_url) {
+            Some((title, after)) if after.trim().is_empty() => Some(title),
+            _ => return false,
+        }
+    };
+    refs.entry(label).or_insert((url, title));
+    true
+}
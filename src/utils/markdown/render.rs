@@ -0,0 +1,481 @@
+//! Writes a [`Block`] tree out as HTML or as Markdown. The Markdown is escaped
+//! so that reading it back gives the same tree.
+
+use super::parse;
+use super::{Alignment, Block, Inline, List, Table};
+
+/// Shortest code fence
+const MIN_FENCE_LEN: usize = 3;
+
+pub(super) fn html(blocks: &[Block]) -> String {
+    let mut out = String::new();
+    for block in blocks {
+        block_html(block, false, &mut out);
+    }
+    out
+}
+
+// `tight` leaves paragraphs in list items without their `<p>`
+fn block_html(block: &Block, tight: bool, out: &mut String) {
+    match block {
+        Block::Heading { level, content } => {
+            out.push_str(&format!("<h{}>", level));
+            inlines_html(content, out);
+            out.push_str(&format!("</h{}>\n", level));
+        }
+        Block::Paragraph(content) if tight => inlines_html(content, out),
+        Block::Paragraph(content) => {
+            out.push_str("<p>");
+            inlines_html(content, out);
+            out.push_str("</p>\n");
+        }
+        Block::CodeBlock { language, code } => {
+            match language {
+                Some(language) => out.push_str(&format!(
+                    "<pre><code class=\"language-{}\">",
+                    escape_attribute(language)
+                )),
+                None => out.push_str("<pre><code>"),
+            }
+            out.push_str(&escape_html(code));
+            out.push_str("</code></pre>\n");
+        }
+        Block::Quote(blocks) => {
+            out.push_str("<blockquote>\n");
+            for block in blocks {
+                block_html(block, false, out);
+            }
+            out.push_str("</blockquote>\n");
+        }
+        Block::List(list) => list_html(list, out),
+        Block::Table(table) => table_html(table, out),
+        Block::Rule => out.push_str("<hr>\n"),
+    }
+}
+
+fn list_html(list: &List, out: &mut String) {
+    match list.start {
+        None => out.push_str("<ul>\n"),
+        Some(1) => out.push_str("<ol>\n"),
+        Some(start) => out.push_str(&format!("<ol start=\"{}\">\n", start)),
+    }
+    for item in &list.items {
+        out.push_str("<li>");
+        // Whether the last thing written ended its line
+        let mut line_ended = false;
+        for block in item {
+            let bare = list.tight && matches!(block, Block::Paragraph(_));
+            if !bare && !line_ended {
+                out.push('\n');
+            }
+            block_html(block, list.tight, out);
+            line_ended = !bare;
+        }
+        out.push_str("</li>\n");
+    }
+    out.push_str(if list.start.is_some() {
+        "</ol>\n"
+    } else {
+        "</ul>\n"
+    });
+}
+
+fn table_html(table: &Table, out: &mut String) {
+    let row = |cells: &[Vec<Inline>], tag: &str, out: &mut String| {
+        out.push_str("<tr>\n");
+        for (cell, alignment) in cells.iter().zip(&table.alignments) {
+            out.push('<');
+            out.push_str(tag);
+            match alignment {
+                Some(Alignment::Left) => out.push_str(" align=\"left\""),
+                Some(Alignment::Center) => out.push_str(" align=\"center\""),
+                Some(Alignment::Right) => out.push_str(" align=\"right\""),
+                None => {}
+            }
+            out.push('>');
+            inlines_html(cell, out);
+            out.push_str(&format!("</{}>\n", tag));
+        }
+        out.push_str("</tr>\n");
+    };
+
+    out.push_str("<table>\n<thead>\n");
+    row(&table.header, "th", out);
+    out.push_str("</thead>\n");
+    if !table.rows.is_empty() {
+        out.push_str("<tbody>\n");
+        for cells in &table.rows {
+            row(cells, "td", out);
+        }
+        out.push_str("</tbody>\n");
+    }
+    out.push_str("</table>\n");
+}
+
+fn inlines_html(inlines: &[Inline], out: &mut String) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(text) => out.push_str(&escape_html(text)),
+            Inline::Code(code) => {
+                out.push_str("<code>");
+                out.push_str(&escape_html(code));
+                out.push_str("</code>");
+            }
+            Inline::Emphasis(content) => wrap_html("em", content, out),
+            Inline::Strong(content) => wrap_html("strong", content, out),
+            Inline::Strikethrough(content) => wrap_html("del", content, out),
+            Inline::Link {
+                url,
+                title,
+                content,
+            } => {
+                out.push_str(&format!("<a href=\"{}\"", escape_attribute(url)));
+                if let Some(title) = title {
+                    out.push_str(&format!(" title=\"{}\"", escape_attribute(title)));
+                }
+                out.push('>');
+                inlines_html(content, out);
+                out.push_str("</a>");
+            }
+            Inline::Image { url, title, alt } => {
+                out.push_str(&format!(
+                    "<img src=\"{}\" alt=\"{}\"",
+                    escape_attribute(url),
+                    escape_attribute(alt)
+                ));
+                if let Some(title) = title {
+                    out.push_str(&format!(" title=\"{}\"", escape_attribute(title)));
+                }
+                out.push('>');
+            }
+            Inline::LineBreak => out.push_str("<br>\n"),
+            Inline::SoftBreak => out.push('\n'),
+        }
+    }
+}
+
+fn wrap_html(tag: &str, content: &[Inline], out: &mut String) {
+    out.push_str(&format!("<{}>", tag));
+    inlines_html(content, out);
+    out.push_str(&format!("</{}>", tag));
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_attribute(value: &str) -> String {
+    escape_html(value).replace('"', "&quot;")
+}
+
+pub(super) fn markdown(blocks: &[Block]) -> String {
+    blocks_markdown(blocks, "\n\n")
+}
+
+fn blocks_markdown(blocks: &[Block], separator: &str) -> String {
+    let mut parts = Vec::with_capacity(blocks.len());
+    // Lists of the same kind next to each other would merge, so every other
+    // one uses different markers
+    let mut alternate = false;
+    for (i, block) in blocks.iter().enumerate() {
+        let follows_list = match (i.checked_sub(1).map(|j| &blocks[j]), block) {
+            (Some(Block::List(previous)), Block::List(list)) => {
+                previous.start.is_some() == list.start.is_some()
+            }
+            _ => false,
+        };
+        let part = match block {
+            Block::List(list) => {
+                let (part, alternated) = list_markdown(list, follows_list && !alternate);
+                alternate = alternated;
+                part
+            }
+            _ => block_markdown(block),
+        };
+        parts.push(part);
+    }
+    parts.join(separator)
+}
+
+fn block_markdown(block: &Block) -> String {
+    match block {
+        Block::Heading { level, content } => {
+            let mut text = inlines_markdown(content).replace('\n', " ");
+            // A trailing `#` would be taken for a closing sequence
+            if text.ends_with('#') {
+                text.insert(text.len() - 1, '\\');
+            }
+            format!("{} {}", "#".repeat(*level as usize), text)
+        }
+        Block::Paragraph(content) => escape_line_starts(&inlines_markdown(content)),
+        Block::CodeBlock { language, code } => {
+            let fence = "`".repeat(longest_run(code, '`').max(MIN_FENCE_LEN - 1) + 1);
+            let newline = if code.ends_with('\n') || code.is_empty() {
+                ""
+            } else {
+                "\n"
+            };
+            format!(
+                "{}{}\n{}{}{}",
+                fence,
+                language.as_deref().unwrap_or_default(),
+                code,
+                newline,
+                fence
+            )
+        }
+        Block::Quote(blocks) => prefix_lines(&blocks_markdown(blocks, "\n\n"), "> ", ">"),
+        Block::List(list) => list_markdown(list, false).0,
+        Block::Table(table) => table_markdown(table),
+        Block::Rule => "---".to_string(),
+    }
+}
+
+/// Also returns whether the list ended up with the alternate markers
+fn list_markdown(list: &List, alternate: bool) -> (String, bool) {
+    let separator = if list.tight { "\n" } else { "\n\n" };
+    let items: Vec<String> = list
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = match list.start {
+                None if alternate => "*".to_string(),
+                None => "-".to_string(),
+                Some(start) if alternate => format!("{})", start + i as u64),
+                Some(start) => format!("{}.", start + i as u64),
+            };
+            let content = blocks_markdown(item, separator);
+            if content.is_empty() {
+                return marker;
+            }
+            let indent = " ".repeat(marker.len() + 1);
+            prefix_lines(&content, &indent, "").replacen(&indent, &format!("{} ", marker), 1)
+        })
+        .collect();
+    // `- ---` or `- - -` would read back as a rule, which `*` avoids
+    if !alternate && list.start.is_none() && items.iter().any(|item| starts_with_rule(item)) {
+        return list_markdown(list, true);
+    }
+    (items.join(separator), alternate)
+}
+
+fn starts_with_rule(text: &str) -> bool {
+    text.lines().next().is_some_and(parse::is_rule)
+}
+
+fn table_markdown(table: &Table) -> String {
+    let row = |cells: &[Vec<Inline>]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .map(|cell| {
+                let text = inlines_markdown(cell)
+                    .replace('\n', " ")
+                    .replace('|', "\\|");
+                if text.is_empty() {
+                    " ".to_string()
+                } else {
+                    format!(" {} ", text)
+                }
+            })
+            .collect();
+        format!("|{}|", cells.join("|"))
+    };
+    let delimiters: Vec<&str> = table
+        .alignments
+        .iter()
+        .map(|alignment| match alignment {
+            None => " --- ",
+            Some(Alignment::Left) => " :--- ",
+            Some(Alignment::Center) => " :---: ",
+            Some(Alignment::Right) => " ---: ",
+        })
+        .collect();
+    let mut lines = vec![row(&table.header), format!("|{}|", delimiters.join("|"))];
+    lines.extend(table.rows.iter().map(|cells| row(cells)));
+    lines.join("\n")
+}
+
+// Prefixes every line, using `empty_prefix` for blank ones
+fn prefix_lines(text: &str, prefix: &str, empty_prefix: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                empty_prefix.to_string()
+            } else {
+                format!("{}{}", prefix, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn inlines_markdown(inlines: &[Inline]) -> String {
+    let mut out = String::new();
+    for (i, inline) in inlines.iter().enumerate() {
+        match inline {
+            Inline::Text(text) => {
+                let next = inlines.get(i + 1).and_then(first_char);
+                escape_markdown(text, out.chars().next_back(), next, &mut out);
+            }
+            Inline::Code(code) => {
+                let ticks = "`".repeat(longest_run(code, '`') + 1);
+                let pad = code.starts_with('`')
+                    || code.ends_with('`')
+                    || (code.starts_with(' ') && code.ends_with(' ') && !code.trim().is_empty());
+                let space = if pad { " " } else { "" };
+                out.push_str(&format!("{}{}{}{}{}", ticks, space, code, space, ticks));
+            }
+            Inline::Emphasis(content) => wrap_markdown("*", content, &mut out),
+            Inline::Strong(content) => wrap_markdown("**", content, &mut out),
+            Inline::Strikethrough(content) => wrap_markdown("~~", content, &mut out),
+            Inline::Link {
+                url,
+                title: None,
+                content,
+            } if is_autolink(url, content) => {
+                out.push_str(&format!("<{}>", url));
+            }
+            Inline::Link {
+                url,
+                title,
+                content,
+            } => {
+                out.push('[');
+                out.push_str(&inlines_markdown(content));
+                out.push_str("](");
+                push_destination(url, title.as_deref(), &mut out);
+            }
+            Inline::Image { url, title, alt } => {
+                out.push_str("![");
+                escape_markdown(alt, None, None, &mut out);
+                out.push_str("](");
+                push_destination(url, title.as_deref(), &mut out);
+            }
+            Inline::LineBreak => out.push_str("\\\n"),
+            Inline::SoftBreak => out.push('\n'),
+        }
+    }
+    out
+}
+
+fn first_char(inline: &Inline) -> Option<char> {
+    match inline {
+        Inline::Text(text) => text.chars().next(),
+        Inline::SoftBreak | Inline::LineBreak => Some('\n'),
+        // Everything else starts with punctuation
+        _ => Some('*'),
+    }
+}
+
+// `<https://example.com>`, for a link showing its own URL
+fn is_autolink(url: &str, content: &[Inline]) -> bool {
+    let [Inline::Text(text)] = content else {
+        return false;
+    };
+    let shown = url.strip_prefix("mailto:").unwrap_or(url);
+    text == shown
+        && url.contains(':')
+        && !url.contains(|c: char| c.is_whitespace() || matches!(c, '<' | '>'))
+}
+
+// `url "title")`, closing a link or image
+fn push_destination(url: &str, title: Option<&str>, out: &mut String) {
+    let balanced = url.matches('(').count() == url.matches(')').count();
+    if url.is_empty() || url.contains([' ', '<', '>', '\n']) || !balanced {
+        out.push('<');
+        out.push_str(&url.replace('<', "%3C").replace('>', "%3E"));
+        out.push('>');
+    } else {
+        out.push_str(url);
+    }
+    if let Some(title) = title {
+        out.push_str(" \"");
+        out.push_str(&title.replace('\\', "\\\\").replace('"', "\\\""));
+        out.push('"');
+    }
+    out.push(')');
+}
+
+// Emphasis can't start or end with a space, so spaces are moved outside
+fn wrap_markdown(marker: &str, content: &[Inline], out: &mut String) {
+    let inner = inlines_markdown(content);
+    let trimmed = inner.trim_matches(' ');
+    if trimmed.is_empty() {
+        out.push_str(&inner);
+        return;
+    }
+    let leading = inner.len() - inner.trim_start_matches(' ').len();
+    let trailing = inner.len() - inner.trim_end_matches(' ').len();
+    out.push_str(&inner[..leading]);
+    out.push_str(marker);
+    out.push_str(trimmed);
+    out.push_str(marker);
+    out.push_str(&inner[inner.len() - trailing..]);
+}
+
+// Backslash-escapes characters that would otherwise be read as Markdown.
+// `before` and `after` are the characters around `text`.
+fn escape_markdown(text: &str, before: Option<char>, after: Option<char>, out: &mut String) {
+    let chars: Vec<char> = text.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = if i == 0 { before } else { Some(chars[i - 1]) };
+        let next = chars.get(i + 1).copied().or(after);
+        let escape = match c {
+            '\\' | '`' | '*' | '[' | ']' | '<' | '~' => true,
+            // `_` only has a meaning at the edges of words
+            '_' => {
+                !(prev.is_some_and(char::is_alphanumeric)
+                    && next.is_some_and(char::is_alphanumeric))
+            }
+            // Entity references
+            '&' => next.is_some_and(|n| n.is_ascii_alphanumeric() || n == '#'),
+            _ => false,
+        };
+        if escape {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
+
+// Escapes the start of lines that would begin a heading, quote, list or rule
+fn escape_line_starts(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            let after_digits = &line[digits..];
+            if line.starts_with(['#', '>', '-', '+', '=', '|']) {
+                format!("\\{}", line)
+            } else if digits > 0 && after_digits.starts_with(['.', ')']) {
+                format!("{}\\{}", &line[..digits], after_digits)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for ch in text.chars() {
+        if ch == c {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+    longest
+}
@@ -0,0 +1,108 @@
+//! MD5 (RFC 1321).
+
+use super::digest::{BlockBuffer, Digest, LengthOrder, BLOCK_SIZE};
+
+// MD5 Constants
+// Shift amounts for each round
+const S: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+// Constants derived from the binary integer part of the sines of integers
+const K: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+// Initial hash values (A, B, C, D)
+const INITIAL_STATE: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+/// Incremental MD5 hasher
+#[derive(Debug, Clone)]
+pub struct Md5 {
+    state: [u32; 4],
+    buffer: BlockBuffer,
+}
+
+impl Default for Md5 {
+    fn default() -> Self {
+        Self {
+            state: INITIAL_STATE,
+            buffer: BlockBuffer::default(),
+        }
+    }
+}
+
+impl Digest for Md5 {
+    type Output = [u8; 16];
+
+    fn update(&mut self, data: &[u8]) {
+        let state = &mut self.state;
+        self.buffer.update(data, |block| compress(state, block));
+    }
+
+    fn finalize(self) -> Self::Output {
+        let Self { mut state, buffer } = self;
+        buffer.finish(LengthOrder::LittleEndian, |block| {
+            compress(&mut state, block)
+        });
+        let mut output = [0u8; 16];
+        for (bytes, word) in output.chunks_exact_mut(4).zip(state) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+        output
+    }
+}
+
+fn compress(state: &mut [u32; 4], block: &[u8; BLOCK_SIZE]) {
+    // Convert the block to 16 32-bit words (little-endian)
+    let mut x = [0u32; 16];
+    for (i, chunk) in block.chunks_exact(4).enumerate().take(16) {
+        x[i] = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+
+    // Initialize hash value for this chunk
+    let mut a = state[0];
+    let mut b = state[1];
+    let mut c = state[2];
+    let mut d = state[3];
+
+    // Main loop
+    for i in 0..64 {
+        let (mut f, g): (u32, usize);
+
+        if i < 16 {
+            f = (b & c) | (!b & d);
+            g = i;
+        } else if i < 32 {
+            f = (d & b) | (!d & c);
+            g = (5 * i + 1) % 16;
+        } else if i < 48 {
+            f = b ^ c ^ d;
+            g = (3 * i + 5) % 16;
+        } else {
+            f = c ^ (b | !d);
+            g = (7 * i) % 16;
+        }
+
+        f = f.wrapping_add(a).wrapping_add(K[i]).wrapping_add(x[g]);
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(f.rotate_left(S[i]));
+    }
+
+    // Add the compressed chunk to the current hash value
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+}
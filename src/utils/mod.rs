@@ -1,4 +1,27 @@
+pub mod argon2;
+pub mod blake2b;
+pub mod cancel;
 pub mod color;
+pub mod crc;
 pub mod crc32;
+pub mod csv;
+pub mod digest;
+pub mod hexdump;
+pub mod json;
+pub mod markdown;
+pub mod md5;
+pub mod qr;
+pub mod random;
+pub mod regex;
+pub mod sha1;
+pub mod sha256;
+pub mod time;
+pub mod xxhash;
 
+pub use cancel::CancelToken;
 pub use color::Color;
+pub use digest::{hex_digest, hmac, Digest};
+pub use hexdump::hexdump;
+pub use md5::Md5;
+pub use sha1::Sha1;
+pub use sha256::Sha256;
@@ -0,0 +1,638 @@
+//! QR code generation (ISO/IEC 18004), byte mode only, rendered to SVG.
+//!
+//! ```
+//! use buup::utils::qr::{EccLevel, QrCode};
+//!
+//! let qr = QrCode::encode(b"https://buup.io", EccLevel::Medium).unwrap();
+//! assert_eq!(qr.size(), 25);
+//! assert!(qr.to_svg(4).starts_with("<svg"));
+//! ```
+
+use crate::TransformError;
+
+/// How much of the symbol can be damaged and still be read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EccLevel {
+    /// About 7% of codewords can be restored
+    Low,
+    /// About 15% of codewords can be restored
+    Medium,
+    /// About 25% of codewords can be restored
+    Quartile,
+    /// About 30% of codewords can be restored
+    High,
+}
+
+impl EccLevel {
+    fn index(self) -> usize {
+        match self {
+            Self::Low => 0,
+            Self::Medium => 1,
+            Self::Quartile => 2,
+            Self::High => 3,
+        }
+    }
+
+    // The two bits identifying the level in the format information
+    fn format_bits(self) -> u32 {
+        match self {
+            Self::Low => 1,
+            Self::Medium => 0,
+            Self::Quartile => 3,
+            Self::High => 2,
+        }
+    }
+}
+
+const MIN_VERSION: usize = 1;
+const MAX_VERSION: usize = 40;
+
+// Error correction codewords per block, by level then version (index 0 unused)
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+// Error correction blocks, by level then version (index 0 unused)
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// A QR code symbol: a square grid of dark and light modules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrCode {
+    version: usize,
+    size: usize,
+    modules: Vec<bool>,
+    // Finder, timing, alignment and format/version modules, which masks skip
+    function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encodes `data` in the smallest version that fits at `ecc`
+    pub fn encode(data: &[u8], ecc: EccLevel) -> Result<Self, TransformError> {
+        let version = (MIN_VERSION..=MAX_VERSION)
+            .find(|&version| {
+                4 + char_count_bits(version) + data.len() * 8
+                    <= num_data_codewords(version, ecc) * 8
+            })
+            .ok_or_else(|| {
+                TransformError::InvalidArgument(
+                    format!(
+                        "{} bytes is too long for a QR code (at most {} at this error correction level)",
+                        data.len(),
+                        num_data_codewords(MAX_VERSION, ecc) - 3
+                    )
+                    .into(),
+                )
+            })?;
+
+        let codewords = data_codewords(data, version, ecc);
+        let mut qr = QrCode::blank(version);
+        qr.draw_function_patterns();
+        qr.draw_codewords(&add_ecc_and_interleave(&codewords, version, ecc));
+
+        // Use the mask that leaves the fewest patterns that confuse scanners
+        let mut best = (u32::MAX, 0);
+        for mask in 0..8 {
+            qr.apply_mask(mask);
+            qr.draw_format_bits(ecc, mask);
+            let penalty = qr.penalty_score();
+            if penalty < best.0 {
+                best = (penalty, mask);
+            }
+            // Masks are XORs, so applying one again undoes it
+            qr.apply_mask(mask);
+        }
+        qr.apply_mask(best.1);
+        qr.draw_format_bits(ecc, best.1);
+        Ok(qr)
+    }
+
+    /// The symbol's version, from 1 (21×21 modules) to 40 (177×177)
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Width and height in modules, without the quiet zone
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the module at column `x`, row `y` is dark
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    /// Draws the symbol as an SVG with a light `border` of that many modules
+    /// (the standard asks for 4). Dark modules form a single path.
+    pub fn to_svg(&self, border: usize) -> String {
+        let dimension = self.size + border * 2;
+        let mut path = String::new();
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if self.get(x, y) {
+                    if !path.is_empty() {
+                        path.push(' ');
+                    }
+                    path.push_str(&format!("M{},{}h1v1h-1z", x + border, y + border));
+                }
+            }
+        }
+        format!(
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {0} {0}\" ",
+                "shape-rendering=\"crispEdges\">",
+                "<rect width=\"100%\" height=\"100%\" fill=\"#FFFFFF\"/>",
+                "<path d=\"{1}\" fill=\"#000000\"/></svg>"
+            ),
+            dimension, path
+        )
+    }
+
+    fn blank(version: usize) -> Self {
+        let size = version * 4 + 17;
+        QrCode {
+            version,
+            size,
+            modules: vec![false; size * size],
+            function: vec![false; size * size],
+        }
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let index = y * self.size + x;
+        self.modules[index] = dark;
+        self.function[index] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        let size = self.size;
+        // Timing patterns
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        // Finder patterns, drawn after timing so they overwrite it
+        self.draw_finder(3, 3);
+        self.draw_finder(size - 4, 3);
+        self.draw_finder(3, size - 4);
+
+        // Alignment patterns, except where they would overlap a finder
+        let positions = alignment_positions(self.version);
+        let last = positions.len().saturating_sub(1);
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                let corner = (i == 0 && (j == 0 || j == last)) || (i == last && j == 0);
+                if !corner {
+                    self.draw_alignment(x, y);
+                }
+            }
+        }
+
+        // Reserve the format areas with a dummy value until a mask is chosen
+        self.draw_format_bits(EccLevel::Low, 0);
+        self.draw_version();
+    }
+
+    fn draw_finder(&mut self, x: usize, y: usize) {
+        for dy in -4_isize..=4 {
+            for dx in -4_isize..=4 {
+                let (xx, yy) = (x as isize + dx, y as isize + dy);
+                if (0..self.size as isize).contains(&xx) && (0..self.size as isize).contains(&yy) {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function(xx as usize, yy as usize, distance != 2 && distance != 4);
+                }
+            }
+        }
+    }
+
+    fn draw_alignment(&mut self, x: usize, y: usize) {
+        for dy in -2_isize..=2 {
+            for dx in -2_isize..=2 {
+                self.set_function(
+                    (x as isize + dx) as usize,
+                    (y as isize + dy) as usize,
+                    dx.abs().max(dy.abs()) != 1,
+                );
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, ecc: EccLevel, mask: u8) {
+        let bits = format_bits(ecc, mask);
+        let bit = |i: u32| (bits >> i) & 1 != 0;
+        let size = self.size;
+
+        // First copy, around the top left finder
+        for i in 0..6 {
+            self.set_function(8, i, bit(i as u32));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i as u32));
+        }
+
+        // Second copy, split between the other two finders
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i as u32));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i as u32));
+        }
+        // Always dark
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let mut remainder = self.version as u32;
+        for _ in 0..12 {
+            remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+        }
+        let bits = (self.version as u32) << 12 | remainder;
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    // Places the bits in the zigzag order of ISO/IEC 18004 section 7.7.3:
+    // two-module columns from the right, alternately upwards and downwards
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let total_bits = codewords.len() * 8;
+        let mut bit = 0;
+        let mut right = size - 1;
+        loop {
+            // The vertical timing pattern is skipped entirely
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vertical in 0..size {
+                for j in 0..2 {
+                    let x = right - j;
+                    let y = if upward {
+                        size - 1 - vertical
+                    } else {
+                        vertical
+                    };
+                    let index = y * size + x;
+                    if !self.function[index] && bit < total_bits {
+                        self.modules[index] = (codewords[bit / 8] >> (7 - bit % 8)) & 1 != 0;
+                        bit += 1;
+                    }
+                    // Remainder bits stay light
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                if invert && !self.function[index] {
+                    self.modules[index] = !self.modules[index];
+                }
+            }
+        }
+    }
+
+    // The four penalty rules of ISO/IEC 18004 section 7.8.3
+    fn penalty_score(&self) -> u32 {
+        let size = self.size;
+        let mut penalty = 0;
+        let row = |y: usize| (0..size).map(move |x| self.get(x, y));
+        let column = |x: usize| (0..size).map(move |y| self.get(x, y));
+        let lines = (0..size)
+            .map(|i| row(i).collect::<Vec<_>>())
+            .chain((0..size).map(|i| column(i).collect::<Vec<_>>()));
+
+        const FINDER_LIKE: [[bool; 11]; 2] = [
+            [
+                true, false, true, true, true, false, true, false, false, false, false,
+            ],
+            [
+                false, false, false, false, true, false, true, true, true, false, true,
+            ],
+        ];
+
+        for line in lines {
+            // Runs of five or more modules of the same color
+            let mut run = 1;
+            for i in 1..=size {
+                if i < size && line[i] == line[i - 1] {
+                    run += 1;
+                } else {
+                    if run >= 5 {
+                        penalty += 3 + (run - 5);
+                    }
+                    run = 1;
+                }
+            }
+            // Patterns that look like a finder
+            penalty += 40
+                * line
+                    .windows(11)
+                    .filter(|window| FINDER_LIKE.iter().any(|pattern| window == pattern))
+                    .count() as u32;
+        }
+
+        // 2×2 blocks of one color
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let dark = self.get(x, y);
+                if dark == self.get(x + 1, y)
+                    && dark == self.get(x, y + 1)
+                    && dark == self.get(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        // Balance of dark and light modules, in steps of 5% away from half
+        let total = (size * size) as u64;
+        let dark = self.modules.iter().filter(|&&dark| dark).count() as u64;
+        let k = (dark * 20)
+            .abs_diff(total * 10)
+            .div_ceil(total)
+            .saturating_sub(1);
+        penalty + k as u32 * 10
+    }
+}
+
+// Bits of the character count field in byte mode
+fn char_count_bits(version: usize) -> usize {
+    if version <= 9 {
+        8
+    } else {
+        16
+    }
+}
+
+// Modules left for data and error correction once function patterns are placed
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let num_align = version / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn num_data_codewords(version: usize, ecc: EccLevel) -> usize {
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[ecc.index()][version] as usize
+            * NUM_ERROR_CORRECTION_BLOCKS[ecc.index()][version] as usize
+}
+
+fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let num_align = version / 7 + 2;
+    let step = if version == 32 {
+        26
+    } else {
+        (version * 4 + num_align * 2 + 1) / (num_align * 2 - 2) * 2
+    };
+    let mut positions = vec![6];
+    let mut position = version * 4 + 17 - 7;
+    let mut rest = Vec::with_capacity(num_align - 1);
+    for _ in 1..num_align {
+        rest.push(position);
+        position -= step;
+    }
+    positions.extend(rest.into_iter().rev());
+    positions
+}
+
+// 15 bits: the level and mask, a BCH(15,5) remainder, and a fixed XOR mask
+fn format_bits(ecc: EccLevel, mask: u8) -> u32 {
+    let data = ecc.format_bits() << 3 | mask as u32;
+    let mut remainder = data;
+    for _ in 0..10 {
+        remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+    }
+    (data << 10 | remainder) ^ 0x5412
+}
+
+// Mode indicator, length, data, terminator and padding, as codewords
+fn data_codewords(data: &[u8], version: usize, ecc: EccLevel) -> Vec<u8> {
+    let capacity = num_data_codewords(version, ecc) * 8;
+    let mut bits = BitBuffer::default();
+    bits.push(0b0100, 4);
+    bits.push(data.len() as u32, char_count_bits(version));
+    for &byte in data {
+        bits.push(byte as u32, 8);
+    }
+    let terminator = (capacity - bits.len).min(4);
+    bits.push(0, terminator);
+    let to_byte = (8 - bits.len % 8) % 8;
+    bits.push(0, to_byte);
+
+    let mut codewords = bits.bytes;
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if codewords.len() * 8 >= capacity {
+            break;
+        }
+        codewords.push(pad);
+    }
+    codewords
+}
+
+#[derive(Default)]
+struct BitBuffer {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitBuffer {
+    // Appends the low `count` bits of `value`, most significant first
+    fn push(&mut self, value: u32, count: usize) {
+        for i in (0..count).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+}
+
+// Splits the data into blocks, appends each block's Reed-Solomon codewords
+// and interleaves the blocks as ISO/IEC 18004 section 7.6 describes
+fn add_ecc_and_interleave(data: &[u8], version: usize, ecc: EccLevel) -> Vec<u8> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[ecc.index()][version] as usize;
+    let block_ecc_len = ECC_CODEWORDS_PER_BLOCK[ecc.index()][version] as usize;
+    let raw_codewords = num_raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_len = raw_codewords / num_blocks;
+
+    let divisor = reed_solomon_divisor(block_ecc_len);
+    let mut blocks = Vec::with_capacity(num_blocks);
+    let mut offset = 0;
+    for i in 0..num_blocks {
+        let data_len = short_block_len - block_ecc_len + usize::from(i >= num_short_blocks);
+        let block_data = &data[offset..offset + data_len];
+        offset += data_len;
+        let mut block = block_data.to_vec();
+        // Short blocks get a placeholder so all blocks line up when interleaving
+        if i < num_short_blocks {
+            block.push(0);
+        }
+        block.extend(reed_solomon_remainder(block_data, &divisor));
+        blocks.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            if i != short_block_len - block_ecc_len || j >= num_short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+// Coefficients of the generator polynomial of the given degree, highest
+// power first, leaving out the leading 1
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0; degree];
+    result[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (value, &coefficient) in result.iter_mut().zip(divisor) {
+            *value ^= gf_multiply(coefficient, factor);
+        }
+    }
+    result
+}
+
+// Multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u16 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u16 >> i) & 1) * x as u16;
+    }
+    z as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_reed_solomon() {
+        // "HELLO WORLD" at 1-M, as worked through in most QR tutorials
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        assert_eq!(
+            reed_solomon_remainder(&data, &reed_solomon_divisor(10)),
+            [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+        );
+    }
+
+    #[test]
+    fn test_qr_format_and_layout() {
+        assert_eq!(format_bits(EccLevel::Medium, 0), 0b101010000010010);
+        assert_eq!(format_bits(EccLevel::Low, 0), 0b111011111000100);
+        assert_eq!(alignment_positions(2), [6, 18]);
+        assert_eq!(alignment_positions(7), [6, 22, 38]);
+        assert_eq!(alignment_positions(32), [6, 34, 60, 86, 112, 138]);
+        assert_eq!(num_data_codewords(1, EccLevel::Low), 19);
+        assert_eq!(num_data_codewords(40, EccLevel::High), 1276);
+    }
+
+    #[test]
+    fn test_qr_encode() {
+        let qr = QrCode::encode(b"Hello, world!", EccLevel::Medium).unwrap();
+        assert_eq!((qr.version(), qr.size()), (1, 21));
+        // Finder pattern corners and the always-dark module
+        assert!(qr.get(0, 0) && qr.get(20, 0) && qr.get(0, 20));
+        assert!(!qr.get(7, 7));
+        assert!(qr.get(8, qr.size() - 8));
+
+        let long = vec![b'a'; 1000];
+        assert_eq!(QrCode::encode(&long, EccLevel::Low).unwrap().version(), 22);
+        assert!(QrCode::encode(&[0; 3000], EccLevel::Low).is_err());
+    }
+}
@@ -0,0 +1,280 @@
+//! Random numbers for the generator transformers.
+//!
+//! Randomness comes from an [`EntropySource`]: the operating system's by
+//! default, or one installed with [`set_entropy_source`], e.g. by a
+//! WebAssembly frontend that can reach `crypto.getRandomValues`. [`Rng`]
+//! stretches a seed from the source into a fast stream of numbers, and can be
+//! seeded explicitly when output should be reproducible.
+
+use crate::{CancelToken, TransformError, TransformOption, TransformOptionKind, TransformOptions};
+use std::sync::OnceLock;
+
+/// A source of unpredictable bytes
+pub trait EntropySource: Sync {
+    /// Fills `buf` with random bytes, or fails if none can be had
+    fn fill(&self, buf: &mut [u8]) -> Result<(), TransformError>;
+}
+
+static SOURCE: OnceLock<&'static dyn EntropySource> = OnceLock::new();
+
+/// Installs the source behind [`fill_random`] and [`Rng::new`]. Only the
+/// first call takes effect; returns whether this one did.
+pub fn set_entropy_source(source: &'static dyn EntropySource) -> bool {
+    SOURCE.set(source).is_ok()
+}
+
+/// Fills `buf` with bytes from the installed entropy source
+pub fn fill_random(buf: &mut [u8]) -> Result<(), TransformError> {
+    entropy_source().fill(buf)
+}
+
+/// The installed entropy source, or [`SystemEntropy`] if there is none
+pub(crate) fn entropy_source() -> &'static dyn EntropySource {
+    SOURCE.get().copied().unwrap_or(&SystemEntropy)
+}
+
+/// The default source: the operating system's secure generator, which is
+/// `/dev/urandom` on Unix and `RtlGenRandom` on Windows. Other targets (such
+/// as WebAssembly without an installed source) have none, so it fails there
+/// rather than hand out guessable bytes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemEntropy;
+
+impl EntropySource for SystemEntropy {
+    #[cfg(unix)]
+    fn fill(&self, buf: &mut [u8]) -> Result<(), TransformError> {
+        use std::io::Read;
+        std::fs::File::open("/dev/urandom")
+            .and_then(|mut urandom| urandom.read_exact(buf))
+            .map_err(|e| TransformError::EntropyError(format!("/dev/urandom: {}", e)))
+    }
+
+    #[cfg(windows)]
+    fn fill(&self, buf: &mut [u8]) -> Result<(), TransformError> {
+        #[link(name = "advapi32")]
+        extern "system" {
+            #[link_name = "SystemFunction036"]
+            fn RtlGenRandom(buffer: *mut u8, length: u32) -> u8;
+        }
+        for chunk in buf.chunks_mut(u32::MAX as usize) {
+            // SAFETY: the pointer and length describe the writable `chunk`
+            if unsafe { RtlGenRandom(chunk.as_mut_ptr(), chunk.len() as u32) } == 0 {
+                return Err(TransformError::EntropyError(
+                    "RtlGenRandom failed".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn fill(&self, _buf: &mut [u8]) -> Result<(), TransformError> {
+        Err(TransformError::EntropyError(
+            "this platform has no operating system source".to_string(),
+        ))
+    }
+}
+
+// Spreads a 64-bit seed into well mixed words (used to seed xoshiro)
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A xoshiro256** pseudo-random generator. Fast and statistically sound,
+/// but predictable from its output: passwords and keys should come straight
+/// from [`fill_random`].
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// A generator seeded from the entropy source
+    pub fn new() -> Result<Self, TransformError> {
+        let mut bytes = [0; 8];
+        fill_random(&mut bytes)?;
+        Ok(Self::from_seed(u64::from_le_bytes(bytes)))
+    }
+
+    /// A generator that always produces the same numbers for `seed`
+    pub fn from_seed(seed: u64) -> Self {
+        let mut seed = seed;
+        Self {
+            state: [(); 4].map(|_| splitmix64(&mut seed)),
+        }
+    }
+
+    /// The next 64 random bits
+    pub fn next_u64(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = &mut self.state;
+        let result = s1.wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = *s1 << 17;
+        *s2 ^= *s0;
+        *s3 ^= *s1;
+        *s1 ^= *s2;
+        *s0 ^= *s3;
+        *s2 ^= t;
+        *s3 = s3.rotate_left(45);
+        result
+    }
+
+    /// A uniformly distributed number below `bound` (which must not be 0)
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Rng::below needs a positive bound");
+        // Reject the top partial range so every result is equally likely
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    /// A uniformly distributed number in `0.0..1.0`
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Puts `items` in a uniformly random order (Fisher-Yates), polling `cancel` as it goes
+    pub fn shuffle<T>(
+        &mut self,
+        items: &mut [T],
+        cancel: &CancelToken,
+    ) -> Result<(), TransformError> {
+        for i in (1..items.len()).rev() {
+            cancel.check_every(i)?;
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+        Ok(())
+    }
+
+    /// Fills `buf` with random bytes
+    pub fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+/// A source that always fails, standing in for a platform without one
+#[cfg(test)]
+pub(crate) struct NoEntropy;
+
+#[cfg(test)]
+impl EntropySource for NoEntropy {
+    fn fill(&self, _buf: &mut [u8]) -> Result<(), TransformError> {
+        Err(TransformError::EntropyError("none in tests".to_string()))
+    }
+}
+
+/// Option making a generator's output reproducible
+pub(crate) const SEED_OPTION: TransformOption = TransformOption {
+    key: "seed",
+    description: "Seed for reproducible output (empty for random)",
+    default: "",
+    kind: TransformOptionKind::Text,
+};
+
+/// A generator seeded by the `seed` option, or from the entropy source if
+/// it is empty. Numeric seeds are used as is; any other text is hashed.
+pub(crate) fn rng_from_options(options: &TransformOptions) -> Result<Rng, TransformError> {
+    let seed = options.value(&SEED_OPTION).trim();
+    if seed.is_empty() {
+        Rng::new()
+    } else {
+        Ok(Rng::from_seed(seed.parse().unwrap_or_else(|_| {
+            super::xxhash::xxh64(seed.as_bytes(), 0)
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_rng_is_reproducible() {
+        let mut a = Rng::from_seed(42);
+        let mut b = Rng::from_seed(42);
+        let mut c = Rng::from_seed(43);
+        let first: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        assert_eq!(first, (0..8).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(first, (0..8).map(|_| c.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_rng_ranges() {
+        let mut rng = Rng::from_seed(7);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let value = rng.below(6);
+            seen[value as usize] = true;
+            let float = rng.next_f64();
+            assert!((0.0..1.0).contains(&float));
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        assert!(bytes.iter().any(|&b| b != 0));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let never = CancelToken::new();
+        let mut items: Vec<u32> = (0..50).collect();
+        Rng::from_seed(1).shuffle(&mut items, &never).unwrap();
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        let mut again: Vec<u32> = (0..50).collect();
+        Rng::from_seed(1).shuffle(&mut again, &never).unwrap();
+        assert_eq!(items, again);
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+
+        // Every position is reachable
+        let mut firsts = [false; 3];
+        let mut rng = Rng::from_seed(2);
+        for _ in 0..100 {
+            let mut small = [0, 1, 2];
+            rng.shuffle(&mut small, &never).unwrap();
+            firsts[small[0]] = true;
+        }
+        assert!(firsts.iter().all(|&seen| seen));
+        rng.shuffle::<u8>(&mut [], &never).unwrap();
+    }
+
+    #[test]
+    fn test_entropy_differs_between_calls() {
+        let mut a = [0; 16];
+        let mut b = [0; 16];
+        fill_random(&mut a).unwrap();
+        fill_random(&mut b).unwrap();
+        assert_ne!(a, b);
+        assert_ne!(
+            Rng::new().unwrap().next_u64(),
+            Rng::new().unwrap().next_u64()
+        );
+    }
+
+    #[test]
+    fn test_rng_from_options() {
+        let mut options = TransformOptions::new();
+        options.set("seed", "12345");
+        assert_eq!(
+            rng_from_options(&options).unwrap().next_u64(),
+            Rng::from_seed(12345).next_u64()
+        );
+        options.set("seed", "buup");
+        assert_eq!(
+            rng_from_options(&options).unwrap().next_u64(),
+            rng_from_options(&options).unwrap().next_u64()
+        );
+    }
+}
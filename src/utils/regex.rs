@@ -0,0 +1,597 @@
+//! A small regular expression engine for the line filter.
+//!
+//! Supports the common subset of Perl syntax: literals, `.`, character
+//! classes (`[a-z]`, `[^,]`, `\d`, `\w`, `\s` and their negations), anchors
+//! (`^`, `$`, `\b`, `\B`), groups (`(...)`, `(?:...)`), alternation and the
+//! greedy and lazy quantifiers `*`, `+`, `?` and `{n,m}`. Backreferences and
+//! lookaround are not supported.
+//!
+//! Patterns compile to a program run by a Pike VM, which steps every
+//! possible match forward one character at a time. Matching is linear in the
+//! text length, so no pattern can backtrack catastrophically.
+
+use super::CancelToken;
+use crate::TransformError;
+
+/// Upper bound on compiled program size, as `a{1000}{1000}` expands a lot
+const MAX_INSTRUCTIONS: usize = 100_000;
+
+/// Largest count accepted in `{n,m}`
+const MAX_REPEAT: u32 = 1000;
+
+/// A compiled regular expression
+#[derive(Debug, Clone)]
+pub struct Regex {
+    program: Vec<Inst>,
+    ignore_case: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Inst {
+    Char(char),
+    /// Any character but a line break
+    Any,
+    Class(Class),
+    Assert(Assertion),
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Assertion {
+    Start,
+    End,
+    WordBoundary,
+    NotWordBoundary,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Class {
+    negated: bool,
+    items: Vec<ClassItem>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ClassItem {
+    Range(char, char),
+    /// `\d`, `\w` or `\s`, negated for `\D`, `\W` and `\S`
+    Digit(bool),
+    Word(bool),
+    Space(bool),
+}
+
+#[derive(Debug)]
+enum Node {
+    Empty,
+    Char(char),
+    Any,
+    Class(Class),
+    Assert(Assertion),
+    Concat(Vec<Node>),
+    Alternate(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+impl Regex {
+    /// Compiles `pattern`, matching letters regardless of case when
+    /// `ignore_case` is set
+    pub fn new(pattern: &str, ignore_case: bool) -> Result<Self, TransformError> {
+        let invalid = |reason: &str| {
+            TransformError::InvalidArgument(
+                format!("Invalid pattern '{}': {}", pattern, reason).into(),
+            )
+        };
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            i: 0,
+        };
+        let node = parser.alternation().map_err(invalid)?;
+        if parser.i < parser.chars.len() {
+            // alternation() only stops early at a ')'
+            return Err(invalid("unmatched ')'"));
+        }
+
+        let mut program = Vec::new();
+        compile(&node, &mut program).map_err(invalid)?;
+        program.push(Inst::Match);
+        Ok(Regex {
+            program,
+            ignore_case,
+        })
+    }
+
+    /// Whether the pattern matches anywhere in `text`
+    pub fn is_match(&self, text: &str, cancel: &CancelToken) -> Result<bool, TransformError> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut current = Threads::new(self.program.len());
+        let mut next = Threads::new(self.program.len());
+
+        for i in 0..=chars.len() {
+            cancel.check_every(i)?;
+            // A new attempt starts at every position, so matches aren't anchored
+            if self.add_thread(&mut current, 0, &chars, i) {
+                return Ok(true);
+            }
+            let Some(&c) = chars.get(i) else {
+                break;
+            };
+            next.clear();
+            for &pc in &current.list {
+                let matched = match &self.program[pc] {
+                    Inst::Char(expected) => self.chars_equal(*expected, c),
+                    Inst::Any => c != '\n',
+                    Inst::Class(class) => self.class_matches(class, c),
+                    _ => false,
+                };
+                if matched && self.add_thread(&mut next, pc + 1, &chars, i + 1) {
+                    return Ok(true);
+                }
+            }
+            std::mem::swap(&mut current, &mut next);
+        }
+        Ok(false)
+    }
+
+    // Adds the thread at pc and everything reachable from it without
+    // consuming a character. Returns true if that reaches a match.
+    fn add_thread(&self, threads: &mut Threads, pc: usize, chars: &[char], i: usize) -> bool {
+        let mut stack = vec![pc];
+        while let Some(pc) = stack.pop() {
+            if std::mem::replace(&mut threads.seen[pc], true) {
+                continue;
+            }
+            threads.marked.push(pc);
+            match self.program[pc] {
+                Inst::Match => return true,
+                Inst::Jump(target) => stack.push(target),
+                // Pushed in reverse so the preferred branch runs first
+                Inst::Split(first, second) => stack.extend([second, first]),
+                Inst::Assert(assertion) => {
+                    if assertion_holds(assertion, chars, i) {
+                        stack.push(pc + 1);
+                    }
+                }
+                Inst::Char(_) | Inst::Any | Inst::Class(_) => threads.list.push(pc),
+            }
+        }
+        false
+    }
+
+    fn chars_equal(&self, expected: char, c: char) -> bool {
+        expected == c || (self.ignore_case && fold(expected) == fold(c))
+    }
+
+    fn class_matches(&self, class: &Class, c: char) -> bool {
+        let contains = |c: char| class.items.iter().any(|item| item.matches(c));
+        let found = contains(c)
+            || (self.ignore_case
+                && (c.to_lowercase().any(contains) || c.to_uppercase().any(contains)));
+        found != class.negated
+    }
+}
+
+impl ClassItem {
+    fn matches(self, c: char) -> bool {
+        match self {
+            ClassItem::Range(low, high) => (low..=high).contains(&c),
+            ClassItem::Digit(negated) => c.is_ascii_digit() != negated,
+            ClassItem::Word(negated) => is_word_char(c) != negated,
+            ClassItem::Space(negated) => c.is_whitespace() != negated,
+        }
+    }
+}
+
+/// Program counters of the live threads, plus which ones were already
+/// visited at this position
+struct Threads {
+    list: Vec<usize>,
+    seen: Vec<bool>,
+    marked: Vec<usize>,
+}
+
+impl Threads {
+    fn new(size: usize) -> Self {
+        Threads {
+            list: Vec::new(),
+            seen: vec![false; size],
+            marked: Vec::new(),
+        }
+    }
+
+    fn clear(&mut self) {
+        for pc in self.marked.drain(..) {
+            self.seen[pc] = false;
+        }
+        self.list.clear();
+    }
+}
+
+fn assertion_holds(assertion: Assertion, chars: &[char], i: usize) -> bool {
+    let before = i.checked_sub(1).map(|j| chars[j]);
+    let after = chars.get(i).copied();
+    let boundary = || before.is_some_and(is_word_char) != after.is_some_and(is_word_char);
+    match assertion {
+        Assertion::Start => i == 0,
+        Assertion::End => i == chars.len(),
+        Assertion::WordBoundary => boundary(),
+        Assertion::NotWordBoundary => !boundary(),
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    i: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.i).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let matches = self.peek() == Some(c);
+        if matches {
+            self.i += 1;
+        }
+        matches
+    }
+
+    // Branches separated by '|', up to a ')' or the end of the pattern
+    fn alternation(&mut self) -> Result<Node, &'static str> {
+        let mut branches = vec![self.concatenation()?];
+        while self.eat('|') {
+            branches.push(self.concatenation()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap_or(Node::Empty)
+        } else {
+            Node::Alternate(branches)
+        })
+    }
+
+    fn concatenation(&mut self) -> Result<Node, &'static str> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            nodes.push(self.quantified(atom)?);
+        }
+        Ok(match nodes.len() {
+            0 => Node::Empty,
+            1 => nodes.pop().unwrap_or(Node::Empty),
+            _ => Node::Concat(nodes),
+        })
+    }
+
+    fn atom(&mut self) -> Result<Node, &'static str> {
+        let c = self.peek().ok_or("unexpected end")?;
+        self.i += 1;
+        Ok(match c {
+            '(' => {
+                // Groups don't capture, so (?:...) is the same as (...)
+                if self.chars[self.i..].starts_with(&['?', ':']) {
+                    self.i += 2;
+                } else if self.peek() == Some('?') {
+                    return Err("lookaround and inline flags are not supported");
+                }
+                let node = self.alternation()?;
+                if !self.eat(')') {
+                    return Err("unclosed '('");
+                }
+                node
+            }
+            '[' => Node::Class(self.class()?),
+            '.' => Node::Any,
+            '^' => Node::Assert(Assertion::Start),
+            '$' => Node::Assert(Assertion::End),
+            '*' | '+' | '?' => return Err("nothing to repeat"),
+            '\\' => match self.escape()? {
+                Escape::Char(c) => Node::Char(c),
+                Escape::Item(item) => Node::Class(Class {
+                    negated: false,
+                    items: vec![item],
+                }),
+                Escape::Assert(assertion) => Node::Assert(assertion),
+            },
+            c => Node::Char(c),
+        })
+    }
+
+    fn quantified(&mut self, mut node: Node) -> Result<Node, &'static str> {
+        loop {
+            let (min, max) = match self.peek() {
+                Some('{') => match self.counted()? {
+                    Some(range) => range,
+                    None => return Ok(node),
+                },
+                Some(c @ ('*' | '+' | '?')) => {
+                    self.i += 1;
+                    (u32::from(c == '+'), (c == '?').then_some(1))
+                }
+                _ => return Ok(node),
+            };
+            // Laziness only changes which match is found, not whether there is one
+            self.eat('?');
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+            };
+        }
+    }
+
+    // Parses {n}, {n,} or {n,m} and moves past it. Anything else isn't a
+    // quantifier, so the '{' is left to be read as a literal.
+    fn counted(&mut self) -> Result<Option<(u32, Option<u32>)>, &'static str> {
+        let Some(length) = self.chars[self.i..].iter().position(|&c| c == '}') else {
+            return Ok(None);
+        };
+        let inner: String = self.chars[self.i + 1..self.i + length].iter().collect();
+        let number = |text: &str| -> Result<Option<u32>, &'static str> {
+            if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+                return Ok(None);
+            }
+            match text.parse() {
+                Ok(n) if n <= MAX_REPEAT => Ok(Some(n)),
+                _ => Err("repeat count is over 1000"),
+            }
+        };
+        let range = match inner.split_once(',') {
+            None => number(&inner)?.map(|n| (n, Some(n))),
+            Some((min, "")) => number(min)?.map(|n| (n, None)),
+            Some((min, max)) => match (number(min)?, number(max)?) {
+                (Some(min), Some(max)) if min > max => return Err("repeat range is reversed"),
+                (Some(min), Some(max)) => Some((min, Some(max))),
+                _ => None,
+            },
+        };
+        if range.is_some() {
+            self.i += length + 1;
+        }
+        Ok(range)
+    }
+
+    fn class(&mut self) -> Result<Class, &'static str> {
+        let mut class = Class {
+            negated: self.eat('^'),
+            items: Vec::new(),
+        };
+        let mut first = true;
+        loop {
+            let c = self.peek().ok_or("unclosed '['")?;
+            self.i += 1;
+            if c == ']' && !first {
+                return Ok(class);
+            }
+            first = false;
+            let low = match c {
+                '\\' => match self.escape()? {
+                    Escape::Char(c) => c,
+                    Escape::Item(item) => {
+                        class.items.push(item);
+                        continue;
+                    }
+                    Escape::Assert(_) => return Err("\\b and \\B can't be used in a class"),
+                },
+                c => c,
+            };
+            // a-z, but a trailing '-' as in [a-] is literal
+            if self.peek() == Some('-') && self.chars.get(self.i + 1).is_some_and(|&c| c != ']') {
+                self.i += 1;
+                let c = self.peek().ok_or("unclosed '['")?;
+                self.i += 1;
+                let high = match c {
+                    '\\' => match self.escape()? {
+                        Escape::Char(c) => c,
+                        _ => return Err("class range must end in a character"),
+                    },
+                    c => c,
+                };
+                if low > high {
+                    return Err("class range is reversed");
+                }
+                class.items.push(ClassItem::Range(low, high));
+            } else {
+                class.items.push(ClassItem::Range(low, low));
+            }
+        }
+    }
+
+    // The escape after a '\'
+    fn escape(&mut self) -> Result<Escape, &'static str> {
+        let c = self.peek().ok_or("pattern ends with '\\'")?;
+        self.i += 1;
+        Ok(match c {
+            'd' | 'D' => Escape::Item(ClassItem::Digit(c == 'D')),
+            'w' | 'W' => Escape::Item(ClassItem::Word(c == 'W')),
+            's' | 'S' => Escape::Item(ClassItem::Space(c == 'S')),
+            'b' => Escape::Assert(Assertion::WordBoundary),
+            'B' => Escape::Assert(Assertion::NotWordBoundary),
+            'n' => Escape::Char('\n'),
+            'r' => Escape::Char('\r'),
+            't' => Escape::Char('\t'),
+            'f' => Escape::Char('\x0c'),
+            'v' => Escape::Char('\x0b'),
+            '0' => Escape::Char('\0'),
+            c if c.is_alphanumeric() => return Err("unknown escape"),
+            c => Escape::Char(c),
+        })
+    }
+}
+
+enum Escape {
+    Char(char),
+    Item(ClassItem),
+    Assert(Assertion),
+}
+
+fn compile(node: &Node, program: &mut Vec<Inst>) -> Result<(), &'static str> {
+    if program.len() > MAX_INSTRUCTIONS {
+        return Err("pattern is too large");
+    }
+    match node {
+        Node::Empty => {}
+        Node::Char(c) => program.push(Inst::Char(*c)),
+        Node::Any => program.push(Inst::Any),
+        Node::Class(class) => program.push(Inst::Class(class.clone())),
+        Node::Assert(assertion) => program.push(Inst::Assert(*assertion)),
+        Node::Concat(nodes) => {
+            for node in nodes {
+                compile(node, program)?;
+            }
+        }
+        Node::Alternate(branches) => {
+            let mut jumps = Vec::new();
+            for (index, branch) in branches.iter().enumerate() {
+                if index + 1 < branches.len() {
+                    let split = program.len();
+                    program.push(Inst::Split(split + 1, 0));
+                    compile(branch, program)?;
+                    jumps.push(program.len());
+                    program.push(Inst::Jump(0));
+                    let next = program.len();
+                    program[split] = Inst::Split(split + 1, next);
+                } else {
+                    compile(branch, program)?;
+                }
+            }
+            let end = program.len();
+            for jump in jumps {
+                program[jump] = Inst::Jump(end);
+            }
+        }
+        Node::Repeat { node, min, max } => {
+            for _ in 0..*min {
+                compile(node, program)?;
+            }
+            match max {
+                None => {
+                    let split = program.len();
+                    program.push(Inst::Split(split + 1, 0));
+                    compile(node, program)?;
+                    program.push(Inst::Jump(split));
+                    let end = program.len();
+                    program[split] = Inst::Split(split + 1, end);
+                }
+                Some(max) => {
+                    let mut splits = Vec::new();
+                    for _ in *min..*max {
+                        splits.push(program.len());
+                        program.push(Inst::Split(0, 0));
+                        compile(node, program)?;
+                    }
+                    let end = program.len();
+                    for split in splits {
+                        program[split] = Inst::Split(split + 1, end);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, text: &str) -> bool {
+        Regex::new(pattern, false)
+            .unwrap()
+            .is_match(text, &CancelToken::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_literals_and_anchors() {
+        assert!(matches("error", "an error occurred"));
+        assert!(!matches("error", "all good"));
+        assert!(matches("^GET ", "GET /index.html"));
+        assert!(!matches("^GET ", "a GET /"));
+        assert!(matches("html$", "GET /index.html"));
+        assert!(matches("^$", ""));
+        assert!(matches("", "anything"));
+        assert!(matches("a\\.b", "a.b"));
+        assert!(!matches("a\\.b", "axb"));
+        assert!(matches("\\bcat\\b", "the cat sat"));
+        assert!(!matches("\\bcat\\b", "concatenate"));
+        assert!(matches("\\Bcat\\B", "concatenate"));
+    }
+
+    #[test]
+    fn test_classes_and_quantifiers() {
+        assert!(matches("^\\d{3}-\\d{4}$", "555-1234"));
+        assert!(!matches("^\\d{3}-\\d{4}$", "555-12345"));
+        assert!(matches("^[A-Z][a-z]+$", "Hello"));
+        assert!(!matches("^[A-Z][a-z]+$", "hello"));
+        assert!(matches("^[^,]*,[^,]*$", "a,b"));
+        assert!(!matches("^[^,]*,[^,]*$", "a,b,c"));
+        assert!(matches("^[-\\w.]+@\\w+\\.com$", "first.last-1@example.com"));
+        assert!(matches("^a{2,}$", "aaaa"));
+        assert!(!matches("^a{2,3}$", "aaaa"));
+        assert!(matches("^colou?r$", "color"));
+        assert!(matches("^(?:ab)+?c$", "ababc"));
+        assert!(matches("x{y", "x{y"));
+        assert!(matches("^[]a]+$", "]a]"));
+        assert!(matches("^\\S+\\s\\S+$", "two words"));
+    }
+
+    #[test]
+    fn test_alternation_and_groups() {
+        assert!(matches("^(GET|POST) /api", "POST /api/users"));
+        assert!(!matches("^(GET|POST) /api", "DELETE /api/users"));
+        assert!(matches("^(a|b|)c$", "c"));
+        assert!(matches("warn|error", "ERROR then error"));
+        assert!(matches("^(a*)*b$", "aaab"));
+        // Linear time: this would take forever with naive backtracking
+        let text = "a".repeat(5000);
+        assert!(!matches("^(a|aa)*$b", &text));
+        assert!(!matches("(a*)*c", &text));
+    }
+
+    #[test]
+    fn test_ignore_case() {
+        let regex = Regex::new("^h[a-e]llo wörld$", true).unwrap();
+        let cancel = CancelToken::new();
+        assert!(regex.is_match("HELLO WÖRLD", &cancel).unwrap());
+        assert!(!regex.is_match("HXLLO WÖRLD", &cancel).unwrap());
+        let regex = Regex::new("[^a]", true).unwrap();
+        assert!(!regex.is_match("A", &cancel).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        for pattern in [
+            "(a", "a)", "[a", "*a", "|*", "\\q", "[z-a]", "a{3,1}", "(?=a)", "\\", "a{1001}",
+        ] {
+            assert!(Regex::new(pattern, false).is_err(), "{}", pattern);
+        }
+        assert!(Regex::new("(a{1000}){1000}", false).is_err());
+    }
+
+    #[test]
+    fn test_cancelled_match() {
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let regex = Regex::new("b", false).unwrap();
+        assert_eq!(
+            regex.is_match("aaaa", &cancel),
+            Err(TransformError::Cancelled)
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! SHA-1 (RFC 3174).
+
+use super::digest::{BlockBuffer, Digest, LengthOrder, BLOCK_SIZE};
+
+// Initial hash values (H0-H4)
+const INITIAL_STATE: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+/// Incremental SHA-1 hasher
+#[derive(Debug, Clone)]
+pub struct Sha1 {
+    state: [u32; 5],
+    buffer: BlockBuffer,
+}
+
+impl Default for Sha1 {
+    fn default() -> Self {
+        Self {
+            state: INITIAL_STATE,
+            buffer: BlockBuffer::default(),
+        }
+    }
+}
+
+impl Digest for Sha1 {
+    type Output = [u8; 20];
+
+    fn update(&mut self, data: &[u8]) {
+        let state = &mut self.state;
+        self.buffer.update(data, |block| compress(state, block));
+    }
+
+    fn finalize(self) -> Self::Output {
+        let Self { mut state, buffer } = self;
+        buffer.finish(LengthOrder::BigEndian, |block| compress(&mut state, block));
+        let mut output = [0u8; 20];
+        for (bytes, word) in output.chunks_exact_mut(4).zip(state) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+        output
+    }
+}
+
+fn compress(h: &mut [u32; 5], block: &[u8; BLOCK_SIZE]) {
+    let mut w = [0u32; 80];
+    for (i, chunk) in block.chunks_exact(4).enumerate() {
+        w[i] = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+
+    for i in 16..80 {
+        w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+    }
+
+    let mut a = h[0];
+    let mut b = h[1];
+    let mut c = h[2];
+    let mut d = h[3];
+    let mut e = h[4];
+
+    for (i, w_i) in w.iter().enumerate() {
+        let (f, k) = match i {
+            0..=19 => (((b & c) | (!b & d)), 0x5A827999),
+            20..=39 => ((b ^ c ^ d), 0x6ED9EBA1),
+            40..=59 => (((b & c) | (b & d) | (c & d)), 0x8F1BBCDC),
+            60..=79 => ((b ^ c ^ d), 0xCA62C1D6),
+            _ => unreachable!(), // Should not happen
+        };
+
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(*w_i);
+
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+    }
+
+    h[0] = h[0].wrapping_add(a);
+    h[1] = h[1].wrapping_add(b);
+    h[2] = h[2].wrapping_add(c);
+    h[3] = h[3].wrapping_add(d);
+    h[4] = h[4].wrapping_add(e);
+}
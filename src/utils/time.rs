@@ -0,0 +1,492 @@
+//! Wall clock time and calendar arithmetic for the date transformers.
+//!
+//! The current time comes from a [`Clock`]: the operating system's by
+//! default, or one installed with [`set_clock`], since WebAssembly in a
+//! browser has no system clock and reads `Date.now()` instead. The clock
+//! also knows the local time zone's offset from UTC. Dates are proleptic
+//! Gregorian: [`Timestamp`] is an instant and [`DateTime`] its calendar
+//! date and time at some UTC offset.
+
+use std::sync::OnceLock;
+
+#[cfg(unix)]
+mod tzif;
+
+/// A source of the current time
+pub trait Clock: Sync {
+    /// Milliseconds since the Unix epoch
+    fn now_millis(&self) -> i64;
+
+    /// Offset of local time from UTC, in seconds, at `seconds` since the
+    /// epoch. UTC unless the clock knows better.
+    fn utc_offset_seconds(&self, _seconds: i64) -> i32 {
+        0
+    }
+}
+
+static CLOCK: OnceLock<&'static dyn Clock> = OnceLock::new();
+
+/// Installs the clock behind [`now_millis`]. Only the first call takes
+/// effect; returns whether this one did.
+pub fn set_clock(clock: &'static dyn Clock) -> bool {
+    CLOCK.set(clock).is_ok()
+}
+
+fn clock() -> &'static dyn Clock {
+    CLOCK.get().copied().unwrap_or(&SystemClock)
+}
+
+/// Milliseconds since the Unix epoch according to the installed clock
+pub fn now_millis() -> i64 {
+    clock().now_millis()
+}
+
+/// The local UTC offset in seconds at `seconds` since the epoch, according
+/// to the installed clock
+pub fn local_offset_seconds(seconds: i64) -> i32 {
+    clock().utc_offset_seconds(seconds)
+}
+
+/// The default clock. Reads the system time, except on WebAssembly where
+/// there is none and it stays at the epoch. On Unix the local time zone
+/// comes from `TZ` or `/etc/localtime`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> i64 {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Ok(elapsed) = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            return elapsed.as_millis() as i64;
+        }
+        0
+    }
+
+    #[cfg(unix)]
+    fn utc_offset_seconds(&self, seconds: i64) -> i32 {
+        tzif::local_offset(seconds).unwrap_or(0)
+    }
+}
+
+pub const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Days since 1970-01-01 of a date (month 1 to 12, day 1 to 31)
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // Howard Hinnant's algorithm, counting years from March
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The (year, month, day) that is `days` after 1970-01-01
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Whether `year` has a February 29th
+pub fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in `month` (1 to 12) of `year`
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Day of the week of a day count from [`days_from_civil`], 0 for Sunday
+pub fn weekday(days: i64) -> u32 {
+    // 1970-01-01 was a Thursday
+    (days + 4).rem_euclid(7) as u32
+}
+
+/// Formats milliseconds since the epoch as RFC 3339 UTC, with the
+/// milliseconds only when there are any (`2024-02-29T12:00:00Z`)
+pub fn format_rfc3339(millis: i64) -> String {
+    Timestamp::from_millis(millis).to_rfc3339()
+}
+
+/// An instant: seconds since the Unix epoch and nanoseconds within that
+/// second
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    pub seconds: i64,
+    pub nanos: u32,
+}
+
+impl Timestamp {
+    /// The instant `millis` milliseconds after the epoch
+    pub fn from_millis(millis: i64) -> Self {
+        Self {
+            seconds: millis.div_euclid(1000),
+            nanos: millis.rem_euclid(1000) as u32 * 1_000_000,
+        }
+    }
+
+    /// Whole milliseconds since the epoch, rounded down
+    pub fn as_millis(&self) -> i64 {
+        self.seconds * 1000 + i64::from(self.nanos / 1_000_000)
+    }
+
+    /// RFC 3339 in UTC, such as `2024-02-29T12:00:00.5Z`
+    pub fn to_rfc3339(&self) -> String {
+        self.to_rfc3339_with_offset(0)
+    }
+
+    /// RFC 3339 at the given offset from UTC, such as
+    /// `2024-02-29T23:00:00+11:00`
+    pub fn to_rfc3339_with_offset(&self, offset_seconds: i32) -> String {
+        let date_time = DateTime::from_timestamp(*self, offset_seconds);
+        format!(
+            "{}T{}{}",
+            date_time.format_date(),
+            date_time.format_time(),
+            format_offset(offset_seconds)
+        )
+    }
+}
+
+/// A calendar date and time of day, at an offset that is kept separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub nanos: u32,
+}
+
+impl DateTime {
+    /// Midnight at the start of a date
+    pub fn from_date(year: i64, month: u32, day: u32) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanos: 0,
+        }
+    }
+
+    /// The date and time of `timestamp` at `offset_seconds` from UTC
+    pub fn from_timestamp(timestamp: Timestamp, offset_seconds: i32) -> Self {
+        let seconds = timestamp.seconds + i64::from(offset_seconds);
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+        let second_of_day = seconds.rem_euclid(86_400) as u32;
+        Self {
+            year,
+            month,
+            day,
+            hour: second_of_day / 3600,
+            minute: second_of_day / 60 % 60,
+            second: second_of_day % 60,
+            nanos: timestamp.nanos,
+        }
+    }
+
+    /// The instant this is at `offset_seconds` from UTC. A leap second
+    /// (second 60) is read as the first second of the next minute.
+    pub fn timestamp(&self, offset_seconds: i32) -> Timestamp {
+        let days = days_from_civil(self.year, self.month, self.day);
+        let seconds = days * 86_400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+            - i64::from(offset_seconds);
+        Timestamp {
+            seconds,
+            nanos: self.nanos,
+        }
+    }
+
+    /// Whether every field is in range (allowing a leap second)
+    pub fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && (1..=days_in_month(self.year, self.month)).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second <= 60
+            && self.nanos < 1_000_000_000
+    }
+
+    /// `YYYY-MM-DD`, with a sign and more digits outside years 0 to 9999
+    pub fn format_date(&self) -> String {
+        if (0..=9999).contains(&self.year) {
+            format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+        } else {
+            // RFC 3339 stops at year 9999; ISO 8601 extends it with a sign
+            format!("{:+05}-{:02}-{:02}", self.year, self.month, self.day)
+        }
+    }
+
+    /// `HH:MM:SS`, followed by as many groups of three fraction digits as
+    /// the nanoseconds need
+    pub fn format_time(&self) -> String {
+        let time = format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second);
+        match self.nanos {
+            0 => time,
+            nanos if nanos.is_multiple_of(1_000_000) => {
+                format!("{}.{:03}", time, nanos / 1_000_000)
+            }
+            nanos if nanos.is_multiple_of(1000) => format!("{}.{:06}", time, nanos / 1000),
+            nanos => format!("{}.{:09}", time, nanos),
+        }
+    }
+}
+
+/// `Z` for UTC, otherwise `+HH:MM` or `-HH:MM` (with seconds if any)
+pub fn format_offset(offset_seconds: i32) -> String {
+    if offset_seconds == 0 {
+        return "Z".to_string();
+    }
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let offset = offset_seconds.unsigned_abs();
+    let formatted = format!("{}{:02}:{:02}", sign, offset / 3600, offset / 60 % 60);
+    if offset.is_multiple_of(60) {
+        formatted
+    } else {
+        format!("{}:{:02}", formatted, offset % 60)
+    }
+}
+
+/// Parses an ISO 8601 / RFC 3339 date or date and time, such as
+/// `2024-02-29`, `2024-02-29 12:00` or `2024-02-29T12:00:00.5+11:00`,
+/// returning it with its UTC offset in seconds if it has one
+pub fn parse_iso8601(text: &str) -> Option<(DateTime, Option<i32>)> {
+    let mut cursor = Cursor::new(text.trim());
+    let year = match cursor.peek() {
+        Some(sign @ (b'+' | b'-')) => {
+            cursor.pos += 1;
+            let year = i64::from(cursor.number(4, 6)?);
+            if sign == b'-' {
+                -year
+            } else {
+                year
+            }
+        }
+        _ => i64::from(cursor.number(4, 4)?),
+    };
+    cursor.expect(b'-')?;
+    let month = cursor.number(2, 2)?;
+    cursor.expect(b'-')?;
+    let day = cursor.number(2, 2)?;
+    let mut date_time = DateTime::from_date(year, month, day);
+    let mut offset = None;
+
+    if matches!(cursor.peek(), Some(b'T' | b't' | b' ')) {
+        cursor.pos += 1;
+        date_time.hour = cursor.number(2, 2)?;
+        cursor.expect(b':')?;
+        date_time.minute = cursor.number(2, 2)?;
+        if cursor.peek() == Some(b':') {
+            cursor.pos += 1;
+            date_time.second = cursor.number(2, 2)?;
+            if matches!(cursor.peek(), Some(b'.' | b',')) {
+                cursor.pos += 1;
+                date_time.nanos = cursor.fraction()?;
+            }
+        }
+        offset = parse_offset(&mut cursor)?;
+    }
+    (cursor.is_done() && date_time.is_valid()).then_some((date_time, offset))
+}
+
+// "Z", "+HH:MM", "+HHMM" or "+HH"; nothing at all is no offset
+fn parse_offset(cursor: &mut Cursor) -> Option<Option<i32>> {
+    let sign = match cursor.peek() {
+        None => return Some(None),
+        Some(b'Z' | b'z') => {
+            cursor.pos += 1;
+            return Some(Some(0));
+        }
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        Some(_) => return None,
+    };
+    cursor.pos += 1;
+    let hours = cursor.number(2, 2)?;
+    if cursor.peek() == Some(b':') {
+        cursor.pos += 1;
+    }
+    let minutes = if cursor.is_done() {
+        0
+    } else {
+        cursor.number(2, 2)?
+    };
+    (hours < 24 && minutes < 60).then_some(Some(sign * (hours * 3600 + minutes * 60) as i32))
+}
+
+/// Position in text being parsed
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            bytes: text.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        (self.peek() == Some(byte)).then(|| self.pos += 1)
+    }
+
+    // A run of `min` to `max` digits
+    fn number(&mut self, min: usize, max: usize) -> Option<u32> {
+        let start = self.pos;
+        while self.pos - start < max && self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.pos - start < min {
+            return None;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    // Fraction digits as nanoseconds, ignoring any past the ninth
+    fn fraction(&mut self) -> Option<u32> {
+        let start = self.pos;
+        let mut nanos = 0;
+        while let Some(digit) = self.peek().filter(u8::is_ascii_digit) {
+            if self.pos - start < 9 {
+                nanos = nanos * 10 + u32::from(digit - b'0');
+            }
+            self.pos += 1;
+        }
+        let digits = (self.pos - start).min(9);
+        (digits > 0).then(|| nanos * 10u32.pow(9 - digits as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        for days in (-800_000..800_000).step_by(997) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+            assert!(day <= days_in_month(year, month));
+        }
+    }
+
+    #[test]
+    fn test_format_rfc3339() {
+        assert_eq!(format_rfc3339(0), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            format_rfc3339(1_709_208_000_123),
+            "2024-02-29T12:00:00.123Z"
+        );
+        assert_eq!(format_rfc3339(-1), "1969-12-31T23:59:59.999Z");
+        assert_eq!(
+            format_rfc3339(253_402_300_800_000),
+            "+10000-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_formatting() {
+        let timestamp = Timestamp {
+            seconds: 1_709_208_000,
+            nanos: 500_000,
+        };
+        assert_eq!(timestamp.to_rfc3339(), "2024-02-29T12:00:00.000500Z");
+        assert_eq!(
+            timestamp.to_rfc3339_with_offset(11 * 3600),
+            "2024-02-29T23:00:00.000500+11:00"
+        );
+        assert_eq!(
+            Timestamp::from_millis(0).to_rfc3339_with_offset(-(5 * 3600 + 30 * 60)),
+            "1969-12-31T18:30:00-05:30"
+        );
+        assert_eq!(Timestamp::from_millis(-1500).as_millis(), -1500);
+    }
+
+    #[test]
+    fn test_parse_iso8601() {
+        let (date_time, offset) = parse_iso8601("2024-02-29T23:00:00.5+11:00").unwrap();
+        assert_eq!(offset, Some(39_600));
+        assert_eq!(date_time.nanos, 500_000_000);
+        assert_eq!(
+            date_time.timestamp(39_600),
+            Timestamp {
+                seconds: 1_709_208_000,
+                nanos: 500_000_000
+            }
+        );
+        assert_eq!(
+            parse_iso8601("2024-02-29"),
+            Some((DateTime::from_date(2024, 2, 29), None))
+        );
+        assert_eq!(parse_iso8601("2024-02-29 12:30").unwrap().0.minute, 30);
+        assert_eq!(parse_iso8601("2024-02-29T12:30:00z").unwrap().1, Some(0));
+        assert_eq!(
+            parse_iso8601("2024-02-29T12:30:00-0800").unwrap().1,
+            Some(-28_800)
+        );
+        assert_eq!(parse_iso8601("+10000-01-01").unwrap().0.year, 10_000);
+        for invalid in [
+            "2023-02-29",
+            "2024-13-01",
+            "2024-02-29T24:00",
+            "2024-2-29",
+            "2024-02-29T12",
+            "2024-02-29T12:00:00+25:00",
+            "2024-02-29 trailing",
+        ] {
+            assert_eq!(parse_iso8601(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_calendar_helpers() {
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert_eq!(days_in_month(2023, 2), 28);
+        // 2024-02-29 was a Thursday
+        assert_eq!(weekday(days_from_civil(2024, 2, 29)), 4);
+        assert!(now_millis() > 1_600_000_000_000);
+    }
+}
@@ -0,0 +1,156 @@
+//! The local UTC offset from the system's compiled time zone files (TZif,
+//! RFC 8536), found through `TZ` or `/etc/localtime`.
+
+use std::sync::OnceLock;
+
+/// The local zone's file, read once
+fn local_zone() -> Option<&'static [u8]> {
+    static ZONE: OnceLock<Option<Vec<u8>>> = OnceLock::new();
+    ZONE.get_or_init(|| {
+        let path = match std::env::var("TZ") {
+            Ok(name) if !name.is_empty() => {
+                let name = name.trim_start_matches(':');
+                if name.starts_with('/') {
+                    name.to_string()
+                } else {
+                    format!("/usr/share/zoneinfo/{}", name)
+                }
+            }
+            _ => "/etc/localtime".to_string(),
+        };
+        std::fs::read(path).ok()
+    })
+    .as_deref()
+}
+
+/// Local offset from UTC at `seconds` since the epoch
+pub(super) fn local_offset(seconds: i64) -> Option<i32> {
+    offset_at(local_zone()?, seconds)
+}
+
+/// The offset in force at `seconds` according to TZif `data`: that of the
+/// last transition at or before it, or the first standard time type before
+/// any transition. Times past the last transition keep its offset.
+pub(super) fn offset_at(data: &[u8], seconds: i64) -> Option<i32> {
+    if data.get(..4)? != b"TZif" {
+        return None;
+    }
+    let header = Header::parse(data)?;
+    // Version 2 and later repeat the data with 64-bit times after the
+    // version 1 block
+    let (header, body, time_size) = if data[4] >= b'2' {
+        let v2 = &data[44 + header.block_len(4)..];
+        (Header::parse(v2)?, &v2[44..], 8)
+    } else {
+        (header, &data[44..], 4)
+    };
+
+    let times = body.get(..header.timecnt * time_size)?;
+    let indices = body.get(times.len()..times.len() + header.timecnt)?;
+    let types_start = times.len() + indices.len();
+    let types = body.get(types_start..types_start + header.typecnt * 6)?;
+    let offset_of = |index: usize| {
+        let ttinfo = types.get(index * 6..index * 6 + 6)?;
+        Some(i32::from_be_bytes(ttinfo[..4].try_into().ok()?))
+    };
+
+    let transition = |i: usize| -> i64 {
+        let bytes = &times[i * time_size..(i + 1) * time_size];
+        if time_size == 8 {
+            i64::from_be_bytes(bytes.try_into().unwrap_or_default())
+        } else {
+            i64::from(i32::from_be_bytes(bytes.try_into().unwrap_or_default()))
+        }
+    };
+    let passed = (0..header.timecnt)
+        .take_while(|&i| transition(i) <= seconds)
+        .count();
+    if passed == 0 {
+        let standard = (0..header.typecnt)
+            .find(|&i| types.get(i * 6 + 4) == Some(&0))
+            .unwrap_or(0);
+        return offset_of(standard);
+    }
+    offset_of(usize::from(indices[passed - 1]))
+}
+
+/// The counts from a TZif header
+struct Header {
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let count = |i: usize| -> Option<usize> {
+            let bytes = data.get(20 + i * 4..24 + i * 4)?;
+            Some(u32::from_be_bytes(bytes.try_into().ok()?) as usize)
+        };
+        Some(Self {
+            isutcnt: count(0)?,
+            isstdcnt: count(1)?,
+            leapcnt: count(2)?,
+            timecnt: count(3)?,
+            typecnt: count(4)?,
+            charcnt: count(5)?,
+        })
+    }
+
+    // Length of the data block after the header, for times of `time_size`
+    fn block_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A version 1 zone at +10:00, switching to +11:00 at t = 1000 and back
+    // at t = 2000
+    fn sample_zone() -> Vec<u8> {
+        let mut data = b"TZif".to_vec();
+        data.extend_from_slice(&[0; 16]);
+        for count in [0u32, 0, 0, 2, 2, 8] {
+            data.extend_from_slice(&count.to_be_bytes());
+        }
+        data.extend_from_slice(&1000i32.to_be_bytes());
+        data.extend_from_slice(&2000i32.to_be_bytes());
+        data.extend_from_slice(&[1, 0]);
+        data.extend_from_slice(&36_000i32.to_be_bytes());
+        data.extend_from_slice(&[0, 0]);
+        data.extend_from_slice(&39_600i32.to_be_bytes());
+        data.extend_from_slice(&[1, 5]);
+        data.extend_from_slice(b"AEST\0AE\0");
+        data
+    }
+
+    #[test]
+    fn test_offset_at_transitions() {
+        let zone = sample_zone();
+        assert_eq!(offset_at(&zone, 0), Some(36_000));
+        assert_eq!(offset_at(&zone, 1000), Some(39_600));
+        assert_eq!(offset_at(&zone, 1999), Some(39_600));
+        assert_eq!(offset_at(&zone, 5000), Some(36_000));
+        assert_eq!(offset_at(b"nope", 0), None);
+    }
+
+    #[test]
+    fn test_system_zone_files() {
+        // Only where the zone database is installed
+        if let Ok(zone) = std::fs::read("/usr/share/zoneinfo/Australia/Sydney") {
+            // Daylight saving in January, standard time in July (2024)
+            assert_eq!(offset_at(&zone, 1_704_067_200), Some(39_600));
+            assert_eq!(offset_at(&zone, 1_719_792_000), Some(36_000));
+        }
+    }
+}
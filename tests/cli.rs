@@ -0,0 +1,65 @@
+//! End-to-end tests that run the `buup` binary.
+//!
+//! `XDG_CONFIG_HOME` points at a directory without a config file so the
+//! user's own defaults and aliases can't change the results.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn command(args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_buup"));
+    command
+        .args(args)
+        .env("XDG_CONFIG_HOME", env!("CARGO_TARGET_TMPDIR"));
+    command
+}
+
+fn buup(args: &[&str]) -> Output {
+    command(args).output().unwrap()
+}
+
+fn buup_with_stdin(args: &[&str], input: Vec<u8>) -> Output {
+    let mut child = command(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let writer = std::thread::spawn(move || stdin.write_all(&input));
+    let output = child.wait_with_output().unwrap();
+    writer.join().unwrap().unwrap();
+    output
+}
+
+#[test]
+fn max_output_limits_decompressors() {
+    // "hello" gzipped
+    let input = "H4sIAAAAAAAA/8tIzcnJBwCGphA2BQAAAA==";
+    let output = buup(&["gzipdecompress", "--max-output", "1K", input]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim_end(), "hello");
+
+    let output = buup(&["gzipdecompress", "--max-output", "2", input]);
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn max_output_is_rejected_for_other_transformers() {
+    let output = buup(&["base64encode", "--max-output", "1K", "hello"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr).trim_end(),
+        "Error: --max-output only applies to decompressors, not 'base64encode'"
+    );
+}
+
+#[test]
+fn timeout_cancels_transformers_with_options() {
+    // Big enough that sorting it takes far longer than the timeout
+    let input = format!("[{}0]", r#"{"b": 1, "a": [true, null]},"#.repeat(500_000));
+    let output = buup_with_stdin(&["json_sort_keys", "--timeout", "0.05"], input.into_bytes());
+    assert_eq!(output.status.code(), Some(5));
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,118 @@
+//! Golden-file snapshot tests for the formatters.
+//!
+//! Every directory under `tests/golden/` is named after a transformer ID and
+//! holds sample inputs. Each input `<name>` has its expected output stored next
+//! to it as `<name>.golden`. Run with `BUUP_UPDATE_GOLDEN=1` to (re)write the
+//! snapshots after an intentional formatting change, then review the diff.
+
+use buup::transformer_from_id;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const GOLDEN_EXTENSION: &str = "golden";
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+fn update_mode() -> bool {
+    std::env::var("BUUP_UPDATE_GOLDEN").is_ok_and(|v| v == "1")
+}
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+    entries
+}
+
+// Describes the first line where the actual output diverges from the snapshot
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (None, None) => return "outputs differ only in trailing newlines".to_string(),
+            (e, a) => {
+                return format!(
+                    "first difference at line {}:\n    expected: {:?}\n    actual:   {:?}",
+                    line,
+                    e.unwrap_or("<end of file>"),
+                    a.unwrap_or("<end of file>")
+                )
+            }
+        }
+    }
+}
+
+#[test]
+fn golden_snapshots() {
+    let update = update_mode();
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for dir in sorted_entries(&golden_dir()) {
+        if !dir.is_dir() {
+            continue;
+        }
+        let id = dir.file_name().unwrap().to_string_lossy().into_owned();
+        let transformer = transformer_from_id(&id)
+            .unwrap_or_else(|_| panic!("tests/golden/{} is not a transformer ID", id));
+
+        for input_path in sorted_entries(&dir) {
+            if input_path
+                .extension()
+                .is_some_and(|ext| ext == GOLDEN_EXTENSION)
+            {
+                continue;
+            }
+            let case = format!(
+                "{}/{}",
+                id,
+                input_path.file_name().unwrap().to_string_lossy()
+            );
+            let input = fs::read_to_string(&input_path).unwrap();
+            let actual = match transformer.transform(&input) {
+                Ok(output) => output,
+                Err(e) => {
+                    failures.push(format!("{}: transform failed: {}", case, e));
+                    continue;
+                }
+            };
+
+            let mut golden_path = input_path.clone().into_os_string();
+            golden_path.push(".");
+            golden_path.push(GOLDEN_EXTENSION);
+            let golden_path = PathBuf::from(golden_path);
+            checked += 1;
+
+            if update {
+                fs::write(&golden_path, &actual).unwrap();
+                continue;
+            }
+            match fs::read_to_string(&golden_path) {
+                Ok(expected) if expected == actual => {}
+                Ok(expected) => failures.push(format!(
+                    "{}: output changed, {}",
+                    case,
+                    first_difference(&expected, &actual)
+                )),
+                Err(_) => failures.push(format!("{}: missing snapshot", case)),
+            }
+        }
+    }
+
+    assert!(checked > 0, "no golden inputs found in tests/golden");
+    assert!(
+        failures.is_empty(),
+        "{} golden snapshot(s) failed (rerun with BUUP_UPDATE_GOLDEN=1 to accept changes):\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
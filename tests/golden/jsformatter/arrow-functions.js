@@ -0,0 +1,4 @@
+const add=(a,b)=>a+b;const square=x=>x*x;
+const log=async(...args)=>{await send(args);return args.length};
+items.map(item=>({id:item.id,label:item.name.trim()})).filter(Boolean);
+const compose=(...fns)=>x=>fns.reduceRight((acc,fn)=>fn(acc),x);
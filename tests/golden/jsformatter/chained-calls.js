@@ -0,0 +1,4 @@
+const total=orders.filter(o=>o.paid).map(o=>o.amount).reduce((sum,a)=>sum+a,0);
+document.querySelector("#app").classList.add("ready");
+fetch("/api/users").then(res=>res.json()).then(users=>render(users)).catch(console.error).finally(()=>setLoading(false));
+const value=config?.server?.port??8080;
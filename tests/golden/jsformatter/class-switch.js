@@ -0,0 +1,23 @@
+// A small state machine
+class Light extends EventTarget {
+  #state = "off"
+
+  /**
+   * Moves to the next state
+   */
+  next() {
+    switch (this.#state) {
+      case "off": this.#state = "on"; break
+      case "on":
+        this.#state = "blink"
+        break
+      default: this.#state = "off"
+    }
+    this.dispatchEvent(new Event("change"))
+  }
+
+  static from(value) { return Object.assign(new Light(), { value }) }
+}
+
+try { new Light().next() } catch (err) { console.error(err) } finally { done() }
+do { i++ } while (i < 10)
@@ -0,0 +1,4 @@
+const point={x:1,y:2};const empty={};const list=[1,2,3];
+const settings={theme:"dark",features:["search","export","sharing"],limits:{upload:10485760,requests:1000}};
+const matrix=[[1,0,0],[0,1,0],[0,0,1]];
+const handlers={onClick(){toggle()},get size(){return this.items.length},[computed]:true,...defaults};
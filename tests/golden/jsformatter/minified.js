@@ -0,0 +1 @@
+!function(e,t){"use strict";var n=/^\s+|\s+$/g;function r(e){return null==e?"":(e+"").replace(n,"")}for(var o=0;o<t.length;o++){if(t[o]instanceof Object)continue;e[o]=r(t[o])}e.trim=r,e.version="1.0.0"}(window,[" a ","b "]);
@@ -0,0 +1,5 @@
+const name=`world`;const greeting=`Hello, ${name}!`;
+const html=`<ul>${items.map(i=>`<li class="${i.done?"done":""}">${i.text}</li>`).join("")}</ul>`;
+const query=sql`SELECT * FROM users WHERE id = ${id}`;
+const multi=`line one
+  line two {not code}`;
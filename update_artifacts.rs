@@ -206,6 +206,7 @@ fn generate_sitemap() -> Result<(), Box<dyn Error>> {
             buup::TransformerCategory::Crypto => "0.8",
             buup::TransformerCategory::Compression => "0.9",
             buup::TransformerCategory::Color => "0.8",
+            buup::TransformerCategory::Generator => "0.7",
             buup::TransformerCategory::Other => "0.7",
         }
     };
@@ -218,6 +219,7 @@ fn generate_sitemap() -> Result<(), Box<dyn Error>> {
         buup::TransformerCategory::Formatter,
         buup::TransformerCategory::Crypto,
         buup::TransformerCategory::Color,
+        buup::TransformerCategory::Generator,
         buup::TransformerCategory::Other,
     ];
 